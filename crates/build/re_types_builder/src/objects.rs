@@ -1305,6 +1305,31 @@ impl ElementType {
             }
         }
 
+        let is_int = matches!(
+            inner_type,
+            FbsBaseType::Byte
+                | FbsBaseType::UByte
+                | FbsBaseType::Short
+                | FbsBaseType::UShort
+                | FbsBaseType::Int
+                | FbsBaseType::UInt
+                | FbsBaseType::Long
+                | FbsBaseType::ULong
+        );
+        if is_int {
+            // Same hack as in `Type::from_raw_type`: vectors & arrays of enums have an integer element type.
+            let enum_index = outer_type.index() as usize;
+            if enum_index < enums.len() {
+                assert!(
+                    inner_type == FbsBaseType::UByte,
+                    "For consistency, enums must be declared as the `ubyte` type"
+                );
+
+                let enum_ = &enums[enum_index];
+                return Self::Object(enum_.name().to_owned());
+            }
+        }
+
         #[allow(clippy::match_same_arms)]
         match inner_type {
             FbsBaseType::Bool => Self::Bool,
@@ -0,0 +1,83 @@
+use std::collections::BTreeSet;
+
+use arrow::array::BooleanArray as ArrowBooleanArray;
+use re_chunk::{ChunkId, RowId};
+
+use crate::{ChunkStore, ChunkStoreEvent};
+
+impl ChunkStore {
+    /// Drop the rows with the given [`RowId`]s, wherever they ended up after compaction.
+    ///
+    /// Chunks that only contain some of these rows are replaced by a copy without them.
+    ///
+    /// Static chunks are unaffected.
+    ///
+    /// Used to implement undo for data that was edited from within the viewer.
+    pub fn drop_rows(&mut self, row_ids: &BTreeSet<RowId>) -> Vec<ChunkStoreEvent> {
+        re_tracing::profile_function!();
+
+        let (Some(first_row_id), Some(last_row_id)) = (row_ids.first(), row_ids.last()) else {
+            return Default::default();
+        };
+
+        // Prepare the changes:
+
+        let mut chunk_ids_to_drop = vec![];
+        let mut new_chunks = vec![];
+
+        for (chunk_id, chunk) in &self.chunks_per_chunk_id {
+            if chunk.is_static() {
+                continue; // keep it
+            }
+
+            let Some((min_row_id, max_row_id)) = chunk.row_id_range() else {
+                continue;
+            };
+            if max_row_id < *first_row_id || *last_row_id < min_row_id {
+                continue; // keep it
+            }
+
+            let keep: ArrowBooleanArray = chunk
+                .row_ids()
+                .map(|row_id| Some(!row_ids.contains(&row_id)))
+                .collect();
+
+            if keep.true_count() == chunk.num_rows() {
+                continue; // keep it
+            }
+
+            chunk_ids_to_drop.push(*chunk_id);
+            if 0 < keep.true_count() {
+                if let Some(chunk) = chunk.filtered(&keep) {
+                    new_chunks.push(chunk.with_id(ChunkId::new()));
+                }
+            }
+        }
+
+        // ------------------
+        // Apply the changes:
+
+        let generation = self.generation();
+        let mut events: Vec<ChunkStoreEvent> = vec![];
+
+        for chunk_id in chunk_ids_to_drop {
+            for diff in self.remove_chunk(chunk_id) {
+                events.push(ChunkStoreEvent {
+                    store_id: self.id.clone(),
+                    store_generation: generation.clone(),
+                    event_id: self
+                        .event_id
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                    diff,
+                });
+            }
+        }
+        for mut chunk in new_chunks {
+            chunk.sort_if_unsorted();
+            #[allow(clippy::unwrap_used)] // The chunk came from the store, so it should be fine
+            events.append(&mut self.insert_chunk(&chunk.into()).unwrap());
+        }
+
+        events
+    }
+}
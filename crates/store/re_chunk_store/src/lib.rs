@@ -15,6 +15,7 @@
 //!
 
 mod dataframe;
+mod drop_rows;
 mod drop_time_range;
 mod events;
 mod gc;
@@ -22,6 +23,7 @@ mod query;
 mod stats;
 mod store;
 mod subscribers;
+mod timeline_mapping;
 mod writes;
 
 mod protobuf_conversions;
@@ -36,6 +38,7 @@ pub use self::{
     stats::{ChunkStoreChunkStats, ChunkStoreStats},
     store::{ChunkStore, ChunkStoreConfig, ChunkStoreGeneration, ChunkStoreHandle, ColumnMetadata},
     subscribers::{ChunkStoreSubscriber, ChunkStoreSubscriberHandle, PerStoreChunkSubscriber},
+    timeline_mapping::TimelineMapping,
 };
 pub use re_sorbet::{ColumnDescriptor, ComponentColumnDescriptor, TimeColumnDescriptor};
 
@@ -0,0 +1,366 @@
+use std::collections::BTreeMap;
+
+use re_chunk::{Chunk, ChunkResult, RowId, TimeColumn, TimeInt, Timeline};
+use re_log_types::{EntityPath, ResolvedTimeRange, TimelineName};
+use re_types_core::{
+    components::{ClockDrift, ClockOffset, SourceTimeline, TargetTimeline},
+    Component as _, ComponentName,
+};
+
+use crate::ChunkStore;
+
+// ---
+
+/// How the times of one timeline translate to times on another timeline.
+///
+/// Built from the [`re_types_core::archetypes::TimelineMapping`]s logged to a [`ChunkStore`],
+/// see [`ChunkStore::timeline_mappings`].
+///
+/// Mappings are always increasing: later times on the source timeline map to later times on the
+/// target timeline, which is what allows mapping time ranges and not just individual times.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineMapping {
+    pub source: Timeline,
+    pub target: Timeline,
+    model: ClockModel,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ClockModel {
+    /// `target = source + offset + drift * source`
+    Linear { offset: i64, drift: f64 },
+
+    /// The inverse of [`ClockModel::Linear`], i.e. mapping from its target back to its source.
+    InverseLinear { offset: i64, drift: f64 },
+
+    /// Pairs of `(source, target)` times, strictly increasing on both timelines.
+    Correspondences(Vec<(i64, i64)>),
+}
+
+impl TimelineMapping {
+    /// A linear clock model: `target = source + offset + drift * source`.
+    ///
+    /// Returns `None` if the drift would make time run backwards.
+    pub fn linear(source: Timeline, target: Timeline, offset: i64, drift: f64) -> Option<Self> {
+        (drift.is_finite() && drift > -1.0).then_some(Self {
+            source,
+            target,
+            model: ClockModel::Linear { offset, drift },
+        })
+    }
+
+    /// Interpolates between pairs of corresponding `(source, target)` times.
+    ///
+    /// Pairs that would make time run backwards on either timeline are dropped.
+    /// Returns `None` if no pairs are left.
+    pub fn from_correspondences(
+        source: Timeline,
+        target: Timeline,
+        correspondences: impl IntoIterator<Item = (TimeInt, TimeInt)>,
+    ) -> Option<Self> {
+        let mut correspondences: Vec<(i64, i64)> = correspondences
+            .into_iter()
+            .filter(|(source, target)| !source.is_static() && !target.is_static())
+            .map(|(source, target)| (source.as_i64(), target.as_i64()))
+            .collect();
+        correspondences.sort_unstable();
+
+        let mut increasing: Vec<(i64, i64)> = Vec::with_capacity(correspondences.len());
+        for (source, target) in correspondences {
+            if increasing
+                .last()
+                .map_or(true, |&(last_source, last_target)| {
+                    last_source < source && last_target < target
+                })
+            {
+                increasing.push((source, target));
+            }
+        }
+
+        (!increasing.is_empty()).then_some(Self {
+            source,
+            target,
+            model: ClockModel::Correspondences(increasing),
+        })
+    }
+
+    /// The same mapping, but from the target timeline to the source timeline.
+    pub fn inverted(&self) -> Self {
+        let model = match &self.model {
+            ClockModel::Linear { offset, drift } => ClockModel::InverseLinear {
+                offset: *offset,
+                drift: *drift,
+            },
+            ClockModel::InverseLinear { offset, drift } => ClockModel::Linear {
+                offset: *offset,
+                drift: *drift,
+            },
+            ClockModel::Correspondences(correspondences) => ClockModel::Correspondences(
+                correspondences
+                    .iter()
+                    .map(|&(source, target)| (target, source))
+                    .collect(),
+            ),
+        };
+
+        Self {
+            source: self.target,
+            target: self.source,
+            model,
+        }
+    }
+
+    /// Expresses a time of the source timeline on the target timeline.
+    ///
+    /// Static data stays static, and the ends of the timelines map onto each other.
+    pub fn to_target(&self, time: TimeInt) -> TimeInt {
+        if time.is_static() || time == TimeInt::MIN || time == TimeInt::MAX {
+            return time;
+        }
+
+        let time = time.as_i64() as i128;
+        let mapped = match &self.model {
+            ClockModel::Linear { offset, drift } => {
+                time + *offset as i128 + (time as f64 * drift).round() as i128
+            }
+            ClockModel::InverseLinear { offset, drift } => {
+                let time = time - *offset as i128;
+                time - (time as f64 * drift / (1.0 + drift)).round() as i128
+            }
+            ClockModel::Correspondences(correspondences) => interpolate(correspondences, time),
+        };
+
+        TimeInt::new_temporal(mapped.clamp(i64::MIN as i128 + 1, i64::MAX as i128) as i64)
+    }
+
+    /// Expresses a time of the target timeline on the source timeline.
+    pub fn to_source(&self, time: TimeInt) -> TimeInt {
+        self.inverted().to_target(time)
+    }
+
+    /// Expresses a time range of the target timeline on the source timeline.
+    pub fn range_to_source(&self, range: ResolvedTimeRange) -> ResolvedTimeRange {
+        let inverse = self.inverted();
+        ResolvedTimeRange::new(
+            inverse.to_target(range.min()),
+            inverse.to_target(range.max()),
+        )
+    }
+
+    /// Adds a column for the target timeline to a chunk that has the source timeline.
+    ///
+    /// Returns the chunk as-is if it is static, doesn't have the source timeline, or already has
+    /// the target timeline.
+    pub fn chunk_with_target_timeline(&self, chunk: &Chunk) -> ChunkResult<Chunk> {
+        if chunk.timelines().contains_key(&self.target) {
+            return Ok(chunk.clone());
+        }
+        let Some(time_column) = chunk.timelines().get(&self.source) else {
+            return Ok(chunk.clone());
+        };
+
+        let times: Vec<i64> = time_column
+            .times()
+            .map(|time| self.to_target(time).as_i64())
+            .collect();
+
+        // The mapping is increasing, so the new column is sorted iff the source column is.
+        let mut chunk = chunk.clone();
+        chunk.add_timeline(TimeColumn::new(
+            Some(time_column.is_sorted()),
+            self.target,
+            times.into(),
+        ))?;
+
+        Ok(chunk.sorted_by_timeline_if_unsorted(&self.target))
+    }
+}
+
+/// Linear interpolation between the two correspondences closest to `time`.
+///
+/// With a single correspondence, the timelines are assumed to run at the same rate.
+fn interpolate(correspondences: &[(i64, i64)], time: i128) -> i128 {
+    let pair = |idx: usize| {
+        let (source, target) = correspondences[idx];
+        (source as i128, target as i128)
+    };
+
+    match correspondences.len() {
+        0 => time,
+        1 => {
+            let (source, target) = pair(0);
+            target + (time - source)
+        }
+        len => {
+            let idx = correspondences
+                .partition_point(|&(source, _)| (source as i128) <= time)
+                .clamp(1, len - 1);
+            let (source0, target0) = pair(idx - 1);
+            let (source1, target1) = pair(idx);
+            target0 + (time - source0) * (target1 - target0) / (source1 - source0)
+        }
+    }
+}
+
+// ---
+
+impl ChunkStore {
+    /// All [`TimelineMapping`]s logged to this store.
+    ///
+    /// If the same pair of timelines is mapped more than once, the most recently logged linear
+    /// clock model wins, and otherwise all correspondences are merged into a single table.
+    ///
+    /// This scans the whole store, callers should cache the results.
+    pub fn timeline_mappings(&self) -> Vec<TimelineMapping> {
+        re_tracing::profile_function!();
+
+        #[derive(Default)]
+        struct MappingRows {
+            linear: Option<(RowId, i64, f64)>,
+            correspondences: Vec<(Timeline, Timeline, TimeInt, TimeInt)>,
+        }
+
+        let mut rows_per_mapping: BTreeMap<(TimelineName, TimelineName), MappingRows> =
+            Default::default();
+
+        let source_name = SourceTimeline::name();
+        for chunk in self.chunks_per_chunk_id.values() {
+            if chunk.get_first_component(&source_name).is_none() {
+                continue;
+            }
+
+            for (row_index, row_id) in chunk.row_ids().enumerate() {
+                let (Some(Ok(source)), Some(Ok(target))) = (
+                    chunk.component_mono::<SourceTimeline>(row_index),
+                    chunk.component_mono::<TargetTimeline>(row_index),
+                ) else {
+                    continue;
+                };
+                let source = TimelineName::new(source.as_str());
+                let target = TimelineName::new(target.as_str());
+                if source == target {
+                    continue;
+                }
+
+                let rows = rows_per_mapping.entry((source, target)).or_default();
+
+                let offset = chunk.component_mono::<ClockOffset>(row_index);
+                let drift = chunk.component_mono::<ClockDrift>(row_index);
+                if offset.is_some() || drift.is_some() {
+                    let offset = offset.and_then(Result::ok).map_or(0, |offset| offset.0 .0);
+                    let drift = drift.and_then(Result::ok).map_or(0.0, |drift| drift.0 .0);
+                    if rows
+                        .linear
+                        .map_or(true, |(latest_row_id, _, _)| latest_row_id < row_id)
+                    {
+                        rows.linear = Some((row_id, offset, drift));
+                    }
+                    continue;
+                }
+
+                let time_column = |name: &TimelineName| {
+                    chunk
+                        .timelines()
+                        .iter()
+                        .find(|(timeline, _)| timeline.name() == name)
+                };
+                if let (
+                    Some((source_timeline, source_times)),
+                    Some((target_timeline, target_times)),
+                ) = (time_column(&source), time_column(&target))
+                {
+                    rows.correspondences.push((
+                        *source_timeline,
+                        *target_timeline,
+                        TimeInt::new_temporal(source_times.times_raw()[row_index]),
+                        TimeInt::new_temporal(target_times.times_raw()[row_index]),
+                    ));
+                }
+            }
+        }
+
+        let timelines = self.all_timelines();
+        let timeline_named = |name: &TimelineName| {
+            timelines
+                .iter()
+                .find(|timeline| timeline.name() == name)
+                .copied()
+        };
+
+        rows_per_mapping
+            .into_iter()
+            .filter_map(|((source, target), rows)| {
+                if let Some((_, offset, drift)) = rows.linear {
+                    // A timeline that has no data yet is assumed to be of the same type as the
+                    // other one: both sides of a clock model are usually in the same unit.
+                    let (source, target) = match (timeline_named(&source), timeline_named(&target))
+                    {
+                        (Some(source), Some(target)) => (source, target),
+                        (Some(source), None) => (source, Timeline::new(target, source.typ())),
+                        (None, Some(target)) => (Timeline::new(source, target.typ()), target),
+                        (None, None) => return None,
+                    };
+                    let mapping = TimelineMapping::linear(source, target, offset, drift);
+                    if mapping.is_none() {
+                        re_log::warn_once!(
+                            "Ignoring the mapping from {:?} to {:?}: a drift of {drift} makes time run backwards",
+                            source.name(),
+                            target.name(),
+                        );
+                    }
+                    mapping
+                } else {
+                    let (source, target, _, _) = rows.correspondences.first().copied()?;
+                    TimelineMapping::from_correspondences(
+                        source,
+                        target,
+                        rows.correspondences
+                            .iter()
+                            .map(|&(_, _, source_time, target_time)| (source_time, target_time)),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// Finds a mapping to `timeline` from another timeline that `entity_path` has `component_name` on.
+    ///
+    /// This is what allows querying data logged on one timeline at a time expressed on another.
+    /// Returns `None` if the component is static or already on `timeline`, since no mapping is
+    /// needed in that case.
+    pub fn timeline_mapping_for(
+        &self,
+        mappings: &[TimelineMapping],
+        timeline: &Timeline,
+        entity_path: &EntityPath,
+        component_name: &ComponentName,
+    ) -> Option<TimelineMapping> {
+        if mappings.is_empty()
+            || self.entity_has_component_on_timeline(timeline, entity_path, component_name)
+        {
+            return None;
+        }
+
+        mappings.iter().find_map(|mapping| {
+            if mapping.target == *timeline
+                && self.entity_has_temporal_component_on_timeline(
+                    &mapping.source,
+                    entity_path,
+                    component_name,
+                )
+            {
+                Some(mapping.clone())
+            } else if mapping.source == *timeline
+                && self.entity_has_temporal_component_on_timeline(
+                    &mapping.target,
+                    entity_path,
+                    component_name,
+                )
+            {
+                Some(mapping.inverted())
+            } else {
+                None
+            }
+        })
+    }
+}
@@ -0,0 +1,80 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use re_chunk::{Chunk, RowId};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+use re_log_types::example_components::MyColor;
+use re_log_types::{EntityPath, TimePoint, Timeline};
+use re_types_core::Component as _;
+
+#[test]
+fn drop_rows() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let entity_path = EntityPath::from("this/that");
+    let timeline = Timeline::new_sequence("timeline");
+    let data = MyColor::from_rgb(255, 0, 0);
+    let time_point_at = |time: i64| TimePoint::from([(timeline, time)]);
+
+    for config in [
+        ChunkStoreConfig::DEFAULT,
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    ] {
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            config,
+        );
+
+        let num_events = |store: &ChunkStore| {
+            store.num_temporal_events_for_component_on_timeline(
+                &timeline,
+                &entity_path,
+                MyColor::name(),
+            )
+        };
+
+        let row_ids: Vec<RowId> = (0..6).map(|_| RowId::new()).collect();
+
+        store.insert_chunk(&Arc::new(
+            Chunk::builder(entity_path.clone())
+                .with_component_batch(row_ids[0], time_point_at(0), &data)
+                .with_component_batch(row_ids[1], time_point_at(1), &data)
+                .with_component_batch(row_ids[2], time_point_at(2), &data)
+                .build()?,
+        ))?;
+
+        // Small edits like these typically get compacted into the existing chunk.
+        for (time, row_id) in [(1, row_ids[3]), (3, row_ids[4]), (4, row_ids[5])] {
+            store.insert_chunk(&Arc::new(
+                Chunk::builder(entity_path.clone())
+                    .with_component_batch(row_id, time_point_at(time), &data)
+                    .build()?,
+            ))?;
+        }
+
+        assert_eq!(num_events(&store), 6);
+
+        // Drop nothing:
+        store.drop_rows(&BTreeSet::new());
+        store.drop_rows(&BTreeSet::from([RowId::new()]));
+        assert_eq!(num_events(&store), 6);
+
+        // Drop some of the edits, wherever they ended up:
+        store.drop_rows(&BTreeSet::from([row_ids[3], row_ids[5]]));
+        assert_eq!(num_events(&store), 4);
+
+        // Drop an original row and the last edit:
+        store.drop_rows(&BTreeSet::from([row_ids[0], row_ids[4]]));
+        assert_eq!(num_events(&store), 2);
+
+        let remaining: BTreeSet<RowId> = store
+            .iter_chunks()
+            .flat_map(|chunk| chunk.row_ids().collect::<Vec<_>>())
+            .collect();
+        assert_eq!(remaining, BTreeSet::from([row_ids[1], row_ids[2]]));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId, TimePoint};
+use re_chunk_store::{
+    ChunkStore, ChunkStoreConfig, ResolvedTimeRange, TimeInt, TimeType, Timeline, TimelineMapping,
+};
+use re_log_types::{example_components::MyPoint, EntityPath, StoreId};
+use re_types_core::{archetypes, Component as _};
+
+// ---
+
+fn new_store() -> ChunkStore {
+    ChunkStore::new(
+        StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    )
+}
+
+fn insert(store: &mut ChunkStore, chunk: Chunk) -> anyhow::Result<()> {
+    store.insert_chunk(&Arc::new(chunk))?;
+    Ok(())
+}
+
+#[test]
+fn correspondences() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+
+    let mut store = new_store();
+
+    let mapping_path = EntityPath::from("clocks");
+    let mut builder = Chunk::builder(mapping_path);
+    for (device_time, frame_nr) in [(1000, 10), (2000, 20), (4000, 30)] {
+        builder = builder.with_archetype(
+            RowId::new(),
+            TimePoint::default()
+                .with(device, device_time)
+                .with(frame, frame_nr),
+            &archetypes::TimelineMapping::new("device_time", "frame_nr"),
+        );
+    }
+    insert(&mut store, builder.build()?)?;
+
+    let mappings = store.timeline_mappings();
+    assert_eq!(1, mappings.len());
+    let mapping = &mappings[0];
+    assert_eq!(device, mapping.source);
+    assert_eq!(frame, mapping.target);
+
+    let t = TimeInt::new_temporal;
+
+    // Exact correspondences.
+    assert_eq!(t(10), mapping.to_target(t(1000)));
+    assert_eq!(t(30), mapping.to_target(t(4000)));
+
+    // Interpolation.
+    assert_eq!(t(15), mapping.to_target(t(1500)));
+    assert_eq!(t(25), mapping.to_target(t(3000)));
+
+    // Extrapolation using the closest segment.
+    assert_eq!(t(0), mapping.to_target(t(0)));
+    assert_eq!(t(40), mapping.to_target(t(6000)));
+
+    // Both directions.
+    assert_eq!(t(3000), mapping.to_source(t(25)));
+    assert_eq!(
+        ResolvedTimeRange::new(1500, 3000),
+        mapping.range_to_source(ResolvedTimeRange::new(15, 25))
+    );
+
+    // Static data and the ends of the timelines are left untouched.
+    assert_eq!(TimeInt::STATIC, mapping.to_target(TimeInt::STATIC));
+    assert_eq!(TimeInt::MIN, mapping.to_target(TimeInt::MIN));
+    assert_eq!(TimeInt::MAX, mapping.to_target(TimeInt::MAX));
+
+    Ok(())
+}
+
+#[test]
+fn correspondences_must_increase() {
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+
+    let t = TimeInt::new_temporal;
+
+    // The last pair would make time run backwards on the target timeline.
+    let mapping = TimelineMapping::from_correspondences(
+        device,
+        frame,
+        [(t(0), t(0)), (t(10), t(100)), (t(20), t(50))],
+    )
+    .unwrap();
+    assert_eq!(t(200), mapping.to_target(t(20)));
+
+    // A single pair is a plain offset.
+    let mapping = TimelineMapping::from_correspondences(device, frame, [(t(5), t(105))]).unwrap();
+    assert_eq!(t(142), mapping.to_target(t(42)));
+
+    assert!(TimelineMapping::from_correspondences(device, frame, []).is_none());
+}
+
+#[test]
+fn linear() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let device = Timeline::new("device_time", TimeType::Time);
+    let log = Timeline::new("log_time", TimeType::Time);
+
+    let mut store = new_store();
+
+    let entity_path = EntityPath::from("points");
+    insert(
+        &mut store,
+        Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                RowId::new(),
+                TimePoint::default().with(device, 0).with(log, 0),
+                &[MyPoint::new(1.0, 1.0)],
+            )
+            .build()?,
+    )?;
+
+    let mapping_path = EntityPath::from("clocks");
+    for offset in [1_000_i64, 5_000] {
+        // Only the most recently logged model is used.
+        insert(
+            &mut store,
+            Chunk::builder(mapping_path.clone())
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default(),
+                    &archetypes::TimelineMapping::new("device_time", "log_time")
+                        .with_offset(offset)
+                        .with_drift(0.5),
+                )
+                .build()?,
+        )?;
+    }
+
+    let mappings = store.timeline_mappings();
+    assert_eq!(1, mappings.len());
+    let mapping = &mappings[0];
+
+    let t = TimeInt::new_temporal;
+    assert_eq!(t(5_000 + 1_000 + 500), mapping.to_target(t(1_000)));
+    assert_eq!(t(1_000), mapping.to_source(t(6_500)));
+    assert_eq!(t(1_000), mapping.inverted().to_target(t(6_500)));
+    assert_eq!(mapping, &mapping.inverted().inverted());
+
+    // Time that runs backwards is rejected.
+    assert!(TimelineMapping::linear(device, log, 0, -1.0).is_none());
+
+    // The points exist on both timelines already: no mapping needed.
+    assert!(store
+        .timeline_mapping_for(&mappings, &log, &entity_path, &MyPoint::name())
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+fn mapping_lookup() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+
+    let mut store = new_store();
+
+    let entity_path = EntityPath::from("sensor");
+    insert(
+        &mut store,
+        Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                RowId::new(),
+                TimePoint::default().with(device, 1_000),
+                &[MyPoint::new(1.0, 1.0)],
+            )
+            .build()?,
+    )?;
+    insert(
+        &mut store,
+        Chunk::builder(EntityPath::from("clocks"))
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &archetypes::TimelineMapping::new("frame_nr", "device_time").with_offset(-100_i64),
+            )
+            .build()?,
+    )?;
+    insert(
+        &mut store,
+        Chunk::builder(EntityPath::from("camera"))
+            .with_component_batch(
+                RowId::new(),
+                TimePoint::default().with(frame, 1),
+                &[MyPoint::new(2.0, 2.0)],
+            )
+            .build()?,
+    )?;
+
+    let mappings = store.timeline_mappings();
+
+    // The mapping was logged from `frame_nr` to `device_time`: querying the sensor on `frame_nr`
+    // goes through the inverse.
+    let mapping = store
+        .timeline_mapping_for(&mappings, &frame, &entity_path, &MyPoint::name())
+        .unwrap();
+    assert_eq!(device, mapping.source);
+    assert_eq!(frame, mapping.target);
+    assert_eq!(
+        TimeInt::new_temporal(1_100),
+        mapping.to_target(TimeInt::new_temporal(1_000))
+    );
+
+    // The sensor data lives on `device_time` already.
+    assert!(store
+        .timeline_mapping_for(&mappings, &device, &entity_path, &MyPoint::name())
+        .is_none());
+
+    // Timelines that aren't mapped at all.
+    let other = Timeline::new("other", TimeType::Sequence);
+    assert!(store
+        .timeline_mapping_for(&mappings, &other, &entity_path, &MyPoint::name())
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+fn chunk_with_target_timeline() -> anyhow::Result<()> {
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+
+    let t = TimeInt::new_temporal;
+    let mapping =
+        TimelineMapping::from_correspondences(device, frame, [(t(0), t(0)), (t(100), t(10))])
+            .unwrap();
+
+    let chunk = Chunk::builder(EntityPath::from("sensor"))
+        .with_component_batch(
+            RowId::new(),
+            TimePoint::default().with(device, 50),
+            &[MyPoint::new(1.0, 1.0)],
+        )
+        .with_component_batch(
+            RowId::new(),
+            TimePoint::default().with(device, 200),
+            &[MyPoint::new(2.0, 2.0)],
+        )
+        .build()?;
+
+    let chunk = mapping.chunk_with_target_timeline(&chunk)?;
+    let times = chunk
+        .timelines()
+        .get(&frame)
+        .unwrap()
+        .times()
+        .collect::<Vec<_>>();
+    assert_eq!(vec![t(5), t(20)], times);
+    assert!(chunk.is_timeline_sorted(&frame));
+
+    Ok(())
+}
@@ -101,7 +101,7 @@ impl DataSource {
 
         // TODO(#8761): URL prefix
         #[cfg(feature = "grpc")]
-        if uri.starts_with("temp://") {
+        if uri.starts_with("temp://") || uri.starts_with("temps://") {
             return Self::MessageProxy { url: uri };
         }
 
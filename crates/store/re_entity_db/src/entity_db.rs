@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 use nohash_hasher::IntMap;
 use parking_lot::Mutex;
@@ -488,6 +488,26 @@ impl EntityDb {
         store_events
     }
 
+    /// Drop the rows with the given [`RowId`]s.
+    ///
+    /// Used to implement undo for data that was edited from within the viewer.
+    pub fn drop_rows(&mut self, row_ids: &BTreeSet<RowId>) -> Vec<ChunkStoreEvent> {
+        re_tracing::profile_function!();
+
+        let mut engine = self.storage_engine.write();
+
+        let store_events = engine.store().drop_rows(row_ids);
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+
+        store_events
+    }
+
     /// Unconditionally drops all the data for a given [`EntityPath`] .
     ///
     /// This is _not_ recursive. Children of this entity will not be affected.
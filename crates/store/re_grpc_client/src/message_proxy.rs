@@ -1,8 +1,11 @@
 pub mod read;
-pub use read::stream;
+pub use read::{stream, viewer_commands, ViewerEventPublisher};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use read::add_tls_ca_certificate;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod write;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use write::Client;
+pub use write::{Client, ViewerCommandError, ViewerRemote};
@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::sync::Mutex;
 
 use re_log_encoding::protobuf_conversions::log_msg_from_proto;
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerCommand, ViewerEvent};
 use re_protos::sdk_comms::v0::message_proxy_client::MessageProxyClient;
-use re_protos::sdk_comms::v0::Empty;
+use re_protos::sdk_comms::v0::{Empty, ViewerEvent as ViewerEventProto};
 use tokio_stream::StreamExt;
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
 use url::Url;
 
 use crate::StreamError;
@@ -14,9 +19,12 @@ pub fn stream(
     url: String,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> Result<re_smart_channel::Receiver<LogMsg>, InvalidMessageProxyAddress> {
-    re_log::debug!("Loading {url} via gRPC…");
-
     let parsed_url = MessageProxyAddress::parse(&url)?;
+    parsed_url.remember_auth_token();
+
+    // The token must never end up in the UI or in the logs.
+    let url = parsed_url.redacted_url.clone();
+    re_log::debug!("Loading {url} via gRPC…");
 
     let (tx, rx) = re_smart_channel::smart_channel(
         re_smart_channel::SmartMessageSource::MessageProxy { url: url.clone() },
@@ -35,39 +43,261 @@ pub fn stream(
     Ok(rx)
 }
 
-struct MessageProxyAddress(String);
+#[cfg(target_arch = "wasm32")]
+type Transport = tonic_web_wasm_client::Client;
+
+#[cfg(not(target_arch = "wasm32"))]
+type Transport = tonic::transport::Channel;
+
+type ProxyClient = MessageProxyClient<InterceptedService<Transport, AuthToken>>;
+
+/// Adds the auth token of a [`MessageProxyAddress`] to every request, if it has one.
+///
+/// This is the same as `re_auth::client::AuthDecorator`, which isn't available on the web.
+#[derive(Clone)]
+struct AuthToken(Option<MetadataValue<Ascii>>);
+
+impl Interceptor for AuthToken {
+    fn call(&mut self, mut req: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        if let Some(token) = &self.0 {
+            req.metadata_mut().insert("authorization", token.clone());
+        }
+        Ok(req)
+    }
+}
+
+/// Publishes [`ViewerEvent`]s to a message proxy, so that any SDK subscribed to it can react to them.
+///
+/// This is the reverse direction of [`stream`]: it is used by viewers to tell SDKs what the user is doing.
+#[derive(Clone)]
+pub struct ViewerEventPublisher {
+    url: String,
+    client: ProxyClient,
+}
+
+impl ViewerEventPublisher {
+    /// The connection is established lazily, when the first event is published.
+    pub fn new(url: &str) -> Result<Self, InvalidMessageProxyAddress> {
+        let parsed_url = MessageProxyAddress::parse(url)?;
+
+        #[cfg(target_arch = "wasm32")]
+        let tonic_client = parsed_url.web_client();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let tonic_client = parsed_url
+            .endpoint()
+            .map_err(|err| InvalidMessageProxyAddress {
+                url: parsed_url.redacted_url.clone(),
+                msg: err.to_string(),
+            })?
+            .connect_lazy();
+
+        Ok(Self {
+            url: parsed_url.redacted_url.clone(),
+            client: MessageProxyClient::with_interceptor(tonic_client, parsed_url.auth_token()),
+        })
+    }
+
+    /// The url of the message proxy this publisher sends events to, without its auth token.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Send an event in the background.
+    ///
+    /// Errors are logged, but otherwise ignored: nobody might be listening anyway.
+    pub fn publish(&self, event: ViewerEvent) {
+        let mut client = self.client.clone();
+        let url = self.url.clone();
+
+        crate::spawn_future(async move {
+            if let Err(err) = client
+                .write_viewer_event(ViewerEventProto::from(event))
+                .await
+            {
+                re_log::debug!("Failed to publish viewer event to {url}: {err}");
+            }
+        });
+    }
+}
+
+/// Receive the [`ViewerCommand`]s sent to a message proxy, e.g. by a script remote controlling the viewer.
+///
+/// `on_cmd` can be used to wake up the UI thread on Wasm.
+///
+/// The returned channel is disconnected once the message proxy closes the connection.
+pub fn viewer_commands(
+    url: &str,
+    on_cmd: Option<Box<dyn Fn() + Send + Sync>>,
+) -> Result<std::sync::mpsc::Receiver<ViewerCommand>, InvalidMessageProxyAddress> {
+    let parsed_url = MessageProxyAddress::parse(url)?;
+    let url = parsed_url.redacted_url.clone();
+    re_log::debug!("Receiving viewer commands from {url}…");
+
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    crate::spawn_future(async move {
+        if let Err(err) = viewer_commands_async(parsed_url, tx, on_cmd).await {
+            re_log::warn!(
+                "Error while receiving viewer commands from {url}: {}",
+                re_error::format_ref(&err)
+            );
+        }
+    });
+
+    Ok(rx)
+}
+
+/// The auth tokens of the message proxies we stream from, by their url without the token.
+///
+/// Urls are shown and stored without their token, e.g. in [`re_smart_channel::SmartChannelSource`],
+/// so this is how viewer events and commands reuse the token of the stream they belong to.
+static AUTH_TOKENS: Mutex<BTreeMap<String, MetadataValue<Ascii>>> = Mutex::new(BTreeMap::new());
+
+/// Certificate authorities to trust for `temps://` urls, in addition to the system's, see [`add_tls_ca_certificate`].
+#[cfg(not(target_arch = "wasm32"))]
+static TLS_CA_CERTIFICATES: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+/// Trust this PEM encoded certificate authority for all later `temps://` connections, in addition to the system's.
+///
+/// E.g. for message proxies with a self-signed certificate, like `rerun --serve-grpc --tls-cert`.
+/// This is what `write::Options::with_tls_ca_certificate` is for SDKs.
+/// On the web, the browser decides which certificates to trust.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn add_tls_ca_certificate(pem: Vec<u8>) {
+    if let Ok(mut certificates) = TLS_CA_CERTIFICATES.lock() {
+        certificates.push(pem);
+    }
+}
+
+/// Removes the `token` query parameter from a url, so that it can be shown and logged.
+fn redact_token(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_owned();
+    };
+
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            url::form_urlencoded::parse(query.as_bytes()).filter(|(key, _)| key != "token"),
+        )
+        .finish();
+
+    if query.is_empty() {
+        base.to_owned()
+    } else {
+        format!("{base}?{query}")
+    }
+}
+
+/// A parsed `temp://` (or `temps://` for TLS) url.
+///
+/// An auth token can be passed as a `token` query parameter, e.g. `temp://127.0.0.1:1852?token=…`.
+/// Without one, the token of an earlier [`stream`] from the same url is used.
+struct MessageProxyAddress {
+    /// E.g. `http://127.0.0.1:1852`.
+    url: String,
+
+    /// The original `temp://` url, without the auth token.
+    redacted_url: String,
+
+    /// The `authorization` header value, if any.
+    auth: Option<MetadataValue<Ascii>>,
+}
 
 impl MessageProxyAddress {
     fn parse(url: &str) -> Result<Self, InvalidMessageProxyAddress> {
-        let Some(url) = url.strip_prefix("temp") else {
+        let redacted_url = redact_token(url);
+
+        // TODO(#8761): URL prefix
+        let (scheme, rest) = if let Some(rest) = url.strip_prefix("temps") {
+            ("https", rest)
+        } else if let Some(rest) = url.strip_prefix("temp") {
+            ("http", rest)
+        } else {
             let scheme = url.split_once("://").map(|(a, _)| a).ok_or("unknown");
             return Err(InvalidMessageProxyAddress {
-                url: url.to_owned(),
+                url: redacted_url,
                 msg: format!(
-                    "Invalid scheme {scheme:?}, expected {:?}",
-                    // TODO(#8761): URL prefix
-                    "temp"
+                    "Invalid scheme {scheme:?}, expected {:?} or {:?}",
+                    "temp", "temps"
                 ),
             });
         };
-        let url = format!("http{url}");
 
-        let _ = Url::parse(&url).map_err(|err| InvalidMessageProxyAddress {
-            url: url.clone(),
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let http_url = format!("{scheme}{rest}");
+
+        let _ = Url::parse(&http_url).map_err(|err| InvalidMessageProxyAddress {
+            url: http_url.clone(),
             msg: err.to_string(),
         })?;
 
-        Ok(Self(url))
+        let auth = url::form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key == "token")
+            .map(|(_, token)| {
+                format!("Bearer {token}")
+                    .parse()
+                    .map_err(|_err| InvalidMessageProxyAddress {
+                        url: http_url.clone(),
+                        msg: "malformed auth token".to_owned(),
+                    })
+            })
+            .transpose()?
+            .or_else(|| {
+                AUTH_TOKENS
+                    .lock()
+                    .ok()
+                    .and_then(|tokens| tokens.get(&redacted_url).cloned())
+            });
+
+        Ok(Self {
+            url: http_url,
+            redacted_url,
+            auth,
+        })
+    }
+
+    /// Reuse our auth token for later connections to the same url, see [`AUTH_TOKENS`].
+    fn remember_auth_token(&self) {
+        if let (Some(auth), Ok(mut tokens)) = (&self.auth, AUTH_TOKENS.lock()) {
+            tokens.insert(self.redacted_url.clone(), auth.clone());
+        }
+    }
+
+    fn auth_token(&self) -> AuthToken {
+        AuthToken(self.auth.clone())
     }
 
-    fn to_http(&self) -> String {
-        self.0.clone()
+    #[cfg(target_arch = "wasm32")]
+    fn web_client(&self) -> Transport {
+        tonic_web_wasm_client::Client::new_with_options(
+            self.url.clone(),
+            tonic_web_wasm_client::options::FetchOptions::new(),
+        )
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn endpoint(&self) -> Result<tonic::transport::Endpoint, tonic::transport::Error> {
+        let endpoint = tonic::transport::Endpoint::new(self.url.clone())?;
+        if self.url.starts_with("https://") {
+            let mut tls_config = tonic::transport::ClientTlsConfig::new().with_native_roots();
+            if let Ok(certificates) = TLS_CA_CERTIFICATES.lock() {
+                for pem in certificates.iter() {
+                    tls_config =
+                        tls_config.ca_certificate(tonic::transport::Certificate::from_pem(pem));
+                }
+            }
+            endpoint.tls_config(tls_config)
+        } else {
+            Ok(endpoint)
+        }
     }
 }
 
 impl Display for MessageProxyAddress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Display::fmt(&self.0, f)
+        // Never print the token.
+        Display::fmt(&self.url, f)
     }
 }
 
@@ -84,21 +314,15 @@ async fn stream_async(
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> Result<(), StreamError> {
     let mut client = {
-        let url = url.to_http();
-
         #[cfg(target_arch = "wasm32")]
-        let tonic_client = {
-            tonic_web_wasm_client::Client::new_with_options(
-                url,
-                tonic_web_wasm_client::options::FetchOptions::new(),
-            )
-        };
+        let tonic_client = url.web_client();
 
         #[cfg(not(target_arch = "wasm32"))]
-        let tonic_client = { tonic::transport::Endpoint::new(url)?.connect().await? };
+        let tonic_client = url.endpoint()?.connect().await?;
 
         // TODO(#8411): figure out the right size for this
-        MessageProxyClient::new(tonic_client).max_decoding_message_size(usize::MAX)
+        MessageProxyClient::with_interceptor(tonic_client, url.auth_token())
+            .max_decoding_message_size(usize::MAX)
     };
 
     re_log::debug!("Streaming messages from gRPC endpoint {url}");
@@ -137,3 +361,93 @@ async fn stream_async(
 
     Ok(())
 }
+
+async fn viewer_commands_async(
+    url: MessageProxyAddress,
+    tx: std::sync::mpsc::Sender<ViewerCommand>,
+    on_cmd: Option<Box<dyn Fn() + Send + Sync>>,
+) -> Result<(), StreamError> {
+    let mut client = {
+        #[cfg(target_arch = "wasm32")]
+        let tonic_client = url.web_client();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let tonic_client = url.endpoint()?.connect().await?;
+
+        MessageProxyClient::with_interceptor(tonic_client, url.auth_token())
+    };
+
+    let mut stream = client
+        .read_viewer_commands(Empty {})
+        .await
+        .map_err(TonicStatusError)?
+        .into_inner();
+
+    loop {
+        match stream.try_next().await {
+            Ok(Some(cmd)) => {
+                let cmd: ViewerCommand = match cmd.try_into() {
+                    Ok(cmd) => cmd,
+                    Err(err) => {
+                        re_log::warn!("Ignoring invalid viewer command: {err}");
+                        continue;
+                    }
+                };
+                if tx.send(cmd).is_err() {
+                    re_log::debug!("Viewer command channel closed");
+                    break;
+                }
+                if let Some(on_cmd) = &on_cmd {
+                    on_cmd();
+                }
+            }
+
+            // Stream closed
+            Ok(None) => {
+                re_log::debug!("Viewer command stream disconnected");
+                break;
+            }
+
+            Err(err) => {
+                re_log::debug!("Error while receiving viewer commands: {err}");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_token() {
+        assert_eq!(
+            redact_token("temp://127.0.0.1:1852"),
+            "temp://127.0.0.1:1852"
+        );
+        assert_eq!(
+            redact_token("temp://127.0.0.1:1852?token=secret"),
+            "temp://127.0.0.1:1852"
+        );
+        assert_eq!(
+            redact_token("temps://lab:1852?foo=bar&token=secret"),
+            "temps://lab:1852?foo=bar"
+        );
+    }
+
+    #[test]
+    fn test_address_never_shows_token() {
+        let address = MessageProxyAddress::parse("temp://127.0.0.1:1852?token=a%2Bb").unwrap();
+        assert_eq!(address.url, "http://127.0.0.1:1852");
+        assert_eq!(address.redacted_url, "temp://127.0.0.1:1852");
+        assert_eq!(address.auth.unwrap().to_str().unwrap(), "Bearer a+b");
+
+        let err = MessageProxyAddress::parse("foo://127.0.0.1:1852?token=secret")
+            .err()
+            .unwrap();
+        assert!(!err.to_string().contains("secret"));
+    }
+}
@@ -1,9 +1,12 @@
 use std::thread;
 use std::thread::JoinHandle;
 
+use re_auth::client::AuthDecorator;
+pub use re_auth::Jwt;
 use re_log_encoding::Compression;
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerCommand, ViewerEvent};
 use re_protos::sdk_comms::v0::message_proxy_client::MessageProxyClient;
+use re_protos::sdk_comms::v0::{Empty, ViewerCommand as ViewerCommandProto};
 use tokio::runtime;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::Receiver;
@@ -11,38 +14,82 @@ use tokio::sync::mpsc::Sender;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::Channel;
 use tonic::transport::Endpoint;
+use tonic::transport::{Certificate, ClientTlsConfig};
+
+use crate::TonicStatusError;
 
 enum Cmd {
     LogMsg(LogMsg),
     Flush(oneshot::Sender<()>),
 }
 
+type ViewerCommandRequest = (ViewerCommand, oneshot::Sender<Result<(), TonicStatusError>>);
+
+#[derive(Debug, thiserror::Error)]
+pub enum ViewerCommandError {
+    #[error("the message proxy client has shut down, or never managed to connect")]
+    Disconnected,
+
+    /// E.g. because no viewer is connected to the message proxy.
+    #[error(transparent)]
+    Rejected(#[from] TonicStatusError),
+}
+
+type ProxyClient = MessageProxyClient<InterceptedService<Channel, AuthDecorator>>;
+
 #[derive(Clone)]
 pub struct Options {
     compression: Compression,
+    auth_token: Option<Jwt>,
+    tls_ca_certificate: Option<Vec<u8>>,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
             compression: Compression::LZ4,
+            auth_token: None,
+            tls_ca_certificate: None,
         }
     }
 }
 
+impl Options {
+    /// Authenticate with the message proxy using this token.
+    #[inline]
+    pub fn with_auth_token(mut self, token: Jwt) -> Self {
+        self.auth_token = Some(token);
+        self
+    }
+
+    /// Trust this PEM encoded certificate authority, in addition to the system's.
+    ///
+    /// Only used for `https://` urls, e.g. when the message proxy uses a self-signed certificate.
+    #[inline]
+    pub fn with_tls_ca_certificate(mut self, pem: Vec<u8>) -> Self {
+        self.tls_ca_certificate = Some(pem);
+        self
+    }
+}
+
 pub struct Client {
     thread: Option<JoinHandle<()>>,
     cmd_tx: UnboundedSender<Cmd>,
     shutdown_tx: Sender<()>,
+    viewer_event_subscribe_tx: UnboundedSender<std::sync::mpsc::Sender<ViewerEvent>>,
+    viewer_command_tx: UnboundedSender<ViewerCommandRequest>,
 }
 
 impl Client {
-    #[expect(clippy::needless_pass_by_value)]
     pub fn new(url: impl Into<String>, options: Options) -> Self {
         let url: String = url.into();
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
+        let (viewer_event_subscribe_tx, viewer_event_subscribe_rx) = mpsc::unbounded_channel();
+        let (viewer_command_tx, viewer_command_rx) = mpsc::unbounded_channel();
 
         let thread = thread::Builder::new()
             .name("message_proxy_client".to_owned())
@@ -56,7 +103,9 @@ impl Client {
                         url,
                         cmd_rx,
                         shutdown_rx,
-                        options.compression,
+                        viewer_event_subscribe_rx,
+                        viewer_command_rx,
+                        options,
                     ));
             })
             .expect("Failed to spawn message proxy client thread");
@@ -65,6 +114,8 @@ impl Client {
             thread: Some(thread),
             cmd_tx,
             shutdown_tx,
+            viewer_event_subscribe_tx,
+            viewer_command_tx,
         }
     }
 
@@ -72,6 +123,31 @@ impl Client {
         self.cmd_tx.send(Cmd::LogMsg(msg)).ok();
     }
 
+    /// Subscribe to events published by viewers connected to the same message proxy,
+    /// e.g. when the user selects an entity or moves the time cursor.
+    ///
+    /// Viewer events are not buffered by the message proxy: only events published
+    /// after subscribing are received.
+    ///
+    /// The returned channel is disconnected once the client shuts down, or if the
+    /// message proxy does not support viewer events.
+    pub fn viewer_events(&self) -> std::sync::mpsc::Receiver<ViewerEvent> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        if self.viewer_event_subscribe_tx.send(tx).is_err() {
+            re_log::debug!("Failed to subscribe to viewer events: already shut down.");
+        }
+        rx
+    }
+
+    /// Remote control the viewers connected to the same message proxy.
+    ///
+    /// The returned handle can be cloned, and keeps working for as long as this client is alive.
+    pub fn viewer_remote(&self) -> ViewerRemote {
+        ViewerRemote {
+            command_tx: self.viewer_command_tx.clone(),
+        }
+    }
+
     pub fn flush(&self) {
         let (tx, rx) = oneshot::channel();
         if self.cmd_tx.send(Cmd::Flush(tx)).is_err() {
@@ -90,6 +166,54 @@ impl Client {
     }
 }
 
+/// Sends [`ViewerCommand`]s to the viewers connected to a message proxy.
+///
+/// Created with [`Client::viewer_remote`].
+#[derive(Clone)]
+pub struct ViewerRemote {
+    command_tx: UnboundedSender<ViewerCommandRequest>,
+}
+
+impl ViewerRemote {
+    /// Blocks until the message proxy has forwarded the command to the viewers.
+    /// This fails if no viewer is connected.
+    ///
+    /// Commands do not wait for previously sent log messages to arrive.
+    /// Flush the client first if a command refers to data that was just logged.
+    ///
+    /// ## Panics
+    /// If called from within an async runtime, use [`Self::send_async`] there instead.
+    pub fn send(&self, command: ViewerCommand) -> Result<(), ViewerCommandError> {
+        let rx = self.enqueue(command)?;
+
+        match rx.blocking_recv() {
+            Ok(result) => result.map_err(Into::into),
+            Err(_) => Err(ViewerCommandError::Disconnected),
+        }
+    }
+
+    /// Like [`Self::send`], but waits for the message proxy without blocking the async runtime.
+    pub async fn send_async(&self, command: ViewerCommand) -> Result<(), ViewerCommandError> {
+        let rx = self.enqueue(command)?;
+
+        match rx.await {
+            Ok(result) => result.map_err(Into::into),
+            Err(_) => Err(ViewerCommandError::Disconnected),
+        }
+    }
+
+    fn enqueue(
+        &self,
+        command: ViewerCommand,
+    ) -> Result<oneshot::Receiver<Result<(), TonicStatusError>>, ViewerCommandError> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx
+            .send((command, tx))
+            .map_err(|_err| ViewerCommandError::Disconnected)?;
+        Ok(rx)
+    }
+}
+
 impl Drop for Client {
     fn drop(&mut self) {
         re_log::debug!("Shutting down message proxy client");
@@ -107,23 +231,27 @@ async fn message_proxy_client(
     url: String,
     mut cmd_rx: UnboundedReceiver<Cmd>,
     mut shutdown_rx: Receiver<()>,
-    compression: Compression,
+    viewer_event_subscribe_rx: UnboundedReceiver<std::sync::mpsc::Sender<ViewerEvent>>,
+    viewer_command_rx: UnboundedReceiver<ViewerCommandRequest>,
+    options: Options,
 ) {
-    let endpoint = match Endpoint::from_shared(url) {
-        Ok(endpoint) => endpoint,
-        Err(err) => {
-            re_log::error!("Failed to connect to message proxy server: {err}");
-            return;
-        }
-    };
-    let channel = match endpoint.connect().await {
+    let channel = match connect(url, &options).await {
         Ok(channel) => channel,
         Err(err) => {
             re_log::error!("Failed to connect to message proxy server: {err}");
             return;
         }
     };
-    let mut client = MessageProxyClient::new(channel);
+    let mut client =
+        MessageProxyClient::with_interceptor(channel, AuthDecorator::new(options.auth_token));
+    let compression = options.compression;
+
+    // Runs for as long as the runtime does, i.e. until this function returns.
+    tokio::spawn(forward_viewer_events(
+        client.clone(),
+        viewer_event_subscribe_rx,
+    ));
+    tokio::spawn(forward_viewer_commands(client.clone(), viewer_command_rx));
 
     let stream = async_stream::stream! {
         loop {
@@ -171,3 +299,90 @@ async fn message_proxy_client(
         re_log::error!("Write messages call failed: {err}");
     };
 }
+
+async fn connect(url: String, options: &Options) -> Result<Channel, tonic::transport::Error> {
+    let is_tls = url.starts_with("https://");
+    let mut endpoint = Endpoint::from_shared(url)?;
+
+    if is_tls {
+        let mut tls_config = ClientTlsConfig::new().with_native_roots();
+        if let Some(pem) = &options.tls_ca_certificate {
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(pem));
+        }
+        endpoint = endpoint.tls_config(tls_config)?;
+    }
+
+    endpoint.connect().await
+}
+
+async fn forward_viewer_events(
+    mut client: ProxyClient,
+    mut subscribe_rx: UnboundedReceiver<std::sync::mpsc::Sender<ViewerEvent>>,
+) {
+    // Don't bother the server until someone is actually interested in viewer events.
+    let Some(first_subscriber) = subscribe_rx.recv().await else {
+        return;
+    };
+    let mut subscribers = vec![first_subscriber];
+
+    let mut stream = match client.read_viewer_events(Empty {}).await {
+        Ok(response) => response.into_inner(),
+        Err(err) => {
+            re_log::warn!("Failed to subscribe to viewer events: {err}");
+            return;
+        }
+    };
+
+    loop {
+        tokio::select! {
+            subscriber = subscribe_rx.recv() => {
+                match subscriber {
+                    Some(subscriber) => subscribers.push(subscriber),
+                    None => break,
+                }
+            }
+
+            event = stream.message() => {
+                match event {
+                    Ok(Some(event)) => {
+                        let event: ViewerEvent = match event.try_into() {
+                            Ok(event) => event,
+                            Err(err) => {
+                                re_log::warn!("Dropping invalid viewer event: {err}");
+                                continue;
+                            }
+                        };
+
+                        subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+                    }
+
+                    Ok(None) => {
+                        re_log::debug!("Viewer event stream closed");
+                        break;
+                    }
+
+                    Err(err) => {
+                        re_log::debug!("Error while receiving viewer events: {err}");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn forward_viewer_commands(
+    mut client: ProxyClient,
+    mut command_rx: UnboundedReceiver<ViewerCommandRequest>,
+) {
+    while let Some((command, response_tx)) = command_rx.recv().await {
+        let result = client
+            .write_viewer_command(ViewerCommandProto::from(command))
+            .await
+            .map(|_| ())
+            .map_err(TonicStatusError);
+
+        // The caller may have given up waiting, which is fine.
+        response_tx.send(result).ok();
+    }
+}
@@ -1,13 +1,21 @@
 //! Server implementation of an in-memory Storage Node.
 
 use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::path::Path;
 use std::pin::Pin;
 
+use anyhow::Context as _;
+
+use re_auth::{server::authorize, Access, Resource};
 use re_byte_size::SizeBytes;
 use re_memory::MemoryLimit;
 use re_protos::{
     log_msg::v0::LogMsg as LogMsgProto,
-    sdk_comms::v0::{message_proxy_server, Empty},
+    sdk_comms::v0::{
+        message_proxy_server, Empty, ViewerCommand as ViewerCommandProto,
+        ViewerEvent as ViewerEventProto,
+    },
 };
 use tokio::sync::broadcast;
 use tokio::sync::mpsc;
@@ -15,6 +23,110 @@ use tokio::sync::oneshot;
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_stream::Stream;
 use tokio_stream::StreamExt as _;
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Identity, Server, ServerTlsConfig};
+
+/// The default port of the message proxy.
+pub const DEFAULT_SERVER_PORT: u16 = 1852;
+
+/// How [`serve`] hosts the [`MessageProxy`].
+pub struct ServerOptions {
+    /// How much memory the proxy may use to buffer messages for late-arriving clients.
+    pub memory_limit: MemoryLimit,
+
+    /// Only accept clients that present a token accepted by this authenticator.
+    pub authenticator: Option<re_auth::server::Authenticator>,
+
+    /// Serve over TLS.
+    pub tls_config: Option<ServerTlsConfig>,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self {
+            memory_limit: MemoryLimit::UNLIMITED,
+            authenticator: None,
+            tls_config: None,
+        }
+    }
+}
+
+impl ServerOptions {
+    /// Only accept clients that present a token signed with this base64 encoded secret.
+    pub fn with_auth_secret(mut self, secret: &str) -> anyhow::Result<Self> {
+        let provider = re_auth::RedapProvider::from_base64(secret).context("invalid auth secret")?;
+        self.authenticator = Some(re_auth::server::Authenticator::new(provider).reject_anonymous());
+        Ok(self)
+    }
+
+    /// Serve over TLS, using a PEM encoded certificate (chain) and private key.
+    pub fn with_tls_pem_files(mut self, cert_path: &Path, key_path: &Path) -> anyhow::Result<Self> {
+        let cert = std::fs::read(cert_path)
+            .with_context(|| format!("failed to read TLS certificate {cert_path:?}"))?;
+        let key = std::fs::read(key_path)
+            .with_context(|| format!("failed to read TLS key {key_path:?}"))?;
+        self.tls_config = Some(ServerTlsConfig::new().identity(Identity::from_pem(cert, key)));
+        Ok(self)
+    }
+}
+
+/// Hosts a [`MessageProxy`] on `addr`, for both native and web (`grpc-web`) clients.
+///
+/// Only returns if the server fails.
+pub async fn serve(addr: SocketAddr, options: ServerOptions) -> anyhow::Result<()> {
+    let ServerOptions {
+        memory_limit,
+        authenticator,
+        tls_config,
+    } = options;
+
+    let tcp_listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("failed to bind listener on {addr}"))?;
+    let incoming =
+        TcpIncoming::from_listener(tcp_listener, true, None).expect("failed to init listener");
+
+    re_log::info!(
+        "Listening for gRPC connections on {addr}{}{}",
+        if tls_config.is_some() { " (TLS)" } else { "" },
+        if authenticator.is_some() {
+            " (auth required)"
+        } else {
+            ""
+        },
+    );
+
+    use tower_http::cors::{Any, CorsLayer};
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods(Any)
+        .allow_headers(Any);
+
+    let grpc_web = tonic_web::GrpcWebLayer::new();
+
+    let routes = {
+        let mut routes_builder = tonic::service::Routes::builder();
+        routes_builder.add_service(MessageProxy::new(memory_limit).into_service(authenticator));
+        routes_builder.routes()
+    };
+
+    let mut server = Server::builder();
+    if let Some(tls_config) = tls_config {
+        server = server.tls_config(tls_config)?;
+    }
+
+    server
+        .accept_http1(true) // Support `grpc-web` clients
+        .layer(cors) // Allow CORS requests from web clients
+        .layer(grpc_web) // Support `grpc-web` clients
+        .add_routes(routes)
+        .serve_with_incoming(incoming)
+        .await?;
+
+    Ok(())
+}
 
 enum Event {
     /// New client connected, requesting full history and subscribing to new messages.
@@ -171,6 +283,16 @@ fn message_size(msg: &LogMsgProto) -> u64 {
 pub struct MessageProxy {
     _queue_task_handle: tokio::task::JoinHandle<()>,
     event_tx: mpsc::Sender<Event>,
+
+    /// Viewer events are broadcast to all subscribed clients.
+    ///
+    /// They are never stored, so they bypass the event loop entirely.
+    viewer_event_tx: broadcast::Sender<ViewerEventProto>,
+
+    /// Viewer commands are broadcast to all subscribed viewers.
+    ///
+    /// Just like viewer events, they are never stored.
+    viewer_command_tx: broadcast::Sender<ViewerCommandProto>,
 }
 
 impl MessageProxy {
@@ -188,9 +310,39 @@ impl MessageProxy {
         Self {
             _queue_task_handle: task_handle,
             event_tx,
+            // Channel capacity is completely arbitrary.
+            // Viewer events are produced by user interactions, so they are rare compared to log messages.
+            viewer_event_tx: broadcast::channel(256).0,
+            viewer_command_tx: broadcast::channel(256).0,
         }
     }
 
+    /// Wraps the proxy in a gRPC service.
+    ///
+    /// If an `authenticator` is given, every request needs to carry a valid auth token
+    /// that grants read access to all recordings.
+    /// Sending log messages, viewer events and viewer commands additionally requires write access.
+    pub fn into_service(
+        self,
+        authenticator: Option<re_auth::server::Authenticator>,
+    ) -> InterceptedService<
+        message_proxy_server::MessageProxyServer<Self>,
+        impl Interceptor + Clone + Send + Sync + 'static,
+    > {
+        let mut authenticator = authenticator
+            .map(|authenticator| authenticator.require(Access::Read, Resource::all_recordings()));
+        message_proxy_server::MessageProxyServer::with_interceptor(self, move |mut req| {
+            match &mut authenticator {
+                Some(authenticator) => authenticator.call(req),
+                None => {
+                    req.extensions_mut()
+                        .insert(re_auth::server::UserContext::unrestricted());
+                    Ok(req)
+                }
+            }
+        })
+    }
+
     async fn push(&self, msg: LogMsgProto) {
         self.event_tx.send(Event::Message(msg)).await.ok();
     }
@@ -219,16 +371,55 @@ impl MessageProxy {
 
         Box::pin(history.merge(channel))
     }
+
+    fn push_viewer_event(&self, event: ViewerEventProto) {
+        // This only fails if nobody is subscribed, in which case the event is simply dropped.
+        self.viewer_event_tx.send(event).ok();
+    }
+
+    fn new_viewer_event_stream(&self) -> ViewerEventStream {
+        let channel = BroadcastStream::new(self.viewer_event_tx.subscribe()).map(|result| {
+            result.map_err(|err| {
+                re_log::error!("Error reading viewer event from broadcast channel: {err}");
+                tonic::Status::internal("internal channel error")
+            })
+        });
+
+        Box::pin(channel)
+    }
+
+    /// Returns the number of viewers the command was sent to.
+    fn push_viewer_command(&self, command: ViewerCommandProto) -> usize {
+        // This only fails if no viewer is subscribed.
+        self.viewer_command_tx.send(command).unwrap_or(0)
+    }
+
+    fn new_viewer_command_stream(&self) -> ViewerCommandStream {
+        let channel = BroadcastStream::new(self.viewer_command_tx.subscribe()).map(|result| {
+            result.map_err(|err| {
+                re_log::error!("Error reading viewer command from broadcast channel: {err}");
+                tonic::Status::internal("internal channel error")
+            })
+        });
+
+        Box::pin(channel)
+    }
 }
 
 type LogMsgStream = Pin<Box<dyn Stream<Item = tonic::Result<LogMsgProto>> + Send>>;
 
+type ViewerEventStream = Pin<Box<dyn Stream<Item = tonic::Result<ViewerEventProto>> + Send>>;
+
+type ViewerCommandStream = Pin<Box<dyn Stream<Item = tonic::Result<ViewerCommandProto>> + Send>>;
+
 #[tonic::async_trait]
 impl message_proxy_server::MessageProxy for MessageProxy {
     async fn write_messages(
         &self,
         request: tonic::Request<tonic::Streaming<LogMsgProto>>,
     ) -> tonic::Result<tonic::Response<Empty>> {
+        authorize(&request, Access::Write, &Resource::all_recordings())?;
+
         let mut stream = request.into_inner();
         loop {
             match stream.message().await {
@@ -257,22 +448,66 @@ impl message_proxy_server::MessageProxy for MessageProxy {
     ) -> tonic::Result<tonic::Response<Self::ReadMessagesStream>> {
         Ok(tonic::Response::new(self.new_client_stream().await))
     }
+
+    async fn write_viewer_event(
+        &self,
+        request: tonic::Request<ViewerEventProto>,
+    ) -> tonic::Result<tonic::Response<Empty>> {
+        authorize(&request, Access::Write, &Resource::all_recordings())?;
+
+        self.push_viewer_event(request.into_inner());
+        Ok(tonic::Response::new(Empty {}))
+    }
+
+    type ReadViewerEventsStream = ViewerEventStream;
+
+    async fn read_viewer_events(
+        &self,
+        _: tonic::Request<Empty>,
+    ) -> tonic::Result<tonic::Response<Self::ReadViewerEventsStream>> {
+        Ok(tonic::Response::new(self.new_viewer_event_stream()))
+    }
+
+    async fn write_viewer_command(
+        &self,
+        request: tonic::Request<ViewerCommandProto>,
+    ) -> tonic::Result<tonic::Response<Empty>> {
+        authorize(&request, Access::Write, &Resource::all_recordings())?;
+
+        // Unlike viewer events, nobody listening is an error worth reporting:
+        // whoever sent the command expects a viewer to act on it.
+        if self.push_viewer_command(request.into_inner()) == 0 {
+            return Err(tonic::Status::unavailable(
+                "no viewer is connected to receive the command",
+            ));
+        }
+        Ok(tonic::Response::new(Empty {}))
+    }
+
+    type ReadViewerCommandsStream = ViewerCommandStream;
+
+    async fn read_viewer_commands(
+        &self,
+        _: tonic::Request<Empty>,
+    ) -> tonic::Result<tonic::Response<Self::ReadViewerCommandsStream>> {
+        Ok(tonic::Response::new(self.new_viewer_command_stream()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use re_auth::{client::AuthDecorator, server::Authenticator, Jwt, Permission, RedapProvider};
     use re_build_info::CrateVersion;
     use re_chunk::RowId;
     use re_log_encoding::protobuf_conversions::{log_msg_from_proto, log_msg_to_proto};
     use re_log_encoding::Compression;
     use re_log_types::{
         ApplicationId, LogMsg, SetStoreInfo, StoreId, StoreInfo, StoreKind, StoreSource, Time,
+        ViewerCommand, ViewerEvent, ViewerEventItem, ViewerEventKind, ViewerPlayState,
     };
-    use re_protos::sdk_comms::v0::{
-        message_proxy_client::MessageProxyClient, message_proxy_server::MessageProxyServer,
-    };
+    use re_protos::sdk_comms::v0::message_proxy_client::MessageProxyClient;
     use std::net::SocketAddr;
     use std::sync::Arc;
     use std::time::Duration;
@@ -363,6 +598,18 @@ mod tests {
     }
 
     async fn setup_with_memory_limit(memory_limit: MemoryLimit) -> (Completion, SocketAddr) {
+        setup_with_options(memory_limit, None).await
+    }
+
+    async fn setup_with_auth(provider: RedapProvider) -> (Completion, SocketAddr) {
+        let authenticator = Authenticator::new(provider).reject_anonymous();
+        setup_with_options(MemoryLimit::UNLIMITED, Some(authenticator)).await
+    }
+
+    async fn setup_with_options(
+        memory_limit: MemoryLimit,
+        authenticator: Option<Authenticator>,
+    ) -> (Completion, SocketAddr) {
         let completion = Completion::new();
 
         let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -372,9 +619,7 @@ mod tests {
             let completion = completion.clone();
             async move {
                 tonic::transport::Server::builder()
-                    .add_service(MessageProxyServer::new(super::MessageProxy::new(
-                        memory_limit,
-                    )))
+                    .add_service(super::MessageProxy::new(memory_limit).into_service(authenticator))
                     .serve_with_incoming_shutdown(
                         TcpIncoming::from_listener(tcp_listener, true, None).unwrap(),
                         completion.wait(),
@@ -397,6 +642,25 @@ mod tests {
         )
     }
 
+    async fn make_client_with_token(
+        addr: SocketAddr,
+        token: Option<Jwt>,
+    ) -> MessageProxyClient<tonic::service::interceptor::InterceptedService<Channel, AuthDecorator>>
+    {
+        MessageProxyClient::with_interceptor(
+            Endpoint::from_shared(format!("http://{addr}"))
+                .unwrap()
+                .connect()
+                .await
+                .unwrap(),
+            AuthDecorator::new(token),
+        )
+    }
+
+    fn test_provider() -> RedapProvider {
+        RedapProvider::from_base64("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=").unwrap()
+    }
+
     async fn read_log_stream(
         log_stream: &mut tonic::Response<tonic::Streaming<LogMsgProto>>,
         n: usize,
@@ -547,6 +811,256 @@ mod tests {
         completion.finish();
     }
 
+    #[tokio::test]
+    async fn viewer_events_pubsub() {
+        let (completion, addr) = setup().await;
+        let mut viewer = make_client(addr).await;
+        let mut subscribers = vec![make_client(addr).await, make_client(addr).await];
+
+        let store_id = StoreId::random(StoreKind::Recording);
+        let events = vec![
+            ViewerEvent {
+                store_id: store_id.clone(),
+                kind: ViewerEventKind::SelectionChange {
+                    items: vec![ViewerEventItem::from(re_log_types::EntityPath::from(
+                        "test_entity",
+                    ))],
+                },
+            },
+            ViewerEvent {
+                store_id,
+                kind: ViewerEventKind::HoverChange { items: vec![] },
+            },
+        ];
+
+        // Subscribe before anything is published, since viewer events are not buffered:
+        let mut event_streams = vec![];
+        for subscriber in &mut subscribers {
+            event_streams.push(subscriber.read_viewer_events(Empty {}).await.unwrap());
+        }
+
+        for event in &events {
+            viewer
+                .write_viewer_event(ViewerEventProto::from(event.clone()))
+                .await
+                .unwrap();
+        }
+
+        // Each subscriber should've received them, in order:
+        for event_stream in &mut event_streams {
+            let mut actual = vec![];
+            for _ in 0..events.len() {
+                let event = event_stream.get_mut().next().await.unwrap().unwrap();
+                actual.push(ViewerEvent::try_from(event).unwrap());
+            }
+            assert_eq!(events, actual);
+        }
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn viewer_events_are_not_buffered() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+
+        client
+            .write_viewer_event(ViewerEventProto::from(ViewerEvent {
+                store_id: StoreId::random(StoreKind::Recording),
+                kind: ViewerEventKind::HoverChange { items: vec![] },
+            }))
+            .await
+            .unwrap();
+
+        // Subscribing after the fact should not yield the event published above.
+        let mut event_stream = client.read_viewer_events(Empty {}).await.unwrap();
+        let timeout_stream = event_stream.get_mut().timeout(Duration::from_millis(100));
+        tokio::pin!(timeout_stream);
+        assert!(timeout_stream.try_next().await.is_err());
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn viewer_commands_pubsub() {
+        let (completion, addr) = setup().await;
+        let mut sdk = make_client(addr).await;
+        let mut viewers = vec![make_client(addr).await, make_client(addr).await];
+
+        let commands = vec![
+            ViewerCommand::SetTime {
+                timeline: "frame_nr".into(),
+                time: Some(re_log_types::TimeInt::new_temporal(42)),
+            },
+            ViewerCommand::SetPlayState {
+                play_state: ViewerPlayState::Playing,
+            },
+        ];
+
+        let mut command_streams = vec![];
+        for viewer in &mut viewers {
+            command_streams.push(viewer.read_viewer_commands(Empty {}).await.unwrap());
+        }
+
+        for command in &commands {
+            sdk.write_viewer_command(ViewerCommandProto::from(command.clone()))
+                .await
+                .unwrap();
+        }
+
+        // Each viewer should've received them, in order:
+        for command_stream in &mut command_streams {
+            let mut actual = vec![];
+            for _ in 0..commands.len() {
+                let command = command_stream.get_mut().next().await.unwrap().unwrap();
+                actual.push(ViewerCommand::try_from(command).unwrap());
+            }
+            assert_eq!(commands, actual);
+        }
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn viewer_command_without_viewer_fails() {
+        let (completion, addr) = setup().await;
+        let mut client = make_client(addr).await;
+
+        let status = client
+            .write_viewer_command(ViewerCommandProto::from(ViewerCommand::Screenshot {
+                path: None,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn auth_accepts_valid_token() {
+        let provider = test_provider();
+        let (completion, addr) = setup_with_auth(provider.clone()).await;
+        let token = provider
+            .token(
+                Duration::from_secs(60),
+                "test",
+                "test_user",
+                [Permission::WRITE_ALL],
+            )
+            .unwrap();
+        let mut client = make_client_with_token(addr, Some(token)).await;
+        let messages = fake_log_stream(3);
+
+        let mut log_stream = client.read_messages(Empty {}).await.unwrap();
+
+        client
+            .write_messages(tokio_stream::iter(
+                messages
+                    .clone()
+                    .into_iter()
+                    .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap()),
+            ))
+            .await
+            .unwrap();
+
+        let actual = read_log_stream(&mut log_stream, messages.len()).await;
+        assert_eq!(messages, actual);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn auth_rejects_missing_token() {
+        let (completion, addr) = setup_with_auth(test_provider()).await;
+        let mut client = make_client_with_token(addr, None).await;
+
+        let status = client.read_messages(Empty {}).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn auth_rejects_token_from_other_provider() {
+        let (completion, addr) = setup_with_auth(test_provider()).await;
+        let other_provider =
+            RedapProvider::from_base64("HxwdHhscGRoXGBUWExQREg8QDQ4LDAkKBwgFBgMEAQI=").unwrap();
+        let token = other_provider
+            .token(
+                Duration::from_secs(60),
+                "test",
+                "test_user",
+                [Permission::WRITE_ALL],
+            )
+            .unwrap();
+        let mut client = make_client_with_token(addr, Some(token)).await;
+
+        let status = client.read_messages(Empty {}).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn auth_read_only_token_cannot_write() {
+        let provider = test_provider();
+        let (completion, addr) = setup_with_auth(provider.clone()).await;
+        let token = provider
+            .token(
+                Duration::from_secs(60),
+                "test",
+                "test_user",
+                [Permission::READ_ALL],
+            )
+            .unwrap();
+        let mut client = make_client_with_token(addr, Some(token)).await;
+
+        client.read_messages(Empty {}).await.unwrap();
+
+        let status = client
+            .write_messages(tokio_stream::iter(
+                fake_log_stream(1)
+                    .into_iter()
+                    .map(|msg| log_msg_to_proto(msg, Compression::Off).unwrap()),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        let status = client
+            .write_viewer_event(ViewerEventProto::from(ViewerEvent {
+                store_id: StoreId::random(StoreKind::Recording),
+                kind: ViewerEventKind::HoverChange { items: vec![] },
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn auth_rejects_token_scoped_to_single_recording() {
+        let provider = test_provider();
+        let (completion, addr) = setup_with_auth(provider.clone()).await;
+        let token = provider
+            .token(
+                Duration::from_secs(60),
+                "test",
+                "test_user",
+                ["write:recording/some_recording".parse().unwrap()],
+            )
+            .unwrap();
+        let mut client = make_client_with_token(addr, Some(token)).await;
+
+        // The proxy relays all recordings, so it requires access to all of them.
+        let status = client.read_messages(Empty {}).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+        completion.finish();
+    }
+
     #[tokio::test]
     async fn memory_limit_drops_messages() {
         // Use an absurdly low memory limit to force all messages to be dropped immediately from history
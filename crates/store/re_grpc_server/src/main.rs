@@ -1,51 +1,51 @@
 use std::net::IpAddr;
 use std::net::Ipv4Addr;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
-use re_grpc_server::MessageProxy;
-use re_memory::MemoryLimit;
-use re_protos::sdk_comms::v0::message_proxy_server::MessageProxyServer;
-use tokio::net::TcpListener;
-use tonic::transport::server::TcpIncoming;
-use tonic::transport::Server;
+use clap::Parser as _;
+use re_grpc_server::{ServerOptions, DEFAULT_SERVER_PORT};
 
-const DEFAULT_GRPC_PORT: u16 = 1852;
 const DEFAULT_GRPC_ADDR: SocketAddr =
-    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), DEFAULT_GRPC_PORT);
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), DEFAULT_SERVER_PORT);
+
+/// Relays log messages from SDKs to viewers over gRPC.
+#[derive(Debug, clap::Parser)]
+struct Args {
+    /// What address to listen on.
+    #[clap(long, default_value_t = DEFAULT_GRPC_ADDR)]
+    addr: SocketAddr,
+
+    /// Only accept clients that present a token signed with this secret.
+    ///
+    /// The secret is a base64 encoded symmetric key.
+    /// Clients pass their token with `--auth-token`, or as a `?token=` query parameter
+    /// of the `temp://` url in the viewer.
+    #[clap(long)]
+    auth_secret: Option<String>,
+
+    /// Serve over TLS, using this PEM encoded certificate (chain).
+    #[clap(long, requires = "tls_key")]
+    tls_cert: Option<PathBuf>,
+
+    /// The PEM encoded private key for `--tls-cert`.
+    #[clap(long, requires = "tls_cert")]
+    tls_key: Option<PathBuf>,
+}
 
 #[tokio::main(flavor = "current_thread")]
-async fn main() -> Result<(), tonic::transport::Error> {
+async fn main() -> anyhow::Result<()> {
     re_log::setup_logging();
 
-    let tcp_listener = TcpListener::bind(DEFAULT_GRPC_ADDR)
-        .await
-        .unwrap_or_else(|err| panic!("failed to bind listener on {DEFAULT_GRPC_ADDR}: {err}"));
-    let incoming =
-        TcpIncoming::from_listener(tcp_listener, true, None).expect("failed to init listener");
-
-    re_log::info!("Listening for gRPC connections on {DEFAULT_GRPC_ADDR}");
-
-    use tower_http::cors::{Any, CorsLayer};
-    let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
-
-    let grpc_web = tonic_web::GrpcWebLayer::new();
-
-    let routes = {
-        let mut routes_builder = tonic::service::Routes::builder();
-        routes_builder.add_service(MessageProxyServer::new(MessageProxy::new(
-            MemoryLimit::UNLIMITED,
-        )));
-        routes_builder.routes()
-    };
-
-    Server::builder()
-        .accept_http1(true) // Support `grpc-web` clients
-        .layer(cors) // Allow CORS requests from web clients
-        .layer(grpc_web) // Support `grpc-web` clients
-        .add_routes(routes)
-        .serve_with_incoming(incoming)
-        .await
+    let args = Args::parse();
+
+    let mut options = ServerOptions::default();
+    if let Some(secret) = &args.auth_secret {
+        options = options.with_auth_secret(secret)?;
+    }
+    if let (Some(cert_path), Some(key_path)) = (&args.tls_cert, &args.tls_key) {
+        options = options.with_tls_pem_files(cert_path, key_path)?;
+    }
+
+    re_grpc_server::serve(args.addr, options).await
 }
@@ -31,6 +31,8 @@ mod resolved_time_range;
 mod time;
 mod time_real;
 mod vec_deque_ext;
+mod viewer_command;
+mod viewer_event;
 
 use std::sync::Arc;
 
@@ -47,6 +49,8 @@ pub use self::time_point::{
 };
 pub use self::time_real::TimeReal;
 pub use self::vec_deque_ext::{VecDequeInsertionExt, VecDequeRemovalExt, VecDequeSortingExt};
+pub use self::viewer_command::{ViewerCommand, ViewerPlayState};
+pub use self::viewer_event::{ViewerEvent, ViewerEventItem, ViewerEventKind};
 
 pub mod external {
     pub use arrow;
@@ -17,9 +17,12 @@ pub enum EntityPathFilterError {
 
 /// A set of substitutions for entity paths.
 ///
+/// A variable `name` is referred to as `$name` or `${name}`.
+/// Variable names consist of ASCII letters, digits and underscores.
+///
 /// Important: the same substitutions must be used in every place we resolve [`EntityPathFilter`] to
 /// [`ResolvedEntityPathFilter`].
-#[derive(Debug)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct EntityPathSubs(HashMap<String, String>);
 
 impl EntityPathSubs {
@@ -32,6 +35,94 @@ impl EntityPathSubs {
     pub fn empty() -> Self {
         Self(HashMap::default())
     }
+
+    /// Add a variable, replacing any previous value of it.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// The value of a variable, if set.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Replaces all known variables in `expression` with their values.
+    ///
+    /// Unknown variables are left as is.
+    /// Unlike when resolving an [`EntityPathFilter`], a variable name always extends as far as
+    /// possible: `$origin_x` refers to a variable called `origin_x`, not to `$origin`.
+    pub fn substitute(&self, expression: &str) -> String {
+        let mut result = String::with_capacity(expression.len());
+        let mut rest = expression;
+        while let Some((before, name, after)) = next_variable(rest) {
+            result.push_str(before);
+            if let Some(value) = self.get(name) {
+                result.push_str(value);
+            } else {
+                result.push_str(&rest[before.len()..rest.len() - after.len()]);
+            }
+            rest = after;
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// The names of all variables used in `expression`, in order of appearance.
+    pub fn variable_names(expression: &str) -> impl Iterator<Item = &str> {
+        let mut rest = expression;
+        std::iter::from_fn(move || {
+            let (_, name, after) = next_variable(rest)?;
+            rest = after;
+            Some(name)
+        })
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for EntityPathSubs {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+/// Finds the next `$name` or `${name}` in `expression`.
+///
+/// Returns the text before the variable, the variable name, and the text after the variable.
+fn next_variable(expression: &str) -> Option<(&str, &str, &str)> {
+    fn is_name_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_'
+    }
+
+    let mut search_start = 0;
+    loop {
+        let dollar = search_start + expression[search_start..].find('$')?;
+        let after_dollar = &expression[dollar + 1..];
+
+        if let Some(braced) = after_dollar.strip_prefix('{') {
+            if let Some(end) = braced.find('}') {
+                let name = &braced[..end];
+                if !name.is_empty() && name.chars().all(is_name_char) {
+                    return Some((&expression[..dollar], name, &braced[end + 1..]));
+                }
+            }
+        } else {
+            let end = after_dollar
+                .find(|c| !is_name_char(c))
+                .unwrap_or(after_dollar.len());
+            if end > 0 {
+                return Some((
+                    &expression[..dollar],
+                    &after_dollar[..end],
+                    &after_dollar[end..],
+                ));
+            }
+        }
+
+        search_start = dollar + 1;
+    }
 }
 
 /// A way to filter a set of `EntityPath`s.
@@ -761,8 +852,16 @@ impl ResolvedEntityPathRule {
         // TODO(#5528): This is a very naive implementation of variable substitution.
         // unclear if we want to do this here, push this down into `EntityPath::parse`,
         // or even supported deferred evaluation on the `EntityPath` itself.
+        //
+        // Note that unlike `EntityPathSubs::substitute`, this also replaces variables that are
+        // followed by more name characters, e.g. `$origin_x` becomes `/world_x`.
+        // Longer names go first, so that `$robot_cam` isn't replaced as `$robot` followed by `_cam`.
         let mut expression_sub = rule.0.clone();
-        for (key, value) in &subst_env.0 {
+        for (key, value) in subst_env
+            .0
+            .iter()
+            .sorted_by_key(|(key, _)| std::cmp::Reverse(key.len()))
+        {
             expression_sub = expression_sub.replace(format!("${key}").as_str(), value);
             expression_sub = expression_sub.replace(format!("${{{key}}}").as_str(), value);
         }
@@ -1007,6 +1106,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_entity_path_subs_user_variables() {
+        let mut subst_env = EntityPathSubs::new_with_origin(&EntityPath::from("/world"));
+        subst_env.insert("robot", "/robots/arm_a");
+        subst_env.insert("robot_cam", "/robots/arm_a/wrist_cam");
+
+        assert_eq!(
+            subst_env.substitute("+ $robot/** - ${robot}/gripper + $robot_cam + $origin/$unknown"),
+            "+ /robots/arm_a/** - /robots/arm_a/gripper + /robots/arm_a/wrist_cam + /world/$unknown"
+        );
+        assert_eq!(subst_env.substitute("$ ${} $/x"), "$ ${} $/x");
+
+        assert_eq!(
+            EntityPathSubs::variable_names("+ $robot/** - ${camera}/image + $origin")
+                .collect::<Vec<_>>(),
+            vec!["robot", "camera", "origin"]
+        );
+
+        let filter =
+            EntityPathFilter::parse_forgiving("+ $robot/**\n- $robot/gripper\n+ $robot_cam")
+                .resolve_forgiving(&subst_env);
+        assert_eq!(
+            filter.most_specific_match(&EntityPath::from("/robots/arm_a/base")),
+            Some(RuleEffect::Include)
+        );
+        assert_eq!(
+            filter.most_specific_match(&EntityPath::from("/robots/arm_a/gripper")),
+            Some(RuleEffect::Exclude)
+        );
+        assert_eq!(
+            filter.most_specific_match(&EntityPath::from("/robots/arm_a/wrist_cam")),
+            Some(RuleEffect::Include)
+        );
+
+        // Filters keep replacing variables that are followed by more name characters.
+        let filter = EntityPathFilter::parse_forgiving("+ $origin_x").resolve_forgiving(&subst_env);
+        assert_eq!(
+            filter.most_specific_match(&EntityPath::from("/world_x")),
+            Some(RuleEffect::Include)
+        );
+    }
+
     #[test]
     fn test_entity_path_filter_subtree() {
         let filter = EntityPathFilter::parse_forgiving(
@@ -0,0 +1,67 @@
+use crate::{StoreId, TimeInt, TimelineName, ViewerEventItem};
+
+/// How the time cursor of a recording should move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerPlayState {
+    /// Time doesn't move.
+    Paused,
+
+    /// Time moves at the current playback speed.
+    Playing,
+
+    /// Time stays at the latest data, as it arrives.
+    Following,
+}
+
+/// A command sent to a running viewer, to steer it remotely.
+///
+/// These are e.g. used by test harnesses and demo scripts.
+/// Unless stated otherwise, commands apply to the recording that is currently active in the viewer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerCommand {
+    /// Switch to the given recording, if the viewer has it.
+    SetActiveRecording {
+        store_id: StoreId,
+    },
+
+    /// Switch to the given timeline, and optionally move the time cursor on it.
+    SetTime {
+        timeline: TimelineName,
+        time: Option<TimeInt>,
+    },
+
+    SetPlayState {
+        play_state: ViewerPlayState,
+    },
+
+    /// Replace the selection with the given entities and instances.
+    ///
+    /// An empty list clears the selection.
+    Select {
+        items: Vec<ViewerEventItem>,
+    },
+
+    /// Take a screenshot of the whole viewer.
+    ///
+    /// The screenshot is saved to `path` on the machine running the viewer,
+    /// or copied to the clipboard if no path is given.
+    /// The path must be relative, and is resolved in the working directory of the viewer:
+    /// viewers refuse to write anywhere else.
+    Screenshot {
+        path: Option<String>,
+    },
+
+    /// Open a recording from a file path or url, as if it was passed on the command line.
+    ///
+    /// Just like screenshots, file paths must be relative to the working directory of the viewer.
+    OpenRecording {
+        uri: String,
+    },
+
+    /// Close the given recording and free its memory.
+    CloseRecording {
+        store_id: StoreId,
+    },
+}
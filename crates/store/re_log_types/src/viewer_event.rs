@@ -0,0 +1,77 @@
+use crate::{EntityPath, Instance, StoreId, TimeInt, Timeline};
+
+/// An entity or a specific instance of an entity, as referred to by a [`ViewerEvent`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ViewerEventItem {
+    pub entity_path: EntityPath,
+
+    /// If this refers to a specific instance, which one?
+    ///
+    /// [`Instance::ALL`] is used when the whole entity is referred to.
+    pub instance: Instance,
+}
+
+impl From<EntityPath> for ViewerEventItem {
+    #[inline]
+    fn from(entity_path: EntityPath) -> Self {
+        Self {
+            entity_path,
+            instance: Instance::ALL,
+        }
+    }
+}
+
+impl std::fmt::Display for ViewerEventItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.instance.is_all() {
+            self.entity_path.fmt(f)
+        } else {
+            write!(f, "{}[{}]", self.entity_path, self.instance)
+        }
+    }
+}
+
+/// What happened in the viewer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerEventKind {
+    /// The user changed the selection.
+    ///
+    /// Contains all entities and instances that are selected now.
+    /// Empty if the selection was cleared, or if nothing entity-related is selected.
+    SelectionChange { items: Vec<ViewerEventItem> },
+
+    /// The user hovered something new.
+    ///
+    /// Empty if nothing entity-related is hovered anymore.
+    HoverChange { items: Vec<ViewerEventItem> },
+
+    /// The time cursor moved, the active timeline changed, or playback was started or stopped.
+    TimeUpdate {
+        timeline: Timeline,
+        time: TimeInt,
+        playing: bool,
+    },
+
+    /// The user pressed one of the custom buttons the viewer was started with.
+    ///
+    /// Contains the entities and instances that were selected at the time of the press.
+    ButtonPress {
+        button: String,
+        items: Vec<ViewerEventItem>,
+    },
+}
+
+/// An event published by a viewer about what the user is doing with a recording.
+///
+/// These flow in the opposite direction of a [`crate::LogMsg`]: from the viewer back to
+/// whoever is logging the data, so that tooling can react to what an operator clicks on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ViewerEvent {
+    /// The recording that was active in the viewer when the event happened.
+    pub store_id: StoreId,
+
+    pub kind: ViewerEventKind,
+}
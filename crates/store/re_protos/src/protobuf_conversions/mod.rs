@@ -1,3 +1,5 @@
 mod arrow;
 mod log_types;
 mod tuid;
+mod viewer_command;
+mod viewer_event;
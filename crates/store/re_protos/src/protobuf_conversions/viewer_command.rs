@@ -0,0 +1,182 @@
+use crate::missing_field;
+use crate::sdk_comms::v0::viewer_command::Command;
+use crate::TypeConversionError;
+
+impl From<re_log_types::ViewerPlayState> for crate::sdk_comms::v0::PlayState {
+    #[inline]
+    fn from(value: re_log_types::ViewerPlayState) -> Self {
+        match value {
+            re_log_types::ViewerPlayState::Paused => Self::Paused,
+            re_log_types::ViewerPlayState::Playing => Self::Playing,
+            re_log_types::ViewerPlayState::Following => Self::Following,
+        }
+    }
+}
+
+impl From<crate::sdk_comms::v0::PlayState> for re_log_types::ViewerPlayState {
+    #[inline]
+    fn from(value: crate::sdk_comms::v0::PlayState) -> Self {
+        match value {
+            crate::sdk_comms::v0::PlayState::Paused => Self::Paused,
+            crate::sdk_comms::v0::PlayState::Playing => Self::Playing,
+            crate::sdk_comms::v0::PlayState::Following => Self::Following,
+        }
+    }
+}
+
+impl From<re_log_types::ViewerCommand> for crate::sdk_comms::v0::ViewerCommand {
+    fn from(value: re_log_types::ViewerCommand) -> Self {
+        let command = match value {
+            re_log_types::ViewerCommand::SetActiveRecording { store_id } => {
+                Command::SetActiveRecording(crate::sdk_comms::v0::SetActiveRecording {
+                    store_id: Some(store_id.into()),
+                })
+            }
+            re_log_types::ViewerCommand::SetTime { timeline, time } => {
+                Command::SetTime(crate::sdk_comms::v0::SetTime {
+                    timeline: Some(crate::common::v0::Timeline {
+                        name: timeline.to_string(),
+                    }),
+                    time: time.map(Into::into),
+                })
+            }
+            re_log_types::ViewerCommand::SetPlayState { play_state } => {
+                let play_state: crate::sdk_comms::v0::PlayState = play_state.into();
+                Command::SetPlayState(crate::sdk_comms::v0::SetPlayState {
+                    play_state: play_state as i32,
+                })
+            }
+            re_log_types::ViewerCommand::Select { items } => {
+                Command::Select(crate::sdk_comms::v0::Select {
+                    items: items.into_iter().map(Into::into).collect(),
+                })
+            }
+            re_log_types::ViewerCommand::Screenshot { path } => {
+                Command::Screenshot(crate::sdk_comms::v0::Screenshot { path })
+            }
+            re_log_types::ViewerCommand::OpenRecording { uri } => {
+                Command::OpenRecording(crate::sdk_comms::v0::OpenRecording { uri })
+            }
+            re_log_types::ViewerCommand::CloseRecording { store_id } => {
+                Command::CloseRecording(crate::sdk_comms::v0::CloseRecording {
+                    store_id: Some(store_id.into()),
+                })
+            }
+        };
+
+        Self {
+            command: Some(command),
+        }
+    }
+}
+
+impl TryFrom<crate::sdk_comms::v0::ViewerCommand> for re_log_types::ViewerCommand {
+    type Error = TypeConversionError;
+
+    fn try_from(value: crate::sdk_comms::v0::ViewerCommand) -> Result<Self, Self::Error> {
+        let command = value.command.ok_or(missing_field!(
+            crate::sdk_comms::v0::ViewerCommand,
+            "command"
+        ))?;
+
+        Ok(match command {
+            Command::SetActiveRecording(set_active_recording) => Self::SetActiveRecording {
+                store_id: set_active_recording
+                    .store_id
+                    .ok_or(missing_field!(
+                        crate::sdk_comms::v0::SetActiveRecording,
+                        "store_id"
+                    ))?
+                    .into(),
+            },
+            Command::SetTime(set_time) => Self::SetTime {
+                timeline: set_time
+                    .timeline
+                    .ok_or(missing_field!(crate::sdk_comms::v0::SetTime, "timeline"))?
+                    .name
+                    .into(),
+                time: set_time.time.map(Into::into),
+            },
+            Command::SetPlayState(set_play_state) => Self::SetPlayState {
+                play_state: set_play_state.play_state().into(),
+            },
+            Command::Select(select) => Self::Select {
+                items: select
+                    .items
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+            },
+            Command::Screenshot(screenshot) => Self::Screenshot {
+                path: screenshot.path,
+            },
+            Command::OpenRecording(open_recording) => Self::OpenRecording {
+                uri: open_recording.uri,
+            },
+            Command::CloseRecording(close_recording) => Self::CloseRecording {
+                store_id: close_recording
+                    .store_id
+                    .ok_or(missing_field!(
+                        crate::sdk_comms::v0::CloseRecording,
+                        "store_id"
+                    ))?
+                    .into(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{
+        StoreId, StoreKind, TimeInt, ViewerCommand, ViewerEventItem, ViewerPlayState,
+    };
+
+    fn roundtrip(command: ViewerCommand) {
+        let proto: crate::sdk_comms::v0::ViewerCommand = command.clone().into();
+        let command2: ViewerCommand = proto.try_into().unwrap();
+        assert_eq!(command, command2);
+    }
+
+    #[test]
+    fn viewer_command_conversion() {
+        let store_id = StoreId::random(StoreKind::Recording);
+
+        roundtrip(ViewerCommand::SetActiveRecording {
+            store_id: store_id.clone(),
+        });
+        roundtrip(ViewerCommand::SetTime {
+            timeline: "frame_nr".into(),
+            time: Some(TimeInt::new_temporal(42)),
+        });
+        roundtrip(ViewerCommand::SetTime {
+            timeline: "log_time".into(),
+            time: None,
+        });
+        for play_state in [
+            ViewerPlayState::Paused,
+            ViewerPlayState::Playing,
+            ViewerPlayState::Following,
+        ] {
+            roundtrip(ViewerCommand::SetPlayState { play_state });
+        }
+        roundtrip(ViewerCommand::Select {
+            items: vec![
+                ViewerEventItem::from(re_log_types::EntityPath::from("a/b")),
+                ViewerEventItem {
+                    entity_path: "points".into(),
+                    instance: 7.into(),
+                },
+            ],
+        });
+        roundtrip(ViewerCommand::Select { items: vec![] });
+        roundtrip(ViewerCommand::Screenshot {
+            path: Some("/tmp/screenshot.png".to_owned()),
+        });
+        roundtrip(ViewerCommand::Screenshot { path: None });
+        roundtrip(ViewerCommand::OpenRecording {
+            uri: "https://app.rerun.io/version/latest/examples/dna.rrd".to_owned(),
+        });
+        roundtrip(ViewerCommand::CloseRecording { store_id });
+    }
+}
@@ -0,0 +1,189 @@
+use crate::sdk_comms::v0::viewer_event::Event;
+use crate::TypeConversionError;
+use crate::{invalid_field, missing_field};
+
+impl From<re_log_types::ViewerEventItem> for crate::sdk_comms::v0::ViewerEventItem {
+    #[inline]
+    fn from(value: re_log_types::ViewerEventItem) -> Self {
+        Self {
+            entity_path: Some(value.entity_path.into()),
+            instance: value
+                .instance
+                .specific_index()
+                .map(|instance| instance.get()),
+        }
+    }
+}
+
+impl TryFrom<crate::sdk_comms::v0::ViewerEventItem> for re_log_types::ViewerEventItem {
+    type Error = TypeConversionError;
+
+    #[inline]
+    fn try_from(value: crate::sdk_comms::v0::ViewerEventItem) -> Result<Self, Self::Error> {
+        let entity_path = value
+            .entity_path
+            .ok_or(missing_field!(
+                crate::sdk_comms::v0::ViewerEventItem,
+                "entity_path"
+            ))?
+            .try_into()?;
+
+        let instance = match value.instance {
+            Some(u64::MAX) => {
+                return Err(invalid_field!(
+                    crate::sdk_comms::v0::ViewerEventItem,
+                    "instance",
+                    "u64::MAX is reserved to refer to all instances"
+                ));
+            }
+            Some(instance) => instance.into(),
+            None => re_log_types::Instance::ALL,
+        };
+
+        Ok(Self {
+            entity_path,
+            instance,
+        })
+    }
+}
+
+fn items_from_proto(
+    items: Vec<crate::sdk_comms::v0::ViewerEventItem>,
+) -> Result<Vec<re_log_types::ViewerEventItem>, TypeConversionError> {
+    items.into_iter().map(TryInto::try_into).collect()
+}
+
+impl From<re_log_types::ViewerEvent> for crate::sdk_comms::v0::ViewerEvent {
+    fn from(value: re_log_types::ViewerEvent) -> Self {
+        let event = match value.kind {
+            re_log_types::ViewerEventKind::SelectionChange { items } => {
+                Event::SelectionChange(crate::sdk_comms::v0::SelectionChange {
+                    items: items.into_iter().map(Into::into).collect(),
+                })
+            }
+            re_log_types::ViewerEventKind::HoverChange { items } => {
+                Event::HoverChange(crate::sdk_comms::v0::HoverChange {
+                    items: items.into_iter().map(Into::into).collect(),
+                })
+            }
+            re_log_types::ViewerEventKind::TimeUpdate {
+                timeline,
+                time,
+                playing,
+            } => Event::TimeUpdate(crate::sdk_comms::v0::TimeUpdate {
+                timeline: Some(timeline.into()),
+                time: Some(time.into()),
+                playing,
+            }),
+            re_log_types::ViewerEventKind::ButtonPress { button, items } => {
+                Event::ButtonPress(crate::sdk_comms::v0::ButtonPress {
+                    button,
+                    items: items.into_iter().map(Into::into).collect(),
+                })
+            }
+        };
+
+        Self {
+            store_id: Some(value.store_id.into()),
+            event: Some(event),
+        }
+    }
+}
+
+impl TryFrom<crate::sdk_comms::v0::ViewerEvent> for re_log_types::ViewerEvent {
+    type Error = TypeConversionError;
+
+    fn try_from(value: crate::sdk_comms::v0::ViewerEvent) -> Result<Self, Self::Error> {
+        let store_id = value
+            .store_id
+            .ok_or(missing_field!(
+                crate::sdk_comms::v0::ViewerEvent,
+                "store_id"
+            ))?
+            .into();
+
+        let kind = match value
+            .event
+            .ok_or(missing_field!(crate::sdk_comms::v0::ViewerEvent, "event"))?
+        {
+            Event::SelectionChange(selection_change) => {
+                re_log_types::ViewerEventKind::SelectionChange {
+                    items: items_from_proto(selection_change.items)?,
+                }
+            }
+            Event::HoverChange(hover_change) => re_log_types::ViewerEventKind::HoverChange {
+                items: items_from_proto(hover_change.items)?,
+            },
+            Event::TimeUpdate(time_update) => re_log_types::ViewerEventKind::TimeUpdate {
+                timeline: time_update
+                    .timeline
+                    .ok_or(missing_field!(crate::sdk_comms::v0::TimeUpdate, "timeline"))?
+                    .into(),
+                time: time_update
+                    .time
+                    .ok_or(missing_field!(crate::sdk_comms::v0::TimeUpdate, "time"))?
+                    .into(),
+                playing: time_update.playing,
+            },
+            Event::ButtonPress(button_press) => re_log_types::ViewerEventKind::ButtonPress {
+                button: button_press.button,
+                items: items_from_proto(button_press.items)?,
+            },
+        };
+
+        Ok(Self { store_id, kind })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{
+        StoreId, StoreKind, TimeInt, Timeline, ViewerEvent, ViewerEventItem, ViewerEventKind,
+    };
+
+    fn roundtrip(event: ViewerEvent) {
+        let proto: crate::sdk_comms::v0::ViewerEvent = event.clone().into();
+        let event2: ViewerEvent = proto.try_into().unwrap();
+        assert_eq!(event, event2);
+    }
+
+    #[test]
+    fn viewer_event_conversion() {
+        let store_id = StoreId::random(StoreKind::Recording);
+
+        roundtrip(ViewerEvent {
+            store_id: store_id.clone(),
+            kind: ViewerEventKind::SelectionChange {
+                items: vec![
+                    ViewerEventItem::from(re_log_types::EntityPath::from("a/b")),
+                    ViewerEventItem {
+                        entity_path: "points".into(),
+                        instance: 42.into(),
+                    },
+                ],
+            },
+        });
+
+        roundtrip(ViewerEvent {
+            store_id: store_id.clone(),
+            kind: ViewerEventKind::HoverChange { items: vec![] },
+        });
+
+        roundtrip(ViewerEvent {
+            store_id: store_id.clone(),
+            kind: ViewerEventKind::TimeUpdate {
+                timeline: Timeline::new_sequence("frame_nr"),
+                time: TimeInt::new_temporal(123),
+                playing: true,
+            },
+        });
+
+        roundtrip(ViewerEvent {
+            store_id,
+            kind: ViewerEventKind::ButtonPress {
+                button: "Replay from here".to_owned(),
+                items: vec![ViewerEventItem::from(re_log_types::EntityPath::from("a/b"))],
+            },
+        });
+    }
+}
@@ -11,6 +11,314 @@ impl ::prost::Name for Empty {
         "/rerun.sdk_comms.v0.Empty".into()
     }
 }
+/// Corresponds to `re_log_types::ViewerEvent`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ViewerEvent {
+    /// The recording that was active in the viewer when the event happened.
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v0::StoreId>,
+    #[prost(oneof = "viewer_event::Event", tags = "2, 3, 4, 5")]
+    pub event: ::core::option::Option<viewer_event::Event>,
+}
+/// Nested message and enum types in `ViewerEvent`.
+pub mod viewer_event {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        /// The user changed the selection.
+        #[prost(message, tag = "2")]
+        SelectionChange(super::SelectionChange),
+        /// The user hovered something new.
+        #[prost(message, tag = "3")]
+        HoverChange(super::HoverChange),
+        /// The time cursor moved, the active timeline changed, or playback was started or stopped.
+        #[prost(message, tag = "4")]
+        TimeUpdate(super::TimeUpdate),
+        /// The user pressed one of the custom buttons the viewer was started with.
+        #[prost(message, tag = "5")]
+        ButtonPress(super::ButtonPress),
+    }
+}
+impl ::prost::Name for ViewerEvent {
+    const NAME: &'static str = "ViewerEvent";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.ViewerEvent".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.ViewerEvent".into()
+    }
+}
+/// An entity or a specific instance of an entity.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ViewerEventItem {
+    #[prost(message, optional, tag = "1")]
+    pub entity_path: ::core::option::Option<super::super::common::v0::EntityPath>,
+    /// Not set if the whole entity is referred to.
+    #[prost(uint64, optional, tag = "2")]
+    pub instance: ::core::option::Option<u64>,
+}
+impl ::prost::Name for ViewerEventItem {
+    const NAME: &'static str = "ViewerEventItem";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.ViewerEventItem".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.ViewerEventItem".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectionChange {
+    /// Everything that is selected now. Empty if the selection was cleared.
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<ViewerEventItem>,
+}
+impl ::prost::Name for SelectionChange {
+    const NAME: &'static str = "SelectionChange";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.SelectionChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.SelectionChange".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HoverChange {
+    /// Everything that is hovered now. Empty if nothing is hovered anymore.
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<ViewerEventItem>,
+}
+impl ::prost::Name for HoverChange {
+    const NAME: &'static str = "HoverChange";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.HoverChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.HoverChange".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub timeline: ::core::option::Option<super::super::common::v0::Timeline>,
+    #[prost(message, optional, tag = "2")]
+    pub time: ::core::option::Option<super::super::common::v0::TimeInt>,
+    #[prost(bool, tag = "3")]
+    pub playing: bool,
+}
+impl ::prost::Name for TimeUpdate {
+    const NAME: &'static str = "TimeUpdate";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.TimeUpdate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.TimeUpdate".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ButtonPress {
+    /// The label of the button.
+    #[prost(string, tag = "1")]
+    pub button: ::prost::alloc::string::String,
+    /// Everything that was selected when the button was pressed.
+    #[prost(message, repeated, tag = "2")]
+    pub items: ::prost::alloc::vec::Vec<ViewerEventItem>,
+}
+impl ::prost::Name for ButtonPress {
+    const NAME: &'static str = "ButtonPress";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.ButtonPress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.ButtonPress".into()
+    }
+}
+/// Corresponds to `re_log_types::ViewerCommand`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ViewerCommand {
+    #[prost(oneof = "viewer_command::Command", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub command: ::core::option::Option<viewer_command::Command>,
+}
+/// Nested message and enum types in `ViewerCommand`.
+pub mod viewer_command {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Command {
+        #[prost(message, tag = "1")]
+        SetActiveRecording(super::SetActiveRecording),
+        #[prost(message, tag = "2")]
+        SetTime(super::SetTime),
+        #[prost(message, tag = "3")]
+        SetPlayState(super::SetPlayState),
+        #[prost(message, tag = "4")]
+        Select(super::Select),
+        #[prost(message, tag = "5")]
+        Screenshot(super::Screenshot),
+        #[prost(message, tag = "6")]
+        OpenRecording(super::OpenRecording),
+        #[prost(message, tag = "7")]
+        CloseRecording(super::CloseRecording),
+    }
+}
+impl ::prost::Name for ViewerCommand {
+    const NAME: &'static str = "ViewerCommand";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.ViewerCommand".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.ViewerCommand".into()
+    }
+}
+/// Switch to the given recording, if the viewer has it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetActiveRecording {
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v0::StoreId>,
+}
+impl ::prost::Name for SetActiveRecording {
+    const NAME: &'static str = "SetActiveRecording";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.SetActiveRecording".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.SetActiveRecording".into()
+    }
+}
+/// Switch to the given timeline, and optionally move the time cursor on it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetTime {
+    #[prost(message, optional, tag = "1")]
+    pub timeline: ::core::option::Option<super::super::common::v0::Timeline>,
+    /// Not set if only the timeline should change.
+    #[prost(message, optional, tag = "2")]
+    pub time: ::core::option::Option<super::super::common::v0::TimeInt>,
+}
+impl ::prost::Name for SetTime {
+    const NAME: &'static str = "SetTime";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.SetTime".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.SetTime".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetPlayState {
+    #[prost(enumeration = "PlayState", tag = "1")]
+    pub play_state: i32,
+}
+impl ::prost::Name for SetPlayState {
+    const NAME: &'static str = "SetPlayState";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.SetPlayState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.SetPlayState".into()
+    }
+}
+/// Replace the selection. An empty list clears the selection.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Select {
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<ViewerEventItem>,
+}
+impl ::prost::Name for Select {
+    const NAME: &'static str = "Select";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.Select".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.Select".into()
+    }
+}
+/// Take a screenshot of the whole viewer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Screenshot {
+    /// Where to save the screenshot, on the machine running the viewer.
+    ///
+    /// Not set if the screenshot should be copied to the clipboard instead.
+    #[prost(string, optional, tag = "1")]
+    pub path: ::core::option::Option<::prost::alloc::string::String>,
+}
+impl ::prost::Name for Screenshot {
+    const NAME: &'static str = "Screenshot";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.Screenshot".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.Screenshot".into()
+    }
+}
+/// Open a recording from a file path or url.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenRecording {
+    #[prost(string, tag = "1")]
+    pub uri: ::prost::alloc::string::String,
+}
+impl ::prost::Name for OpenRecording {
+    const NAME: &'static str = "OpenRecording";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.OpenRecording".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.OpenRecording".into()
+    }
+}
+/// Close the given recording.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloseRecording {
+    #[prost(message, optional, tag = "1")]
+    pub store_id: ::core::option::Option<super::super::common::v0::StoreId>,
+}
+impl ::prost::Name for CloseRecording {
+    const NAME: &'static str = "CloseRecording";
+    const PACKAGE: &'static str = "rerun.sdk_comms.v0";
+    fn full_name() -> ::prost::alloc::string::String {
+        "rerun.sdk_comms.v0.CloseRecording".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/rerun.sdk_comms.v0.CloseRecording".into()
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlayState {
+    Paused = 0,
+    Playing = 1,
+    Following = 2,
+}
+impl PlayState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Paused => "PAUSED",
+            Self::Playing => "PLAYING",
+            Self::Following => "FOLLOWING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PAUSED" => Some(Self::Paused),
+            "PLAYING" => Some(Self::Playing),
+            "FOLLOWING" => Some(Self::Following),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod message_proxy_client {
     #![allow(
@@ -31,6 +339,14 @@ pub mod message_proxy_client {
     ///
     /// Whenever `ReadMessages` is called, all buffered messages are sent in the order they were received.
     /// The stream will then also yield any new messages passed to `WriteMessages` from any client.
+    ///
+    /// Viewers can also talk back to SDKs, by publishing events via `WriteViewerEvent`.
+    /// Any client subscribed via `ReadViewerEvents` will receive them.
+    /// Viewer events are never buffered: late subscribers will only see events published after they subscribed.
+    ///
+    /// Clients can also remote control viewers, by sending commands via `WriteViewerCommand`.
+    /// Every viewer subscribed via `ReadViewerCommands` will receive and execute them.
+    /// Like viewer events, viewer commands are never buffered.
     #[derive(Debug, Clone)]
     pub struct MessageProxyClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -142,6 +458,86 @@ pub mod message_proxy_client {
             ));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// This is a unary call rather than a client stream, because `grpc-web` does not support
+        /// client streaming, and the web viewer needs to be able to publish events too.
+        pub async fn write_viewer_event(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ViewerEvent>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v0.MessageProxy/WriteViewerEvent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v0.MessageProxy",
+                "WriteViewerEvent",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn read_viewer_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ViewerEvent>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v0.MessageProxy/ReadViewerEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v0.MessageProxy",
+                "ReadViewerEvents",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn write_viewer_command(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ViewerCommand>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v0.MessageProxy/WriteViewerCommand",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v0.MessageProxy",
+                "WriteViewerCommand",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn read_viewer_commands(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Empty>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ViewerCommand>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rerun.sdk_comms.v0.MessageProxy/ReadViewerCommands",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "rerun.sdk_comms.v0.MessageProxy",
+                "ReadViewerCommands",
+            ));
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -172,6 +568,34 @@ pub mod message_proxy_server {
             &self,
             request: tonic::Request<super::Empty>,
         ) -> std::result::Result<tonic::Response<Self::ReadMessagesStream>, tonic::Status>;
+        /// This is a unary call rather than a client stream, because `grpc-web` does not support
+        /// client streaming, and the web viewer needs to be able to publish events too.
+        async fn write_viewer_event(
+            &self,
+            request: tonic::Request<super::ViewerEvent>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        /// Server streaming response type for the ReadViewerEvents method.
+        type ReadViewerEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ViewerEvent, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        async fn read_viewer_events(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<Self::ReadViewerEventsStream>, tonic::Status>;
+        async fn write_viewer_command(
+            &self,
+            request: tonic::Request<super::ViewerCommand>,
+        ) -> std::result::Result<tonic::Response<super::Empty>, tonic::Status>;
+        /// Server streaming response type for the ReadViewerCommands method.
+        type ReadViewerCommandsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ViewerCommand, tonic::Status>,
+            > + std::marker::Send
+            + 'static;
+        async fn read_viewer_commands(
+            &self,
+            request: tonic::Request<super::Empty>,
+        ) -> std::result::Result<tonic::Response<Self::ReadViewerCommandsStream>, tonic::Status>;
     }
     /// Simple buffer for messages between SDKs and viewers.
     ///
@@ -182,6 +606,14 @@ pub mod message_proxy_server {
     ///
     /// Whenever `ReadMessages` is called, all buffered messages are sent in the order they were received.
     /// The stream will then also yield any new messages passed to `WriteMessages` from any client.
+    ///
+    /// Viewers can also talk back to SDKs, by publishing events via `WriteViewerEvent`.
+    /// Any client subscribed via `ReadViewerEvents` will receive them.
+    /// Viewer events are never buffered: late subscribers will only see events published after they subscribed.
+    ///
+    /// Clients can also remote control viewers, by sending commands via `WriteViewerCommand`.
+    /// Every viewer subscribed via `ReadViewerCommands` will receive and execute them.
+    /// Like viewer events, viewer commands are never buffered.
     #[derive(Debug)]
     pub struct MessageProxyServer<T> {
         inner: Arc<T>,
@@ -338,6 +770,166 @@ pub mod message_proxy_server {
                     };
                     Box::pin(fut)
                 }
+                "/rerun.sdk_comms.v0.MessageProxy/WriteViewerEvent" => {
+                    #[allow(non_camel_case_types)]
+                    struct WriteViewerEventSvc<T: MessageProxy>(pub Arc<T>);
+                    impl<T: MessageProxy> tonic::server::UnaryService<super::ViewerEvent> for WriteViewerEventSvc<T> {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ViewerEvent>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxy>::write_viewer_event(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WriteViewerEventSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v0.MessageProxy/ReadViewerEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadViewerEventsSvc<T: MessageProxy>(pub Arc<T>);
+                    impl<T: MessageProxy> tonic::server::ServerStreamingService<super::Empty>
+                        for ReadViewerEventsSvc<T>
+                    {
+                        type Response = super::ViewerEvent;
+                        type ResponseStream = T::ReadViewerEventsStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxy>::read_viewer_events(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadViewerEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v0.MessageProxy/WriteViewerCommand" => {
+                    #[allow(non_camel_case_types)]
+                    struct WriteViewerCommandSvc<T: MessageProxy>(pub Arc<T>);
+                    impl<T: MessageProxy> tonic::server::UnaryService<super::ViewerCommand>
+                        for WriteViewerCommandSvc<T>
+                    {
+                        type Response = super::Empty;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ViewerCommand>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxy>::write_viewer_command(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WriteViewerCommandSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rerun.sdk_comms.v0.MessageProxy/ReadViewerCommands" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadViewerCommandsSvc<T: MessageProxy>(pub Arc<T>);
+                    impl<T: MessageProxy> tonic::server::ServerStreamingService<super::Empty>
+                        for ReadViewerCommandsSvc<T>
+                    {
+                        type Response = super::ViewerCommand;
+                        type ResponseStream = T::ReadViewerCommandsStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(&mut self, request: tonic::Request<super::Empty>) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MessageProxy>::read_viewer_commands(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadViewerCommandsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     let mut response = http::Response::new(empty_body());
                     let headers = response.headers_mut();
@@ -1,18 +1,25 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 use ahash::HashMap;
 use nohash_hasher::IntSet;
 use parking_lot::RwLock;
 
-use re_chunk::ChunkId;
+use re_chunk::{Chunk, ChunkId, RowId};
 use re_chunk_store::{
-    ChunkCompactionReport, ChunkStoreDiff, ChunkStoreEvent, ChunkStoreHandle, ChunkStoreSubscriber,
+    ChunkCompactionReport, ChunkStore, ChunkStoreDiff, ChunkStoreEvent, ChunkStoreGeneration,
+    ChunkStoreHandle, ChunkStoreSubscriber, TimelineMapping,
 };
 use re_log_types::{EntityPath, ResolvedTimeRange, StoreId, TimeInt, Timeline};
-use re_types_core::{components::ClearIsRecursive, Component as _, ComponentName};
+use re_types_core::{
+    components::{ClearIsRecursive, SourceTimeline},
+    Component as _, ComponentName,
+};
 
 use crate::{LatestAtCache, RangeCache};
 
@@ -155,6 +162,19 @@ pub struct QueryCache {
     /// This is a huge performance improvement in practice, especially in recordings with many entities.
     pub(crate) might_require_clearing: RwLock<IntSet<EntityPath>>,
 
+    /// Whether the associated store might contain any [`TimelineMapping`].
+    ///
+    /// This is checked before anything else, so that stores without mappings never pay for them.
+    pub(crate) might_have_timeline_mappings: AtomicBool,
+
+    /// All the [`TimelineMapping`]s of the associated store, computed lazily.
+    ///
+    /// Reset whenever mapping data gets added or removed, see [`ChunkStore::timeline_mappings`].
+    pub(crate) timeline_mappings: RwLock<Option<Arc<Vec<TimelineMapping>>>>,
+
+    /// Query results that were expressed on another timeline through a [`TimelineMapping`].
+    pub(crate) mapped_chunks: RwLock<MappedChunks>,
+
     // NOTE: `Arc` so we can cheaply free the top-level lock early when needed.
     pub(crate) latest_at_per_cache_key: RwLock<HashMap<QueryCacheKey, Arc<RwLock<LatestAtCache>>>>,
 
@@ -168,6 +188,9 @@ impl std::fmt::Debug for QueryCache {
             store_id,
             store: _,
             might_require_clearing,
+            might_have_timeline_mappings: _,
+            timeline_mappings,
+            mapped_chunks: _,
             latest_at_per_cache_key,
             range_per_cache_key,
         } = self;
@@ -186,6 +209,18 @@ impl std::fmt::Debug for QueryCache {
             strings.push("\n".to_owned());
         }
 
+        if let Some(timeline_mappings) = timeline_mappings.read().as_ref() {
+            strings.push(format!("[Timeline mappings @ {store_id}]\n"));
+            for mapping in timeline_mappings.iter() {
+                strings.push(format!(
+                    "  * {} -> {}\n",
+                    mapping.source.name(),
+                    mapping.target.name()
+                ));
+            }
+            strings.push("\n".to_owned());
+        }
+
         strings.push(format!("[LatestAt @ {store_id}]"));
         {
             let latest_at_per_cache_key = latest_at_per_cache_key.read();
@@ -230,6 +265,10 @@ impl QueryCache {
             store,
             store_id,
             might_require_clearing: Default::default(),
+            // We don't know until we've looked.
+            might_have_timeline_mappings: AtomicBool::new(true),
+            timeline_mappings: Default::default(),
+            mapped_chunks: Default::default(),
             latest_at_per_cache_key: Default::default(),
             range_per_cache_key: Default::default(),
         }
@@ -246,14 +285,114 @@ impl QueryCache {
             store: _,
             store_id: _,
             might_require_clearing,
+            might_have_timeline_mappings,
+            timeline_mappings,
+            mapped_chunks,
             latest_at_per_cache_key,
             range_per_cache_key,
         } = self;
 
         might_require_clearing.write().clear();
+        might_have_timeline_mappings.store(true, Ordering::Relaxed);
+        *timeline_mappings.write() = None;
+        *mapped_chunks.write() = Default::default();
         latest_at_per_cache_key.write().clear();
         range_per_cache_key.write().clear();
     }
+
+    /// All the [`TimelineMapping`]s of the associated store.
+    ///
+    /// This is cached, see [`ChunkStore::timeline_mappings`].
+    pub fn timeline_mappings(&self, store: &ChunkStore) -> Arc<Vec<TimelineMapping>> {
+        if let Some(timeline_mappings) = self.timeline_mappings.read().as_ref() {
+            return Arc::clone(timeline_mappings);
+        }
+
+        let timeline_mappings = Arc::new(store.timeline_mappings());
+        self.might_have_timeline_mappings
+            .store(!timeline_mappings.is_empty(), Ordering::Relaxed);
+        *self.timeline_mappings.write() = Some(Arc::clone(&timeline_mappings));
+        timeline_mappings
+    }
+
+    /// Finds the mapping through which `component_name` can be queried on `timeline`, if any.
+    ///
+    /// Only meant for components that `entity_path` doesn't have on `timeline`.
+    /// This is free for stores without any mapping.
+    pub(crate) fn timeline_mapping_for(
+        &self,
+        store: &ChunkStore,
+        timeline: &Timeline,
+        entity_path: &EntityPath,
+        component_name: &ComponentName,
+    ) -> Option<TimelineMapping> {
+        if !self.might_have_timeline_mappings.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let timeline_mappings = self.timeline_mappings(store);
+        store.timeline_mapping_for(&timeline_mappings, timeline, entity_path, component_name)
+    }
+
+    /// Adds a time column for the target timeline of `mapping` to a query result, see
+    /// [`TimelineMapping::chunk_with_target_timeline`].
+    ///
+    /// The results are cached until the store changes.
+    pub(crate) fn mapped_chunk(
+        &self,
+        generation: &ChunkStoreGeneration,
+        mapping: &TimelineMapping,
+        component_name: ComponentName,
+        chunk: &Chunk,
+    ) -> Option<Chunk> {
+        // Query results are slices of the stored chunks, which keep the id of the original chunk.
+        let key = MappedChunkKey {
+            chunk_id: chunk.id(),
+            row_ids: chunk.row_id_range(),
+            num_rows: chunk.num_rows(),
+            component_name,
+            source: mapping.source,
+            target: mapping.target,
+        };
+
+        {
+            let mapped_chunks = self.mapped_chunks.read();
+            if mapped_chunks.generation == *generation {
+                if let Some(chunk) = mapped_chunks.chunks.get(&key) {
+                    return Some(chunk.clone());
+                }
+            }
+        }
+
+        let mapped_chunk = mapping.chunk_with_target_timeline(chunk).ok()?;
+
+        let mut mapped_chunks = self.mapped_chunks.write();
+        if mapped_chunks.generation != *generation {
+            mapped_chunks.generation = generation.clone();
+            mapped_chunks.chunks.clear();
+        }
+        mapped_chunks.chunks.insert(key, mapped_chunk.clone());
+
+        Some(mapped_chunk)
+    }
+}
+
+/// Identifies a query result that was expressed on another timeline, see [`QueryCache::mapped_chunk`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) struct MappedChunkKey {
+    chunk_id: ChunkId,
+    row_ids: Option<(RowId, RowId)>,
+    num_rows: usize,
+    component_name: ComponentName,
+    source: Timeline,
+    target: Timeline,
+}
+
+/// Query results that were expressed on another timeline, for a given store generation.
+#[derive(Default)]
+pub(crate) struct MappedChunks {
+    generation: ChunkStoreGeneration,
+    chunks: HashMap<MappedChunkKey, Chunk>,
 }
 
 impl ChunkStoreSubscriber for QueryCache {
@@ -283,6 +422,7 @@ impl ChunkStoreSubscriber for QueryCache {
         }
 
         let mut compacted_events = CompactedEvents::default();
+        let mut timeline_mappings_changed = false;
 
         for event in events {
             let ChunkStoreEvent {
@@ -305,6 +445,9 @@ impl ChunkStoreSubscriber for QueryCache {
                 compacted,
             } = diff;
 
+            timeline_mappings_changed |=
+                chunk.get_first_component(&SourceTimeline::name()).is_some();
+
             {
                 re_tracing::profile_scope!("compact events");
 
@@ -390,6 +533,12 @@ impl ChunkStoreSubscriber for QueryCache {
             }
         }
 
+        if timeline_mappings_changed {
+            self.might_have_timeline_mappings
+                .store(true, Ordering::Relaxed);
+            *self.timeline_mappings.write() = None;
+        }
+
         let mut might_require_clearing = self.might_require_clearing.write();
         let caches_latest_at = self.latest_at_per_cache_key.write();
         let caches_range = self.range_per_cache_key.write();
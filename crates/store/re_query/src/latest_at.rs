@@ -10,7 +10,7 @@ use parking_lot::RwLock;
 
 use re_byte_size::SizeBytes;
 use re_chunk::{Chunk, RowId, UnitChunkShared};
-use re_chunk_store::{ChunkStore, LatestAtQuery, TimeInt};
+use re_chunk_store::{ChunkStore, LatestAtQuery, TimeInt, TimelineMapping};
 use re_log_types::EntityPath;
 use re_types_core::{
     components::ClearIsRecursive, external::arrow::array::ArrayRef, Component, ComponentDescriptor,
@@ -59,15 +59,30 @@ impl QueryCache {
         // NOTE: This pre-filtering is extremely important: going through all these query layers
         // has non-negligible overhead even if the final result ends up being nothing, and our
         // number of queries for a frame grows linearly with the number of entity paths.
+        //
+        // Components that only exist on another timeline can still be found through a timeline
+        // mapping, these are queried separately at the end.
+        let mut mapped_components: Vec<(TimelineMapping, ComponentDescriptor)> = Vec::new();
         let component_names = component_descrs.into_iter().filter_map(|component_descr| {
             let component_descr = component_descr.into();
-            store
-                .entity_has_component_on_timeline(
-                    &query.timeline(),
-                    entity_path,
-                    &component_descr.component_name,
-                )
-                .then_some(component_descr.component_name)
+            if store.entity_has_component_on_timeline(
+                &query.timeline(),
+                entity_path,
+                &component_descr.component_name,
+            ) {
+                return Some(component_descr.component_name);
+            }
+
+            if let Some(mapping) = self.timeline_mapping_for(
+                &store,
+                &query.timeline(),
+                entity_path,
+                &component_descr.component_name,
+            ) {
+                mapped_components.push((mapping, component_descr.into_owned()));
+            }
+
+            None
         });
 
         // Query-time clears
@@ -178,6 +193,37 @@ impl QueryCache {
             }
         }
 
+        if mapped_components.is_empty() {
+            return results;
+        }
+
+        // Components logged on another timeline: query them at the corresponding time of their
+        // own timeline, then express the results on the queried timeline.
+        let generation = store.generation();
+        drop(store); // The nested queries need to lock the store again.
+        for (mapping, component_descr) in mapped_components {
+            let source_query = LatestAtQuery::new(mapping.source, mapping.to_source(query.at()));
+            let source_results = self.latest_at(&source_query, entity_path, [component_descr]);
+
+            for (component_name, cached) in source_results.components {
+                let Some(cached) = self
+                    .mapped_chunk(&generation, &mapping, component_name, &cached)
+                    .and_then(Chunk::into_unit)
+                else {
+                    continue;
+                };
+
+                // Clears on the queried timeline shadow mapped data just like any other data.
+                if let Some(index) = cached.index(&query.timeline()) {
+                    if component_name == ClearIsRecursive::name()
+                        || compare_indices(index, max_clear_index) == std::cmp::Ordering::Greater
+                    {
+                        results.add(component_name, index, cached);
+                    }
+                }
+            }
+        }
+
         results
     }
 
@@ -6,7 +6,7 @@ use parking_lot::RwLock;
 
 use re_byte_size::SizeBytes;
 use re_chunk::{Chunk, ChunkId};
-use re_chunk_store::{ChunkStore, RangeQuery, TimeInt};
+use re_chunk_store::{ChunkStore, RangeQuery, TimeInt, TimelineMapping};
 use re_log_types::{EntityPath, ResolvedTimeRange};
 use re_types_core::{ComponentDescriptor, ComponentName, DeserializationError};
 
@@ -35,15 +35,30 @@ impl QueryCache {
         // NOTE: This pre-filtering is extremely important: going through all these query layers
         // has non-negligible overhead even if the final result ends up being nothing, and our
         // number of queries for a frame grows linearly with the number of entity paths.
+        //
+        // Components that only exist on another timeline can still be found through a timeline
+        // mapping, these are queried separately at the end.
+        let mut mapped_components: Vec<(TimelineMapping, ComponentDescriptor)> = Vec::new();
         let component_names = component_descrs.into_iter().filter_map(|component_descr| {
             let component_descr = component_descr.into();
-            store
-                .entity_has_component_on_timeline(
-                    &query.timeline(),
-                    entity_path,
-                    &component_descr.component_name,
-                )
-                .then_some(component_descr.component_name)
+            if store.entity_has_component_on_timeline(
+                &query.timeline(),
+                entity_path,
+                &component_descr.component_name,
+            ) {
+                return Some(component_descr.component_name);
+            }
+
+            if let Some(mapping) = self.timeline_mapping_for(
+                &store,
+                &query.timeline(),
+                entity_path,
+                &component_descr.component_name,
+            ) {
+                mapped_components.push((mapping, component_descr.into_owned()));
+            }
+
+            None
         });
 
         for component_name in component_names {
@@ -66,6 +81,36 @@ impl QueryCache {
             }
         }
 
+        if mapped_components.is_empty() {
+            return results;
+        }
+
+        // Components logged on another timeline: query the corresponding range of their own
+        // timeline, then express the results on the queried timeline.
+        let generation = store.generation();
+        drop(store); // The nested queries need to lock the store again.
+        let options = query.options();
+        for (mapping, component_descr) in mapped_components {
+            let source_query =
+                RangeQuery::new(mapping.source, mapping.range_to_source(query.range()))
+                    .keep_extra_timelines(options.keep_extra_timelines)
+                    .keep_extra_components(options.keep_extra_components)
+                    .include_extended_bounds(options.include_extended_bounds);
+            let source_results = self.range(&source_query, entity_path, [component_descr]);
+
+            for (component_name, chunks) in source_results.components {
+                let chunks: Vec<Chunk> = chunks
+                    .iter()
+                    .filter_map(|chunk| {
+                        self.mapped_chunk(&generation, &mapping, component_name, chunk)
+                    })
+                    .collect();
+                if !chunks.is_empty() {
+                    results.add(component_name, chunks);
+                }
+            }
+        }
+
         results
     }
 }
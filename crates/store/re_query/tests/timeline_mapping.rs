@@ -0,0 +1,196 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use re_chunk::{RowId, Timeline};
+use re_chunk_store::{
+    external::re_chunk::Chunk, ChunkStore, ChunkStoreSubscriber as _, LatestAtQuery, RangeQuery,
+    ResolvedTimeRange, TimeInt, TimeType,
+};
+use re_log_types::{example_components::MyPoint, EntityPath, TimePoint};
+use re_query::QueryCache;
+use re_types_core::{archetypes, Component as _};
+
+// ---
+
+fn insert_and_react(store: &mut ChunkStore, caches: &mut QueryCache, chunk: Chunk) {
+    caches.on_events(&store.insert_chunk(&Arc::new(chunk)).unwrap());
+}
+
+/// Logs `points` at the given times of `device_time`, and nothing on `frame_nr`.
+fn log_sensor(
+    store: &mut ChunkStore,
+    caches: &mut QueryCache,
+    device: Timeline,
+    points: &[(i64, MyPoint)],
+) -> anyhow::Result<()> {
+    let mut builder = Chunk::builder("sensor".into());
+    for (device_time, point) in points {
+        builder = builder.with_component_batch(
+            RowId::new(),
+            TimePoint::default().with(device, *device_time),
+            &[*point],
+        );
+    }
+    insert_and_react(store, caches, builder.build()?);
+    Ok(())
+}
+
+/// `frame_nr = device_time / 100`, as a linear clock model.
+fn log_clock_model(store: &mut ChunkStore, caches: &mut QueryCache) -> anyhow::Result<()> {
+    let chunk = Chunk::builder("clocks".into())
+        .with_archetype(
+            RowId::new(),
+            TimePoint::default(),
+            &archetypes::TimelineMapping::new("device_time", "frame_nr").with_drift(-0.99),
+        )
+        .build()?;
+    insert_and_react(store, caches, chunk);
+    Ok(())
+}
+
+#[test]
+fn latest_at_through_mapping() -> anyhow::Result<()> {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+    let entity_path: EntityPath = "sensor".into();
+
+    log_sensor(
+        &mut store.write(),
+        &mut caches,
+        device,
+        &[
+            (1_000, MyPoint::new(1.0, 1.0)),
+            (2_000, MyPoint::new(2.0, 2.0)),
+        ],
+    )?;
+
+    let query = LatestAtQuery::new(frame, 15);
+
+    // Without a mapping, the sensor has no data on `frame_nr`.
+    let results = caches.latest_at(&query, &entity_path, [MyPoint::name()]);
+    assert!(results.components.is_empty());
+
+    // Mappings that are logged after the first query are picked up.
+    log_clock_model(&mut store.write(), &mut caches)?;
+
+    for _ in 0..2 {
+        let results = caches.latest_at(&query, &entity_path, [MyPoint::name()]);
+        let points = results.component_batch::<MyPoint>().unwrap();
+        assert_eq!(vec![MyPoint::new(1.0, 1.0)], points);
+        assert_eq!(TimeInt::new_temporal(10), results.index().0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn clears_shadow_mapped_data() -> anyhow::Result<()> {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+    let entity_path: EntityPath = "sensor".into();
+
+    log_clock_model(&mut store.write(), &mut caches)?;
+    log_sensor(
+        &mut store.write(),
+        &mut caches,
+        device,
+        &[
+            (1_000, MyPoint::new(1.0, 1.0)),
+            (2_000, MyPoint::new(2.0, 2.0)),
+        ],
+    )?;
+
+    // Cleared on `frame_nr`, in between the mapped frames 10 & 20.
+    let chunk = Chunk::builder(entity_path.clone())
+        .with_archetype(
+            RowId::new(),
+            TimePoint::default().with(frame, 12),
+            &archetypes::Clear::flat(),
+        )
+        .build()?;
+    insert_and_react(&mut store.write(), &mut caches, chunk);
+
+    let points_at = |caches: &QueryCache, frame_nr: i64| {
+        let query = LatestAtQuery::new(frame, frame_nr);
+        caches
+            .latest_at(&query, &entity_path, [MyPoint::name()])
+            .component_batch::<MyPoint>()
+    };
+
+    assert_eq!(Some(vec![MyPoint::new(1.0, 1.0)]), points_at(&caches, 11));
+    assert_eq!(None, points_at(&caches, 15));
+    assert_eq!(Some(vec![MyPoint::new(2.0, 2.0)]), points_at(&caches, 25));
+
+    Ok(())
+}
+
+#[test]
+fn range_through_mapping() -> anyhow::Result<()> {
+    let store = ChunkStore::new_handle(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        Default::default(),
+    );
+    let mut caches = QueryCache::new(store.clone());
+
+    let device = Timeline::new("device_time", TimeType::Sequence);
+    let frame = Timeline::new("frame_nr", TimeType::Sequence);
+    let entity_path: EntityPath = "sensor".into();
+
+    log_clock_model(&mut store.write(), &mut caches)?;
+    log_sensor(
+        &mut store.write(),
+        &mut caches,
+        device,
+        &[
+            (1_000, MyPoint::new(1.0, 1.0)),
+            (2_000, MyPoint::new(2.0, 2.0)),
+            (3_000, MyPoint::new(3.0, 3.0)),
+        ],
+    )?;
+
+    let frames_of = |caches: &QueryCache, range: ResolvedTimeRange| {
+        let query = RangeQuery::new(frame, range);
+        let results = caches.range(&query, &entity_path, [MyPoint::name()]);
+        results
+            .get(&MyPoint::name())
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|chunk| chunk.iter_component_indices(&frame, &MyPoint::name()))
+            .map(|(time, _row_id)| time.as_i64())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        vec![10, 20, 30],
+        frames_of(&caches, ResolvedTimeRange::EVERYTHING)
+    );
+    assert_eq!(vec![20], frames_of(&caches, ResolvedTimeRange::new(15, 25)));
+
+    // New data invalidates the mapped results.
+    log_sensor(
+        &mut store.write(),
+        &mut caches,
+        device,
+        &[(4_000, MyPoint::new(4.0, 4.0))],
+    )?;
+    assert_eq!(
+        vec![20, 30, 40],
+        frames_of(&caches, ResolvedTimeRange::new(15, 45))
+    );
+
+    Ok(())
+}
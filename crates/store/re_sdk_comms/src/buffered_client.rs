@@ -2,7 +2,9 @@ use std::{fmt, net::SocketAddr, thread::JoinHandle};
 
 use crossbeam::channel::{select, Receiver, Sender};
 
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerEvent};
+
+use crate::viewer_events::Subscribers;
 
 #[derive(Debug, PartialEq, Eq)]
 struct FlushedMsg;
@@ -45,6 +47,7 @@ pub struct Client {
     send_quit_tx: Sender<InterruptMsg>,
     encode_join: Option<JoinHandle<()>>,
     send_join: Option<JoinHandle<()>>,
+    viewer_event_subscribers: Subscribers,
 
     /// Only used for diagnostics, not for communication after `new()`.
     addr: SocketAddr,
@@ -73,6 +76,8 @@ impl Client {
         // can be expensive, see https://github.com/rerun-io/rerun/issues/2216
         let encoding_options = re_log_encoding::EncodingOptions::MSGPACK_UNCOMPRESSED;
 
+        let viewer_event_subscribers = Subscribers::default();
+
         let encode_join = std::thread::Builder::new()
             .name("msg_encoder".into())
             .spawn(move || {
@@ -82,8 +87,16 @@ impl Client {
 
         let send_join = std::thread::Builder::new()
             .name("tcp_sender".into())
-            .spawn(move || {
-                tcp_sender(addr, flush_timeout, &packet_rx, &send_quit_rx, &flushed_tx);
+            .spawn({
+                let viewer_event_subscribers = viewer_event_subscribers.clone();
+                move || {
+                    let tcp_client = crate::tcp_client::TcpClient::new(
+                        addr,
+                        flush_timeout,
+                        viewer_event_subscribers,
+                    );
+                    tcp_sender(tcp_client, &packet_rx, &send_quit_rx, &flushed_tx);
+                }
             })
             .expect("Failed to spawn thread");
 
@@ -94,6 +107,7 @@ impl Client {
             send_quit_tx,
             encode_join: Some(encode_join),
             send_join: Some(send_join),
+            viewer_event_subscribers,
             addr,
         }
     }
@@ -102,6 +116,16 @@ impl Client {
         self.send_msg_msg(MsgMsg::LogMsg(log_msg));
     }
 
+    /// Subscribe to [`ViewerEvent`]s published by the viewer we are connected to,
+    /// e.g. when the user selects an entity or moves the time cursor.
+    ///
+    /// The connection is established lazily, when the first message is sent.
+    /// The server is only asked for viewer events if someone is subscribed at that time,
+    /// so subscribe before logging anything.
+    pub fn viewer_events(&self) -> std::sync::mpsc::Receiver<ViewerEvent> {
+        self.viewer_event_subscribers.subscribe()
+    }
+
     /// Stall until all messages so far has been sent.
     pub fn flush(&self) {
         re_log::debug!("Flushing message queue…");
@@ -210,13 +234,11 @@ fn msg_encode(
 }
 
 fn tcp_sender(
-    addr: SocketAddr,
-    flush_timeout: Option<std::time::Duration>,
+    mut tcp_client: crate::tcp_client::TcpClient,
     packet_rx: &Receiver<PacketMsg>,
     quit_rx: &Receiver<InterruptMsg>,
     flushed_tx: &Sender<FlushedMsg>,
 ) {
-    let mut tcp_client = crate::tcp_client::TcpClient::new(addr, flush_timeout);
     // Once this flag has been set, we will drop all messages if the tcp_client is
     // no longer connected.
     let mut drop_if_disconnected = false;
@@ -7,6 +7,9 @@
 #[cfg(feature = "client")]
 pub(crate) mod tcp_client;
 
+#[cfg(any(feature = "client", feature = "server"))]
+mod viewer_events;
+
 #[cfg(feature = "client")]
 mod buffered_client;
 
@@ -17,7 +20,7 @@ pub use {buffered_client::Client, tcp_client::ClientError};
 mod server;
 
 #[cfg(feature = "server")]
-pub use server::{serve, ServerError, ServerOptions};
+pub use server::{serve, serve_with_viewer_events, ServerError, ServerOptions, ViewerEventSender};
 
 /// Server connection error.
 ///
@@ -65,6 +68,10 @@ pub const PROTOCOL_VERSION_0: u16 = 0;
 /// Added [`PROTOCOL_HEADER`]. Introduced for Rerun 0.16.
 pub const PROTOCOL_VERSION_1: u16 = 1;
 
+/// Like [`PROTOCOL_VERSION_1`], but the server also sends [`re_log_types::ViewerEvent`]s
+/// back to the client over the same connection. Introduced for Rerun 0.22.
+pub const PROTOCOL_VERSION_2: u16 = 2;
+
 /// Comes after version.
 pub const PROTOCOL_HEADER: &str = "rerun";
 
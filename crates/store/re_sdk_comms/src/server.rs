@@ -1,12 +1,16 @@
 use std::{
-    io::{ErrorKind, Read as _},
+    io::{ErrorKind, Read as _, Write as _},
     net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
     time::Instant,
 };
 
 use rand::{Rng as _, SeedableRng};
 
-use re_log_types::{LogMsg, TimePoint, TimeType, TimelineName};
+use re_log_types::{LogMsg, TimePoint, TimeType, TimelineName, ViewerEvent};
 use re_smart_channel::{Receiver, Sender};
 
 use crate::{ConnectionError, VersionError};
@@ -59,6 +63,16 @@ pub fn serve(
     port: u16,
     options: ServerOptions,
 ) -> Result<Receiver<LogMsg>, ServerError> {
+    serve_with_viewer_events(bind_ip, port, options).map(|(rx, _viewer_event_sender)| rx)
+}
+
+/// Like [`serve`], but also returns a [`ViewerEventSender`] for sending [`ViewerEvent`]s
+/// back to the connected SDKs that asked for them.
+pub fn serve_with_viewer_events(
+    bind_ip: &str,
+    port: u16,
+    options: ServerOptions,
+) -> Result<(Receiver<LogMsg>, ViewerEventSender), ServerError> {
     let (tx, rx) = re_smart_channel::smart_channel(
         // NOTE: We don't know until we start actually accepting clients!
         re_smart_channel::SmartMessageSource::Unknown,
@@ -71,10 +85,15 @@ pub fn serve(
         err,
     })?;
 
+    let viewer_event_sender = ViewerEventSender::default();
+
     std::thread::Builder::new()
         .name("rerun_sdk_comms: listener".to_owned())
-        .spawn(move || {
-            listen_for_new_clients(&listener, options, &tx);
+        .spawn({
+            let viewer_event_sender = viewer_event_sender.clone();
+            move || {
+                listen_for_new_clients(&listener, options, &tx, &viewer_event_sender);
+            }
         })?;
 
     if options.quiet {
@@ -87,10 +106,93 @@ pub fn serve(
         );
     }
 
-    Ok(rx)
+    Ok((rx, viewer_event_sender))
+}
+
+/// Sends [`ViewerEvent`]s to all connected SDKs that asked for them.
+///
+/// Events are not buffered: clients only receive events that are sent while they are connected.
+#[derive(Clone, Default)]
+pub struct ViewerEventSender {
+    clients: Arc<Mutex<ahash::HashMap<u64, crossbeam::channel::Sender<Arc<Vec<u8>>>>>>,
+    next_client_id: Arc<AtomicU64>,
 }
 
-fn listen_for_new_clients(listener: &TcpListener, options: ServerOptions, tx: &Sender<LogMsg>) {
+impl ViewerEventSender {
+    /// How many events we queue up per client before we start dropping them.
+    const MAX_QUEUED_EVENTS: usize = 1024;
+
+    /// Sends the event to all connected clients that asked for viewer events.
+    ///
+    /// Never blocks: if a client can't keep up, the event is dropped for that client.
+    pub fn send(&self, event: &ViewerEvent) {
+        let clients = self.clients.lock().unwrap_or_else(|err| err.into_inner());
+        if clients.is_empty() {
+            return;
+        }
+
+        let packet = Arc::new(crate::viewer_events::encode(event));
+        for tx in clients.values() {
+            if tx.try_send(packet.clone()).is_err() {
+                re_log::warn_once!(
+                    "An SDK client is not reading viewer events fast enough - dropping events."
+                );
+            }
+        }
+    }
+
+    /// Start writing events to this client, until the returned guard is dropped.
+    fn add_client(&self, stream: TcpStream) -> std::io::Result<ViewerEventClientGuard> {
+        let (tx, rx) = crossbeam::channel::bounded::<Arc<Vec<u8>>>(Self::MAX_QUEUED_EVENTS);
+
+        std::thread::Builder::new()
+            .name("rerun_sdk_comms: viewer events".to_owned())
+            .spawn(move || {
+                let mut stream = stream;
+                // Ends once the guard is dropped, or the client is gone.
+                for packet in rx {
+                    if let Err(err) = stream.write_all(&packet) {
+                        re_log::debug!("Stopped sending viewer events to SDK client: {err}");
+                        break;
+                    }
+                }
+            })?;
+
+        let client_id = self.next_client_id.fetch_add(1, Ordering::Relaxed);
+        self.clients
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(client_id, tx);
+
+        Ok(ViewerEventClientGuard {
+            sender: self.clone(),
+            client_id,
+        })
+    }
+}
+
+/// Stops sending viewer events to a client when dropped.
+struct ViewerEventClientGuard {
+    sender: ViewerEventSender,
+    client_id: u64,
+}
+
+impl Drop for ViewerEventClientGuard {
+    fn drop(&mut self) {
+        self.sender
+            .clients
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&self.client_id);
+    }
+}
+
+fn listen_for_new_clients(
+    listener: &TcpListener,
+    options: ServerOptions,
+    tx: &Sender<LogMsg>,
+    viewer_event_sender: &ViewerEventSender,
+) {
     // TODO(emilk): some way of aborting this loop
     #[allow(clippy::infinite_loop)]
     loop {
@@ -98,11 +200,12 @@ fn listen_for_new_clients(listener: &TcpListener, options: ServerOptions, tx: &S
             Ok((stream, _)) => {
                 let addr = stream.peer_addr().ok();
                 let tx = tx.clone_as(re_smart_channel::SmartMessageSource::TcpClient { addr });
+                let viewer_event_sender = viewer_event_sender.clone();
 
                 std::thread::Builder::new()
                     .name("rerun_sdk_comms: client".to_owned())
                     .spawn(move || {
-                        spawn_client(stream, &tx, options, addr);
+                        spawn_client(stream, &tx, options, addr, &viewer_event_sender);
                     })
                     .ok();
             }
@@ -145,10 +248,11 @@ fn spawn_client(
     tx: &Sender<LogMsg>,
     options: ServerOptions,
     peer_addr: Option<std::net::SocketAddr>,
+    viewer_event_sender: &ViewerEventSender,
 ) {
     let addr_string = peer_addr.map_or_else(|| "(unknown ip)".to_owned(), |addr| addr.to_string());
 
-    if let Err(err) = run_client(stream, &addr_string, tx, options) {
+    if let Err(err) = run_client(stream, &addr_string, tx, options, viewer_event_sender) {
         if let ConnectionError::SendError(err) = &err {
             if err.kind() == ErrorKind::UnexpectedEof {
                 // Client gracefully severed the connection.
@@ -177,6 +281,7 @@ fn run_client(
     addr_string: &str,
     tx: &Sender<LogMsg>,
     options: ServerOptions,
+    viewer_event_sender: &ViewerEventSender,
 ) -> Result<(), ConnectionError> {
     #![allow(clippy::read_zero_byte_vec)] // false positive: https://github.com/rust-lang/rust-clippy/issues/9274
 
@@ -204,8 +309,10 @@ fn run_client(
             re_log::info!("New SDK client connected from: {addr_string}");
         }
 
-        let server_version = crate::PROTOCOL_VERSION_1;
+        // Version 2 is version 1 plus viewer events, so we support both.
+        let server_version = crate::PROTOCOL_VERSION_2;
         match client_version.cmp(&server_version) {
+            std::cmp::Ordering::Less if client_version == crate::PROTOCOL_VERSION_1 => {}
             std::cmp::Ordering::Less => {
                 return Err(ConnectionError::VersionError(VersionError::ClientIsOlder {
                     client_version,
@@ -222,6 +329,13 @@ fn run_client(
         }
     };
 
+    // Keep sending viewer events for as long as the client is connected.
+    let _viewer_event_client_guard = if client_version == crate::PROTOCOL_VERSION_2 {
+        Some(viewer_event_sender.add_client(stream.try_clone()?)?)
+    } else {
+        None
+    };
+
     let mut congestion_manager = CongestionManager::new(options.max_latency_sec);
 
     let mut packet = Vec::new();
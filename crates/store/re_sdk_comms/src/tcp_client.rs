@@ -66,14 +66,22 @@ pub struct TcpClient {
     addr: SocketAddr,
     stream_state: TcpStreamState,
     flush_timeout: Option<Duration>,
+
+    /// If anyone is subscribed when we connect, we ask the server for viewer events.
+    viewer_event_subscribers: crate::viewer_events::Subscribers,
 }
 
 impl TcpClient {
-    pub fn new(addr: SocketAddr, flush_timeout: Option<Duration>) -> Self {
+    pub fn new(
+        addr: SocketAddr,
+        flush_timeout: Option<Duration>,
+        viewer_event_subscribers: crate::viewer_events::Subscribers,
+    ) -> Self {
         Self {
             addr,
             stream_state: TcpStreamState::reset(),
             flush_timeout,
+            viewer_event_subscribers,
         }
     }
 
@@ -93,9 +101,25 @@ impl TcpClient {
                     Ok(mut stream) => {
                         re_log::debug!("Connected to {:?}.", self.addr);
 
+                        let wants_viewer_events = !self.viewer_event_subscribers.is_empty();
+                        let protocol_version = if wants_viewer_events {
+                            crate::PROTOCOL_VERSION_2
+                        } else {
+                            crate::PROTOCOL_VERSION_1
+                        };
+
                         if let Err(err) = stream
-                            .write(&crate::PROTOCOL_VERSION_1.to_le_bytes())
+                            .write(&protocol_version.to_le_bytes())
                             .and_then(|_| stream.write(crate::PROTOCOL_HEADER.as_bytes()))
+                            .and_then(|_| {
+                                if wants_viewer_events {
+                                    crate::viewer_events::spawn_reader(
+                                        stream.try_clone()?,
+                                        self.viewer_event_subscribers.clone(),
+                                    )?;
+                                }
+                                Ok(0)
+                            })
                         {
                             self.stream_state = TcpStreamState::Pending {
                                 start_time,
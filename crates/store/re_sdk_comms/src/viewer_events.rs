@@ -0,0 +1,112 @@
+//! Encoding of the [`ViewerEvent`]s that a server sends back to clients using [`crate::PROTOCOL_VERSION_2`].
+//!
+//! Each event is sent as its own packet, prefixed with its length, just like log messages.
+
+use re_log_types::ViewerEvent;
+
+#[cfg(feature = "server")]
+pub fn encode(event: &ViewerEvent) -> Vec<u8> {
+    use bincode::Options as _;
+
+    let mut packet = vec![0_u8; 4];
+    #[allow(clippy::unwrap_used)] // Serializing into a `Vec` can't fail.
+    bincode::DefaultOptions::new()
+        .serialize_into(&mut packet, event)
+        .unwrap();
+
+    let payload_size = (packet.len() - 4) as u32;
+    packet[..4].copy_from_slice(&payload_size.to_le_bytes());
+    packet
+}
+
+#[cfg(feature = "client")]
+pub fn decode(payload: &[u8]) -> Result<ViewerEvent, bincode::Error> {
+    use bincode::Options as _;
+    bincode::DefaultOptions::new().deserialize(payload)
+}
+
+/// Everyone who subscribed to viewer events via [`crate::Client::viewer_events`].
+#[cfg(feature = "client")]
+#[derive(Clone, Default)]
+pub struct Subscribers(std::sync::Arc<std::sync::Mutex<Vec<std::sync::mpsc::Sender<ViewerEvent>>>>);
+
+#[cfg(feature = "client")]
+impl Subscribers {
+    pub fn subscribe(&self) -> std::sync::mpsc::Receiver<ViewerEvent> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.lock().push(tx);
+        rx
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn publish(&self, event: &ViewerEvent) {
+        // Forget about subscribers that dropped their receiver.
+        self.lock().retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<std::sync::mpsc::Sender<ViewerEvent>>> {
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Reads viewer events from the server on a background thread, until the connection is closed.
+#[cfg(feature = "client")]
+pub fn spawn_reader(
+    mut stream: std::net::TcpStream,
+    subscribers: Subscribers,
+) -> std::io::Result<()> {
+    use std::io::Read as _;
+
+    std::thread::Builder::new()
+        .name("rerun_sdk_comms: viewer event reader".to_owned())
+        .spawn(move || {
+            let mut payload = Vec::new();
+            loop {
+                let mut payload_size = [0_u8; 4];
+                if stream.read_exact(&mut payload_size).is_err() {
+                    break; // The connection was closed.
+                }
+                payload.resize(u32::from_le_bytes(payload_size) as usize, 0_u8);
+                if stream.read_exact(&mut payload).is_err() {
+                    break;
+                }
+
+                match decode(&payload) {
+                    Ok(event) => subscribers.publish(&event),
+                    Err(err) => {
+                        re_log::warn_once!("Failed to decode viewer event: {err}");
+                        break;
+                    }
+                }
+            }
+            re_log::debug!("Stopped reading viewer events.");
+        })?;
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "client", feature = "server"))]
+mod tests {
+    use re_log_types::{StoreId, StoreKind, ViewerEventItem, ViewerEventKind};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let event = ViewerEvent {
+            store_id: StoreId::random(StoreKind::Recording),
+            kind: ViewerEventKind::ButtonPress {
+                button: "Label this".to_owned(),
+                items: vec![ViewerEventItem::from(re_log_types::EntityPath::from("a/b"))],
+            },
+        };
+
+        let packet = encode(&event);
+        let payload_size = u32::from_le_bytes(packet[..4].try_into().unwrap());
+        assert_eq!(payload_size as usize, packet.len() - 4);
+        assert_eq!(decode(&packet[4..]).unwrap(), event);
+    }
+}
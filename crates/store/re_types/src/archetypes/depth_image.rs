@@ -112,6 +112,17 @@ pub struct DepthImage {
     ///
     /// Objects with higher values are drawn on top of those with lower values.
     pub draw_order: Option<SerializedComponentBatch>,
+
+    /// An optional path to a color image that is registered with this depth image.
+    ///
+    /// If set, the points of the point cloud generated from this image are colored by projecting them
+    /// into the referenced [`archetypes::Image`][crate::archetypes::Image] or [`archetypes::EncodedImage`][crate::archetypes::EncodedImage] instead of using the [`components::Colormap`][crate::components::Colormap].
+    /// If the color image is logged under a different [`archetypes::Pinhole`][crate::archetypes::Pinhole] than the depth image,
+    /// the intrinsics of that camera and the transform between the two cameras are taken into account.
+    /// Points that aren't seen by the color camera fall back to the colormap.
+    ///
+    /// TODO(#6744): This applies only to 3D views!
+    pub color_image_reference: Option<SerializedComponentBatch>,
 }
 
 impl DepthImage {
@@ -185,6 +196,16 @@ impl DepthImage {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::color_image_reference`].
+    #[inline]
+    pub fn descriptor_color_image_reference() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.DepthImage".into()),
+            component_name: "rerun.components.EntityPath".into(),
+            archetype_field_name: Some("color_image_reference".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for the associated indicator component.
     #[inline]
     pub fn descriptor_indicator() -> ComponentDescriptor {
@@ -207,7 +228,7 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
     once_cell::sync::Lazy::new(|| [DepthImage::descriptor_indicator()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 6usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             DepthImage::descriptor_meter(),
@@ -215,10 +236,11 @@ static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]>
             DepthImage::descriptor_depth_range(),
             DepthImage::descriptor_point_fill_ratio(),
             DepthImage::descriptor_draw_order(),
+            DepthImage::descriptor_color_image_reference(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 9usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             DepthImage::descriptor_buffer(),
@@ -229,12 +251,13 @@ static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
             DepthImage::descriptor_depth_range(),
             DepthImage::descriptor_point_fill_ratio(),
             DepthImage::descriptor_draw_order(),
+            DepthImage::descriptor_color_image_reference(),
         ]
     });
 
 impl DepthImage {
-    /// The total number of components in the archetype: 2 required, 1 recommended, 5 optional
-    pub const NUM_COMPONENTS: usize = 8usize;
+    /// The total number of components in the archetype: 2 required, 1 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 9usize;
 }
 
 /// Indicator component for the [`DepthImage`] [`::re_types_core::Archetype`]
@@ -313,6 +336,14 @@ impl ::re_types_core::Archetype for DepthImage {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_draw_order())
             });
+        let color_image_reference = arrays_by_descr
+            .get(&Self::descriptor_color_image_reference())
+            .map(|array| {
+                SerializedComponentBatch::new(
+                    array.clone(),
+                    Self::descriptor_color_image_reference(),
+                )
+            });
         Ok(Self {
             buffer,
             format,
@@ -321,6 +352,7 @@ impl ::re_types_core::Archetype for DepthImage {
             depth_range,
             point_fill_ratio,
             draw_order,
+            color_image_reference,
         })
     }
 }
@@ -338,6 +370,7 @@ impl ::re_types_core::AsComponents for DepthImage {
             self.depth_range.clone(),
             self.point_fill_ratio.clone(),
             self.draw_order.clone(),
+            self.color_image_reference.clone(),
         ]
         .into_iter()
         .flatten()
@@ -362,6 +395,7 @@ impl DepthImage {
             depth_range: None,
             point_fill_ratio: None,
             draw_order: None,
+            color_image_reference: None,
         }
     }
 
@@ -404,6 +438,10 @@ impl DepthImage {
                 crate::components::DrawOrder::arrow_empty(),
                 Self::descriptor_draw_order(),
             )),
+            color_image_reference: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_color_image_reference(),
+            )),
         }
     }
 
@@ -447,6 +485,9 @@ impl DepthImage {
             self.draw_order
                 .map(|draw_order| draw_order.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.color_image_reference
+                .map(|color_image_reference| color_image_reference.partitioned(_lengths.clone()))
+                .transpose()?,
         ];
         Ok(columns
             .into_iter()
@@ -471,6 +512,7 @@ impl DepthImage {
         let len_depth_range = self.depth_range.as_ref().map(|b| b.array.len());
         let len_point_fill_ratio = self.point_fill_ratio.as_ref().map(|b| b.array.len());
         let len_draw_order = self.draw_order.as_ref().map(|b| b.array.len());
+        let len_color_image_reference = self.color_image_reference.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_buffer)
             .or(len_format)
@@ -479,6 +521,7 @@ impl DepthImage {
             .or(len_depth_range)
             .or(len_point_fill_ratio)
             .or(len_draw_order)
+            .or(len_color_image_reference)
             .unwrap_or(0);
         self.columns(std::iter::repeat(1).take(len))
     }
@@ -656,6 +699,43 @@ impl DepthImage {
         self.draw_order = try_serialize_field(Self::descriptor_draw_order(), draw_order);
         self
     }
+
+    /// An optional path to a color image that is registered with this depth image.
+    ///
+    /// If set, the points of the point cloud generated from this image are colored by projecting them
+    /// into the referenced [`archetypes::Image`][crate::archetypes::Image] or [`archetypes::EncodedImage`][crate::archetypes::EncodedImage] instead of using the [`components::Colormap`][crate::components::Colormap].
+    /// If the color image is logged under a different [`archetypes::Pinhole`][crate::archetypes::Pinhole] than the depth image,
+    /// the intrinsics of that camera and the transform between the two cameras are taken into account.
+    /// Points that aren't seen by the color camera fall back to the colormap.
+    ///
+    /// TODO(#6744): This applies only to 3D views!
+    #[inline]
+    pub fn with_color_image_reference(
+        mut self,
+        color_image_reference: impl Into<crate::components::EntityPath>,
+    ) -> Self {
+        self.color_image_reference = try_serialize_field(
+            Self::descriptor_color_image_reference(),
+            [color_image_reference],
+        );
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::EntityPath`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_color_image_reference`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_color_image_reference(
+        mut self,
+        color_image_reference: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        self.color_image_reference = try_serialize_field(
+            Self::descriptor_color_image_reference(),
+            color_image_reference,
+        );
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for DepthImage {
@@ -668,5 +748,6 @@ impl ::re_byte_size::SizeBytes for DepthImage {
             + self.depth_range.heap_size_bytes()
             + self.point_fill_ratio.heap_size_bytes()
             + self.draw_order.heap_size_bytes()
+            + self.color_image_reference.heap_size_bytes()
     }
 }
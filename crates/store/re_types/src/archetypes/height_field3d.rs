@@ -0,0 +1,657 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/height_field3d.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A surface spanned over a regular grid of heights, e.g. a terrain or an elevation map.
+///
+/// The heights are stored like a single-channel image: height `[x, y]` is placed at
+/// `origin + [x, y] * cell_size` in the XY plane of the entity's local coordinate system,
+/// and the surface extends from there along +Z.
+/// Non-finite heights (e.g. NaN) leave holes in the surface.
+///
+/// The surface is shaded and colored either with a single [`components::Color`][crate::components::Color], by height through a
+/// [`components::Colormap`][crate::components::Colormap], or with an image draped over it via [`components::EntityPath`][crate::components::EntityPath].
+/// Logging a new height map every frame is cheap since only the heights are uploaded to the GPU.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct HeightField3D {
+    /// The heights, row by row.
+    pub buffer: Option<SerializedComponentBatch>,
+
+    /// The format of the heights.
+    ///
+    /// Must be single-channel.
+    pub format: Option<SerializedComponentBatch>,
+
+    /// The distance between neighboring heights along each axis.
+    ///
+    /// If not specified, heights are one unit apart.
+    pub cell_size: Option<SerializedComponentBatch>,
+
+    /// The position of height `[0, 0]` in the XY plane.
+    ///
+    /// If not specified, the grid starts at the origin.
+    pub origin: Option<SerializedComponentBatch>,
+
+    /// Color of the surface, if neither a colormap nor a texture is used.
+    pub color: Option<SerializedComponentBatch>,
+
+    /// If set, the surface is colored by height using this colormap.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// The range of heights that is mapped to the colormap.
+    ///
+    /// If not specified, the range of the heights is used.
+    pub value_range: Option<SerializedComponentBatch>,
+
+    /// Path to an [`archetypes::Image`][crate::archetypes::Image] or [`archetypes::EncodedImage`][crate::archetypes::EncodedImage] that is stretched over the entire surface.
+    ///
+    /// Takes precedence over the color and the colormap.
+    /// The image has to be logged to a different entity than the height field.
+    pub texture_reference: Option<SerializedComponentBatch>,
+}
+
+impl HeightField3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::buffer`].
+    #[inline]
+    pub fn descriptor_buffer() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.ImageBuffer".into(),
+            archetype_field_name: Some("buffer".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::format`].
+    #[inline]
+    pub fn descriptor_format() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.ImageFormat".into(),
+            archetype_field_name: Some("format".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::cell_size`].
+    #[inline]
+    pub fn descriptor_cell_size() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.CellSize".into(),
+            archetype_field_name: Some("cell_size".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::origin`].
+    #[inline]
+    pub fn descriptor_origin() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.Position2D".into(),
+            archetype_field_name: Some("origin".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::color`].
+    #[inline]
+    pub fn descriptor_color() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.Color".into(),
+            archetype_field_name: Some("color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.Colormap".into(),
+            archetype_field_name: Some("colormap".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::value_range`].
+    #[inline]
+    pub fn descriptor_value_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.ValueRange".into(),
+            archetype_field_name: Some("value_range".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::texture_reference`].
+    #[inline]
+    pub fn descriptor_texture_reference() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.EntityPath".into(),
+            archetype_field_name: Some("texture_reference".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.HeightField3D".into()),
+            component_name: "rerun.components.HeightField3DIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            HeightField3D::descriptor_buffer(),
+            HeightField3D::descriptor_format(),
+        ]
+    });
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [HeightField3D::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 6usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            HeightField3D::descriptor_cell_size(),
+            HeightField3D::descriptor_origin(),
+            HeightField3D::descriptor_color(),
+            HeightField3D::descriptor_colormap(),
+            HeightField3D::descriptor_value_range(),
+            HeightField3D::descriptor_texture_reference(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 9usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            HeightField3D::descriptor_buffer(),
+            HeightField3D::descriptor_format(),
+            HeightField3D::descriptor_indicator(),
+            HeightField3D::descriptor_cell_size(),
+            HeightField3D::descriptor_origin(),
+            HeightField3D::descriptor_color(),
+            HeightField3D::descriptor_colormap(),
+            HeightField3D::descriptor_value_range(),
+            HeightField3D::descriptor_texture_reference(),
+        ]
+    });
+
+impl HeightField3D {
+    /// The total number of components in the archetype: 2 required, 1 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 9usize;
+}
+
+/// Indicator component for the [`HeightField3D`] [`::re_types_core::Archetype`]
+pub type HeightField3DIndicator = ::re_types_core::GenericIndicatorComponent<HeightField3D>;
+
+impl ::re_types_core::Archetype for HeightField3D {
+    type Indicator = HeightField3DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.HeightField3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Height field 3D"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        HeightField3DIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let buffer = arrays_by_descr
+            .get(&Self::descriptor_buffer())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_buffer()));
+        let format = arrays_by_descr
+            .get(&Self::descriptor_format())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_format()));
+        let cell_size = arrays_by_descr
+            .get(&Self::descriptor_cell_size())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_cell_size())
+            });
+        let origin = arrays_by_descr
+            .get(&Self::descriptor_origin())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_origin()));
+        let color = arrays_by_descr
+            .get(&Self::descriptor_color())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_color()));
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let value_range = arrays_by_descr
+            .get(&Self::descriptor_value_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_value_range())
+            });
+        let texture_reference = arrays_by_descr
+            .get(&Self::descriptor_texture_reference())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_texture_reference())
+            });
+        Ok(Self {
+            buffer,
+            format,
+            cell_size,
+            origin,
+            color,
+            colormap,
+            value_range,
+            texture_reference,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for HeightField3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.buffer.clone(),
+            self.format.clone(),
+            self.cell_size.clone(),
+            self.origin.clone(),
+            self.color.clone(),
+            self.colormap.clone(),
+            self.value_range.clone(),
+            self.texture_reference.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for HeightField3D {}
+
+impl HeightField3D {
+    /// Create a new `HeightField3D`.
+    #[inline]
+    pub fn new(
+        buffer: impl Into<crate::components::ImageBuffer>,
+        format: impl Into<crate::components::ImageFormat>,
+    ) -> Self {
+        Self {
+            buffer: try_serialize_field(Self::descriptor_buffer(), [buffer]),
+            format: try_serialize_field(Self::descriptor_format(), [format]),
+            cell_size: None,
+            origin: None,
+            color: None,
+            colormap: None,
+            value_range: None,
+            texture_reference: None,
+        }
+    }
+
+    /// Update only some specific fields of a `HeightField3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `HeightField3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            buffer: Some(SerializedComponentBatch::new(
+                crate::components::ImageBuffer::arrow_empty(),
+                Self::descriptor_buffer(),
+            )),
+            format: Some(SerializedComponentBatch::new(
+                crate::components::ImageFormat::arrow_empty(),
+                Self::descriptor_format(),
+            )),
+            cell_size: Some(SerializedComponentBatch::new(
+                crate::components::CellSize::arrow_empty(),
+                Self::descriptor_cell_size(),
+            )),
+            origin: Some(SerializedComponentBatch::new(
+                crate::components::Position2D::arrow_empty(),
+                Self::descriptor_origin(),
+            )),
+            color: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_color(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            value_range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_value_range(),
+            )),
+            texture_reference: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_texture_reference(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.buffer
+                .map(|buffer| buffer.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.format
+                .map(|format| format.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.cell_size
+                .map(|cell_size| cell_size.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.origin
+                .map(|origin| origin.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.color
+                .map(|color| color.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colormap
+                .map(|colormap| colormap.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.value_range
+                .map(|value_range| value_range.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.texture_reference
+                .map(|texture_reference| texture_reference.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_buffer = self.buffer.as_ref().map(|b| b.array.len());
+        let len_format = self.format.as_ref().map(|b| b.array.len());
+        let len_cell_size = self.cell_size.as_ref().map(|b| b.array.len());
+        let len_origin = self.origin.as_ref().map(|b| b.array.len());
+        let len_color = self.color.as_ref().map(|b| b.array.len());
+        let len_colormap = self.colormap.as_ref().map(|b| b.array.len());
+        let len_value_range = self.value_range.as_ref().map(|b| b.array.len());
+        let len_texture_reference = self.texture_reference.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_buffer)
+            .or(len_format)
+            .or(len_cell_size)
+            .or(len_origin)
+            .or(len_color)
+            .or(len_colormap)
+            .or(len_value_range)
+            .or(len_texture_reference)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The heights, row by row.
+    #[inline]
+    pub fn with_buffer(mut self, buffer: impl Into<crate::components::ImageBuffer>) -> Self {
+        self.buffer = try_serialize_field(Self::descriptor_buffer(), [buffer]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ImageBuffer`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_buffer`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_buffer(
+        mut self,
+        buffer: impl IntoIterator<Item = impl Into<crate::components::ImageBuffer>>,
+    ) -> Self {
+        self.buffer = try_serialize_field(Self::descriptor_buffer(), buffer);
+        self
+    }
+
+    /// The format of the heights.
+    ///
+    /// Must be single-channel.
+    #[inline]
+    pub fn with_format(mut self, format: impl Into<crate::components::ImageFormat>) -> Self {
+        self.format = try_serialize_field(Self::descriptor_format(), [format]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ImageFormat`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_format`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_format(
+        mut self,
+        format: impl IntoIterator<Item = impl Into<crate::components::ImageFormat>>,
+    ) -> Self {
+        self.format = try_serialize_field(Self::descriptor_format(), format);
+        self
+    }
+
+    /// The distance between neighboring heights along each axis.
+    ///
+    /// If not specified, heights are one unit apart.
+    #[inline]
+    pub fn with_cell_size(mut self, cell_size: impl Into<crate::components::CellSize>) -> Self {
+        self.cell_size = try_serialize_field(Self::descriptor_cell_size(), [cell_size]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::CellSize`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_cell_size`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_cell_size(
+        mut self,
+        cell_size: impl IntoIterator<Item = impl Into<crate::components::CellSize>>,
+    ) -> Self {
+        self.cell_size = try_serialize_field(Self::descriptor_cell_size(), cell_size);
+        self
+    }
+
+    /// The position of height `[0, 0]` in the XY plane.
+    ///
+    /// If not specified, the grid starts at the origin.
+    #[inline]
+    pub fn with_origin(mut self, origin: impl Into<crate::components::Position2D>) -> Self {
+        self.origin = try_serialize_field(Self::descriptor_origin(), [origin]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Position2D`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_origin`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_origin(
+        mut self,
+        origin: impl IntoIterator<Item = impl Into<crate::components::Position2D>>,
+    ) -> Self {
+        self.origin = try_serialize_field(Self::descriptor_origin(), origin);
+        self
+    }
+
+    /// Color of the surface, if neither a colormap nor a texture is used.
+    #[inline]
+    pub fn with_color(mut self, color: impl Into<crate::components::Color>) -> Self {
+        self.color = try_serialize_field(Self::descriptor_color(), [color]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Color`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_color`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_color(
+        mut self,
+        color: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.color = try_serialize_field(Self::descriptor_color(), color);
+        self
+    }
+
+    /// If set, the surface is colored by height using this colormap.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Colormap`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_colormap`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_colormap(
+        mut self,
+        colormap: impl IntoIterator<Item = impl Into<crate::components::Colormap>>,
+    ) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), colormap);
+        self
+    }
+
+    /// The range of heights that is mapped to the colormap.
+    ///
+    /// If not specified, the range of the heights is used.
+    #[inline]
+    pub fn with_value_range(
+        mut self,
+        value_range: impl Into<crate::components::ValueRange>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), [value_range]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ValueRange`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_value_range`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_value_range(
+        mut self,
+        value_range: impl IntoIterator<Item = impl Into<crate::components::ValueRange>>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), value_range);
+        self
+    }
+
+    /// Path to an [`archetypes::Image`][crate::archetypes::Image] or [`archetypes::EncodedImage`][crate::archetypes::EncodedImage] that is stretched over the entire surface.
+    ///
+    /// Takes precedence over the color and the colormap.
+    /// The image has to be logged to a different entity than the height field.
+    #[inline]
+    pub fn with_texture_reference(
+        mut self,
+        texture_reference: impl Into<crate::components::EntityPath>,
+    ) -> Self {
+        self.texture_reference =
+            try_serialize_field(Self::descriptor_texture_reference(), [texture_reference]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::EntityPath`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_texture_reference`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_texture_reference(
+        mut self,
+        texture_reference: impl IntoIterator<Item = impl Into<crate::components::EntityPath>>,
+    ) -> Self {
+        self.texture_reference =
+            try_serialize_field(Self::descriptor_texture_reference(), texture_reference);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for HeightField3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.buffer.heap_size_bytes()
+            + self.format.heap_size_bytes()
+            + self.cell_size.heap_size_bytes()
+            + self.origin.heap_size_bytes()
+            + self.color.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.value_range.heap_size_bytes()
+            + self.texture_reference.heap_size_bytes()
+    }
+}
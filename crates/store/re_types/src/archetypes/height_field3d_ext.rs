@@ -0,0 +1,37 @@
+use crate::{
+    components::{ImageBuffer, ImageFormat},
+    datatypes::{ChannelDatatype, ColorModel},
+};
+
+use super::HeightField3D;
+
+impl HeightField3D {
+    /// Construct a height field from a byte buffer given its size in heights, and data type.
+    pub fn from_data_type_and_bytes(
+        bytes: impl Into<ImageBuffer>,
+        [width, height]: [u32; 2],
+        datatype: ChannelDatatype,
+    ) -> Self {
+        let buffer = bytes.into();
+
+        let format = ImageFormat::from_color_model([width, height], ColorModel::L, datatype);
+
+        let num_expected_bytes = format.num_bytes();
+        if buffer.len() != num_expected_bytes {
+            re_log::warn_once!(
+                "Expected {width}x{height} {datatype:?} height field to be {num_expected_bytes} B, but got {} B", buffer.len()
+            );
+        }
+
+        Self::new(buffer, format)
+    }
+
+    /// From `width * height` heights, row by row.
+    pub fn from_heights(heights: &[f32], size: [u32; 2]) -> Self {
+        Self::from_data_type_and_bytes(
+            bytemuck::cast_slice::<f32, u8>(heights).to_vec(),
+            size,
+            ChannelDatatype::F32,
+        )
+    }
+}
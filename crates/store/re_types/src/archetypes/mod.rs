@@ -29,6 +29,8 @@ mod geo_points_ext;
 mod graph_edges;
 mod graph_edges_ext;
 mod graph_nodes;
+mod height_field3d;
+mod height_field3d_ext;
 mod image;
 mod image_ext;
 mod instance_poses3d;
@@ -36,6 +38,8 @@ mod line_strips2d;
 mod line_strips3d;
 mod mesh3d;
 mod mesh3d_ext;
+mod occupancy_grid;
+mod occupancy_grid_ext;
 mod pinhole;
 mod pinhole_ext;
 mod points2d;
@@ -51,11 +55,14 @@ mod tensor_ext;
 mod text_document;
 mod text_document_ext;
 mod text_log;
+mod timeline_event;
 mod transform3d;
 mod transform3d_ext;
 mod video_frame_reference;
 mod view_coordinates;
 mod view_coordinates_ext;
+mod voxel_grid3d;
+mod voxel_grid3d_ext;
 
 pub use self::annotation_context::AnnotationContext;
 pub use self::arrows2d::Arrows2D;
@@ -73,11 +80,13 @@ pub use self::geo_line_strings::GeoLineStrings;
 pub use self::geo_points::GeoPoints;
 pub use self::graph_edges::GraphEdges;
 pub use self::graph_nodes::GraphNodes;
+pub use self::height_field3d::HeightField3D;
 pub use self::image::Image;
 pub use self::instance_poses3d::InstancePoses3D;
 pub use self::line_strips2d::LineStrips2D;
 pub use self::line_strips3d::LineStrips3D;
 pub use self::mesh3d::Mesh3D;
+pub use self::occupancy_grid::OccupancyGrid;
 pub use self::pinhole::Pinhole;
 pub use self::points2d::Points2D;
 pub use self::points3d::Points3D;
@@ -88,6 +97,8 @@ pub use self::series_point::SeriesPoint;
 pub use self::tensor::Tensor;
 pub use self::text_document::TextDocument;
 pub use self::text_log::TextLog;
+pub use self::timeline_event::TimelineEvent;
 pub use self::transform3d::Transform3D;
 pub use self::video_frame_reference::VideoFrameReference;
 pub use self::view_coordinates::ViewCoordinates;
+pub use self::voxel_grid3d::VoxelGrid3D;
@@ -0,0 +1,658 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/occupancy_grid.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A 2D occupancy grid, e.g. a map built by a robot.
+///
+/// Each cell holds a single value, typically the probability of the cell being occupied.
+/// Cell `[x, y]` spans from `origin + [x, y] * cell_size` to `origin + [x + 1, y + 1] * cell_size`
+/// in the local coordinate system of the entity. In 3D views the grid lies in the XY plane.
+///
+/// The cell values are stored like a single-channel image, e.g. an `I8` image following the ROS convention
+/// of probabilities from 0 to 100, and are mapped to colors through the [`components::Colormap`][crate::components::Colormap].
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct OccupancyGrid {
+    /// The raw cell values, row by row.
+    pub buffer: Option<SerializedComponentBatch>,
+
+    /// The format of the cell values.
+    ///
+    /// Must be single-channel.
+    pub format: Option<SerializedComponentBatch>,
+
+    /// The size of a single cell along each axis.
+    ///
+    /// If not specified, cells are one unit large.
+    pub cell_size: Option<SerializedComponentBatch>,
+
+    /// The position of the corner of cell `[0, 0]`.
+    ///
+    /// If not specified, the grid starts at the origin.
+    pub origin: Option<SerializedComponentBatch>,
+
+    /// Colormap to use for mapping cell values to colors.
+    ///
+    /// If not set, the grayscale colormap is used.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// The range of cell values that is mapped to the colormap.
+    ///
+    /// If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+    /// with the default colormap.
+    pub value_range: Option<SerializedComponentBatch>,
+
+    /// Opacity of the grid, useful for layering it over other content.
+    ///
+    /// Defaults to 1.0 (fully opaque).
+    pub opacity: Option<SerializedComponentBatch>,
+
+    /// An optional floating point value that specifies the 2D drawing order.
+    ///
+    /// Objects with higher values are drawn on top of those with lower values.
+    pub draw_order: Option<SerializedComponentBatch>,
+}
+
+impl OccupancyGrid {
+    /// Returns the [`ComponentDescriptor`] for [`Self::buffer`].
+    #[inline]
+    pub fn descriptor_buffer() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.ImageBuffer".into(),
+            archetype_field_name: Some("buffer".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::format`].
+    #[inline]
+    pub fn descriptor_format() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.ImageFormat".into(),
+            archetype_field_name: Some("format".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::cell_size`].
+    #[inline]
+    pub fn descriptor_cell_size() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.CellSize".into(),
+            archetype_field_name: Some("cell_size".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::origin`].
+    #[inline]
+    pub fn descriptor_origin() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.Position2D".into(),
+            archetype_field_name: Some("origin".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.Colormap".into(),
+            archetype_field_name: Some("colormap".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::value_range`].
+    #[inline]
+    pub fn descriptor_value_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.ValueRange".into(),
+            archetype_field_name: Some("value_range".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacity`].
+    #[inline]
+    pub fn descriptor_opacity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.Opacity".into(),
+            archetype_field_name: Some("opacity".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::draw_order`].
+    #[inline]
+    pub fn descriptor_draw_order() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.DrawOrder".into(),
+            archetype_field_name: Some("draw_order".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.OccupancyGrid".into()),
+            component_name: "rerun.components.OccupancyGridIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            OccupancyGrid::descriptor_buffer(),
+            OccupancyGrid::descriptor_format(),
+        ]
+    });
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [OccupancyGrid::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 6usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            OccupancyGrid::descriptor_cell_size(),
+            OccupancyGrid::descriptor_origin(),
+            OccupancyGrid::descriptor_colormap(),
+            OccupancyGrid::descriptor_value_range(),
+            OccupancyGrid::descriptor_opacity(),
+            OccupancyGrid::descriptor_draw_order(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 9usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            OccupancyGrid::descriptor_buffer(),
+            OccupancyGrid::descriptor_format(),
+            OccupancyGrid::descriptor_indicator(),
+            OccupancyGrid::descriptor_cell_size(),
+            OccupancyGrid::descriptor_origin(),
+            OccupancyGrid::descriptor_colormap(),
+            OccupancyGrid::descriptor_value_range(),
+            OccupancyGrid::descriptor_opacity(),
+            OccupancyGrid::descriptor_draw_order(),
+        ]
+    });
+
+impl OccupancyGrid {
+    /// The total number of components in the archetype: 2 required, 1 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 9usize;
+}
+
+/// Indicator component for the [`OccupancyGrid`] [`::re_types_core::Archetype`]
+pub type OccupancyGridIndicator = ::re_types_core::GenericIndicatorComponent<OccupancyGrid>;
+
+impl ::re_types_core::Archetype for OccupancyGrid {
+    type Indicator = OccupancyGridIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.OccupancyGrid".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Occupancy grid"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        OccupancyGridIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let buffer = arrays_by_descr
+            .get(&Self::descriptor_buffer())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_buffer()));
+        let format = arrays_by_descr
+            .get(&Self::descriptor_format())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_format()));
+        let cell_size = arrays_by_descr
+            .get(&Self::descriptor_cell_size())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_cell_size())
+            });
+        let origin = arrays_by_descr
+            .get(&Self::descriptor_origin())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_origin()));
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let value_range = arrays_by_descr
+            .get(&Self::descriptor_value_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_value_range())
+            });
+        let opacity = arrays_by_descr
+            .get(&Self::descriptor_opacity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_opacity()));
+        let draw_order = arrays_by_descr
+            .get(&Self::descriptor_draw_order())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_draw_order())
+            });
+        Ok(Self {
+            buffer,
+            format,
+            cell_size,
+            origin,
+            colormap,
+            value_range,
+            opacity,
+            draw_order,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for OccupancyGrid {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.buffer.clone(),
+            self.format.clone(),
+            self.cell_size.clone(),
+            self.origin.clone(),
+            self.colormap.clone(),
+            self.value_range.clone(),
+            self.opacity.clone(),
+            self.draw_order.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for OccupancyGrid {}
+
+impl OccupancyGrid {
+    /// Create a new `OccupancyGrid`.
+    #[inline]
+    pub fn new(
+        buffer: impl Into<crate::components::ImageBuffer>,
+        format: impl Into<crate::components::ImageFormat>,
+    ) -> Self {
+        Self {
+            buffer: try_serialize_field(Self::descriptor_buffer(), [buffer]),
+            format: try_serialize_field(Self::descriptor_format(), [format]),
+            cell_size: None,
+            origin: None,
+            colormap: None,
+            value_range: None,
+            opacity: None,
+            draw_order: None,
+        }
+    }
+
+    /// Update only some specific fields of a `OccupancyGrid`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `OccupancyGrid`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            buffer: Some(SerializedComponentBatch::new(
+                crate::components::ImageBuffer::arrow_empty(),
+                Self::descriptor_buffer(),
+            )),
+            format: Some(SerializedComponentBatch::new(
+                crate::components::ImageFormat::arrow_empty(),
+                Self::descriptor_format(),
+            )),
+            cell_size: Some(SerializedComponentBatch::new(
+                crate::components::CellSize::arrow_empty(),
+                Self::descriptor_cell_size(),
+            )),
+            origin: Some(SerializedComponentBatch::new(
+                crate::components::Position2D::arrow_empty(),
+                Self::descriptor_origin(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            value_range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_value_range(),
+            )),
+            opacity: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacity(),
+            )),
+            draw_order: Some(SerializedComponentBatch::new(
+                crate::components::DrawOrder::arrow_empty(),
+                Self::descriptor_draw_order(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.buffer
+                .map(|buffer| buffer.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.format
+                .map(|format| format.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.cell_size
+                .map(|cell_size| cell_size.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.origin
+                .map(|origin| origin.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colormap
+                .map(|colormap| colormap.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.value_range
+                .map(|value_range| value_range.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.opacity
+                .map(|opacity| opacity.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.draw_order
+                .map(|draw_order| draw_order.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_buffer = self.buffer.as_ref().map(|b| b.array.len());
+        let len_format = self.format.as_ref().map(|b| b.array.len());
+        let len_cell_size = self.cell_size.as_ref().map(|b| b.array.len());
+        let len_origin = self.origin.as_ref().map(|b| b.array.len());
+        let len_colormap = self.colormap.as_ref().map(|b| b.array.len());
+        let len_value_range = self.value_range.as_ref().map(|b| b.array.len());
+        let len_opacity = self.opacity.as_ref().map(|b| b.array.len());
+        let len_draw_order = self.draw_order.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_buffer)
+            .or(len_format)
+            .or(len_cell_size)
+            .or(len_origin)
+            .or(len_colormap)
+            .or(len_value_range)
+            .or(len_opacity)
+            .or(len_draw_order)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The raw cell values, row by row.
+    #[inline]
+    pub fn with_buffer(mut self, buffer: impl Into<crate::components::ImageBuffer>) -> Self {
+        self.buffer = try_serialize_field(Self::descriptor_buffer(), [buffer]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ImageBuffer`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_buffer`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_buffer(
+        mut self,
+        buffer: impl IntoIterator<Item = impl Into<crate::components::ImageBuffer>>,
+    ) -> Self {
+        self.buffer = try_serialize_field(Self::descriptor_buffer(), buffer);
+        self
+    }
+
+    /// The format of the cell values.
+    ///
+    /// Must be single-channel.
+    #[inline]
+    pub fn with_format(mut self, format: impl Into<crate::components::ImageFormat>) -> Self {
+        self.format = try_serialize_field(Self::descriptor_format(), [format]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ImageFormat`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_format`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_format(
+        mut self,
+        format: impl IntoIterator<Item = impl Into<crate::components::ImageFormat>>,
+    ) -> Self {
+        self.format = try_serialize_field(Self::descriptor_format(), format);
+        self
+    }
+
+    /// The size of a single cell along each axis.
+    ///
+    /// If not specified, cells are one unit large.
+    #[inline]
+    pub fn with_cell_size(mut self, cell_size: impl Into<crate::components::CellSize>) -> Self {
+        self.cell_size = try_serialize_field(Self::descriptor_cell_size(), [cell_size]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::CellSize`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_cell_size`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_cell_size(
+        mut self,
+        cell_size: impl IntoIterator<Item = impl Into<crate::components::CellSize>>,
+    ) -> Self {
+        self.cell_size = try_serialize_field(Self::descriptor_cell_size(), cell_size);
+        self
+    }
+
+    /// The position of the corner of cell `[0, 0]`.
+    ///
+    /// If not specified, the grid starts at the origin.
+    #[inline]
+    pub fn with_origin(mut self, origin: impl Into<crate::components::Position2D>) -> Self {
+        self.origin = try_serialize_field(Self::descriptor_origin(), [origin]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Position2D`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_origin`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_origin(
+        mut self,
+        origin: impl IntoIterator<Item = impl Into<crate::components::Position2D>>,
+    ) -> Self {
+        self.origin = try_serialize_field(Self::descriptor_origin(), origin);
+        self
+    }
+
+    /// Colormap to use for mapping cell values to colors.
+    ///
+    /// If not set, the grayscale colormap is used.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Colormap`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_colormap`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_colormap(
+        mut self,
+        colormap: impl IntoIterator<Item = impl Into<crate::components::Colormap>>,
+    ) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), colormap);
+        self
+    }
+
+    /// The range of cell values that is mapped to the colormap.
+    ///
+    /// If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+    /// with the default colormap.
+    #[inline]
+    pub fn with_value_range(
+        mut self,
+        value_range: impl Into<crate::components::ValueRange>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), [value_range]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ValueRange`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_value_range`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_value_range(
+        mut self,
+        value_range: impl IntoIterator<Item = impl Into<crate::components::ValueRange>>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), value_range);
+        self
+    }
+
+    /// Opacity of the grid, useful for layering it over other content.
+    ///
+    /// Defaults to 1.0 (fully opaque).
+    #[inline]
+    pub fn with_opacity(mut self, opacity: impl Into<crate::components::Opacity>) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), [opacity]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Opacity`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_opacity`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_opacity(
+        mut self,
+        opacity: impl IntoIterator<Item = impl Into<crate::components::Opacity>>,
+    ) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), opacity);
+        self
+    }
+
+    /// An optional floating point value that specifies the 2D drawing order.
+    ///
+    /// Objects with higher values are drawn on top of those with lower values.
+    #[inline]
+    pub fn with_draw_order(mut self, draw_order: impl Into<crate::components::DrawOrder>) -> Self {
+        self.draw_order = try_serialize_field(Self::descriptor_draw_order(), [draw_order]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::DrawOrder`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_draw_order`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_draw_order(
+        mut self,
+        draw_order: impl IntoIterator<Item = impl Into<crate::components::DrawOrder>>,
+    ) -> Self {
+        self.draw_order = try_serialize_field(Self::descriptor_draw_order(), draw_order);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for OccupancyGrid {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.buffer.heap_size_bytes()
+            + self.format.heap_size_bytes()
+            + self.cell_size.heap_size_bytes()
+            + self.origin.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.value_range.heap_size_bytes()
+            + self.opacity.heap_size_bytes()
+            + self.draw_order.heap_size_bytes()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    components::{ImageBuffer, ImageFormat},
+    datatypes::{ChannelDatatype, ColorModel},
+};
+
+use super::OccupancyGrid;
+
+impl OccupancyGrid {
+    /// Construct an occupancy grid from a byte buffer given its size in cells, and data type.
+    pub fn from_data_type_and_bytes(
+        bytes: impl Into<ImageBuffer>,
+        [width, height]: [u32; 2],
+        datatype: ChannelDatatype,
+    ) -> Self {
+        let buffer = bytes.into();
+
+        let format = ImageFormat::from_color_model([width, height], ColorModel::L, datatype);
+
+        let num_expected_bytes = format.num_bytes();
+        if buffer.len() != num_expected_bytes {
+            re_log::warn_once!(
+                "Expected {width}x{height} {datatype:?} occupancy grid to be {num_expected_bytes} B, but got {} B", buffer.len()
+            );
+        }
+
+        Self::new(buffer, format)
+    }
+
+    /// From cells following the ROS convention: occupancy probabilities from 0 to 100, and -1 for unknown cells.
+    pub fn from_ros_cells(cells: &[i8], size: [u32; 2]) -> Self {
+        Self::from_data_type_and_bytes(
+            bytemuck::cast_slice::<i8, u8>(cells).to_vec(),
+            size,
+            ChannelDatatype::I8,
+        )
+    }
+}
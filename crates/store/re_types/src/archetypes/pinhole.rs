@@ -134,6 +134,23 @@ pub struct Pinhole {
     ///
     /// This is only used for visualization purposes, and does not affect the projection itself.
     pub image_plane_distance: Option<SerializedComponentBatch>,
+
+    /// The lens distortion model, determining how the distortion coefficients are applied.
+    ///
+    /// Defaults to [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady] if any distortion coefficients are set.
+    pub distortion_model: Option<SerializedComponentBatch>,
+
+    /// Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+    ///
+    /// For the [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady] model, `k4` is a non-standard `k4·r⁸` term
+    /// which should be left at zero for OpenCV calibrations.
+    /// If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+    pub radial_distortion: Option<SerializedComponentBatch>,
+
+    /// Tangential distortion coefficients `[p1, p2]` of the lens.
+    ///
+    /// Only used by the [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady] model.
+    pub tangential_distortion: Option<SerializedComponentBatch>,
 }
 
 impl Pinhole {
@@ -177,6 +194,36 @@ impl Pinhole {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::distortion_model`].
+    #[inline]
+    pub fn descriptor_distortion_model() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.Pinhole".into()),
+            component_name: "rerun.components.LensDistortionModel".into(),
+            archetype_field_name: Some("distortion_model".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::radial_distortion`].
+    #[inline]
+    pub fn descriptor_radial_distortion() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.Pinhole".into()),
+            component_name: "rerun.components.RadialDistortion".into(),
+            archetype_field_name: Some("radial_distortion".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::tangential_distortion`].
+    #[inline]
+    pub fn descriptor_tangential_distortion() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.Pinhole".into()),
+            component_name: "rerun.components.TangentialDistortion".into(),
+            archetype_field_name: Some("tangential_distortion".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for the associated indicator component.
     #[inline]
     pub fn descriptor_indicator() -> ComponentDescriptor {
@@ -199,15 +246,18 @@ static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usiz
         ]
     });
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             Pinhole::descriptor_camera_xyz(),
             Pinhole::descriptor_image_plane_distance(),
+            Pinhole::descriptor_distortion_model(),
+            Pinhole::descriptor_radial_distortion(),
+            Pinhole::descriptor_tangential_distortion(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             Pinhole::descriptor_image_from_camera(),
@@ -215,12 +265,15 @@ static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
             Pinhole::descriptor_indicator(),
             Pinhole::descriptor_camera_xyz(),
             Pinhole::descriptor_image_plane_distance(),
+            Pinhole::descriptor_distortion_model(),
+            Pinhole::descriptor_radial_distortion(),
+            Pinhole::descriptor_tangential_distortion(),
         ]
     });
 
 impl Pinhole {
-    /// The total number of components in the archetype: 1 required, 2 recommended, 2 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 1 required, 2 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
 }
 
 /// Indicator component for the [`Pinhole`] [`::re_types_core::Archetype`]
@@ -295,11 +348,32 @@ impl ::re_types_core::Archetype for Pinhole {
                     Self::descriptor_image_plane_distance(),
                 )
             });
+        let distortion_model = arrays_by_descr
+            .get(&Self::descriptor_distortion_model())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_distortion_model())
+            });
+        let radial_distortion = arrays_by_descr
+            .get(&Self::descriptor_radial_distortion())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_radial_distortion())
+            });
+        let tangential_distortion = arrays_by_descr
+            .get(&Self::descriptor_tangential_distortion())
+            .map(|array| {
+                SerializedComponentBatch::new(
+                    array.clone(),
+                    Self::descriptor_tangential_distortion(),
+                )
+            });
         Ok(Self {
             image_from_camera,
             resolution,
             camera_xyz,
             image_plane_distance,
+            distortion_model,
+            radial_distortion,
+            tangential_distortion,
         })
     }
 }
@@ -314,6 +388,9 @@ impl ::re_types_core::AsComponents for Pinhole {
             self.resolution.clone(),
             self.camera_xyz.clone(),
             self.image_plane_distance.clone(),
+            self.distortion_model.clone(),
+            self.radial_distortion.clone(),
+            self.tangential_distortion.clone(),
         ]
         .into_iter()
         .flatten()
@@ -335,6 +412,9 @@ impl Pinhole {
             resolution: None,
             camera_xyz: None,
             image_plane_distance: None,
+            distortion_model: None,
+            radial_distortion: None,
+            tangential_distortion: None,
         }
     }
 
@@ -365,6 +445,18 @@ impl Pinhole {
                 crate::components::ImagePlaneDistance::arrow_empty(),
                 Self::descriptor_image_plane_distance(),
             )),
+            distortion_model: Some(SerializedComponentBatch::new(
+                crate::components::LensDistortionModel::arrow_empty(),
+                Self::descriptor_distortion_model(),
+            )),
+            radial_distortion: Some(SerializedComponentBatch::new(
+                crate::components::RadialDistortion::arrow_empty(),
+                Self::descriptor_radial_distortion(),
+            )),
+            tangential_distortion: Some(SerializedComponentBatch::new(
+                crate::components::TangentialDistortion::arrow_empty(),
+                Self::descriptor_tangential_distortion(),
+            )),
         }
     }
 
@@ -399,6 +491,15 @@ impl Pinhole {
             self.image_plane_distance
                 .map(|image_plane_distance| image_plane_distance.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.distortion_model
+                .map(|distortion_model| distortion_model.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.radial_distortion
+                .map(|radial_distortion| radial_distortion.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.tangential_distortion
+                .map(|tangential_distortion| tangential_distortion.partitioned(_lengths.clone()))
+                .transpose()?,
         ];
         Ok(columns
             .into_iter()
@@ -420,11 +521,17 @@ impl Pinhole {
         let len_resolution = self.resolution.as_ref().map(|b| b.array.len());
         let len_camera_xyz = self.camera_xyz.as_ref().map(|b| b.array.len());
         let len_image_plane_distance = self.image_plane_distance.as_ref().map(|b| b.array.len());
+        let len_distortion_model = self.distortion_model.as_ref().map(|b| b.array.len());
+        let len_radial_distortion = self.radial_distortion.as_ref().map(|b| b.array.len());
+        let len_tangential_distortion = self.tangential_distortion.as_ref().map(|b| b.array.len());
         let len = None
             .or(len_image_from_camera)
             .or(len_resolution)
             .or(len_camera_xyz)
             .or(len_image_plane_distance)
+            .or(len_distortion_model)
+            .or(len_radial_distortion)
+            .or(len_tangential_distortion)
             .unwrap_or(0);
         self.columns(std::iter::repeat(1).take(len))
     }
@@ -560,6 +667,95 @@ impl Pinhole {
         );
         self
     }
+
+    /// The lens distortion model, determining how the distortion coefficients are applied.
+    ///
+    /// Defaults to [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady] if any distortion coefficients are set.
+    #[inline]
+    pub fn with_distortion_model(
+        mut self,
+        distortion_model: impl Into<crate::components::LensDistortionModel>,
+    ) -> Self {
+        self.distortion_model =
+            try_serialize_field(Self::descriptor_distortion_model(), [distortion_model]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::LensDistortionModel`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_distortion_model`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_distortion_model(
+        mut self,
+        distortion_model: impl IntoIterator<Item = impl Into<crate::components::LensDistortionModel>>,
+    ) -> Self {
+        self.distortion_model =
+            try_serialize_field(Self::descriptor_distortion_model(), distortion_model);
+        self
+    }
+
+    /// Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+    ///
+    /// For the [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady] model, `k4` is a non-standard `k4·r⁸` term
+    /// which should be left at zero for OpenCV calibrations.
+    /// If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+    #[inline]
+    pub fn with_radial_distortion(
+        mut self,
+        radial_distortion: impl Into<crate::components::RadialDistortion>,
+    ) -> Self {
+        self.radial_distortion =
+            try_serialize_field(Self::descriptor_radial_distortion(), [radial_distortion]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::RadialDistortion`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_radial_distortion`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_radial_distortion(
+        mut self,
+        radial_distortion: impl IntoIterator<Item = impl Into<crate::components::RadialDistortion>>,
+    ) -> Self {
+        self.radial_distortion =
+            try_serialize_field(Self::descriptor_radial_distortion(), radial_distortion);
+        self
+    }
+
+    /// Tangential distortion coefficients `[p1, p2]` of the lens.
+    ///
+    /// Only used by the [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady] model.
+    #[inline]
+    pub fn with_tangential_distortion(
+        mut self,
+        tangential_distortion: impl Into<crate::components::TangentialDistortion>,
+    ) -> Self {
+        self.tangential_distortion = try_serialize_field(
+            Self::descriptor_tangential_distortion(),
+            [tangential_distortion],
+        );
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::TangentialDistortion`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_tangential_distortion`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_tangential_distortion(
+        mut self,
+        tangential_distortion: impl IntoIterator<
+            Item = impl Into<crate::components::TangentialDistortion>,
+        >,
+    ) -> Self {
+        self.tangential_distortion = try_serialize_field(
+            Self::descriptor_tangential_distortion(),
+            tangential_distortion,
+        );
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for Pinhole {
@@ -569,5 +765,8 @@ impl ::re_byte_size::SizeBytes for Pinhole {
             + self.resolution.heap_size_bytes()
             + self.camera_xyz.heap_size_bytes()
             + self.image_plane_distance.heap_size_bytes()
+            + self.distortion_model.heap_size_bytes()
+            + self.radial_distortion.heap_size_bytes()
+            + self.tangential_distortion.heap_size_bytes()
     }
 }
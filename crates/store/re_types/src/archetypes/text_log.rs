@@ -70,6 +70,12 @@ pub struct TextLog {
 
     /// Optional color to use for the log line in the Rerun Viewer.
     pub color: Option<SerializedComponentBatch>,
+
+    /// Optional key-value fields of a structured log entry.
+    ///
+    /// The Rerun Viewer shows each key as a column of the text log.
+    /// The fields apply to all entries of the same row.
+    pub fields: Option<SerializedComponentBatch>,
 }
 
 impl TextLog {
@@ -103,6 +109,16 @@ impl TextLog {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::fields`].
+    #[inline]
+    pub fn descriptor_fields() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TextLog".into()),
+            component_name: "rerun.components.TextLogField".into(),
+            archetype_field_name: Some("fields".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for the associated indicator component.
     #[inline]
     pub fn descriptor_indicator() -> ComponentDescriptor {
@@ -120,22 +136,23 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
     once_cell::sync::Lazy::new(|| [TextLog::descriptor_level(), TextLog::descriptor_indicator()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
-    once_cell::sync::Lazy::new(|| [TextLog::descriptor_color()]);
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| [TextLog::descriptor_color(), TextLog::descriptor_fields()]);
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             TextLog::descriptor_text(),
             TextLog::descriptor_level(),
             TextLog::descriptor_indicator(),
             TextLog::descriptor_color(),
+            TextLog::descriptor_fields(),
         ]
     });
 
 impl TextLog {
-    /// The total number of components in the archetype: 1 required, 2 recommended, 1 optional
-    pub const NUM_COMPONENTS: usize = 4usize;
+    /// The total number of components in the archetype: 1 required, 2 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
 }
 
 /// Indicator component for the [`TextLog`] [`::re_types_core::Archetype`]
@@ -196,7 +213,15 @@ impl ::re_types_core::Archetype for TextLog {
         let color = arrays_by_descr
             .get(&Self::descriptor_color())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_color()));
-        Ok(Self { text, level, color })
+        let fields = arrays_by_descr
+            .get(&Self::descriptor_fields())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_fields()));
+        Ok(Self {
+            text,
+            level,
+            color,
+            fields,
+        })
     }
 }
 
@@ -209,6 +234,7 @@ impl ::re_types_core::AsComponents for TextLog {
             self.text.clone(),
             self.level.clone(),
             self.color.clone(),
+            self.fields.clone(),
         ]
         .into_iter()
         .flatten()
@@ -226,6 +252,7 @@ impl TextLog {
             text: try_serialize_field(Self::descriptor_text(), [text]),
             level: None,
             color: None,
+            fields: None,
         }
     }
 
@@ -252,6 +279,10 @@ impl TextLog {
                 crate::components::Color::arrow_empty(),
                 Self::descriptor_color(),
             )),
+            fields: Some(SerializedComponentBatch::new(
+                crate::components::TextLogField::arrow_empty(),
+                Self::descriptor_fields(),
+            )),
         }
     }
 
@@ -283,6 +314,9 @@ impl TextLog {
             self.color
                 .map(|color| color.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.fields
+                .map(|fields| fields.partitioned(_lengths.clone()))
+                .transpose()?,
         ];
         Ok(columns
             .into_iter()
@@ -303,7 +337,13 @@ impl TextLog {
         let len_text = self.text.as_ref().map(|b| b.array.len());
         let len_level = self.level.as_ref().map(|b| b.array.len());
         let len_color = self.color.as_ref().map(|b| b.array.len());
-        let len = None.or(len_text).or(len_level).or(len_color).unwrap_or(0);
+        let len_fields = self.fields.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_text)
+            .or(len_level)
+            .or(len_color)
+            .or(len_fields)
+            .unwrap_or(0);
         self.columns(std::iter::repeat(1).take(len))
     }
 
@@ -368,11 +408,27 @@ impl TextLog {
         self.color = try_serialize_field(Self::descriptor_color(), color);
         self
     }
+
+    /// Optional key-value fields of a structured log entry.
+    ///
+    /// The Rerun Viewer shows each key as a column of the text log.
+    /// The fields apply to all entries of the same row.
+    #[inline]
+    pub fn with_fields(
+        mut self,
+        fields: impl IntoIterator<Item = impl Into<crate::components::TextLogField>>,
+    ) -> Self {
+        self.fields = try_serialize_field(Self::descriptor_fields(), fields);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for TextLog {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.text.heap_size_bytes() + self.level.heap_size_bytes() + self.color.heap_size_bytes()
+        self.text.heap_size_bytes()
+            + self.level.heap_size_bytes()
+            + self.color.heap_size_bytes()
+            + self.fields.heap_size_bytes()
     }
 }
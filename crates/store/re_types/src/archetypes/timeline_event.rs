@@ -0,0 +1,421 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_event.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A discrete event, shown as a marker on the timelines of the Rerun Viewer's time panel.
+///
+/// Use this to highlight important moments of a recording, such as a fault being raised,
+/// a change of mission phase or an operator intervention.
+/// Events with a duration are shown as spans starting at the time they are logged at.
+///
+/// The viewer's "next marker" and "previous marker" commands move the time cursor between events.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct TimelineEvent {
+    /// A short description of the event.
+    pub label: Option<SerializedComponentBatch>,
+
+    /// The severity of the event.
+    ///
+    /// Events are colored by severity unless a color is specified.
+    pub level: Option<SerializedComponentBatch>,
+
+    /// Optional color of the event's marker.
+    pub color: Option<SerializedComponentBatch>,
+
+    /// Optional duration of the event, turning its marker into a span.
+    ///
+    /// Measured in the units of the timeline the event is shown on:
+    /// nanoseconds for temporal timelines, and steps for sequence timelines.
+    pub duration: Option<SerializedComponentBatch>,
+}
+
+impl TimelineEvent {
+    /// Returns the [`ComponentDescriptor`] for [`Self::label`].
+    #[inline]
+    pub fn descriptor_label() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineEvent".into()),
+            component_name: "rerun.components.Text".into(),
+            archetype_field_name: Some("label".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::level`].
+    #[inline]
+    pub fn descriptor_level() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineEvent".into()),
+            component_name: "rerun.components.TextLogLevel".into(),
+            archetype_field_name: Some("level".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::color`].
+    #[inline]
+    pub fn descriptor_color() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineEvent".into()),
+            component_name: "rerun.components.Color".into(),
+            archetype_field_name: Some("color".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::duration`].
+    #[inline]
+    pub fn descriptor_duration() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineEvent".into()),
+            component_name: "rerun.components.EventDuration".into(),
+            archetype_field_name: Some("duration".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineEvent".into()),
+            component_name: "rerun.components.TimelineEventIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TimelineEvent::descriptor_label()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineEvent::descriptor_level(),
+            TimelineEvent::descriptor_indicator(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineEvent::descriptor_color(),
+            TimelineEvent::descriptor_duration(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineEvent::descriptor_label(),
+            TimelineEvent::descriptor_level(),
+            TimelineEvent::descriptor_indicator(),
+            TimelineEvent::descriptor_color(),
+            TimelineEvent::descriptor_duration(),
+        ]
+    });
+
+impl TimelineEvent {
+    /// The total number of components in the archetype: 1 required, 2 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+/// Indicator component for the [`TimelineEvent`] [`::re_types_core::Archetype`]
+pub type TimelineEventIndicator = ::re_types_core::GenericIndicatorComponent<TimelineEvent>;
+
+impl ::re_types_core::Archetype for TimelineEvent {
+    type Indicator = TimelineEventIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.TimelineEvent".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Timeline event"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TimelineEventIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let label = arrays_by_descr
+            .get(&Self::descriptor_label())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_label()));
+        let level = arrays_by_descr
+            .get(&Self::descriptor_level())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_level()));
+        let color = arrays_by_descr
+            .get(&Self::descriptor_color())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_color()));
+        let duration = arrays_by_descr
+            .get(&Self::descriptor_duration())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_duration()));
+        Ok(Self {
+            label,
+            level,
+            color,
+            duration,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TimelineEvent {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.label.clone(),
+            self.level.clone(),
+            self.color.clone(),
+            self.duration.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TimelineEvent {}
+
+impl TimelineEvent {
+    /// Create a new `TimelineEvent`.
+    #[inline]
+    pub fn new(label: impl Into<crate::components::Text>) -> Self {
+        Self {
+            label: try_serialize_field(Self::descriptor_label(), [label]),
+            level: None,
+            color: None,
+            duration: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TimelineEvent`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TimelineEvent`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            label: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_label(),
+            )),
+            level: Some(SerializedComponentBatch::new(
+                crate::components::TextLogLevel::arrow_empty(),
+                Self::descriptor_level(),
+            )),
+            color: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_color(),
+            )),
+            duration: Some(SerializedComponentBatch::new(
+                crate::components::EventDuration::arrow_empty(),
+                Self::descriptor_duration(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.label
+                .map(|label| label.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.level
+                .map(|level| level.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.color
+                .map(|color| color.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.duration
+                .map(|duration| duration.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_label = self.label.as_ref().map(|b| b.array.len());
+        let len_level = self.level.as_ref().map(|b| b.array.len());
+        let len_color = self.color.as_ref().map(|b| b.array.len());
+        let len_duration = self.duration.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_label)
+            .or(len_level)
+            .or(len_color)
+            .or(len_duration)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// A short description of the event.
+    #[inline]
+    pub fn with_label(mut self, label: impl Into<crate::components::Text>) -> Self {
+        self.label = try_serialize_field(Self::descriptor_label(), [label]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Text`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_label`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_label(
+        mut self,
+        label: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.label = try_serialize_field(Self::descriptor_label(), label);
+        self
+    }
+
+    /// The severity of the event.
+    ///
+    /// Events are colored by severity unless a color is specified.
+    #[inline]
+    pub fn with_level(mut self, level: impl Into<crate::components::TextLogLevel>) -> Self {
+        self.level = try_serialize_field(Self::descriptor_level(), [level]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::TextLogLevel`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_level`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_level(
+        mut self,
+        level: impl IntoIterator<Item = impl Into<crate::components::TextLogLevel>>,
+    ) -> Self {
+        self.level = try_serialize_field(Self::descriptor_level(), level);
+        self
+    }
+
+    /// Optional color of the event's marker.
+    #[inline]
+    pub fn with_color(mut self, color: impl Into<crate::components::Color>) -> Self {
+        self.color = try_serialize_field(Self::descriptor_color(), [color]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Color`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_color`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_color(
+        mut self,
+        color: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.color = try_serialize_field(Self::descriptor_color(), color);
+        self
+    }
+
+    /// Optional duration of the event, turning its marker into a span.
+    ///
+    /// Measured in the units of the timeline the event is shown on:
+    /// nanoseconds for temporal timelines, and steps for sequence timelines.
+    #[inline]
+    pub fn with_duration(mut self, duration: impl Into<crate::components::EventDuration>) -> Self {
+        self.duration = try_serialize_field(Self::descriptor_duration(), [duration]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::EventDuration`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_duration`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_duration(
+        mut self,
+        duration: impl IntoIterator<Item = impl Into<crate::components::EventDuration>>,
+    ) -> Self {
+        self.duration = try_serialize_field(Self::descriptor_duration(), duration);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TimelineEvent {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.label.heap_size_bytes()
+            + self.level.heap_size_bytes()
+            + self.color.heap_size_bytes()
+            + self.duration.heap_size_bytes()
+    }
+}
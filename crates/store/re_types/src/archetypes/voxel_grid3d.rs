@@ -0,0 +1,623 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/voxel_grid3d.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A 3D grid of voxels, e.g. an occupancy map.
+///
+/// The grid is either dense, given by a 3D tensor of voxel values, or sparse, given by a list of voxel indices.
+/// Voxel `[x, y, z]` spans from `origin + [x, y, z] * voxel_size` to `origin + [x + 1, y + 1, z + 1] * voxel_size`
+/// in the local coordinate system of the entity.
+///
+/// Voxels are colored by their [`components::Color`][crate::components::Color] if set, otherwise their values are mapped through the [`components::Colormap`][crate::components::Colormap].
+/// When picking a voxel, the instance index is the index into `voxel_indices` for sparse grids,
+/// and the row-major index into `dense_values` for dense grids.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct VoxelGrid3D {
+    /// The size of a single voxel along each axis.
+    pub voxel_size: Option<SerializedComponentBatch>,
+
+    /// The indices of the occupied voxels of a sparse grid.
+    pub voxel_indices: Option<SerializedComponentBatch>,
+
+    /// Optional colors for the voxels.
+    ///
+    /// Takes precedence over the colormap.
+    pub colors: Option<SerializedComponentBatch>,
+
+    /// The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+    ///
+    /// Voxels with a value of zero or NaN are empty, all others are occupied.
+    /// Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+    pub dense_values: Option<SerializedComponentBatch>,
+
+    /// Optional values of the voxels of a sparse grid, mapped through the colormap.
+    pub values: Option<SerializedComponentBatch>,
+
+    /// The position of the corner of voxel `[0, 0, 0]`.
+    ///
+    /// If not specified, the grid starts at the origin.
+    pub origin: Option<SerializedComponentBatch>,
+
+    /// Colormap to use for mapping voxel values to colors.
+    ///
+    /// If not set, the Viridis colormap is used.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// The range of voxel values that is mapped to the colormap.
+    ///
+    /// If not specified, the range is estimated from the data.
+    pub value_range: Option<SerializedComponentBatch>,
+}
+
+impl VoxelGrid3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::voxel_size`].
+    #[inline]
+    pub fn descriptor_voxel_size() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.VoxelSize".into(),
+            archetype_field_name: Some("voxel_size".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::voxel_indices`].
+    #[inline]
+    pub fn descriptor_voxel_indices() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.VoxelIndex".into(),
+            archetype_field_name: Some("voxel_indices".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colors`].
+    #[inline]
+    pub fn descriptor_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.Color".into(),
+            archetype_field_name: Some("colors".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::dense_values`].
+    #[inline]
+    pub fn descriptor_dense_values() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.TensorData".into(),
+            archetype_field_name: Some("dense_values".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::values`].
+    #[inline]
+    pub fn descriptor_values() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.Scalar".into(),
+            archetype_field_name: Some("values".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::origin`].
+    #[inline]
+    pub fn descriptor_origin() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.Position3D".into(),
+            archetype_field_name: Some("origin".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.Colormap".into(),
+            archetype_field_name: Some("colormap".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::value_range`].
+    #[inline]
+    pub fn descriptor_value_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.ValueRange".into(),
+            archetype_field_name: Some("value_range".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.VoxelGrid3D".into()),
+            component_name: "rerun.components.VoxelGrid3DIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [VoxelGrid3D::descriptor_voxel_size()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            VoxelGrid3D::descriptor_voxel_indices(),
+            VoxelGrid3D::descriptor_colors(),
+            VoxelGrid3D::descriptor_indicator(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            VoxelGrid3D::descriptor_dense_values(),
+            VoxelGrid3D::descriptor_values(),
+            VoxelGrid3D::descriptor_origin(),
+            VoxelGrid3D::descriptor_colormap(),
+            VoxelGrid3D::descriptor_value_range(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 9usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            VoxelGrid3D::descriptor_voxel_size(),
+            VoxelGrid3D::descriptor_voxel_indices(),
+            VoxelGrid3D::descriptor_colors(),
+            VoxelGrid3D::descriptor_indicator(),
+            VoxelGrid3D::descriptor_dense_values(),
+            VoxelGrid3D::descriptor_values(),
+            VoxelGrid3D::descriptor_origin(),
+            VoxelGrid3D::descriptor_colormap(),
+            VoxelGrid3D::descriptor_value_range(),
+        ]
+    });
+
+impl VoxelGrid3D {
+    /// The total number of components in the archetype: 1 required, 3 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 9usize;
+}
+
+/// Indicator component for the [`VoxelGrid3D`] [`::re_types_core::Archetype`]
+pub type VoxelGrid3DIndicator = ::re_types_core::GenericIndicatorComponent<VoxelGrid3D>;
+
+impl ::re_types_core::Archetype for VoxelGrid3D {
+    type Indicator = VoxelGrid3DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.VoxelGrid3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Voxel grid 3D"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        VoxelGrid3DIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let voxel_size = arrays_by_descr
+            .get(&Self::descriptor_voxel_size())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_voxel_size())
+            });
+        let voxel_indices = arrays_by_descr
+            .get(&Self::descriptor_voxel_indices())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_voxel_indices())
+            });
+        let colors = arrays_by_descr
+            .get(&Self::descriptor_colors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colors()));
+        let dense_values = arrays_by_descr
+            .get(&Self::descriptor_dense_values())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_dense_values())
+            });
+        let values = arrays_by_descr
+            .get(&Self::descriptor_values())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_values()));
+        let origin = arrays_by_descr
+            .get(&Self::descriptor_origin())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_origin()));
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let value_range = arrays_by_descr
+            .get(&Self::descriptor_value_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_value_range())
+            });
+        Ok(Self {
+            voxel_size,
+            voxel_indices,
+            colors,
+            dense_values,
+            values,
+            origin,
+            colormap,
+            value_range,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for VoxelGrid3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.voxel_size.clone(),
+            self.voxel_indices.clone(),
+            self.colors.clone(),
+            self.dense_values.clone(),
+            self.values.clone(),
+            self.origin.clone(),
+            self.colormap.clone(),
+            self.value_range.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for VoxelGrid3D {}
+
+impl VoxelGrid3D {
+    /// Create a new `VoxelGrid3D`.
+    #[inline]
+    pub fn new(voxel_size: impl Into<crate::components::VoxelSize>) -> Self {
+        Self {
+            voxel_size: try_serialize_field(Self::descriptor_voxel_size(), [voxel_size]),
+            voxel_indices: None,
+            colors: None,
+            dense_values: None,
+            values: None,
+            origin: None,
+            colormap: None,
+            value_range: None,
+        }
+    }
+
+    /// Update only some specific fields of a `VoxelGrid3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `VoxelGrid3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            voxel_size: Some(SerializedComponentBatch::new(
+                crate::components::VoxelSize::arrow_empty(),
+                Self::descriptor_voxel_size(),
+            )),
+            voxel_indices: Some(SerializedComponentBatch::new(
+                crate::components::VoxelIndex::arrow_empty(),
+                Self::descriptor_voxel_indices(),
+            )),
+            colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_colors(),
+            )),
+            dense_values: Some(SerializedComponentBatch::new(
+                crate::components::TensorData::arrow_empty(),
+                Self::descriptor_dense_values(),
+            )),
+            values: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_values(),
+            )),
+            origin: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_origin(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            value_range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_value_range(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.voxel_size
+                .map(|voxel_size| voxel_size.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.voxel_indices
+                .map(|voxel_indices| voxel_indices.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colors
+                .map(|colors| colors.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.dense_values
+                .map(|dense_values| dense_values.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.values
+                .map(|values| values.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.origin
+                .map(|origin| origin.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colormap
+                .map(|colormap| colormap.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.value_range
+                .map(|value_range| value_range.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_voxel_size = self.voxel_size.as_ref().map(|b| b.array.len());
+        let len_voxel_indices = self.voxel_indices.as_ref().map(|b| b.array.len());
+        let len_colors = self.colors.as_ref().map(|b| b.array.len());
+        let len_dense_values = self.dense_values.as_ref().map(|b| b.array.len());
+        let len_values = self.values.as_ref().map(|b| b.array.len());
+        let len_origin = self.origin.as_ref().map(|b| b.array.len());
+        let len_colormap = self.colormap.as_ref().map(|b| b.array.len());
+        let len_value_range = self.value_range.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_voxel_size)
+            .or(len_voxel_indices)
+            .or(len_colors)
+            .or(len_dense_values)
+            .or(len_values)
+            .or(len_origin)
+            .or(len_colormap)
+            .or(len_value_range)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The size of a single voxel along each axis.
+    #[inline]
+    pub fn with_voxel_size(mut self, voxel_size: impl Into<crate::components::VoxelSize>) -> Self {
+        self.voxel_size = try_serialize_field(Self::descriptor_voxel_size(), [voxel_size]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::VoxelSize`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_voxel_size`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_voxel_size(
+        mut self,
+        voxel_size: impl IntoIterator<Item = impl Into<crate::components::VoxelSize>>,
+    ) -> Self {
+        self.voxel_size = try_serialize_field(Self::descriptor_voxel_size(), voxel_size);
+        self
+    }
+
+    /// The indices of the occupied voxels of a sparse grid.
+    #[inline]
+    pub fn with_voxel_indices(
+        mut self,
+        voxel_indices: impl IntoIterator<Item = impl Into<crate::components::VoxelIndex>>,
+    ) -> Self {
+        self.voxel_indices = try_serialize_field(Self::descriptor_voxel_indices(), voxel_indices);
+        self
+    }
+
+    /// Optional colors for the voxels.
+    ///
+    /// Takes precedence over the colormap.
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = try_serialize_field(Self::descriptor_colors(), colors);
+        self
+    }
+
+    /// The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+    ///
+    /// Voxels with a value of zero or NaN are empty, all others are occupied.
+    /// Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+    #[inline]
+    pub fn with_dense_values(
+        mut self,
+        dense_values: impl Into<crate::components::TensorData>,
+    ) -> Self {
+        self.dense_values = try_serialize_field(Self::descriptor_dense_values(), [dense_values]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::TensorData`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_dense_values`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_dense_values(
+        mut self,
+        dense_values: impl IntoIterator<Item = impl Into<crate::components::TensorData>>,
+    ) -> Self {
+        self.dense_values = try_serialize_field(Self::descriptor_dense_values(), dense_values);
+        self
+    }
+
+    /// Optional values of the voxels of a sparse grid, mapped through the colormap.
+    #[inline]
+    pub fn with_values(
+        mut self,
+        values: impl IntoIterator<Item = impl Into<crate::components::Scalar>>,
+    ) -> Self {
+        self.values = try_serialize_field(Self::descriptor_values(), values);
+        self
+    }
+
+    /// The position of the corner of voxel `[0, 0, 0]`.
+    ///
+    /// If not specified, the grid starts at the origin.
+    #[inline]
+    pub fn with_origin(mut self, origin: impl Into<crate::components::Position3D>) -> Self {
+        self.origin = try_serialize_field(Self::descriptor_origin(), [origin]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Position3D`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_origin`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_origin(
+        mut self,
+        origin: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.origin = try_serialize_field(Self::descriptor_origin(), origin);
+        self
+    }
+
+    /// Colormap to use for mapping voxel values to colors.
+    ///
+    /// If not set, the Viridis colormap is used.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Colormap`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_colormap`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_colormap(
+        mut self,
+        colormap: impl IntoIterator<Item = impl Into<crate::components::Colormap>>,
+    ) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), colormap);
+        self
+    }
+
+    /// The range of voxel values that is mapped to the colormap.
+    ///
+    /// If not specified, the range is estimated from the data.
+    #[inline]
+    pub fn with_value_range(
+        mut self,
+        value_range: impl Into<crate::components::ValueRange>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), [value_range]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ValueRange`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_value_range`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_value_range(
+        mut self,
+        value_range: impl IntoIterator<Item = impl Into<crate::components::ValueRange>>,
+    ) -> Self {
+        self.value_range = try_serialize_field(Self::descriptor_value_range(), value_range);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for VoxelGrid3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.voxel_size.heap_size_bytes()
+            + self.voxel_indices.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.dense_values.heap_size_bytes()
+            + self.values.heap_size_bytes()
+            + self.origin.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.value_range.heap_size_bytes()
+    }
+}
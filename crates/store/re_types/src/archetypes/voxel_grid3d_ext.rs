@@ -0,0 +1,27 @@
+use crate::{
+    components::{TensorData, VoxelIndex, VoxelSize},
+    datatypes::TensorData as TensorDataDatatype,
+};
+
+use super::VoxelGrid3D;
+
+impl VoxelGrid3D {
+    /// A sparse grid with the given occupied voxels.
+    pub fn sparse(
+        voxel_size: impl Into<VoxelSize>,
+        voxel_indices: impl IntoIterator<Item = impl Into<VoxelIndex>>,
+    ) -> Self {
+        Self::new(voxel_size).with_voxel_indices(voxel_indices)
+    }
+
+    /// A dense grid from anything that can be converted into a 3D tensor with shape `[x, y, z]`.
+    ///
+    /// This is useful for constructing a [`VoxelGrid3D`] from an ndarray.
+    pub fn try_dense<T: TryInto<TensorDataDatatype>>(
+        voxel_size: impl Into<VoxelSize>,
+        values: T,
+    ) -> Result<Self, T::Error> {
+        let values: TensorDataDatatype = values.try_into()?;
+        Ok(Self::new(voxel_size).with_dense_values(TensorData(values)))
+    }
+}
@@ -0,0 +1,239 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/clip_planes3d.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Clipping planes that cut away parts of a spatial 3D view.
+///
+/// Everything on the side of a plane its normal points towards is not drawn.
+#[derive(Clone, Debug, Default)]
+pub struct ClipPlanes3D {
+    /// Whether the clipping planes are applied.
+    ///
+    /// Defaults to false.
+    pub enabled: Option<SerializedComponentBatch>,
+
+    /// The clipping planes in world-space.
+    ///
+    /// At most 8 planes are supported, additional planes are ignored.
+    /// While the section box is enabled, only the first 2 planes are applied.
+    /// Defaults to a single plane through the center of the scene, facing up.
+    pub planes: Option<SerializedComponentBatch>,
+}
+
+impl ClipPlanes3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::enabled`].
+    #[inline]
+    pub fn descriptor_enabled() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.ClipPlanes3D".into()),
+            component_name: "rerun.blueprint.components.Enabled".into(),
+            archetype_field_name: Some("enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::planes`].
+    #[inline]
+    pub fn descriptor_planes() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.ClipPlanes3D".into()),
+            component_name: "rerun.components.Plane3D".into(),
+            archetype_field_name: Some("planes".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.ClipPlanes3D".into()),
+            component_name: "rerun.blueprint.components.ClipPlanes3DIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [ClipPlanes3D::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            ClipPlanes3D::descriptor_enabled(),
+            ClipPlanes3D::descriptor_planes(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            ClipPlanes3D::descriptor_indicator(),
+            ClipPlanes3D::descriptor_enabled(),
+            ClipPlanes3D::descriptor_planes(),
+        ]
+    });
+
+impl ClipPlanes3D {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+/// Indicator component for the [`ClipPlanes3D`] [`::re_types_core::Archetype`]
+pub type ClipPlanes3DIndicator = ::re_types_core::GenericIndicatorComponent<ClipPlanes3D>;
+
+impl ::re_types_core::Archetype for ClipPlanes3D {
+    type Indicator = ClipPlanes3DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.ClipPlanes3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Clip planes 3D"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        ClipPlanes3DIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let enabled = arrays_by_descr
+            .get(&Self::descriptor_enabled())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_enabled()));
+        let planes = arrays_by_descr
+            .get(&Self::descriptor_planes())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_planes()));
+        Ok(Self { enabled, planes })
+    }
+}
+
+impl ::re_types_core::AsComponents for ClipPlanes3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.enabled.clone(),
+            self.planes.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for ClipPlanes3D {}
+
+impl ClipPlanes3D {
+    /// Create a new `ClipPlanes3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            enabled: None,
+            planes: None,
+        }
+    }
+
+    /// Update only some specific fields of a `ClipPlanes3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `ClipPlanes3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            enabled: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_enabled(),
+            )),
+            planes: Some(SerializedComponentBatch::new(
+                crate::components::Plane3D::arrow_empty(),
+                Self::descriptor_planes(),
+            )),
+        }
+    }
+
+    /// Whether the clipping planes are applied.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_enabled(
+        mut self,
+        enabled: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.enabled = try_serialize_field(Self::descriptor_enabled(), [enabled]);
+        self
+    }
+
+    /// The clipping planes in world-space.
+    ///
+    /// At most 8 planes are supported, additional planes are ignored.
+    /// While the section box is enabled, only the first 2 planes are applied.
+    /// Defaults to a single plane through the center of the scene, facing up.
+    #[inline]
+    pub fn with_planes(
+        mut self,
+        planes: impl IntoIterator<Item = impl Into<crate::components::Plane3D>>,
+    ) -> Self {
+        self.planes = try_serialize_field(Self::descriptor_planes(), planes);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for ClipPlanes3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.enabled.heap_size_bytes() + self.planes.heap_size_bytes()
+    }
+}
@@ -0,0 +1,448 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/eye_controls3d.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: The controls for the 3D eye in a spatial 3D view.
+///
+/// This configures the camera through which the 3D scene is viewed.
+/// Fields that are not set are determined automatically from the scene,
+/// and interacting with the view writes the resulting eye back to the blueprint.
+#[derive(Clone, Debug, Default)]
+pub struct EyeControls3D {
+    /// The kind of the eye for the spatial 3D view.
+    ///
+    /// This controls how the eye reacts to user input, e.g. mouse dragging.
+    /// Defaults to orbital.
+    pub kind: Option<SerializedComponentBatch>,
+
+    /// The world-space position of the eye.
+    ///
+    /// Only used if `look_target` is set as well.
+    pub position: Option<SerializedComponentBatch>,
+
+    /// The world-space point the eye is looking at.
+    ///
+    /// For orbital eyes this is the center of rotation.
+    /// Only used if `position` is set as well.
+    pub look_target: Option<SerializedComponentBatch>,
+
+    /// The up-axis of the eye in world-space.
+    ///
+    /// Defaults to the up-axis of the scene as defined by [`components::ViewCoordinates`][crate::components::ViewCoordinates], or +Z if not specified.
+    pub eye_up: Option<SerializedComponentBatch>,
+
+    /// Vertical field of view of the eye.
+    ///
+    /// Defaults to 55°.
+    pub fov_y: Option<SerializedComponentBatch>,
+
+    /// An entity the eye follows.
+    ///
+    /// While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+    pub tracking_entity: Option<SerializedComponentBatch>,
+
+    /// Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+    ///
+    /// The eye is placed at this offset and looks at the origin of the tracked entity,
+    /// e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+    /// If not set, the eye keeps its orientation & distance and only follows the entity's position.
+    pub tracking_offset: Option<SerializedComponentBatch>,
+}
+
+impl EyeControls3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::kind`].
+    #[inline]
+    pub fn descriptor_kind() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.blueprint.components.Eye3DKind".into(),
+            archetype_field_name: Some("kind".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::position`].
+    #[inline]
+    pub fn descriptor_position() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.components.Position3D".into(),
+            archetype_field_name: Some("position".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::look_target`].
+    #[inline]
+    pub fn descriptor_look_target() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.blueprint.components.LookTarget".into(),
+            archetype_field_name: Some("look_target".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::eye_up`].
+    #[inline]
+    pub fn descriptor_eye_up() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.components.Vector3D".into(),
+            archetype_field_name: Some("eye_up".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::fov_y`].
+    #[inline]
+    pub fn descriptor_fov_y() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.blueprint.components.FieldOfView".into(),
+            archetype_field_name: Some("fov_y".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::tracking_entity`].
+    #[inline]
+    pub fn descriptor_tracking_entity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.components.EntityPath".into(),
+            archetype_field_name: Some("tracking_entity".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::tracking_offset`].
+    #[inline]
+    pub fn descriptor_tracking_offset() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.components.Translation3D".into(),
+            archetype_field_name: Some("tracking_offset".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.EyeControls3D".into()),
+            component_name: "rerun.blueprint.components.EyeControls3DIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [EyeControls3D::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 7usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            EyeControls3D::descriptor_kind(),
+            EyeControls3D::descriptor_position(),
+            EyeControls3D::descriptor_look_target(),
+            EyeControls3D::descriptor_eye_up(),
+            EyeControls3D::descriptor_fov_y(),
+            EyeControls3D::descriptor_tracking_entity(),
+            EyeControls3D::descriptor_tracking_offset(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            EyeControls3D::descriptor_indicator(),
+            EyeControls3D::descriptor_kind(),
+            EyeControls3D::descriptor_position(),
+            EyeControls3D::descriptor_look_target(),
+            EyeControls3D::descriptor_eye_up(),
+            EyeControls3D::descriptor_fov_y(),
+            EyeControls3D::descriptor_tracking_entity(),
+            EyeControls3D::descriptor_tracking_offset(),
+        ]
+    });
+
+impl EyeControls3D {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 7 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
+}
+
+/// Indicator component for the [`EyeControls3D`] [`::re_types_core::Archetype`]
+pub type EyeControls3DIndicator = ::re_types_core::GenericIndicatorComponent<EyeControls3D>;
+
+impl ::re_types_core::Archetype for EyeControls3D {
+    type Indicator = EyeControls3DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.EyeControls3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Eye controls 3D"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        EyeControls3DIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let kind = arrays_by_descr
+            .get(&Self::descriptor_kind())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_kind()));
+        let position = arrays_by_descr
+            .get(&Self::descriptor_position())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_position()));
+        let look_target = arrays_by_descr
+            .get(&Self::descriptor_look_target())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_look_target())
+            });
+        let eye_up = arrays_by_descr
+            .get(&Self::descriptor_eye_up())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_eye_up()));
+        let fov_y = arrays_by_descr
+            .get(&Self::descriptor_fov_y())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_fov_y()));
+        let tracking_entity = arrays_by_descr
+            .get(&Self::descriptor_tracking_entity())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_tracking_entity())
+            });
+        let tracking_offset = arrays_by_descr
+            .get(&Self::descriptor_tracking_offset())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_tracking_offset())
+            });
+        Ok(Self {
+            kind,
+            position,
+            look_target,
+            eye_up,
+            fov_y,
+            tracking_entity,
+            tracking_offset,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for EyeControls3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.kind.clone(),
+            self.position.clone(),
+            self.look_target.clone(),
+            self.eye_up.clone(),
+            self.fov_y.clone(),
+            self.tracking_entity.clone(),
+            self.tracking_offset.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for EyeControls3D {}
+
+impl EyeControls3D {
+    /// Create a new `EyeControls3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            kind: None,
+            position: None,
+            look_target: None,
+            eye_up: None,
+            fov_y: None,
+            tracking_entity: None,
+            tracking_offset: None,
+        }
+    }
+
+    /// Update only some specific fields of a `EyeControls3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `EyeControls3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            kind: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Eye3DKind::arrow_empty(),
+                Self::descriptor_kind(),
+            )),
+            position: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_position(),
+            )),
+            look_target: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::LookTarget::arrow_empty(),
+                Self::descriptor_look_target(),
+            )),
+            eye_up: Some(SerializedComponentBatch::new(
+                crate::components::Vector3D::arrow_empty(),
+                Self::descriptor_eye_up(),
+            )),
+            fov_y: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::FieldOfView::arrow_empty(),
+                Self::descriptor_fov_y(),
+            )),
+            tracking_entity: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_tracking_entity(),
+            )),
+            tracking_offset: Some(SerializedComponentBatch::new(
+                crate::components::Translation3D::arrow_empty(),
+                Self::descriptor_tracking_offset(),
+            )),
+        }
+    }
+
+    /// The kind of the eye for the spatial 3D view.
+    ///
+    /// This controls how the eye reacts to user input, e.g. mouse dragging.
+    /// Defaults to orbital.
+    #[inline]
+    pub fn with_kind(mut self, kind: impl Into<crate::blueprint::components::Eye3DKind>) -> Self {
+        self.kind = try_serialize_field(Self::descriptor_kind(), [kind]);
+        self
+    }
+
+    /// The world-space position of the eye.
+    ///
+    /// Only used if `look_target` is set as well.
+    #[inline]
+    pub fn with_position(mut self, position: impl Into<crate::components::Position3D>) -> Self {
+        self.position = try_serialize_field(Self::descriptor_position(), [position]);
+        self
+    }
+
+    /// The world-space point the eye is looking at.
+    ///
+    /// For orbital eyes this is the center of rotation.
+    /// Only used if `position` is set as well.
+    #[inline]
+    pub fn with_look_target(
+        mut self,
+        look_target: impl Into<crate::blueprint::components::LookTarget>,
+    ) -> Self {
+        self.look_target = try_serialize_field(Self::descriptor_look_target(), [look_target]);
+        self
+    }
+
+    /// The up-axis of the eye in world-space.
+    ///
+    /// Defaults to the up-axis of the scene as defined by [`components::ViewCoordinates`][crate::components::ViewCoordinates], or +Z if not specified.
+    #[inline]
+    pub fn with_eye_up(mut self, eye_up: impl Into<crate::components::Vector3D>) -> Self {
+        self.eye_up = try_serialize_field(Self::descriptor_eye_up(), [eye_up]);
+        self
+    }
+
+    /// Vertical field of view of the eye.
+    ///
+    /// Defaults to 55°.
+    #[inline]
+    pub fn with_fov_y(
+        mut self,
+        fov_y: impl Into<crate::blueprint::components::FieldOfView>,
+    ) -> Self {
+        self.fov_y = try_serialize_field(Self::descriptor_fov_y(), [fov_y]);
+        self
+    }
+
+    /// An entity the eye follows.
+    ///
+    /// While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+    #[inline]
+    pub fn with_tracking_entity(
+        mut self,
+        tracking_entity: impl Into<crate::components::EntityPath>,
+    ) -> Self {
+        self.tracking_entity =
+            try_serialize_field(Self::descriptor_tracking_entity(), [tracking_entity]);
+        self
+    }
+
+    /// Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+    ///
+    /// The eye is placed at this offset and looks at the origin of the tracked entity,
+    /// e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+    /// If not set, the eye keeps its orientation & distance and only follows the entity's position.
+    #[inline]
+    pub fn with_tracking_offset(
+        mut self,
+        tracking_offset: impl Into<crate::components::Translation3D>,
+    ) -> Self {
+        self.tracking_offset =
+            try_serialize_field(Self::descriptor_tracking_offset(), [tracking_offset]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for EyeControls3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.kind.heap_size_bytes()
+            + self.position.heap_size_bytes()
+            + self.look_target.heap_size_bytes()
+            + self.eye_up.heap_size_bytes()
+            + self.fov_y.heap_size_bytes()
+            + self.tracking_entity.heap_size_bytes()
+            + self.tracking_offset.heap_size_bytes()
+    }
+}
@@ -0,0 +1,228 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/measurements.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Measurements taken with the measurement tools of a spatial view.
+///
+/// Each measurement is a kind together with the points it was taken between.
+/// Measurements in 2D views are stored with a z-coordinate of zero.
+#[derive(Clone, Debug, Default)]
+pub struct Measurements {
+    /// The kind of each measurement.
+    pub kinds: Option<SerializedComponentBatch>,
+
+    /// The points of each measurement, in the coordinate space of the view's origin.
+    pub points: Option<SerializedComponentBatch>,
+}
+
+impl Measurements {
+    /// Returns the [`ComponentDescriptor`] for [`Self::kinds`].
+    #[inline]
+    pub fn descriptor_kinds() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.Measurements".into()),
+            component_name: "rerun.blueprint.components.MeasurementKind".into(),
+            archetype_field_name: Some("kinds".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::points`].
+    #[inline]
+    pub fn descriptor_points() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.Measurements".into()),
+            component_name: "rerun.components.LineStrip3D".into(),
+            archetype_field_name: Some("points".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.Measurements".into()),
+            component_name: "rerun.blueprint.components.MeasurementsIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [Measurements::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            Measurements::descriptor_kinds(),
+            Measurements::descriptor_points(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            Measurements::descriptor_indicator(),
+            Measurements::descriptor_kinds(),
+            Measurements::descriptor_points(),
+        ]
+    });
+
+impl Measurements {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+/// Indicator component for the [`Measurements`] [`::re_types_core::Archetype`]
+pub type MeasurementsIndicator = ::re_types_core::GenericIndicatorComponent<Measurements>;
+
+impl ::re_types_core::Archetype for Measurements {
+    type Indicator = MeasurementsIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.Measurements".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Measurements"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        MeasurementsIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let kinds = arrays_by_descr
+            .get(&Self::descriptor_kinds())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_kinds()));
+        let points = arrays_by_descr
+            .get(&Self::descriptor_points())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_points()));
+        Ok(Self { kinds, points })
+    }
+}
+
+impl ::re_types_core::AsComponents for Measurements {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.kinds.clone(),
+            self.points.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for Measurements {}
+
+impl Measurements {
+    /// Create a new `Measurements`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            kinds: None,
+            points: None,
+        }
+    }
+
+    /// Update only some specific fields of a `Measurements`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `Measurements`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            kinds: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::MeasurementKind::arrow_empty(),
+                Self::descriptor_kinds(),
+            )),
+            points: Some(SerializedComponentBatch::new(
+                crate::components::LineStrip3D::arrow_empty(),
+                Self::descriptor_points(),
+            )),
+        }
+    }
+
+    /// The kind of each measurement.
+    #[inline]
+    pub fn with_kinds(
+        mut self,
+        kinds: impl IntoIterator<Item = impl Into<crate::blueprint::components::MeasurementKind>>,
+    ) -> Self {
+        self.kinds = try_serialize_field(Self::descriptor_kinds(), kinds);
+        self
+    }
+
+    /// The points of each measurement, in the coordinate space of the view's origin.
+    #[inline]
+    pub fn with_points(
+        mut self,
+        points: impl IntoIterator<Item = impl Into<crate::components::LineStrip3D>>,
+    ) -> Self {
+        self.points = try_serialize_field(Self::descriptor_points(), points);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Measurements {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.kinds.heap_size_bytes() + self.points.heap_size_bytes()
+    }
+}
@@ -1,8 +1,10 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
 mod background;
+mod clip_planes3d;
 mod container_blueprint;
 mod dataframe_query;
+mod eye_controls3d;
 mod force_center;
 mod force_collision_radius;
 mod force_link;
@@ -11,13 +13,18 @@ mod force_position;
 mod line_grid3d;
 mod map_background;
 mod map_zoom;
+mod measurements;
 mod near_clip_plane;
 mod panel_blueprint;
 mod plot_legend;
+mod recording_comparison;
 mod scalar_axis;
+mod section_box3d;
 mod tensor_scalar_mapping;
 mod tensor_slice_selection;
 mod tensor_view_fit;
+mod text_log_search;
+mod timeline_bookmarks;
 mod view_blueprint;
 mod view_contents;
 mod viewport_blueprint;
@@ -25,8 +32,10 @@ mod visible_time_ranges;
 mod visual_bounds2d;
 
 pub use self::background::Background;
+pub use self::clip_planes3d::ClipPlanes3D;
 pub use self::container_blueprint::ContainerBlueprint;
 pub use self::dataframe_query::DataframeQuery;
+pub use self::eye_controls3d::EyeControls3D;
 pub use self::force_center::ForceCenter;
 pub use self::force_collision_radius::ForceCollisionRadius;
 pub use self::force_link::ForceLink;
@@ -35,13 +44,18 @@ pub use self::force_position::ForcePosition;
 pub use self::line_grid3d::LineGrid3D;
 pub use self::map_background::MapBackground;
 pub use self::map_zoom::MapZoom;
+pub use self::measurements::Measurements;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_blueprint::PanelBlueprint;
 pub use self::plot_legend::PlotLegend;
+pub use self::recording_comparison::RecordingComparison;
 pub use self::scalar_axis::ScalarAxis;
+pub use self::section_box3d::SectionBox3D;
 pub use self::tensor_scalar_mapping::TensorScalarMapping;
 pub use self::tensor_slice_selection::TensorSliceSelection;
 pub use self::tensor_view_fit::TensorViewFit;
+pub use self::text_log_search::TextLogSearch;
+pub use self::timeline_bookmarks::TimelineBookmarks;
 pub use self::view_blueprint::ViewBlueprint;
 pub use self::view_contents::ViewContents;
 pub use self::viewport_blueprint::ViewportBlueprint;
@@ -0,0 +1,245 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_comparison.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Compares the active recording against another recording in a view.
+///
+/// Time series views overlay the data of both recordings, all other views show the compared recording instead of the active one.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingComparison {
+    /// The id of the recording to compare against.
+    ///
+    /// If not set, or if no recording with this id is loaded, the view only shows the active recording.
+    pub recording: Option<SerializedComponentBatch>,
+
+    /// How much later things happen in the active recording than in the compared recording.
+    ///
+    /// Added to all times of the compared recording so that both recordings share one time cursor.
+    /// In the unit of the currently selected timeline, defaults to zero.
+    pub time_offset: Option<SerializedComponentBatch>,
+}
+
+impl RecordingComparison {
+    /// Returns the [`ComponentDescriptor`] for [`Self::recording`].
+    #[inline]
+    pub fn descriptor_recording() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingComparison".into()),
+            component_name: "rerun.blueprint.components.ComparedRecording".into(),
+            archetype_field_name: Some("recording".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::time_offset`].
+    #[inline]
+    pub fn descriptor_time_offset() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingComparison".into()),
+            component_name: "rerun.blueprint.components.TimeOffset".into(),
+            archetype_field_name: Some("time_offset".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.RecordingComparison".into()),
+            component_name: "rerun.blueprint.components.RecordingComparisonIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [RecordingComparison::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            RecordingComparison::descriptor_recording(),
+            RecordingComparison::descriptor_time_offset(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            RecordingComparison::descriptor_indicator(),
+            RecordingComparison::descriptor_recording(),
+            RecordingComparison::descriptor_time_offset(),
+        ]
+    });
+
+impl RecordingComparison {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+/// Indicator component for the [`RecordingComparison`] [`::re_types_core::Archetype`]
+pub type RecordingComparisonIndicator =
+    ::re_types_core::GenericIndicatorComponent<RecordingComparison>;
+
+impl ::re_types_core::Archetype for RecordingComparison {
+    type Indicator = RecordingComparisonIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.RecordingComparison".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Recording comparison"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        RecordingComparisonIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let recording = arrays_by_descr
+            .get(&Self::descriptor_recording())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_recording())
+            });
+        let time_offset = arrays_by_descr
+            .get(&Self::descriptor_time_offset())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_time_offset())
+            });
+        Ok(Self {
+            recording,
+            time_offset,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for RecordingComparison {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.recording.clone(),
+            self.time_offset.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for RecordingComparison {}
+
+impl RecordingComparison {
+    /// Create a new `RecordingComparison`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            recording: None,
+            time_offset: None,
+        }
+    }
+
+    /// Update only some specific fields of a `RecordingComparison`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `RecordingComparison`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            recording: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::ComparedRecording::arrow_empty(),
+                Self::descriptor_recording(),
+            )),
+            time_offset: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimeOffset::arrow_empty(),
+                Self::descriptor_time_offset(),
+            )),
+        }
+    }
+
+    /// The id of the recording to compare against.
+    ///
+    /// If not set, or if no recording with this id is loaded, the view only shows the active recording.
+    #[inline]
+    pub fn with_recording(
+        mut self,
+        recording: impl Into<crate::blueprint::components::ComparedRecording>,
+    ) -> Self {
+        self.recording = try_serialize_field(Self::descriptor_recording(), [recording]);
+        self
+    }
+
+    /// How much later things happen in the active recording than in the compared recording.
+    ///
+    /// Added to all times of the compared recording so that both recordings share one time cursor.
+    /// In the unit of the currently selected timeline, defaults to zero.
+    #[inline]
+    pub fn with_time_offset(
+        mut self,
+        time_offset: impl Into<crate::blueprint::components::TimeOffset>,
+    ) -> Self {
+        self.time_offset = try_serialize_field(Self::descriptor_time_offset(), [time_offset]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for RecordingComparison {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.recording.heap_size_bytes() + self.time_offset.heap_size_bytes()
+    }
+}
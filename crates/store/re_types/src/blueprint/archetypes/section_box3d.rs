@@ -0,0 +1,310 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_box3d.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A box in a spatial 3D view outside of which nothing is drawn.
+#[derive(Clone, Debug, Default)]
+pub struct SectionBox3D {
+    /// Whether the section box is applied.
+    ///
+    /// Defaults to false.
+    pub enabled: Option<SerializedComponentBatch>,
+
+    /// The center of the box in world-space.
+    ///
+    /// Defaults to the center of the scene's bounding box.
+    pub center: Option<SerializedComponentBatch>,
+
+    /// Half of the box's size along each of its local axes.
+    ///
+    /// Defaults to half the size of the scene's bounding box.
+    pub half_size: Option<SerializedComponentBatch>,
+
+    /// Rotation of the box in world-space.
+    ///
+    /// Defaults to no rotation, i.e. an axis-aligned box.
+    pub rotation: Option<SerializedComponentBatch>,
+}
+
+impl SectionBox3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::enabled`].
+    #[inline]
+    pub fn descriptor_enabled() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SectionBox3D".into()),
+            component_name: "rerun.blueprint.components.Enabled".into(),
+            archetype_field_name: Some("enabled".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::center`].
+    #[inline]
+    pub fn descriptor_center() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SectionBox3D".into()),
+            component_name: "rerun.components.Position3D".into(),
+            archetype_field_name: Some("center".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::half_size`].
+    #[inline]
+    pub fn descriptor_half_size() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SectionBox3D".into()),
+            component_name: "rerun.components.HalfSize3D".into(),
+            archetype_field_name: Some("half_size".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::rotation`].
+    #[inline]
+    pub fn descriptor_rotation() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SectionBox3D".into()),
+            component_name: "rerun.components.RotationQuat".into(),
+            archetype_field_name: Some("rotation".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SectionBox3D".into()),
+            component_name: "rerun.blueprint.components.SectionBox3DIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [SectionBox3D::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            SectionBox3D::descriptor_enabled(),
+            SectionBox3D::descriptor_center(),
+            SectionBox3D::descriptor_half_size(),
+            SectionBox3D::descriptor_rotation(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            SectionBox3D::descriptor_indicator(),
+            SectionBox3D::descriptor_enabled(),
+            SectionBox3D::descriptor_center(),
+            SectionBox3D::descriptor_half_size(),
+            SectionBox3D::descriptor_rotation(),
+        ]
+    });
+
+impl SectionBox3D {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+/// Indicator component for the [`SectionBox3D`] [`::re_types_core::Archetype`]
+pub type SectionBox3DIndicator = ::re_types_core::GenericIndicatorComponent<SectionBox3D>;
+
+impl ::re_types_core::Archetype for SectionBox3D {
+    type Indicator = SectionBox3DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.SectionBox3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Section box 3D"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        SectionBox3DIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let enabled = arrays_by_descr
+            .get(&Self::descriptor_enabled())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_enabled()));
+        let center = arrays_by_descr
+            .get(&Self::descriptor_center())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_center()));
+        let half_size = arrays_by_descr
+            .get(&Self::descriptor_half_size())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_half_size())
+            });
+        let rotation = arrays_by_descr
+            .get(&Self::descriptor_rotation())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_rotation()));
+        Ok(Self {
+            enabled,
+            center,
+            half_size,
+            rotation,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for SectionBox3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.enabled.clone(),
+            self.center.clone(),
+            self.half_size.clone(),
+            self.rotation.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for SectionBox3D {}
+
+impl SectionBox3D {
+    /// Create a new `SectionBox3D`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            enabled: None,
+            center: None,
+            half_size: None,
+            rotation: None,
+        }
+    }
+
+    /// Update only some specific fields of a `SectionBox3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `SectionBox3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            enabled: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_enabled(),
+            )),
+            center: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_center(),
+            )),
+            half_size: Some(SerializedComponentBatch::new(
+                crate::components::HalfSize3D::arrow_empty(),
+                Self::descriptor_half_size(),
+            )),
+            rotation: Some(SerializedComponentBatch::new(
+                crate::components::RotationQuat::arrow_empty(),
+                Self::descriptor_rotation(),
+            )),
+        }
+    }
+
+    /// Whether the section box is applied.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_enabled(
+        mut self,
+        enabled: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.enabled = try_serialize_field(Self::descriptor_enabled(), [enabled]);
+        self
+    }
+
+    /// The center of the box in world-space.
+    ///
+    /// Defaults to the center of the scene's bounding box.
+    #[inline]
+    pub fn with_center(mut self, center: impl Into<crate::components::Position3D>) -> Self {
+        self.center = try_serialize_field(Self::descriptor_center(), [center]);
+        self
+    }
+
+    /// Half of the box's size along each of its local axes.
+    ///
+    /// Defaults to half the size of the scene's bounding box.
+    #[inline]
+    pub fn with_half_size(mut self, half_size: impl Into<crate::components::HalfSize3D>) -> Self {
+        self.half_size = try_serialize_field(Self::descriptor_half_size(), [half_size]);
+        self
+    }
+
+    /// Rotation of the box in world-space.
+    ///
+    /// Defaults to no rotation, i.e. an axis-aligned box.
+    #[inline]
+    pub fn with_rotation(mut self, rotation: impl Into<crate::components::RotationQuat>) -> Self {
+        self.rotation = try_serialize_field(Self::descriptor_rotation(), [rotation]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for SectionBox3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.enabled.heap_size_bytes()
+            + self.center.heap_size_bytes()
+            + self.half_size.heap_size_bytes()
+            + self.rotation.heap_size_bytes()
+    }
+}
@@ -0,0 +1,238 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_search.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Searches the bodies of the entries of a text log view.
+///
+/// Only entries whose body matches the query are shown.
+#[derive(Clone, Debug, Default)]
+pub struct TextLogSearch {
+    /// The text to search for.
+    ///
+    /// Matched case-insensitively as a substring, unless `use_regex` is set.
+    /// An empty query shows all entries.
+    pub query: Option<SerializedComponentBatch>,
+
+    /// Whether `query` is a regular expression.
+    ///
+    /// Regular expressions are case-sensitive unless they start with `(?i)`.
+    /// Defaults to false.
+    pub use_regex: Option<SerializedComponentBatch>,
+}
+
+impl TextLogSearch {
+    /// Returns the [`ComponentDescriptor`] for [`Self::query`].
+    #[inline]
+    pub fn descriptor_query() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogSearch".into()),
+            component_name: "rerun.components.Text".into(),
+            archetype_field_name: Some("query".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::use_regex`].
+    #[inline]
+    pub fn descriptor_use_regex() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogSearch".into()),
+            component_name: "rerun.blueprint.components.UseRegex".into(),
+            archetype_field_name: Some("use_regex".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TextLogSearch".into()),
+            component_name: "rerun.blueprint.components.TextLogSearchIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TextLogSearch::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogSearch::descriptor_query(),
+            TextLogSearch::descriptor_use_regex(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogSearch::descriptor_indicator(),
+            TextLogSearch::descriptor_query(),
+            TextLogSearch::descriptor_use_regex(),
+        ]
+    });
+
+impl TextLogSearch {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+/// Indicator component for the [`TextLogSearch`] [`::re_types_core::Archetype`]
+pub type TextLogSearchIndicator = ::re_types_core::GenericIndicatorComponent<TextLogSearch>;
+
+impl ::re_types_core::Archetype for TextLogSearch {
+    type Indicator = TextLogSearchIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TextLogSearch".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text log search"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TextLogSearchIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let query = arrays_by_descr
+            .get(&Self::descriptor_query())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_query()));
+        let use_regex = arrays_by_descr
+            .get(&Self::descriptor_use_regex())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_use_regex())
+            });
+        Ok(Self { query, use_regex })
+    }
+}
+
+impl ::re_types_core::AsComponents for TextLogSearch {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.query.clone(),
+            self.use_regex.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TextLogSearch {}
+
+impl TextLogSearch {
+    /// Create a new `TextLogSearch`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            use_regex: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TextLogSearch`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TextLogSearch`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            query: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_query(),
+            )),
+            use_regex: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::UseRegex::arrow_empty(),
+                Self::descriptor_use_regex(),
+            )),
+        }
+    }
+
+    /// The text to search for.
+    ///
+    /// Matched case-insensitively as a substring, unless `use_regex` is set.
+    /// An empty query shows all entries.
+    #[inline]
+    pub fn with_query(mut self, query: impl Into<crate::components::Text>) -> Self {
+        self.query = try_serialize_field(Self::descriptor_query(), [query]);
+        self
+    }
+
+    /// Whether `query` is a regular expression.
+    ///
+    /// Regular expressions are case-sensitive unless they start with `(?i)`.
+    /// Defaults to false.
+    #[inline]
+    pub fn with_use_regex(
+        mut self,
+        use_regex: impl Into<crate::blueprint::components::UseRegex>,
+    ) -> Self {
+        self.use_regex = try_serialize_field(Self::descriptor_use_regex(), [use_regex]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogSearch {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.query.heap_size_bytes() + self.use_regex.heap_size_bytes()
+    }
+}
@@ -0,0 +1,270 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/timeline_bookmarks.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Bookmarked moments on the timelines of the time panel.
+///
+/// The i-th bookmark is made up of the i-th timeline, time and label.
+/// Bookmarks are shown as markers above the streams of the time panel.
+#[derive(Clone, Debug, Default)]
+pub struct TimelineBookmarks {
+    /// The timeline of each bookmark.
+    pub timelines: Option<SerializedComponentBatch>,
+
+    /// The time of each bookmark on its timeline.
+    pub times: Option<SerializedComponentBatch>,
+
+    /// The label of each bookmark.
+    pub labels: Option<SerializedComponentBatch>,
+}
+
+impl TimelineBookmarks {
+    /// Returns the [`ComponentDescriptor`] for [`Self::timelines`].
+    #[inline]
+    pub fn descriptor_timelines() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TimelineBookmarks".into()),
+            component_name: "rerun.blueprint.components.TimelineName".into(),
+            archetype_field_name: Some("timelines".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::times`].
+    #[inline]
+    pub fn descriptor_times() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TimelineBookmarks".into()),
+            component_name: "rerun.blueprint.components.BookmarkTime".into(),
+            archetype_field_name: Some("times".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::labels`].
+    #[inline]
+    pub fn descriptor_labels() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TimelineBookmarks".into()),
+            component_name: "rerun.components.Name".into(),
+            archetype_field_name: Some("labels".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TimelineBookmarks".into()),
+            component_name: "rerun.blueprint.components.TimelineBookmarksIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TimelineBookmarks::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineBookmarks::descriptor_timelines(),
+            TimelineBookmarks::descriptor_times(),
+            TimelineBookmarks::descriptor_labels(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineBookmarks::descriptor_indicator(),
+            TimelineBookmarks::descriptor_timelines(),
+            TimelineBookmarks::descriptor_times(),
+            TimelineBookmarks::descriptor_labels(),
+        ]
+    });
+
+impl TimelineBookmarks {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+/// Indicator component for the [`TimelineBookmarks`] [`::re_types_core::Archetype`]
+pub type TimelineBookmarksIndicator = ::re_types_core::GenericIndicatorComponent<TimelineBookmarks>;
+
+impl ::re_types_core::Archetype for TimelineBookmarks {
+    type Indicator = TimelineBookmarksIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TimelineBookmarks".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Timeline bookmarks"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TimelineBookmarksIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let timelines = arrays_by_descr
+            .get(&Self::descriptor_timelines())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_timelines())
+            });
+        let times = arrays_by_descr
+            .get(&Self::descriptor_times())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_times()));
+        let labels = arrays_by_descr
+            .get(&Self::descriptor_labels())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_labels()));
+        Ok(Self {
+            timelines,
+            times,
+            labels,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TimelineBookmarks {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.timelines.clone(),
+            self.times.clone(),
+            self.labels.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TimelineBookmarks {}
+
+impl TimelineBookmarks {
+    /// Create a new `TimelineBookmarks`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            timelines: None,
+            times: None,
+            labels: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TimelineBookmarks`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TimelineBookmarks`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            timelines: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimelineName::arrow_empty(),
+                Self::descriptor_timelines(),
+            )),
+            times: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::BookmarkTime::arrow_empty(),
+                Self::descriptor_times(),
+            )),
+            labels: Some(SerializedComponentBatch::new(
+                crate::components::Name::arrow_empty(),
+                Self::descriptor_labels(),
+            )),
+        }
+    }
+
+    /// The timeline of each bookmark.
+    #[inline]
+    pub fn with_timelines(
+        mut self,
+        timelines: impl IntoIterator<Item = impl Into<crate::blueprint::components::TimelineName>>,
+    ) -> Self {
+        self.timelines = try_serialize_field(Self::descriptor_timelines(), timelines);
+        self
+    }
+
+    /// The time of each bookmark on its timeline.
+    #[inline]
+    pub fn with_times(
+        mut self,
+        times: impl IntoIterator<Item = impl Into<crate::blueprint::components::BookmarkTime>>,
+    ) -> Self {
+        self.times = try_serialize_field(Self::descriptor_times(), times);
+        self
+    }
+
+    /// The label of each bookmark.
+    #[inline]
+    pub fn with_labels(
+        mut self,
+        labels: impl IntoIterator<Item = impl Into<crate::components::Name>>,
+    ) -> Self {
+        self.labels = try_serialize_field(Self::descriptor_labels(), labels);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TimelineBookmarks {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.timelines.heap_size_bytes()
+            + self.times.heap_size_bytes()
+            + self.labels.heap_size_bytes()
+    }
+}
@@ -0,0 +1,168 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/eye3d_kind.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The kind of the 3D eye to view a scene in a `Spatial3DView`.
+///
+/// This is used to specify how the controls of the view react to user input (such as mouse gestures).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum Eye3DKind {
+    /// First person point of view.
+    ///
+    /// The camera perspective as if one is seeing it through the eyes of a person as popularized by first-person games.
+    /// The center of rotation is the position of the eye (the camera).
+    /// Dragging the mouse on the spatial 3D view rotates the scene as if one is moving
+    /// their head around.
+    FirstPerson = 1,
+
+    /// Orbital eye.
+    ///
+    /// The center of rotation is located to a center location in front of the eye (it is different from the eye
+    /// location itself), as if the eye was orbiting around the scene.
+    #[default]
+    Orbital = 2,
+}
+
+impl ::re_types_core::Component for Eye3DKind {
+    #[inline]
+    fn descriptor() -> ComponentDescriptor {
+        ComponentDescriptor::new("rerun.blueprint.components.Eye3DKind")
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(Eye3DKind);
+
+impl ::re_types_core::Loggable for Eye3DKind {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.blueprint.components.Eye3DKind#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::FirstPerson)),
+                Some(2) => Ok(Some(Self::Orbital)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.blueprint.components.Eye3DKind")?)
+    }
+}
+
+impl std::fmt::Display for Eye3DKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FirstPerson => write!(f, "FirstPerson"),
+            Self::Orbital => write!(f, "Orbital"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for Eye3DKind {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[Self::FirstPerson, Self::Orbital]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::FirstPerson => {
+                "First person point of view.\n\nThe camera perspective as if one is seeing it through the eyes of a person as popularized by first-person games.\nThe center of rotation is the position of the eye (the camera).\nDragging the mouse on the spatial 3D view rotates the scene as if one is moving\ntheir head around."
+            }
+            Self::Orbital => {
+                "Orbital eye.\n\nThe center of rotation is located to a center location in front of the eye (it is different from the eye\nlocation itself), as if the eye was orbiting around the scene."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for Eye3DKind {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
@@ -0,0 +1,11 @@
+use re_types_core::datatypes::Float32;
+
+use super::FieldOfView;
+
+impl Default for FieldOfView {
+    #[inline]
+    fn default() -> Self {
+        // Same as the default field of view of the eye in the 3D view.
+        Self(Float32(55.0_f32.to_radians()))
+    }
+}
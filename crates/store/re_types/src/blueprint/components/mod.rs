@@ -6,7 +6,9 @@ mod auto_layout;
 mod auto_layout_ext;
 mod auto_views;
 mod background_kind;
+mod bookmark_time;
 mod column_share;
+mod compared_recording;
 mod component_column_selector;
 mod component_column_selector_ext;
 mod container_kind;
@@ -14,6 +16,9 @@ mod corner2d;
 mod corner2d_ext;
 mod enabled;
 mod enabled_ext;
+mod eye3d_kind;
+mod field_of_view;
+mod field_of_view_ext;
 mod filter_by_range;
 mod filter_by_range_ext;
 mod filter_is_not_null;
@@ -28,7 +33,9 @@ mod included_content;
 mod interactive;
 mod interactive_ext;
 mod lock_range_during_zoom;
+mod look_target;
 mod map_provider;
+mod measurement_kind;
 mod near_clip_plane;
 mod near_clip_plane_ext;
 mod panel_state;
@@ -39,8 +46,11 @@ mod row_share;
 mod selected_columns;
 mod tensor_dimension_index_slider;
 mod tensor_dimension_index_slider_ext;
+mod time_offset;
+mod time_offset_ext;
 mod timeline_name;
 mod timeline_name_ext;
+mod use_regex;
 mod view_class;
 mod view_class_ext;
 mod view_fit;
@@ -62,11 +72,15 @@ pub use self::apply_latest_at::ApplyLatestAt;
 pub use self::auto_layout::AutoLayout;
 pub use self::auto_views::AutoViews;
 pub use self::background_kind::BackgroundKind;
+pub use self::bookmark_time::BookmarkTime;
 pub use self::column_share::ColumnShare;
+pub use self::compared_recording::ComparedRecording;
 pub use self::component_column_selector::ComponentColumnSelector;
 pub use self::container_kind::ContainerKind;
 pub use self::corner2d::Corner2D;
 pub use self::enabled::Enabled;
+pub use self::eye3d_kind::Eye3DKind;
+pub use self::field_of_view::FieldOfView;
 pub use self::filter_by_range::FilterByRange;
 pub use self::filter_is_not_null::FilterIsNotNull;
 pub use self::force_distance::ForceDistance;
@@ -77,7 +91,9 @@ pub use self::grid_spacing::GridSpacing;
 pub use self::included_content::IncludedContent;
 pub use self::interactive::Interactive;
 pub use self::lock_range_during_zoom::LockRangeDuringZoom;
+pub use self::look_target::LookTarget;
 pub use self::map_provider::MapProvider;
+pub use self::measurement_kind::MeasurementKind;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_state::PanelState;
 pub use self::query_expression::QueryExpression;
@@ -85,7 +101,9 @@ pub use self::root_container::RootContainer;
 pub use self::row_share::RowShare;
 pub use self::selected_columns::SelectedColumns;
 pub use self::tensor_dimension_index_slider::TensorDimensionIndexSlider;
+pub use self::time_offset::TimeOffset;
 pub use self::timeline_name::TimelineName;
+pub use self::use_regex::UseRegex;
 pub use self::view_class::ViewClass;
 pub use self::view_fit::ViewFit;
 pub use self::view_maximized::ViewMaximized;
@@ -0,0 +1,8 @@
+use super::TimeOffset;
+
+impl Default for TimeOffset {
+    #[inline]
+    fn default() -> Self {
+        Self(crate::datatypes::TimeInt(0))
+    }
+}
@@ -30,6 +30,9 @@ pub struct Spatial2DView {
     /// Somethings outside of these bounds may also be visible due to letterboxing.
     pub visual_bounds: crate::blueprint::archetypes::VisualBounds2D,
 
+    /// Measurements taken with the measurement tools of this view.
+    pub measurements: crate::blueprint::archetypes::Measurements,
+
     /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
     ///
     /// If not specified, the default is to show the latest state of each component.
@@ -49,6 +52,7 @@ impl ::re_byte_size::SizeBytes for Spatial2DView {
     fn heap_size_bytes(&self) -> u64 {
         self.background.heap_size_bytes()
             + self.visual_bounds.heap_size_bytes()
+            + self.measurements.heap_size_bytes()
             + self.time_ranges.heap_size_bytes()
     }
 
@@ -56,6 +60,7 @@ impl ::re_byte_size::SizeBytes for Spatial2DView {
     fn is_pod() -> bool {
         <crate::blueprint::archetypes::Background>::is_pod()
             && <crate::blueprint::archetypes::VisualBounds2D>::is_pod()
+            && <crate::blueprint::archetypes::Measurements>::is_pod()
             && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
     }
 }
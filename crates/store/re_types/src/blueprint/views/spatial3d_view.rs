@@ -27,6 +27,18 @@ pub struct Spatial3DView {
     /// Configuration for the 3D line grid.
     pub line_grid: crate::blueprint::archetypes::LineGrid3D,
 
+    /// Configuration of the 3D eye, i.e. the camera through which the scene is viewed.
+    pub eye_controls: crate::blueprint::archetypes::EyeControls3D,
+
+    /// Measurements taken with the measurement tools of this view.
+    pub measurements: crate::blueprint::archetypes::Measurements,
+
+    /// Clipping planes that cut away parts of the scene.
+    pub clip_planes: crate::blueprint::archetypes::ClipPlanes3D,
+
+    /// A box outside of which the scene is cut away.
+    pub section_box: crate::blueprint::archetypes::SectionBox3D,
+
     /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
     ///
     /// If not specified, the default is to show the latest state of each component.
@@ -46,6 +58,10 @@ impl ::re_byte_size::SizeBytes for Spatial3DView {
     fn heap_size_bytes(&self) -> u64 {
         self.background.heap_size_bytes()
             + self.line_grid.heap_size_bytes()
+            + self.eye_controls.heap_size_bytes()
+            + self.measurements.heap_size_bytes()
+            + self.clip_planes.heap_size_bytes()
+            + self.section_box.heap_size_bytes()
             + self.time_ranges.heap_size_bytes()
     }
 
@@ -53,6 +69,10 @@ impl ::re_byte_size::SizeBytes for Spatial3DView {
     fn is_pod() -> bool {
         <crate::blueprint::archetypes::Background>::is_pod()
             && <crate::blueprint::archetypes::LineGrid3D>::is_pod()
+            && <crate::blueprint::archetypes::EyeControls3D>::is_pod()
+            && <crate::blueprint::archetypes::Measurements>::is_pod()
+            && <crate::blueprint::archetypes::ClipPlanes3D>::is_pod()
+            && <crate::blueprint::archetypes::SectionBox3D>::is_pod()
             && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
     }
 }
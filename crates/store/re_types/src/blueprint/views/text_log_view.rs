@@ -20,7 +20,10 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 
 /// **View**: A view of a text log, for use with [`archetypes::TextLog`][crate::archetypes::TextLog].
 #[derive(Clone, Debug)]
-pub struct TextLogView {}
+pub struct TextLogView {
+    /// Searches the bodies of the log entries.
+    pub search: crate::blueprint::archetypes::TextLogSearch,
+}
 
 impl ::re_types_core::View for TextLogView {
     #[inline]
@@ -29,14 +32,43 @@ impl ::re_types_core::View for TextLogView {
     }
 }
 
+impl<T: Into<crate::blueprint::archetypes::TextLogSearch>> From<T> for TextLogView {
+    fn from(v: T) -> Self {
+        Self { search: v.into() }
+    }
+}
+
+impl std::borrow::Borrow<crate::blueprint::archetypes::TextLogSearch> for TextLogView {
+    #[inline]
+    fn borrow(&self) -> &crate::blueprint::archetypes::TextLogSearch {
+        &self.search
+    }
+}
+
+impl std::ops::Deref for TextLogView {
+    type Target = crate::blueprint::archetypes::TextLogSearch;
+
+    #[inline]
+    fn deref(&self) -> &crate::blueprint::archetypes::TextLogSearch {
+        &self.search
+    }
+}
+
+impl std::ops::DerefMut for TextLogView {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::blueprint::archetypes::TextLogSearch {
+        &mut self.search
+    }
+}
+
 impl ::re_byte_size::SizeBytes for TextLogView {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        0
+        self.search.heap_size_bytes()
     }
 
     #[inline]
     fn is_pod() -> bool {
-        true
+        <crate::blueprint::archetypes::TextLogSearch>::is_pod()
     }
 }
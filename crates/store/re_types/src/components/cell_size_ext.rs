@@ -0,0 +1,32 @@
+use crate::datatypes::Vec2D;
+
+use super::CellSize;
+
+impl CellSize {
+    /// Create a new cell size from its extent along each axis.
+    #[inline]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self(Vec2D::new(x, y))
+    }
+
+    /// Create a new cell size for square cells.
+    #[inline]
+    pub const fn splat(size: f32) -> Self {
+        Self(Vec2D::new(size, size))
+    }
+}
+
+impl Default for CellSize {
+    #[inline]
+    fn default() -> Self {
+        Self::splat(1.0)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<CellSize> for glam::Vec2 {
+    #[inline]
+    fn from(size: CellSize) -> Self {
+        Self::new(size.x(), size.y())
+    }
+}
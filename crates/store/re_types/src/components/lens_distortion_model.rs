@@ -0,0 +1,183 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/lens_distortion_model.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: The lens distortion model of a camera, applied on top of its pinhole projection.
+///
+/// The model determines how [`components::RadialDistortion`][crate::components::RadialDistortion] and [`components::TangentialDistortion`][crate::components::TangentialDistortion]
+/// are interpreted.
+/// All models operate on normalized image coordinates, i.e. `(x, y) = (X / Z, Y / Z)`
+/// for a point `(X, Y, Z)` in camera space, before the focal length and principal point are applied.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum LensDistortionModel {
+    /// The Brown–Conrady model with radial and tangential distortion, e.g. OpenCV's default camera model.
+    ///
+    /// With `r² = x² + y²`, normalized image coordinates are distorted as:
+    /// ```text
+    /// radial = 1 + k1·r² + k2·r⁴ + k3·r⁶ + k4·r⁸
+    /// x' = x·radial + 2·p1·x·y + p2·(r² + 2·x²)
+    /// y' = y·radial + p1·(r² + 2·y²) + 2·p2·x·y
+    /// ```
+    /// The `k4·r⁸` term is an extension of OpenCV's model, which stops at `k3·r⁶`.
+    /// Leave `k4` at zero for calibrations from OpenCV or most other tools.
+    /// In particular, the `k4` of OpenCV's rational model (`CALIB_RATIONAL_MODEL`) is a
+    /// denominator coefficient and can't be represented here.
+    ///
+    /// Note that OpenCV orders its distortion coefficients as `[k1, k2, p1, p2, k3]`.
+    #[default]
+    BrownConrady = 1,
+
+    /// The Kannala–Brandt equidistant fisheye model, e.g. OpenCV's fisheye camera model.
+    ///
+    /// With `θ = atan(r)` the angle of incidence, normalized image coordinates are distorted as:
+    /// ```text
+    /// θ' = θ·(1 + k1·θ² + k2·θ⁴ + k3·θ⁶ + k4·θ⁸)
+    /// (x', y') = (θ' / r)·(x, y)
+    /// ```
+    /// Tangential distortion is ignored by this model.
+    KannalaBrandt = 2,
+}
+
+impl ::re_types_core::Component for LensDistortionModel {
+    #[inline]
+    fn descriptor() -> ComponentDescriptor {
+        ComponentDescriptor::new("rerun.components.LensDistortionModel")
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(LensDistortionModel);
+
+impl ::re_types_core::Loggable for LensDistortionModel {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.components.LensDistortionModel#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::BrownConrady)),
+                Some(2) => Ok(Some(Self::KannalaBrandt)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.components.LensDistortionModel")?)
+    }
+}
+
+impl std::fmt::Display for LensDistortionModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BrownConrady => write!(f, "BrownConrady"),
+            Self::KannalaBrandt => write!(f, "KannalaBrandt"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for LensDistortionModel {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[Self::BrownConrady, Self::KannalaBrandt]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::BrownConrady => {
+                "The Brown–Conrady model with radial and tangential distortion, e.g. OpenCV's default camera model.\n\nWith `r² = x² + y²`, normalized image coordinates are distorted as:\n```text\nradial = 1 + k1·r² + k2·r⁴ + k3·r⁶ + k4·r⁸\nx' = x·radial + 2·p1·x·y + p2·(r² + 2·x²)\ny' = y·radial + p1·(r² + 2·y²) + 2·p2·x·y\n```\nThe `k4·r⁸` term is an extension of OpenCV's model, which stops at `k3·r⁶`.\nLeave `k4` at zero for calibrations from OpenCV or most other tools.\nIn particular, the `k4` of OpenCV's rational model (`CALIB_RATIONAL_MODEL`) is a\ndenominator coefficient and can't be represented here.\n\nNote that OpenCV orders its distortion coefficients as `[k1, k2, p1, p2, k3]`."
+            }
+            Self::KannalaBrandt => {
+                "The Kannala–Brandt equidistant fisheye model, e.g. OpenCV's fisheye camera model.\n\nWith `θ = atan(r)` the angle of incidence, normalized image coordinates are distorted as:\n```text\nθ' = θ·(1 + k1·θ² + k2·θ⁴ + k3·θ⁶ + k4·θ⁸)\n(x', y') = (θ' / r)·(x, y)\n```\nTangential distortion is ignored by this model."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for LensDistortionModel {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
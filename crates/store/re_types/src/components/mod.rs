@@ -7,6 +7,8 @@ mod annotation_context;
 mod axis_length;
 mod axis_length_ext;
 mod blob;
+mod cell_size;
+mod cell_size_ext;
 mod class_id;
 mod class_id_ext;
 mod color;
@@ -18,6 +20,7 @@ mod depth_meter_ext;
 mod draw_order;
 mod draw_order_ext;
 mod entity_path;
+mod event_duration;
 mod fill_mode;
 mod fill_mode_ext;
 mod fill_ratio;
@@ -46,6 +49,7 @@ mod lat_lon;
 mod lat_lon_ext;
 mod length;
 mod length_ext;
+mod lens_distortion_model;
 mod line_strip2d;
 mod line_strip2d_ext;
 mod line_strip3d;
@@ -79,6 +83,7 @@ mod position2d;
 mod position2d_ext;
 mod position3d;
 mod position3d_ext;
+mod radial_distortion;
 mod radius;
 mod radius_ext;
 mod range1d;
@@ -99,6 +104,7 @@ mod show_labels;
 mod show_labels_ext;
 mod stroke_width;
 mod stroke_width_ext;
+mod tangential_distortion;
 mod tensor_data;
 mod tensor_dimension_index_selection;
 mod tensor_dimension_index_selection_ext;
@@ -108,6 +114,8 @@ mod texcoord2d;
 mod texcoord2d_ext;
 mod text;
 mod text_ext;
+mod text_log_field;
+mod text_log_field_ext;
 mod text_log_level;
 mod text_log_level_ext;
 mod transform_mat3x3;
@@ -127,18 +135,24 @@ mod video_timestamp;
 mod video_timestamp_ext;
 mod view_coordinates;
 mod view_coordinates_ext;
+mod voxel_index;
+mod voxel_index_ext;
+mod voxel_size;
+mod voxel_size_ext;
 
 pub use self::aggregation_policy::AggregationPolicy;
 pub use self::albedo_factor::AlbedoFactor;
 pub use self::annotation_context::AnnotationContext;
 pub use self::axis_length::AxisLength;
 pub use self::blob::Blob;
+pub use self::cell_size::CellSize;
 pub use self::class_id::ClassId;
 pub use self::color::Color;
 pub use self::colormap::Colormap;
 pub use self::depth_meter::DepthMeter;
 pub use self::draw_order::DrawOrder;
 pub use self::entity_path::EntityPath;
+pub use self::event_duration::EventDuration;
 pub use self::fill_mode::FillMode;
 pub use self::fill_ratio::FillRatio;
 pub use self::gamma_correction::GammaCorrection;
@@ -154,6 +168,7 @@ pub use self::image_plane_distance::ImagePlaneDistance;
 pub use self::keypoint_id::KeypointId;
 pub use self::lat_lon::LatLon;
 pub use self::length::Length;
+pub use self::lens_distortion_model::LensDistortionModel;
 pub use self::line_strip2d::LineStrip2D;
 pub use self::line_strip3d::LineStrip3D;
 pub use self::magnification_filter::MagnificationFilter;
@@ -171,6 +186,7 @@ pub use self::pose_transform_mat3x3::PoseTransformMat3x3;
 pub use self::pose_translation3d::PoseTranslation3D;
 pub use self::position2d::Position2D;
 pub use self::position3d::Position3D;
+pub use self::radial_distortion::RadialDistortion;
 pub use self::radius::Radius;
 pub use self::range1d::Range1D;
 pub use self::recording_uri::RecordingUri;
@@ -181,12 +197,14 @@ pub use self::scalar::Scalar;
 pub use self::scale3d::Scale3D;
 pub use self::show_labels::ShowLabels;
 pub use self::stroke_width::StrokeWidth;
+pub use self::tangential_distortion::TangentialDistortion;
 pub use self::tensor_data::TensorData;
 pub use self::tensor_dimension_index_selection::TensorDimensionIndexSelection;
 pub use self::tensor_height_dimension::TensorHeightDimension;
 pub use self::tensor_width_dimension::TensorWidthDimension;
 pub use self::texcoord2d::Texcoord2D;
 pub use self::text::Text;
+pub use self::text_log_field::TextLogField;
 pub use self::text_log_level::TextLogLevel;
 pub use self::transform_mat3x3::TransformMat3x3;
 pub use self::transform_relation::TransformRelation;
@@ -197,3 +215,5 @@ pub use self::vector2d::Vector2D;
 pub use self::vector3d::Vector3D;
 pub use self::video_timestamp::VideoTimestamp;
 pub use self::view_coordinates::ViewCoordinates;
+pub use self::voxel_index::VoxelIndex;
+pub use self::voxel_size::VoxelSize;
@@ -0,0 +1,116 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/radial_distortion.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: Radial lens distortion coefficients `[k1, k2, k3, k4]`.
+///
+/// How they are applied depends on the [`components::LensDistortionModel`][crate::components::LensDistortionModel].
+/// For [`components::LensDistortionModel::BrownConrady`][crate::components::LensDistortionModel::BrownConrady], `k4` is a non-standard `k4·r⁸` term
+/// which should be left at zero for OpenCV calibrations.
+#[derive(Clone, Debug, Copy, PartialEq, Default)]
+pub struct RadialDistortion(pub crate::datatypes::Vec4D);
+
+impl ::re_types_core::Component for RadialDistortion {
+    #[inline]
+    fn descriptor() -> ComponentDescriptor {
+        ComponentDescriptor::new("rerun.components.RadialDistortion")
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(RadialDistortion);
+
+impl ::re_types_core::Loggable for RadialDistortion {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        crate::datatypes::Vec4D::arrow_datatype()
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        crate::datatypes::Vec4D::to_arrow_opt(data.into_iter().map(|datum| {
+            datum.map(|datum| match datum.into() {
+                ::std::borrow::Cow::Borrowed(datum) => ::std::borrow::Cow::Borrowed(&datum.0),
+                ::std::borrow::Cow::Owned(datum) => ::std::borrow::Cow::Owned(datum.0),
+            })
+        }))
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Vec4D::from_arrow_opt(arrow_data)
+            .map(|v| v.into_iter().map(|v| v.map(Self)).collect())
+    }
+
+    #[inline]
+    fn from_arrow(arrow_data: &dyn arrow::array::Array) -> DeserializationResult<Vec<Self>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Vec4D::from_arrow(arrow_data).map(|v| v.into_iter().map(Self).collect())
+    }
+}
+
+impl<T: Into<crate::datatypes::Vec4D>> From<T> for RadialDistortion {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::datatypes::Vec4D> for RadialDistortion {
+    #[inline]
+    fn borrow(&self) -> &crate::datatypes::Vec4D {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for RadialDistortion {
+    type Target = crate::datatypes::Vec4D;
+
+    #[inline]
+    fn deref(&self) -> &crate::datatypes::Vec4D {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for RadialDistortion {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::datatypes::Vec4D {
+        &mut self.0
+    }
+}
+
+impl ::re_byte_size::SizeBytes for RadialDistortion {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::datatypes::Vec4D>::is_pod()
+    }
+}
@@ -0,0 +1,27 @@
+use super::TextLogField;
+
+impl TextLogField {
+    /// A field with the given key and value.
+    #[inline]
+    pub fn new(
+        key: impl Into<crate::datatypes::Utf8>,
+        value: impl Into<crate::datatypes::Utf8>,
+    ) -> Self {
+        Self(crate::datatypes::Utf8Pair {
+            first: key.into(),
+            second: value.into(),
+        })
+    }
+
+    /// The name of the field.
+    #[inline]
+    pub fn key(&self) -> &str {
+        self.0.first.as_str()
+    }
+
+    /// The value of the field.
+    #[inline]
+    pub fn value(&self) -> &str {
+        self.0.second.as_str()
+    }
+}
@@ -0,0 +1,19 @@
+use crate::datatypes::UVec3D;
+
+use super::VoxelIndex;
+
+impl VoxelIndex {
+    /// Create a new voxel index from its coordinates.
+    #[inline]
+    pub const fn new(x: u32, y: u32, z: u32) -> Self {
+        Self(UVec3D::new(x, y, z))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<VoxelIndex> for glam::UVec3 {
+    #[inline]
+    fn from(index: VoxelIndex) -> Self {
+        Self::new(index.x(), index.y(), index.z())
+    }
+}
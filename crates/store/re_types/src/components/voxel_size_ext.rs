@@ -0,0 +1,32 @@
+use crate::datatypes::Vec3D;
+
+use super::VoxelSize;
+
+impl VoxelSize {
+    /// Create a new voxel size from its extent along each axis.
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(Vec3D::new(x, y, z))
+    }
+
+    /// Create a new voxel size for cubic voxels.
+    #[inline]
+    pub const fn splat(size: f32) -> Self {
+        Self(Vec3D::new(size, size, size))
+    }
+}
+
+impl Default for VoxelSize {
+    #[inline]
+    fn default() -> Self {
+        Self::splat(1.0)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<VoxelSize> for glam::Vec3 {
+    #[inline]
+    fn from(size: VoxelSize) -> Self {
+        Self::new(size.x(), size.y(), size.z())
+    }
+}
@@ -73,6 +73,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: BackgroundKind::arrow_datatype(),
             },
         ),
+        (
+            <BookmarkTime as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The time of a bookmark on a timeline.",
+                custom_placeholder: None,
+                datatype: BookmarkTime::arrow_datatype(),
+            },
+        ),
         (
             <ColumnShare as Component>::name(),
             ComponentReflection {
@@ -81,6 +89,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: ColumnShare::arrow_datatype(),
             },
         ),
+        (
+            <ComparedRecording as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The id of a recording that a view compares against the active recording.",
+                custom_placeholder: None,
+                datatype: ComparedRecording::arrow_datatype(),
+            },
+        ),
         (
             <ComponentColumnSelector as Component>::name(),
             ComponentReflection {
@@ -113,6 +129,22 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: Enabled::arrow_datatype(),
             },
         ),
+        (
+            <Eye3DKind as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The kind of the 3D eye to view a scene in a `Spatial3DView`.\n\nThis is used to specify how the controls of the view react to user input (such as mouse gestures).",
+                custom_placeholder: Some(Eye3DKind::default().to_arrow()?),
+                datatype: Eye3DKind::arrow_datatype(),
+            },
+        ),
+        (
+            <FieldOfView as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Vertical field of view of a 3D eye.",
+                custom_placeholder: Some(FieldOfView::default().to_arrow()?),
+                datatype: FieldOfView::arrow_datatype(),
+            },
+        ),
         (
             <FilterByRange as Component>::name(),
             ComponentReflection {
@@ -193,6 +225,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: LockRangeDuringZoom::arrow_datatype(),
             },
         ),
+        (
+            <LookTarget as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The point in 3D space an eye is looking at.",
+                custom_placeholder: Some(LookTarget::default().to_arrow()?),
+                datatype: LookTarget::arrow_datatype(),
+            },
+        ),
         (
             <MapProvider as Component>::name(),
             ComponentReflection {
@@ -201,6 +241,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: MapProvider::arrow_datatype(),
             },
         ),
+        (
+            <MeasurementKind as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The kind of a measurement taken in a spatial view.",
+                custom_placeholder: Some(MeasurementKind::default().to_arrow()?),
+                datatype: MeasurementKind::arrow_datatype(),
+            },
+        ),
         (
             <NearClipPlane as Component>::name(),
             ComponentReflection {
@@ -259,6 +307,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: TensorDimensionIndexSlider::arrow_datatype(),
             },
         ),
+        (
+            <TimeOffset as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A shift applied to all times of a recording.",
+                custom_placeholder: Some(TimeOffset::default().to_arrow()?),
+                datatype: TimeOffset::arrow_datatype(),
+            },
+        ),
         (
             <TimelineName as Component>::name(),
             ComponentReflection {
@@ -267,6 +323,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: TimelineName::arrow_datatype(),
             },
         ),
+        (
+            <UseRegex as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Whether a search query is a regular expression rather than a plain substring.",
+                custom_placeholder: Some(UseRegex::default().to_arrow()?),
+                datatype: UseRegex::arrow_datatype(),
+            },
+        ),
         (
             <ViewClass as Component>::name(),
             ComponentReflection {
@@ -389,6 +453,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: Blob::arrow_datatype(),
             },
         ),
+        (
+            <CellSize as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The size of a single cell of a 2D grid along each axis.\n\nMeasured in the local coordinate system of the grid.",
+                custom_placeholder: Some(CellSize::default().to_arrow()?),
+                datatype: CellSize::arrow_datatype(),
+            },
+        ),
         (
             <ClassId as Component>::name(),
             ComponentReflection {
@@ -405,6 +477,22 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: ClearIsRecursive::arrow_datatype(),
             },
         ),
+        (
+            <ClockDrift as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How much faster one clock runs than another, as a fraction.\n\nA drift of `0.001` means that the target clock advances by 1.001 units for every unit of the source clock.",
+                custom_placeholder: None,
+                datatype: ClockDrift::arrow_datatype(),
+            },
+        ),
+        (
+            <ClockOffset as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A constant offset added to the times of a timeline to express them on another timeline.\n\nMeasured in the units of the target timeline:\nnanoseconds for temporal timelines, and steps for sequence timelines.",
+                custom_placeholder: None,
+                datatype: ClockOffset::arrow_datatype(),
+            },
+        ),
         (
             <Color as Component>::name(),
             ComponentReflection {
@@ -445,6 +533,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: EntityPath::arrow_datatype(),
             },
         ),
+        (
+            <EventDuration as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The duration of a [`archetypes.TimelineEvent`](https://rerun.io/docs/reference/types/archetypes/timeline_event).\n\nMeasured in the units of the timeline the event is shown on:\nnanoseconds for temporal timelines, and steps for sequence timelines.",
+                custom_placeholder: None,
+                datatype: EventDuration::arrow_datatype(),
+            },
+        ),
         (
             <FillMode as Component>::name(),
             ComponentReflection {
@@ -565,6 +661,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: Length::arrow_datatype(),
             },
         ),
+        (
+            <LensDistortionModel as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The lens distortion model of a camera, applied on top of its pinhole projection.\n\nThe model determines how [`components.RadialDistortion`](https://rerun.io/docs/reference/types/components/radial_distortion) and [`components.TangentialDistortion`](https://rerun.io/docs/reference/types/components/tangential_distortion)\nare interpreted.\nAll models operate on normalized image coordinates, i.e. `(x, y) = (X / Z, Y / Z)`\nfor a point `(X, Y, Z)` in camera space, before the focal length and principal point are applied.",
+                custom_placeholder: Some(LensDistortionModel::default().to_arrow()?),
+                datatype: LensDistortionModel::arrow_datatype(),
+            },
+        ),
         (
             <LineStrip2D as Component>::name(),
             ComponentReflection {
@@ -701,6 +805,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: Position3D::arrow_datatype(),
             },
         ),
+        (
+            <RadialDistortion as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Radial lens distortion coefficients `[k1, k2, k3, k4]`.\n\nHow they are applied depends on the [`components.LensDistortionModel`](https://rerun.io/docs/reference/types/components/lens_distortion_model).\nFor [`components.LensDistortionModel#BrownConrady`](https://rerun.io/docs/reference/types/components/lens_distortion_model), `k4` is a non-standard `k4·r⁸` term\nwhich should be left at zero for OpenCV calibrations.",
+                custom_placeholder: Some(RadialDistortion::default().to_arrow()?),
+                datatype: RadialDistortion::arrow_datatype(),
+            },
+        ),
         (
             <Radius as Component>::name(),
             ComponentReflection {
@@ -773,6 +885,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: ShowLabels::arrow_datatype(),
             },
         ),
+        (
+            <SourceTimeline as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The name of the timeline a [`archetypes.TimelineMapping`](https://rerun.io/docs/reference/types/archetypes/timeline_mapping) maps from.",
+                custom_placeholder: None,
+                datatype: SourceTimeline::arrow_datatype(),
+            },
+        ),
         (
             <StrokeWidth as Component>::name(),
             ComponentReflection {
@@ -781,6 +901,22 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: StrokeWidth::arrow_datatype(),
             },
         ),
+        (
+            <TangentialDistortion as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Tangential lens distortion coefficients `[p1, p2]`.\n\nOnly used by the [`components.LensDistortionModel#BrownConrady`](https://rerun.io/docs/reference/types/components/lens_distortion_model) model.",
+                custom_placeholder: Some(TangentialDistortion::default().to_arrow()?),
+                datatype: TangentialDistortion::arrow_datatype(),
+            },
+        ),
+        (
+            <TargetTimeline as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The name of the timeline a [`archetypes.TimelineMapping`](https://rerun.io/docs/reference/types/archetypes/timeline_mapping) maps to.",
+                custom_placeholder: None,
+                datatype: TargetTimeline::arrow_datatype(),
+            },
+        ),
         (
             <TensorData as Component>::name(),
             ComponentReflection {
@@ -831,6 +967,14 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: Text::arrow_datatype(),
             },
         ),
+        (
+            <TextLogField as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A key-value field of a structured log entry, e.g. `(\"request_id\", \"42\")`.",
+                custom_placeholder: Some(TextLogField::default().to_arrow()?),
+                datatype: TextLogField::arrow_datatype(),
+            },
+        ),
         (
             <TextLogLevel as Component>::name(),
             ComponentReflection {
@@ -911,6 +1055,22 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 datatype: ViewCoordinates::arrow_datatype(),
             },
         ),
+        (
+            <VoxelIndex as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The integer coordinates of a voxel in a voxel grid.\n\nVoxel `[0, 0, 0]` is the one at the origin of the grid.",
+                custom_placeholder: Some(VoxelIndex::default().to_arrow()?),
+                datatype: VoxelIndex::arrow_datatype(),
+            },
+        ),
+        (
+            <VoxelSize as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The size of a single voxel of a voxel grid along each axis.\n\nMeasured in the local coordinate system of the grid.",
+                custom_placeholder: Some(VoxelSize::default().to_arrow()?),
+                datatype: VoxelSize::arrow_datatype(),
+            },
+        ),
     ];
     Ok(ComponentReflectionMap::from_iter(array))
 }
@@ -1251,6 +1411,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     "draw_order", display_name : "Draw order", component_name :
                     "rerun.components.DrawOrder".into(), docstring_md :
                     "An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.\n\nObjects with higher values are drawn on top of those with lower values.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "color_image_reference", display_name : "Color image reference",
+                    component_name : "rerun.components.EntityPath".into(), docstring_md :
+                    "An optional path to a color image that is registered with this depth image.\n\nIf set, the points of the point cloud generated from this image are colored by projecting them\ninto the referenced [`archetypes.Image`](https://rerun.io/docs/reference/types/archetypes/image) or [`archetypes.EncodedImage`](https://rerun.io/docs/reference/types/archetypes/encoded_image) instead of using the [`components.Colormap`](https://rerun.io/docs/reference/types/components/colormap).\nIf the color image is logged under a different [`archetypes.Pinhole`](https://rerun.io/docs/reference/types/archetypes/pinhole) than the depth image,\nthe intrinsics of that camera and the transform between the two cameras are taken into account.\nPoints that aren't seen by the color camera fall back to the colormap.\n\nTODO(#6744): This applies only to 3D views!",
                     is_required : false, },
                 ],
             },
@@ -1427,6 +1591,47 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.HeightField3D"),
+            ArchetypeReflection {
+                display_name: "Height field 3D",
+                scope: None,
+                view_types: &["Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "buffer", display_name : "Buffer",
+                    component_name : "rerun.components.ImageBuffer".into(), docstring_md
+                    : "The heights, row by row.", is_required : true, },
+                    ArchetypeFieldReflection { name : "format", display_name : "Format",
+                    component_name : "rerun.components.ImageFormat".into(), docstring_md
+                    : "The format of the heights.\n\nMust be single-channel.",
+                    is_required : true, }, ArchetypeFieldReflection { name : "cell_size",
+                    display_name : "Cell size", component_name :
+                    "rerun.components.CellSize".into(), docstring_md :
+                    "The distance between neighboring heights along each axis.\n\nIf not specified, heights are one unit apart.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "origin",
+                    display_name : "Origin", component_name :
+                    "rerun.components.Position2D".into(), docstring_md :
+                    "The position of height `[0, 0]` in the XY plane.\n\nIf not specified, the grid starts at the origin.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "color",
+                    display_name : "Color", component_name : "rerun.components.Color"
+                    .into(), docstring_md :
+                    "Color of the surface, if neither a colormap nor a texture is used.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colormap",
+                    display_name : "Colormap", component_name :
+                    "rerun.components.Colormap".into(), docstring_md :
+                    "If set, the surface is colored by height using this colormap.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "value_range", display_name : "Value range", component_name :
+                    "rerun.components.ValueRange".into(), docstring_md :
+                    "The range of heights that is mapped to the colormap.\n\nIf not specified, the range of the heights is used.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "texture_reference", display_name : "Texture reference",
+                    component_name : "rerun.components.EntityPath".into(), docstring_md :
+                    "Path to an [`archetypes.Image`](https://rerun.io/docs/reference/types/archetypes/image) or [`archetypes.EncodedImage`](https://rerun.io/docs/reference/types/archetypes/encoded_image) that is stretched over the entire surface.\n\nTakes precedence over the color and the colormap.\nThe image has to be logged to a different entity than the height field.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.Image"),
             ArchetypeReflection {
@@ -1593,6 +1798,47 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.OccupancyGrid"),
+            ArchetypeReflection {
+                display_name: "Occupancy grid",
+                scope: None,
+                view_types: &["Spatial2DView", "Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "buffer", display_name : "Buffer",
+                    component_name : "rerun.components.ImageBuffer".into(), docstring_md
+                    : "The raw cell values, row by row.", is_required : true, },
+                    ArchetypeFieldReflection { name : "format", display_name : "Format",
+                    component_name : "rerun.components.ImageFormat".into(), docstring_md
+                    : "The format of the cell values.\n\nMust be single-channel.",
+                    is_required : true, }, ArchetypeFieldReflection { name : "cell_size",
+                    display_name : "Cell size", component_name :
+                    "rerun.components.CellSize".into(), docstring_md :
+                    "The size of a single cell along each axis.\n\nIf not specified, cells are one unit large.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "origin",
+                    display_name : "Origin", component_name :
+                    "rerun.components.Position2D".into(), docstring_md :
+                    "The position of the corner of cell `[0, 0]`.\n\nIf not specified, the grid starts at the origin.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colormap",
+                    display_name : "Colormap", component_name :
+                    "rerun.components.Colormap".into(), docstring_md :
+                    "Colormap to use for mapping cell values to colors.\n\nIf not set, the grayscale colormap is used.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "value_range", display_name : "Value range", component_name :
+                    "rerun.components.ValueRange".into(), docstring_md :
+                    "The range of cell values that is mapped to the colormap.\n\nIf not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black\nwith the default colormap.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "opacity",
+                    display_name : "Opacity", component_name : "rerun.components.Opacity"
+                    .into(), docstring_md :
+                    "Opacity of the grid, useful for layering it over other content.\n\nDefaults to 1.0 (fully opaque).",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "draw_order", display_name : "Draw order", component_name :
+                    "rerun.components.DrawOrder".into(), docstring_md :
+                    "An optional floating point value that specifies the 2D drawing order.\n\nObjects with higher values are drawn on top of those with lower values.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.Pinhole"),
             ArchetypeReflection {
@@ -1617,6 +1863,20 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     component_name : "rerun.components.ImagePlaneDistance".into(),
                     docstring_md :
                     "The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.\n\nThis is only used for visualization purposes, and does not affect the projection itself.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "distortion_model", display_name : "Distortion model", component_name
+                    : "rerun.components.LensDistortionModel".into(), docstring_md :
+                    "The lens distortion model, determining how the distortion coefficients are applied.\n\nDefaults to [`components.LensDistortionModel#BrownConrady`](https://rerun.io/docs/reference/types/components/lens_distortion_model) if any distortion coefficients are set.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "radial_distortion", display_name : "Radial distortion",
+                    component_name : "rerun.components.RadialDistortion".into(),
+                    docstring_md :
+                    "Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.\n\nFor the [`components.LensDistortionModel#BrownConrady`](https://rerun.io/docs/reference/types/components/lens_distortion_model) model, `k4` is a non-standard `k4·r⁸` term\nwhich should be left at zero for OpenCV calibrations.\nIf neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "tangential_distortion", display_name : "Tangential distortion",
+                    component_name : "rerun.components.TangentialDistortion".into(),
+                    docstring_md :
+                    "Tangential distortion coefficients `[p1, p2]` of the lens.\n\nOnly used by the [`components.LensDistortionModel#BrownConrady`](https://rerun.io/docs/reference/types/components/lens_distortion_model) model.",
                     is_required : false, },
                 ],
             },
@@ -1839,6 +2099,61 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     display_name : "Color", component_name : "rerun.components.Color"
                     .into(), docstring_md :
                     "Optional color to use for the log line in the Rerun Viewer.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "fields",
+                    display_name : "Fields", component_name :
+                    "rerun.components.TextLogField".into(), docstring_md :
+                    "Optional key-value fields of a structured log entry.\n\nThe Rerun Viewer shows each key as a column of the text log.\nThe fields apply to all entries of the same row.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.archetypes.TimelineEvent"),
+            ArchetypeReflection {
+                display_name: "Timeline event",
+                scope: None,
+                view_types: &["TextLogView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "label", display_name : "Label",
+                    component_name : "rerun.components.Text".into(), docstring_md :
+                    "A short description of the event.", is_required : true, },
+                    ArchetypeFieldReflection { name : "level", display_name : "Level",
+                    component_name : "rerun.components.TextLogLevel".into(), docstring_md
+                    :
+                    "The severity of the event.\n\nEvents are colored by severity unless a color is specified.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "color",
+                    display_name : "Color", component_name : "rerun.components.Color"
+                    .into(), docstring_md : "Optional color of the event's marker.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "duration",
+                    display_name : "Duration", component_name :
+                    "rerun.components.EventDuration".into(), docstring_md :
+                    "Optional duration of the event, turning its marker into a span.\n\nMeasured in the units of the timeline the event is shown on:\nnanoseconds for temporal timelines, and steps for sequence timelines.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.archetypes.TimelineMapping"),
+            ArchetypeReflection {
+                display_name: "Timeline mapping",
+                scope: None,
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "source", display_name : "Source",
+                    component_name : "rerun.components.SourceTimeline".into(),
+                    docstring_md : "The timeline the mapping translates from.",
+                    is_required : true, }, ArchetypeFieldReflection { name : "target",
+                    display_name : "Target", component_name :
+                    "rerun.components.TargetTimeline".into(), docstring_md :
+                    "The timeline the mapping translates to.", is_required : true, },
+                    ArchetypeFieldReflection { name : "offset", display_name : "Offset",
+                    component_name : "rerun.components.ClockOffset".into(), docstring_md
+                    :
+                    "Constant offset of the linear clock model, in units of the target timeline.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "drift",
+                    display_name : "Drift", component_name :
+                    "rerun.components.ClockDrift".into(), docstring_md :
+                    "Drift of the linear clock model, as a fraction of the source time.",
                     is_required : false, },
                 ],
             },
@@ -1911,6 +2226,48 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.VoxelGrid3D"),
+            ArchetypeReflection {
+                display_name: "Voxel grid 3D",
+                scope: None,
+                view_types: &["Spatial3DView", "Spatial2DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "voxel_size", display_name :
+                    "Voxel size", component_name : "rerun.components.VoxelSize".into(),
+                    docstring_md : "The size of a single voxel along each axis.",
+                    is_required : true, }, ArchetypeFieldReflection { name :
+                    "voxel_indices", display_name : "Voxel indices", component_name :
+                    "rerun.components.VoxelIndex".into(), docstring_md :
+                    "The indices of the occupied voxels of a sparse grid.", is_required :
+                    false, }, ArchetypeFieldReflection { name : "colors", display_name :
+                    "Colors", component_name : "rerun.components.Color".into(),
+                    docstring_md :
+                    "Optional colors for the voxels.\n\nTakes precedence over the colormap.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "dense_values", display_name : "Dense values", component_name :
+                    "rerun.components.TensorData".into(), docstring_md :
+                    "The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.\n\nVoxels with a value of zero or NaN are empty, all others are occupied.\nSigned distance volumes such as a TSDF need to be thresholded into occupancy first.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "values",
+                    display_name : "Values", component_name : "rerun.components.Scalar"
+                    .into(), docstring_md :
+                    "Optional values of the voxels of a sparse grid, mapped through the colormap.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "origin",
+                    display_name : "Origin", component_name :
+                    "rerun.components.Position3D".into(), docstring_md :
+                    "The position of the corner of voxel `[0, 0, 0]`.\n\nIf not specified, the grid starts at the origin.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colormap",
+                    display_name : "Colormap", component_name :
+                    "rerun.components.Colormap".into(), docstring_md :
+                    "Colormap to use for mapping voxel values to colors.\n\nIf not set, the Viridis colormap is used.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "value_range", display_name : "Value range", component_name :
+                    "rerun.components.ValueRange".into(), docstring_md :
+                    "The range of voxel values that is mapped to the colormap.\n\nIf not specified, the range is estimated from the data.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.Background"),
             ArchetypeReflection {
@@ -1927,6 +2284,25 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.ClipPlanes3D"),
+            ArchetypeReflection {
+                display_name: "Clip planes 3D",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "enabled", display_name :
+                    "Enabled", component_name : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether the clipping planes are applied.\n\nDefaults to false.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "planes",
+                    display_name : "Planes", component_name : "rerun.components.Plane3D"
+                    .into(), docstring_md :
+                    "The clipping planes in world-space.\n\nAt most 8 planes are supported, additional planes are ignored.\nWhile the section box is enabled, only the first 2 planes are applied.\nDefaults to a single plane through the center of the scene, facing up.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ContainerBlueprint"),
             ArchetypeReflection {
@@ -2001,6 +2377,45 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.EyeControls3D"),
+            ArchetypeReflection {
+                display_name: "Eye controls 3D",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "kind", display_name : "Kind",
+                    component_name : "rerun.blueprint.components.Eye3DKind".into(),
+                    docstring_md :
+                    "The kind of the eye for the spatial 3D view.\n\nThis controls how the eye reacts to user input, e.g. mouse dragging.\nDefaults to orbital.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "position",
+                    display_name : "Position", component_name :
+                    "rerun.components.Position3D".into(), docstring_md :
+                    "The world-space position of the eye.\n\nOnly used if `look_target` is set as well.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "look_target", display_name : "Look target", component_name :
+                    "rerun.blueprint.components.LookTarget".into(), docstring_md :
+                    "The world-space point the eye is looking at.\n\nFor orbital eyes this is the center of rotation.\nOnly used if `position` is set as well.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "eye_up",
+                    display_name : "Eye up", component_name : "rerun.components.Vector3D"
+                    .into(), docstring_md :
+                    "The up-axis of the eye in world-space.\n\nDefaults to the up-axis of the scene as defined by [`components.ViewCoordinates`](https://rerun.io/docs/reference/types/components/view_coordinates), or +Z if not specified.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "fov_y",
+                    display_name : "Fov y", component_name :
+                    "rerun.blueprint.components.FieldOfView".into(), docstring_md :
+                    "Vertical field of view of the eye.\n\nDefaults to 55°.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "tracking_entity", display_name : "Tracking entity", component_name :
+                    "rerun.components.EntityPath".into(), docstring_md :
+                    "An entity the eye follows.\n\nWhile set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "tracking_offset", display_name : "Tracking offset", component_name :
+                    "rerun.components.Translation3D".into(), docstring_md :
+                    "Offset of the eye from the tracked entity, in the entity's local coordinate frame.\n\nThe eye is placed at this offset and looks at the origin of the tracked entity,\ne.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.\nIf not set, the eye keeps its orientation & distance and only follows the entity's position.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ForceCenter"),
             ArchetypeReflection {
@@ -2168,6 +2583,24 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.Measurements"),
+            ArchetypeReflection {
+                display_name: "Measurements",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "kinds", display_name : "Kinds",
+                    component_name : "rerun.blueprint.components.MeasurementKind".into(),
+                    docstring_md : "The kind of each measurement.", is_required : false,
+                    }, ArchetypeFieldReflection { name : "points", display_name :
+                    "Points", component_name : "rerun.components.LineStrip3D".into(),
+                    docstring_md :
+                    "The points of each measurement, in the coordinate space of the view's origin.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.NearClipPlane"),
             ArchetypeReflection {
@@ -2216,6 +2649,25 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.RecordingComparison"),
+            ArchetypeReflection {
+                display_name: "Recording comparison",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "recording", display_name :
+                    "Recording", component_name :
+                    "rerun.blueprint.components.ComparedRecording".into(), docstring_md :
+                    "The id of the recording to compare against.\n\nIf not set, or if no recording with this id is loaded, the view only shows the active recording.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "time_offset", display_name : "Time offset", component_name :
+                    "rerun.blueprint.components.TimeOffset".into(), docstring_md :
+                    "How much later things happen in the active recording than in the compared recording.\n\nAdded to all times of the compared recording so that both recordings share one time cursor.\nIn the unit of the currently selected timeline, defaults to zero.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ScalarAxis"),
             ArchetypeReflection {
@@ -2235,6 +2687,33 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.SectionBox3D"),
+            ArchetypeReflection {
+                display_name: "Section box 3D",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "enabled", display_name :
+                    "Enabled", component_name : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether the section box is applied.\n\nDefaults to false.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "center",
+                    display_name : "Center", component_name :
+                    "rerun.components.Position3D".into(), docstring_md :
+                    "The center of the box in world-space.\n\nDefaults to the center of the scene's bounding box.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "half_size", display_name : "Half size", component_name :
+                    "rerun.components.HalfSize3D".into(), docstring_md :
+                    "Half of the box's size along each of its local axes.\n\nDefaults to half the size of the scene's bounding box.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "rotation",
+                    display_name : "Rotation", component_name :
+                    "rerun.components.RotationQuat".into(), docstring_md :
+                    "Rotation of the box in world-space.\n\nDefaults to no rotation, i.e. an axis-aligned box.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.TensorScalarMapping"),
             ArchetypeReflection {
@@ -2301,6 +2780,45 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TextLogSearch"),
+            ArchetypeReflection {
+                display_name: "Text log search",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "query", display_name : "Query",
+                    component_name : "rerun.components.Text".into(), docstring_md :
+                    "The text to search for.\n\nMatched case-insensitively as a substring, unless `use_regex` is set.\nAn empty query shows all entries.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "use_regex", display_name : "Use regex", component_name :
+                    "rerun.blueprint.components.UseRegex".into(), docstring_md :
+                    "Whether `query` is a regular expression.\n\nRegular expressions are case-sensitive unless they start with `(?i)`.\nDefaults to false.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TimelineBookmarks"),
+            ArchetypeReflection {
+                display_name: "Timeline bookmarks",
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "timelines", display_name :
+                    "Timelines", component_name :
+                    "rerun.blueprint.components.TimelineName".into(), docstring_md :
+                    "The timeline of each bookmark.", is_required : false, },
+                    ArchetypeFieldReflection { name : "times", display_name : "Times",
+                    component_name : "rerun.blueprint.components.BookmarkTime".into(),
+                    docstring_md : "The time of each bookmark on its timeline.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "labels",
+                    display_name : "Labels", component_name : "rerun.components.Name"
+                    .into(), docstring_md : "The label of each bookmark.", is_required :
+                    false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ViewBlueprint"),
             ArchetypeReflection {
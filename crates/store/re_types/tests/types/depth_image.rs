@@ -29,6 +29,7 @@ fn depth_image_roundtrip() {
         colormap: None,
         point_fill_ratio: None,
         depth_range: None,
+        color_image_reference: None,
     }];
 
     let all_arch_serialized = [
@@ -17,11 +17,20 @@ fn roundtrip() {
             .serialized()
             .map(|batch| batch.with_descriptor_override(Pinhole::descriptor_camera_xyz())),
         image_plane_distance: None,
+        distortion_model: components::LensDistortionModel::KannalaBrandt
+            .serialized()
+            .map(|batch| batch.with_descriptor_override(Pinhole::descriptor_distortion_model())),
+        radial_distortion: components::RadialDistortion([0.1, -0.2, 0.3, -0.4].into())
+            .serialized()
+            .map(|batch| batch.with_descriptor_override(Pinhole::descriptor_radial_distortion())),
+        tangential_distortion: None,
     };
 
     let arch = Pinhole::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
         .with_resolution([1.0, 2.0])
-        .with_camera_xyz(components::ViewCoordinates::RDF);
+        .with_camera_xyz(components::ViewCoordinates::RDF)
+        .with_distortion_model(components::LensDistortionModel::KannalaBrandt)
+        .with_radial_distortion([0.1, -0.2, 0.3, -0.4]);
     similar_asserts::assert_eq!(expected, arch);
 
     eprintln!("arch = {arch:#?}");
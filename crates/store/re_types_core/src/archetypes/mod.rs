@@ -2,5 +2,7 @@
 
 mod clear;
 mod clear_ext;
+mod timeline_mapping;
 
 pub use self::clear::Clear;
+pub use self::timeline_mapping::TimelineMapping;
@@ -0,0 +1,419 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_mapping.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use crate::try_serialize_field;
+use crate::SerializationResult;
+use crate::{ComponentBatch, SerializedComponentBatch};
+use crate::{ComponentDescriptor, ComponentName};
+use crate::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Declares how the times of one timeline translate to times on another timeline.
+///
+/// This relates timelines that data was logged on independently, e.g. `frame_nr`, `sensor_time` and `log_time`,
+/// so that data logged on one of them can be queried at a time expressed on the other.
+/// The mapping is used in both directions.
+///
+/// If a clock offset or drift is set, the timelines are related by a linear clock model:
+/// `target = source + offset + drift * source`.
+///
+/// Otherwise the mapping is a correspondence table: every time it is logged with both timelines set,
+/// it records one pair of corresponding times.
+/// Times in between two pairs are interpolated linearly, times beyond the first or last pair are extrapolated.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct TimelineMapping {
+    /// The timeline the mapping translates from.
+    pub source: Option<SerializedComponentBatch>,
+
+    /// The timeline the mapping translates to.
+    pub target: Option<SerializedComponentBatch>,
+
+    /// Constant offset of the linear clock model, in units of the target timeline.
+    pub offset: Option<SerializedComponentBatch>,
+
+    /// Drift of the linear clock model, as a fraction of the source time.
+    pub drift: Option<SerializedComponentBatch>,
+}
+
+impl TimelineMapping {
+    /// Returns the [`ComponentDescriptor`] for [`Self::source`].
+    #[inline]
+    pub fn descriptor_source() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineMapping".into()),
+            component_name: "rerun.components.SourceTimeline".into(),
+            archetype_field_name: Some("source".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::target`].
+    #[inline]
+    pub fn descriptor_target() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineMapping".into()),
+            component_name: "rerun.components.TargetTimeline".into(),
+            archetype_field_name: Some("target".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::offset`].
+    #[inline]
+    pub fn descriptor_offset() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineMapping".into()),
+            component_name: "rerun.components.ClockOffset".into(),
+            archetype_field_name: Some("offset".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::drift`].
+    #[inline]
+    pub fn descriptor_drift() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineMapping".into()),
+            component_name: "rerun.components.ClockDrift".into(),
+            archetype_field_name: Some("drift".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.TimelineMapping".into()),
+            component_name: "rerun.components.TimelineMappingIndicator".into(),
+            archetype_field_name: None,
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineMapping::descriptor_source(),
+            TimelineMapping::descriptor_target(),
+        ]
+    });
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TimelineMapping::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineMapping::descriptor_offset(),
+            TimelineMapping::descriptor_drift(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TimelineMapping::descriptor_source(),
+            TimelineMapping::descriptor_target(),
+            TimelineMapping::descriptor_indicator(),
+            TimelineMapping::descriptor_offset(),
+            TimelineMapping::descriptor_drift(),
+        ]
+    });
+
+impl TimelineMapping {
+    /// The total number of components in the archetype: 2 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+/// Indicator component for the [`TimelineMapping`] [`crate::Archetype`]
+pub type TimelineMappingIndicator = crate::GenericIndicatorComponent<TimelineMapping>;
+
+impl crate::Archetype for TimelineMapping {
+    type Indicator = TimelineMappingIndicator;
+
+    #[inline]
+    fn name() -> crate::ArchetypeName {
+        "rerun.archetypes.TimelineMapping".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Timeline mapping"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TimelineMappingIndicator::DEFAULT.serialized().unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use crate::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let source = arrays_by_descr
+            .get(&Self::descriptor_source())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_source()));
+        let target = arrays_by_descr
+            .get(&Self::descriptor_target())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_target()));
+        let offset = arrays_by_descr
+            .get(&Self::descriptor_offset())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_offset()));
+        let drift = arrays_by_descr
+            .get(&Self::descriptor_drift())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_drift()));
+        Ok(Self {
+            source,
+            target,
+            offset,
+            drift,
+        })
+    }
+}
+
+impl crate::AsComponents for TimelineMapping {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use crate::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.source.clone(),
+            self.target.clone(),
+            self.offset.clone(),
+            self.drift.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl crate::ArchetypeReflectionMarker for TimelineMapping {}
+
+impl TimelineMapping {
+    /// Create a new `TimelineMapping`.
+    #[inline]
+    pub fn new(
+        source: impl Into<crate::components::SourceTimeline>,
+        target: impl Into<crate::components::TargetTimeline>,
+    ) -> Self {
+        Self {
+            source: try_serialize_field(Self::descriptor_source(), [source]),
+            target: try_serialize_field(Self::descriptor_target(), [target]),
+            offset: None,
+            drift: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TimelineMapping`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TimelineMapping`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use crate::Loggable as _;
+        Self {
+            source: Some(SerializedComponentBatch::new(
+                crate::components::SourceTimeline::arrow_empty(),
+                Self::descriptor_source(),
+            )),
+            target: Some(SerializedComponentBatch::new(
+                crate::components::TargetTimeline::arrow_empty(),
+                Self::descriptor_target(),
+            )),
+            offset: Some(SerializedComponentBatch::new(
+                crate::components::ClockOffset::arrow_empty(),
+                Self::descriptor_offset(),
+            )),
+            drift: Some(SerializedComponentBatch::new(
+                crate::components::ClockDrift::arrow_empty(),
+                Self::descriptor_drift(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [crate::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = crate::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.source
+                .map(|source| source.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.target
+                .map(|target| target.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.offset
+                .map(|offset| offset.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.drift
+                .map(|drift| drift.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([crate::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = crate::SerializedComponentColumn>> {
+        let len_source = self.source.as_ref().map(|b| b.array.len());
+        let len_target = self.target.as_ref().map(|b| b.array.len());
+        let len_offset = self.offset.as_ref().map(|b| b.array.len());
+        let len_drift = self.drift.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_source)
+            .or(len_target)
+            .or(len_offset)
+            .or(len_drift)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The timeline the mapping translates from.
+    #[inline]
+    pub fn with_source(mut self, source: impl Into<crate::components::SourceTimeline>) -> Self {
+        self.source = try_serialize_field(Self::descriptor_source(), [source]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::SourceTimeline`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_source`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_source(
+        mut self,
+        source: impl IntoIterator<Item = impl Into<crate::components::SourceTimeline>>,
+    ) -> Self {
+        self.source = try_serialize_field(Self::descriptor_source(), source);
+        self
+    }
+
+    /// The timeline the mapping translates to.
+    #[inline]
+    pub fn with_target(mut self, target: impl Into<crate::components::TargetTimeline>) -> Self {
+        self.target = try_serialize_field(Self::descriptor_target(), [target]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::TargetTimeline`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_target`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_target(
+        mut self,
+        target: impl IntoIterator<Item = impl Into<crate::components::TargetTimeline>>,
+    ) -> Self {
+        self.target = try_serialize_field(Self::descriptor_target(), target);
+        self
+    }
+
+    /// Constant offset of the linear clock model, in units of the target timeline.
+    #[inline]
+    pub fn with_offset(mut self, offset: impl Into<crate::components::ClockOffset>) -> Self {
+        self.offset = try_serialize_field(Self::descriptor_offset(), [offset]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ClockOffset`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_offset`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_offset(
+        mut self,
+        offset: impl IntoIterator<Item = impl Into<crate::components::ClockOffset>>,
+    ) -> Self {
+        self.offset = try_serialize_field(Self::descriptor_offset(), offset);
+        self
+    }
+
+    /// Drift of the linear clock model, as a fraction of the source time.
+    #[inline]
+    pub fn with_drift(mut self, drift: impl Into<crate::components::ClockDrift>) -> Self {
+        self.drift = try_serialize_field(Self::descriptor_drift(), [drift]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ClockDrift`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_drift`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_drift(
+        mut self,
+        drift: impl IntoIterator<Item = impl Into<crate::components::ClockDrift>>,
+    ) -> Self {
+        self.drift = try_serialize_field(Self::descriptor_drift(), drift);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TimelineMapping {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.source.heap_size_bytes()
+            + self.target.heap_size_bytes()
+            + self.offset.heap_size_bytes()
+            + self.drift.heap_size_bytes()
+    }
+}
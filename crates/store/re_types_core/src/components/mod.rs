@@ -2,5 +2,15 @@
 
 mod clear_is_recursive;
 mod clear_is_recursive_ext;
+mod clock_drift;
+mod clock_offset;
+mod source_timeline;
+mod source_timeline_ext;
+mod target_timeline;
+mod target_timeline_ext;
 
 pub use self::clear_is_recursive::ClearIsRecursive;
+pub use self::clock_drift::ClockDrift;
+pub use self::clock_offset::ClockOffset;
+pub use self::source_timeline::SourceTimeline;
+pub use self::target_timeline::TargetTimeline;
@@ -0,0 +1,9 @@
+use super::TargetTimeline;
+
+impl TargetTimeline {
+    /// Returns the name of the timeline as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
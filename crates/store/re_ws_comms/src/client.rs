@@ -1,7 +1,13 @@
 use std::ops::ControlFlow;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 use ewebsock::{WsEvent, WsMessage};
 
+use re_log_types::ViewerEvent;
+
 // TODO(jleibs): use thiserror
 pub type Result<T> = anyhow::Result<T>;
 
@@ -58,3 +64,55 @@ pub fn viewer_to_server(
     )
     .map_err(|err| anyhow::format_err!("ewebsock: {err}"))
 }
+
+/// Sends [`ViewerEvent`]s back to a Rerun WebSocket server, e.g. one hosted with `rerun --serve-web`.
+///
+/// Uses its own connection, separate from the one log messages are received on.
+pub struct ViewerEventPublisher {
+    sender: ewebsock::WsSender,
+    is_open: Arc<AtomicBool>,
+}
+
+impl ViewerEventPublisher {
+    /// Connect to the server at `url`, i.e. the same url log messages are received from.
+    pub fn new(url: &str) -> Result<Self> {
+        let url = format!("{}{}", url.trim_end_matches('/'), crate::VIEWER_EVENTS_PATH);
+
+        let is_open = Arc::new(AtomicBool::new(false));
+        let sender = ewebsock::ws_connect(
+            url.clone(),
+            ewebsock::Options::default(),
+            Box::new({
+                let is_open = is_open.clone();
+                move |event: WsEvent| match event {
+                    WsEvent::Opened => {
+                        re_log::debug!("Publishing viewer events to {url}");
+                        is_open.store(true, Ordering::Relaxed);
+                        ControlFlow::Continue(())
+                    }
+                    WsEvent::Message(_) => ControlFlow::Continue(()),
+                    WsEvent::Error(error) => {
+                        re_log::debug!("Stopped publishing viewer events to {url}: {error}");
+                        is_open.store(false, Ordering::Relaxed);
+                        ControlFlow::Break(())
+                    }
+                    WsEvent::Closed => {
+                        is_open.store(false, Ordering::Relaxed);
+                        ControlFlow::Break(())
+                    }
+                }
+            }),
+        )
+        .map_err(|err| anyhow::format_err!("ewebsock: {err}"))?;
+
+        Ok(Self { sender, is_open })
+    }
+
+    /// Events published while the connection isn't open are dropped.
+    pub fn publish(&mut self, event: &ViewerEvent) {
+        if self.is_open.load(Ordering::Relaxed) {
+            self.sender
+                .send(WsMessage::Binary(crate::encode_viewer_event(event)));
+        }
+    }
+}
@@ -12,14 +12,14 @@ mod client;
 use std::{fmt::Display, str::FromStr};
 
 #[cfg(feature = "client")]
-pub use client::viewer_to_server;
+pub use client::{viewer_to_server, ViewerEventPublisher};
 
 #[cfg(feature = "server")]
 mod server;
 #[cfg(feature = "server")]
 pub use server::RerunServer;
 
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerEvent};
 
 pub const DEFAULT_WS_SERVER_PORT: u16 = 9877;
 
@@ -79,6 +79,10 @@ impl FromStr for RerunServerPort {
     }
 }
 
+/// Viewers connect to this path of the server to send [`ViewerEvent`]s back to it,
+/// on a connection that is separate from the one they receive log messages on.
+pub const VIEWER_EVENTS_PATH: &str = "/viewer_events";
+
 /// Add a protocol (`ws://` or `wss://`) to the given address.
 pub fn server_url(local_addr: &std::net::SocketAddr) -> String {
     if local_addr.ip().is_unspecified() {
@@ -110,3 +114,25 @@ pub fn decode_log_msg(data: &[u8]) -> Result<LogMsg, RerunServerError> {
     use bincode::Options as _;
     Ok(bincode::DefaultOptions::new().deserialize(payload)?)
 }
+
+const VIEWER_EVENT_PREFIX: [u8; 4] = *b"RE00";
+
+pub fn encode_viewer_event(event: &ViewerEvent) -> Vec<u8> {
+    re_tracing::profile_function!();
+    use bincode::Options as _;
+    let mut bytes = VIEWER_EVENT_PREFIX.to_vec();
+    bincode::DefaultOptions::new()
+        .serialize_into(&mut bytes, event)
+        .unwrap();
+    bytes
+}
+
+pub fn decode_viewer_event(data: &[u8]) -> Result<ViewerEvent, RerunServerError> {
+    re_tracing::profile_function!();
+    let payload = data
+        .strip_prefix(&VIEWER_EVENT_PREFIX)
+        .ok_or(RerunServerError::InvalidMessagePrefix)?;
+
+    use bincode::Options as _;
+    Ok(bincode::DefaultOptions::new().deserialize(payload)?)
+}
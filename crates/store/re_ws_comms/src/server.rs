@@ -17,14 +17,20 @@ use std::{
 
 use parking_lot::Mutex;
 use polling::{Event, Poller};
-use tungstenite::WebSocket;
+use tungstenite::{
+    handshake::server::{ErrorResponse, Request, Response},
+    WebSocket,
+};
 
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, ViewerEvent};
 use re_memory::MemoryLimit;
 use re_smart_channel::ReceiveSet;
 
 use crate::{server_url, RerunServerError, RerunServerPort};
 
+/// Everyone who subscribed via [`RerunServer::viewer_events`].
+type ViewerEventSubscribers = Arc<Mutex<Vec<std::sync::mpsc::Sender<ViewerEvent>>>>;
+
 struct MessageQueue {
     server_memory_limit: MemoryLimit,
     messages: VecDeque<Vec<u8>>,
@@ -105,6 +111,8 @@ pub struct RerunServer {
 
     /// Total count; never decreasing.
     num_accepted_clients: Arc<AtomicU64>,
+
+    viewer_event_subscribers: ViewerEventSubscribers,
 }
 
 impl RerunServer {
@@ -145,6 +153,8 @@ impl RerunServer {
         let poller_copy = poller.clone();
         let shutdown_flag_copy = shutdown_flag.clone();
         let num_clients_copy = num_accepted_clients.clone();
+        let viewer_event_subscribers = ViewerEventSubscribers::default();
+        let viewer_event_subscribers_copy = viewer_event_subscribers.clone();
 
         let listener_join_handle = std::thread::Builder::new()
             .name("rerun_ws_server: listener".to_owned())
@@ -155,6 +165,7 @@ impl RerunServer {
                     &ReceiveSetBroadcaster::new(rerun_rx, server_memory_limit),
                     &shutdown_flag,
                     &num_accepted_clients,
+                    &viewer_event_subscribers,
                 );
             })?;
 
@@ -164,6 +175,7 @@ impl RerunServer {
             listener_join_handle: Some(listener_join_handle),
             shutdown_flag: shutdown_flag_copy,
             num_accepted_clients: num_clients_copy,
+            viewer_event_subscribers: viewer_event_subscribers_copy,
         };
 
         re_log::info!(
@@ -184,6 +196,16 @@ impl RerunServer {
         self.num_accepted_clients.load(Ordering::Relaxed)
     }
 
+    /// Subscribe to the [`ViewerEvent`]s that connected viewers send back to this server,
+    /// e.g. when the user selects an entity or moves the time cursor.
+    ///
+    /// Only events sent after subscribing are received.
+    pub fn viewer_events(&self) -> std::sync::mpsc::Receiver<ViewerEvent> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.viewer_event_subscribers.lock().push(tx);
+        rx
+    }
+
     /// Blocks execution as long as the server is running.
     ///
     /// There's no way of shutting the server down from the outside right now.
@@ -199,6 +221,7 @@ impl RerunServer {
         message_broadcaster: &ReceiveSetBroadcaster,
         shutdown_flag: &AtomicBool,
         num_accepted_clients: &AtomicU64,
+        viewer_event_subscribers: &ViewerEventSubscribers,
     ) {
         // Each socket in `poll::Poller` needs a "name".
         // Doesn't matter much what we're using here, as long as it's not used for something else
@@ -232,6 +255,7 @@ impl RerunServer {
                         poller,
                         listener_poll_key,
                         num_accepted_clients,
+                        viewer_event_subscribers,
                     );
                 }
             }
@@ -248,6 +272,7 @@ impl RerunServer {
         poller: &Poller,
         listener_poll_key: usize,
         num_accepted_clients: &AtomicU64,
+        viewer_event_subscribers: &ViewerEventSubscribers,
     ) {
         match listener_socket.accept() {
             Ok((tcp_stream, _)) => {
@@ -258,7 +283,20 @@ impl RerunServer {
 
                 re_log::debug!("New WebSocket connection from {address:?}");
 
-                match tungstenite::accept(tcp_stream) {
+                // Viewers send events back on a separate connection, see `VIEWER_EVENTS_PATH`.
+                let mut path = String::new();
+                let accept_result = tungstenite::accept_hdr(
+                    tcp_stream,
+                    |request: &Request, response: Response| -> Result<Response, ErrorResponse> {
+                        request.uri().path().clone_into(&mut path);
+                        Ok(response)
+                    },
+                );
+
+                match accept_result {
+                    Ok(ws_stream) if path == crate::VIEWER_EVENTS_PATH => {
+                        Self::spawn_viewer_event_reader(ws_stream, viewer_event_subscribers);
+                    }
                     Ok(ws_stream) => {
                         message_broadcaster.add_client(ws_stream);
                         num_accepted_clients.fetch_add(1, Ordering::Relaxed);
@@ -280,6 +318,43 @@ impl RerunServer {
         }
     }
 
+    /// Reads the events a viewer sends us, until it disconnects.
+    fn spawn_viewer_event_reader(
+        mut ws_stream: WebSocket<TcpStream>,
+        viewer_event_subscribers: &ViewerEventSubscribers,
+    ) {
+        let viewer_event_subscribers = viewer_event_subscribers.clone();
+
+        let result = std::thread::Builder::new()
+            .name("rerun_ws_server: viewer events".to_owned())
+            .spawn(move || loop {
+                match ws_stream.read() {
+                    Ok(tungstenite::Message::Binary(data)) => {
+                        match crate::decode_viewer_event(&data) {
+                            Ok(event) => {
+                                viewer_event_subscribers
+                                    .lock()
+                                    .retain(|tx| tx.send(event.clone()).is_ok());
+                            }
+                            Err(err) => {
+                                re_log::warn_once!("Received an invalid viewer event: {err}");
+                            }
+                        }
+                    }
+                    Ok(tungstenite::Message::Close(_)) => break,
+                    Ok(_) => {}
+                    Err(err) => {
+                        re_log::debug!("Stopped receiving viewer events: {err}");
+                        break;
+                    }
+                }
+            });
+
+        if let Err(err) = result {
+            re_log::warn!("Failed to spawn thread for receiving viewer events: {err}");
+        }
+    }
+
     fn stop_listener(&mut self) {
         let Some(join_handle) = self.listener_join_handle.take() else {
             return;
@@ -73,6 +73,17 @@ pub mod sink {
     #[cfg(feature = "grpc")]
     pub use crate::log_sink::grpc::GrpcSink;
 
+    #[cfg(feature = "grpc")]
+    pub use re_grpc_client::message_proxy::{
+        write::{Jwt, Options as GrpcSinkOptions},
+        ViewerCommandError, ViewerRemote,
+    };
+
+    pub use re_log_types::{ViewerEvent, ViewerEventItem, ViewerEventKind};
+
+    #[cfg(feature = "grpc")]
+    pub use re_log_types::{ViewerCommand, ViewerPlayState};
+
     #[cfg(not(target_arch = "wasm32"))]
     pub use re_log_encoding::{FileSink, FileSinkError};
 }
@@ -349,6 +349,27 @@ impl TcpSink {
             client: re_sdk_comms::Client::new(addr, flush_timeout),
         }
     }
+
+    /// Subscribe to events published by the viewer we are connected to,
+    /// e.g. when the user selects an entity, moves the time cursor, or presses a custom button.
+    ///
+    /// The viewer is only asked for events if someone is subscribed when the connection is
+    /// established, which happens when the first message is sent.
+    /// Subscribe before handing the sink over to a [`crate::RecordingStream`]:
+    ///
+    /// ```ignore
+    /// let sink = TcpSink::new(re_sdk::default_server_addr(), re_sdk::default_flush_timeout());
+    /// let viewer_events = sink.viewer_events();
+    /// rec.set_sink(Box::new(sink));
+    ///
+    /// for event in viewer_events {
+    ///     println!("{event:?}");
+    /// }
+    /// ```
+    #[inline]
+    pub fn viewer_events(&self) -> std::sync::mpsc::Receiver<re_log_types::ViewerEvent> {
+        self.client.viewer_events()
+    }
 }
 
 impl LogSink for TcpSink {
@@ -371,8 +392,8 @@ impl LogSink for TcpSink {
 #[cfg(feature = "grpc")]
 pub mod grpc {
     use super::LogSink;
-    use re_grpc_client::message_proxy::write::Client;
-    use re_log_types::LogMsg;
+    use re_grpc_client::message_proxy::write::{Client, Options, ViewerRemote};
+    use re_log_types::{LogMsg, ViewerEvent};
 
     /// Stream log messages to an in-memory storage node.
     pub struct GrpcSink {
@@ -393,6 +414,66 @@ pub mod grpc {
                 client: Client::new(addr, Default::default()),
             }
         }
+
+        /// Connect to the in-memory storage node, e.g. with an auth token or over TLS.
+        ///
+        /// ### Example
+        ///
+        /// ```ignore
+        /// let token = Jwt::try_from(std::env::var("RERUN_AUTH_TOKEN")?)?;
+        /// GrpcSink::new_with_options(
+        ///     "https://lab-server:1852",
+        ///     GrpcSinkOptions::default().with_auth_token(token),
+        /// );
+        /// ```
+        #[inline]
+        pub fn new_with_options(addr: impl Into<String>, options: Options) -> Self {
+            Self {
+                client: Client::new(addr, options),
+            }
+        }
+
+        /// Subscribe to events published by viewers connected to the same storage node,
+        /// e.g. when the user selects an entity or moves the time cursor.
+        ///
+        /// Only events published after subscribing are received.
+        /// Subscribe before handing the sink over to a [`crate::RecordingStream`]:
+        ///
+        /// ```ignore
+        /// let sink = GrpcSink::new("http://127.0.0.1:9434");
+        /// let viewer_events = sink.viewer_events();
+        /// rec.set_sink(Box::new(sink));
+        ///
+        /// for event in viewer_events {
+        ///     println!("{event:?}");
+        /// }
+        /// ```
+        #[inline]
+        pub fn viewer_events(&self) -> std::sync::mpsc::Receiver<ViewerEvent> {
+            self.client.viewer_events()
+        }
+
+        /// Remote control the viewers connected to the same storage node,
+        /// e.g. to move the time cursor, select entities or take screenshots.
+        ///
+        /// The returned handle stays usable after handing the sink over to a [`crate::RecordingStream`]:
+        ///
+        /// ```ignore
+        /// let sink = GrpcSink::new("http://127.0.0.1:9434");
+        /// let viewer = sink.viewer_remote();
+        /// rec.set_sink(Box::new(sink));
+        ///
+        /// rec.flush_blocking();
+        /// viewer.send(ViewerCommand::SetTime {
+        ///     timeline: "frame_nr".into(),
+        ///     time: Some(TimeInt::new_temporal(42)),
+        /// })?;
+        /// viewer.send(ViewerCommand::Screenshot { path: Some("frame_42.png".to_owned()) })?;
+        /// ```
+        #[inline]
+        pub fn viewer_remote(&self) -> ViewerRemote {
+            self.client.viewer_remote()
+        }
     }
 
     impl LogSink for GrpcSink {
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use anyhow::Context as _;
+use clap::Subcommand;
+use itertools::Itertools as _;
+use re_auth::{Jwt, Permission, RedapProvider, VerificationOptions};
+
+/// The environment variable that is used for the secret key if `--secret` isn't given.
+const SECRET_ENV_VAR: &str = "RERUN_AUTH_SECRET";
+
+// ---
+
+/// Generate secret keys and issue or inspect auth tokens.
+#[derive(Debug, Clone, Subcommand)]
+pub enum AuthCommands {
+    /// Generates a new random secret key, and prints it as base64.
+    ///
+    /// Pass it to the server with `--auth-secret`.
+    /// Anyone with access to the key can issue tokens, so keep it safe!
+    GenerateKey,
+
+    /// Issues a new token signed with a secret key, and prints it.
+    ///
+    /// Example: `rerun auth issue-token --subject alice --expires-in 7d --permissions read,write:recording/*`
+    IssueToken(IssueTokenCommand),
+
+    /// Verifies a token against a secret key, and prints its claims.
+    ///
+    /// Returns a failing shell exit code if the token is invalid or has expired.
+    VerifyToken(VerifyTokenCommand),
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct IssueTokenCommand {
+    #[command(flatten)]
+    secret: SecretArgs,
+
+    /// Who the token is issued to.
+    #[clap(long)]
+    subject: String,
+
+    /// How long the token is valid for, e.g. `30m`, `12h` or `7d`.
+    #[clap(long, default_value = "1d", value_parser = parse_duration)]
+    expires_in: Duration,
+
+    /// Who issued the token.
+    #[clap(long, default_value = "rerun")]
+    issuer: String,
+
+    /// Comma-separated list of what the token allows.
+    ///
+    /// Each permission has the form `<read|write>[:<catalog|recording>/<id>]`,
+    /// where `write` implies `read`, and an id of `*` means all catalogs/recordings.
+    /// Without a resource, the permission applies to everything.
+    ///
+    /// Example: `read,write:recording/my_recording`
+    #[clap(long, value_delimiter = ',', default_value = "read")]
+    permissions: Vec<Permission>,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyTokenCommand {
+    #[command(flatten)]
+    secret: SecretArgs,
+
+    /// The token to verify.
+    token: String,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+struct SecretArgs {
+    /// The base64 encoded secret key, as printed by `rerun auth generate-key`.
+    ///
+    /// Falls back to the `RERUN_AUTH_SECRET` environment variable.
+    #[clap(long)]
+    secret: Option<String>,
+}
+
+impl SecretArgs {
+    fn provider(&self) -> anyhow::Result<RedapProvider> {
+        let secret = match &self.secret {
+            Some(secret) => secret.clone(),
+            None => std::env::var(SECRET_ENV_VAR).with_context(|| {
+                format!("no secret key given: pass --secret or set {SECRET_ENV_VAR}")
+            })?,
+        };
+        RedapProvider::from_base64(secret.trim()).context("invalid secret key")
+    }
+}
+
+fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let secs = re_format::parse_duration(duration)?;
+    Duration::try_from_secs_f32(secs).map_err(|err| err.to_string())
+}
+
+impl AuthCommands {
+    pub fn run(&self) -> anyhow::Result<()> {
+        match self {
+            Self::GenerateKey => {
+                let provider = RedapProvider::generate(rand::thread_rng());
+                println!("{}", provider.to_base64());
+                Ok(())
+            }
+            Self::IssueToken(issue_token_command) => issue_token_command.run(),
+            Self::VerifyToken(verify_token_command) => verify_token_command.run(),
+        }
+    }
+}
+
+impl IssueTokenCommand {
+    fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            secret,
+            subject,
+            expires_in,
+            issuer,
+            permissions,
+        } = self;
+
+        let token = secret
+            .provider()?
+            .token(
+                *expires_in,
+                issuer.clone(),
+                subject.clone(),
+                permissions.iter().cloned(),
+            )
+            .context("failed to issue token")?;
+
+        println!("{token}");
+        Ok(())
+    }
+}
+
+impl VerifyTokenCommand {
+    fn run(&self) -> anyhow::Result<()> {
+        let Self { secret, token } = self;
+
+        let token = Jwt::try_from(token.trim().to_owned())?;
+        let claims = secret
+            .provider()?
+            .verify(&token, VerificationOptions::default().without_leeway())
+            .context("invalid token")?;
+
+        let format_time = |secs: u64| {
+            re_log_types::Time::from_seconds_since_epoch(secs as f64)
+                .format(re_log_types::TimeZone::Utc)
+        };
+
+        println!("subject:     {}", claims.sub);
+        println!("issuer:      {}", claims.iss);
+        println!("audience:    {}", claims.aud);
+        println!("issued at:   {}", format_time(claims.iat));
+        println!("expires at:  {}", format_time(claims.exp));
+        if claims.permissions.is_empty() {
+            println!("permissions: none");
+        } else {
+            println!("permissions: {}", claims.permissions.iter().join(", "));
+        }
+
+        Ok(())
+    }
+}
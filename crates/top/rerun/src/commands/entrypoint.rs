@@ -6,7 +6,10 @@ use re_log_types::LogMsg;
 use re_sdk::sink::LogSink;
 use re_smart_channel::{ReceiveSet, Receiver, SmartMessagePayload};
 
-use crate::{commands::RrdCommands, CallSource};
+use crate::{
+    commands::{AuthCommands, RrdCommands},
+    CallSource,
+};
 
 #[cfg(feature = "web_viewer")]
 use re_sdk::web_viewer::WebViewerConfig;
@@ -18,6 +21,9 @@ use re_ws_comms::RerunServerPort;
 #[cfg(feature = "analytics")]
 use crate::commands::AnalyticsCommands;
 
+#[cfg(feature = "native_viewer")]
+use crate::commands::{RblCommands, RenderCommand};
+
 // ---
 
 const LONG_ABOUT: &str = r#"
@@ -83,10 +89,31 @@ struct Args {
     #[command(subcommand)]
     command: Option<Command>,
 
+    /// Only accept clients that present a token signed with this secret, when using `--serve-grpc`.
+    ///
+    /// The secret is a base64 encoded symmetric key, see `rerun auth`.
+    /// Prefer setting it through the environment, so that it doesn't show up in the process list.
+    #[cfg(feature = "grpc")]
+    #[clap(long, env = "RERUN_AUTH_SECRET", hide_env_values = true)]
+    auth_secret: Option<String>,
+
+    /// Auth token to present to message proxies (`temp://` and `temps://` urls) that require authentication.
+    ///
+    /// Message proxies started with `--auth-secret` only accept tokens signed with that secret.
+    #[clap(long, env = "RERUN_AUTH_TOKEN", hide_env_values = true)]
+    auth_token: Option<String>,
+
     /// What bind address IP to use.
     #[clap(long, default_value = "0.0.0.0")]
     bind: String,
 
+    /// Show a custom button with this label in the top panel of the viewer. Can be repeated.
+    ///
+    /// Pressing it tells the connected SDKs, together with what is selected at the time,
+    /// e.g. to trigger a "replay from here" in your own tooling.
+    #[clap(long, value_name = "LABEL")]
+    button: Vec<String>,
+
     /// Set a maximum input latency, e.g. "200ms" or "10s".
     ///
     /// If we go over this, we start dropping packets.
@@ -109,7 +136,7 @@ Example: `16GB` or `50%` (of system total)."
     #[clap(
         long,
         default_value = "25%",
-        long_help = r"An upper limit on how much memory the WebSocket server (`--serve-web`) should use.
+        long_help = r"An upper limit on how much memory the WebSocket server (`--serve-web`) or the gRPC server (`--serve-grpc`) should use.
 The server buffers log messages for the benefit of late-arriving viewers.
 When this limit is reached, Rerun will drop the oldest data.
 Example: `16GB` or `50%` (of system total)."
@@ -160,6 +187,35 @@ When persisted, the state will be stored at the following locations:
     #[clap(long)]
     serve_web: bool,
 
+    /// Host a gRPC message proxy that SDKs can log to, and that viewers can connect to with `temp://` urls.
+    ///
+    /// Use `--auth-secret` to require an auth token, and `--tls-cert` with `--tls-key` to serve over TLS.
+    #[cfg(feature = "grpc")]
+    #[clap(long)]
+    serve_grpc: bool,
+
+    /// What port `--serve-grpc` listens to.
+    #[cfg(feature = "grpc")]
+    #[clap(long, default_value_t = re_grpc_server::DEFAULT_SERVER_PORT)]
+    grpc_port: u16,
+
+    /// Trust this PEM encoded certificate authority when connecting to `temps://` urls, in addition to the system's.
+    ///
+    /// E.g. the certificate of a message proxy that was started with a self-signed `--tls-cert`.
+    #[cfg(feature = "grpc")]
+    #[clap(long)]
+    tls_ca: Option<std::path::PathBuf>,
+
+    /// Serve `--serve-grpc` over TLS, using this PEM encoded certificate (chain).
+    #[cfg(feature = "grpc")]
+    #[clap(long, requires = "tls_key")]
+    tls_cert: Option<std::path::PathBuf>,
+
+    /// The PEM encoded private key for `--tls-cert`.
+    #[cfg(feature = "grpc")]
+    #[clap(long, requires = "tls_cert")]
+    tls_key: Option<std::path::PathBuf>,
+
     /// This is a hint that we expect a recording to stream in very soon.
     ///
     /// This is set by the `spawn()` method in our logging SDK.
@@ -265,6 +321,12 @@ If no arguments are given, a server will be hosted which a Rerun SDK can connect
     #[clap(long, verbatim_doc_comment)]
     video_decoder: Option<String>,
 
+    /// Load view plugins (`*.wasm` files) from this directory.
+    ///
+    /// Each plugin adds a view class that can be picked when adding a view.
+    #[clap(long)]
+    view_plugins: Option<std::path::PathBuf>,
+
     // ----------------------------------------------------------------------------
     // Debug-options:
     /// Ingest data and then quit once the goodbye message has been received.
@@ -503,6 +565,22 @@ enum Command {
     #[command(subcommand)]
     Analytics(AnalyticsCommands),
 
+    #[command(subcommand)]
+    Auth(AuthCommands),
+
+    /// Render a recording to a PNG sequence or an MP4 video, using a blueprint to lay out the views.
+    ///
+    /// Steps the timeline over the given range and renders the viewport, or a single view,
+    /// at a fixed resolution.
+    ///
+    /// Example: `rerun render recording.rrd layout.rbl --view "Camera" --fps 30 -o out.mp4`
+    #[cfg(feature = "native_viewer")]
+    Render(RenderCommand),
+
+    #[cfg(feature = "native_viewer")]
+    #[command(subcommand)]
+    Rbl(RblCommands),
+
     #[command(subcommand)]
     Rrd(RrdCommands),
 
@@ -576,6 +654,14 @@ where
             #[cfg(feature = "analytics")]
             Command::Analytics(analytics) => analytics.run().map_err(Into::into),
 
+            Command::Auth(auth) => auth.run(),
+
+            #[cfg(feature = "native_viewer")]
+            Command::Render(render) => render.run(main_thread_token, build_info, &call_source),
+
+            #[cfg(feature = "native_viewer")]
+            Command::Rbl(rbl) => rbl.run(),
+
             Command::Rrd(rrd) => rrd.run(),
 
             #[cfg(feature = "native_viewer")]
@@ -618,6 +704,30 @@ where
     }
 }
 
+/// Passes `--auth-token` on to message proxy urls, as a `token` query parameter.
+///
+/// A token that is already part of the url takes precedence.
+fn with_auth_token(uri: String, auth_token: Option<&str>) -> String {
+    let Some(auth_token) = auth_token else {
+        return uri;
+    };
+
+    let is_message_proxy = uri.starts_with("temp://") || uri.starts_with("temps://");
+    if !is_message_proxy {
+        return uri;
+    }
+
+    let (base, query) = uri.split_once('?').unwrap_or((&uri, ""));
+    if url::form_urlencoded::parse(query.as_bytes()).any(|(key, _)| key == "token") {
+        return uri;
+    }
+
+    let query = url::form_urlencoded::Serializer::new(query.to_owned())
+        .append_pair("token", auth_token)
+        .finish();
+    format!("{base}?{query}")
+}
+
 fn run_impl(
     _main_thread_token: crate::MainThreadToken,
     _build_info: re_build_info::BuildInfo,
@@ -648,6 +758,8 @@ fn run_impl(
             persist_state: args.persist_state,
             is_in_notebook: false,
             screenshot_to_path_then_quit: args.screenshot_to.clone(),
+            render_job: None,
+            view_plugin_dir: args.view_plugins.clone(),
 
             expect_data_soon: if args.expect_data_soon {
                 Some(true)
@@ -665,15 +777,35 @@ fn run_impl(
             video_decoder_hw_acceleration,
 
             panel_state_overrides: Default::default(),
+            custom_buttons: args.button.clone(),
         }
     };
 
+    #[cfg(feature = "grpc")]
+    if args.serve_grpc {
+        return serve_grpc(&args);
+    }
+
+    #[cfg(feature = "grpc")]
+    if let Some(path) = &args.tls_ca {
+        use anyhow::Context as _;
+
+        let pem =
+            std::fs::read(path).with_context(|| format!("Failed to read --tls-ca {path:?}"))?;
+        re_grpc_client::message_proxy::add_tls_ca_certificate(pem);
+    }
+
+    // Sends viewer events back to the SDKs connected to our TCP server, if we host one.
+    #[cfg(feature = "server")]
+    let mut tcp_viewer_event_sender: Option<re_sdk_comms::ViewerEventSender> = None;
+
     // Where do we get the data from?
     let rxs: Vec<Receiver<LogMsg>> = {
         let data_sources = args
             .url_or_paths
             .iter()
             .cloned()
+            .map(|uri| with_auth_token(uri, args.auth_token.as_deref()))
             .map(|uri| DataSource::from_uri(re_log_types::FileSource::Cli, uri))
             .collect_vec();
 
@@ -719,9 +851,10 @@ fn run_impl(
                     max_latency_sec: parse_max_latency(args.drop_at_latency.as_ref()),
                     quiet: false,
                 };
-                let tcp_listener: Receiver<LogMsg> =
-                    re_sdk_comms::serve(&args.bind, args.port, server_options)?;
+                let (tcp_listener, viewer_event_sender) =
+                    re_sdk_comms::serve_with_viewer_events(&args.bind, args.port, server_options)?;
                 rxs.push(tcp_listener);
+                tcp_viewer_event_sender = Some(viewer_event_sender);
             }
         }
 
@@ -775,6 +908,18 @@ fn run_impl(
                 server_memory_limit,
             )?;
 
+            // Relay what the users of the web viewers are doing to the SDKs that log to us.
+            if let Some(tcp_viewer_event_sender) = tcp_viewer_event_sender {
+                let viewer_events = _ws_server.viewer_events();
+                std::thread::Builder::new()
+                    .name("rerun: viewer event relay".to_owned())
+                    .spawn(move || {
+                        for event in viewer_events {
+                            tcp_viewer_event_sender.send(&event);
+                        }
+                    })?;
+            }
+
             #[cfg(feature = "web_viewer")]
             {
                 // We always host the web-viewer in case the users wants it,
@@ -848,6 +993,12 @@ fn run_impl(
                 for rx in rxs {
                     app.add_receiver(rx);
                 }
+                #[cfg(feature = "server")]
+                if let Some(tcp_viewer_event_sender) = tcp_viewer_event_sender {
+                    app.add_viewer_event_sink(Box::new(move |event| {
+                        tcp_viewer_event_sender.send(event);
+                    }));
+                }
                 app.set_profiler(profiler);
                 if let Ok(url) = std::env::var("EXAMPLES_MANIFEST_URL") {
                     app.set_examples_manifest_url(url);
@@ -868,6 +1019,40 @@ fn run_impl(
     }
 }
 
+/// Hosts a message proxy, until the process is killed.
+#[cfg(feature = "grpc")]
+fn serve_grpc(args: &Args) -> anyhow::Result<()> {
+    use anyhow::Context as _;
+
+    anyhow::ensure!(
+        args.url_or_paths.is_empty(),
+        "--serve-grpc only relays data logged by SDKs, it can't load files or urls"
+    );
+
+    let ip: std::net::IpAddr = args
+        .bind
+        .parse()
+        .with_context(|| format!("Bad --bind address {:?}", args.bind))?;
+    let addr = std::net::SocketAddr::new(ip, args.grpc_port);
+
+    let mut options = re_grpc_server::ServerOptions {
+        memory_limit: re_memory::MemoryLimit::parse(&args.server_memory_limit)
+            .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?,
+        ..Default::default()
+    };
+    if let Some(secret) = &args.auth_secret {
+        options = options.with_auth_secret(secret)?;
+    }
+    if let (Some(cert_path), Some(key_path)) = (&args.tls_cert, &args.tls_key) {
+        options = options.with_tls_pem_files(cert_path, key_path)?;
+    }
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(re_grpc_server::serve(addr, options))
+}
+
 // NOTE: This is only used as part of end-to-end tests.
 fn assert_receive_into_entity_db(
     rx: &ReceiveSet<LogMsg>,
@@ -1032,3 +1217,36 @@ fn stream_to_rrd_on_disk(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_auth_token() {
+        assert_eq!(
+            with_auth_token("temp://127.0.0.1:1852".to_owned(), Some("a+b/c")),
+            "temp://127.0.0.1:1852?token=a%2Bb%2Fc"
+        );
+        assert_eq!(
+            with_auth_token("temps://lab:1852?foo=bar".to_owned(), Some("abc")),
+            "temps://lab:1852?foo=bar&token=abc"
+        );
+
+        // Tokens in the url itself win:
+        assert_eq!(
+            with_auth_token("temp://127.0.0.1:1852?token=abc".to_owned(), Some("def")),
+            "temp://127.0.0.1:1852?token=abc"
+        );
+
+        // Only message proxies get the token:
+        assert_eq!(
+            with_auth_token("recording.rrd".to_owned(), Some("abc")),
+            "recording.rrd"
+        );
+        assert_eq!(
+            with_auth_token("temp://127.0.0.1:1852".to_owned(), None),
+            "temp://127.0.0.1:1852"
+        );
+    }
+}
@@ -21,6 +21,7 @@ impl CallSource {
 
 // ---
 
+mod auth;
 mod entrypoint;
 mod rrd;
 mod stdio;
@@ -28,9 +29,22 @@ mod stdio;
 #[cfg(feature = "analytics")]
 mod analytics;
 
+#[cfg(feature = "native_viewer")]
+mod rbl;
+
+#[cfg(feature = "native_viewer")]
+mod render;
+
+pub use self::auth::AuthCommands;
 pub use self::entrypoint::run;
 pub use self::rrd::RrdCommands;
 pub use self::stdio::read_rrd_streams_from_file_or_stdin;
 
 #[cfg(feature = "analytics")]
 pub(crate) use self::analytics::AnalyticsCommands;
+
+#[cfg(feature = "native_viewer")]
+pub(crate) use self::rbl::RblCommands;
+
+#[cfg(feature = "native_viewer")]
+pub(crate) use self::render::RenderCommand;
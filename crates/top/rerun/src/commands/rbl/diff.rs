@@ -0,0 +1,343 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+
+use re_types::{blueprint::components::IncludedContent, Component as _};
+
+use super::{format_value, BlueprintObject, BlueprintSnapshot, Properties};
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DiffCommand {
+    path_to_rbl1: String,
+    path_to_rbl2: String,
+}
+
+impl DiffCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_rbl1,
+            path_to_rbl2,
+        } = self;
+
+        re_log::debug!("Diffing {path_to_rbl1:?} against {path_to_rbl2:?}…");
+
+        let path_to_rbl1 = PathBuf::from(path_to_rbl1);
+        let path_to_rbl2 = PathBuf::from(path_to_rbl2);
+
+        let blueprint1 = BlueprintSnapshot::load(&path_to_rbl1)
+            .with_context(|| format!("path: {path_to_rbl1:?}"))?;
+        let blueprint2 = BlueprintSnapshot::load(&path_to_rbl2)
+            .with_context(|| format!("path: {path_to_rbl2:?}"))?;
+
+        let mut diff = BlueprintDiff {
+            before: &blueprint1,
+            after: &blueprint2,
+            visited: Default::default(),
+            num_differences: 0,
+        };
+        let lines = diff.lines();
+
+        for line in &lines {
+            println!("{line}");
+        }
+
+        let num_differences = diff.num_differences;
+        if num_differences == 0 {
+            println!("No differences found.");
+        } else {
+            println!(
+                "{} difference(s) found.",
+                re_format::format_uint(num_differences)
+            );
+        }
+
+        anyhow::ensure!(
+            num_differences == 0,
+            "Found {} difference(s) between {path_to_rbl1:?} and {path_to_rbl2:?}",
+            re_format::format_uint(num_differences),
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Walks the viewport trees of two blueprints side by side, and reports the differences
+/// as an indented tree.
+struct BlueprintDiff<'a> {
+    before: &'a BlueprintSnapshot,
+    after: &'a BlueprintSnapshot,
+
+    /// Objects that were already reported, e.g. because they were moved to another container.
+    visited: BTreeSet<BlueprintObject>,
+
+    num_differences: usize,
+}
+
+impl BlueprintDiff<'_> {
+    fn lines(&mut self) -> Vec<String> {
+        let mut lines = self.object_lines(&BlueprintObject::Viewport, 0);
+
+        // Everything that isn't part of the viewport's tree, e.g. the panels.
+        let objects: BTreeSet<BlueprintObject> = self
+            .before
+            .objects
+            .keys()
+            .chain(self.after.objects.keys())
+            .cloned()
+            .collect();
+        for object in objects {
+            if !self.visited.contains(&object) {
+                lines.extend(self.object_lines(&object, 0));
+            }
+        }
+
+        lines
+    }
+
+    /// The lines for an object and its children.
+    ///
+    /// Unchanged objects are only listed if some of their children changed,
+    /// so that the tree shows where the changes are.
+    fn object_lines(&mut self, object: &BlueprintObject, depth: usize) -> Vec<String> {
+        if !self.visited.insert(object.clone()) {
+            return Vec::new();
+        }
+
+        let indent = "  ".repeat(depth);
+        let before = self.before.objects.get(object);
+        let after = self.after.objects.get(object);
+
+        let mut lines = Vec::new();
+        match (before, after) {
+            (None, Some(_)) => {
+                self.num_differences += 1;
+                lines.push(format!("{indent}+ {}", self.after.label(object)));
+            }
+            (Some(_), None) => {
+                self.num_differences += 1;
+                lines.push(format!("{indent}- {}", self.before.label(object)));
+            }
+            (Some(before), Some(after)) => {
+                let property_lines = self.property_lines(object, before, after, &indent);
+                if !property_lines.is_empty() {
+                    lines.push(format!("{indent}~ {}", self.after.label(object)));
+                    lines.extend(property_lines);
+                }
+            }
+            (None, None) => {}
+        }
+
+        // Children of the new version first, then the ones that were removed.
+        let mut children = self.after.children(object);
+        for child in self.before.children(object) {
+            if !children.contains(&child) {
+                children.push(child);
+            }
+        }
+
+        let mut child_lines = Vec::new();
+        for child in &children {
+            child_lines.extend(self.object_lines(child, depth + 1));
+        }
+        if lines.is_empty() && !child_lines.is_empty() {
+            let label = if after.is_some() {
+                self.after.label(object)
+            } else {
+                self.before.label(object)
+            };
+            lines.push(format!("{indent}  {label}"));
+        }
+        lines.extend(child_lines);
+
+        lines
+    }
+
+    fn property_lines(
+        &mut self,
+        object: &BlueprintObject,
+        before: &Properties,
+        after: &Properties,
+        indent: &str,
+    ) -> Vec<String> {
+        let keys: BTreeSet<_> = before.keys().chain(after.keys()).collect();
+
+        let mut lines = Vec::new();
+        for key in keys {
+            let name = object.property_name(key);
+            let (before, after) = (before.get(key), after.get(key));
+            if before == after {
+                continue;
+            }
+
+            // Report which children were added or removed, instead of the raw lists.
+            if matches!(object, BlueprintObject::Container(_)) && key.1 == IncludedContent::name() {
+                lines.extend(self.contents_lines(object, indent));
+                continue;
+            }
+
+            self.num_differences += 1;
+            match (before, after) {
+                (None, Some(after)) => {
+                    lines.push(format!(
+                        "{indent}    + {name} = {}",
+                        format_value(after.value.as_ref())
+                    ));
+                }
+                (Some(before), None) => {
+                    lines.push(format!(
+                        "{indent}    - {name} (was {})",
+                        format_value(before.value.as_ref())
+                    ));
+                }
+                (Some(before), Some(after)) => {
+                    lines.push(format!(
+                        "{indent}    ~ {name}: {} -> {}",
+                        format_value(before.value.as_ref()),
+                        format_value(after.value.as_ref())
+                    ));
+                }
+                (None, None) => {}
+            }
+        }
+
+        lines
+    }
+
+    fn contents_lines(&mut self, object: &BlueprintObject, indent: &str) -> Vec<String> {
+        let before = self.before.children(object);
+        let after = self.after.children(object);
+
+        let mut lines = Vec::new();
+        for child in &after {
+            if !before.contains(child) {
+                self.num_differences += 1;
+                lines.push(format!(
+                    "{indent}    + contains {}",
+                    self.after.label(child)
+                ));
+            }
+        }
+        for child in &before {
+            if !after.contains(child) {
+                self.num_differences += 1;
+                lines.push(format!(
+                    "{indent}    - contains {}",
+                    self.before.label(child)
+                ));
+            }
+        }
+
+        if lines.is_empty() {
+            self.num_differences += 1;
+            lines.push(format!("{indent}    ~ contents reordered"));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::blueprint::archetypes;
+
+    use super::super::tests::{blueprint, log, root_id, view_id, TestView};
+    use super::*;
+
+    fn diff(before: &BlueprintSnapshot, after: &BlueprintSnapshot) -> (Vec<String>, usize) {
+        let mut diff = BlueprintDiff {
+            before,
+            after,
+            visited: Default::default(),
+            num_differences: 0,
+        };
+        let lines = diff.lines();
+        (lines, diff.num_differences)
+    }
+
+    #[test]
+    fn identical() {
+        let views = [
+            TestView { id: "a", name: "A" },
+            TestView { id: "b", name: "B" },
+        ];
+        let before = BlueprintSnapshot::new(&blueprint(&views));
+        let after = BlueprintSnapshot::new(&blueprint(&views));
+
+        assert_eq!(diff(&before, &after), (Vec::new(), 0));
+    }
+
+    #[test]
+    fn changes() {
+        let before = BlueprintSnapshot::new(&blueprint(&[
+            TestView { id: "a", name: "A" },
+            TestView { id: "b", name: "B" },
+        ]));
+
+        let mut after = blueprint(&[
+            TestView {
+                id: "a",
+                name: "Renamed",
+            },
+            TestView { id: "c", name: "C" },
+        ]);
+        log(
+            &mut after,
+            view_id("a").as_entity_path(),
+            &archetypes::ViewBlueprint::update_fields().with_visible(false),
+        );
+        let after = BlueprintSnapshot::new(&after);
+
+        let root = BlueprintObject::Container(root_id());
+        let view_a = BlueprintObject::View(view_id("a"));
+        let view_b = BlueprintObject::View(view_id("b"));
+        let view_c = BlueprintObject::View(view_id("c"));
+
+        let (lines, num_differences) = diff(&before, &after);
+        assert_eq!(
+            lines,
+            [
+                "  viewport".to_owned(),
+                format!("  ~ {}", after.label(&root)),
+                format!("      + contains {}", after.label(&view_c)),
+                format!("      - contains {}", before.label(&view_b)),
+                format!("    ~ {}", after.label(&view_a)),
+                "        + Visible = false".to_owned(),
+                "        ~ Name: A -> Renamed".to_owned(),
+                format!("    + {}", after.label(&view_c)),
+                format!("    - {}", before.label(&view_b)),
+            ]
+        );
+        assert_eq!(num_differences, 6);
+    }
+
+    #[test]
+    fn reordered() {
+        let before = BlueprintSnapshot::new(&blueprint(&[
+            TestView { id: "a", name: "A" },
+            TestView { id: "b", name: "B" },
+        ]));
+        let after = BlueprintSnapshot::new(&blueprint(&[
+            TestView { id: "b", name: "B" },
+            TestView { id: "a", name: "A" },
+        ]));
+
+        let (lines, num_differences) = diff(&before, &after);
+        assert_eq!(
+            lines,
+            [
+                "  viewport".to_owned(),
+                format!(
+                    "  ~ {}",
+                    after.label(&BlueprintObject::Container(root_id()))
+                ),
+                "      ~ contents reordered".to_owned(),
+            ]
+        );
+        assert_eq!(num_differences, 1);
+    }
+}
@@ -0,0 +1,570 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, RowId};
+use re_entity_db::EntityDb;
+use re_log_types::{EntityPath, SetStoreInfo, StoreId, StoreKind, TimeInt, TimePoint};
+use re_types::{
+    blueprint::components::{ColumnShare, IncludedContent, RowShare},
+    Component as _, ComponentName, Loggable as _,
+};
+use re_viewer_context::{blueprint_timeline, Contents};
+
+use super::{BlueprintObject, BlueprintSnapshot, Properties, Property, PropertyKey};
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct MergeCommand {
+    /// The blueprint both edits started from.
+    path_to_base: String,
+
+    /// The first edit of the base blueprint.
+    path_to_ours: String,
+
+    /// The second edit of the base blueprint.
+    path_to_theirs: String,
+
+    /// Path to write the merged blueprint to.
+    #[arg(short = 'o', long = "output", value_name = "dst.rbl")]
+    path_to_output: String,
+
+    /// Resolve conflicting changes by picking the version of this side.
+    #[clap(long, value_enum)]
+    prefer: Option<Side>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Side {
+    Ours,
+    Theirs,
+}
+
+impl MergeCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_base,
+            path_to_ours,
+            path_to_theirs,
+            path_to_output,
+            prefer,
+        } = self;
+
+        let load = |path: &String| {
+            let path = PathBuf::from(path);
+            BlueprintSnapshot::load(&path).with_context(|| format!("path: {path:?}"))
+        };
+        let base = load(path_to_base)?;
+        let ours = load(path_to_ours)?;
+        let theirs = load(path_to_theirs)?;
+
+        let mut merge = BlueprintMerge {
+            base: &base,
+            ours: &ours,
+            theirs: &theirs,
+            prefer: *prefer,
+            conflicts: Vec::new(),
+        };
+        let objects = merge.merge();
+
+        for conflict in &merge.conflicts {
+            eprintln!("{conflict}");
+        }
+        anyhow::ensure!(
+            merge.conflicts.is_empty() || prefer.is_some(),
+            "Found {} conflict(s), pass `--prefer ours` or `--prefer theirs` to resolve them",
+            re_format::format_uint(merge.conflicts.len()),
+        );
+
+        let blueprint = to_blueprint(&ours, objects)?;
+        let merged = BlueprintSnapshot::new(&blueprint);
+        for object in merged.objects.keys() {
+            let is_in_tree = match object {
+                BlueprintObject::Container(container_id) => {
+                    merged.viewport.container(container_id).is_some()
+                }
+                BlueprintObject::View(view_id) => merged.viewport.view(view_id).is_some(),
+                BlueprintObject::Viewport | BlueprintObject::Other(_) => true,
+            };
+            if !is_in_tree {
+                re_log::warn!(
+                    "{} is not part of the merged viewport and won't be shown",
+                    merged.label(object)
+                );
+            }
+        }
+
+        let mut rbl_out = std::io::BufWriter::new(
+            std::fs::File::create(path_to_output).with_context(|| format!("{path_to_output:?}"))?,
+        );
+        let version = blueprint
+            .store_info()
+            .and_then(|info| info.store_version)
+            .unwrap_or(re_build_info::CrateVersion::LOCAL);
+        re_log_encoding::encoder::encode(
+            version,
+            re_log_encoding::EncodingOptions::MSGPACK_COMPRESSED,
+            blueprint.to_messages(None /* time selection */),
+            &mut rbl_out,
+        )
+        .context("couldn't encode messages")?;
+        rbl_out.flush().context("couldn't flush output")?;
+
+        re_log::info!(
+            conflicts = merge.conflicts.len(),
+            dst = ?path_to_output,
+            "blueprint merge finished"
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+struct BlueprintMerge<'a> {
+    base: &'a BlueprintSnapshot,
+    ours: &'a BlueprintSnapshot,
+    theirs: &'a BlueprintSnapshot,
+
+    prefer: Option<Side>,
+
+    /// Human-readable descriptions of all conflicting changes.
+    conflicts: Vec<String>,
+}
+
+impl BlueprintMerge<'_> {
+    /// Merges all objects, property by property.
+    fn merge(&mut self) -> BTreeMap<BlueprintObject, Properties> {
+        let objects: Vec<BlueprintObject> = self
+            .base
+            .objects
+            .keys()
+            .chain(self.ours.objects.keys())
+            .chain(self.theirs.objects.keys())
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut merged: BTreeMap<BlueprintObject, Properties> = BTreeMap::new();
+        for object in objects {
+            if let Some(properties) = self.merge_object(&object) {
+                merged.insert(object, properties);
+            }
+        }
+
+        // One side may have added a child to a container that the other side removed.
+        let merged_objects: BTreeSet<BlueprintObject> = merged.keys().cloned().collect();
+        for (object, properties) in &mut merged {
+            if !matches!(object, BlueprintObject::Container(_)) {
+                continue;
+            }
+            let key = (object.entity_path(), IncludedContent::name());
+            let Some(property) = properties.get(&key) else {
+                continue;
+            };
+
+            let contents = contents_from_property(property);
+            let existing_contents: Vec<Contents> = contents
+                .iter()
+                .filter(|contents| merged_objects.contains(&BlueprintObject::from(**contents)))
+                .copied()
+                .collect();
+            if existing_contents.len() == contents.len() {
+                continue;
+            }
+            if let Some(property) = contents_property(property, &existing_contents) {
+                properties.insert(key, property);
+                properties.retain(|(_, component_name), _| !is_share(*component_name));
+            }
+        }
+
+        merged
+    }
+
+    /// Returns `None` if the object was removed.
+    fn merge_object(&mut self, object: &BlueprintObject) -> Option<Properties> {
+        let base = self.base.objects.get(object);
+        let ours = self.ours.objects.get(object);
+        let theirs = self.theirs.objects.get(object);
+
+        let label = if base.is_some() {
+            self.base.label(object)
+        } else if ours.is_some() {
+            self.ours.label(object)
+        } else {
+            self.theirs.label(object)
+        };
+
+        // An object that was removed on one side is gone, unless the other side changed it.
+        match (base, ours, theirs) {
+            (Some(base), None, Some(theirs)) => {
+                if base == theirs
+                    || self.conflict_resolves_to(Side::Ours, || {
+                        format!("{label} was removed in ours, but changed in theirs")
+                    })
+                {
+                    return None;
+                }
+                return Some(theirs.clone());
+            }
+            (Some(base), Some(ours), None) => {
+                if base == ours
+                    || self.conflict_resolves_to(Side::Theirs, || {
+                        format!("{label} was removed in theirs, but changed in ours")
+                    })
+                {
+                    return None;
+                }
+                return Some(ours.clone());
+            }
+            (Some(_), None, None) => return None,
+            _ => {}
+        }
+
+        let empty = Properties::new();
+        let base = base.unwrap_or(&empty);
+        let ours = ours.unwrap_or(&empty);
+        let theirs = theirs.unwrap_or(&empty);
+
+        let mut keys: Vec<&PropertyKey> = base
+            .keys()
+            .chain(ours.keys())
+            .chain(theirs.keys())
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut merged = Properties::new();
+        let mut reset_shares = false;
+
+        // The contents first, since the shares of a container depend on them.
+        let contents_key = (object.entity_path(), IncludedContent::name());
+        if let BlueprintObject::Container(_) = object {
+            if let Some(property) = self.merge_contents(
+                &label,
+                base.get(&contents_key),
+                ours.get(&contents_key),
+                theirs.get(&contents_key),
+                &mut reset_shares,
+            ) {
+                merged.insert(contents_key.clone(), property);
+            }
+        }
+
+        for key in keys {
+            if *key == contents_key {
+                continue;
+            }
+            if reset_shares && is_share(key.1) {
+                // The children of the container changed, so they all get the same size again.
+                continue;
+            }
+
+            let property =
+                self.merge_property(base.get(key), ours.get(key), theirs.get(key), || {
+                    format!("{label}: {}", object.property_name(key))
+                });
+            if let Some(property) = property {
+                merged.insert(key.clone(), property);
+            }
+        }
+
+        (!merged.is_empty()).then_some(merged)
+    }
+
+    fn merge_property(
+        &mut self,
+        base: Option<&Property>,
+        ours: Option<&Property>,
+        theirs: Option<&Property>,
+        describe: impl FnOnce() -> String,
+    ) -> Option<Property> {
+        if ours == theirs || theirs == base {
+            ours.cloned()
+        } else if ours == base {
+            theirs.cloned()
+        } else if self.conflict_resolves_to(Side::Ours, || {
+            format!("{} was changed on both sides", describe())
+        }) {
+            ours.cloned()
+        } else {
+            theirs.cloned()
+        }
+    }
+
+    /// Merges the children of a container: children added on either side are appended,
+    /// children removed on either side are removed.
+    ///
+    /// The order of `ours` is kept.
+    fn merge_contents(
+        &mut self,
+        label: &str,
+        base: Option<&Property>,
+        ours: Option<&Property>,
+        theirs: Option<&Property>,
+        reset_shares: &mut bool,
+    ) -> Option<Property> {
+        if ours == theirs || theirs == base {
+            return ours.cloned();
+        } else if ours == base {
+            return theirs.cloned();
+        }
+        let (Some(ours), Some(theirs)) = (ours, theirs) else {
+            return self.merge_property(base, ours, theirs, || format!("{label}: contents"));
+        };
+
+        let base_contents = base.map(contents_from_property).unwrap_or_default();
+        let ours_contents = contents_from_property(ours);
+        let theirs_contents = contents_from_property(theirs);
+
+        let mut merged: Vec<Contents> = ours_contents
+            .iter()
+            .filter(|contents| {
+                !base_contents.contains(contents) || theirs_contents.contains(contents)
+            })
+            .copied()
+            .collect();
+        for contents in theirs_contents {
+            if !base_contents.contains(&contents) && !merged.contains(&contents) {
+                merged.push(contents);
+            }
+        }
+
+        *reset_shares = true;
+        contents_property(ours, &merged)
+    }
+
+    /// Records a conflict, and returns whether it's resolved in favor of `side`.
+    fn conflict_resolves_to(&mut self, side: Side, describe: impl FnOnce() -> String) -> bool {
+        self.conflicts.push(format!("conflict: {}", describe()));
+        self.prefer.unwrap_or(Side::Ours) == side
+    }
+}
+
+/// Whether the component is the size of the children of a container.
+fn is_share(component_name: ComponentName) -> bool {
+    component_name == ColumnShare::name() || component_name == RowShare::name()
+}
+
+/// The children of a container, from its [`IncludedContent`] property.
+fn contents_from_property(property: &Property) -> Vec<Contents> {
+    IncludedContent::from_arrow(property.value.as_ref())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|contents| Contents::try_from(&EntityPath::from(contents.0)))
+        .collect()
+}
+
+/// Replaces the value of an [`IncludedContent`] property.
+fn contents_property(property: &Property, contents: &[Contents]) -> Option<Property> {
+    let value = IncludedContent::to_arrow(
+        contents
+            .iter()
+            .map(|contents| IncludedContent::from(&contents.as_entity_path())),
+    )
+    .ok()?;
+
+    Some(Property {
+        descriptor: property.descriptor.clone(),
+        value,
+    })
+}
+
+/// Creates a new blueprint with the merged properties, based on the store info of `ours`.
+fn to_blueprint(
+    ours: &BlueprintSnapshot,
+    objects: BTreeMap<BlueprintObject, Properties>,
+) -> anyhow::Result<EntityDb> {
+    let store_id = StoreId::random(StoreKind::Blueprint);
+    let mut blueprint = EntityDb::new(store_id.clone());
+
+    let mut store_info = ours
+        .store_info
+        .clone()
+        .context("the blueprint has no store info")?;
+    store_info.store_id = store_id;
+    store_info.cloned_from = None;
+    blueprint.set_store_info(SetStoreInfo {
+        row_id: *RowId::new(),
+        info: store_info,
+    });
+
+    let mut components_per_entity: BTreeMap<EntityPath, Vec<Property>> = BTreeMap::new();
+    for ((entity_path, _), property) in objects.into_values().flatten() {
+        components_per_entity
+            .entry(entity_path)
+            .or_default()
+            .push(property);
+    }
+
+    let timepoint = TimePoint::from([(blueprint_timeline(), TimeInt::new_temporal(0))]);
+    for (entity_path, properties) in components_per_entity {
+        let chunk = Chunk::builder(entity_path)
+            .with_row(
+                RowId::new(),
+                timepoint.clone(),
+                properties
+                    .into_iter()
+                    .map(|property| (property.descriptor, property.value)),
+            )
+            .build()?;
+        blueprint.add_chunk(&Arc::new(chunk))?;
+    }
+
+    Ok(blueprint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{blueprint, root_id, view_id, TestView};
+    use super::*;
+
+    fn snapshot(views: &[TestView]) -> BlueprintSnapshot {
+        BlueprintSnapshot::new(&blueprint(views))
+    }
+
+    fn merge(
+        base: &BlueprintSnapshot,
+        ours: &BlueprintSnapshot,
+        theirs: &BlueprintSnapshot,
+        prefer: Option<Side>,
+    ) -> (BlueprintSnapshot, Vec<String>) {
+        let mut merge = BlueprintMerge {
+            base,
+            ours,
+            theirs,
+            prefer,
+            conflicts: Vec::new(),
+        };
+        let objects = merge.merge();
+        let merged = BlueprintSnapshot::new(&to_blueprint(ours, objects).unwrap());
+        (merged, merge.conflicts)
+    }
+
+    /// The origins and names of the views in the root container, in order.
+    fn views(snapshot: &BlueprintSnapshot) -> Vec<String> {
+        let root = snapshot.viewport.container(&root_id()).unwrap();
+        root.contents
+            .iter()
+            .map(|contents| {
+                let Contents::View(view_id) = contents else {
+                    panic!("unexpected container {contents:?}");
+                };
+                let view = snapshot.viewport.view(view_id).unwrap();
+                format!(
+                    "{} {}",
+                    view.space_origin,
+                    view.display_name.clone().unwrap_or_default()
+                )
+            })
+            .collect()
+    }
+
+    fn base() -> BlueprintSnapshot {
+        snapshot(&[
+            TestView { id: "a", name: "A" },
+            TestView { id: "b", name: "B" },
+        ])
+    }
+
+    #[test]
+    fn identical() {
+        let (merged, conflicts) = merge(&base(), &base(), &base(), None);
+        assert_eq!(views(&merged), ["/a A", "/b B"]);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn disjoint() {
+        let ours = snapshot(&[
+            TestView {
+                id: "a",
+                name: "Renamed",
+            },
+            TestView { id: "b", name: "B" },
+        ]);
+        let theirs = snapshot(&[
+            TestView { id: "a", name: "A" },
+            TestView { id: "b", name: "B" },
+            TestView { id: "c", name: "C" },
+        ]);
+        let (merged, conflicts) = merge(&base(), &ours, &theirs, None);
+        assert_eq!(views(&merged), ["/a Renamed", "/b B", "/c C"]);
+        assert!(conflicts.is_empty());
+
+        // Views added and removed on both sides.
+        let ours = snapshot(&[
+            TestView { id: "a", name: "A" },
+            TestView { id: "c", name: "C" },
+        ]);
+        let theirs = snapshot(&[
+            TestView { id: "d", name: "D" },
+            TestView { id: "b", name: "B" },
+            TestView { id: "a", name: "A" },
+        ]);
+        let (merged, conflicts) = merge(&base(), &ours, &theirs, None);
+        assert_eq!(views(&merged), ["/a A", "/c C", "/d D"]);
+        assert!(!merged
+            .objects
+            .contains_key(&BlueprintObject::View(view_id("b"))));
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn conflicting() {
+        let base = base();
+        let ours = snapshot(&[
+            TestView {
+                id: "a",
+                name: "Ours",
+            },
+            TestView { id: "b", name: "B" },
+        ]);
+        let theirs = snapshot(&[
+            TestView {
+                id: "a",
+                name: "Theirs",
+            },
+            TestView { id: "b", name: "B" },
+        ]);
+
+        let (merged, conflicts) = merge(&base, &ours, &theirs, None);
+        assert_eq!(views(&merged), ["/a Ours", "/b B"]);
+        assert_eq!(
+            conflicts,
+            [format!(
+                "conflict: {}: Name was changed on both sides",
+                base.label(&BlueprintObject::View(view_id("a")))
+            )]
+        );
+
+        let (merged, conflicts) = merge(&base, &ours, &theirs, Some(Side::Theirs));
+        assert_eq!(views(&merged), ["/a Theirs", "/b B"]);
+        assert_eq!(conflicts.len(), 1);
+
+        // Removed on one side, but changed on the other.
+        let ours = snapshot(&[TestView { id: "a", name: "A" }]);
+        let theirs = snapshot(&[
+            TestView { id: "a", name: "A" },
+            TestView {
+                id: "b",
+                name: "Theirs",
+            },
+        ]);
+        let (merged, conflicts) = merge(&base, &ours, &theirs, None);
+        assert_eq!(views(&merged), ["/a A"]);
+        assert_eq!(
+            conflicts,
+            [format!(
+                "conflict: {} was removed in ours, but changed in theirs",
+                base.label(&BlueprintObject::View(view_id("b")))
+            )]
+        );
+    }
+}
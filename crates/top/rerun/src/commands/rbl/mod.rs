@@ -0,0 +1,491 @@
+mod diff;
+mod merge;
+
+use self::diff::DiffCommand;
+use self::merge::MergeCommand;
+
+// ---
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Context as _;
+use arrow::array::{Array as _, ArrayRef as ArrowArrayRef};
+use clap::Subcommand;
+
+use re_chunk::LatestAtQuery;
+use re_chunk_store::ChunkStoreConfig;
+use re_entity_db::EntityDb;
+use re_log_types::{EntityPath, StoreInfo, StoreKind};
+use re_types::{components::ClearIsRecursive, Component as _, ComponentDescriptor, ComponentName};
+use re_viewer_context::{blueprint_timeline, ContainerId, Contents, ViewId};
+use re_viewport_blueprint::{ViewportBlueprint, VIEWPORT_PATH};
+
+/// Compare and merge .rbl blueprint files.
+#[derive(Debug, Clone, Subcommand)]
+pub enum RblCommands {
+    /// Reports the differences between 2 .rbl files, as a tree of containers and views.
+    ///
+    /// Containers and views are matched by their blueprint ids, so renaming or moving them is
+    /// reported as a change, not as a removal and an addition.
+    /// Returns a successful shell exit code only if there are no differences.
+    ///
+    /// Example: `rerun rbl diff before.rbl after.rbl`
+    Diff(DiffCommand),
+
+    /// Three-way merges two edits of the same base blueprint, and writes the result to a .rbl file.
+    ///
+    /// Changes to different properties are combined, and containers & views added on either side
+    /// are kept.
+    /// Conflicting changes to the same property are reported and fail the merge, unless
+    /// `--prefer` picks a side.
+    ///
+    /// Example: `rerun rbl merge base.rbl ours.rbl theirs.rbl -o merged.rbl`
+    Merge(MergeCommand),
+}
+
+impl RblCommands {
+    pub fn run(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Diff(diff_command) => diff_command.run(),
+            Self::Merge(merge_command) => merge_command.run(),
+        }
+    }
+}
+
+// ---
+
+/// The part of a blueprint that a property belongs to.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum BlueprintObject {
+    Viewport,
+    Container(ContainerId),
+    View(ViewId),
+
+    /// Anything outside of the viewport, e.g. the state of the panels.
+    Other(EntityPath),
+}
+
+impl BlueprintObject {
+    /// The object that owns the data at the given path.
+    ///
+    /// Views own everything below their path, e.g. their properties and overrides.
+    fn from_entity_path(entity_path: &EntityPath) -> Self {
+        let object_path = EntityPath::new(entity_path.iter().take(2).cloned().collect());
+
+        if entity_path.starts_with(&EntityPath::from(VIEWPORT_PATH)) {
+            Self::Viewport
+        } else if entity_path.starts_with(ContainerId::registry()) && entity_path.len() >= 2 {
+            Self::Container(ContainerId::from_entity_path(&object_path))
+        } else if entity_path.starts_with(ViewId::registry()) && entity_path.len() >= 2 {
+            Self::View(ViewId::from_entity_path(&object_path))
+        } else {
+            Self::Other(EntityPath::new(
+                entity_path.iter().take(1).cloned().collect(),
+            ))
+        }
+    }
+
+    fn entity_path(&self) -> EntityPath {
+        match self {
+            Self::Viewport => VIEWPORT_PATH.into(),
+            Self::Container(container_id) => container_id.as_entity_path(),
+            Self::View(view_id) => view_id.as_entity_path(),
+            Self::Other(entity_path) => entity_path.clone(),
+        }
+    }
+
+    /// The name of a property of this object, relative to the object's path.
+    fn property_name(&self, (entity_path, component_name): &PropertyKey) -> String {
+        let object_path = self.entity_path();
+        let relative_path = entity_path
+            .iter()
+            .skip(object_path.len())
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        if relative_path.is_empty() {
+            component_name.short_name().to_owned()
+        } else {
+            format!("{relative_path}:{}", component_name.short_name())
+        }
+    }
+}
+
+impl From<Contents> for BlueprintObject {
+    fn from(contents: Contents) -> Self {
+        match contents {
+            Contents::Container(container_id) => Self::Container(container_id),
+            Contents::View(view_id) => Self::View(view_id),
+        }
+    }
+}
+
+type PropertyKey = (EntityPath, ComponentName);
+
+/// The latest value of a component, with the descriptor it was logged with.
+#[derive(Clone, Debug)]
+struct Property {
+    descriptor: ComponentDescriptor,
+    value: ArrowArrayRef,
+}
+
+impl PartialEq for Property {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.to_data() == other.value.to_data()
+    }
+}
+
+type Properties = BTreeMap<PropertyKey, Property>;
+
+/// The latest state of a blueprint, i.e. what the viewer would show.
+struct BlueprintSnapshot {
+    store_info: Option<StoreInfo>,
+
+    viewport: ViewportBlueprint,
+
+    /// The latest value of every component, per object.
+    ///
+    /// Objects without any data, e.g. cleared views, are not included.
+    objects: BTreeMap<BlueprintObject, Properties>,
+}
+
+impl BlueprintSnapshot {
+    /// Loads the (single) blueprint of a .rbl file.
+    fn load(path_to_rbl: &Path) -> anyhow::Result<Self> {
+        let rbl_file = std::fs::File::open(path_to_rbl).context("couldn't open rbl file")?;
+        let rbl_file = std::io::BufReader::new(rbl_file);
+
+        let version_policy = re_log_encoding::VersionPolicy::Warn;
+        let decoder = re_log_encoding::decoder::Decoder::new(version_policy, rbl_file)?;
+
+        let mut blueprint: Option<EntityDb> = None;
+        for msg in decoder {
+            let msg = msg.context("decode rbl message")?;
+            if msg.store_id().kind != StoreKind::Blueprint {
+                continue;
+            }
+
+            let blueprint = blueprint.get_or_insert_with(|| {
+                EntityDb::with_store_config(msg.store_id().clone(), ChunkStoreConfig::ALL_DISABLED)
+            });
+            anyhow::ensure!(
+                blueprint.store_id() == *msg.store_id(),
+                "more than one blueprint found in rbl file"
+            );
+            blueprint.add(&msg).context("decode rbl file contents")?;
+        }
+
+        let blueprint = blueprint.context("no blueprint found in rbl file")?;
+        Ok(Self::new(&blueprint))
+    }
+
+    fn new(blueprint: &EntityDb) -> Self {
+        re_tracing::profile_function!();
+
+        let query = LatestAtQuery::latest(blueprint_timeline());
+        let viewport = ViewportBlueprint::try_from_db(blueprint, &query);
+
+        let mut objects: BTreeMap<BlueprintObject, Properties> = BTreeMap::new();
+        let engine = blueprint.storage_engine();
+        for entity_path in blueprint.entity_paths() {
+            let Some(component_names) = engine.store().all_components_for_entity(entity_path)
+            else {
+                continue;
+            };
+            let results = engine
+                .cache()
+                .latest_at(&query, entity_path, component_names);
+
+            for (component_name, unit) in &results.components {
+                // Clears are already applied by the query, and indicators don't carry any data.
+                if component_name.is_indicator_component()
+                    || *component_name == ClearIsRecursive::name()
+                {
+                    continue;
+                }
+                let Some(value) = results.component_batch_raw(component_name) else {
+                    continue;
+                };
+                let descriptor = unit
+                    .components()
+                    .get(component_name)
+                    .and_then(|descriptors| descriptors.keys().next().cloned())
+                    .unwrap_or_else(|| ComponentDescriptor::new(*component_name));
+
+                objects
+                    .entry(BlueprintObject::from_entity_path(entity_path))
+                    .or_default()
+                    .insert(
+                        (entity_path.clone(), *component_name),
+                        Property { descriptor, value },
+                    );
+            }
+        }
+
+        Self {
+            store_info: blueprint.store_info().cloned(),
+            viewport,
+            objects,
+        }
+    }
+
+    /// A human-readable description of an object, including its blueprint id.
+    fn label(&self, object: &BlueprintObject) -> String {
+        match object {
+            BlueprintObject::Viewport => "viewport".to_owned(),
+
+            BlueprintObject::Container(container_id) => {
+                let Some(container) = self.viewport.container(container_id) else {
+                    return container_id.to_string();
+                };
+                let mut label = format!("{container_id} {:?}", container.container_kind);
+                if let Some(name) = &container.display_name {
+                    label += &format!(" {name:?}");
+                }
+                label
+            }
+
+            BlueprintObject::View(view_id) => {
+                let Some(view) = self.viewport.view(view_id) else {
+                    return view_id.to_string();
+                };
+                let mut label = format!("{view_id} {}", view.class_identifier());
+                if let Some(name) = &view.display_name {
+                    label += &format!(" {name:?}");
+                }
+                label + &format!(" (origin {})", view.space_origin)
+            }
+
+            BlueprintObject::Other(entity_path) => entity_path.to_string(),
+        }
+    }
+
+    /// The children of an object in the viewport's tree, in order.
+    fn children(&self, object: &BlueprintObject) -> Vec<BlueprintObject> {
+        match object {
+            BlueprintObject::Viewport => {
+                let root = BlueprintObject::Container(self.viewport.root_container);
+                if self.objects.contains_key(&root) {
+                    vec![root]
+                } else {
+                    Vec::new()
+                }
+            }
+
+            BlueprintObject::Container(container_id) => self
+                .viewport
+                .container(container_id)
+                .map(|container| container.contents.iter().copied().map(Into::into).collect())
+                .unwrap_or_default(),
+
+            BlueprintObject::View(_) | BlueprintObject::Other(_) => Vec::new(),
+        }
+    }
+}
+
+/// Formats the value of a property for humans, truncating long values.
+fn format_value(value: &dyn arrow::array::Array) -> String {
+    use arrow::util::display::{ArrayFormatter, FormatOptions};
+
+    const MAX_CHARS: usize = 80;
+
+    let options = FormatOptions::default().with_null("null");
+    let Ok(formatter) = ArrayFormatter::try_new(value, &options) else {
+        return format!("<{}>", value.data_type());
+    };
+
+    let mut values: Vec<String> = (0..value.len())
+        .map(|index| formatter.value(index).to_string())
+        .collect();
+    let formatted = if values.len() == 1 {
+        values.remove(0)
+    } else {
+        format!("[{}]", values.join(", "))
+    };
+
+    if formatted.chars().count() > MAX_CHARS {
+        let truncated: String = formatted.chars().take(MAX_CHARS - 1).collect();
+        format!("{truncated}…")
+    } else {
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{SetStoreInfo, StoreId, StoreSource, Time, TimeInt, TimePoint};
+    use re_types::{
+        blueprint::{
+            archetypes,
+            components::{ContainerKind, IncludedContent},
+        },
+        components::Name,
+        AsComponents, Loggable as _,
+    };
+
+    use super::*;
+
+    /// A view in a test blueprint, with the origin `/{id}`.
+    pub(super) struct TestView {
+        pub id: &'static str,
+        pub name: &'static str,
+    }
+
+    pub(super) fn view_id(id: &str) -> ViewId {
+        ViewId::hashed_from_str(id)
+    }
+
+    pub(super) fn root_id() -> ContainerId {
+        ContainerId::hashed_from_str("root")
+    }
+
+    /// A blueprint with a horizontal root container that holds the given views.
+    pub(super) fn blueprint(views: &[TestView]) -> EntityDb {
+        let store_id = StoreId::random(StoreKind::Blueprint);
+        let mut blueprint = EntityDb::new(store_id.clone());
+        blueprint.set_store_info(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                application_id: "test".into(),
+                store_id,
+                cloned_from: None,
+                is_official_example: false,
+                started: Time::now(),
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        });
+
+        log(
+            &mut blueprint,
+            VIEWPORT_PATH.into(),
+            &archetypes::ViewportBlueprint::new().with_root_container(root_id()),
+        );
+        log(
+            &mut blueprint,
+            root_id().as_entity_path(),
+            &archetypes::ContainerBlueprint::new(ContainerKind::Horizontal).with_contents(
+                views
+                    .iter()
+                    .map(|view| IncludedContent::from(&view_id(view.id).as_entity_path())),
+            ),
+        );
+        for view in views {
+            log(
+                &mut blueprint,
+                view_id(view.id).as_entity_path(),
+                &archetypes::ViewBlueprint::new("3D")
+                    .with_display_name(view.name)
+                    .with_space_origin(format!("/{}", view.id)),
+            );
+        }
+
+        blueprint
+    }
+
+    pub(super) fn log(blueprint: &mut EntityDb, entity_path: EntityPath, data: &dyn AsComponents) {
+        let timepoint = TimePoint::from([(blueprint_timeline(), TimeInt::new_temporal(0))]);
+        let chunk = Chunk::builder(entity_path)
+            .with_archetype(RowId::new(), timepoint, data)
+            .build()
+            .unwrap();
+        blueprint.add_chunk(&Arc::new(chunk)).unwrap();
+    }
+
+    #[test]
+    fn blueprint_objects() {
+        let container_id = root_id();
+        let view_id = view_id("a");
+
+        assert_eq!(
+            BlueprintObject::from_entity_path(&VIEWPORT_PATH.into()),
+            BlueprintObject::Viewport
+        );
+        assert_eq!(
+            BlueprintObject::from_entity_path(&container_id.as_entity_path()),
+            BlueprintObject::Container(container_id)
+        );
+        assert_eq!(
+            BlueprintObject::from_entity_path(
+                &view_id.as_entity_path().join(&"ViewContents".into())
+            ),
+            BlueprintObject::View(view_id)
+        );
+        assert_eq!(
+            BlueprintObject::from_entity_path(&"/time_panel/sub".into()),
+            BlueprintObject::Other("/time_panel".into())
+        );
+        assert_eq!(
+            BlueprintObject::from_entity_path(ViewId::registry()),
+            BlueprintObject::Other(ViewId::registry().clone())
+        );
+
+        let object = BlueprintObject::View(view_id);
+        assert_eq!(
+            object.property_name(&(view_id.as_entity_path(), Name::name())),
+            "Name"
+        );
+        assert_eq!(
+            object.property_name(&(
+                view_id.as_entity_path().join(&"ViewContents".into()),
+                Name::name()
+            )),
+            "ViewContents:Name"
+        );
+    }
+
+    #[test]
+    fn snapshot() {
+        let snapshot = BlueprintSnapshot::new(&blueprint(&[
+            TestView { id: "a", name: "A" },
+            TestView { id: "b", name: "B" },
+        ]));
+
+        let root = BlueprintObject::Container(root_id());
+        let view_a = BlueprintObject::View(view_id("a"));
+        let view_b = BlueprintObject::View(view_id("b"));
+
+        assert_eq!(
+            snapshot.objects.keys().cloned().collect::<BTreeSet<_>>(),
+            BTreeSet::from([
+                BlueprintObject::Viewport,
+                root.clone(),
+                view_a.clone(),
+                view_b.clone()
+            ])
+        );
+        assert_eq!(
+            snapshot.children(&BlueprintObject::Viewport),
+            [root.clone()]
+        );
+        assert_eq!(snapshot.children(&root), [view_a.clone(), view_b]);
+        assert!(snapshot.children(&view_a).is_empty());
+
+        assert_eq!(snapshot.label(&root), format!("{} Horizontal", root_id()));
+        assert_eq!(
+            snapshot.label(&view_a),
+            format!("{} 3D \"A\" (origin /a)", view_id("a"))
+        );
+        assert_eq!(snapshot.label(&BlueprintObject::Viewport), "viewport");
+    }
+
+    #[test]
+    fn format_values() {
+        let value = Name::to_arrow([Name::from("A")]).unwrap();
+        assert_eq!(format_value(value.as_ref()), "A");
+
+        let value = Name::to_arrow([Name::from("A"), Name::from("B")]).unwrap();
+        assert_eq!(format_value(value.as_ref()), "[A, B]");
+
+        let value = Name::to_arrow([Name::from("x".repeat(100))]).unwrap();
+        let formatted = format_value(value.as_ref());
+        assert_eq!(formatted.chars().count(), 80);
+        assert!(formatted.ends_with('…'));
+    }
+}
@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+
+use re_data_source::DataSource;
+
+use crate::CallSource;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct RenderCommand {
+    /// Paths to the .rrd recording to render, and optionally the .rbl blueprint to render it with.
+    #[clap(required = true)]
+    path_to_inputs: Vec<String>,
+
+    /// Where to write the rendered frames.
+    ///
+    /// A path ending in `.mp4` is encoded to a video using `ffmpeg`,
+    /// any other path is used as a directory to write a PNG sequence to.
+    #[arg(short = 'o', long = "output", value_name = "dst")]
+    path_to_output: PathBuf,
+
+    /// The timeline to step through.
+    ///
+    /// Defaults to the timeline the viewer would pick.
+    #[clap(long)]
+    timeline: Option<String>,
+
+    /// First time to render, in seconds for temporal timelines or ticks for sequence timelines.
+    ///
+    /// Times are absolute, e.g. seconds since the unix epoch for timestamps.
+    /// Prefix with `+` for an offset from the start of the timeline, e.g. `+2.5`.
+    /// Defaults to the start of the timeline.
+    #[clap(long, allow_hyphen_values = true)]
+    start: Option<re_viewer::RenderTime>,
+
+    /// Last time to render, in the same format as `--start`.
+    ///
+    /// Defaults to the end of the timeline.
+    #[clap(long, allow_hyphen_values = true)]
+    end: Option<re_viewer::RenderTime>,
+
+    /// Time between two rendered frames, in seconds for temporal timelines or ticks for sequence timelines.
+    ///
+    /// Defaults to `1 / fps` seconds for temporal timelines and a single tick for sequence timelines.
+    #[clap(long)]
+    step: Option<f64>,
+
+    /// Frame rate of the encoded video.
+    #[clap(long, default_value_t = 30.0)]
+    fps: f64,
+
+    /// Name or id of the view to render.
+    ///
+    /// If not set, the whole viewport is rendered.
+    #[clap(long)]
+    view: Option<String>,
+
+    /// Size of the rendered frames in pixels, e.g. "1920x1080".
+    #[clap(long, default_value = "1280x720")]
+    resolution: String,
+
+    /// The `ffmpeg` executable to encode videos with.
+    ///
+    /// Defaults to `ffmpeg` on the `PATH`.
+    #[clap(long)]
+    ffmpeg_path: Option<PathBuf>,
+
+    /// Force a specific graphics backend.
+    ///
+    /// One of: `vulkan` (Linux & Windows only), `gl` (Linux & Windows only), `metal` (macOS only).
+    #[clap(long)]
+    renderer: Option<String>,
+}
+
+impl RenderCommand {
+    pub fn run(
+        &self,
+        main_thread_token: crate::MainThreadToken,
+        build_info: re_build_info::BuildInfo,
+        call_source: &CallSource,
+    ) -> anyhow::Result<()> {
+        let Self {
+            path_to_inputs,
+            path_to_output,
+            timeline,
+            start,
+            end,
+            step,
+            fps,
+            view,
+            resolution,
+            ffmpeg_path,
+            renderer,
+        } = self.clone();
+
+        let resolution = parse_resolution(&resolution)?;
+        anyhow::ensure!(0.0 < fps, "--fps must be positive");
+
+        let render_job = re_viewer::RenderJobOptions {
+            output: path_to_output,
+            timeline,
+            start,
+            end,
+            step,
+            fps,
+            view,
+            resolution,
+            ffmpeg_path,
+        };
+
+        let startup_options = re_viewer::StartupOptions {
+            // Don't let the state of previous sessions affect the rendering.
+            persist_state: false,
+            hide_welcome_screen: true,
+            force_wgpu_backend: renderer,
+            render_job: Some(render_job),
+            ..Default::default()
+        };
+
+        let rxs = path_to_inputs
+            .into_iter()
+            .map(|uri| DataSource::from_uri(re_log_types::FileSource::Cli, uri).stream(None))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        re_viewer::render(
+            main_thread_token,
+            build_info,
+            &call_source.app_env(),
+            startup_options,
+            rxs,
+        )
+    }
+}
+
+fn parse_resolution(resolution: &str) -> anyhow::Result<[u32; 2]> {
+    let parsed = resolution.split_once('x').and_then(|(w, h)| {
+        let w: u32 = w.parse().ok()?;
+        let h: u32 = h.parse().ok()?;
+        (w > 0 && h > 0).then_some([w, h])
+    });
+    parsed.ok_or_else(|| {
+        anyhow::anyhow!("Invalid resolution {resolution:?}, expected e.g. 1920x1080")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_resolution() {
+        assert_eq!(parse_resolution("1920x1080").unwrap(), [1920, 1080]);
+        assert_eq!(parse_resolution("1x1").unwrap(), [1, 1]);
+
+        assert!(parse_resolution("1920").is_err());
+        assert!(parse_resolution("1920x").is_err());
+        assert!(parse_resolution("0x1080").is_err());
+        assert!(parse_resolution("-1920x1080").is_err());
+        assert!(parse_resolution("1920 x 1080").is_err());
+        assert!(parse_resolution("1920x1080x3").is_err());
+    }
+}
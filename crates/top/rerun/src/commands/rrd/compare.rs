@@ -118,7 +118,7 @@ impl CompareCommand {
 /// `Chunk`s.
 ///
 /// Fails if there are more than one data recordings present in the rrd file.
-fn compute_uber_table(
+pub(super) fn compute_uber_table(
     path_to_rrd: &Path,
 ) -> anyhow::Result<(re_log_types::ApplicationId, Vec<Arc<re_chunk::Chunk>>)> {
     use re_entity_db::EntityDb;
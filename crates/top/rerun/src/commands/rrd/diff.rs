@@ -0,0 +1,813 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use anyhow::Context as _;
+use arrow::{
+    array::{Array as _, ArrayRef as ArrowArrayRef, AsArray as _},
+    datatypes::{DataType as ArrowDataType, Float64Type},
+};
+use serde::Serialize as _;
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, TimeInt, Timeline};
+use re_types::ComponentDescriptor;
+
+use super::compare::compute_uber_table;
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DiffCommand {
+    path_to_rrd1: String,
+    path_to_rrd2: String,
+
+    /// Numeric values whose absolute difference is at most this large are considered equal.
+    #[clap(long, default_value_t = 0.0)]
+    tolerance: f64,
+
+    /// Ignore the given timeline. Can be specified multiple times.
+    ///
+    /// Data that only lives on ignored timelines is not compared.
+    #[clap(long = "ignore-timeline")]
+    ignored_timelines: Vec<String>,
+
+    /// Also compare the `log_time` timeline.
+    ///
+    /// It is ignored by default, since it is different for every run.
+    #[clap(long, default_value_t = false)]
+    include_log_time: bool,
+
+    /// Print the report as JSON instead of human-readable text.
+    #[clap(long, default_value_t = false)]
+    json: bool,
+}
+
+impl DiffCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_rrd1,
+            path_to_rrd2,
+            tolerance,
+            ignored_timelines,
+            include_log_time,
+            json,
+        } = self;
+
+        re_log::debug!("Diffing {path_to_rrd1:?} against {path_to_rrd2:?}…");
+
+        let path_to_rrd1 = PathBuf::from(path_to_rrd1);
+        let path_to_rrd2 = PathBuf::from(path_to_rrd2);
+
+        let (_, chunks1) =
+            compute_uber_table(&path_to_rrd1).with_context(|| format!("path: {path_to_rrd1:?}"))?;
+        let (_, chunks2) =
+            compute_uber_table(&path_to_rrd2).with_context(|| format!("path: {path_to_rrd2:?}"))?;
+
+        let is_ignored = |timeline: &Timeline| {
+            (!include_log_time && *timeline == Timeline::log_time())
+                || ignored_timelines
+                    .iter()
+                    .any(|name| name == timeline.name().as_str())
+        };
+
+        let recording1 = RecordingIndex::new(&chunks1, &is_ignored);
+        let recording2 = RecordingIndex::new(&chunks2, &is_ignored);
+        let report = DiffReport::new(&recording1, &recording2, *tolerance);
+
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print!("{report}");
+        }
+
+        let num_differences = report.num_differences();
+        anyhow::ensure!(
+            num_differences == 0,
+            "Found {} difference(s) between {path_to_rrd1:?} and {path_to_rrd2:?}",
+            re_format::format_uint(num_differences),
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// All the cells logged at a given time, in [`RowId`] order.
+type Cells = Vec<(RowId, ArrowArrayRef)>;
+
+/// The data of a single component of a single entity on a single timeline.
+///
+/// Static data is stored under the [`TimeInt::STATIC`] time of the `None` timeline.
+type ComponentColumns = BTreeMap<Option<Timeline>, BTreeMap<TimeInt, Cells>>;
+
+/// The data of a recording, indexed for diffing.
+#[derive(Default)]
+struct RecordingIndex {
+    entities: BTreeMap<EntityPath, BTreeMap<ComponentDescriptor, ComponentColumns>>,
+}
+
+impl RecordingIndex {
+    fn new(chunks: &[Arc<Chunk>], is_ignored: &dyn Fn(&Timeline) -> bool) -> Self {
+        let mut index = Self::default();
+
+        for chunk in chunks {
+            let times_per_timeline: Vec<(Option<Timeline>, Vec<TimeInt>)> = if chunk.is_static() {
+                vec![(None, vec![TimeInt::STATIC; chunk.num_rows()])]
+            } else {
+                chunk
+                    .timelines()
+                    .iter()
+                    .filter(|(timeline, _)| !is_ignored(timeline))
+                    .map(|(timeline, time_column)| (Some(*timeline), time_column.times().collect()))
+                    .collect()
+            };
+
+            let row_ids: Vec<RowId> = chunk.row_ids().collect();
+            let components = index
+                .entities
+                .entry(chunk.entity_path().clone())
+                .or_default();
+
+            for (descriptor, list_array) in chunk.components().iter_flattened() {
+                // Even if all the timelines are ignored, the component still exists.
+                let columns = components.entry(descriptor.clone()).or_default();
+
+                for (timeline, times) in &times_per_timeline {
+                    let column = columns.entry(*timeline).or_default();
+                    for (row, (time, row_id)) in times.iter().zip(&row_ids).enumerate() {
+                        if list_array.is_null(row) {
+                            continue;
+                        }
+                        column
+                            .entry(*time)
+                            .or_default()
+                            .push((*row_id, list_array.value(row)));
+                    }
+                }
+            }
+        }
+
+        for columns in index.entities.values_mut().flat_map(|c| c.values_mut()) {
+            for cells in columns.values_mut().flat_map(|c| c.values_mut()) {
+                cells.sort_by_key(|(row_id, _)| *row_id);
+            }
+        }
+
+        index
+    }
+}
+
+// ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ChangeKind {
+    /// Only present in the second recording.
+    Added,
+
+    /// Only present in the first recording.
+    Removed,
+
+    /// Present in both recordings, but with different values.
+    Changed,
+}
+
+/// A contiguous range of times at which a component differs in the same way.
+#[derive(Debug, serde::Serialize)]
+struct ChangedRange {
+    kind: ChangeKind,
+
+    /// First time in the range (`null` for static data).
+    #[serde(serialize_with = "serialize_time")]
+    start: TimeInt,
+
+    /// Last time in the range, inclusive (`null` for static data).
+    #[serde(serialize_with = "serialize_time")]
+    end: TimeInt,
+
+    /// Number of distinct times in the range that differ.
+    num_times: usize,
+
+    /// The largest absolute difference between numeric values, if the values are numeric
+    /// and of the same shape.
+    max_abs_difference: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ComponentRef {
+    entity: String,
+    component: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ComponentChanges {
+    entity: String,
+    component: String,
+
+    /// The timeline the times in `ranges` refer to (`null` for static data).
+    #[serde(serialize_with = "serialize_timeline")]
+    timeline: Option<Timeline>,
+
+    ranges: Vec<ChangedRange>,
+}
+
+/// The semantic differences between two recordings.
+#[derive(Debug, Default, serde::Serialize)]
+struct DiffReport {
+    added_entities: Vec<String>,
+    removed_entities: Vec<String>,
+    added_components: Vec<ComponentRef>,
+    removed_components: Vec<ComponentRef>,
+    changes: Vec<ComponentChanges>,
+}
+
+fn serialize_time<S: serde::Serializer>(time: &TimeInt, serializer: S) -> Result<S::Ok, S::Error> {
+    (!time.is_static())
+        .then(|| time.as_i64())
+        .serialize(serializer)
+}
+
+fn serialize_timeline<S: serde::Serializer>(
+    timeline: &Option<Timeline>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    timeline
+        .map(|timeline| timeline.name().to_string())
+        .serialize(serializer)
+}
+
+impl DiffReport {
+    fn new(recording1: &RecordingIndex, recording2: &RecordingIndex, tolerance: f64) -> Self {
+        let mut report = Self::default();
+
+        let entities: BTreeSet<&EntityPath> = recording1
+            .entities
+            .keys()
+            .chain(recording2.entities.keys())
+            .collect();
+
+        for entity_path in entities {
+            let (components1, components2) = match (
+                recording1.entities.get(entity_path),
+                recording2.entities.get(entity_path),
+            ) {
+                (Some(components1), Some(components2)) => (components1, components2),
+                (Some(_), None) => {
+                    report.removed_entities.push(entity_path.to_string());
+                    continue;
+                }
+                (None, Some(_)) => {
+                    report.added_entities.push(entity_path.to_string());
+                    continue;
+                }
+                (None, None) => unreachable!("entity comes from one of the recordings"),
+            };
+
+            let descriptors: BTreeSet<&ComponentDescriptor> =
+                components1.keys().chain(components2.keys()).collect();
+
+            for descriptor in descriptors {
+                let component_ref = || ComponentRef {
+                    entity: entity_path.to_string(),
+                    component: descriptor.to_string(),
+                };
+
+                let (columns1, columns2) =
+                    match (components1.get(descriptor), components2.get(descriptor)) {
+                        (Some(columns1), Some(columns2)) => (columns1, columns2),
+                        (Some(_), None) => {
+                            report.removed_components.push(component_ref());
+                            continue;
+                        }
+                        (None, Some(_)) => {
+                            report.added_components.push(component_ref());
+                            continue;
+                        }
+                        (None, None) => unreachable!("component comes from one of the recordings"),
+                    };
+
+                let timelines: BTreeSet<Option<Timeline>> =
+                    columns1.keys().chain(columns2.keys()).copied().collect();
+
+                let empty = BTreeMap::new();
+                for timeline in timelines {
+                    let ranges = diff_column(
+                        columns1.get(&timeline).unwrap_or(&empty),
+                        columns2.get(&timeline).unwrap_or(&empty),
+                        tolerance,
+                    );
+
+                    if !ranges.is_empty() {
+                        report.changes.push(ComponentChanges {
+                            entity: entity_path.to_string(),
+                            component: descriptor.to_string(),
+                            timeline,
+                            ranges,
+                        });
+                    }
+                }
+            }
+        }
+
+        report
+    }
+
+    fn num_differences(&self) -> usize {
+        self.added_entities.len()
+            + self.removed_entities.len()
+            + self.added_components.len()
+            + self.removed_components.len()
+            + self
+                .changes
+                .iter()
+                .map(|changes| changes.ranges.len())
+                .sum::<usize>()
+    }
+}
+
+impl std::fmt::Display for DiffReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            added_entities,
+            removed_entities,
+            added_components,
+            removed_components,
+            changes,
+        } = self;
+
+        for entity in added_entities {
+            writeln!(f, "+ entity {entity}")?;
+        }
+        for entity in removed_entities {
+            writeln!(f, "- entity {entity}")?;
+        }
+        for ComponentRef { entity, component } in added_components {
+            writeln!(f, "+ component {entity}:{component}")?;
+        }
+        for ComponentRef { entity, component } in removed_components {
+            writeln!(f, "- component {entity}:{component}")?;
+        }
+
+        for ComponentChanges {
+            entity,
+            component,
+            timeline,
+            ranges,
+        } in changes
+        {
+            match timeline {
+                Some(timeline) => writeln!(f, "~ {entity}:{component} on '{}'", timeline.name())?,
+                None => writeln!(f, "~ {entity}:{component} (static)")?,
+            }
+
+            for range in ranges {
+                let ChangedRange {
+                    kind,
+                    start,
+                    end,
+                    num_times,
+                    max_abs_difference,
+                } = range;
+
+                let kind = match kind {
+                    ChangeKind::Added => "added",
+                    ChangeKind::Removed => "removed",
+                    ChangeKind::Changed => "changed",
+                };
+                write!(f, "    {kind:<8}")?;
+
+                if let Some(timeline) = timeline {
+                    let typ = timeline.typ();
+                    if start == end {
+                        write!(f, "{}", typ.format_utc(*start))?;
+                    } else {
+                        write!(f, "{}..={}", typ.format_utc(*start), typ.format_utc(*end))?;
+                    }
+                    write!(f, " ({} time(s))", re_format::format_uint(*num_times))?;
+                }
+
+                if let Some(max_abs_difference) = max_abs_difference {
+                    write!(
+                        f,
+                        ", max abs difference {}",
+                        re_format::format_f64(*max_abs_difference)
+                    )?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        let num_differences = self.num_differences();
+        if num_differences == 0 {
+            writeln!(f, "No differences found.")
+        } else {
+            writeln!(
+                f,
+                "{} difference(s) found.",
+                re_format::format_uint(num_differences)
+            )
+        }
+    }
+}
+
+/// Walks all the times of both columns in order, and merges consecutive times that
+/// differ in the same way into ranges.
+fn diff_column(
+    column1: &BTreeMap<TimeInt, Cells>,
+    column2: &BTreeMap<TimeInt, Cells>,
+    tolerance: f64,
+) -> Vec<ChangedRange> {
+    let times: BTreeSet<TimeInt> = column1.keys().chain(column2.keys()).copied().collect();
+
+    let mut ranges = Vec::new();
+    let mut current: Option<ChangedRange> = None;
+
+    for time in times {
+        let change = match (column1.get(&time), column2.get(&time)) {
+            (Some(_), None) => Some((ChangeKind::Removed, None)),
+            (None, Some(_)) => Some((ChangeKind::Added, None)),
+            (Some(cells1), Some(cells2)) => match compare_cells(cells1, cells2, tolerance) {
+                CellComparison::Equal => None,
+                CellComparison::Changed { max_abs_difference } => {
+                    Some((ChangeKind::Changed, max_abs_difference))
+                }
+            },
+            (None, None) => unreachable!("time comes from one of the columns"),
+        };
+
+        let Some((kind, max_abs_difference)) = change else {
+            ranges.extend(current.take());
+            continue;
+        };
+
+        if let Some(range) = current.as_mut().filter(|range| range.kind == kind) {
+            range.end = time;
+            range.num_times += 1;
+            range.max_abs_difference = max_option(range.max_abs_difference, max_abs_difference);
+        } else {
+            ranges.extend(current.replace(ChangedRange {
+                kind,
+                start: time,
+                end: time,
+                num_times: 1,
+                max_abs_difference,
+            }));
+        }
+    }
+
+    ranges.extend(current);
+    ranges
+}
+
+fn max_option(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+enum CellComparison {
+    Equal,
+    Changed { max_abs_difference: Option<f64> },
+}
+
+/// Compares all the cells logged at the same time, pairwise in [`RowId`] order.
+fn compare_cells(cells1: &Cells, cells2: &Cells, tolerance: f64) -> CellComparison {
+    if cells1.len() != cells2.len() {
+        return CellComparison::Changed {
+            max_abs_difference: None,
+        };
+    }
+
+    let mut changed = false;
+    let mut max_abs_difference = None;
+
+    for ((_, cell1), (_, cell2)) in cells1.iter().zip(cells2) {
+        match compare_cell(cell1, cell2, tolerance) {
+            CellComparison::Equal => {}
+            CellComparison::Changed {
+                max_abs_difference: difference,
+            } => {
+                changed = true;
+                max_abs_difference = max_option(max_abs_difference, difference);
+            }
+        }
+    }
+
+    if changed {
+        CellComparison::Changed { max_abs_difference }
+    } else {
+        CellComparison::Equal
+    }
+}
+
+fn compare_cell(cell1: &ArrowArrayRef, cell2: &ArrowArrayRef, tolerance: f64) -> CellComparison {
+    if cell1.data_type() == cell2.data_type() {
+        if let (Some(numbers1), Some(numbers2)) = (numeric_values(cell1), numeric_values(cell2)) {
+            if numbers1.list_lengths == numbers2.list_lengths
+                && numbers1.values.len() == numbers2.values.len()
+            {
+                let (values1, values2) = (&numbers1.values, &numbers2.values);
+
+                let max_abs_difference = values1
+                    .iter()
+                    .zip(values2)
+                    .map(|(v1, v2)| {
+                        if v1.is_nan() && v2.is_nan() {
+                            0.0
+                        } else if v1 == v2 {
+                            // Handles infinities.
+                            0.0
+                        } else {
+                            (v1 - v2).abs()
+                        }
+                    })
+                    .fold(0.0, f64::max);
+
+                // NaN differences (i.e. only one side is NaN) are never within tolerance.
+                let nan_mismatch = values1
+                    .iter()
+                    .zip(values2)
+                    .any(|(v1, v2)| v1.is_nan() != v2.is_nan());
+
+                return if max_abs_difference <= tolerance && !nan_mismatch {
+                    CellComparison::Equal
+                } else {
+                    CellComparison::Changed {
+                        max_abs_difference: Some(max_abs_difference),
+                    }
+                };
+            }
+        } else if cell1.to_data() == cell2.to_data() {
+            return CellComparison::Equal;
+        }
+    }
+
+    CellComparison::Changed {
+        max_abs_difference: None,
+    }
+}
+
+/// The numbers in an array of (nested) numbers.
+#[derive(Debug, Default, PartialEq)]
+struct NumericValues {
+    /// All numbers, flattened, with nulls as NaN.
+    values: Vec<f64>,
+
+    /// The length of every (nested) list, in order, or `None` for null lists.
+    ///
+    /// Values can only be compared one by one if both arrays are laid out the same way,
+    /// e.g. `[[1, 2], [3]]` and `[[1], [2, 3]]` have the same values, but not the same shape.
+    list_lengths: Vec<Option<usize>>,
+}
+
+/// Returns `None` if the array contains anything but numbers.
+fn numeric_values(array: &dyn arrow::array::Array) -> Option<NumericValues> {
+    let mut numbers = NumericValues::default();
+    collect_numeric_values(array, &mut numbers)?;
+    Some(numbers)
+}
+
+fn collect_numeric_values(
+    array: &dyn arrow::array::Array,
+    numbers: &mut NumericValues,
+) -> Option<()> {
+    let data_type = array.data_type();
+
+    if data_type.is_numeric() {
+        let array = arrow::compute::cast(array, &ArrowDataType::Float64).ok()?;
+        numbers.values.extend(
+            array
+                .as_primitive::<Float64Type>()
+                .iter()
+                .map(|value| value.unwrap_or(f64::NAN)),
+        );
+        return Some(());
+    }
+
+    match data_type {
+        ArrowDataType::List(_) => {
+            let list = array.as_list::<i32>();
+            for i in 0..list.len() {
+                collect_list_values(list.is_null(i), &list.value(i), numbers)?;
+            }
+        }
+        ArrowDataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            for i in 0..list.len() {
+                collect_list_values(list.is_null(i), &list.value(i), numbers)?;
+            }
+        }
+        ArrowDataType::FixedSizeList(_, _) => {
+            let list = array.as_fixed_size_list();
+            for i in 0..list.len() {
+                collect_list_values(list.is_null(i), &list.value(i), numbers)?;
+            }
+        }
+        ArrowDataType::Struct(_) => {
+            for column in array.as_struct().columns() {
+                collect_numeric_values(column, numbers)?;
+            }
+        }
+        _ => return None,
+    }
+
+    Some(())
+}
+
+fn collect_list_values(
+    is_null: bool,
+    values: &ArrowArrayRef,
+    numbers: &mut NumericValues,
+) -> Option<()> {
+    if is_null {
+        numbers.list_lengths.push(None);
+        Some(())
+    } else {
+        numbers.list_lengths.push(Some(values.len()));
+        collect_numeric_values(values, numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Float64Array, ListArray, StringArray};
+
+    use super::*;
+
+    fn floats(values: &[f64]) -> ArrowArrayRef {
+        Arc::new(Float64Array::from(values.to_vec()))
+    }
+
+    fn lists(lists: &[&[f64]]) -> ArrowArrayRef {
+        Arc::new(ListArray::from_iter_primitive::<Float64Type, _, _>(
+            lists
+                .iter()
+                .map(|list| Some(list.iter().copied().map(Some).collect::<Vec<_>>())),
+        ))
+    }
+
+    fn max_abs_difference(comparison: &CellComparison) -> Option<Option<f64>> {
+        match comparison {
+            CellComparison::Equal => None,
+            CellComparison::Changed { max_abs_difference } => Some(*max_abs_difference),
+        }
+    }
+
+    #[test]
+    fn compare_cell_tolerance() {
+        let cell1 = floats(&[1.0, 2.0]);
+        let cell2 = floats(&[1.0, 2.25]);
+
+        assert!(matches!(
+            compare_cell(&cell1, &cell1, 0.0),
+            CellComparison::Equal
+        ));
+        assert!(matches!(
+            compare_cell(&cell1, &cell2, 0.5),
+            CellComparison::Equal
+        ));
+        assert_eq!(
+            max_abs_difference(&compare_cell(&cell1, &cell2, 0.1)),
+            Some(Some(0.25))
+        );
+    }
+
+    #[test]
+    fn compare_cell_nan() {
+        let nan = floats(&[f64::NAN]);
+        let one = floats(&[1.0]);
+
+        assert!(matches!(
+            compare_cell(&nan, &nan, 0.0),
+            CellComparison::Equal
+        ));
+        assert!(matches!(
+            compare_cell(&nan, &one, f64::INFINITY),
+            CellComparison::Changed { .. }
+        ));
+    }
+
+    #[test]
+    fn compare_cell_list_shape() {
+        let cell1 = lists(&[&[1.0, 2.0], &[3.0]]);
+        let cell2 = lists(&[&[1.0], &[2.0, 3.0]]);
+        let cell3 = lists(&[&[1.0, 2.5], &[3.0]]);
+
+        // Same values, different shape: there is nothing to compare value by value.
+        assert_eq!(
+            max_abs_difference(&compare_cell(&cell1, &cell2, f64::INFINITY)),
+            Some(None)
+        );
+        assert_eq!(
+            max_abs_difference(&compare_cell(&cell1, &cell3, 0.0)),
+            Some(Some(0.5))
+        );
+    }
+
+    #[test]
+    fn compare_cell_non_numeric() {
+        let cell1: ArrowArrayRef = Arc::new(StringArray::from(vec!["a", "b"]));
+        let cell2: ArrowArrayRef = Arc::new(StringArray::from(vec!["a", "c"]));
+
+        assert!(matches!(
+            compare_cell(&cell1, &cell1.clone(), 0.0),
+            CellComparison::Equal
+        ));
+        assert_eq!(
+            max_abs_difference(&compare_cell(&cell1, &cell2, f64::INFINITY)),
+            Some(None)
+        );
+
+        // Different types are never equal.
+        assert_eq!(
+            max_abs_difference(&compare_cell(&cell1, &floats(&[1.0]), f64::INFINITY)),
+            Some(None)
+        );
+    }
+
+    fn column(values: &[(i64, f64)]) -> BTreeMap<TimeInt, Cells> {
+        values
+            .iter()
+            .map(|&(time, value)| {
+                (
+                    TimeInt::new_temporal(time),
+                    vec![(RowId::new(), floats(&[value]))],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_column_ranges() {
+        let column1 = column(&[(1, 1.0), (2, 2.0), (3, 3.0), (4, 4.0), (5, 5.0)]);
+        let column2 = column(&[(1, 1.0), (2, 2.5), (3, 4.0), (4, 4.0), (6, 6.0)]);
+
+        let ranges = diff_column(&column1, &column2, 0.0);
+        let ranges: Vec<_> = ranges
+            .iter()
+            .map(|range| {
+                (
+                    range.kind,
+                    range.start.as_i64(),
+                    range.end.as_i64(),
+                    range.num_times,
+                    range.max_abs_difference,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            ranges,
+            vec![
+                (ChangeKind::Changed, 2, 3, 2, Some(1.0)),
+                (ChangeKind::Removed, 5, 5, 1, None),
+                (ChangeKind::Added, 6, 6, 1, None),
+            ]
+        );
+    }
+
+    fn recording(entities: &[(&str, &[(i64, f64)])]) -> RecordingIndex {
+        let timeline = Timeline::new_sequence("frame");
+        let descriptor = ComponentDescriptor::new("rerun.components.Scalar");
+
+        RecordingIndex {
+            entities: entities
+                .iter()
+                .map(|&(entity_path, values)| {
+                    let columns = std::iter::once((Some(timeline), column(values))).collect();
+                    let components = std::iter::once((descriptor.clone(), columns)).collect();
+                    (EntityPath::from(entity_path), components)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn diff_report_tolerance() {
+        let recording1 = recording(&[("a", &[(1, 1.0), (2, 2.0)]), ("b", &[(1, 1.0)])]);
+        let recording2 = recording(&[("a", &[(1, 1.0), (2, 2.01)]), ("c", &[(1, 1.0)])]);
+
+        let report = DiffReport::new(&recording1, &recording1, 0.0);
+        assert_eq!(report.num_differences(), 0);
+
+        // Within tolerance, only the added and removed entities are reported.
+        let report = DiffReport::new(&recording1, &recording2, 0.1);
+        assert_eq!(report.added_entities, vec!["/c".to_owned()]);
+        assert_eq!(report.removed_entities, vec!["/b".to_owned()]);
+        assert!(report.changes.is_empty());
+        assert_eq!(report.num_differences(), 2);
+
+        let report = DiffReport::new(&recording1, &recording2, 0.001);
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].entity, "/a");
+        assert_eq!(report.changes[0].ranges.len(), 1);
+        let max_abs_difference = report.changes[0].ranges[0].max_abs_difference.unwrap();
+        assert!((max_abs_difference - 0.01).abs() < 1e-9);
+        assert_eq!(report.num_differences(), 3);
+    }
+}
@@ -1,9 +1,11 @@
 mod compare;
+mod diff;
 mod filter;
 mod merge_compact;
 mod print;
 
 use self::compare::CompareCommand;
+use self::diff::DiffCommand;
 use self::filter::FilterCommand;
 use self::merge_compact::{CompactCommand, MergeCommand};
 use self::print::PrintCommand;
@@ -22,6 +24,18 @@ pub enum RrdCommands {
     /// This ignores the `log_time` timeline.
     Compare(CompareCommand),
 
+    /// Reports the semantic differences between 2 .rrd files, per entity and component over time.
+    ///
+    /// Reports added and removed entities and components, as well as the time ranges in which
+    /// values differ, either as text or as JSON.
+    /// Returns a successful shell exit code only if there are no differences.
+    ///
+    /// Unlike `compare`, this does not care about how the data is split into chunks.
+    /// The `log_time` timeline is ignored unless `--include-log-time` is passed.
+    ///
+    /// Example: `rerun rrd diff --tolerance 1e-6 --json before.rrd after.rrd > report.json`
+    Diff(DiffCommand),
+
     /// Print the contents of one or more .rrd/.rbl files/streams.
     ///
     /// Reads from standard input if no paths are specified.
@@ -75,6 +89,9 @@ impl RrdCommands {
                     // Print current directory, this can be useful for debugging issues with relative paths.
                     .with_context(|| format!("current directory {:?}", std::env::current_dir()))
             }
+            Self::Diff(diff_command) => diff_command
+                .run()
+                .with_context(|| format!("current directory {:?}", std::env::current_dir())),
             Self::Print(print_command) => print_command.run(),
             Self::Compact(compact_command) => compact_command.run(),
             Self::Merge(merge_command) => merge_command.run(),
@@ -1,13 +1,13 @@
 /// Handles errors for the `re_auth` crate.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("transparent")]
+    #[error(transparent)]
     Jwt(#[from] jsonwebtoken::errors::Error),
 
-    #[error("transparent")]
+    #[error(transparent)]
     Base64Decode(#[from] base64::DecodeError),
 
-    #[error("transparent")]
+    #[error(transparent)]
     SystemTime(#[from] std::time::SystemTimeError),
 
     #[error("failed to parse token")]
@@ -8,11 +8,13 @@
 //! integration of _real_ identity-providers, most likely based on OpenID Connect.
 
 pub use error::Error;
+pub use permission::{Access, InvalidPermission, Permission, Resource};
 pub use provider::{Claims, RedapProvider, VerificationOptions};
 pub use service::*;
 pub use token::{Jwt, TokenError};
 
 mod error;
+mod permission;
 mod provider;
 mod service;
 mod token;
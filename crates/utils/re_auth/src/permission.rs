@@ -0,0 +1,235 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The kind of access a [`Permission`] grants.
+///
+/// [`Access::Write`] implies [`Access::Read`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Access {
+    Read,
+    Write,
+}
+
+impl Access {
+    /// Does having `self` also allow `other`?
+    #[inline]
+    pub fn includes(self, other: Self) -> bool {
+        self >= other
+    }
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Read => "read",
+            Self::Write => "write",
+        })
+    }
+}
+
+/// A resource that can be accessed on the server.
+///
+/// An id of `*` refers to all resources of that kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Catalog(String),
+    Recording(String),
+}
+
+impl Resource {
+    /// The id referring to all resources of a kind.
+    pub const WILDCARD: &'static str = "*";
+
+    /// All recordings.
+    #[inline]
+    pub fn all_recordings() -> Self {
+        Self::Recording(Self::WILDCARD.to_owned())
+    }
+
+    /// All catalogs.
+    #[inline]
+    pub fn all_catalogs() -> Self {
+        Self::Catalog(Self::WILDCARD.to_owned())
+    }
+
+    /// Does `self` cover the `other` resource?
+    ///
+    /// A wildcard only ever covers resources of the same kind.
+    fn covers(&self, other: &Self) -> bool {
+        let matches = |pattern: &str, id: &str| pattern == Self::WILDCARD || pattern == id;
+        match (self, other) {
+            (Self::Catalog(pattern), Self::Catalog(id))
+            | (Self::Recording(pattern), Self::Recording(id)) => matches(pattern, id),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Catalog(id) => write!(f, "catalog/{id}"),
+            Self::Recording(id) => write!(f, "recording/{id}"),
+        }
+    }
+}
+
+/// A scope that is encoded in a token, restricting what its bearer is allowed to do.
+///
+/// The string representation is `<access>[:<resource>]`, e.g.:
+/// * `read`: read everything
+/// * `write`: read and write everything
+/// * `read:catalog/my_catalog`: read a single catalog
+/// * `write:recording/*`: read and write all recordings
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Permission {
+    pub access: Access,
+
+    /// The resource this permission applies to, or `None` for all resources.
+    pub resource: Option<Resource>,
+}
+
+impl Permission {
+    /// Read access to everything.
+    pub const READ_ALL: Self = Self {
+        access: Access::Read,
+        resource: None,
+    };
+
+    /// Read and write access to everything.
+    pub const WRITE_ALL: Self = Self {
+        access: Access::Write,
+        resource: None,
+    };
+
+    #[inline]
+    pub fn new(access: Access, resource: Option<Resource>) -> Self {
+        Self { access, resource }
+    }
+
+    /// Does this permission allow `access` to `resource`?
+    pub fn grants(&self, access: Access, resource: &Resource) -> bool {
+        self.access.includes(access)
+            && self
+                .resource
+                .as_ref()
+                .map_or(true, |scope| scope.covers(resource))
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.resource {
+            Some(resource) => write!(f, "{}:{resource}", self.access),
+            None => write!(f, "{}", self.access),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "invalid permission {0:?}, expected `<read|write>[:<catalog|recording>/<id>]`, e.g. `read:recording/*`"
+)]
+pub struct InvalidPermission(String);
+
+impl FromStr for Permission {
+    type Err = InvalidPermission;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidPermission(s.to_owned());
+
+        let (access, resource) = match s.split_once(':') {
+            Some((access, resource)) => (access, Some(resource)),
+            None => (s, None),
+        };
+
+        let access = match access {
+            "read" => Access::Read,
+            "write" => Access::Write,
+            _ => return Err(invalid()),
+        };
+
+        let resource = resource
+            .map(|resource| {
+                let (kind, id) = resource.split_once('/').ok_or_else(invalid)?;
+                if id.is_empty() {
+                    return Err(invalid());
+                }
+                match kind {
+                    "catalog" => Ok(Resource::Catalog(id.to_owned())),
+                    "recording" => Ok(Resource::Recording(id.to_owned())),
+                    _ => Err(invalid()),
+                }
+            })
+            .transpose()?;
+
+        Ok(Self { access, resource })
+    }
+}
+
+impl TryFrom<String> for Permission {
+    type Error = InvalidPermission;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Permission> for String {
+    fn from(permission: Permission) -> Self {
+        permission.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for s in [
+            "read",
+            "write",
+            "read:catalog/my_catalog",
+            "write:recording/*",
+        ] {
+            let permission: Permission = s.parse().unwrap();
+            assert_eq!(permission.to_string(), s);
+        }
+
+        for s in [
+            "",
+            "admin",
+            "read:",
+            "read:catalog",
+            "read:catalog/",
+            "read:foo/bar",
+        ] {
+            assert!(s.parse::<Permission>().is_err(), "{s:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn grants() {
+        let recording = Resource::Recording("rec".to_owned());
+        let catalog = Resource::Catalog("cat".to_owned());
+
+        assert!(Permission::READ_ALL.grants(Access::Read, &recording));
+        assert!(!Permission::READ_ALL.grants(Access::Write, &recording));
+        assert!(Permission::WRITE_ALL.grants(Access::Read, &catalog));
+        assert!(Permission::WRITE_ALL.grants(Access::Write, &Resource::all_recordings()));
+
+        let single: Permission = "write:recording/rec".parse().unwrap();
+        assert!(single.grants(Access::Read, &recording));
+        assert!(single.grants(Access::Write, &recording));
+        assert!(!single.grants(Access::Read, &Resource::Recording("other".to_owned())));
+        assert!(!single.grants(Access::Read, &Resource::all_recordings()));
+        assert!(!single.grants(Access::Read, &Resource::Catalog("rec".to_owned())));
+
+        let all_recordings: Permission = "read:recording/*".parse().unwrap();
+        assert!(all_recordings.grants(Access::Read, &recording));
+        assert!(all_recordings.grants(Access::Read, &Resource::all_recordings()));
+        assert!(!all_recordings.grants(Access::Read, &catalog));
+    }
+}
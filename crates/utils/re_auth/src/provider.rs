@@ -3,7 +3,7 @@ use std::time::Duration;
 use base64::{engine::general_purpose, Engine as _};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 
-use crate::{Error, Jwt};
+use crate::{Error, Jwt, Permission};
 
 /// Identifies who should be the consumer of a token. In our case, this is the Rerun storage node.
 const AUDIENCE: &str = "redap";
@@ -29,7 +29,7 @@ impl std::fmt::Debug for RedapProvider {
     }
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Claims {
     /// The issuer of the token.
     ///
@@ -49,6 +49,20 @@ pub struct Claims {
 
     /// Issued at time of the token.
     pub iat: u64,
+
+    /// What the bearer of the token is allowed to do.
+    ///
+    /// Tokens issued before permissions were introduced don't have this field.
+    /// They keep the full access they used to have, i.e. [`Permission::WRITE_ALL`].
+    /// Re-issue them with explicit permissions to restrict them.
+    /// An explicitly empty list grants nothing.
+    #[serde(default = "legacy_permissions")]
+    pub permissions: Vec<Permission>,
+}
+
+/// The permissions of tokens that predate the `permissions` claim.
+fn legacy_permissions() -> Vec<Permission> {
+    vec![Permission::WRITE_ALL]
 }
 
 #[derive(Debug, Clone)]
@@ -126,13 +140,13 @@ impl RedapProvider {
     /// signed by the [`RedapProvider`]. This means that its contents are readable
     /// by everyone.
     ///
-    /// If `duration` is `None`, the token will be valid forever. `scope` can be
-    /// used to restrict the token to a specific context.
+    /// The bearer of the token is only allowed to do what `permissions` grant.
     pub fn token(
         &self,
         duration: Duration,
         issuer: impl Into<String>,
         subject: impl Into<String>,
+        permissions: impl IntoIterator<Item = Permission>,
     ) -> Result<Jwt, Error> {
         let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
 
@@ -142,6 +156,7 @@ impl RedapProvider {
             aud: AUDIENCE.to_owned(),
             exp: (now + duration).as_secs(),
             iat: now.as_secs(),
+            permissions: permissions.into_iter().collect(),
         };
 
         let token = encode(
@@ -6,7 +6,8 @@ use crate::Jwt;
 
 use super::{AUTHORIZATION_KEY, TOKEN_PREFIX};
 
-#[derive(Default)]
+/// Adds the given token to every request, if any.
+#[derive(Default, Clone)]
 pub struct AuthDecorator {
     jwt: Option<Jwt>,
 }
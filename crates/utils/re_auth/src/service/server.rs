@@ -4,13 +4,66 @@ use tonic::{
     Request, Status,
 };
 
-use crate::{provider::VerificationOptions, Error, Jwt, RedapProvider};
+use crate::{
+    provider::VerificationOptions, Access, Error, Jwt, Permission, RedapProvider, Resource,
+};
 
 use super::{AUTHORIZATION_KEY, TOKEN_PREFIX};
 
+/// The authenticated user of a request, inserted into the request extensions by the [`Authenticator`].
 #[derive(Debug, Clone)]
 pub struct UserContext {
     pub user_id: String,
+
+    /// The permissions granted by the user's token.
+    pub permissions: Vec<Permission>,
+}
+
+impl UserContext {
+    /// A user that is allowed to do everything.
+    ///
+    /// Services that don't require authentication attach this to every request,
+    /// so that [`authorize`] lets them through.
+    /// The [`Authenticator`] also attaches it to requests without a token,
+    /// unless it requires any permissions or [`Authenticator::reject_anonymous`] is set.
+    pub fn unrestricted() -> Self {
+        Self {
+            user_id: "anonymous".to_owned(),
+            permissions: vec![Permission::WRITE_ALL],
+        }
+    }
+
+    /// Is the user allowed `access` to `resource`?
+    pub fn is_allowed(&self, access: Access, resource: &Resource) -> bool {
+        self.permissions
+            .iter()
+            .any(|permission| permission.grants(access, resource))
+    }
+
+    /// Like [`Self::is_allowed`], but returns a [`Status::permission_denied`] error.
+    pub fn authorize(&self, access: Access, resource: &Resource) -> Result<(), Status> {
+        if self.is_allowed(access, resource) {
+            Ok(())
+        } else {
+            Err(Status::permission_denied(format!(
+                "{} is not allowed to {access} {resource}",
+                self.user_id
+            )))
+        }
+    }
+}
+
+/// Checks that the user of `req` is allowed `access` to `resource`.
+///
+/// This is meant to be called from within service methods, for checks that depend
+/// on the contents of the request.
+/// Requests without a [`UserContext`] are always denied, so services without authentication
+/// need to attach [`UserContext::unrestricted`] themselves.
+pub fn authorize<T>(req: &Request<T>, access: Access, resource: &Resource) -> Result<(), Status> {
+    match req.extensions().get::<UserContext>() {
+        Some(user) => user.authorize(access, resource),
+        None => Err(Status::unauthenticated("missing credentials")),
+    }
 }
 
 impl TryFrom<&MetadataValue<Ascii>> for Jwt {
@@ -26,15 +79,47 @@ impl TryFrom<&MetadataValue<Ascii>> for Jwt {
 }
 
 /// A basic authenticator that checks for a valid auth token.
+///
+/// Requests with an invalid token are rejected, as are requests whose token lacks
+/// the permissions added with [`Self::require`].
+///
+/// Requests without a token are rejected if any permissions were added with [`Self::require`].
+/// Otherwise they are let through with [`UserContext::unrestricted`],
+/// like they were before permissions were introduced.
+/// Use [`Self::reject_anonymous`] to require a token for every request.
 #[derive(Clone)]
 pub struct Authenticator {
     secret_key: RedapProvider,
+    required: Vec<(Access, Resource)>,
+    reject_anonymous: bool,
 }
 
 impl Authenticator {
-    /// Creates a new [`Authenticator`] with the given secret key and scope.
+    /// Creates a new [`Authenticator`] with the given secret key.
     pub fn new(secret_key: RedapProvider) -> Self {
-        Self { secret_key }
+        Self {
+            secret_key,
+            required: Vec::new(),
+            reject_anonymous: false,
+        }
+    }
+
+    /// Reject requests that don't carry an auth token.
+    #[inline]
+    pub fn reject_anonymous(mut self) -> Self {
+        self.reject_anonymous = true;
+        self
+    }
+
+    /// Only accept requests whose token grants `access` to `resource`.
+    ///
+    /// Requests without a token are rejected from then on.
+    ///
+    /// Use [`authorize`] for checks that depend on the individual request.
+    #[inline]
+    pub fn require(mut self, access: Access, resource: Resource) -> Self {
+        self.required.push((access, resource));
+        self
     }
 }
 
@@ -42,20 +127,34 @@ impl Interceptor for Authenticator {
     fn call(&mut self, req: Request<()>) -> Result<Request<()>, Status> {
         let mut req = req;
 
-        if let Some(token_metadata) = req.metadata().get(AUTHORIZATION_KEY) {
-            let token = Jwt::try_from(token_metadata)
-                .map_err(|_err| Status::unauthenticated("malformed auth token"))?;
+        let Some(token_metadata) = req.metadata().get(AUTHORIZATION_KEY) else {
+            if self.reject_anonymous || !self.required.is_empty() {
+                return Err(Status::unauthenticated("missing credentials"));
+            }
+
+            req.extensions_mut().insert(UserContext::unrestricted());
+            return Ok(req);
+        };
+
+        let token = Jwt::try_from(token_metadata)
+            .map_err(|_err| Status::unauthenticated("malformed auth token"))?;
 
-            let claims = self
-                .secret_key
-                .verify(&token, VerificationOptions::default())
-                .map_err(|_err| Status::unauthenticated("invalid credentials"))?;
+        let claims = self
+            .secret_key
+            .verify(&token, VerificationOptions::default())
+            .map_err(|_err| Status::unauthenticated("invalid credentials"))?;
 
-            req.extensions_mut().insert(UserContext {
-                user_id: claims.sub,
-            });
+        let user = UserContext {
+            user_id: claims.sub,
+            permissions: claims.permissions,
+        };
+
+        for (access, resource) in &self.required {
+            user.authorize(*access, resource)?;
         }
 
+        req.extensions_mut().insert(user);
+
         Ok(req)
     }
 }
@@ -1,7 +1,12 @@
 use rand::rngs::ThreadRng;
 use std::time::Duration;
 
-use re_auth::{Error, RedapProvider, VerificationOptions};
+use base64::Engine as _;
+use re_auth::{
+    client::AuthDecorator, server::Authenticator, Access, Error, Jwt, Permission, RedapProvider,
+    Resource, VerificationOptions,
+};
+use tonic::{service::Interceptor as _, Code, Request};
 
 const KEY: &str = "CKxq6b4Hy3xdjDOWwaShOJab+eu6jcsFso4rbLjJuZ8=";
 
@@ -22,6 +27,7 @@ fn generate_read_only_token_with_duration() {
             Duration::from_secs(2 * 60 * 60),
             "re_auth_test",
             "test@rerun.io",
+            [Permission::READ_ALL],
         )
         .unwrap();
 
@@ -29,6 +35,7 @@ fn generate_read_only_token_with_duration() {
 
     assert_eq!(&user.sub, "test@rerun.io");
     assert_eq!(&user.iss, "re_auth_test");
+    assert_eq!(user.permissions, vec![Permission::READ_ALL]);
 }
 
 #[test]
@@ -38,7 +45,12 @@ fn expired_token() {
     let duration = Duration::from_secs(1);
 
     let token = key
-        .token(Duration::from_secs(1), "re_auth_test", "test@rerun.io")
+        .token(
+            Duration::from_secs(1),
+            "re_auth_test",
+            "test@rerun.io",
+            [Permission::READ_ALL],
+        )
         .unwrap();
 
     std::thread::sleep(duration * 2);
@@ -50,3 +62,84 @@ fn expired_token() {
         "Expected an expired token error"
     );
 }
+
+#[test]
+fn token_without_permissions_claim_has_full_access() {
+    // Tokens issued before the `permissions` claim existed.
+    #[derive(serde::Serialize)]
+    struct LegacyClaims {
+        iss: String,
+        sub: String,
+        aud: String,
+        exp: u64,
+        iat: u64,
+    }
+
+    let key = RedapProvider::from_base64(KEY).unwrap();
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let claims = LegacyClaims {
+        iss: "re_auth_test".to_owned(),
+        sub: "test@rerun.io".to_owned(),
+        aud: "redap".to_owned(),
+        exp: now + 60,
+        iat: now,
+    };
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(
+            &base64::engine::general_purpose::STANDARD
+                .decode(KEY)
+                .unwrap(),
+        ),
+    )
+    .unwrap();
+
+    let user = key
+        .verify(
+            &Jwt::try_from(token).unwrap(),
+            VerificationOptions::default(),
+        )
+        .unwrap();
+
+    assert_eq!(user.permissions, vec![Permission::WRITE_ALL]);
+}
+
+#[test]
+fn required_access_rejects_requests_without_token() {
+    let key = RedapProvider::from_base64(KEY).unwrap();
+
+    // Without required permissions, anonymous requests are let through.
+    assert!(Authenticator::new(key.clone())
+        .call(Request::new(()))
+        .is_ok());
+
+    let mut authenticator =
+        Authenticator::new(key.clone()).require(Access::Write, Resource::all_recordings());
+
+    let status = authenticator.call(Request::new(())).unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+
+    let mut call_with = |permission: Permission| {
+        let token = key
+            .token(
+                Duration::from_secs(60),
+                "re_auth_test",
+                "test@rerun.io",
+                [permission],
+            )
+            .unwrap();
+        let req = AuthDecorator::new(Some(token))
+            .call(Request::new(()))
+            .unwrap();
+        authenticator.call(req)
+    };
+
+    let status = call_with(Permission::READ_ALL).unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert!(call_with(Permission::WRITE_ALL).is_ok());
+}
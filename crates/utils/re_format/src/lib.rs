@@ -715,9 +715,11 @@ pub fn parse_duration(duration: &str) -> Result<f32, String> {
         Ok(parse_num(s)? * 60.0)
     } else if let Some(s) = duration.strip_suffix('h') {
         Ok(parse_num(s)? * 60.0 * 60.0)
+    } else if let Some(s) = duration.strip_suffix('d') {
+        Ok(parse_num(s)? * 24.0 * 60.0 * 60.0)
     } else {
         Err(format!(
-            "Expected a suffix of 'ms', 's', 'm' or 'h' in string {duration:?}"
+            "Expected a suffix of 'ms', 's', 'm', 'h' or 'd' in string {duration:?}"
         ))
     }
 }
@@ -727,4 +729,5 @@ fn test_parse_duration() {
     assert_eq!(parse_duration("3.2s"), Ok(3.2));
     assert_eq!(parse_duration("250ms"), Ok(0.250));
     assert_eq!(parse_duration("3m"), Ok(3.0 * 60.0));
+    assert_eq!(parse_duration("7d"), Ok(7.0 * 24.0 * 60.0 * 60.0));
 }
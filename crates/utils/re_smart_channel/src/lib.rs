@@ -72,7 +72,7 @@ pub enum SmartChannelSource {
     /// A stream of messages over message proxy gRPC interface.
     MessageProxy {
         // TODO(#8761): URL prefix
-        /// Should include `temp://` prefix.
+        /// Should include `temp://` (or `temps://`) prefix.
         url: String,
     },
 }
@@ -169,7 +169,7 @@ pub enum SmartMessageSource {
     /// A stream of messages over message proxy gRPC interface.
     MessageProxy {
         // TODO(#8761): URL prefix
-        /// Should include `temp://` prefix.
+        /// Should include `temp://` (or `temps://`) prefix.
         url: String,
     },
 }
@@ -36,14 +36,16 @@ use datatype_uis::{
 use re_types::blueprint::components::{RootContainer, ViewMaximized};
 use re_types::{
     blueprint::components::{
-        BackgroundKind, Corner2D, Enabled, ForceDistance, ForceIterations, ForceStrength,
-        GridSpacing, LockRangeDuringZoom, MapProvider, NearClipPlane, ViewFit, Visible,
+        BackgroundKind, Corner2D, Enabled, Eye3DKind, FieldOfView, ForceDistance, ForceIterations,
+        ForceStrength, GridSpacing, LockRangeDuringZoom, LookTarget, MapProvider, MeasurementKind,
+        NearClipPlane, UseRegex, ViewFit, Visible,
     },
     components::{
-        AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
-        FillRatio, GammaCorrection, GraphType, ImagePlaneDistance, MagnificationFilter, MarkerSize,
-        Name, Opacity, Position2D, Range1D, Scale3D, ShowLabels, StrokeWidth, Text,
-        TransformRelation, Translation3D, ValueRange,
+        AggregationPolicy, AlbedoFactor, AxisLength, CellSize, Color, DepthMeter, DrawOrder,
+        FillMode, FillRatio, GammaCorrection, GraphType, ImagePlaneDistance, LensDistortionModel,
+        MagnificationFilter, MarkerSize, Name, Opacity, Position2D, Position3D, Range1D, Scale3D,
+        ShowLabels, StrokeWidth, Text, TransformRelation, Translation3D, ValueRange, Vector3D,
+        VoxelSize,
     },
 };
 use re_viewer_context::gpu_bridge::colormap_edit_or_view_ui;
@@ -70,6 +72,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     // 0-inf float components:
     registry.add_singleline_edit_or_view::<AxisLength>(edit_f32_zero_to_max);
     registry.add_singleline_edit_or_view::<DepthMeter>(edit_f32_zero_to_max);
+    registry.add_singleline_edit_or_view::<FieldOfView>(edit_f32_zero_to_max);
     registry.add_singleline_edit_or_view::<FillRatio>(edit_f32_zero_to_max);
     registry.add_singleline_edit_or_view::<ForceDistance>(edit_f64_zero_to_max);
     registry.add_singleline_edit_or_view::<GammaCorrection>(edit_f32_zero_to_max);
@@ -95,6 +98,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<Enabled>(edit_bool);
     registry.add_singleline_edit_or_view::<LockRangeDuringZoom>(edit_bool);
     registry.add_singleline_edit_or_view::<ShowLabels>(edit_bool);
+    registry.add_singleline_edit_or_view::<UseRegex>(edit_bool);
     registry.add_singleline_edit_or_view::<Visible>(edit_bool);
 
     // Text components:
@@ -108,8 +112,11 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<AggregationPolicy>(edit_view_enum);
     registry.add_singleline_edit_or_view::<BackgroundKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Corner2D>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<Eye3DKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<FillMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<GraphType>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<LensDistortionModel>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<MeasurementKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<MapProvider>(
         edit_view_enum_with_variant_available::<
             MapProvider,
@@ -121,11 +128,16 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<ViewFit>(edit_view_enum);
 
     // Vec2 components:
+    registry.add_singleline_edit_or_view::<CellSize>(edit_or_view_vec2d);
     registry.add_singleline_edit_or_view::<Position2D>(edit_or_view_vec2d);
 
     // Vec3 components:
+    registry.add_singleline_edit_or_view::<LookTarget>(edit_or_view_vec3d);
+    registry.add_singleline_edit_or_view::<Position3D>(edit_or_view_vec3d);
     registry.add_singleline_edit_or_view::<Translation3D>(edit_or_view_vec3d);
     registry.add_singleline_edit_or_view::<Scale3D>(edit_or_view_vec3d);
+    registry.add_singleline_edit_or_view::<Vector3D>(edit_or_view_vec3d);
+    registry.add_singleline_edit_or_view::<VoxelSize>(edit_or_view_vec3d);
 
     // Components that refer to views:
     registry.add_singleline_edit_or_view::<ViewMaximized>(view_view_id);
@@ -5,11 +5,13 @@
 #import <./colormap.wgsl>
 #import <./global_bindings.wgsl>
 #import <./types.wgsl>
+#import <./utils/albedo.wgsl>
 #import <./utils/camera.wgsl>
+#import <./utils/clipping.wgsl>
 #import <./utils/flags.wgsl>
+#import <./utils/lens_distortion.wgsl>
 #import <./utils/size.wgsl>
 #import <./utils/sphere_quad.wgsl>
-#import <./utils/srgb.wgsl>
 
 // ---
 
@@ -53,6 +55,27 @@ struct DepthCloudInfo {
 
     /// Changes between the opaque and outline draw-phases.
     radius_boost_in_ui_points: f32,
+
+    /// Lens distortion of the depth camera, see `lens_distortion.wgsl`.
+    lens_distortion_radial: vec4f,
+    lens_distortion_tangential: vec2f,
+
+    /// One of `LENS_DISTORTION_MODEL_*`, zero for an ideal pinhole camera.
+    lens_distortion_model: u32,
+
+    /// Extrinsics between the depth camera and the camera of the albedo texture.
+    albedo_rdf_from_depth_rdf: mat4x4f,
+
+    /// The intrinsics of the albedo camera.
+    albedo_camera_intrinsics: mat3x3f,
+
+    /// Lens distortion of the albedo camera, see `lens_distortion.wgsl`.
+    albedo_lens_distortion_radial: vec4f,
+    albedo_lens_distortion_tangential: vec2f,
+    albedo_lens_distortion_model: u32,
+
+    /// How to turn the texels of the albedo texture into colors.
+    @align(16) albedo: AlbedoInfo,
 };
 
 @group(1) @binding(0)
@@ -67,6 +90,9 @@ var texture_sint: texture_2d<i32>;
 @group(1) @binding(3)
 var texture_uint: texture_2d<u32>;
 
+@group(1) @binding(4)
+var albedo_texture: texture_2d<f32>;
+
 struct VertexOut {
     @builtin(position)
     pos_in_clip: vec4f,
@@ -95,6 +121,32 @@ struct PointData {
     color: vec4f,
 }
 
+// Looks up the color of a point in the albedo texture by projecting it into the albedo camera.
+//
+// Returns a negative alpha if the point isn't seen by the albedo camera.
+fn sample_albedo(pos_in_depth_rdf: vec3f) -> vec4f {
+    let pos_in_albedo_rdf = (depth_cloud_info.albedo_rdf_from_depth_rdf * vec4f(pos_in_depth_rdf, 1.0)).xyz;
+    if pos_in_albedo_rdf.z <= 0.0 {
+        return vec4f(-1.0);
+    }
+
+    var lens_distortion: LensDistortion;
+    lens_distortion.model = depth_cloud_info.albedo_lens_distortion_model;
+    lens_distortion.radial = depth_cloud_info.albedo_lens_distortion_radial;
+    lens_distortion.tangential = depth_cloud_info.albedo_lens_distortion_tangential;
+    let normalized = lens_distort(lens_distortion, pos_in_albedo_rdf.xy / pos_in_albedo_rdf.z);
+
+    let intrinsics = depth_cloud_info.albedo_camera_intrinsics;
+    let focal_length = vec2f(intrinsics[0][0], intrinsics[1][1]);
+    let offset = vec2f(intrinsics[2][0], intrinsics[2][1]);
+    let texcoords = vec2i(round(normalized * focal_length + offset));
+    if any(texcoords < vec2i(0)) || any(vec2i(textureDimensions(albedo_texture)) <= texcoords) {
+        return vec4f(-1.0);
+    }
+
+    return albedo_color(depth_cloud_info.albedo, textureLoad(albedo_texture, texcoords, 0));
+}
+
 // Backprojects the depth texture using the intrinsics passed in the uniform buffer.
 fn compute_point_data(quad_idx: u32) -> PointData {
     var texcoords: vec2u;
@@ -119,23 +171,35 @@ fn compute_point_data(quad_idx: u32) -> PointData {
     var data: PointData;
 
     if 0.0 < world_space_depth && world_space_depth < f32max {
-        // TODO(cmc): albedo textures
         let normalized_depth =
             (world_space_depth - depth_cloud_info.min_max_depth_in_world.x) /
             (depth_cloud_info.min_max_depth_in_world.y - depth_cloud_info.min_max_depth_in_world.x);
-        let color = vec4f(colormap_linear(depth_cloud_info.colormap, normalized_depth), 1.0);
+        var color = vec4f(colormap_linear(depth_cloud_info.colormap, normalized_depth), 1.0);
 
         // TODO(cmc): This assumes a pinhole camera; need to support other kinds at some point.
         let intrinsics = depth_cloud_info.depth_camera_intrinsics;
         let focal_length = vec2f(intrinsics[0][0], intrinsics[1][1]);
         let offset = vec2f(intrinsics[2][0], intrinsics[2][1]);
 
+        var lens_distortion: LensDistortion;
+        lens_distortion.model = depth_cloud_info.lens_distortion_model;
+        lens_distortion.radial = depth_cloud_info.lens_distortion_radial;
+        lens_distortion.tangential = depth_cloud_info.lens_distortion_tangential;
+        let normalized = lens_undistort(lens_distortion, (vec2f(texcoords) - offset) / focal_length);
+
         // RDF: X=Right, Y=Down, Z=Forward
         let pos_in_rdf = vec3f(
-            (vec2f(texcoords) - offset) * world_space_depth / focal_length,
+            normalized * world_space_depth,
             world_space_depth, // RDF, Z=forward, so positive depth
         );
 
+        if depth_cloud_info.albedo.color_mapper != ALBEDO_COLOR_MAPPER_NONE {
+            let albedo = sample_albedo(pos_in_rdf);
+            if 0.0 <= albedo.a {
+                color = albedo;
+            }
+        }
+
         let pos_in_world = depth_cloud_info.world_from_rdf * vec4f(pos_in_rdf, 1.0);
 
         data.pos_in_world = pos_in_world.xyz;
@@ -162,7 +226,8 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     out.point_color = point_data.color;
     out.quad_idx = u32(quad_idx);
 
-    if 0.0 < point_data.unresolved_radius {
+    // Points are clipped as a whole, so that they don't turn into half-circles at the clipping planes.
+    if 0.0 < point_data.unresolved_radius && !is_clipped(point_data.pos_in_world) {
         // Span quad
         let camera_distance = distance(frame.camera_position, point_data.pos_in_world);
         let world_scale_factor = average_scale_from_transform(depth_cloud_info.world_from_rdf); // TODO(andreas): somewhat costly, should precompute this
@@ -25,6 +25,24 @@ struct FrameUniformBuffer {
 
     /// re_renderer defined device tier.
     device_tier: u32,
+
+    /// Lens distortion of the pinhole camera whose pixel space is shown, see `utils/lens_distortion.wgsl`.
+    lens_distortion_radial: vec4f,
+    lens_distortion_tangential: vec2f,
+    lens_distortion_model: u32,
+
+    /// One of `LENS_DISTORTION_MODE_*`, zero if the view doesn't apply any lens distortion.
+    lens_distortion_mode: u32,
+
+    /// Focal length and principal point in pixels of the pinhole camera the distortion belongs to.
+    lens_distortion_focal_length: vec2f,
+    lens_distortion_principal_point: vec2f,
+
+    /// Clipping planes in world space, see `utils/clipping.wgsl`.
+    ///
+    /// Only the first `num_clip_planes` entries are valid.
+    clip_planes: array<vec4f, 8>,
+    num_clip_planes: u32,
 };
 
 @group(0) @binding(0)
@@ -0,0 +1,237 @@
+//! Renders a shaded surface from a height texture.
+//!
+//! See `src/renderer/height_field.rs` for more documentation.
+
+#import <./colormap.wgsl>
+#import <./global_bindings.wgsl>
+#import <./types.wgsl>
+#import <./utils/albedo.wgsl>
+#import <./utils/clipping.wgsl>
+
+// ---
+
+// Keep in sync with `HeightFieldInfoUBO` in `height_field.rs`.
+
+// Which texture to read from?
+const SAMPLE_TYPE_FLOAT = 1u;
+const SAMPLE_TYPE_SINT  = 2u;
+const SAMPLE_TYPE_UINT  = 3u;
+
+// How to color the surface?
+const COLOR_MODE_SOLID    = 0u;
+const COLOR_MODE_COLORMAP = 1u;
+const COLOR_MODE_TEXTURE  = 2u;
+
+struct HeightFieldInfo {
+    world_from_obj: mat4x4f,
+
+    /// Inverse transpose of the upper 3x3 of `world_from_obj`, for transforming normals.
+    world_from_obj_normal: mat3x3f,
+
+    /// Position of height `[0, 0]` (xy) and the distance between neighboring heights (zw).
+    origin_and_cell_size: vec4f,
+
+    /// Color of the surface in linear space, used with `COLOR_MODE_SOLID`.
+    color: vec4f,
+
+    /// Outline mask id for the outline mask pass.
+    outline_mask_id: vec2u,
+
+    /// Picking object id that applies for the entire height field.
+    picking_layer_object_id: vec2u,
+
+    /// The heights mapped to the start & end of the colormap.
+    min_max_height: vec2f,
+
+    /// Colormap used with `COLOR_MODE_COLORMAP`, see `colormap.wgsl`.
+    colormap: u32,
+
+    /// Which texture sample to use.
+    sample_type: u32,
+
+    /// One of `COLOR_MODE_*`.
+    color_mode: u32,
+
+    /// How to turn the texels of the albedo texture into colors, used with `COLOR_MODE_TEXTURE`.
+    @align(16) albedo: AlbedoInfo,
+};
+
+@group(1) @binding(0)
+var<uniform> height_field_info: HeightFieldInfo;
+
+@group(1) @binding(1)
+var texture_float: texture_2d<f32>;
+
+@group(1) @binding(2)
+var texture_sint: texture_2d<i32>;
+
+@group(1) @binding(3)
+var texture_uint: texture_2d<u32>;
+
+@group(1) @binding(4)
+var albedo_texture: texture_2d<f32>;
+
+struct VertexOut {
+    @builtin(position)
+    position: vec4f,
+
+    @location(0) @interpolate(perspective)
+    pos_in_world: vec3f,
+
+    @location(1) @interpolate(perspective)
+    normal_in_world: vec3f,
+
+    /// Linear space color, unused with `COLOR_MODE_TEXTURE`.
+    @location(2) @interpolate(perspective)
+    color: vec4f,
+
+    /// Position on the surface, [0, 1] across the entire grid.
+    @location(3) @interpolate(perspective)
+    texcoord: vec2f,
+};
+
+// ---
+
+fn grid_dimensions() -> vec2u {
+    if height_field_info.sample_type == SAMPLE_TYPE_FLOAT {
+        return textureDimensions(texture_float);
+    } else if height_field_info.sample_type == SAMPLE_TYPE_SINT {
+        return textureDimensions(texture_sint);
+    } else {
+        return textureDimensions(texture_uint);
+    }
+}
+
+fn load_height(coords: vec2u) -> f32 {
+    if height_field_info.sample_type == SAMPLE_TYPE_FLOAT {
+        return textureLoad(texture_float, coords, 0).x;
+    } else if height_field_info.sample_type == SAMPLE_TYPE_SINT {
+        return f32(textureLoad(texture_sint, coords, 0).x);
+    } else {
+        return f32(textureLoad(texture_uint, coords, 0).x);
+    }
+}
+
+// Compilers may assume that there are no NaNs, so we check the bit pattern instead.
+fn is_finite_height(height: f32) -> bool {
+    let exponent_mask = 0x7f800000u;
+    return (bitcast<u32>(height) & exponent_mask) != exponent_mask;
+}
+
+// Height of a neighbor for computing slopes, falls back to `center` for missing neighbors.
+fn neighbor_height(coords: vec2i, dimensions: vec2u, center: f32) -> f32 {
+    let clamped = vec2u(clamp(coords, vec2i(0), vec2i(dimensions) - 1));
+    let height = load_height(clamped);
+    if is_finite_height(height) {
+        return height;
+    }
+    return center;
+}
+
+// Looks up the color of the albedo texture at the given position on the surface.
+fn sample_albedo(texcoord: vec2f) -> vec4f {
+    let dimensions = textureDimensions(albedo_texture);
+    let texel = min(vec2u(max(texcoord, vec2f(0.0)) * vec2f(dimensions)), dimensions - 1u);
+    return albedo_color(height_field_info.albedo, textureLoad(albedo_texture, texel, 0));
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
+    let dimensions = grid_dimensions();
+
+    // Two triangles per cell, spanned between its four corner heights.
+    var corner_offsets = array<vec2u, 6>(
+        vec2u(0u, 0u), vec2u(1u, 0u), vec2u(0u, 1u),
+        vec2u(0u, 1u), vec2u(1u, 0u), vec2u(1u, 1u),
+    );
+    let cell_idx = vertex_idx / 6u;
+    let cell = vec2u(cell_idx % (dimensions.x - 1u), cell_idx / (dimensions.x - 1u));
+    let coords = cell + corner_offsets[vertex_idx % 6u];
+
+    var out: VertexOut;
+
+    // A single missing height punches a hole into all cells around it.
+    let cell_heights = vec4f(
+        load_height(cell),
+        load_height(cell + vec2u(1u, 0u)),
+        load_height(cell + vec2u(0u, 1u)),
+        load_height(cell + vec2u(1u, 1u)),
+    );
+    if !(is_finite_height(cell_heights.x) && is_finite_height(cell_heights.y) &&
+         is_finite_height(cell_heights.z) && is_finite_height(cell_heights.w)) {
+        // Degenerate case - early-out!
+        out.position = vec4f(0.0);
+        return out;
+    }
+
+    let height = load_height(coords);
+    let origin = height_field_info.origin_and_cell_size.xy;
+    let cell_size = height_field_info.origin_and_cell_size.zw;
+    let pos_in_obj = vec3f(origin + vec2f(coords) * cell_size, height);
+
+    // Normal from the central differences of the neighboring heights.
+    let icoords = vec2i(coords);
+    let slope = vec2f(
+        neighbor_height(icoords + vec2i(1, 0), dimensions, height) -
+            neighbor_height(icoords - vec2i(1, 0), dimensions, height),
+        neighbor_height(icoords + vec2i(0, 1), dimensions, height) -
+            neighbor_height(icoords - vec2i(0, 1), dimensions, height),
+    ) / (2.0 * cell_size);
+    let normal_in_obj = vec3f(-slope, 1.0);
+
+    let pos_in_world = (height_field_info.world_from_obj * vec4f(pos_in_obj, 1.0)).xyz;
+    out.position = frame.projection_from_world * vec4f(pos_in_world, 1.0);
+    out.pos_in_world = pos_in_world;
+    out.normal_in_world = height_field_info.world_from_obj_normal * normal_in_obj;
+    out.texcoord = vec2f(coords) / vec2f(dimensions - 1u);
+
+    if height_field_info.color_mode == COLOR_MODE_COLORMAP {
+        let range = height_field_info.min_max_height;
+        let normalized_height = (height - range.x) / (range.y - range.x);
+        out.color = vec4f(colormap_linear(height_field_info.colormap, normalized_height), 1.0);
+    } else {
+        out.color = height_field_info.color;
+    }
+
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4f {
+    if is_clipped(in.pos_in_world) {
+        discard;
+    }
+
+    var albedo = in.color.rgb;
+    if height_field_info.color_mode == COLOR_MODE_TEXTURE {
+        albedo = sample_albedo(in.texcoord).rgb;
+    }
+
+    // Same lighting as for meshes, but lit from both sides since the surface has no inside.
+    var normal = normalize(in.normal_in_world);
+    if dot(normal, frame.camera_position - in.pos_in_world) < 0.0 {
+        normal = -normal;
+    }
+    var shading = 0.2;
+    shading += 1.0 * clamp(dot(normalize(vec3f(1.0, 2.0, 3.0)), normal), 0.0, 1.0);
+    shading += 0.5 * clamp(dot(normalize(vec3f(-1.0, -3.0, -5.0)), normal), 0.0, 1.0);
+    shading = clamp(shading, 0.0, 1.0);
+
+    return vec4f(albedo * shading, 1.0);
+}
+
+@fragment
+fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
+    if is_clipped(in.pos_in_world) {
+        discard;
+    }
+    return vec4u(height_field_info.picking_layer_object_id, 0u, 0u);
+}
+
+@fragment
+fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
+    if is_clipped(in.pos_in_world) {
+        discard;
+    }
+    return height_field_info.outline_mask_id;
+}
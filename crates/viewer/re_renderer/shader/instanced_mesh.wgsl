@@ -1,7 +1,10 @@
 #import <./types.wgsl>
 #import <./global_bindings.wgsl>
 #import <./mesh_vertex.wgsl>
+#import <./utils/clipping.wgsl>
+#import <./utils/lens_distortion.wgsl>
 #import <./utils/srgb.wgsl>
+#import <./utils/oit.wgsl>
 
 @group(1) @binding(0)
 var albedo_texture: texture_2d<f32>;
@@ -35,15 +38,24 @@ struct VertexOut {
 
     @location(5) @interpolate(flat)
     picking_layer_id: vec4u,
+
+    @location(6) @interpolate(flat)
+    opacity: f32,
+
+    @location(7)
+    world_position: vec3f,
 };
 
 @vertex
 fn vs_main(in_vertex: VertexIn, in_instance: InstanceIn) -> VertexOut {
-    let world_position = vec3f(
+    var world_position = vec3f(
         dot(in_instance.world_from_mesh_row_0.xyz, in_vertex.position) + in_instance.world_from_mesh_row_0.w,
         dot(in_instance.world_from_mesh_row_1.xyz, in_vertex.position) + in_instance.world_from_mesh_row_1.w,
         dot(in_instance.world_from_mesh_row_2.xyz, in_vertex.position) + in_instance.world_from_mesh_row_2.w,
     );
+    if in_instance.outline_mask_ids_and_flags.z != 0u {
+        world_position = distort_position_in_pixel_space(world_position);
+    }
     let world_normal = vec3f(
         dot(in_instance.world_from_mesh_normal_row_0.xyz, in_vertex.normal),
         dot(in_instance.world_from_mesh_normal_row_1.xyz, in_vertex.normal),
@@ -56,23 +68,27 @@ fn vs_main(in_vertex: VertexIn, in_instance: InstanceIn) -> VertexOut {
     out.texcoord = in_vertex.texcoord;
     out.normal_world_space = world_normal;
     out.additive_tint_rgb = linear_from_srgb(in_instance.additive_tint_srgb.rgb);
-    out.outline_mask_ids = in_instance.outline_mask_ids;
+    out.outline_mask_ids = in_instance.outline_mask_ids_and_flags.xy;
     out.picking_layer_id = in_instance.picking_layer_id;
+    // The alpha channel of the tint is the instance's opacity.
+    out.opacity = in_instance.additive_tint_srgb.a;
+    out.world_position = world_position;
 
     return out;
 }
 
-@fragment
-fn fs_main_shaded(in: VertexOut) -> @location(0) vec4f {
+/// Shaded color with separate alpha.
+fn shaded_color(in: VertexOut) -> vec4f {
     let texture = linear_from_srgb(textureSample(albedo_texture, trilinear_sampler_repeat, in.texcoord).rgb);
     let albedo = texture
                  * in.color.rgb
                  * material.albedo_factor.rgb
                  + in.additive_tint_rgb;
+    let alpha = in.color.a * material.albedo_factor.a * in.opacity;
 
     if all(in.normal_world_space == vec3f(0.0, 0.0, 0.0)) {
         // no normal, no shading
-        return vec4f(albedo, 1.0);
+        return vec4f(albedo, alpha);
     } else {
         let normal = normalize(in.normal_world_space);
 
@@ -86,16 +102,46 @@ fn fs_main_shaded(in: VertexOut) -> @location(0) vec4f {
 
         let radiance = albedo * shading;
 
-        return vec4f(radiance, 1.0);
+        return vec4f(radiance, alpha);
+    }
+}
+
+@fragment
+fn fs_main_shaded(in: VertexOut) -> @location(0) vec4f {
+    if is_clipped(in.world_position) {
+        discard;
+    }
+    let color = shaded_color(in);
+    if is_oit_transparent(color.a) {
+        discard;
+    }
+    return vec4f(color.rgb, 1.0);
+}
+
+@fragment
+fn fs_main_transparent(in: VertexOut) -> OitOutput {
+    if is_clipped(in.world_position) {
+        discard;
+    }
+    let color = shaded_color(in);
+    if !is_oit_transparent(color.a) {
+        discard;
     }
+    return oit_output(color.rgb, color.a, in.world_position);
 }
 
 @fragment
 fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
+    if is_clipped(in.world_position) {
+        discard;
+    }
     return in.picking_layer_id;
 }
 
 @fragment
 fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
+    if is_clipped(in.world_position) {
+        discard;
+    }
     return in.outline_mask_ids;
 }
@@ -1,8 +1,11 @@
 #import <./global_bindings.wgsl>
 #import <./types.wgsl>
 #import <./utils/camera.wgsl>
+#import <./utils/clipping.wgsl>
 #import <./utils/encoding.wgsl>
 #import <./utils/flags.wgsl>
+#import <./utils/lens_distortion.wgsl>
+#import <./utils/oit.wgsl>
 #import <./utils/size.wgsl>
 #import <./utils/srgb.wgsl>
 #import <./utils/depth_offset.wgsl>
@@ -32,6 +35,7 @@ struct BatchUniformBuffer {
     depth_offset: f32,
     triangle_cap_length_factor: f32,
     triangle_cap_width_factor: f32,
+    lens_distortion: u32, // Boolean: apply the view's lens distortion to all positions.
 };
 @group(2) @binding(0)
 var<uniform> batch: BatchUniformBuffer;
@@ -122,6 +126,9 @@ fn read_position_data(idx: u32) -> PositionData {
     var data: PositionData;
     let pos_4d = batch.world_from_obj * vec4f(raw_data.xyz, 1.0);
     data.pos = pos_4d.xyz / pos_4d.w;
+    if batch.lens_distortion != 0u {
+        data.pos = distort_position_in_pixel_space(data.pos);
+    }
     data.strip_index = bitcast<u32>(raw_data.w);
     return data;
 }
@@ -323,6 +330,10 @@ fn distance_to_line(pos: vec3f, line_a: vec3f, line_b: vec3f) -> f32 {
 }
 
 fn compute_coverage(in: VertexOut) -> f32 {
+    if is_clipped(in.position_world) {
+        return 0.0;
+    }
+
     var coverage = 1.0;
 
     if !has_any_flag(in.fragment_flags, FLAG_CAP_TRIANGLE) {
@@ -341,21 +352,36 @@ fn compute_coverage(in: VertexOut) -> f32 {
     return coverage;
 }
 
+fn shading(in: VertexOut) -> f32 {
+    // TODO(andreas): lighting setup
+    var shading = 1.0;
+    if has_any_flag(in.fragment_flags, FLAG_COLOR_GRADIENT) {
+        let distance_to_inner = distance_to_line_sq(in.position_world, in.rounded_inner_line_begin, in.rounded_inner_line_end);
+        shading = max(0.2, 1.0 - distance_to_inner / (in.rounded_inner_line_radius * in.rounded_inner_line_radius)) * 0.9;
+    }
+    return shading;
+}
+
 @fragment
 fn fs_main(in: VertexOut) -> @location(0) vec4f {
     var coverage = compute_coverage(in);
-    if coverage < 0.001 {
+    if coverage < 0.001 || is_oit_transparent(in.color.a) {
         discard;
     }
 
-    // TODO(andreas): lighting setup
-    var shading = 1.0;
-    if has_any_flag(in.fragment_flags, FLAG_COLOR_GRADIENT) {
-        let distance_to_inner = distance_to_line_sq(in.position_world, in.rounded_inner_line_begin, in.rounded_inner_line_end);
-        shading = max(0.2, 1.0 - distance_to_inner / (in.rounded_inner_line_radius * in.rounded_inner_line_radius)) * 0.9;
+    return vec4f(in.color.rgb * shading(in), coverage);
+}
+
+@fragment
+fn fs_main_transparent(in: VertexOut) -> OitOutput {
+    var coverage = compute_coverage(in);
+    if coverage < 0.001 || !is_oit_transparent(in.color.a) {
+        discard;
     }
 
-    return vec4f(in.color.rgb * shading, coverage);
+    // Colors come from `Color32`, i.e. have premultiplied alpha.
+    let color = in.color.rgb / max(in.color.a, 1e-5);
+    return oit_output(color * shading(in), in.color.a * coverage, in.position_world);
 }
 
 @fragment
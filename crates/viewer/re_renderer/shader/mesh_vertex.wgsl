@@ -18,5 +18,5 @@ struct InstanceIn {
     @location(9) world_from_mesh_normal_row_2: vec3f,
     @location(10) additive_tint_srgb: vec4f,
     @location(11) picking_layer_id: vec4u,
-    @location(12) outline_mask_ids: vec2u,
+    @location(12) outline_mask_ids_and_flags: vec4u, // Outline mask ids in xy, lens distortion flag in z.
 };
@@ -0,0 +1,28 @@
+// Composites the result of the order independent transparency pass on top of the main target.
+// See `draw_phases/order_independent_transparency.rs`.
+
+#import <./types.wgsl>
+#import <./screen_triangle_vertex.wgsl>
+
+@group(0) @binding(0)
+var accumulation_texture: texture_2d<f32>;
+
+@group(0) @binding(1)
+var revealage_texture: texture_2d<f32>;
+
+@fragment
+fn main(in: FragmentInput) -> @location(0) vec4f {
+    let resolution = vec2f(textureDimensions(accumulation_texture).xy);
+    let pixel_coordinates = vec2i(floor(resolution * in.texcoord));
+
+    let revealage = textureLoad(revealage_texture, pixel_coordinates, 0).r;
+    if revealage >= 1.0 {
+        // No transparent object covers this pixel.
+        discard;
+    }
+    let accumulation = textureLoad(accumulation_texture, pixel_coordinates, 0);
+
+    // Weighted average of all transparent colors, blended with the background by the total coverage.
+    let average_color = accumulation.rgb / max(accumulation.a, 1e-5);
+    return vec4f(average_color, 1.0 - revealage);
+}
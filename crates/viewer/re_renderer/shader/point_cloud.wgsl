@@ -1,7 +1,10 @@
 #import <./global_bindings.wgsl>
 #import <./types.wgsl>
 #import <./utils/camera.wgsl>
+#import <./utils/clipping.wgsl>
 #import <./utils/flags.wgsl>
+#import <./utils/lens_distortion.wgsl>
+#import <./utils/oit.wgsl>
 #import <./utils/size.wgsl>
 #import <./utils/sphere_quad.wgsl>
 #import <./utils/depth_offset.wgsl>
@@ -39,6 +42,7 @@ var<uniform> batch: BatchUniformBuffer;
 // See point_cloud.rs#PointCloudBatchFlags
 const FLAG_ENABLE_SHADING: u32 = 1u;
 const FLAG_DRAW_AS_CIRCLES: u32 = 2u;
+const FLAG_LENS_DISTORTION: u32 = 4u;
 
 struct VertexOut {
     @builtin(position)
@@ -60,7 +64,7 @@ struct VertexOut {
     // Yes, that's more fetches but all of these would be cache hits whereas vertex data pass through can be expensive, (especially on tiler architectures!)
 
     @location(3) @interpolate(flat)
-    color: vec4f, // linear RGBA with unmulitplied/separate alpha
+    color: vec4f, // linear RGBA with premultiplied alpha (coming from `Color32`)
 
     @location(4) @interpolate(flat)
     picking_instance_id: vec2u,
@@ -90,6 +94,9 @@ fn read_data(idx: u32) -> PointData {
     var data: PointData;
     let pos_4d = batch.world_from_obj * vec4f(position_data.xyz, 1.0);
     data.pos = pos_4d.xyz / pos_4d.w;
+    if has_any_flag(batch.flags, FLAG_LENS_DISTORTION) {
+        data.pos = distort_position_in_pixel_space(data.pos);
+    }
     data.unresolved_radius = position_data.w;
     data.color = color;
     data.picking_instance_id = picking_instance_id;
@@ -103,6 +110,14 @@ fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
     // Read point data (valid for the entire quad)
     let point_data = read_data(quad_idx);
 
+    // Points are clipped as a whole, so that they don't turn into half-circles at the clipping planes.
+    if is_clipped(point_data.pos) {
+        // Degenerate case - early-out!
+        var out: VertexOut;
+        out.position = vec4f(0.0);
+        return out;
+    }
+
     // Span quad
     let camera_distance = distance(frame.camera_position, point_data.pos);
     let world_scale_factor = average_scale_from_transform(batch.world_from_obj); // TODO(andreas): somewhat costly, should precompute this
@@ -145,13 +160,7 @@ fn coverage(world_position: vec3f, radius: f32, point_center: vec3f) -> f32 {
 }
 
 
-@fragment
-fn fs_main(in: VertexOut) -> @location(0) vec4f {
-    let coverage = coverage(in.world_position, in.radius, in.point_center);
-    if coverage < 0.001 {
-        discard;
-    }
-
+fn shading(in: VertexOut) -> f32 {
     // TODO(andreas): Do we want manipulate the depth buffer depth to actually render spheres?
     // TODO(andreas): Proper shading
     // TODO(andreas): This doesn't even use the sphere's world position for shading, the world position used here is flat!
@@ -159,7 +168,26 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
     if has_any_flag(batch.flags, FLAG_ENABLE_SHADING) {
         shading = max(0.4, sqrt(1.2 - distance(in.point_center, in.world_position) / in.radius)); // quick and dirty coloring
     }
-    return vec4f(in.color.rgb * shading, coverage);
+    return shading;
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4f {
+    let coverage = coverage(in.world_position, in.radius, in.point_center);
+    if coverage < 0.001 || is_oit_transparent(in.color.a) {
+        discard;
+    }
+    return vec4f(in.color.rgb * shading(in), coverage);
+}
+
+@fragment
+fn fs_main_transparent(in: VertexOut) -> OitOutput {
+    let coverage = coverage(in.world_position, in.radius, in.point_center);
+    if coverage < 0.001 || !is_oit_transparent(in.color.a) {
+        discard;
+    }
+    let color = in.color.rgb / max(in.color.a, 1e-5);
+    return oit_output(color * shading(in), in.color.a * coverage, in.world_position);
 }
 
 @fragment
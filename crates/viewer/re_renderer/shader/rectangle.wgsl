@@ -62,6 +62,9 @@ struct UniformBuffer {
 
     /// Boolean: swizzle RGBA to BGRA
     bgra_to_rgba: u32,
+
+    /// Boolean: undistort the texture with the view's lens distortion
+    lens_undistortion: u32,
 };
 
 @group(1) @binding(0)
@@ -1,5 +1,7 @@
 #import <./colormap.wgsl>
 #import <./rectangle.wgsl>
+#import <./utils/lens_distortion.wgsl>
+#import <./utils/oit.wgsl>
 #import <./utils/srgb.wgsl>
 
 fn is_magnifying(pixel_coord: vec2f) -> bool {
@@ -65,8 +67,29 @@ fn decode_color_and_filter_nearest_or_bilinear(filter_nearest: bool, coord: vec2
     }
 }
 
-@fragment
-fn fs_main(in: VertexOut) -> @location(0) vec4f {
+/// Maps texture coordinates of an ideal pinhole image to those of the distorted texture.
+///
+/// Assumes that the rectangle lies in the pixel space of the view's pinhole camera.
+fn lens_undistorted_texcoord(texcoord: vec2f) -> vec2f {
+    let top_left = rect_info.top_left_corner_position.xy;
+    let extent_u = rect_info.extent_u.xy;
+    let extent_v = rect_info.extent_v.xy;
+
+    let pixel = top_left + texcoord.x * extent_u + texcoord.y * extent_v;
+    let offset = distort_pixel(pixel) - top_left;
+    return vec2f(dot(offset, extent_u) / dot(extent_u, extent_u), dot(offset, extent_v) / dot(extent_v, extent_v));
+}
+
+/// Color of the rectangle at the given texture coordinate, with premultiplied alpha.
+fn rectangle_color(in: VertexOut) -> vec4f {
+    var texcoord = in.texcoord;
+    if rect_info.lens_undistortion != 0u && frame.lens_distortion_mode == LENS_DISTORTION_MODE_UNDISTORT_IMAGES {
+        texcoord = lens_undistorted_texcoord(texcoord);
+        if any(texcoord < vec2f(0.0)) || any(vec2f(1.0) < texcoord) {
+            discard;
+        }
+    }
+
     // Sample the main texture:
     var normalized_value: vec4f;
     var v00_coord: vec2i;
@@ -83,7 +106,7 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
         texture_dimensions = vec2f(textureDimensions(texture_uint).xy);
     }
 
-    let coord = in.texcoord * texture_dimensions;
+    let coord = texcoord * texture_dimensions;
 
     let filter_nearest = (tex_filter(coord) == FILTER_NEAREST);
 
@@ -166,6 +189,20 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
     return texture_color * rect_info.multiplicative_tint;
 }
 
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4f {
+    return rectangle_color(in);
+}
+
+@fragment
+fn fs_main_transparent(in: VertexOut) -> OitOutput {
+    let color = rectangle_color(in);
+    let world_position = rect_info.top_left_corner_position
+                         + in.texcoord.x * rect_info.extent_u
+                         + in.texcoord.y * rect_info.extent_v;
+    return oit_output(color.rgb / max(color.a, 1e-5), color.a, world_position);
+}
+
 @fragment
 fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
     return vec4u(0u, 0u, 0u, 0u); // TODO(andreas): Implement picking layer id pass-through.
@@ -0,0 +1,57 @@
+// Albedo textures that color depth clouds & height fields.
+//
+// Mirrors `albedo.rs`, see there for more documentation.
+
+#import <../colormap.wgsl>
+#import <./srgb.wgsl>
+
+// How to turn albedo texture values into colors?
+const ALBEDO_COLOR_MAPPER_NONE          = 0u;
+const ALBEDO_COLOR_MAPPER_OFF_GRAYSCALE = 1u;
+const ALBEDO_COLOR_MAPPER_OFF_RGB       = 2u;
+const ALBEDO_COLOR_MAPPER_FUNCTION      = 3u;
+
+// Keep in sync with `AlbedoInfoUBO` in `albedo.rs`.
+//
+// Has to start on a new row when embedded in another uniform struct, use `@align(16)` for that.
+struct AlbedoInfo {
+    /// One of `ALBEDO_COLOR_MAPPER_*`, `ALBEDO_COLOR_MAPPER_NONE` if there's no albedo texture.
+    color_mapper: u32,
+
+    /// Colormap used with `ALBEDO_COLOR_MAPPER_FUNCTION`, see `colormap.wgsl`.
+    colormap: u32,
+
+    /// Range of the albedo texture values, mapped to the [0, 1] range.
+    range_min_max: vec2f,
+
+    /// Exponent to raise the normalized albedo texture value.
+    gamma: f32,
+
+    /// Boolean: decode 0-1 sRGB gamma to linear space.
+    decode_srgb: u32,
+
+    /// Boolean: swizzle BGRA to RGBA.
+    bgra_to_rgba: u32,
+};
+
+// Turns a texel of an albedo texture into an opaque color in linear space.
+fn albedo_color(info: AlbedoInfo, texel: vec4f) -> vec4f {
+    let range = info.range_min_max;
+    var rgba = (texel - range.x) / (range.y - range.x);
+    rgba = clamp(rgba, vec4f(0.0), vec4f(1.0));
+    if info.bgra_to_rgba != 0u {
+        rgba = rgba.bgra;
+    }
+    if info.decode_srgb != 0u {
+        rgba = linear_from_srgba(rgba);
+    }
+    let rgb = pow(rgba.rgb, vec3f(info.gamma));
+
+    if info.color_mapper == ALBEDO_COLOR_MAPPER_OFF_GRAYSCALE {
+        return vec4f(rgb.rrr, 1.0);
+    } else if info.color_mapper == ALBEDO_COLOR_MAPPER_FUNCTION {
+        return vec4f(colormap_linear(info.colormap, rgb.r), 1.0);
+    } else {
+        return vec4f(rgb, 1.0);
+    }
+}
@@ -0,0 +1,16 @@
+#import <../global_bindings.wgsl>
+#import <./plane.wgsl>
+
+/// Whether a world space position is cut away by any of the view's clipping planes.
+///
+/// Everything on the side of a plane its normal points towards is clipped.
+fn is_clipped(world_position: vec3f) -> bool {
+    for (var i = 0u; i < frame.num_clip_planes; i += 1u) {
+        let plane_vec = frame.clip_planes[i];
+        let plane = Plane(plane_vec.xyz, plane_vec.w);
+        if distance_to_plane(plane, world_position) > 0.0 {
+            return true;
+        }
+    }
+    return false;
+}
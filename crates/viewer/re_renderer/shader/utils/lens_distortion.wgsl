@@ -0,0 +1,134 @@
+// Lens distortion of pinhole cameras.
+//
+// Mirrors the implementation in `lens_distortion.rs`, see there for more documentation.
+// All models operate on normalized image coordinates, i.e. `(X / Z, Y / Z)` for a point in camera space.
+
+#import <../global_bindings.wgsl>
+
+// See `lens_distortion.rs#LensDistortionModel`, zero means no distortion.
+const LENS_DISTORTION_MODEL_NONE = 0u;
+const LENS_DISTORTION_MODEL_BROWN_CONRADY = 1u;
+const LENS_DISTORTION_MODEL_KANNALA_BRANDT = 2u;
+
+// See `lens_distortion.rs#LensDistortionMode`, zero means the view doesn't apply any lens distortion.
+const LENS_DISTORTION_MODE_NONE = 0u;
+const LENS_DISTORTION_MODE_DISTORT_GEOMETRY = 1u;
+const LENS_DISTORTION_MODE_UNDISTORT_IMAGES = 2u;
+
+// Keep in sync with `UNDISTORT_ITERATIONS` in `lens_distortion.rs`.
+const LENS_UNDISTORT_ITERATIONS = 20u;
+
+struct LensDistortion {
+    model: u32,
+    radial: vec4f,
+    tangential: vec2f,
+}
+
+struct BrownConradyTerms {
+    radial: f32,
+    tangential: vec2f,
+}
+
+fn brown_conrady_terms(distortion: LensDistortion, p: vec2f) -> BrownConradyTerms {
+    let k = distortion.radial;
+    let t = distortion.tangential;
+    let r2 = dot(p, p);
+    let xy = p.x * p.y;
+
+    var terms: BrownConradyTerms;
+    terms.radial = 1.0 + r2 * (k.x + r2 * (k.y + r2 * (k.z + r2 * k.w)));
+    terms.tangential = vec2f(
+        2.0 * t.x * xy + t.y * (r2 + 2.0 * p.x * p.x),
+        t.x * (r2 + 2.0 * p.y * p.y) + 2.0 * t.y * xy,
+    );
+    return terms;
+}
+
+fn kannala_brandt_theta_distorted(distortion: LensDistortion, theta: f32) -> f32 {
+    let k = distortion.radial;
+    let t2 = theta * theta;
+    return theta * (1.0 + t2 * (k.x + t2 * (k.y + t2 * (k.z + t2 * k.w))));
+}
+
+// Maps ideal normalized image coordinates to distorted ones.
+fn lens_distort(distortion: LensDistortion, p: vec2f) -> vec2f {
+    if distortion.model == LENS_DISTORTION_MODEL_BROWN_CONRADY {
+        let terms = brown_conrady_terms(distortion, p);
+        return p * terms.radial + terms.tangential;
+    } else if distortion.model == LENS_DISTORTION_MODEL_KANNALA_BRANDT {
+        let r = length(p);
+        if r < 1e-7 {
+            return p;
+        }
+        return p * (kannala_brandt_theta_distorted(distortion, atan(r)) / r);
+    }
+    return p;
+}
+
+// Maps distorted normalized image coordinates to ideal ones.
+fn lens_undistort(distortion: LensDistortion, p: vec2f) -> vec2f {
+    if distortion.model == LENS_DISTORTION_MODEL_BROWN_CONRADY {
+        var undistorted = p;
+        for (var i = 0u; i < LENS_UNDISTORT_ITERATIONS; i += 1u) {
+            let terms = brown_conrady_terms(distortion, undistorted);
+            undistorted = (p - terms.tangential) / terms.radial;
+        }
+        return undistorted;
+    } else if distortion.model == LENS_DISTORTION_MODEL_KANNALA_BRANDT {
+        let theta_distorted = length(p);
+        if theta_distorted < 1e-7 {
+            return p;
+        }
+        let k = distortion.radial;
+        var theta = theta_distorted;
+        for (var i = 0u; i < LENS_UNDISTORT_ITERATIONS; i += 1u) {
+            let t2 = theta * theta;
+            let derivative = 1.0 + t2 * (3.0 * k.x + t2 * (5.0 * k.y + t2 * (7.0 * k.z + t2 * 9.0 * k.w)));
+            theta -= (kannala_brandt_theta_distorted(distortion, theta) - theta_distorted) / derivative;
+        }
+        theta = clamp(theta, 0.0, 1.5707); // Just below 90°, where tan(theta) goes to infinity.
+        return p * (tan(theta) / theta_distorted);
+    }
+    return p;
+}
+
+// ---
+
+fn frame_lens_distortion() -> LensDistortion {
+    var distortion: LensDistortion;
+    distortion.model = frame.lens_distortion_model;
+    distortion.radial = frame.lens_distortion_radial;
+    distortion.tangential = frame.lens_distortion_tangential;
+    return distortion;
+}
+
+// Distorts a pixel coordinate with the lens distortion of the view's pinhole camera.
+fn distort_pixel(pixel: vec2f) -> vec2f {
+    let focal_length = frame.lens_distortion_focal_length;
+    let principal_point = frame.lens_distortion_principal_point;
+    let normalized = (pixel - principal_point) / focal_length;
+    return lens_distort(frame_lens_distortion(), normalized) * focal_length + principal_point;
+}
+
+// Moves a world space position such that it projects onto its distorted pixel coordinate,
+// keeping its distance along the camera's forward axis.
+//
+// Assumes that world space is the pixel space of the view's pinhole camera,
+// i.e. the image plane is at z = 0, as is the case for 2D views.
+fn distort_position_in_pixel_space(pos_in_world: vec3f) -> vec3f {
+    if frame.lens_distortion_mode != LENS_DISTORTION_MODE_DISTORT_GEOMETRY {
+        return pos_in_world;
+    }
+
+    // Project onto the image plane along the ray from the camera.
+    let camera = frame.camera_position;
+    let depth = pos_in_world.z - camera.z;
+    if depth <= 0.0 {
+        return pos_in_world;
+    }
+    let pixel = camera.xy + (pos_in_world.xy - camera.xy) * (-camera.z / depth);
+
+    // Distort and move back to the original depth.
+    let distorted_pixel = distort_pixel(pixel);
+    return vec3f(camera.xy + (distorted_pixel - camera.xy) * (depth / -camera.z), pos_in_world.z);
+}
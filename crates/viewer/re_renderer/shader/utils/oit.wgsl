@@ -0,0 +1,33 @@
+// Weighted blended order independent transparency, see `draw_phases/order_independent_transparency.rs`.
+
+#import <../global_bindings.wgsl>
+
+// Keep in sync with `OrderIndependentTransparencyProcessor::render_targets`.
+struct OitOutput {
+    @location(0) accumulation: vec4f,
+    @location(1) revealage: f32,
+};
+
+/// Whether a fragment with the given alpha belongs into the order independent transparency phase
+/// rather than the opaque one.
+///
+/// Opaque pipelines discard all fragments for which this is true, transparency pipelines all others.
+/// If order independent transparency isn't supported, everything is treated as opaque.
+fn is_oit_transparent(alpha: f32) -> bool {
+    // Not comparing against 1.0 exactly since interpolation of an alpha of 1.0 isn't guaranteed to yield exactly 1.0.
+    // This is still well above the highest alpha below 1.0 that can be expressed with 8 bits (254/255).
+    return alpha < 0.999 && frame.device_tier != DEVICE_TIER_GLES;
+}
+
+/// Output of a transparent fragment with linear, unmultiplied color and the given world position.
+fn oit_output(color: vec3f, alpha: f32, world_position: vec3f) -> OitOutput {
+    // Weighting function (eq. 9) from "Weighted Blended Order-Independent Transparency" by McGuire & Bavoil,
+    // making closer fragments dominate the ones further back.
+    let depth = abs(dot(world_position - frame.camera_position, frame.camera_forward));
+    let weight = alpha * clamp(10.0 / (1e-5 + pow(depth / 5.0, 2.0) + pow(depth / 200.0, 6.0)), 1e-2, 3e3);
+
+    var out: OitOutput;
+    out.accumulation = vec4f(color * alpha, alpha) * weight;
+    out.revealage = alpha;
+    return out;
+}
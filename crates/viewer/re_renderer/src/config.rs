@@ -56,6 +56,17 @@ impl DeviceTier {
         }
     }
 
+    /// Whether the current device tier supports order independent transparency.
+    ///
+    /// This requires blending into half float render targets, which isn't guaranteed on WebGL.
+    /// If this returns false, transparent objects are drawn as if they were opaque.
+    pub fn support_order_independent_transparency(&self) -> bool {
+        match self {
+            Self::Limited => false,
+            Self::FullWebGpuSupport => true,
+        }
+    }
+
     pub fn support_bgra_textures(&self) -> bool {
         match self {
             // TODO(wgpu#3583): Incorrectly reported by wgpu right now.
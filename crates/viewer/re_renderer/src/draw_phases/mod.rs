@@ -10,6 +10,9 @@ pub use picking_layer::{
     PickingLayerProcessor,
 };
 
+mod order_independent_transparency;
+pub use order_independent_transparency::OrderIndependentTransparencyProcessor;
+
 mod screenshot;
 pub use screenshot::ScreenshotProcessor;
 
@@ -35,6 +38,14 @@ pub enum DrawPhase {
     /// Transparent objects, performing reads of the depth buffer, but no writes.
     Transparent,
 
+    /// Transparent objects that are blended in an order independent way.
+    ///
+    /// Drawn into the targets of [`OrderIndependentTransparencyProcessor`], reading but not writing
+    /// the depth buffer of the opaque phase.
+    /// Only draw data that reports [`crate::renderer::DrawData::has_order_independent_transparency`]
+    /// is drawn in this phase.
+    OrderIndependentTransparency,
+
     /// Everything that can be picked with GPU based picking.
     ///
     /// This should be everything in the `Opaque` phase.
@@ -0,0 +1,423 @@
+//! Order independent transparency.
+//!
+//! This module provides the [`OrderIndependentTransparencyProcessor`] which handles the render passes
+//! for drawing transparent objects without having to sort them.
+//!
+//! How it works:
+//! =============
+//! We use weighted blended order independent transparency as described by
+//! [McGuire & Bavoil](https://jcgt.org/published/0002/02/09/).
+//!
+//! * Opaque objects are drawn as usual, transparent fragments are discarded in that pass.
+//! * All transparent fragments are drawn into two targets, reading but not writing the depth buffer of the opaque pass:
+//!     * the accumulation target sums up premultiplied colors & alphas, weighted by a function of depth and alpha
+//!     * the revealage target multiplies up `1 - alpha`, i.e. how much of the background is still visible
+//! * Both targets are resolved and composited on top of the main target.
+//!
+//! This is only an approximation of sorted blending: it is exact for a single transparent layer,
+//! but with several layers the closer ones are merely weighted higher instead of strictly occluding the ones behind.
+//! See `shader/utils/oit.wgsl` for the shader side.
+//!
+//! Renderers opt into this by participating in `DrawPhase::OrderIndependentTransparency`
+//! and reporting [`crate::renderer::DrawData::has_order_independent_transparency`] for draw data that needs it.
+
+use smallvec::smallvec;
+
+use crate::{
+    include_shader_module,
+    renderer::screen_triangle_vertex_shader,
+    view_builder::ViewBuilder,
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuRenderPipelineHandle,
+        GpuRenderPipelinePoolAccessor, GpuTexture, PipelineLayoutDesc, PoolError,
+        RenderPipelineDesc, TextureDesc,
+    },
+    DebugLabel, RenderContext,
+};
+
+// TODO(andreas): Is this a sort of DrawPhase implementor? Need a system for this.
+pub struct OrderIndependentTransparencyProcessor {
+    label: DebugLabel,
+
+    accumulation_msaa: GpuTexture,
+    accumulation_resolved: GpuTexture,
+    revealage_msaa: GpuTexture,
+    revealage_resolved: GpuTexture,
+
+    bind_group_composite: GpuBindGroup,
+    render_pipeline_composite: GpuRenderPipelineHandle,
+}
+
+impl OrderIndependentTransparencyProcessor {
+    /// Format of the target accumulating weighted, premultiplied colors and alphas.
+    pub const ACCUMULATION_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+    /// Format of the target accumulating the product of `1 - alpha` of all transparent fragments.
+    pub const REVEALAGE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R16Float;
+
+    /// Depth state for all pipelines drawing in `DrawPhase::OrderIndependentTransparency`.
+    ///
+    /// Reads the depth buffer of the opaque phase, but doesn't write to it.
+    pub const DEPTH_STATE: Option<wgpu::DepthStencilState> = Some(wgpu::DepthStencilState {
+        format: ViewBuilder::MAIN_TARGET_DEPTH_FORMAT,
+        depth_compare: wgpu::CompareFunction::GreaterEqual,
+        depth_write_enabled: false,
+        stencil: wgpu::StencilState {
+            front: wgpu::StencilFaceState::IGNORE,
+            back: wgpu::StencilFaceState::IGNORE,
+            read_mask: 0,
+            write_mask: 0,
+        },
+        bias: wgpu::DepthBiasState {
+            constant: 0,
+            slope_scale: 0.0,
+            clamp: 0.0,
+        },
+    });
+
+    /// MSAA state for all pipelines drawing in `DrawPhase::OrderIndependentTransparency`.
+    ///
+    /// Has to match the main target since we're sharing its depth buffer.
+    pub const MSAA_STATE: wgpu::MultisampleState = ViewBuilder::MAIN_TARGET_DEFAULT_MSAA_STATE;
+
+    /// Color targets for all pipelines drawing in `DrawPhase::OrderIndependentTransparency`.
+    ///
+    /// Fragment shaders are expected to output `OitOutput` from `utils/oit.wgsl`.
+    pub fn render_targets() -> smallvec::SmallVec<[Option<wgpu::ColorTargetState>; 4]> {
+        smallvec![
+            Some(wgpu::ColorTargetState {
+                format: Self::ACCUMULATION_FORMAT,
+                blend: Some(wgpu::BlendState {
+                    color: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::One,
+                        dst_factor: wgpu::BlendFactor::One,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::One,
+                        dst_factor: wgpu::BlendFactor::One,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                }),
+                write_mask: wgpu::ColorWrites::ALL,
+            }),
+            Some(wgpu::ColorTargetState {
+                format: Self::REVEALAGE_FORMAT,
+                blend: Some(wgpu::BlendState {
+                    color: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::Zero,
+                        dst_factor: wgpu::BlendFactor::OneMinusSrc,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha: wgpu::BlendComponent::REPLACE,
+                }),
+                write_mask: wgpu::ColorWrites::RED,
+            }),
+        ]
+    }
+
+    pub fn new(ctx: &RenderContext, view_name: &DebugLabel, resolution_in_pixel: [u32; 2]) -> Self {
+        re_tracing::profile_function!();
+        let instance_label: DebugLabel =
+            format!("{view_name} - OrderIndependentTransparencyProcessor").into();
+
+        // ------------- Textures -------------
+        let texture_pool = &ctx.gpu_resources.textures;
+
+        let accumulation_desc = TextureDesc {
+            label: format!("{instance_label}::accumulation").into(),
+            size: wgpu::Extent3d {
+                width: resolution_in_pixel[0],
+                height: resolution_in_pixel[1],
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: ViewBuilder::MAIN_TARGET_SAMPLE_COUNT,
+            dimension: wgpu::TextureDimension::D2,
+            format: Self::ACCUMULATION_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        };
+        let accumulation_msaa = texture_pool.alloc(&ctx.device, &accumulation_desc);
+        let accumulation_resolved = texture_pool.alloc(
+            &ctx.device,
+            &TextureDesc {
+                label: format!("{instance_label}::accumulation_resolved").into(),
+                sample_count: 1,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                ..accumulation_desc
+            },
+        );
+        let revealage_desc = TextureDesc {
+            label: format!("{instance_label}::revealage").into(),
+            format: Self::REVEALAGE_FORMAT,
+            ..accumulation_desc
+        };
+        let revealage_msaa = texture_pool.alloc(&ctx.device, &revealage_desc);
+        let revealage_resolved = texture_pool.alloc(
+            &ctx.device,
+            &TextureDesc {
+                label: format!("{instance_label}::revealage_resolved").into(),
+                sample_count: 1,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                ..revealage_desc
+            },
+        );
+
+        // ------------- Bind Groups -------------
+
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let bind_group_layout_composite = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "OrderIndependentTransparencyProcessor::bind_group_layout_composite".into(),
+                entries: vec![texture_entry(0), texture_entry(1)],
+            },
+        );
+        let bind_group_composite = ctx.gpu_resources.bind_groups.alloc(
+            &ctx.device,
+            &ctx.gpu_resources,
+            &BindGroupDesc {
+                label: format!("{instance_label}::composite").into(),
+                entries: smallvec![
+                    BindGroupEntry::DefaultTextureView(accumulation_resolved.handle),
+                    BindGroupEntry::DefaultTextureView(revealage_resolved.handle),
+                ],
+                layout: bind_group_layout_composite,
+            },
+        );
+
+        // ------------- Render Pipelines -------------
+
+        let render_pipeline_composite = ctx.gpu_resources.render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "OrderIndependentTransparencyProcessor::composite".into(),
+                pipeline_layout: ctx.gpu_resources.pipeline_layouts.get_or_create(
+                    ctx,
+                    &PipelineLayoutDesc {
+                        label: "OrderIndependentTransparencyProcessor::composite".into(),
+                        entries: vec![bind_group_layout_composite],
+                    },
+                ),
+                vertex_entrypoint: "main".into(),
+                vertex_handle: screen_triangle_vertex_shader(ctx),
+                fragment_entrypoint: "main".into(),
+                fragment_handle: ctx.gpu_resources.shader_modules.get_or_create(
+                    ctx,
+                    &include_shader_module!("../../shader/oit_composite.wgsl"),
+                ),
+                vertex_buffers: smallvec![],
+                // Blend "over" the main target, which is treated as premultiplied, just like in the compositor.
+                render_targets: smallvec![Some(wgpu::ColorTargetState {
+                    format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::SrcAlpha,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+            },
+        );
+
+        Self {
+            label: instance_label,
+            accumulation_msaa,
+            accumulation_resolved,
+            revealage_msaa,
+            revealage_resolved,
+            bind_group_composite,
+            render_pipeline_composite,
+        }
+    }
+
+    /// Starts the pass in which all transparent objects are drawn.
+    ///
+    /// `depth_buffer` is the depth buffer the opaque objects were drawn to.
+    pub fn start_render_pass<'a>(
+        &'a self,
+        encoder: &'a mut wgpu::CommandEncoder,
+        depth_buffer: &'a GpuTexture,
+    ) -> wgpu::RenderPass<'a> {
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: DebugLabel::from(format!("{} - transparency pass", self.label)).get(),
+            color_attachments: &[
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &self.accumulation_msaa.default_view,
+                    resolve_target: Some(&self.accumulation_resolved.default_view),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Discard,
+                    },
+                }),
+                Some(wgpu::RenderPassColorAttachment {
+                    view: &self.revealage_msaa.default_view,
+                    resolve_target: Some(&self.revealage_resolved.default_view),
+                    ops: wgpu::Operations {
+                        // Everything is fully revealed until a transparent object is drawn.
+                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        store: wgpu::StoreOp::Discard,
+                    },
+                }),
+            ],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &depth_buffer.default_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Discard,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        })
+    }
+
+    /// Blends the accumulated transparent objects on top of the (resolved) main target.
+    pub fn composite(
+        &self,
+        pipelines: &GpuRenderPipelinePoolAccessor<'_>,
+        encoder: &mut wgpu::CommandEncoder,
+        main_target_resolved: &GpuTexture,
+    ) -> Result<(), PoolError> {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: DebugLabel::from(format!("{} - composite", self.label)).get(),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &main_target_resolved.default_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        pass.set_pipeline(pipelines.get(self.render_pipeline_composite)?);
+        pass.set_bind_group(0, &self.bind_group_composite, &[]);
+        pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        renderer::{
+            ColormappedTexture, DrawData as _, RectangleDrawData, RectangleOptions, TexturedRect,
+        },
+        resource_managers::{GpuTexture2D, ImageDataDesc},
+        view_builder::TargetConfiguration,
+        Color32, LineDrawableBuilder, PointCloudBuilder, Rgba, Size,
+    };
+
+    use super::*;
+
+    fn rgba_texture(ctx: &RenderContext, key: u64, alpha: u8) -> GpuTexture2D {
+        ctx.texture_manager_2d
+            .get_or_create(
+                key,
+                ctx,
+                ImageDataDesc {
+                    label: "test texture".into(),
+                    data: [255, 128, 0, alpha].repeat(4).into(),
+                    format: wgpu::TextureFormat::Rgba8UnormSrgb.into(),
+                    width_height: [2, 2],
+                },
+            )
+            .unwrap()
+    }
+
+    fn rectangle(texture: GpuTexture2D, multiplicative_tint: Rgba) -> TexturedRect {
+        TexturedRect {
+            top_left_corner_position: glam::Vec3::ZERO,
+            extent_u: glam::Vec3::X,
+            extent_v: glam::Vec3::Y,
+            colormapped_texture: ColormappedTexture::from_unorm_rgba(texture),
+            options: RectangleOptions {
+                multiplicative_tint,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn transparent_draw_data() {
+        re_log::setup_logging();
+        re_log::PanicOnWarnScope::new();
+
+        RenderContext::new_test().execute_test_frame(|ctx| {
+            let mut view = ViewBuilder::new(ctx, TargetConfiguration::default());
+            let transparent_red = Color32::from_rgba_unmultiplied(255, 0, 0, 128);
+
+            for (color, is_transparent) in [(Color32::RED, false), (transparent_red, true)] {
+                let mut points = PointCloudBuilder::new(ctx);
+                points.batch("points").add_points(
+                    &[glam::Vec3::ZERO],
+                    &[Size::new_scene_units(0.1)],
+                    &[color],
+                    &[],
+                );
+                let points = points.into_draw_data().unwrap();
+                assert_eq!(is_transparent, points.has_order_independent_transparency());
+                view.queue_draw(points);
+
+                let mut lines = LineDrawableBuilder::new(ctx);
+                lines
+                    .batch("lines")
+                    .add_segment(glam::Vec3::ZERO, glam::Vec3::X)
+                    .color(color);
+                let lines = lines.into_draw_data().unwrap();
+                assert_eq!(is_transparent, lines.has_order_independent_transparency());
+                view.queue_draw(lines);
+            }
+
+            let tinted = RectangleDrawData::new(
+                ctx,
+                &[rectangle(
+                    rgba_texture(ctx, 0, 255),
+                    Rgba::from_white_alpha(0.5),
+                )],
+            )
+            .unwrap();
+            assert!(tinted.has_order_independent_transparency());
+            view.queue_draw(tinted);
+
+            // Alpha of the texture itself is blended without sorting in the opaque phase,
+            // only the tint decides whether a rectangle is order independent.
+            let texture_alpha = RectangleDrawData::new(
+                ctx,
+                &[rectangle(rgba_texture(ctx, 1, 128), Rgba::WHITE)],
+            )
+            .unwrap();
+            assert!(!texture_alpha.has_order_independent_transparency());
+            view.queue_draw(texture_alpha);
+
+            [view.draw(ctx, Rgba::BLACK).unwrap()]
+        });
+    }
+}
@@ -41,6 +41,27 @@ pub struct FrameUniformBuffer {
 
     /// `re_renderer` defined device tier.
     pub device_tier: wgpu_buffer_types::U32RowPadded,
+
+    /// Lens distortion of the pinhole camera whose pixel space is shown, see [`crate::ViewLensDistortion`].
+    pub lens_distortion_radial: wgpu_buffer_types::Vec4,
+    pub lens_distortion_tangential: wgpu_buffer_types::Vec2,
+    pub lens_distortion_model: u32,
+
+    /// One of `LENS_DISTORTION_MODE_*`, zero if the view doesn't apply any lens distortion.
+    pub lens_distortion_mode: u32,
+
+    /// Focal length and principal point in pixels of the pinhole camera the distortion belongs to.
+    pub lens_distortion_focal_length: wgpu_buffer_types::Vec2,
+    pub lens_distortion_principal_point: wgpu_buffer_types::Vec2,
+
+    /// Clipping planes in world space, see [`crate::view_builder::TargetConfiguration::clip_planes`].
+    ///
+    /// Only the first `num_clip_planes` entries are valid.
+    pub clip_planes: [wgpu_buffer_types::Vec4; crate::view_builder::MAX_CLIP_PLANES],
+    pub num_clip_planes: wgpu_buffer_types::U32RowPadded,
+
+    #[allow(clippy::pub_underscore_fields)]
+    pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 3 - 9],
 }
 
 pub(crate) struct GlobalBindings {
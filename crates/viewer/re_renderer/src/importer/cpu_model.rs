@@ -86,8 +86,10 @@ impl CpuModel {
                     gpu_mesh: gpu_meshes.get(instance.mesh)?.clone(),
                     world_from_mesh: instance.world_from_mesh,
                     additive_tint: Default::default(),
+                    opacity: 1.0,
                     outline_mask_ids: Default::default(),
                     picking_layer_id: Default::default(),
+                    lens_distortion: false,
                 })
             })
             .collect())
@@ -0,0 +1,198 @@
+//! Lens distortion of pinhole cameras.
+//!
+//! Mirrors the implementation in `shader/utils/lens_distortion.wgsl`.
+//! All models operate on normalized image coordinates, i.e. `(X / Z, Y / Z)` for a point in camera space.
+
+/// Number of iterations used for numerically inverting a distortion model.
+///
+/// Keep in sync with `lens_distortion.wgsl`.
+const UNDISTORT_ITERATIONS: usize = 20;
+
+/// How the distortion coefficients of a [`LensDistortion`] are interpreted.
+///
+/// Keep in sync with `LENS_DISTORTION_MODEL_*` in `lens_distortion.wgsl`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LensDistortionModel {
+    /// Brown–Conrady model with radial and tangential distortion, as used by `OpenCV`'s default camera model.
+    #[default]
+    BrownConrady = 1,
+
+    /// Kannala–Brandt equidistant fisheye model, as used by `OpenCV`'s fisheye camera model.
+    KannalaBrandt = 2,
+}
+
+/// Lens distortion of a pinhole camera.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LensDistortion {
+    pub model: LensDistortionModel,
+
+    /// Radial distortion coefficients `[k1, k2, k3, k4]`.
+    ///
+    /// For [`LensDistortionModel::BrownConrady`], `k4` is the coefficient of an additional `r⁸` term
+    /// that `OpenCV`'s model doesn't have, it is zero for all `OpenCV` calibrations.
+    pub radial: glam::Vec4,
+
+    /// Tangential distortion coefficients `[p1, p2]`.
+    ///
+    /// Ignored by [`LensDistortionModel::KannalaBrandt`].
+    pub tangential: glam::Vec2,
+}
+
+impl LensDistortion {
+    /// Returns true if this distortion doesn't change any coordinates.
+    ///
+    /// Note that a fisheye model is never the identity, even if all its coefficients are zero.
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.model == LensDistortionModel::BrownConrady
+            && self.radial == glam::Vec4::ZERO
+            && self.tangential == glam::Vec2::ZERO
+    }
+
+    /// Maps ideal normalized image coordinates to distorted ones.
+    pub fn distort(&self, p: glam::Vec2) -> glam::Vec2 {
+        match self.model {
+            LensDistortionModel::BrownConrady => {
+                let (radial, tangential) = self.brown_conrady_terms(p);
+                p * radial + tangential
+            }
+            LensDistortionModel::KannalaBrandt => {
+                let r = p.length();
+                if r < f32::EPSILON {
+                    return p;
+                }
+                p * (self.kannala_brandt_theta_distorted(r.atan()) / r)
+            }
+        }
+    }
+
+    /// Maps distorted normalized image coordinates to ideal ones.
+    ///
+    /// This is the numerical inverse of [`Self::distort`].
+    pub fn undistort(&self, p: glam::Vec2) -> glam::Vec2 {
+        match self.model {
+            LensDistortionModel::BrownConrady => {
+                // Fixed point iteration, same as OpenCV's `undistortPoints`.
+                let mut undistorted = p;
+                for _ in 0..UNDISTORT_ITERATIONS {
+                    let (radial, tangential) = self.brown_conrady_terms(undistorted);
+                    undistorted = (p - tangential) / radial;
+                }
+                undistorted
+            }
+            LensDistortionModel::KannalaBrandt => {
+                let theta_distorted = p.length();
+                if theta_distorted < f32::EPSILON {
+                    return p;
+                }
+
+                // Newton's method on `theta_distorted(theta) - theta_distorted = 0`.
+                let [k1, k2, k3, k4] = self.radial.to_array();
+                let mut theta = theta_distorted;
+                for _ in 0..UNDISTORT_ITERATIONS {
+                    let t2 = theta * theta;
+                    let derivative =
+                        1.0 + t2 * (3.0 * k1 + t2 * (5.0 * k2 + t2 * (7.0 * k3 + t2 * 9.0 * k4)));
+                    theta -=
+                        (self.kannala_brandt_theta_distorted(theta) - theta_distorted) / derivative;
+                }
+
+                // Beyond 90° there is no way to represent the ray in normalized coordinates.
+                let theta = theta.clamp(0.0, std::f32::consts::FRAC_PI_2 - 1e-4);
+                p * (theta.tan() / theta_distorted)
+            }
+        }
+    }
+
+    /// Radial scale factor & tangential offset of the Brown–Conrady model.
+    fn brown_conrady_terms(&self, p: glam::Vec2) -> (f32, glam::Vec2) {
+        let [k1, k2, k3, k4] = self.radial.to_array();
+        let [p1, p2] = self.tangential.to_array();
+
+        let r2 = p.length_squared();
+        let radial = 1.0 + r2 * (k1 + r2 * (k2 + r2 * (k3 + r2 * k4)));
+        let xy = p.x * p.y;
+        let tangential = glam::vec2(
+            2.0 * p1 * xy + p2 * (r2 + 2.0 * p.x * p.x),
+            p1 * (r2 + 2.0 * p.y * p.y) + 2.0 * p2 * xy,
+        );
+
+        (radial, tangential)
+    }
+
+    /// Distorted angle of incidence of the Kannala–Brandt model.
+    fn kannala_brandt_theta_distorted(&self, theta: f32) -> f32 {
+        let [k1, k2, k3, k4] = self.radial.to_array();
+        let t2 = theta * theta;
+        theta * (1.0 + t2 * (k1 + t2 * (k2 + t2 * (k3 + t2 * k4))))
+    }
+}
+
+/// What a view does with the [`LensDistortion`] of the pinhole camera whose image it shows.
+///
+/// Only meaningful for views whose world space is the pixel space of that camera,
+/// i.e. the image plane is at `z = 0` and pixel coordinates map directly to `x` & `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LensDistortionMode {
+    /// Distorts geometry of batches that opted into it, so that it lines up with the (distorted) images.
+    DistortGeometry = 1,
+
+    /// Undistorts rectangles that opted into it, showing them as if taken with an ideal pinhole camera.
+    UndistortImages = 2,
+}
+
+/// Lens distortion configuration of a [`crate::ViewBuilder`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewLensDistortion {
+    /// The pinhole projection matrix of the camera, from camera space to pixel space.
+    pub image_from_camera: glam::Mat3,
+
+    pub distortion: LensDistortion,
+
+    pub mode: LensDistortionMode,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undistort_inverts_distort() {
+        let distortions = [
+            LensDistortion {
+                model: LensDistortionModel::BrownConrady,
+                radial: glam::vec4(-0.28, 0.07, 0.001, 0.0),
+                tangential: glam::vec2(0.0002, -0.0003),
+            },
+            LensDistortion {
+                model: LensDistortionModel::KannalaBrandt,
+                radial: glam::vec4(0.1, -0.05, 0.01, -0.001),
+                tangential: glam::Vec2::ZERO,
+            },
+        ];
+
+        for distortion in distortions {
+            for p in [
+                glam::Vec2::ZERO,
+                glam::vec2(0.1, -0.2),
+                glam::vec2(-0.4, 0.3),
+                glam::vec2(0.5, 0.5),
+            ] {
+                let roundtripped = distortion.undistort(distortion.distort(p));
+                assert!(
+                    roundtripped.abs_diff_eq(p, 1e-4),
+                    "{distortion:?}: {p} -> {roundtripped}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn identity() {
+        let p = glam::vec2(0.3, -0.1);
+        let distortion = LensDistortion::default();
+        assert!(distortion.is_identity());
+        assert_eq!(distortion.distort(p), p);
+        assert_eq!(distortion.undistort(p), p);
+    }
+}
@@ -31,6 +31,7 @@ mod file_resolver;
 mod file_server;
 mod file_system;
 mod global_bindings;
+mod lens_distortion;
 mod line_drawable_builder;
 mod point_cloud_builder;
 mod queueable_draw_data;
@@ -62,6 +63,9 @@ pub use context::{adapter_info_summary, RenderContext, RenderContextError};
 pub use debug_label::DebugLabel;
 pub use depth_offset::DepthOffset;
 pub use importer::{CpuMeshInstance, CpuModel, CpuModelMeshKey};
+pub use lens_distortion::{
+    LensDistortion, LensDistortionMode, LensDistortionModel, ViewLensDistortion,
+};
 pub use line_drawable_builder::{LineDrawableBuilder, LineStripBuilder};
 pub use point_cloud_builder::{PointCloudBatchBuilder, PointCloudBuilder};
 pub use queueable_draw_data::QueueableDrawData;
@@ -74,8 +78,8 @@ pub use wgpu_resources::WgpuResourcePoolStatistics;
 
 use draw_phases::DrawPhase;
 pub use draw_phases::{
-    OutlineConfig, OutlineMaskPreference, PickingLayerId, PickingLayerInstanceId,
-    PickingLayerObjectId, PickingLayerProcessor, ScreenshotProcessor,
+    OrderIndependentTransparencyProcessor, OutlineConfig, OutlineMaskPreference, PickingLayerId,
+    PickingLayerInstanceId, PickingLayerObjectId, PickingLayerProcessor, ScreenshotProcessor,
 };
 
 pub use self::file_system::{get_filesystem, FileSystem};
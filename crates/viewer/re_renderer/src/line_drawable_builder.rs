@@ -256,6 +256,15 @@ impl<'ctx> LineBatchBuilder<'_, 'ctx> {
         self
     }
 
+    /// Sets whether the view's lens distortion is applied to all vertices of this batch.
+    ///
+    /// See [`crate::LensDistortionMode::DistortGeometry`].
+    #[inline]
+    pub fn lens_distortion(mut self, lens_distortion: bool) -> Self {
+        self.batch_mut().lens_distortion = lens_distortion;
+        self
+    }
+
     /// Adds a 3D series of line connected points.
     pub fn add_strip(
         &mut self,
@@ -538,6 +547,10 @@ impl Drop for LineStripBuilder<'_, '_> {
             return;
         }
 
+        if self.strip.color.a() < 255 {
+            self.builder.batches.last_mut().unwrap().has_transparency = true;
+        }
+
         if self.outline_mask_ids.is_some() {
             self.builder
                 .batches
@@ -186,6 +186,11 @@ pub struct GpuMesh {
 
     /// Every mesh has at least one material.
     pub materials: SmallVec<[GpuMaterial; 1]>,
+
+    /// Whether any vertex color or material albedo factor has an alpha below 1.
+    ///
+    /// Note that the alpha channel of albedo textures is ignored.
+    pub has_transparency: bool,
 }
 
 #[derive(Clone)]
@@ -331,6 +336,12 @@ impl GpuMesh {
             materials
         };
 
+        let has_transparency = data.vertex_colors.iter().any(|color| color.0[3] < 255)
+            || data
+                .materials
+                .iter()
+                .any(|material| material.albedo_factor.a() < 1.0);
+
         let vb_colors_start = vb_positions_size;
         let vb_normals_start = vb_colors_start + vb_color_size;
         let vb_texcoord_start = vb_normals_start + vb_normals_size;
@@ -344,6 +355,7 @@ impl GpuMesh {
             vertex_buffer_texcoord_range: vb_texcoord_start..vb_combined_size,
             index_buffer_range: 0..index_buffer_size,
             materials,
+            has_transparency,
         })
     }
 }
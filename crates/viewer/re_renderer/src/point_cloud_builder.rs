@@ -222,6 +222,10 @@ impl PointCloudBatchBuilder<'_, '_> {
         {
             re_tracing::profile_scope!("colors");
 
+            if colors.iter().any(|color| color.a() < 255) {
+                self.batch_mut().has_transparency = true;
+            }
+
             self.0
                 .color_buffer
                 .extend_from_slice(colors)
@@ -282,6 +286,17 @@ impl PointCloudBatchBuilder<'_, '_> {
         self
     }
 
+    /// Sets whether the view's lens distortion is applied to all points of this batch.
+    ///
+    /// See [`PointCloudBatchFlags::FLAG_LENS_DISTORTION`].
+    #[inline]
+    pub fn lens_distortion(mut self, lens_distortion: bool) -> Self {
+        self.batch_mut()
+            .flags
+            .set(PointCloudBatchFlags::FLAG_LENS_DISTORTION, lens_distortion);
+        self
+    }
+
     /// Pushes additional outline mask ids for a specific range of points.
     /// The range is relative to this batch.
     ///
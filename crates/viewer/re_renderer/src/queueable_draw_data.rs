@@ -33,10 +33,12 @@ pub struct QueueableDrawData {
     pub(crate) draw_data: Box<dyn std::any::Any + std::marker::Send + std::marker::Sync>,
     pub(crate) renderer_name: &'static str,
     pub(crate) participated_phases: &'static [DrawPhase],
+    pub(crate) has_order_independent_transparency: bool,
 }
 
 impl<D: DrawData + Sync + Send + 'static> From<D> for QueueableDrawData {
     fn from(draw_data: D) -> Self {
+        let has_order_independent_transparency = draw_data.has_order_independent_transparency();
         Self {
             draw_func: Box::new(move |renderers, gpu_resources, phase, pass, draw_data| {
                 let renderer = renderers.get::<D::Renderer>().ok_or(
@@ -54,6 +56,7 @@ impl<D: DrawData + Sync + Send + 'static> From<D> for QueueableDrawData {
             draw_data: Box::new(draw_data),
             renderer_name: std::any::type_name::<D::Renderer>(),
             participated_phases: D::Renderer::participated_phases(),
+            has_order_independent_transparency,
         }
     }
 }
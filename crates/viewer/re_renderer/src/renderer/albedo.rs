@@ -0,0 +1,141 @@
+//! Albedo textures that color depth clouds & height fields.
+//!
+//! Both renderers take a [`ColormappedTexture`] for coloring, but unlike rectangles they look up
+//! single texels in their shaders, see `shader/utils/albedo.wgsl`.
+
+use crate::wgpu_buffer_types;
+
+use super::{ColorMapper, ColormappedTexture, ShaderDecoding};
+
+// Keep in sync with mirror in `albedo.wgsl.`
+
+// How to turn albedo texture values into colors?
+const ALBEDO_COLOR_MAPPER_NONE: u32 = 0;
+const ALBEDO_COLOR_MAPPER_OFF_GRAYSCALE: u32 = 1;
+const ALBEDO_COLOR_MAPPER_OFF_RGB: u32 = 2;
+const ALBEDO_COLOR_MAPPER_FUNCTION: u32 = 3;
+
+#[derive(thiserror::Error, Debug)]
+pub enum AlbedoTextureError {
+    #[error("Albedo texture format not supported: {0:?} - use float textures instead.")]
+    FormatNotSupported(wgpu::TextureFormat),
+
+    #[error("Albedo textures can't be color mapped with a texture.")]
+    ColorMapped,
+}
+
+/// How to turn the texels of an albedo texture into colors.
+///
+/// Takes up two rows and has to start on a new one when embedded in another uniform buffer struct.
+/// Keep in sync with `AlbedoInfo` in `albedo.wgsl`.
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct AlbedoInfoUBO {
+    /// One of `ALBEDO_COLOR_MAPPER_*`, [`ALBEDO_COLOR_MAPPER_NONE`] if there's no albedo texture.
+    pub color_mapper: u32,
+
+    /// Colormap used with [`ALBEDO_COLOR_MAPPER_FUNCTION`].
+    pub colormap: u32,
+
+    /// Range of the albedo texture values, mapped to the [0, 1] range.
+    pub range_min_max: wgpu_buffer_types::Vec2,
+
+    // ---
+    pub gamma: f32,
+    pub decode_srgb: u32,
+    pub bgra_to_rgba: u32,
+    pub _padding: u32,
+}
+
+impl AlbedoInfoUBO {
+    pub fn new(albedo: Option<&ColormappedTexture>) -> Result<Self, AlbedoTextureError> {
+        let Some(albedo) = albedo else {
+            return Ok(Self {
+                color_mapper: ALBEDO_COLOR_MAPPER_NONE,
+                colormap: 0,
+                range_min_max: [0.0, 1.0].into(),
+                gamma: 1.0,
+                decode_srgb: 0,
+                bgra_to_rgba: 0,
+                _padding: 0,
+            });
+        };
+
+        let (color_mapper, colormap) =
+            color_mapper(albedo.texture.format(), &albedo.color_mapper)?;
+
+        Ok(Self {
+            color_mapper,
+            colormap,
+            range_min_max: albedo.range.into(),
+            gamma: albedo.gamma,
+            decode_srgb: albedo.decode_srgb as _,
+            bgra_to_rgba: (albedo.shader_decoding == Some(ShaderDecoding::Bgr)) as _,
+            _padding: 0,
+        })
+    }
+}
+
+/// Returns one of `ALBEDO_COLOR_MAPPER_*` and the colormap to use with it.
+fn color_mapper(
+    format: wgpu::TextureFormat,
+    color_mapper: &ColorMapper,
+) -> Result<(u32, u32), AlbedoTextureError> {
+    if !matches!(
+        format.sample_type(None, None),
+        Some(wgpu::TextureSampleType::Float { .. })
+    ) {
+        return Err(AlbedoTextureError::FormatNotSupported(format));
+    }
+
+    match color_mapper {
+        ColorMapper::OffGrayscale => Ok((ALBEDO_COLOR_MAPPER_OFF_GRAYSCALE, 0)),
+        ColorMapper::OffRGB => Ok((ALBEDO_COLOR_MAPPER_OFF_RGB, 0)),
+        ColorMapper::Function(colormap) => Ok((ALBEDO_COLOR_MAPPER_FUNCTION, *colormap as u32)),
+        ColorMapper::Texture(_) => Err(AlbedoTextureError::ColorMapped),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Colormap;
+
+    use super::*;
+
+    #[test]
+    fn color_mappers() {
+        let rgba = wgpu::TextureFormat::Rgba8UnormSrgb;
+        assert_eq!(
+            color_mapper(rgba, &ColorMapper::OffRGB).unwrap(),
+            (ALBEDO_COLOR_MAPPER_OFF_RGB, 0)
+        );
+        assert_eq!(
+            color_mapper(wgpu::TextureFormat::R32Float, &ColorMapper::OffGrayscale).unwrap(),
+            (ALBEDO_COLOR_MAPPER_OFF_GRAYSCALE, 0)
+        );
+        assert_eq!(
+            color_mapper(
+                wgpu::TextureFormat::R16Float,
+                &ColorMapper::Function(Colormap::Viridis)
+            )
+            .unwrap(),
+            (ALBEDO_COLOR_MAPPER_FUNCTION, Colormap::Viridis as u32)
+        );
+    }
+
+    #[test]
+    fn integer_textures_are_not_supported() {
+        for format in [wgpu::TextureFormat::R32Uint, wgpu::TextureFormat::R16Sint] {
+            assert!(matches!(
+                color_mapper(format, &ColorMapper::OffGrayscale),
+                Err(AlbedoTextureError::FormatNotSupported(f)) if f == format
+            ));
+        }
+    }
+
+    #[test]
+    fn layout() {
+        // Two rows, see `AlbedoInfo` in `albedo.wgsl`.
+        assert_eq!(std::mem::size_of::<AlbedoInfoUBO>(), 32);
+    }
+}
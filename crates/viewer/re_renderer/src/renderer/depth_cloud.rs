@@ -25,15 +25,15 @@ use crate::{
         GpuRenderPipelineHandle, GpuRenderPipelinePoolAccessor, PipelineLayoutDesc,
         RenderPipelineDesc,
     },
-    Colormap, OutlineMaskPreference, PickingLayerObjectId, PickingLayerProcessor,
+    Colormap, LensDistortion, OutlineMaskPreference, PickingLayerObjectId, PickingLayerProcessor,
 };
 
-use super::{DrawData, DrawError, RenderContext, Renderer};
+use super::{AlbedoTextureError, ColormappedTexture, DrawData, DrawError, RenderContext, Renderer};
 
 // ---
 
 mod gpu_data {
-    use crate::{wgpu_buffer_types, PickingLayerObjectId};
+    use crate::{renderer::albedo::AlbedoInfoUBO, wgpu_buffer_types, PickingLayerObjectId};
 
     use super::DepthCloudDrawDataError;
 
@@ -78,7 +78,32 @@ mod gpu_data {
         pub _row_padding: [f32; 1],
 
         // ---
-        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1],
+        /// Lens distortion of the depth camera, see `lens_distortion.wgsl`.
+        pub lens_distortion_radial: wgpu_buffer_types::Vec4,
+        pub lens_distortion_tangential: wgpu_buffer_types::Vec2,
+
+        /// One of `LENS_DISTORTION_MODEL_*`, zero for an ideal pinhole camera.
+        pub lens_distortion_model: u32,
+        pub _lens_distortion_padding: u32,
+
+        // ---
+        /// Extrinsics between the depth camera and the camera of the albedo texture.
+        pub albedo_rdf_from_depth_rdf: wgpu_buffer_types::Mat4,
+
+        pub albedo_camera_intrinsics: wgpu_buffer_types::Mat3,
+
+        /// Lens distortion of the albedo camera, see `lens_distortion.wgsl`.
+        pub albedo_lens_distortion_radial: wgpu_buffer_types::Vec4,
+        pub albedo_lens_distortion_tangential: wgpu_buffer_types::Vec2,
+        pub albedo_lens_distortion_model: u32,
+        pub _albedo_lens_distortion_padding: u32,
+
+        // ---
+        pub albedo: AlbedoInfoUBO,
+
+        // ---
+        pub _end_padding:
+            [wgpu_buffer_types::PaddingRow; 32 - 4 - 3 - 1 - 1 - 1 - 2 - 4 - 3 - 1 - 1 - 2],
     }
 
     impl DepthCloudInfoUBO {
@@ -89,12 +114,14 @@ mod gpu_data {
             let super::DepthCloud {
                 world_from_rdf: world_from_obj,
                 depth_camera_intrinsics,
+                lens_distortion,
                 world_depth_from_texture_depth,
                 point_radius_from_world_depth,
                 min_max_depth_in_world,
                 depth_dimensions: _,
                 depth_texture,
                 colormap,
+                albedo,
                 outline_mask_id,
                 picking_object_id,
             } = depth_cloud;
@@ -111,6 +138,9 @@ mod gpu_data {
                 }
             };
 
+            let albedo_info = AlbedoInfoUBO::new(albedo.as_ref().map(|a| &a.texture))?;
+            let albedo_lens_distortion = albedo.as_ref().and_then(|a| a.lens_distortion);
+
             Ok(Self {
                 world_from_rdf: (*world_from_obj).into(),
                 depth_camera_intrinsics: (*depth_camera_intrinsics).into(),
@@ -123,6 +153,31 @@ mod gpu_data {
                 radius_boost_in_ui_points,
                 picking_layer_object_id: *picking_object_id,
                 _row_padding: Default::default(),
+                lens_distortion_radial: lens_distortion
+                    .map_or(glam::Vec4::ZERO, |d| d.radial)
+                    .into(),
+                lens_distortion_tangential: lens_distortion
+                    .map_or(glam::Vec2::ZERO, |d| d.tangential)
+                    .into(),
+                lens_distortion_model: lens_distortion.map_or(0, |d| d.model as u32),
+                _lens_distortion_padding: 0,
+                albedo_rdf_from_depth_rdf: albedo
+                    .as_ref()
+                    .map_or(glam::Affine3A::IDENTITY, |a| a.albedo_rdf_from_depth_rdf)
+                    .into(),
+                albedo_camera_intrinsics: albedo
+                    .as_ref()
+                    .map_or(glam::Mat3::IDENTITY, |a| a.camera_intrinsics)
+                    .into(),
+                albedo_lens_distortion_radial: albedo_lens_distortion
+                    .map_or(glam::Vec4::ZERO, |d| d.radial)
+                    .into(),
+                albedo_lens_distortion_tangential: albedo_lens_distortion
+                    .map_or(glam::Vec2::ZERO, |d| d.tangential)
+                    .into(),
+                albedo_lens_distortion_model: albedo_lens_distortion.map_or(0, |d| d.model as u32),
+                _albedo_lens_distortion_padding: 0,
+                albedo: albedo_info,
                 _end_padding: Default::default(),
             })
         }
@@ -139,6 +194,9 @@ pub struct DepthCloud {
     /// Only supports pinhole cameras at the moment.
     pub depth_camera_intrinsics: glam::Mat3,
 
+    /// Lens distortion of the depth camera, undone when back-projecting the depth texture.
+    pub lens_distortion: Option<LensDistortion>,
+
     /// Multiplier to get world-space depth from whatever is in [`Self::depth_texture`].
     pub world_depth_from_texture_depth: f32,
 
@@ -159,6 +217,11 @@ pub struct DepthCloud {
     /// Configures color mapping mode.
     pub colormap: Colormap,
 
+    /// Colors the points with a registered color image instead of [`Self::colormap`].
+    ///
+    /// Points that aren't seen by the color camera still use the colormap.
+    pub albedo: Option<DepthCloudAlbedo>,
+
     /// Option outline mask id preference.
     pub outline_mask_id: OutlineMaskPreference,
 
@@ -166,6 +229,26 @@ pub struct DepthCloud {
     pub picking_object_id: PickingLayerObjectId,
 }
 
+/// A color image taken by a camera that is registered with the camera of a [`DepthCloud`].
+///
+/// Every point of the depth cloud is projected into this image to look up its color.
+pub struct DepthCloudAlbedo {
+    /// The color image.
+    ///
+    /// Only textures with sample type `Float` are supported, and no [`super::ColorMapper::Texture`].
+    pub texture: ColormappedTexture,
+
+    /// The intrinsics of the color camera.
+    pub camera_intrinsics: glam::Mat3,
+
+    /// Lens distortion of the color camera.
+    pub lens_distortion: Option<LensDistortion>,
+
+    /// The extrinsics between the two cameras,
+    /// with RDF coordinate systems on both sides.
+    pub albedo_rdf_from_depth_rdf: glam::Affine3A,
+}
+
 impl DepthCloud {
     /// World-space bounding-box.
     ///
@@ -191,7 +274,11 @@ impl DepthCloud {
 
         for corner in corners {
             let depth = corner.z;
-            let pos_in_obj = ((corner.truncate() - offset) * depth / focal_length).extend(depth);
+            let mut normalized = (corner.truncate() - offset) / focal_length;
+            if let Some(lens_distortion) = &self.lens_distortion {
+                normalized = lens_distortion.undistort(normalized);
+            }
+            let pos_in_obj = (normalized * depth).extend(depth);
             let pos_in_world = self.world_from_rdf.transform_point3(pos_in_obj);
             bbox.extend(pos_in_world);
         }
@@ -226,6 +313,9 @@ impl DrawData for DepthCloudDrawData {
 pub enum DepthCloudDrawDataError {
     #[error("Texture format not supported: {0:?} - use float or integer textures instead.")]
     TextureFormatNotSupported(wgpu::TextureFormat),
+
+    #[error(transparent)]
+    Albedo(#[from] AlbedoTextureError),
 }
 
 impl DepthCloudDrawData {
@@ -295,6 +385,11 @@ impl DepthCloudDrawData {
                 }
             }
 
+            let albedo_texture = depth_cloud.albedo.as_ref().map_or_else(
+                || ctx.texture_manager_2d.zeroed_texture_float().handle,
+                |albedo| albedo.texture.texture.handle,
+            );
+
             let mk_bind_group = |label, ubo: BindGroupEntry| {
                 ctx.gpu_resources.bind_groups.alloc(
                     &ctx.device,
@@ -306,6 +401,7 @@ impl DepthCloudDrawData {
                             BindGroupEntry::DefaultTextureView(texture_float),
                             BindGroupEntry::DefaultTextureView(texture_sint),
                             BindGroupEntry::DefaultTextureView(texture_uint),
+                            BindGroupEntry::DefaultTextureView(albedo_texture),
                         ],
                         layout: bg_layout,
                     },
@@ -401,6 +497,17 @@ impl Renderer for DepthCloudRenderer {
                         },
                         count: None,
                     },
+                    // albedo texture:
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
                 ],
             },
         );
@@ -512,3 +619,149 @@ impl Renderer for DepthCloudRenderer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        renderer::ColorMapper, resource_managers::ImageDataDesc, view_builder::TargetConfiguration,
+        LensDistortionModel, Rgba,
+    };
+
+    use super::*;
+
+    fn texture_2x2(
+        ctx: &RenderContext,
+        key: u64,
+        format: wgpu::TextureFormat,
+        texel: &[u8],
+    ) -> GpuTexture2D {
+        ctx.texture_manager_2d
+            .get_or_create(
+                key,
+                ctx,
+                ImageDataDesc {
+                    label: format!("{format:?} test texture").into(),
+                    data: texel.repeat(4).into(),
+                    format: format.into(),
+                    width_height: [2, 2],
+                },
+            )
+            .unwrap()
+    }
+
+    fn depth_cloud(ctx: &RenderContext, albedo: Option<DepthCloudAlbedo>) -> DepthClouds {
+        let intrinsics = glam::Mat3::from_cols(
+            glam::vec3(2.0, 0.0, 0.0),
+            glam::vec3(0.0, 2.0, 0.0),
+            glam::vec3(1.0, 1.0, 1.0),
+        );
+
+        DepthClouds {
+            clouds: vec![DepthCloud {
+                world_from_rdf: glam::Affine3A::IDENTITY,
+                depth_camera_intrinsics: intrinsics,
+                lens_distortion: None,
+                world_depth_from_texture_depth: 1.0,
+                point_radius_from_world_depth: 0.01,
+                min_max_depth_in_world: [0.0, 1.0],
+                depth_dimensions: glam::UVec2::splat(2),
+                depth_texture: texture_2x2(
+                    ctx,
+                    0,
+                    wgpu::TextureFormat::R32Float,
+                    &1.0_f32.to_le_bytes(),
+                ),
+                colormap: Colormap::Turbo,
+                albedo,
+                outline_mask_id: Default::default(),
+                picking_object_id: Default::default(),
+            }],
+            radius_boost_in_ui_points_for_outlines: 0.0,
+        }
+    }
+
+    fn albedo(texture: ColormappedTexture) -> DepthCloudAlbedo {
+        DepthCloudAlbedo {
+            texture,
+            camera_intrinsics: glam::Mat3::from_cols(
+                glam::vec3(2.0, 0.0, 0.0),
+                glam::vec3(0.0, 2.0, 0.0),
+                glam::vec3(1.0, 1.0, 1.0),
+            ),
+            lens_distortion: Some(LensDistortion {
+                model: LensDistortionModel::KannalaBrandt,
+                radial: glam::vec4(0.1, 0.01, 0.0, 0.0),
+                tangential: glam::Vec2::ZERO,
+            }),
+            albedo_rdf_from_depth_rdf: glam::Affine3A::from_translation(glam::vec3(0.1, 0.0, 0.0)),
+        }
+    }
+
+    #[test]
+    fn depth_cloud_with_albedo() {
+        re_log::setup_logging();
+        re_log::PanicOnWarnScope::new();
+
+        RenderContext::new_test().execute_test_frame(|ctx| {
+            let mut view = ViewBuilder::new(ctx, TargetConfiguration::default());
+
+            let rgba = texture_2x2(
+                ctx,
+                1,
+                wgpu::TextureFormat::Rgba8UnormSrgb,
+                &[255, 128, 0, 255],
+            );
+            let clouds = depth_cloud(ctx, Some(albedo(ColormappedTexture::from_unorm_rgba(rgba))));
+            view.queue_draw(DepthCloudDrawData::new(ctx, &clouds).unwrap());
+
+            let gray = texture_2x2(
+                ctx,
+                2,
+                wgpu::TextureFormat::R32Float,
+                &0.5_f32.to_le_bytes(),
+            );
+            let mut gray = ColormappedTexture::from_unorm_rgba(gray);
+            gray.color_mapper = ColorMapper::Function(Colormap::Viridis);
+            let clouds = depth_cloud(ctx, Some(albedo(gray)));
+            view.queue_draw(DepthCloudDrawData::new(ctx, &clouds).unwrap());
+
+            [view.draw(ctx, Rgba::BLACK).unwrap()]
+        });
+    }
+
+    #[test]
+    fn unsupported_albedo() {
+        re_log::setup_logging();
+
+        RenderContext::new_test().execute_test_frame(|ctx| {
+            let uint = texture_2x2(ctx, 1, wgpu::TextureFormat::R32Uint, &1_u32.to_le_bytes());
+            let mut uint = ColormappedTexture::from_unorm_rgba(uint);
+            uint.color_mapper = ColorMapper::OffGrayscale;
+            let clouds = depth_cloud(ctx, Some(albedo(uint)));
+            assert!(matches!(
+                DepthCloudDrawData::new(ctx, &clouds),
+                Err(DepthCloudDrawDataError::Albedo(
+                    AlbedoTextureError::FormatNotSupported(wgpu::TextureFormat::R32Uint)
+                ))
+            ));
+
+            let rgba = texture_2x2(
+                ctx,
+                2,
+                wgpu::TextureFormat::Rgba8UnormSrgb,
+                &[255, 128, 0, 255],
+            );
+            let mut color_mapped = ColormappedTexture::from_unorm_rgba(rgba.clone());
+            color_mapped.color_mapper = ColorMapper::Texture(rgba);
+            let clouds = depth_cloud(ctx, Some(albedo(color_mapped)));
+            assert!(matches!(
+                DepthCloudDrawData::new(ctx, &clouds),
+                Err(DepthCloudDrawDataError::Albedo(
+                    AlbedoTextureError::ColorMapped
+                ))
+            ));
+
+            std::iter::empty()
+        });
+    }
+}
@@ -0,0 +1,678 @@
+//! Renderer that draws shaded surfaces straight out of height textures.
+//!
+//! ## Implementation details
+//!
+//! The surface is never tessellated on the CPU: the vertex shader spans two triangles between every
+//! four neighboring heights, reading them directly from the height texture.
+//! Updating a height field therefore only requires uploading a new texture,
+//! which makes it cheap to show a height map that changes every frame.
+//!
+//! Like for depth clouds, there's no widespread support for bindless textures,
+//! so this requires one bind group and one draw call per height field.
+
+use itertools::Itertools as _;
+use smallvec::smallvec;
+
+use crate::{
+    allocator::create_and_fill_uniform_buffer_batch,
+    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    include_shader_module,
+    resource_managers::GpuTexture2D,
+    view_builder::ViewBuilder,
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
+        GpuRenderPipelineHandle, GpuRenderPipelinePoolAccessor, PipelineLayoutDesc,
+        RenderPipelineDesc,
+    },
+    Colormap, OutlineMaskPreference, PickingLayerObjectId, PickingLayerProcessor, Rgba,
+};
+
+use super::{AlbedoTextureError, ColormappedTexture, DrawData, DrawError, RenderContext, Renderer};
+
+// ---
+
+mod gpu_data {
+    use crate::{renderer::albedo::AlbedoInfoUBO, wgpu_buffer_types, PickingLayerObjectId, Rgba};
+
+    use super::{HeightFieldColoring, HeightFieldDrawDataError};
+
+    // Keep in sync with mirror in `height_field.wgsl.`
+
+    // Which texture to read from?
+    const SAMPLE_TYPE_FLOAT: u32 = 1;
+    const SAMPLE_TYPE_SINT: u32 = 2;
+    const SAMPLE_TYPE_UINT: u32 = 3;
+
+    // How to color the surface?
+    const COLOR_MODE_SOLID: u32 = 0;
+    const COLOR_MODE_COLORMAP: u32 = 1;
+    const COLOR_MODE_TEXTURE: u32 = 2;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct HeightFieldInfoUBO {
+        pub world_from_obj: wgpu_buffer_types::Mat4,
+
+        /// Inverse transpose of the upper 3x3 of `world_from_obj`, for transforming normals.
+        pub world_from_obj_normal: wgpu_buffer_types::Mat3,
+
+        /// Position of height `[0, 0]` (xy) and the distance between neighboring heights (zw).
+        pub origin_and_cell_size: wgpu_buffer_types::Vec4,
+
+        /// Color of the surface in linear space, used with [`COLOR_MODE_SOLID`].
+        pub color: wgpu_buffer_types::Vec4,
+
+        pub outline_mask_id: wgpu_buffer_types::UVec2,
+        pub picking_layer_object_id: PickingLayerObjectId,
+
+        // ---
+        /// The heights mapped to the start & end of the colormap.
+        pub min_max_height: [f32; 2],
+
+        /// Which colormap should be used with [`COLOR_MODE_COLORMAP`].
+        pub colormap: u32,
+
+        /// One of `SAMPLE_TYPE_*`.
+        pub sample_type: u32,
+
+        // ---
+        /// One of `COLOR_MODE_*`.
+        pub color_mode: u32,
+        pub _color_mode_padding: [u32; 3],
+
+        // ---
+        /// Used with [`COLOR_MODE_TEXTURE`].
+        pub albedo: AlbedoInfoUBO,
+
+        // ---
+        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 3 - 1 - 1 - 1 - 1 - 1 - 2],
+    }
+
+    impl HeightFieldInfoUBO {
+        pub fn from_height_field(
+            height_field: &super::HeightField,
+        ) -> Result<Self, HeightFieldDrawDataError> {
+            let super::HeightField {
+                world_from_obj,
+                origin,
+                cell_size,
+                height_texture,
+                coloring,
+                outline_mask_id,
+                picking_object_id,
+            } = height_field;
+
+            let texture_format = height_texture.format();
+            let sample_type = match texture_format.sample_type(None, None) {
+                Some(wgpu::TextureSampleType::Float { .. }) => SAMPLE_TYPE_FLOAT,
+                Some(wgpu::TextureSampleType::Sint) => SAMPLE_TYPE_SINT,
+                Some(wgpu::TextureSampleType::Uint) => SAMPLE_TYPE_UINT,
+                _ => {
+                    return Err(HeightFieldDrawDataError::TextureFormatNotSupported(
+                        texture_format,
+                    ));
+                }
+            };
+
+            let mut color = Rgba::WHITE;
+            let mut colormap = 0;
+            let mut min_max_height = [0.0, 1.0];
+            let mut albedo = None;
+            let color_mode = match coloring {
+                HeightFieldColoring::Solid(solid) => {
+                    color = *solid;
+                    COLOR_MODE_SOLID
+                }
+                HeightFieldColoring::Colormap {
+                    colormap: height_colormap,
+                    min_max_height: range,
+                } => {
+                    colormap = *height_colormap as u32;
+                    min_max_height = *range;
+                    COLOR_MODE_COLORMAP
+                }
+                HeightFieldColoring::Texture(texture) => {
+                    albedo = Some(texture);
+                    COLOR_MODE_TEXTURE
+                }
+            };
+
+            let albedo = AlbedoInfoUBO::new(albedo)?;
+
+            Ok(Self {
+                world_from_obj: (*world_from_obj).into(),
+                world_from_obj_normal: glam::Mat3::from(world_from_obj.matrix3)
+                    .inverse()
+                    .transpose()
+                    .into(),
+                origin_and_cell_size: glam::Vec4::new(origin.x, origin.y, cell_size.x, cell_size.y)
+                    .into(),
+                color: color.into(),
+                outline_mask_id: outline_mask_id.0.unwrap_or_default().into(),
+                picking_layer_object_id: *picking_object_id,
+                min_max_height,
+                colormap,
+                sample_type,
+                color_mode,
+                _color_mode_padding: Default::default(),
+                albedo,
+                _end_padding: Default::default(),
+            })
+        }
+    }
+}
+
+/// A surface spanned over a regular grid of heights.
+///
+/// In the height field's local space, the grid lies in the XY plane and heights go along +Z.
+pub struct HeightField {
+    /// Transforms from the height field's local space to world space.
+    pub world_from_obj: glam::Affine3A,
+
+    /// Position of height `[0, 0]` in the local XY plane.
+    pub origin: glam::Vec2,
+
+    /// Distance between neighboring heights along the local X and Y axes.
+    pub cell_size: glam::Vec2,
+
+    /// One height per texel, row by row along +Y, read from the first channel.
+    ///
+    /// Only textures with sample type `Float`, `Sint` or `Uint` are supported.
+    /// Non-finite heights leave holes in the surface.
+    pub height_texture: GpuTexture2D,
+
+    /// How the surface is colored.
+    pub coloring: HeightFieldColoring,
+
+    /// Option outline mask id preference.
+    pub outline_mask_id: OutlineMaskPreference,
+
+    /// Picking object id that applies for the entire height field.
+    pub picking_object_id: PickingLayerObjectId,
+}
+
+/// How a [`HeightField`] is colored.
+pub enum HeightFieldColoring {
+    /// The same color everywhere.
+    Solid(Rgba),
+
+    /// Colored by height.
+    Colormap {
+        colormap: Colormap,
+
+        /// The heights mapped to the start & end of the colormap.
+        min_max_height: [f32; 2],
+    },
+
+    /// An image stretched over the entire surface.
+    ///
+    /// Only textures with sample type `Float` are supported, and no [`super::ColorMapper::Texture`].
+    Texture(ColormappedTexture),
+}
+
+impl HeightField {
+    /// The number of heights along the local X and Y axes.
+    pub fn dimensions(&self) -> glam::UVec2 {
+        self.height_texture.width_height().into()
+    }
+
+    /// The number of triangles needed to draw the surface.
+    fn num_triangles(&self) -> u32 {
+        let dimensions = self.dimensions();
+        dimensions.x.saturating_sub(1) * dimensions.y.saturating_sub(1) * 2
+    }
+}
+
+#[derive(Clone)]
+struct HeightFieldDrawInstance {
+    bind_group: GpuBindGroup,
+    num_triangles: u32,
+    render_outline_mask: bool,
+}
+
+#[derive(Clone)]
+pub struct HeightFieldDrawData {
+    instances: Vec<HeightFieldDrawInstance>,
+}
+
+impl DrawData for HeightFieldDrawData {
+    type Renderer = HeightFieldRenderer;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HeightFieldDrawDataError {
+    #[error("Texture format not supported: {0:?} - use float or integer textures instead.")]
+    TextureFormatNotSupported(wgpu::TextureFormat),
+
+    #[error(transparent)]
+    Albedo(#[from] AlbedoTextureError),
+}
+
+impl HeightFieldDrawData {
+    pub fn new(
+        ctx: &RenderContext,
+        height_fields: &[HeightField],
+    ) -> Result<Self, HeightFieldDrawDataError> {
+        re_tracing::profile_function!();
+
+        let renderer = ctx.renderer::<HeightFieldRenderer>();
+        let bg_layout = renderer.bind_group_layout;
+
+        // Fields without a single cell would produce no triangles anyways.
+        let height_fields = height_fields
+            .iter()
+            .filter(|height_field| height_field.num_triangles() > 0)
+            .collect_vec();
+        if height_fields.is_empty() {
+            return Ok(Self {
+                instances: Vec::new(),
+            });
+        }
+
+        let ubos: Vec<gpu_data::HeightFieldInfoUBO> = height_fields
+            .iter()
+            .map(|height_field| gpu_data::HeightFieldInfoUBO::from_height_field(height_field))
+            .try_collect()?;
+        let ubo_bindings =
+            create_and_fill_uniform_buffer_batch(ctx, "height_field_ubos".into(), ubos.into_iter());
+
+        let mut instances = Vec::with_capacity(height_fields.len());
+        for (height_field, ubo) in height_fields.into_iter().zip(ubo_bindings) {
+            // We set up several texture sources, then instruct the shader to read from at most one of them.
+            let mut texture_float = ctx.texture_manager_2d.zeroed_texture_float().handle;
+            let mut texture_sint = ctx.texture_manager_2d.zeroed_texture_sint().handle;
+            let mut texture_uint = ctx.texture_manager_2d.zeroed_texture_uint().handle;
+
+            let texture_format = height_field.height_texture.format();
+            match texture_format.sample_type(None, None) {
+                Some(wgpu::TextureSampleType::Float { .. }) => {
+                    texture_float = height_field.height_texture.handle;
+                }
+                Some(wgpu::TextureSampleType::Sint) => {
+                    texture_sint = height_field.height_texture.handle;
+                }
+                Some(wgpu::TextureSampleType::Uint) => {
+                    texture_uint = height_field.height_texture.handle;
+                }
+                _ => {
+                    return Err(HeightFieldDrawDataError::TextureFormatNotSupported(
+                        texture_format,
+                    ));
+                }
+            }
+
+            let albedo_texture = match &height_field.coloring {
+                HeightFieldColoring::Texture(texture) => texture.texture.handle,
+                HeightFieldColoring::Solid(_) | HeightFieldColoring::Colormap { .. } => {
+                    ctx.texture_manager_2d.zeroed_texture_float().handle
+                }
+            };
+
+            let bind_group = ctx.gpu_resources.bind_groups.alloc(
+                &ctx.device,
+                &ctx.gpu_resources,
+                &BindGroupDesc {
+                    label: "height_field".into(),
+                    entries: smallvec![
+                        ubo,
+                        BindGroupEntry::DefaultTextureView(texture_float),
+                        BindGroupEntry::DefaultTextureView(texture_sint),
+                        BindGroupEntry::DefaultTextureView(texture_uint),
+                        BindGroupEntry::DefaultTextureView(albedo_texture),
+                    ],
+                    layout: bg_layout,
+                },
+            );
+
+            instances.push(HeightFieldDrawInstance {
+                bind_group,
+                num_triangles: height_field.num_triangles(),
+                render_outline_mask: height_field.outline_mask_id.is_some(),
+            });
+        }
+
+        Ok(Self { instances })
+    }
+}
+
+pub struct HeightFieldRenderer {
+    render_pipeline_color: GpuRenderPipelineHandle,
+    render_pipeline_picking_layer: GpuRenderPipelineHandle,
+    render_pipeline_outline_mask: GpuRenderPipelineHandle,
+    bind_group_layout: GpuBindGroupLayoutHandle,
+}
+
+impl Renderer for HeightFieldRenderer {
+    type RendererDrawData = HeightFieldDrawData;
+
+    fn participated_phases() -> &'static [DrawPhase] {
+        &[
+            DrawPhase::Opaque,
+            DrawPhase::PickingLayer,
+            DrawPhase::OutlineMask,
+        ]
+    }
+
+    fn create_renderer(ctx: &RenderContext) -> Self {
+        re_tracing::profile_function!();
+
+        let render_pipelines = &ctx.gpu_resources.render_pipelines;
+
+        let height_texture_entry = |binding, sample_type| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Texture {
+                sample_type,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+
+        let bind_group_layout = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "height_field_bg_layout".into(),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: (std::mem::size_of::<gpu_data::HeightFieldInfoUBO>()
+                                as u64)
+                                .try_into()
+                                .ok(),
+                        },
+                        count: None,
+                    },
+                    height_texture_entry(1, wgpu::TextureSampleType::Float { filterable: false }),
+                    height_texture_entry(2, wgpu::TextureSampleType::Sint),
+                    height_texture_entry(3, wgpu::TextureSampleType::Uint),
+                    // albedo texture:
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            },
+        );
+
+        let pipeline_layout = ctx.gpu_resources.pipeline_layouts.get_or_create(
+            ctx,
+            &PipelineLayoutDesc {
+                label: "height_field_rp_layout".into(),
+                entries: vec![ctx.global_bindings.layout, bind_group_layout],
+            },
+        );
+
+        let shader_module = ctx.gpu_resources.shader_modules.get_or_create(
+            ctx,
+            &include_shader_module!("../../shader/height_field.wgsl"),
+        );
+
+        let render_pipeline_desc_color = RenderPipelineDesc {
+            label: "HeightFieldRenderer::render_pipeline_desc_color".into(),
+            pipeline_layout,
+            vertex_entrypoint: "vs_main".into(),
+            vertex_handle: shader_module,
+            fragment_entrypoint: "fs_main".into(),
+            fragment_handle: shader_module,
+            vertex_buffers: smallvec![],
+            render_targets: smallvec![Some(ViewBuilder::MAIN_TARGET_COLOR_FORMAT.into())],
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                // Height fields are seen from below just as often as from above.
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: ViewBuilder::MAIN_TARGET_DEFAULT_DEPTH_STATE,
+            multisample: ViewBuilder::MAIN_TARGET_DEFAULT_MSAA_STATE,
+        };
+        let render_pipeline_color =
+            render_pipelines.get_or_create(ctx, &render_pipeline_desc_color);
+        let render_pipeline_picking_layer = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "HeightFieldRenderer::render_pipeline_picking_layer".into(),
+                fragment_entrypoint: "fs_main_picking_layer".into(),
+                render_targets: smallvec![Some(PickingLayerProcessor::PICKING_LAYER_FORMAT.into())],
+                depth_stencil: PickingLayerProcessor::PICKING_LAYER_DEPTH_STATE,
+                multisample: PickingLayerProcessor::PICKING_LAYER_MSAA_STATE,
+                ..render_pipeline_desc_color.clone()
+            },
+        );
+        let render_pipeline_outline_mask = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "HeightFieldRenderer::render_pipeline_outline_mask".into(),
+                fragment_entrypoint: "fs_main_outline_mask".into(),
+                render_targets: smallvec![Some(OutlineMaskProcessor::MASK_FORMAT.into())],
+                depth_stencil: OutlineMaskProcessor::MASK_DEPTH_STATE,
+                multisample: OutlineMaskProcessor::mask_default_msaa_state(ctx.device_caps().tier),
+                ..render_pipeline_desc_color
+            },
+        );
+
+        Self {
+            render_pipeline_color,
+            render_pipeline_picking_layer,
+            render_pipeline_outline_mask,
+            bind_group_layout,
+        }
+    }
+
+    fn draw(
+        &self,
+        render_pipelines: &GpuRenderPipelinePoolAccessor<'_>,
+        phase: DrawPhase,
+        pass: &mut wgpu::RenderPass<'_>,
+        draw_data: &Self::RendererDrawData,
+    ) -> Result<(), DrawError> {
+        re_tracing::profile_function!();
+        if draw_data.instances.is_empty() {
+            return Ok(());
+        }
+
+        let pipeline_handle = match phase {
+            DrawPhase::Opaque => self.render_pipeline_color,
+            DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
+            DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
+            _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
+        };
+        let pipeline = render_pipelines.get(pipeline_handle)?;
+
+        pass.set_pipeline(pipeline);
+
+        for instance in &draw_data.instances {
+            if phase == DrawPhase::OutlineMask && !instance.render_outline_mask {
+                continue;
+            }
+
+            pass.set_bind_group(1, &instance.bind_group, &[]);
+            pass.draw(0..instance.num_triangles * 3, 0..1);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        renderer::ColorMapper, resource_managers::ImageDataDesc, view_builder::TargetConfiguration,
+    };
+
+    use super::*;
+
+    fn texture(
+        ctx: &RenderContext,
+        key: u64,
+        format: wgpu::TextureFormat,
+        texel: &[u8],
+        width_height: [u32; 2],
+    ) -> GpuTexture2D {
+        ctx.texture_manager_2d
+            .get_or_create(
+                key,
+                ctx,
+                ImageDataDesc {
+                    label: format!("{format:?} test texture").into(),
+                    data: texel
+                        .repeat((width_height[0] * width_height[1]) as usize)
+                        .into(),
+                    format: format.into(),
+                    width_height,
+                },
+            )
+            .unwrap()
+    }
+
+    fn height_field(height_texture: GpuTexture2D, coloring: HeightFieldColoring) -> HeightField {
+        HeightField {
+            world_from_obj: glam::Affine3A::IDENTITY,
+            origin: glam::vec2(-1.0, -1.0),
+            cell_size: glam::Vec2::splat(0.5),
+            height_texture,
+            coloring,
+            outline_mask_id: Default::default(),
+            picking_object_id: Default::default(),
+        }
+    }
+
+    #[test]
+    fn height_field_colorings() {
+        re_log::setup_logging();
+        re_log::PanicOnWarnScope::new();
+
+        RenderContext::new_test().execute_test_frame(|ctx| {
+            let mut view = ViewBuilder::new(ctx, TargetConfiguration::default());
+
+            let float_heights = texture(
+                ctx,
+                0,
+                wgpu::TextureFormat::R32Float,
+                &0.5_f32.to_le_bytes(),
+                [3, 2],
+            );
+            let sint_heights = texture(
+                ctx,
+                1,
+                wgpu::TextureFormat::R16Sint,
+                &(-2_i16).to_le_bytes(),
+                [3, 2],
+            );
+            let uint_heights = texture(ctx, 2, wgpu::TextureFormat::R8Uint, &[7], [3, 2]);
+            let albedo = texture(
+                ctx,
+                3,
+                wgpu::TextureFormat::Rgba8UnormSrgb,
+                &[255, 128, 0, 255],
+                [2, 2],
+            );
+
+            let height_fields = [
+                height_field(float_heights, HeightFieldColoring::Solid(Rgba::RED)),
+                height_field(
+                    sint_heights,
+                    HeightFieldColoring::Colormap {
+                        colormap: Colormap::Viridis,
+                        min_max_height: [-2.0, 2.0],
+                    },
+                ),
+                height_field(
+                    uint_heights,
+                    HeightFieldColoring::Texture(ColormappedTexture::from_unorm_rgba(albedo)),
+                ),
+            ];
+            let draw_data = HeightFieldDrawData::new(ctx, &height_fields).unwrap();
+            assert_eq!(draw_data.instances.len(), 3);
+            view.queue_draw(draw_data);
+
+            [view.draw(ctx, Rgba::BLACK).unwrap()]
+        });
+    }
+
+    #[test]
+    fn num_triangles() {
+        re_log::setup_logging();
+
+        RenderContext::new_test().execute_test_frame(|ctx| {
+            let float_heights = |key, width_height| {
+                texture(
+                    ctx,
+                    key,
+                    wgpu::TextureFormat::R32Float,
+                    &1.0_f32.to_le_bytes(),
+                    width_height,
+                )
+            };
+
+            let grid = height_field(
+                float_heights(0, [3, 2]),
+                HeightFieldColoring::Solid(Rgba::RED),
+            );
+            assert_eq!(grid.dimensions(), glam::uvec2(3, 2));
+            assert_eq!(grid.num_triangles(), 4);
+
+            // A single row or column of heights doesn't span any cells.
+            for width_height in [[1, 1], [4, 1], [1, 4]] {
+                let key = (width_height[0] * 10 + width_height[1]) as u64;
+                let line = height_field(
+                    float_heights(key, width_height),
+                    HeightFieldColoring::Solid(Rgba::RED),
+                );
+                assert_eq!(line.num_triangles(), 0);
+
+                let draw_data = HeightFieldDrawData::new(ctx, &[line]).unwrap();
+                assert!(draw_data.instances.is_empty());
+            }
+
+            std::iter::empty()
+        });
+    }
+
+    #[test]
+    fn color_mapped_albedo_is_not_supported() {
+        re_log::setup_logging();
+
+        RenderContext::new_test().execute_test_frame(|ctx| {
+            let heights = texture(
+                ctx,
+                0,
+                wgpu::TextureFormat::R32Float,
+                &1.0_f32.to_le_bytes(),
+                [2, 2],
+            );
+            let rgba = texture(
+                ctx,
+                1,
+                wgpu::TextureFormat::Rgba8UnormSrgb,
+                &[255, 128, 0, 255],
+                [2, 2],
+            );
+            let mut color_mapped = ColormappedTexture::from_unorm_rgba(rgba.clone());
+            color_mapped.color_mapper = ColorMapper::Texture(rgba);
+
+            let height_fields = [height_field(
+                heights,
+                HeightFieldColoring::Texture(color_mapped),
+            )];
+            assert!(matches!(
+                HeightFieldDrawData::new(ctx, &height_fields),
+                Err(HeightFieldDrawDataError::Albedo(
+                    AlbedoTextureError::ColorMapped
+                ))
+            ));
+
+            std::iter::empty()
+        });
+    }
+}
@@ -86,7 +86,7 @@ use smallvec::smallvec;
 
 use crate::{
     allocator::create_and_fill_uniform_buffer_batch,
-    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    draw_phases::{DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor},
     include_shader_module,
     view_builder::ViewBuilder,
     wgpu_resources::{
@@ -169,7 +169,7 @@ pub mod gpu_data {
         pub depth_offset: f32,
         pub triangle_cap_length_factor: f32,
         pub triangle_cap_width_factor: f32,
-        pub _padding: f32,
+        pub lens_distortion: u32,
 
         pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 6],
     }
@@ -194,6 +194,14 @@ pub struct LineDrawData {
 
 impl DrawData for LineDrawData {
     type Renderer = LineRenderer;
+
+    fn has_order_independent_transparency(&self) -> bool {
+        self.batches.iter().any(|batch| {
+            batch
+                .active_phases
+                .contains(DrawPhase::OrderIndependentTransparency)
+        })
+    }
 }
 
 bitflags! {
@@ -291,6 +299,17 @@ pub struct LineBatchInfo {
     /// This controls how wide the triangle/arrow-head is orthogonal to the line's direction.
     /// (defaults to 2.0)
     pub triangle_cap_width_factor: f32,
+
+    /// If true, line vertices are distorted by the view's lens distortion.
+    ///
+    /// Note that only the vertices are distorted, segments in between remain straight.
+    /// See [`crate::LensDistortionMode::DistortGeometry`].
+    pub lens_distortion: bool,
+
+    /// Whether any of the strips in this batch has a color with an alpha below 1.0.
+    ///
+    /// Transparent strips are drawn with order independent transparency.
+    pub has_transparency: bool,
 }
 
 impl Default for LineBatchInfo {
@@ -305,6 +324,8 @@ impl Default for LineBatchInfo {
             depth_offset: 0,
             triangle_cap_length_factor: 4.0,
             triangle_cap_width_factor: 2.0,
+            lens_distortion: false,
+            has_transparency: false,
         }
     }
 }
@@ -438,7 +459,7 @@ impl LineDrawData {
                     depth_offset: batch_info.depth_offset as f32,
                     triangle_cap_length_factor: batch_info.triangle_cap_length_factor,
                     triangle_cap_width_factor: batch_info.triangle_cap_width_factor,
-                    _padding: 0.0,
+                    lens_distortion: batch_info.lens_distortion as u32,
                     end_padding: Default::default(),
                 }
             }
@@ -487,6 +508,9 @@ impl LineDrawData {
                 if batch_info.overall_outline_mask_ids.is_some() {
                     active_phases.insert(DrawPhase::OutlineMask);
                 }
+                if batch_info.has_transparency {
+                    active_phases.insert(DrawPhase::OrderIndependentTransparency);
+                }
 
                 batches_internal.push(line_renderer.create_linestrip_batch(
                     ctx,
@@ -520,6 +544,10 @@ impl LineDrawData {
 
 pub struct LineRenderer {
     render_pipeline_color: GpuRenderPipelineHandle,
+
+    /// Only available if the device supports order independent transparency.
+    render_pipeline_transparent: Option<GpuRenderPipelineHandle>,
+
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
     bind_group_layout_all_lines: GpuBindGroupLayoutHandle,
@@ -564,6 +592,7 @@ impl Renderer for LineRenderer {
     fn participated_phases() -> &'static [DrawPhase] {
         &[
             DrawPhase::Opaque,
+            DrawPhase::OrderIndependentTransparency,
             DrawPhase::OutlineMask,
             DrawPhase::PickingLayer,
         ]
@@ -683,6 +712,23 @@ impl Renderer for LineRenderer {
         };
         let render_pipeline_color =
             render_pipelines.get_or_create(ctx, &render_pipeline_desc_color);
+        let render_pipeline_transparent = ctx
+            .device_caps()
+            .tier
+            .support_order_independent_transparency()
+            .then(|| {
+                render_pipelines.get_or_create(
+                    ctx,
+                    &RenderPipelineDesc {
+                        label: "LineRenderer::render_pipeline_transparent".into(),
+                        fragment_entrypoint: "fs_main_transparent".into(),
+                        render_targets: OrderIndependentTransparencyProcessor::render_targets(),
+                        depth_stencil: OrderIndependentTransparencyProcessor::DEPTH_STATE,
+                        multisample: OrderIndependentTransparencyProcessor::MSAA_STATE,
+                        ..render_pipeline_desc_color.clone()
+                    },
+                )
+            });
         let render_pipeline_picking_layer = render_pipelines.get_or_create(
             ctx,
             &RenderPipelineDesc {
@@ -717,6 +763,7 @@ impl Renderer for LineRenderer {
 
         Self {
             render_pipeline_color,
+            render_pipeline_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
             bind_group_layout_all_lines,
@@ -737,6 +784,12 @@ impl Renderer for LineRenderer {
                 &draw_data.bind_group_all_lines_outline_mask,
             ),
             DrawPhase::Opaque => (self.render_pipeline_color, &draw_data.bind_group_all_lines),
+            DrawPhase::OrderIndependentTransparency => {
+                let Some(render_pipeline_transparent) = self.render_pipeline_transparent else {
+                    return Ok(()); // Transparency is drawn as opaque then.
+                };
+                (render_pipeline_transparent, &draw_data.bind_group_all_lines)
+            }
             DrawPhase::PickingLayer => (
                 self.render_pipeline_picking_layer,
                 &draw_data.bind_group_all_lines,
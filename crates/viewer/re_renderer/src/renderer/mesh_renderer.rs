@@ -9,7 +9,7 @@ use ahash::{HashMap, HashMapExt};
 use smallvec::smallvec;
 
 use crate::{
-    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    draw_phases::{DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor},
     include_shader_module,
     mesh::{gpu_data::MaterialUniformBuffer, mesh_vertices, GpuMesh},
     view_builder::ViewBuilder,
@@ -44,12 +44,15 @@ mod gpu_data {
         pub world_from_mesh_normal_row_1: [f32; 3],
         pub world_from_mesh_normal_row_2: [f32; 3],
 
+        /// Alpha channel is used for the instance's opacity.
         pub additive_tint: Color32,
 
         pub picking_layer_id: [u32; 4],
 
-        // Need only the first two bytes, but we want to keep everything aligned to at least 4 bytes.
-        pub outline_mask_ids: [u8; 4],
+        /// Outline mask ids in the first two bytes, lens distortion flag in the third.
+        ///
+        /// Packed together since we want to keep everything aligned to at least 4 bytes.
+        pub outline_mask_ids_and_flags: [u8; 4],
     }
 
     impl InstanceData {
@@ -70,14 +73,14 @@ mod gpu_data {
                         wgpu::VertexFormat::Float32x3,
                         wgpu::VertexFormat::Float32x3,
                         wgpu::VertexFormat::Float32x3,
-                        // Tint color
+                        // Tint color & opacity
                         wgpu::VertexFormat::Unorm8x4,
                         // Picking id.
                         // Again this adds overhead for non-picking passes, more this time. Consider moving this elsewhere.
                         wgpu::VertexFormat::Uint32x4,
-                        // Outline mask.
+                        // Outline mask & flags.
                         // This adds a tiny bit of overhead to all instances during non-outline pass, but the alternative is having yet another vertex buffer.
-                        wgpu::VertexFormat::Uint8x2,
+                        wgpu::VertexFormat::Uint8x4,
                     ]
                     .into_iter(),
                 ),
@@ -95,6 +98,9 @@ struct MeshBatch {
     /// Number of meshes out of `count` which have outlines.
     /// We put all instances with outlines at the start of the instance buffer range.
     count_with_outlines: u32,
+
+    /// Whether the mesh or any of its instances may be (partially) transparent.
+    has_transparency: bool,
 }
 
 #[derive(Clone)]
@@ -108,6 +114,10 @@ pub struct MeshDrawData {
 
 impl DrawData for MeshDrawData {
     type Renderer = MeshRenderer;
+
+    fn has_order_independent_transparency(&self) -> bool {
+        self.batches.iter().any(|batch| batch.has_transparency)
+    }
 }
 
 pub struct GpuMeshInstance {
@@ -118,14 +128,25 @@ pub struct GpuMeshInstance {
     pub world_from_mesh: glam::Affine3A,
 
     /// Per-instance (as opposed to per-material/mesh!) tint color that is added to the albedo texture.
-    /// Alpha channel is currently unused.
+    /// Alpha channel is ignored, use [`Self::opacity`] instead.
     pub additive_tint: Color32,
 
+    /// Opacity of this instance, multiplied with the alpha of the mesh's vertex colors and materials.
+    ///
+    /// Instances with an opacity below 1.0 are drawn with order independent transparency.
+    pub opacity: f32,
+
     /// Optional outline mask setting for this instance.
     pub outline_mask_ids: OutlineMaskPreference,
 
     /// Picking layer id.
     pub picking_layer_id: PickingLayerId,
+
+    /// If true, mesh vertices are distorted by the view's lens distortion.
+    ///
+    /// Note that only the vertices are distorted, triangles in between remain flat.
+    /// See [`crate::LensDistortionMode::DistortGeometry`].
+    pub lens_distortion: bool,
 }
 
 impl GpuMeshInstance {
@@ -135,8 +156,10 @@ impl GpuMeshInstance {
             gpu_mesh,
             world_from_mesh: glam::Affine3A::IDENTITY,
             additive_tint: Color32::TRANSPARENT,
+            opacity: 1.0,
             outline_mask_ids: OutlineMaskPreference::NONE,
             picking_layer_id: PickingLayerId::default(),
+            lens_distortion: false,
         }
     }
 }
@@ -202,6 +225,7 @@ impl MeshDrawData {
             for (_mesh_ptr, mut instances) in instances_by_mesh {
                 let mut count = 0;
                 let mut count_with_outlines = 0;
+                let mut has_transparency = false;
 
                 // Put all instances with outlines at the start of the instance buffer range.
                 instances.sort_by(|a, b| {
@@ -218,6 +242,7 @@ impl MeshDrawData {
 
                     count += 1;
                     count_with_outlines += instance.outline_mask_ids.is_some() as u32;
+                    has_transparency |= instance.opacity < 1.0;
 
                     let world_from_mesh_mat3 = instance.world_from_mesh.matrix3;
                     // If the matrix is not invertible the draw result is likely invalid as well.
@@ -245,11 +270,16 @@ impl MeshDrawData {
                         world_from_mesh_normal_row_0: world_from_mesh_normal.row(0).to_array(),
                         world_from_mesh_normal_row_1: world_from_mesh_normal.row(1).to_array(),
                         world_from_mesh_normal_row_2: world_from_mesh_normal.row(2).to_array(),
-                        additive_tint: instance.additive_tint,
-                        outline_mask_ids: instance
-                            .outline_mask_ids
-                            .0
-                            .map_or([0, 0, 0, 0], |mask| [mask[0], mask[1], 0, 0]),
+                        additive_tint: Color32::from_rgba_premultiplied(
+                            instance.additive_tint.r(),
+                            instance.additive_tint.g(),
+                            instance.additive_tint.b(),
+                            (instance.opacity.clamp(0.0, 1.0) * 255.0).round() as u8,
+                        ),
+                        outline_mask_ids_and_flags: {
+                            let [mask_0, mask_1] = instance.outline_mask_ids.0.unwrap_or_default();
+                            [mask_0, mask_1, instance.lens_distortion as u8, 0]
+                        },
                         picking_layer_id: instance.picking_layer_id.into(),
                     })?;
                 }
@@ -257,6 +287,7 @@ impl MeshDrawData {
 
                 if let Some(mesh) = mesh {
                     batches.push(MeshBatch {
+                        has_transparency: has_transparency || mesh.has_transparency,
                         mesh,
                         count: count as _,
                         count_with_outlines,
@@ -280,6 +311,10 @@ impl MeshDrawData {
 
 pub struct MeshRenderer {
     render_pipeline_shaded: GpuRenderPipelineHandle,
+
+    /// Only available if the device supports order independent transparency.
+    render_pipeline_transparent: Option<GpuRenderPipelineHandle>,
+
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
     pub bind_group_layout: GpuBindGroupLayoutHandle,
@@ -291,6 +326,7 @@ impl Renderer for MeshRenderer {
     fn participated_phases() -> &'static [DrawPhase] {
         &[
             DrawPhase::Opaque,
+            DrawPhase::OrderIndependentTransparency,
             DrawPhase::OutlineMask,
             DrawPhase::PickingLayer,
         ]
@@ -370,6 +406,23 @@ impl Renderer for MeshRenderer {
         };
         let render_pipeline_shaded =
             render_pipelines.get_or_create(ctx, &render_pipeline_shaded_desc);
+        let render_pipeline_transparent = ctx
+            .device_caps()
+            .tier
+            .support_order_independent_transparency()
+            .then(|| {
+                render_pipelines.get_or_create(
+                    ctx,
+                    &RenderPipelineDesc {
+                        label: "MeshRenderer::render_pipeline_transparent".into(),
+                        fragment_entrypoint: "fs_main_transparent".into(),
+                        render_targets: OrderIndependentTransparencyProcessor::render_targets(),
+                        depth_stencil: OrderIndependentTransparencyProcessor::DEPTH_STATE,
+                        multisample: OrderIndependentTransparencyProcessor::MSAA_STATE,
+                        ..render_pipeline_shaded_desc.clone()
+                    },
+                )
+            });
         let render_pipeline_picking_layer = render_pipelines.get_or_create(
             ctx,
             &RenderPipelineDesc {
@@ -395,6 +448,7 @@ impl Renderer for MeshRenderer {
 
         Self {
             render_pipeline_shaded,
+            render_pipeline_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
             bind_group_layout,
@@ -417,6 +471,12 @@ impl Renderer for MeshRenderer {
         let pipeline_handle = match phase {
             DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
             DrawPhase::Opaque => self.render_pipeline_shaded,
+            DrawPhase::OrderIndependentTransparency => {
+                let Some(render_pipeline_transparent) = self.render_pipeline_transparent else {
+                    return Ok(()); // Transparency is drawn as opaque then.
+                };
+                render_pipeline_transparent
+            }
             DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
             _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
         };
@@ -428,7 +488,10 @@ impl Renderer for MeshRenderer {
         let mut instance_start_index = 0;
 
         for mesh_batch in &draw_data.batches {
-            if phase == DrawPhase::OutlineMask && mesh_batch.count_with_outlines == 0 {
+            if (phase == DrawPhase::OutlineMask && mesh_batch.count_with_outlines == 0)
+                || (phase == DrawPhase::OrderIndependentTransparency
+                    && !mesh_batch.has_transparency)
+            {
                 instance_start_index += mesh_batch.count;
                 continue;
             }
@@ -9,8 +9,19 @@ pub use point_cloud::{
     PointCloudBatchFlags, PointCloudBatchInfo, PointCloudDrawData, PointCloudDrawDataError,
 };
 
+mod albedo;
+pub use albedo::AlbedoTextureError;
+
 mod depth_cloud;
-pub use self::depth_cloud::{DepthCloud, DepthCloudDrawData, DepthCloudRenderer, DepthClouds};
+pub use self::depth_cloud::{
+    DepthCloud, DepthCloudAlbedo, DepthCloudDrawData, DepthCloudRenderer, DepthClouds,
+};
+
+mod height_field;
+pub use self::height_field::{
+    HeightField, HeightFieldColoring, HeightFieldDrawData, HeightFieldDrawDataError,
+    HeightFieldRenderer,
+};
 
 mod test_triangle;
 pub use test_triangle::TestTriangleDrawData;
@@ -51,6 +62,13 @@ use crate::{
 /// Valid only for the frame in which it was created (typically uses temp allocations!).
 pub trait DrawData {
     type Renderer: Renderer<RendererDrawData = Self> + Send + Sync;
+
+    /// Whether there's anything to draw in `DrawPhase::OrderIndependentTransparency`.
+    ///
+    /// The transparency pass is costly, so the view builder skips it unless any of its draw data needs it.
+    fn has_order_independent_transparency(&self) -> bool {
+        false
+    }
 }
 
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
@@ -17,7 +17,10 @@ use std::{num::NonZeroU64, ops::Range};
 
 use crate::{
     allocator::create_and_fill_uniform_buffer_batch,
-    draw_phases::{DrawPhase, OutlineMaskProcessor, PickingLayerObjectId, PickingLayerProcessor},
+    draw_phases::{
+        DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor,
+        PickingLayerObjectId, PickingLayerProcessor,
+    },
     include_shader_module,
     wgpu_resources::GpuRenderPipelinePoolAccessor,
     DebugLabel, DepthOffset, OutlineMaskPreference, PointCloudBuilder,
@@ -49,6 +52,11 @@ bitflags! {
 
         /// If true, draw 2D camera facing circles instead of spheres.
         const FLAG_DRAW_AS_CIRCLES = 0b0010;
+
+        /// If true, point positions are distorted by the view's lens distortion.
+        ///
+        /// See [`crate::LensDistortionMode::DistortGeometry`].
+        const FLAG_LENS_DISTORTION = 0b0100;
     }
 }
 
@@ -112,6 +120,14 @@ pub struct PointCloudDrawData {
 
 impl DrawData for PointCloudDrawData {
     type Renderer = PointCloudRenderer;
+
+    fn has_order_independent_transparency(&self) -> bool {
+        self.batches.iter().any(|batch| {
+            batch
+                .active_phases
+                .contains(DrawPhase::OrderIndependentTransparency)
+        })
+    }
 }
 
 /// Data that is valid for a batch of point cloud points.
@@ -149,6 +165,11 @@ pub struct PointCloudBatchInfo {
 
     /// Depth offset applied after projection.
     pub depth_offset: DepthOffset,
+
+    /// Whether any of the points in this batch has a color with an alpha below 1.0.
+    ///
+    /// Transparent points are drawn with order independent transparency.
+    pub has_transparency: bool,
 }
 
 impl Default for PointCloudBatchInfo {
@@ -163,6 +184,7 @@ impl Default for PointCloudBatchInfo {
             additional_outline_mask_ids_vertex_ranges: Vec::new(),
             picking_object_id: Default::default(),
             depth_offset: 0,
+            has_transparency: false,
         }
     }
 }
@@ -214,6 +236,7 @@ impl PointCloudDrawData {
             additional_outline_mask_ids_vertex_ranges: Vec::new(),
             picking_object_id: Default::default(),
             depth_offset: 0,
+            has_transparency: false,
         }];
         let batches = if batches.is_empty() {
             &fallback_batches
@@ -343,6 +366,9 @@ impl PointCloudDrawData {
                 if batch_info.overall_outline_mask_ids.is_some() {
                     active_phases.insert(DrawPhase::OutlineMask);
                 }
+                if batch_info.has_transparency {
+                    active_phases.insert(DrawPhase::OrderIndependentTransparency);
+                }
 
                 batches_internal.push(point_renderer.create_point_cloud_batch(
                     ctx,
@@ -383,6 +409,10 @@ impl PointCloudDrawData {
 
 pub struct PointCloudRenderer {
     render_pipeline_color: GpuRenderPipelineHandle,
+
+    /// Only available if the device supports order independent transparency.
+    render_pipeline_transparent: Option<GpuRenderPipelineHandle>,
+
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
     bind_group_layout_all_points: GpuBindGroupLayoutHandle,
@@ -425,6 +455,7 @@ impl Renderer for PointCloudRenderer {
         &[
             DrawPhase::OutlineMask,
             DrawPhase::Opaque,
+            DrawPhase::OrderIndependentTransparency,
             DrawPhase::PickingLayer,
         ]
     }
@@ -553,6 +584,23 @@ impl Renderer for PointCloudRenderer {
         };
         let render_pipeline_color =
             render_pipelines.get_or_create(ctx, &render_pipeline_desc_color);
+        let render_pipeline_transparent = ctx
+            .device_caps()
+            .tier
+            .support_order_independent_transparency()
+            .then(|| {
+                render_pipelines.get_or_create(
+                    ctx,
+                    &RenderPipelineDesc {
+                        label: "PointCloudRenderer::render_pipeline_transparent".into(),
+                        fragment_entrypoint: "fs_main_transparent".into(),
+                        render_targets: OrderIndependentTransparencyProcessor::render_targets(),
+                        depth_stencil: OrderIndependentTransparencyProcessor::DEPTH_STATE,
+                        multisample: OrderIndependentTransparencyProcessor::MSAA_STATE,
+                        ..render_pipeline_desc_color.clone()
+                    },
+                )
+            });
         let render_pipeline_picking_layer = render_pipelines.get_or_create(
             ctx,
             &RenderPipelineDesc {
@@ -579,6 +627,7 @@ impl Renderer for PointCloudRenderer {
 
         Self {
             render_pipeline_color,
+            render_pipeline_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
             bind_group_layout_all_points,
@@ -599,6 +648,15 @@ impl Renderer for PointCloudRenderer {
                 &draw_data.bind_group_all_points_outline_mask,
             ),
             DrawPhase::Opaque => (self.render_pipeline_color, &draw_data.bind_group_all_points),
+            DrawPhase::OrderIndependentTransparency => {
+                let Some(render_pipeline_transparent) = self.render_pipeline_transparent else {
+                    return Ok(()); // Transparency is drawn as opaque then.
+                };
+                (
+                    render_pipeline_transparent,
+                    &draw_data.bind_group_all_points,
+                )
+            }
             DrawPhase::PickingLayer => (
                 self.render_pipeline_picking_layer,
                 &draw_data.bind_group_all_points,
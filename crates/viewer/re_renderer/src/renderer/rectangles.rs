@@ -1,9 +1,11 @@
 //! Renderer that makes it easy to draw textured 2D rectangles with transparency
 //!
-//! Transparency: (TODO(andreas):)
-//! We're not performing any sorting on transparency yet, so the transparent rectangles pretty much
-//! only work correctly when they are directly layered in front of another opaque rectangle.
-//! We do *not* disable depth write.
+//! Transparency:
+//! Rectangles with a [`RectangleOptions::multiplicative_tint`] alpha below 1.0 are drawn with
+//! order independent transparency, see [`crate::OrderIndependentTransparencyProcessor`].
+//! Alpha coming from the texture itself is still blended in the opaque phase without any sorting,
+//! meaning that it pretty much only works correctly when directly layered in front of another opaque rectangle.
+//! (TODO(andreas): We do *not* disable depth write for these.)
 //!
 //! Implementation details:
 //! We assume the standard usecase are individual textured rectangles.
@@ -16,7 +18,7 @@ use smallvec::smallvec;
 use crate::{
     allocator::create_and_fill_uniform_buffer_batch,
     depth_offset::DepthOffset,
-    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    draw_phases::{DrawPhase, OrderIndependentTransparencyProcessor, OutlineMaskProcessor},
     include_shader_module,
     resource_managers::GpuTexture2D,
     view_builder::ViewBuilder,
@@ -170,12 +172,21 @@ pub struct RectangleOptions {
     pub texture_filter_minification: TextureFilterMin,
 
     /// Tint that is multiplied to the rect, supports pre-multiplied alpha.
+    ///
+    /// If alpha is below 1.0, the rectangle is drawn with order independent transparency.
+    /// Alpha of the texture itself doesn't count, it is blended without any sorting (see module docs).
     pub multiplicative_tint: Rgba,
 
     pub depth_offset: DepthOffset,
 
     /// Optional outline mask.
     pub outline_mask: OutlineMaskPreference,
+
+    /// If true, the texture is undistorted with the view's lens distortion.
+    ///
+    /// Assumes that the rectangle lies in the pixel space of the view's pinhole camera.
+    /// See [`crate::LensDistortionMode::UndistortImages`].
+    pub lens_undistortion: bool,
 }
 
 impl Default for RectangleOptions {
@@ -186,6 +197,7 @@ impl Default for RectangleOptions {
             multiplicative_tint: Rgba::WHITE,
             depth_offset: 0,
             outline_mask: OutlineMaskPreference::NONE,
+            lens_undistortion: false,
         }
     }
 }
@@ -262,7 +274,7 @@ mod gpu_data {
         decode_srgb: u32,
         multiply_rgb_with_alpha: u32,
         bgra_to_rgba: u32,
-        _row_padding: [u32; 1],
+        lens_undistortion: u32,
 
         _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 7],
     }
@@ -299,6 +311,7 @@ mod gpu_data {
                 multiplicative_tint,
                 depth_offset,
                 outline_mask,
+                lens_undistortion,
             } = options;
 
             let sample_type = match texture_format.sample_type(None, None) {
@@ -360,7 +373,7 @@ mod gpu_data {
                 decode_srgb: *decode_srgb as _,
                 multiply_rgb_with_alpha: *multiply_rgb_with_alpha as _,
                 bgra_to_rgba: bgra_to_rgba as _,
-                _row_padding: Default::default(),
+                lens_undistortion: *lens_undistortion as _,
                 _end_padding: Default::default(),
             })
         }
@@ -371,6 +384,7 @@ mod gpu_data {
 struct RectangleInstance {
     bind_group: GpuBindGroup,
     draw_outline_mask: bool,
+    is_transparent: bool,
 }
 
 #[derive(Clone)]
@@ -380,6 +394,12 @@ pub struct RectangleDrawData {
 
 impl DrawData for RectangleDrawData {
     type Renderer = RectangleRenderer;
+
+    fn has_order_independent_transparency(&self) -> bool {
+        self.instances
+            .iter()
+            .any(|instance| instance.is_transparent)
+    }
 }
 
 impl RectangleDrawData {
@@ -465,6 +485,7 @@ impl RectangleDrawData {
                     },
                 ),
                 draw_outline_mask: rectangle.options.outline_mask.is_some(),
+                is_transparent: rectangle.options.multiplicative_tint.a() < 1.0,
             });
         }
 
@@ -474,6 +495,10 @@ impl RectangleDrawData {
 
 pub struct RectangleRenderer {
     render_pipeline_color: GpuRenderPipelineHandle,
+
+    /// Only available if the device supports order independent transparency.
+    render_pipeline_transparent: Option<GpuRenderPipelineHandle>,
+
     render_pipeline_picking_layer: GpuRenderPipelineHandle,
     render_pipeline_outline_mask: GpuRenderPipelineHandle,
     bind_group_layout: GpuBindGroupLayoutHandle,
@@ -582,7 +607,7 @@ impl Renderer for RectangleRenderer {
             vertex_buffers: smallvec![],
             render_targets: smallvec![Some(wgpu::ColorTargetState {
                 format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
-                // TODO(andreas): have two render pipelines, an opaque one and a transparent one. Transparent shouldn't write depth!
+                // Still need blending for alpha coming from the texture.
                 blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                 write_mask: wgpu::ColorWrites::ALL,
             })],
@@ -596,6 +621,23 @@ impl Renderer for RectangleRenderer {
         };
         let render_pipeline_color =
             render_pipelines.get_or_create(ctx, &render_pipeline_desc_color);
+        let render_pipeline_transparent = ctx
+            .device_caps()
+            .tier
+            .support_order_independent_transparency()
+            .then(|| {
+                render_pipelines.get_or_create(
+                    ctx,
+                    &(RenderPipelineDesc {
+                        label: "RectangleRenderer::render_pipeline_transparent".into(),
+                        fragment_entrypoint: "fs_main_transparent".into(),
+                        render_targets: OrderIndependentTransparencyProcessor::render_targets(),
+                        depth_stencil: OrderIndependentTransparencyProcessor::DEPTH_STATE,
+                        multisample: OrderIndependentTransparencyProcessor::MSAA_STATE,
+                        ..render_pipeline_desc_color.clone()
+                    }),
+                )
+            });
         let render_pipeline_picking_layer = render_pipelines.get_or_create(
             ctx,
             &(RenderPipelineDesc {
@@ -621,6 +663,7 @@ impl Renderer for RectangleRenderer {
 
         Self {
             render_pipeline_color,
+            render_pipeline_transparent,
             render_pipeline_picking_layer,
             render_pipeline_outline_mask,
             bind_group_layout,
@@ -641,6 +684,12 @@ impl Renderer for RectangleRenderer {
 
         let pipeline_handle = match phase {
             DrawPhase::Opaque => self.render_pipeline_color,
+            DrawPhase::OrderIndependentTransparency => {
+                let Some(render_pipeline_transparent) = self.render_pipeline_transparent else {
+                    return Ok(()); // Transparency is drawn as opaque then.
+                };
+                render_pipeline_transparent
+            }
             DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
             DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
             _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
@@ -649,8 +698,15 @@ impl Renderer for RectangleRenderer {
 
         pass.set_pipeline(pipeline);
 
+        let has_transparency_phase = self.render_pipeline_transparent.is_some();
         for rectangles in &draw_data.instances {
-            if phase == DrawPhase::OutlineMask && !rectangles.draw_outline_mask {
+            let skip = match phase {
+                DrawPhase::OutlineMask => !rectangles.draw_outline_mask,
+                DrawPhase::Opaque => has_transparency_phase && rectangles.is_transparent,
+                DrawPhase::OrderIndependentTransparency => !rectangles.is_transparent,
+                _ => false,
+            };
+            if skip {
                 continue;
             }
             pass.set_bind_group(1, &rectangles.bind_group, &[]);
@@ -661,10 +717,10 @@ impl Renderer for RectangleRenderer {
     }
 
     fn participated_phases() -> &'static [DrawPhase] {
-        // TODO(andreas): This a hack. We have both opaque and transparent.
         &[
             DrawPhase::OutlineMask,
             DrawPhase::Opaque,
+            DrawPhase::OrderIndependentTransparency,
             DrawPhase::PickingLayer,
         ]
     }
@@ -5,17 +5,18 @@ use crate::{
     allocator::{create_and_fill_uniform_buffer, GpuReadbackIdentifier},
     context::{RenderContext, Renderers},
     draw_phases::{
-        DrawPhase, OutlineConfig, OutlineMaskProcessor, PickingLayerError, PickingLayerProcessor,
-        ScreenshotProcessor,
+        DrawPhase, OrderIndependentTransparencyProcessor, OutlineConfig, OutlineMaskProcessor,
+        PickingLayerError, PickingLayerProcessor, ScreenshotProcessor,
     },
     global_bindings::FrameUniformBuffer,
     queueable_draw_data::QueueableDrawData,
     renderer::{CompositorDrawData, DebugOverlayDrawData},
     transform::RectTransform,
+    wgpu_buffer_types,
     wgpu_resources::{
         GpuBindGroup, GpuRenderPipelinePoolAccessor, GpuTexture, PoolError, TextureDesc,
     },
-    DebugLabel, RectInt, Rgba,
+    DebugLabel, RectInt, Rgba, ViewLensDistortion,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -185,6 +186,9 @@ impl Projection {
     }
 }
 
+/// Maximum number of clipping planes a view can have, see [`TargetConfiguration::clip_planes`].
+pub const MAX_CLIP_PLANES: usize = 8;
+
 /// Basic configuration for a target view.
 #[derive(Debug, Clone)]
 pub struct TargetConfiguration {
@@ -222,6 +226,18 @@ pub struct TargetConfiguration {
     /// Otherwise, this step will overwrite whatever was there before, drawing the view builder's result
     /// as an opaque rectangle.
     pub blend_with_background: bool,
+
+    /// Lens distortion of the pinhole camera whose pixel space this view shows.
+    ///
+    /// Only applied to primitives that opted into it, see [`crate::LensDistortionMode`].
+    pub lens_distortion: Option<ViewLensDistortion>,
+
+    /// Planes in world space that cut away everything on the side their normal points towards.
+    ///
+    /// Each plane is given as `(normal, d)`, see [`re_math::Plane3::as_vec4`].
+    /// Applied to meshes, point clouds, lines and depth clouds.
+    /// At most [`MAX_CLIP_PLANES`] planes are supported, additional planes are ignored.
+    pub clip_planes: Vec<glam::Vec4>,
 }
 
 impl Default for TargetConfiguration {
@@ -239,6 +255,8 @@ impl Default for TargetConfiguration {
             pixels_per_point: 1.0,
             outline_config: None,
             blend_with_background: false,
+            lens_distortion: None,
+            clip_planes: Vec::new(),
         }
     }
 }
@@ -464,6 +482,29 @@ impl ViewBuilder {
         let camera_forward = -view_from_world.row(2).truncate();
         let projection_from_world = projection_from_view * view_from_world;
 
+        let (lens_distortion_mode, lens_distortion, lens_distortion_image_from_camera) =
+            match config.lens_distortion {
+                Some(ViewLensDistortion {
+                    image_from_camera,
+                    distortion,
+                    mode,
+                }) => (mode as u32, Some(distortion), image_from_camera),
+                None => (0, None, glam::Mat3::IDENTITY),
+            };
+
+        if config.clip_planes.len() > MAX_CLIP_PLANES {
+            re_log::warn_once!(
+                "{:?} has {} clipping planes, only the first {MAX_CLIP_PLANES} are applied.",
+                config.name,
+                config.clip_planes.len()
+            );
+        }
+        let num_clip_planes = config.clip_planes.len().min(MAX_CLIP_PLANES);
+        let mut clip_planes = [wgpu_buffer_types::Vec4::from(glam::Vec4::ZERO); MAX_CLIP_PLANES];
+        for (gpu_plane, plane) in clip_planes.iter_mut().zip(&config.clip_planes) {
+            *gpu_plane = (*plane).into();
+        }
+
         // Setup frame uniform buffer
         let frame_uniform_buffer_content = FrameUniformBuffer {
             view_from_world: glam::Affine3A::from_mat4(view_from_world).into(),
@@ -476,6 +517,29 @@ impl ViewBuilder {
             pixels_per_point: config.pixels_per_point,
 
             device_tier: (ctx.device_caps().tier as u32).into(),
+
+            lens_distortion_radial: lens_distortion
+                .map_or(glam::Vec4::ZERO, |d| d.radial)
+                .into(),
+            lens_distortion_tangential: lens_distortion
+                .map_or(glam::Vec2::ZERO, |d| d.tangential)
+                .into(),
+            lens_distortion_model: lens_distortion.map_or(0, |d| d.model as u32),
+            lens_distortion_mode,
+            lens_distortion_focal_length: glam::vec2(
+                lens_distortion_image_from_camera.col(0).x,
+                lens_distortion_image_from_camera.col(1).y,
+            )
+            .into(),
+            lens_distortion_principal_point: lens_distortion_image_from_camera
+                .col(2)
+                .truncate()
+                .into(),
+
+            clip_planes,
+            num_clip_planes: (num_clip_planes as u32).into(),
+
+            _end_padding: Default::default(),
         };
         let frame_uniform_buffer = create_and_fill_uniform_buffer(
             ctx,
@@ -546,7 +610,10 @@ impl ViewBuilder {
         re_tracing::profile_function!();
 
         for queued_draw in &self.queued_draws {
-            if queued_draw.participated_phases.contains(&phase) {
+            if queued_draw.participated_phases.contains(&phase)
+                && (phase != DrawPhase::OrderIndependentTransparency
+                    || queued_draw.has_order_independent_transparency)
+            {
                 let res = (queued_draw.draw_func)(
                     renderers,
                     render_pipelines,
@@ -575,6 +642,21 @@ impl ViewBuilder {
     ) -> Result<wgpu::CommandBuffer, PoolError> {
         re_tracing::profile_function!();
 
+        let setup = &self.setup;
+
+        // Needs to be created before locking the render pipelines since it may create pipelines.
+        let needs_oit = ctx
+            .device_caps()
+            .tier
+            .support_order_independent_transparency()
+            && self
+                .queued_draws
+                .iter()
+                .any(|draw| draw.has_order_independent_transparency);
+        let oit_processor = needs_oit.then(|| {
+            OrderIndependentTransparencyProcessor::new(ctx, &setup.name, setup.resolution_in_pixel)
+        });
+
         // Renderers and render pipelines are locked for the entirety of this method:
         // This means it's *not* possible to add renderers or pipelines while drawing is in progress!
         //
@@ -596,8 +678,6 @@ impl ViewBuilder {
         let renderers = ctx.read_lock_renderers();
         let pipelines = ctx.gpu_resources.render_pipelines.resources();
 
-        let setup = &self.setup;
-
         let mut encoder = ctx
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -628,7 +708,12 @@ impl ViewBuilder {
                     view: &setup.depth_buffer.default_view,
                     depth_ops: Some(wgpu::Operations {
                         load: Self::DEFAULT_DEPTH_CLEAR,
-                        store: wgpu::StoreOp::Discard,
+                        // The transparency pass depth tests against opaque objects.
+                        store: if oit_processor.is_some() {
+                            wgpu::StoreOp::Store
+                        } else {
+                            wgpu::StoreOp::Discard
+                        },
                     }),
                     stencil_ops: None,
                 }),
@@ -647,6 +732,21 @@ impl ViewBuilder {
             }
         }
 
+        if let Some(oit_processor) = &oit_processor {
+            re_tracing::profile_scope!("order independent transparency");
+            {
+                let mut pass = oit_processor.start_render_pass(&mut encoder, &setup.depth_buffer);
+                pass.set_bind_group(0, &setup.bind_group_0, &[]);
+                self.draw_phase(
+                    &renderers,
+                    &pipelines,
+                    DrawPhase::OrderIndependentTransparency,
+                    &mut pass,
+                );
+            }
+            oit_processor.composite(&pipelines, &mut encoder, &setup.main_target_resolved)?;
+        }
+
         if let Some(picking_processor) = &self.picking_processor {
             {
                 let mut pass = picking_processor.begin_render_pass(&setup.name, &mut encoder);
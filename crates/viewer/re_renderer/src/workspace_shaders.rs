@@ -61,6 +61,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/height_field.wgsl");
+        let content = include_str!("../shader/height_field.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/instanced_mesh.wgsl");
         let content = include_str!("../shader/instanced_mesh.wgsl").into();
@@ -79,6 +85,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/oit_composite.wgsl");
+        let content = include_str!("../shader/oit_composite.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/outlines/jumpflooding_init.wgsl");
         let content = include_str!("../shader/outlines/jumpflooding_init.wgsl").into();
@@ -151,12 +163,24 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/utils/albedo.wgsl");
+        let content = include_str!("../shader/utils/albedo.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/utils/camera.wgsl");
         let content = include_str!("../shader/utils/camera.wgsl").into();
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/utils/clipping.wgsl");
+        let content = include_str!("../shader/utils/clipping.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/utils/depth_offset.wgsl");
         let content = include_str!("../shader/utils/depth_offset.wgsl").into();
@@ -181,6 +205,18 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/utils/lens_distortion.wgsl");
+        let content = include_str!("../shader/utils/lens_distortion.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
+    {
+        let virtpath = Path::new("shader/utils/oit.wgsl");
+        let content = include_str!("../shader/utils/oit.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/utils/plane.wgsl");
         let content = include_str!("../shader/utils/plane.wgsl").into();
@@ -20,8 +20,8 @@ use itertools::Itertools;
 use re_math::IsoTransform;
 use re_renderer::{
     renderer::{
-        ColormappedTexture, DepthCloud, DepthCloudDrawData, DepthClouds, DrawData,
-        GenericSkyboxDrawData, RectangleDrawData, RectangleOptions, TexturedRect,
+        ColormappedTexture, DepthCloud, DepthCloudAlbedo, DepthCloudDrawData, DepthClouds,
+        DrawData, GenericSkyboxDrawData, RectangleDrawData, RectangleOptions, TexturedRect,
     },
     resource_managers::{GpuTexture2D, ImageDataDesc},
     view_builder::{self, Projection, ViewBuilder},
@@ -157,6 +157,7 @@ impl RenderDepthClouds {
     {
         let Self {
             depth,
+            albedo,
             scale,
             point_radius_from_world_depth,
             intrinsics,
@@ -171,12 +172,19 @@ impl RenderDepthClouds {
                 clouds: vec![DepthCloud {
                     world_from_rdf,
                     depth_camera_intrinsics: *intrinsics,
+                    lens_distortion: None,
                     world_depth_from_texture_depth: 1.0,
                     point_radius_from_world_depth: *point_radius_from_world_depth,
                     min_max_depth_in_world: [0.0, 5.0],
                     depth_dimensions: depth.dimensions,
                     depth_texture: depth.texture.clone(),
                     colormap: re_renderer::Colormap::Turbo,
+                    albedo: Some(DepthCloudAlbedo {
+                        texture: ColormappedTexture::from_unorm_rgba(albedo.texture.clone()),
+                        camera_intrinsics: *intrinsics,
+                        lens_distortion: None,
+                        albedo_rdf_from_depth_rdf: glam::Affine3A::IDENTITY,
+                    }),
                     outline_mask_id: Default::default(),
                     picking_object_id: Default::default(),
                 }],
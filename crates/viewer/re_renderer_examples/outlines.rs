@@ -117,6 +117,8 @@ impl framework::Example for Outlines {
                         outline_mask_ids: props.outline_mask_ids,
                         picking_layer_id: Default::default(),
                         additive_tint: Color32::TRANSPARENT,
+                        opacity: 1.0,
+                        lens_distortion: false,
                     })
             })
             .collect_vec();
@@ -179,7 +179,9 @@ impl framework::Example for Picking {
                 } else {
                     Color32::TRANSPARENT
                 },
+                opacity: 1.0,
                 outline_mask_ids: Default::default(),
+                lens_distortion: false,
             })
             .collect_vec();
 
@@ -0,0 +1,123 @@
+use re_log_types::EntityPath;
+use re_types::{ComponentDescriptor, ComponentName};
+use re_ui::{list_item, UiExt as _};
+use re_viewer_context::{UiLayout, ViewerContext};
+
+/// Lets the user write new values for the components of an entity of the active recording.
+///
+/// Edits are logged as a new row at the current time, so they can be undone and are saved
+/// together with the rest of the recording.
+///
+/// If `component_filter` is set, only that component is shown.
+pub fn edit_data_section_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entity_path: &EntityPath,
+    component_filter: Option<ComponentName>,
+) {
+    let (query, db) = re_data_ui::item_ui::guess_query_and_db_for_selected_entity(ctx, entity_path);
+    if db.store_id() != ctx.recording_id() {
+        // Blueprint data is edited through the view and visualizer UIs instead.
+        return;
+    }
+
+    let markdown = "# Edit data\n
+Writes a new value for a component of this entity into the recording, at the current time.
+
+Edits can be undone, and are kept when saving the recording. \
+Static data and data without a time cannot be edited.";
+
+    let components = {
+        let engine = db.storage_engine();
+        let Some(components) = engine
+            .store()
+            .all_components_on_timeline_sorted(&query.timeline(), entity_path)
+        else {
+            return;
+        };
+        components
+            .into_iter()
+            .filter(|component_name| !component_name.is_indicator_component())
+            .filter(|component_name| component_filter.map_or(true, |c| c == *component_name))
+            .collect::<Vec<_>>()
+    };
+    if components.is_empty() {
+        return;
+    }
+
+    ui.section_collapsing_header("Edit data")
+        .default_open(false)
+        .help_markdown(markdown)
+        .show(ui, |ui| {
+            let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+            let Some(time) = time_ctrl.time_int() else {
+                ui.list_item_flat_noninteractive(
+                    list_item::LabelContent::new("Select a time to edit data")
+                        .weak(true)
+                        .italics(true),
+                );
+                return;
+            };
+            let timeline = *time_ctrl.timeline();
+            drop(time_ctrl);
+
+            ui.list_item_flat_noninteractive(
+                list_item::PropertyContent::new("Writes to").value_text(format!(
+                    "{} = {}",
+                    timeline.name(),
+                    timeline.typ().format(time, ctx.app_options.time_zone)
+                )),
+            );
+
+            for component_name in components {
+                let results = db.latest_at(&query, entity_path, [component_name]);
+                let row_id = results.component_row_id(&component_name);
+                let Some(raw_current_value) = results.component_batch_raw(&component_name) else {
+                    continue;
+                };
+                let is_static = db
+                    .storage_engine()
+                    .store()
+                    .entity_has_static_component(entity_path, &component_name);
+
+                let value_fn = |ui: &mut egui::Ui, _style| {
+                    let allow_multiline = false;
+                    if is_static
+                        || !ctx.component_ui_registry.try_show_edit_ui_with(
+                            ctx,
+                            ui,
+                            raw_current_value.as_ref(),
+                            component_name,
+                            allow_multiline,
+                            |new_value| {
+                                ctx.edit_recording_array(
+                                    entity_path,
+                                    ComponentDescriptor::new(component_name),
+                                    new_value,
+                                );
+                            },
+                        )
+                    {
+                        ctx.component_ui_registry.ui_raw(
+                            ctx,
+                            ui,
+                            UiLayout::List,
+                            &query,
+                            db,
+                            entity_path,
+                            component_name,
+                            row_id,
+                            raw_current_value.as_ref(),
+                        );
+                    }
+                };
+
+                ui.push_id(component_name, |ui| {
+                    ui.list_item_flat_noninteractive(
+                        list_item::PropertyContent::new(component_name.short_name())
+                            .value_fn(value_fn),
+                    );
+                });
+            }
+        });
+}
@@ -1,9 +1,11 @@
 //! The UI for the selection panel.
 
 mod defaults_ui;
+mod edit_data_ui;
 mod item_heading_no_breadcrumbs;
 mod item_heading_with_breadcrumbs;
 mod item_title;
+mod recording_comparison_ui;
 mod selection_panel;
 mod view_entity_picker;
 mod view_space_origin_ui;
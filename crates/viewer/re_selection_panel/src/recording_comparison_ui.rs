@@ -0,0 +1,184 @@
+use egui::Ui;
+
+use re_entity_db::EntityDb;
+use re_log_types::{StoreId, StoreKind, TimeType};
+use re_types::blueprint::{
+    archetypes::RecordingComparison,
+    components::{ComparedRecording, TimeOffset},
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewClass, ViewerContext};
+use re_viewport_blueprint::{ViewBlueprint, ViewProperty};
+
+/// Shows which recording a view is compared against and how the times of both recordings line up.
+pub fn recording_comparison_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut Ui,
+    view: &ViewBlueprint,
+    view_class: &dyn ViewClass,
+) {
+    let markdown = if view_class.overlays_compared_recording() {
+        "# Compare recordings\n
+Draws the data of a second recording on top of the active recording, \
+for instance to compare a baseline run against a candidate run.
+
+The time offset is added to all times of the compared recording, so that both recordings share one time cursor."
+    } else {
+        "# Compare recordings\n
+Shows the data of a second recording instead of the active recording, \
+for instance to look at a baseline run and a candidate run side by side in two copies of this view.
+
+The time offset is added to all times of the compared recording, so that both recordings share one time cursor."
+    };
+
+    let property = ViewProperty::from_archetype::<RecordingComparison>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view.id,
+    );
+    let compared_recording = property
+        .component_or_empty::<ComparedRecording>()
+        .ok()
+        .flatten()
+        .map(|recording| StoreId::from_string(StoreKind::Recording, recording.as_str().to_owned()));
+    let time_offset = property
+        .component_or_empty::<TimeOffset>()
+        .ok()
+        .flatten()
+        .map_or(0, |offset| offset.0 .0);
+
+    ui.section_collapsing_header("Compare recordings")
+        .default_open(compared_recording.is_some())
+        .help_markdown(markdown)
+        .show(ui, |ui| {
+            // TODO(#6075): Because `list_item_scope` changes it. Temporary until everything is `ListItem`.
+            ui.spacing_mut().item_spacing.y = ui.ctx().style().spacing.item_spacing.y;
+
+            egui::Grid::new("recording_comparison")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.grid_left_hand_label("Recording");
+                    compared_recording_ui(ctx, ui, &property, compared_recording.as_ref());
+                    ui.end_row();
+
+                    let compared_db = compared_recording
+                        .as_ref()
+                        .and_then(|store_id| ctx.store_context.bundle.get(store_id));
+
+                    ui.grid_left_hand_label("Time offset");
+                    ui.add_enabled_ui(compared_db.is_some(), |ui| {
+                        ui.horizontal(|ui| {
+                            time_offset_ui(ctx, ui, &property, compared_db, time_offset);
+                        });
+                    });
+                    ui.end_row();
+                });
+        });
+}
+
+fn compared_recording_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut Ui,
+    property: &ViewProperty,
+    compared_recording: Option<&StoreId>,
+) {
+    let active_recording = ctx.recording_id();
+    let selected_text = match compared_recording {
+        None => "None".to_owned(),
+        Some(store_id) => match ctx.store_context.bundle.get(store_id) {
+            Some(entity_db) => recording_label(ctx, entity_db),
+            None => format!("{store_id} (not loaded)"),
+        },
+    };
+
+    let mut new_recording = compared_recording.cloned();
+    egui::ComboBox::from_id_salt("compared_recording")
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut new_recording, None, "None");
+            for entity_db in ctx.store_context.bundle.recordings() {
+                if entity_db.store_id() == active_recording {
+                    continue;
+                }
+                ui.selectable_value(
+                    &mut new_recording,
+                    Some(entity_db.store_id()),
+                    recording_label(ctx, entity_db),
+                );
+            }
+        });
+
+    if new_recording.as_ref() != compared_recording {
+        if let Some(store_id) = new_recording {
+            property.save_blueprint_component(ctx, &ComparedRecording::from(store_id.as_str()));
+        } else {
+            property.clear_blueprint_component::<ComparedRecording>(ctx);
+        }
+    }
+}
+
+fn time_offset_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut Ui,
+    property: &ViewProperty,
+    compared_db: Option<&EntityDb>,
+    time_offset: i64,
+) {
+    let timeline = *ctx.rec_cfg.time_ctrl.read().timeline();
+
+    let mut new_time_offset = time_offset;
+    match timeline.typ() {
+        TimeType::Sequence => {
+            ui.add(egui::DragValue::new(&mut new_time_offset).speed(1.0));
+        }
+        TimeType::Time => {
+            let mut seconds = time_offset as f64 * 1e-9;
+            if ui
+                .add(egui::DragValue::new(&mut seconds).speed(0.01).suffix(" s"))
+                .changed()
+            {
+                new_time_offset = (seconds * 1e9).round() as i64;
+            }
+        }
+    }
+
+    let first_times = compared_db.and_then(|compared_db| {
+        Some((
+            ctx.recording().time_range_for(&timeline)?.min(),
+            compared_db.time_range_for(&timeline)?.min(),
+        ))
+    });
+    if ui
+        .add_enabled(first_times.is_some(), egui::Button::new("Align starts"))
+        .on_hover_text(format!(
+            "Line up the first data of both recordings on the '{}' timeline",
+            timeline.name()
+        ))
+        .clicked()
+    {
+        if let Some((active_start, compared_start)) = first_times {
+            new_time_offset = active_start
+                .as_i64()
+                .saturating_sub(compared_start.as_i64());
+        }
+    }
+
+    if new_time_offset != time_offset {
+        property.save_blueprint_component(ctx, &TimeOffset::from(new_time_offset));
+    }
+}
+
+fn recording_label(ctx: &ViewerContext<'_>, entity_db: &EntityDb) -> String {
+    let app_id = entity_db
+        .app_id()
+        .map_or(String::default(), |app_id| format!("{app_id} - "));
+    let creation_time = entity_db
+        .store_info()
+        .and_then(|info| {
+            info.started
+                .format_time_custom("[hour]:[minute]:[second]", ctx.app_options.time_zone)
+        })
+        .unwrap_or("<unknown time>".to_owned());
+
+    format!("{app_id}{creation_time}")
+}
@@ -22,8 +22,10 @@ use re_viewport_blueprint::{ui::show_add_view_or_container_modal, ViewportBluepr
 
 use crate::{
     defaults_ui::view_components_defaults_section_ui,
+    edit_data_ui::edit_data_section_ui,
     item_heading_no_breadcrumbs::item_title_list_item,
     item_heading_with_breadcrumbs::item_heading_with_breadcrumbs,
+    recording_comparison_ui::recording_comparison_ui,
     view_entity_picker::ViewEntityPicker,
     visible_time_range_ui::{
         visible_time_range_ui_for_data_result, visible_time_range_ui_for_view,
@@ -296,6 +298,25 @@ impl SelectionPanel {
             });
         }
 
+        match item {
+            Item::ComponentPath(component_path) => {
+                edit_data_section_ui(
+                    ctx,
+                    ui,
+                    &component_path.entity_path,
+                    Some(component_path.component_name),
+                );
+            }
+
+            Item::InstancePath(instance_path) | Item::DataResult(_, instance_path)
+                if instance_path.is_all() =>
+            {
+                edit_data_section_ui(ctx, ui, &instance_path.entity_path, None);
+            }
+
+            _ => {}
+        }
+
         match item {
             Item::View(view_id) => {
                 self.view_selection_ui(ctx, ui, viewport, view_id, view_states);
@@ -428,6 +449,8 @@ The last rule matching `/world/house` is `+ /world/**`, so it is included.
             view_components_defaults_section_ui(&view_ctx, ui, view);
 
             visible_time_range_ui_for_view(ctx, ui, view, view_class, view_state);
+
+            recording_comparison_ui(ctx, ui, view, view_class);
         }
     }
 }
@@ -11,9 +11,12 @@ mod time_axis;
 mod time_control_ui;
 mod time_panel;
 mod time_ranges_ui;
+mod time_search;
 mod time_selection_ui;
+mod timeline_markers;
 
 pub use time_panel::TimePanel;
+pub use timeline_markers::{marker_times, next_marker, previous_marker};
 
 #[doc(hidden)]
 pub mod __bench {
@@ -28,7 +28,8 @@ use crate::{
     time_axis::TimelineAxis,
     time_control_ui::TimeControlUi,
     time_ranges_ui::TimeRangesUi,
-    {data_density_graph, paint_ticks, time_ranges_ui, time_selection_ui},
+    time_search::TimeSearch,
+    {data_density_graph, paint_ticks, time_ranges_ui, time_selection_ui, timeline_markers},
 };
 
 #[derive(Debug, Clone)]
@@ -137,6 +138,10 @@ pub struct TimePanel {
     /// everytime the user clicks on an item _without_ holding shift.
     #[serde(skip)]
     range_selection_anchor_item: Option<Item>,
+
+    /// Searching for the times at which a condition on a component holds.
+    #[serde(skip)]
+    time_search: TimeSearch,
 }
 
 impl Default for TimePanel {
@@ -153,6 +158,7 @@ impl Default for TimePanel {
             filter_state: Default::default(),
             filter_state_app_id: None,
             range_selection_anchor_item: None,
+            time_search: Default::default(),
         }
     }
 }
@@ -470,10 +476,27 @@ impl TimePanel {
             Rect::from_x_y_ranges(time_fg_x_range, top..=bottom)
         };
 
-        let streams_rect = Rect::from_x_y_ranges(
-            time_fg_x_range,
-            timeline_rect.bottom()..=ui.max_rect().bottom(),
-        );
+        // Events & bookmarks get their own lane between the time ticks and the streams.
+        let markers_rect = (self.source == TimePanelSource::Recording).then(|| {
+            let top = ui.min_rect().bottom();
+
+            let size = egui::vec2(self.prev_col_width, timeline_markers::MARKERS_LANE_HEIGHT);
+            ui.allocate_ui_with_layout(
+                size,
+                egui::Layout::left_to_right(egui::Align::Center),
+                |ui| {
+                    ui.set_min_size(size);
+                    timeline_markers::markers_lane_header_ui(ctx, ui, time_ctrl);
+                },
+            );
+
+            let bottom = ui.min_rect().bottom();
+            Rect::from_x_y_ranges(time_fg_x_range, top..=bottom)
+        });
+        let streams_top = markers_rect.map_or(timeline_rect.bottom(), |rect| rect.bottom());
+
+        let streams_rect =
+            Rect::from_x_y_ranges(time_fg_x_range, streams_top..=ui.max_rect().bottom());
 
         // includes the timeline and streams areas.
         let time_bg_area_rect = Rect::from_x_y_ranges(time_bg_x_range, full_y_range);
@@ -490,11 +513,28 @@ impl TimePanel {
             );
         }
 
+        for &matched_range in self.time_search.matches() {
+            paint_search_match(
+                matched_range,
+                &self.time_ranges_ui,
+                ui,
+                &time_bg_area_painter,
+                time_fg_area_rect,
+            );
+        }
+
         ui.painter().hline(
             0.0..=ui.max_rect().right(),
             timeline_rect.bottom(),
             ui.visuals().widgets.noninteractive.bg_stroke,
         );
+        if let Some(markers_rect) = markers_rect {
+            ui.painter().hline(
+                0.0..=ui.max_rect().right(),
+                markers_rect.bottom(),
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+        }
 
         paint_ticks::paint_time_ranges_and_ticks(
             &self.time_ranges_ui,
@@ -567,6 +607,18 @@ impl TimePanel {
             ui.draw_shadow_line(rect, egui::Direction::LeftToRight);
         }
 
+        if let Some(markers_rect) = markers_rect {
+            timeline_markers::markers_ui(
+                ctx,
+                entity_db,
+                &self.time_ranges_ui,
+                time_ctrl,
+                ui,
+                &time_area_painter,
+                markers_rect,
+            );
+        }
+
         // Put time-marker on top and last, so that you can always drag it
         time_marker_ui(
             &self.time_ranges_ui,
@@ -1126,7 +1178,7 @@ impl TimePanel {
     }
 
     fn top_row_ui(
-        &self,
+        &mut self,
         ctx: &ViewerContext<'_>,
         entity_db: &re_entity_db::EntityDb,
         ui: &mut egui::Ui,
@@ -1153,6 +1205,10 @@ impl TimePanel {
 
                     current_time_ui(ctx, ui, time_ctrl);
 
+                    if self.source == TimePanelSource::Recording {
+                        self.time_search.ui(entity_db, ui, time_ctrl);
+                    }
+
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         help_button(ui);
                     });
@@ -1170,6 +1226,10 @@ impl TimePanel {
             self.time_control_ui.fps_ui(time_ctrl, ui);
             current_time_ui(ctx, ui, time_ctrl);
 
+            if self.source == TimePanelSource::Recording {
+                self.time_search.ui(entity_db, ui, time_ctrl);
+            }
+
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 help_button(ui);
             });
@@ -1319,6 +1379,29 @@ fn paint_range_highlight(
     }
 }
 
+/// Tints a range matched by the time search, keeping short matches visible as thin bars.
+fn paint_search_match(
+    matched_range: ResolvedTimeRange,
+    time_ranges_ui: &TimeRangesUi,
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    rect: Rect,
+) {
+    let x_from = time_ranges_ui.x_from_time_f32(matched_range.min().into());
+    let x_to = time_ranges_ui.x_from_time_f32(matched_range.max().into());
+
+    if let (Some(x_from), Some(x_to)) = (x_from, x_to) {
+        let x_to = x_to.at_least(x_from + 2.0);
+        let matched_rect = Rect::from_x_y_ranges(x_from..=x_to, rect.y_range()).intersect(rect);
+
+        painter.rect_filled(
+            matched_rect,
+            0.0,
+            ui.visuals().selection.bg_fill.gamma_multiply(0.2),
+        );
+    }
+}
+
 fn help_button(ui: &mut egui::Ui) {
     // TODO(andreas): Nicer help text like on views.
     ui.help_hover_button().on_hover_text(
@@ -1329,7 +1412,13 @@ fn help_button(ui: &mut egui::Ui) {
         Zoom: Ctrl/cmd + scroll, or drag up/down with secondary mouse button.\n\
         Double-click to reset view.\n\
         \n\
-        Press the space bar to play/pause.",
+        Press the space bar to play/pause.\n\
+        \n\
+        Events and bookmarks are shown above the streams, \
+        press shift + left/right arrow to jump between them.\n\
+        \n\
+        Use the search box to find the times at which a condition holds, e.g. `/battery:Scalar < 20`. \
+        Press enter to jump to the next match.",
     );
 }
 
@@ -0,0 +1,774 @@
+//! Searching the current timeline for the times at which a condition on a component holds.
+//!
+//! A query has the form `<entity path>[:<component>] <condition>`, for instance:
+//! * `/battery:Scalar < 20`
+//! * `/detections is empty`
+//! * `/log contains "error"`
+//!
+//! Conditions are evaluated with latest-at semantics: a value matches from the time it was logged
+//! until the next value is logged on the same entity & component.
+
+use std::collections::BTreeMap;
+
+use egui::ahash::{HashMap, HashSet};
+use itertools::{izip, Itertools as _};
+
+use re_chunk_store::external::arrow::{
+    array::{Array, AsArray as _},
+    compute::cast,
+    datatypes::{DataType, Float64Type},
+};
+use re_chunk_store::{Chunk, ChunkId, ChunkStore, ChunkStoreGeneration, RangeQuery, RowId};
+use re_entity_db::EntityDb;
+use re_log_types::{DataPath, EntityPath, ResolvedTimeRange, StoreId, TimeInt, Timeline};
+use re_types::components::{Scalar, Text};
+use re_types_core::{Component as _, ComponentName};
+use re_ui::UiExt as _;
+use re_viewer_context::TimeControl;
+
+use crate::timeline_markers::{next_marker, previous_marker};
+
+/// How a scalar is compared against the value of a query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    /// All comparisons, longer operators first so that they take precedence when parsing.
+    const ALL: [(&'static str, Self); 7] = [
+        ("<=", Self::LessOrEqual),
+        (">=", Self::GreaterOrEqual),
+        ("==", Self::Equal),
+        ("!=", Self::NotEqual),
+        ("<", Self::Less),
+        (">", Self::Greater),
+        ("=", Self::Equal),
+    ];
+
+    fn holds(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Self::Less => lhs < rhs,
+            Self::LessOrEqual => lhs <= rhs,
+            Self::Greater => lhs > rhs,
+            Self::GreaterOrEqual => lhs >= rhs,
+            Self::Equal => lhs == rhs,
+            Self::NotEqual => lhs != rhs,
+        }
+    }
+}
+
+/// The condition a component has to fulfill for a time to match.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Condition {
+    /// Any instance of a numeric component compares true against the value.
+    Compare(Comparison, f64),
+
+    /// Any instance of a text component contains the text, ignoring case.
+    Contains(String),
+
+    /// The component was logged without any instances, or with some if `false`.
+    IsEmpty(bool),
+}
+
+/// A parsed time search query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchQuery {
+    pub entity_path: EntityPath,
+
+    /// The component to evaluate the condition on.
+    ///
+    /// If `None`, the component is picked based on the condition.
+    pub component_name: Option<ComponentName>,
+
+    pub condition: Condition,
+}
+
+impl SearchQuery {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let query = query.trim();
+
+        let path_len = query
+            .find(|c: char| c.is_whitespace() || "<>=!".contains(c))
+            .unwrap_or(query.len());
+        let (path, condition) = query.split_at(path_len);
+
+        let data_path: DataPath = path.parse().map_err(|err| format!("{err}"))?;
+        if data_path.instance.is_some() {
+            return Err("Instances can't be searched for, remove the `[#…]`".to_owned());
+        }
+
+        Ok(Self {
+            entity_path: data_path.entity_path,
+            component_name: data_path.component_name,
+            condition: parse_condition(condition.trim())?,
+        })
+    }
+}
+
+fn parse_condition(condition: &str) -> Result<Condition, String> {
+    if condition.is_empty() {
+        return Err("Missing condition, e.g. `< 20`, `is empty` or `contains text`".to_owned());
+    }
+
+    for (operator, comparison) in Comparison::ALL {
+        if let Some(value) = condition.strip_prefix(operator) {
+            let value = value.trim();
+            return value
+                .parse()
+                .map(|value| Condition::Compare(comparison, value))
+                .map_err(|_err| format!("Expected a number after `{operator}`, got {value:?}"));
+        }
+    }
+
+    let lowercase = condition.to_lowercase();
+    let words: Vec<&str> = lowercase.split_whitespace().collect();
+    match words.as_slice() {
+        ["is", "empty"] => return Ok(Condition::IsEmpty(true)),
+        ["is", "not", "empty"] => return Ok(Condition::IsEmpty(false)),
+        _ => {}
+    }
+
+    if let Some(text) = strip_prefix_ignore_case(condition, "contains") {
+        let text = text.trim();
+        let text = ['"', '\'']
+            .iter()
+            .find_map(|&quote| {
+                text.strip_prefix(quote)
+                    .and_then(|text| text.strip_suffix(quote))
+            })
+            .unwrap_or(text);
+        if text.is_empty() {
+            return Err("Expected a text after `contains`".to_owned());
+        }
+        return Ok(Condition::Contains(text.to_lowercase()));
+    }
+
+    Err(format!(
+        "Unknown condition {condition:?}, expected a comparison, `is empty`, `is not empty` or `contains`"
+    ))
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &text[prefix.len()..])
+}
+
+// ----------------------------------------------------------------------------
+
+/// Evaluates the query on the given timeline and returns the time ranges during which it holds.
+pub fn search_timeline(
+    entity_db: &EntityDb,
+    timeline: &Timeline,
+    query: &SearchQuery,
+) -> Result<Vec<ResolvedTimeRange>, String> {
+    TimelineSearch::new(query.clone(), *timeline).update(entity_db)
+}
+
+/// The components to evaluate the condition of `query` on.
+fn searched_components(
+    store: &ChunkStore,
+    timeline: &Timeline,
+    query: &SearchQuery,
+) -> Result<Vec<ComponentName>, String> {
+    let components = store
+        .all_components_on_timeline_sorted(timeline, &query.entity_path)
+        .ok_or_else(|| {
+            format!(
+                "{} has no data on the '{}' timeline",
+                query.entity_path,
+                timeline.name()
+            )
+        })?;
+    let components: Vec<ComponentName> = components
+        .into_iter()
+        .filter(|component_name| !component_name.is_indicator_component())
+        .collect();
+
+    if let Some(component_name) = query.component_name {
+        let component_name = components
+            .iter()
+            .find(|name| **name == component_name)
+            .or_else(|| {
+                components
+                    .iter()
+                    .find(|name| name.short_name() == component_name.short_name())
+            })
+            .copied()
+            .ok_or_else(|| {
+                format!(
+                    "{} has no {} component",
+                    query.entity_path,
+                    component_name.short_name()
+                )
+            })?;
+        return Ok(vec![component_name]);
+    }
+
+    let (kind, preferred) = match &query.condition {
+        // An entity is empty if all its components are empty.
+        Condition::IsEmpty(_) => return Ok(components),
+        Condition::Compare(..) => ("numeric", Scalar::name()),
+        Condition::Contains(_) => ("text", Text::name()),
+    };
+    let is_searchable = |component_name: &ComponentName| {
+        let datatype = store.lookup_datatype(component_name);
+        match &query.condition {
+            Condition::Compare(..) => datatype.is_some_and(|datatype| datatype.is_numeric()),
+            Condition::Contains(_) => {
+                matches!(datatype, Some(DataType::Utf8 | DataType::LargeUtf8))
+            }
+            Condition::IsEmpty(_) => true,
+        }
+    };
+
+    let candidates: Vec<ComponentName> = components.into_iter().filter(is_searchable).collect();
+    if candidates.contains(&preferred) {
+        return Ok(vec![preferred]);
+    }
+    match candidates.as_slice() {
+        [component_name] => Ok(vec![*component_name]),
+        [] => Err(format!(
+            "{} has no {kind} component to search, specify one with `{}:<component>`",
+            query.entity_path, query.entity_path
+        )),
+        _ => Err(format!(
+            "{} has several {kind} components ({}), specify one with `{}:<component>`",
+            query.entity_path,
+            candidates
+                .iter()
+                .map(|component_name| component_name.short_name())
+                .join(", "),
+            query.entity_path
+        )),
+    }
+}
+
+/// Whether the condition held at each row of a single component.
+#[derive(Default)]
+struct ComponentRows {
+    /// For [`Condition::IsEmpty`], whether the row has no instances.
+    /// Otherwise, whether any instance of the row fulfills the condition.
+    rows: BTreeMap<(TimeInt, RowId), bool>,
+
+    /// The rows of every chunk that was evaluated, so that they can be dropped with the chunk.
+    rows_per_chunk: HashMap<ChunkId, Vec<(TimeInt, RowId)>>,
+}
+
+impl ComponentRows {
+    /// Evaluates the chunks that weren't evaluated before, and forgets about rows of chunks that are gone.
+    ///
+    /// Chunks disappear when they are garbage collected, or compacted into a new chunk.
+    fn update(
+        &mut self,
+        chunks: &[Chunk],
+        timeline: &Timeline,
+        component_name: &ComponentName,
+        condition: &Condition,
+    ) -> Result<(), String> {
+        let chunk_ids: HashSet<ChunkId> = chunks.iter().map(|chunk| chunk.id()).collect();
+        let Self {
+            rows,
+            rows_per_chunk,
+        } = self;
+        rows_per_chunk.retain(|chunk_id, chunk_rows| {
+            let keep = chunk_ids.contains(chunk_id);
+            if !keep {
+                for index in chunk_rows {
+                    rows.remove(index);
+                }
+            }
+            keep
+        });
+
+        for chunk in chunks {
+            if rows_per_chunk.contains_key(&chunk.id()) {
+                continue;
+            }
+            let chunk_rows = evaluate_chunk(chunk, timeline, component_name, condition, rows)?;
+            rows_per_chunk.insert(chunk.id(), chunk_rows);
+        }
+
+        Ok(())
+    }
+}
+
+/// A search of a single timeline that only evaluates data that arrived since its last update.
+pub struct TimelineSearch {
+    query: SearchQuery,
+    timeline: Timeline,
+    components: Vec<(ComponentName, ComponentRows)>,
+}
+
+impl TimelineSearch {
+    pub fn new(query: SearchQuery, timeline: Timeline) -> Self {
+        Self {
+            query,
+            timeline,
+            components: Vec::new(),
+        }
+    }
+
+    /// Evaluates the query on all new data and returns the time ranges during which it holds.
+    pub fn update(&mut self, entity_db: &EntityDb) -> Result<Vec<ResolvedTimeRange>, String> {
+        re_tracing::profile_function!();
+
+        let engine = entity_db.storage_engine();
+        let component_names = searched_components(engine.store(), &self.timeline, &self.query)?;
+        if !itertools::equal(
+            self.components
+                .iter()
+                .map(|(component_name, _)| component_name),
+            &component_names,
+        ) {
+            self.components = component_names
+                .iter()
+                .map(|component_name| (*component_name, ComponentRows::default()))
+                .collect();
+        }
+
+        let results = engine.cache().range(
+            &RangeQuery::new(self.timeline, ResolvedTimeRange::EVERYTHING),
+            &self.query.entity_path,
+            component_names.iter().copied(),
+        );
+        for (component_name, component_rows) in &mut self.components {
+            component_rows.update(
+                results.get(component_name).unwrap_or_default(),
+                &self.timeline,
+                component_name,
+                &self.query.condition,
+            )?;
+        }
+
+        // The last row at each time decides whether that time matches.
+        // An entity is only empty if all its components are.
+        let mut rows: BTreeMap<(TimeInt, RowId), bool> = BTreeMap::new();
+        for (_, component_rows) in &self.components {
+            for (index, value) in &component_rows.rows {
+                *rows.entry(*index).or_insert(true) &= *value;
+            }
+        }
+
+        let mut times: Vec<(TimeInt, bool)> = Vec::with_capacity(rows.len());
+        for ((time, _row_id), value) in rows {
+            if time.is_static() {
+                continue;
+            }
+            let is_match = match self.query.condition {
+                Condition::IsEmpty(empty) => value == empty,
+                Condition::Compare(..) | Condition::Contains(_) => value,
+            };
+            match times.last_mut() {
+                Some((last_time, last_match)) if *last_time == time => *last_match = is_match,
+                _ => times.push((time, is_match)),
+            }
+        }
+
+        let end = entity_db
+            .time_range_for(&self.timeline)
+            .map_or(TimeInt::MAX, |range| range.max());
+        Ok(matching_ranges(&times, end))
+    }
+}
+
+/// Evaluates every row of a component in a chunk into `rows`, and returns the evaluated rows.
+///
+/// The values are cast once for the whole chunk.
+fn evaluate_chunk(
+    chunk: &Chunk,
+    timeline: &Timeline,
+    component_name: &ComponentName,
+    condition: &Condition,
+    rows: &mut BTreeMap<(TimeInt, RowId), bool>,
+) -> Result<Vec<(TimeInt, RowId)>, String> {
+    let indices = chunk.iter_component_indices(timeline, component_name);
+    let offsets = chunk.iter_component_offsets(component_name);
+
+    let datatype = match condition {
+        Condition::IsEmpty(_) => {
+            let mut chunk_rows = Vec::new();
+            for (index, (_offset, len)) in izip!(indices, offsets) {
+                rows.insert(index, len == 0);
+                chunk_rows.push(index);
+            }
+            return Ok(chunk_rows);
+        }
+        Condition::Compare(..) => DataType::Float64,
+        Condition::Contains(_) => DataType::Utf8,
+    };
+
+    let Some(list_array) = chunk.get_first_component(component_name) else {
+        return Ok(Vec::new());
+    };
+    let values = cast(list_array.values(), &datatype).map_err(|_err| {
+        format!(
+            "{} can't be searched as {}",
+            component_name.short_name(),
+            if datatype == DataType::Utf8 {
+                "text"
+            } else {
+                "a number"
+            }
+        )
+    })?;
+
+    let row_matches = |offset: usize, len: usize| match condition {
+        Condition::Compare(comparison, value) => {
+            let values = values.as_primitive::<Float64Type>();
+            (offset..offset + len)
+                .any(|i| values.is_valid(i) && comparison.holds(values.value(i), *value))
+        }
+        Condition::Contains(text) => {
+            let values = values.as_string::<i32>();
+            (offset..offset + len)
+                .any(|i| values.is_valid(i) && values.value(i).to_lowercase().contains(text))
+        }
+        Condition::IsEmpty(_) => unreachable!("handled above"),
+    };
+
+    let mut chunk_rows = Vec::new();
+    for (index, (offset, len)) in izip!(indices, offsets) {
+        rows.insert(index, row_matches(offset, len));
+        chunk_rows.push(index);
+    }
+    Ok(chunk_rows)
+}
+
+/// Turns the sorted times at which a condition was evaluated into the ranges during which it held.
+///
+/// A match lasts until the next time at which the condition doesn't hold, or until `end`.
+pub fn matching_ranges(times: &[(TimeInt, bool)], end: TimeInt) -> Vec<ResolvedTimeRange> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+
+    for &(time, is_match) in times {
+        match (start, is_match) {
+            (None, true) => start = Some(time),
+            (Some(range_start), false) => {
+                ranges.push(ResolvedTimeRange::new(
+                    range_start,
+                    time.as_i64().saturating_sub(1),
+                ));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(range_start) = start {
+        ranges.push(ResolvedTimeRange::new(range_start, end.max(range_start)));
+    }
+
+    ranges
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, PartialEq, Eq)]
+struct CacheKey {
+    query: String,
+    store_id: StoreId,
+    timeline: Timeline,
+}
+
+/// The search of the current query, and its result for the last store generation it saw.
+struct CachedSearch {
+    key: CacheKey,
+
+    /// `None` if the query couldn't be parsed.
+    search: Option<TimelineSearch>,
+
+    /// The generation of the recording the result is up to date with.
+    generation: Option<ChunkStoreGeneration>,
+
+    result: Result<Vec<ResolvedTimeRange>, String>,
+}
+
+/// The search box of the time panel, together with the ranges it matched.
+#[derive(Default)]
+pub struct TimeSearch {
+    query: String,
+
+    /// The last search, updated with new data when the recording changes,
+    /// and restarted when the query, recording or timeline changes.
+    cache: Option<CachedSearch>,
+}
+
+impl TimeSearch {
+    /// The ranges matched by the current query, if any.
+    pub fn matches(&self) -> &[ResolvedTimeRange] {
+        match self.cache.as_ref().map(|cached| &cached.result) {
+            Some(Ok(ranges)) => ranges,
+            Some(Err(_)) | None => &[],
+        }
+    }
+
+    fn update(&mut self, entity_db: &EntityDb, timeline: &Timeline) {
+        if self.query.trim().is_empty() {
+            self.cache = None;
+            return;
+        }
+
+        let key = CacheKey {
+            query: self.query.clone(),
+            store_id: entity_db.store_id(),
+            timeline: *timeline,
+        };
+        let generation = entity_db.generation();
+
+        if self.cache.as_ref().map(|cached| &cached.key) != Some(&key) {
+            let search =
+                SearchQuery::parse(&self.query).map(|query| TimelineSearch::new(query, *timeline));
+            self.cache = Some(CachedSearch {
+                key,
+                generation: None,
+                result: search.as_ref().map(|_| Vec::new()).map_err(Clone::clone),
+                search: search.ok(),
+            });
+        }
+        let Some(cached) = &mut self.cache else {
+            return;
+        };
+        if cached.generation.as_ref() == Some(&generation) {
+            return;
+        }
+
+        if let Some(search) = &mut cached.search {
+            cached.result = search.update(entity_db);
+        }
+        cached.generation = Some(generation);
+    }
+
+    /// Shows the search box and the buttons to step between matches.
+    ///
+    /// Pressing enter in the search box jumps to the next match, shift + enter to the previous one.
+    pub fn ui(&mut self, entity_db: &EntityDb, ui: &mut egui::Ui, time_ctrl: &mut TimeControl) {
+        let response = ui
+            .add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text("/battery:Scalar < 20")
+                    .desired_width(160.0),
+            )
+            .on_hover_text(
+                "Search for the times at which a condition holds, for instance:\n\
+            • /battery:Scalar < 20\n\
+            • /detections is empty\n\
+            • /log contains \"error\"\n\
+            \n\
+            Comparisons: <, <=, >, >=, ==, !=\n\
+            Without a component, the Scalar or Text component of the entity is used,\n\
+            or its only numeric or text component.",
+            );
+
+        self.update(entity_db, time_ctrl.timeline());
+
+        let starts: Vec<TimeInt> = self.matches().iter().map(|range| range.min()).collect();
+        let current_time = time_ctrl.time_int().unwrap_or(TimeInt::MIN);
+        let mut step_to = None;
+
+        let enter_pressed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if enter_pressed {
+            step_to = if ui.input(|i| i.modifiers.shift) {
+                previous_marker(&starts, current_time)
+            } else {
+                next_marker(&starts, current_time)
+            };
+            response.request_focus();
+        }
+
+        if self.cache.is_some() {
+            if ui
+                .add_enabled(
+                    previous_marker(&starts, current_time).is_some(),
+                    ui.small_icon_button_widget(&re_ui::icons::ARROW_LEFT),
+                )
+                .on_hover_text("Previous match (shift + enter)")
+                .clicked()
+            {
+                step_to = previous_marker(&starts, current_time);
+            }
+            if ui
+                .add_enabled(
+                    next_marker(&starts, current_time).is_some(),
+                    ui.small_icon_button_widget(&re_ui::icons::ARROW_RIGHT),
+                )
+                .on_hover_text("Next match (enter)")
+                .clicked()
+            {
+                step_to = next_marker(&starts, current_time);
+            }
+        }
+
+        match self.cache.as_ref().map(|cached| &cached.result) {
+            Some(Ok(ranges)) => {
+                ui.weak(match ranges.len() {
+                    1 => "1 match".to_owned(),
+                    num_matches => format!("{} matches", re_format::format_uint(num_matches)),
+                });
+            }
+            Some(Err(err)) => {
+                ui.error_with_details_on_hover(err);
+            }
+            None => {}
+        }
+
+        if let Some(time) = step_to {
+            time_ctrl.set_time(time);
+            time_ctrl.pause();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_queries() {
+        assert_eq!(
+            SearchQuery::parse("/battery:Scalar < 20"),
+            Ok(SearchQuery {
+                entity_path: EntityPath::from("battery"),
+                component_name: Some("rerun.components.Scalar".into()),
+                condition: Condition::Compare(Comparison::Less, 20.0),
+            })
+        );
+        assert_eq!(
+            SearchQuery::parse("battery>=-1.5"),
+            Ok(SearchQuery {
+                entity_path: EntityPath::from("battery"),
+                component_name: None,
+                condition: Condition::Compare(Comparison::GreaterOrEqual, -1.5),
+            })
+        );
+        assert_eq!(
+            SearchQuery::parse("/detections is empty").map(|query| query.condition),
+            Ok(Condition::IsEmpty(true))
+        );
+        assert_eq!(
+            SearchQuery::parse("/detections Is Not Empty").map(|query| query.condition),
+            Ok(Condition::IsEmpty(false))
+        );
+        assert_eq!(
+            SearchQuery::parse("/log:Text contains \"Lost Track\"").map(|query| query.condition),
+            Ok(Condition::Contains("lost track".to_owned()))
+        );
+
+        assert!(SearchQuery::parse("/battery").is_err());
+        assert!(SearchQuery::parse("/battery < low").is_err());
+        assert!(SearchQuery::parse("/battery[#1] < 20").is_err());
+        assert!(SearchQuery::parse("/log contains").is_err());
+        assert!(SearchQuery::parse("/log matches x").is_err());
+    }
+
+    #[test]
+    fn ranges_follow_latest_at_semantics() {
+        let t = TimeInt::new_temporal;
+        let times = [
+            (t(10), false),
+            (t(20), true),
+            (t(25), true),
+            (t(30), false),
+            (t(40), true),
+        ];
+
+        assert_eq!(
+            matching_ranges(&times, t(100)),
+            vec![
+                ResolvedTimeRange::new(t(20), t(29)),
+                ResolvedTimeRange::new(t(40), t(100)),
+            ]
+        );
+        assert_eq!(matching_ranges(&times[..1], t(100)), vec![]);
+        assert_eq!(matching_ranges(&[], t(100)), vec![]);
+    }
+
+    fn log(
+        entity_db: &mut EntityDb,
+        entity_path: &str,
+        frame: i64,
+        components: &[&dyn re_types_core::ComponentBatch],
+    ) {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_component_batches(
+                RowId::new(),
+                [(Timeline::new_sequence("frame"), frame)],
+                components.iter().copied(),
+            )
+            .build()
+            .unwrap();
+        entity_db.add_chunk(&std::sync::Arc::new(chunk)).unwrap();
+    }
+
+    #[test]
+    fn scalar_is_preferred_and_ambiguity_is_an_error() {
+        use re_types::components::{Color, Radius};
+
+        let mut entity_db = EntityDb::new(StoreId::random(re_log_types::StoreKind::Recording));
+        let timeline = Timeline::new_sequence("frame");
+        log(
+            &mut entity_db,
+            "battery",
+            10,
+            &[&Color::from_rgb(255, 0, 0), &Scalar::from(10.0)],
+        );
+        log(
+            &mut entity_db,
+            "radar",
+            10,
+            &[&Color::from_rgb(255, 0, 0), &Radius::from(10.0_f32)],
+        );
+
+        let search = |query: &str| {
+            search_timeline(&entity_db, &timeline, &SearchQuery::parse(query).unwrap())
+        };
+        assert_eq!(
+            search("/battery < 20"),
+            Ok(vec![ResolvedTimeRange::new(
+                TimeInt::new_temporal(10),
+                TimeInt::new_temporal(10)
+            )])
+        );
+        assert!(search("/radar < 20")
+            .unwrap_err()
+            .contains("several numeric components"));
+        assert_eq!(search("/radar:Radius > 20"), Ok(vec![]));
+    }
+
+    #[test]
+    fn search_only_evaluates_new_data() {
+        let mut entity_db = EntityDb::new(StoreId::random(re_log_types::StoreKind::Recording));
+        let timeline = Timeline::new_sequence("frame");
+        let t = TimeInt::new_temporal;
+        log(&mut entity_db, "battery", 10, &[&Scalar::from(10.0)]);
+        log(&mut entity_db, "battery", 20, &[&Scalar::from(30.0)]);
+
+        let mut search =
+            TimelineSearch::new(SearchQuery::parse("/battery < 20").unwrap(), timeline);
+        assert_eq!(
+            search.update(&entity_db),
+            Ok(vec![ResolvedTimeRange::new(t(10), t(19))])
+        );
+
+        log(&mut entity_db, "battery", 30, &[&Scalar::from(5.0)]);
+        assert_eq!(
+            search.update(&entity_db),
+            Ok(vec![
+                ResolvedTimeRange::new(t(10), t(19)),
+                ResolvedTimeRange::new(t(30), t(30)),
+            ])
+        );
+
+        // Rows of chunks that were compacted away are forgotten, not evaluated twice.
+        let (_, rows) = &search.components[0];
+        assert_eq!(rows.rows.len(), 3);
+        assert_eq!(rows.rows_per_chunk.values().map(Vec::len).sum::<usize>(), 3);
+    }
+}
@@ -0,0 +1,563 @@
+//! Markers shown in their own lane above the streams of the time panel.
+//!
+//! There are two kinds of markers:
+//! * [`TimelineEvent`]s logged to the recording, shown as points or spans.
+//! * Bookmarks created by the user, stored in the blueprint as [`TimelineBookmarks`].
+
+use std::sync::Arc;
+
+use egui::{ahash::HashMap, pos2, Color32, Rect, Shape, Stroke};
+use itertools::izip;
+
+use re_chunk_store::external::re_chunk::ChunkComponentSlicer;
+use re_chunk_store::{Chunk, ChunkStoreGeneration, LatestAtQuery, RangeQuery, RowId};
+use re_entity_db::EntityDb;
+use re_log_types::{EntityPath, ResolvedTimeRange, TimeInt, Timeline};
+use re_types::{
+    archetypes::TimelineEvent,
+    blueprint::{
+        archetypes::TimelineBookmarks,
+        components::{BookmarkTime, TimelineName},
+    },
+    components::{Color, EventDuration, Name, Text, TextLogLevel},
+    Archetype as _, Component as _,
+};
+use re_types_core::ComponentName;
+use re_ui::UiExt as _;
+use re_viewer_context::{Cache, Caches, TimeControl, ViewerContext};
+
+use crate::time_ranges_ui::TimeRangesUi;
+
+/// The blueprint entity bookmarks are stored on.
+///
+/// This is the same entity that stores the state of the time panel.
+pub const BOOKMARKS_ENTITY_PATH: &str = "time_panel";
+
+/// Height of the lane the markers are shown in.
+pub const MARKERS_LANE_HEIGHT: f32 = 18.0;
+
+/// Half the width of a marker, in ui points.
+const MARKER_RADIUS: f32 = 5.0;
+
+// ----------------------------------------------------------------------------
+
+/// A [`TimelineEvent`] on a single timeline.
+#[derive(Clone, Debug)]
+pub struct EventMarker {
+    pub entity_path: EntityPath,
+    pub time: TimeInt,
+
+    /// Where the span of the event ends, if it has a duration.
+    pub end_time: Option<TimeInt>,
+
+    pub label: String,
+    pub level: Option<TextLogLevel>,
+    pub color: Option<Color32>,
+}
+
+impl EventMarker {
+    /// The explicit color of the event, or else the color of its severity.
+    pub fn color(&self, visuals: &egui::Visuals) -> Color32 {
+        self.color
+            .unwrap_or_else(|| match self.level.as_ref().map(|level| level.as_str()) {
+                Some(TextLogLevel::CRITICAL | TextLogLevel::ERROR) => visuals.error_fg_color,
+                Some(TextLogLevel::WARN) => visuals.warn_fg_color,
+                Some(TextLogLevel::INFO) => Color32::LIGHT_GREEN,
+                Some(TextLogLevel::DEBUG) => Color32::LIGHT_BLUE,
+                Some(TextLogLevel::TRACE) => Color32::LIGHT_GRAY,
+                _ => visuals.strong_text_color(),
+            })
+    }
+}
+
+/// All events logged on the given timeline, sorted by time.
+pub fn timeline_events(entity_db: &EntityDb, timeline: &Timeline) -> Vec<EventMarker> {
+    re_tracing::profile_function!();
+
+    let engine = entity_db.storage_engine();
+    let store = engine.store();
+    let indicator = TimelineEvent::descriptor_indicator().component_name;
+    let query = RangeQuery::new(*timeline, ResolvedTimeRange::EVERYTHING);
+
+    let mut events = Vec::new();
+    for entity_path in store.all_entities() {
+        if !store.entity_has_component(&entity_path, &indicator) {
+            continue;
+        }
+
+        let results = engine.cache().range(
+            &query,
+            &entity_path,
+            [
+                Text::name(),
+                TextLogLevel::name(),
+                Color::name(),
+                EventDuration::name(),
+            ],
+        );
+        let Some(all_label_chunks) = results.get(&Text::name()) else {
+            continue;
+        };
+        let chunks_of = |component_name| results.get(&component_name).unwrap_or_default();
+
+        let all_frames = re_query::range_zip_1x3(
+            iter_slices::<String>(all_label_chunks, timeline, Text::name()),
+            iter_slices::<String>(
+                chunks_of(TextLogLevel::name()),
+                timeline,
+                TextLogLevel::name(),
+            ),
+            iter_slices::<u32>(chunks_of(Color::name()), timeline, Color::name()),
+            iter_slices::<i64>(
+                chunks_of(EventDuration::name()),
+                timeline,
+                EventDuration::name(),
+            ),
+        );
+
+        for ((time, _row_id), labels, levels, colors, durations) in all_frames {
+            if time.is_static() {
+                continue;
+            }
+
+            let levels = levels.unwrap_or_default().into_iter().map(Some);
+            let colors = colors.unwrap_or_default().iter().copied().map(Some);
+            let durations = durations.unwrap_or_default().iter().copied().map(Some);
+
+            for (label, level, color, duration) in re_query::clamped_zip_1x3(
+                labels,
+                levels,
+                || None,
+                colors,
+                || None,
+                durations,
+                || None,
+            ) {
+                events.push(EventMarker {
+                    entity_path: entity_path.clone(),
+                    time,
+                    end_time: duration.map(|duration| {
+                        TimeInt::new_temporal(time.as_i64().saturating_add(duration.max(0)))
+                    }),
+                    label: label.to_string(),
+                    level: level.map(|level| TextLogLevel::from(level.as_str())),
+                    color: color.map(|color| Color::from_u32(color).into()),
+                });
+            }
+        }
+    }
+
+    events.sort_by_key(|event| event.time);
+    events
+}
+
+/// Memoizes [`timeline_events`] per timeline, until the recording changes.
+///
+/// Querying all events is too expensive to do every frame.
+#[derive(Default)]
+pub struct TimelineEventsCache(HashMap<Timeline, (ChunkStoreGeneration, Arc<Vec<EventMarker>>)>);
+
+impl TimelineEventsCache {
+    /// All events logged on the given timeline, sorted by time, see [`timeline_events`].
+    pub fn events(&mut self, entity_db: &EntityDb, timeline: &Timeline) -> Arc<Vec<EventMarker>> {
+        let generation = entity_db.generation();
+        match self.0.get(timeline) {
+            Some((cached_generation, events)) if *cached_generation == generation => {
+                events.clone()
+            }
+            _ => {
+                let events = Arc::new(timeline_events(entity_db, timeline));
+                self.0.insert(*timeline, (generation, events.clone()));
+                events
+            }
+        }
+    }
+}
+
+impl Cache for TimelineEventsCache {
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn iter_slices<'a, S: 'a + ChunkComponentSlicer>(
+    chunks: &'a [Chunk],
+    timeline: &'a Timeline,
+    component_name: ComponentName,
+) -> impl Iterator<Item = ((TimeInt, RowId), S::Item<'a>)> + 'a {
+    chunks.iter().flat_map(move |chunk| {
+        izip!(
+            chunk.iter_component_indices(timeline, &component_name),
+            chunk.iter_slices::<S>(component_name)
+        )
+    })
+}
+
+// ----------------------------------------------------------------------------
+
+/// A moment on a timeline bookmarked by the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bookmark {
+    pub timeline: re_log_types::TimelineName,
+    pub time: TimeInt,
+    pub label: String,
+}
+
+/// All bookmarks of the time panel, as stored in the blueprint.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bookmarks(pub Vec<Bookmark>);
+
+impl Bookmarks {
+    /// Reads the bookmarks from the blueprint.
+    ///
+    /// Bookmarks without a timeline or time are dropped, missing labels are left empty.
+    pub fn load(blueprint_db: &EntityDb, query: &LatestAtQuery) -> Self {
+        let results = blueprint_db.latest_at(
+            query,
+            &BOOKMARKS_ENTITY_PATH.into(),
+            TimelineBookmarks::all_components()
+                .iter()
+                .map(|descr| descr.component_name),
+        );
+
+        let timelines = results
+            .component_batch::<TimelineName>()
+            .unwrap_or_default();
+        let times = results
+            .component_batch::<BookmarkTime>()
+            .unwrap_or_default();
+        let mut labels = results.component_batch::<Name>().unwrap_or_default();
+        labels.resize_with(times.len(), Name::default);
+
+        Self(
+            izip!(timelines, times, labels)
+                .map(|(timeline, time, label)| Bookmark {
+                    timeline: timeline.into(),
+                    time: TimeInt::new_temporal(time.0.into()),
+                    label: label.as_str().to_owned(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Writes the bookmarks to the blueprint.
+    pub fn save(&self, ctx: &ViewerContext<'_>) {
+        let Self(bookmarks) = self;
+        ctx.save_blueprint_archetype(
+            &BOOKMARKS_ENTITY_PATH.into(),
+            &TimelineBookmarks::new()
+                .with_timelines(
+                    bookmarks
+                        .iter()
+                        .map(|bookmark| TimelineName::from(bookmark.timeline.as_str())),
+                )
+                .with_times(
+                    bookmarks
+                        .iter()
+                        .map(|bookmark| BookmarkTime::from(bookmark.time.as_i64())),
+                )
+                .with_labels(
+                    bookmarks
+                        .iter()
+                        .map(|bookmark| Name::from(bookmark.label.as_str())),
+                ),
+        );
+    }
+
+    /// The bookmarks on the given timeline, together with their index.
+    pub fn on_timeline<'a>(
+        &'a self,
+        timeline: &'a Timeline,
+    ) -> impl Iterator<Item = (usize, &'a Bookmark)> + 'a {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(move |(_, bookmark)| bookmark.timeline == *timeline.name())
+    }
+}
+
+/// Bookmarks the current time of the time control, unless it is already bookmarked.
+pub fn add_bookmark(ctx: &ViewerContext<'_>, time_ctrl: &TimeControl) {
+    let Some(time) = time_ctrl.time_int() else {
+        return;
+    };
+    let timeline = time_ctrl.timeline();
+
+    let mut bookmarks = Bookmarks::load(ctx.blueprint_db(), ctx.blueprint_query);
+    if bookmarks
+        .on_timeline(timeline)
+        .any(|(_, bookmark)| bookmark.time == time)
+    {
+        return;
+    }
+
+    let label = format!("Bookmark {}", bookmarks.on_timeline(timeline).count() + 1);
+    bookmarks.0.push(Bookmark {
+        timeline: *timeline.name(),
+        time,
+        label,
+    });
+    bookmarks.save(ctx);
+}
+
+// ----------------------------------------------------------------------------
+
+/// The times of all events and bookmarks on the given timeline, sorted and without duplicates.
+///
+/// `caches` are the caches of `recording`.
+pub fn marker_times(
+    recording: &EntityDb,
+    caches: &Caches,
+    blueprint: &EntityDb,
+    blueprint_query: &LatestAtQuery,
+    timeline: &Timeline,
+) -> Vec<TimeInt> {
+    let bookmarks = Bookmarks::load(blueprint, blueprint_query);
+    let events = caches.entry(|cache: &mut TimelineEventsCache| cache.events(recording, timeline));
+
+    let mut times: Vec<TimeInt> = events
+        .iter()
+        .map(|event| event.time)
+        .chain(
+            bookmarks
+                .on_timeline(timeline)
+                .map(|(_, bookmark)| bookmark.time),
+        )
+        .collect();
+    times.sort();
+    times.dedup();
+    times
+}
+
+/// The first marker strictly after `time`, if any.
+///
+/// `marker_times` must be sorted.
+pub fn next_marker(marker_times: &[TimeInt], time: TimeInt) -> Option<TimeInt> {
+    let idx = marker_times.partition_point(|&marker| marker <= time);
+    marker_times.get(idx).copied()
+}
+
+/// The last marker strictly before `time`, if any.
+///
+/// `marker_times` must be sorted.
+pub fn previous_marker(marker_times: &[TimeInt], time: TimeInt) -> Option<TimeInt> {
+    let idx = marker_times.partition_point(|&marker| marker < time);
+    idx.checked_sub(1).map(|idx| marker_times[idx])
+}
+
+// ----------------------------------------------------------------------------
+
+/// Paints the events & bookmarks of the current timeline into `rect` and handles interacting with them.
+///
+/// Clicking a marker moves the time cursor to it, right-clicking a bookmark allows to rename or remove it.
+pub fn markers_ui(
+    ctx: &ViewerContext<'_>,
+    entity_db: &EntityDb,
+    time_ranges_ui: &TimeRangesUi,
+    time_ctrl: &mut TimeControl,
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    rect: Rect,
+) {
+    re_tracing::profile_function!();
+
+    let timeline = *time_ctrl.timeline();
+    let marker_y_range = egui::Rangef::new(rect.top() + 3.0, rect.bottom() - 3.0);
+
+    let events = ctx
+        .cache
+        .entry(|cache: &mut TimelineEventsCache| cache.events(entity_db, &timeline));
+    for (i, event) in events.iter().enumerate() {
+        let Some(x) = time_ranges_ui.x_from_time_f32(event.time.into()) else {
+            continue;
+        };
+        let color = event.color(ui.visuals());
+
+        let mut interact_rect =
+            Rect::from_x_y_ranges((x - MARKER_RADIUS)..=(x + MARKER_RADIUS), marker_y_range);
+        if let Some(end_x) = event
+            .end_time
+            .and_then(|end_time| time_ranges_ui.x_from_time_f32(end_time.into()))
+        {
+            let span_rect = Rect::from_x_y_ranges(x..=end_x.max(x), marker_y_range)
+                .shrink2(egui::vec2(0.0, 2.0));
+            painter.rect_filled(span_rect, 2.0, color.gamma_multiply(0.4));
+            interact_rect = interact_rect.union(span_rect);
+        }
+
+        let center = pos2(x, marker_y_range.center());
+        painter.add(Shape::convex_polygon(
+            vec![
+                pos2(x, marker_y_range.min),
+                pos2(x + MARKER_RADIUS, center.y),
+                pos2(x, marker_y_range.max),
+                pos2(x - MARKER_RADIUS, center.y),
+            ],
+            color,
+            Stroke::NONE,
+        ));
+
+        let response = ui
+            .interact(
+                interact_rect,
+                ui.id()
+                    .with(("timeline_event", &event.entity_path, event.time, i)),
+                egui::Sense::click(),
+            )
+            .on_hover_ui(|ui| {
+                ui.strong(&event.label);
+                if let Some(level) = &event.level {
+                    ui.label(re_viewer_context::level_to_rich_text(ui, level.as_str()));
+                }
+                ui.label(format!(
+                    "{} at {}",
+                    event.entity_path,
+                    timeline.typ().format(event.time, ctx.app_options.time_zone)
+                ));
+            });
+        if response.clicked() {
+            time_ctrl.set_time(event.time);
+            time_ctrl.pause();
+        }
+    }
+
+    let bookmarks = Bookmarks::load(ctx.blueprint_db(), ctx.blueprint_query);
+    let mut edited_bookmarks = bookmarks.clone();
+    for (i, bookmark) in bookmarks.on_timeline(&timeline) {
+        let Some(x) = time_ranges_ui.x_from_time_f32(bookmark.time.into()) else {
+            continue;
+        };
+
+        let response = ui
+            .interact(
+                Rect::from_x_y_ranges((x - MARKER_RADIUS)..=(x + MARKER_RADIUS), marker_y_range),
+                ui.id().with(("timeline_bookmark", i)),
+                egui::Sense::click(),
+            )
+            .on_hover_text(&bookmark.label);
+
+        let color = if response.hovered() {
+            ui.visuals().strong_text_color()
+        } else {
+            ui.visuals().text_color()
+        };
+        painter.add(Shape::convex_polygon(
+            vec![
+                pos2(x - MARKER_RADIUS, marker_y_range.min),
+                pos2(x + MARKER_RADIUS, marker_y_range.min),
+                pos2(x, marker_y_range.max),
+            ],
+            color,
+            Stroke::NONE,
+        ));
+
+        if response.clicked() {
+            time_ctrl.set_time(bookmark.time);
+            time_ctrl.pause();
+        }
+
+        response.context_menu(|ui| {
+            ui.text_edit_singleline(&mut edited_bookmarks.0[i].label);
+            if ui.button("Remove bookmark").clicked() {
+                edited_bookmarks.0.remove(i);
+                ui.close_menu();
+            }
+        });
+
+        // Only one context menu can be open at a time, so `i` is still valid.
+        if edited_bookmarks != bookmarks {
+            break;
+        }
+    }
+
+    if edited_bookmarks != bookmarks {
+        edited_bookmarks.save(ctx);
+    }
+}
+
+/// The left part of the markers lane, with a button to bookmark the current time.
+pub fn markers_lane_header_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, time_ctrl: &TimeControl) {
+    ui.horizontal(|ui| {
+        ui.label("Markers").on_hover_text(
+            "Events logged with the TimelineEvent archetype, and your bookmarks.\n\
+            \n\
+            Use the next/previous marker commands to move the time cursor between them.",
+        );
+        if ui
+            .small_icon_button(&re_ui::icons::ADD)
+            .on_hover_text("Bookmark the current time")
+            .clicked()
+        {
+            add_bookmark(ctx, time_ctrl);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_and_previous_marker() {
+        let times = [10, 20, 30].map(TimeInt::new_temporal);
+
+        assert_eq!(
+            next_marker(&times, TimeInt::new_temporal(5)),
+            Some(times[0])
+        );
+        assert_eq!(next_marker(&times, times[0]), Some(times[1]));
+        assert_eq!(
+            next_marker(&times, TimeInt::new_temporal(25)),
+            Some(times[2])
+        );
+        assert_eq!(next_marker(&times, times[2]), None);
+
+        assert_eq!(previous_marker(&times, times[0]), None);
+        assert_eq!(previous_marker(&times, times[1]), Some(times[0]));
+        assert_eq!(
+            previous_marker(&times, TimeInt::new_temporal(35)),
+            Some(times[2])
+        );
+        assert_eq!(previous_marker(&[], TimeInt::new_temporal(35)), None);
+    }
+
+    #[test]
+    fn events_are_cached_until_the_recording_changes() {
+        let mut entity_db = EntityDb::new(re_log_types::StoreId::random(
+            re_log_types::StoreKind::Recording,
+        ));
+        let timeline = Timeline::new_sequence("frame");
+        let log_event = |entity_db: &mut EntityDb, frame: i64, label: &str| {
+            let chunk = Chunk::builder("events".into())
+                .with_archetype(
+                    RowId::new(),
+                    [(timeline, frame)],
+                    &TimelineEvent::new(label),
+                )
+                .build()
+                .unwrap();
+            entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+        };
+
+        log_event(&mut entity_db, 20, "second");
+        log_event(&mut entity_db, 10, "first");
+
+        let mut cache = TimelineEventsCache::default();
+        let events = cache.events(&entity_db, &timeline);
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.label.as_str())
+                .collect::<Vec<_>>(),
+            ["first", "second"]
+        );
+        assert!(Arc::ptr_eq(&events, &cache.events(&entity_db, &timeline)));
+
+        log_event(&mut entity_db, 30, "third");
+        assert_eq!(cache.events(&entity_db, &timeline).len(), 3);
+    }
+}
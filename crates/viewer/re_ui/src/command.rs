@@ -19,6 +19,8 @@ pub enum UICommand {
     SaveRecording,
     SaveRecordingSelection,
     SaveBlueprint,
+    #[cfg(not(target_arch = "wasm32"))]
+    BlueprintTemplates,
     CloseCurrentRecording,
     CloseAllRecordings,
 
@@ -67,6 +69,8 @@ pub enum UICommand {
     PlaybackFollow,
     PlaybackStepBack,
     PlaybackStepForward,
+    PlaybackPreviousMarker,
+    PlaybackNextMarker,
     PlaybackRestart,
 
     // Dev-tools:
@@ -111,6 +115,8 @@ impl UICommand {
             ),
 
             Self::SaveBlueprint => ("Save blueprint…", "Save the current viewer setup as a Rerun blueprint file (.rbl)"),
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::BlueprintTemplates => ("Blueprint templates…", "Apply a blueprint template to the current recording, or save the current viewer setup as a template"),
 
             Self::Open => ("Open…", "Open any supported files (.rrd, images, meshes, …) in a new recording"),
             Self::Import => ("Import…", "Import any supported files (.rrd, images, meshes, …) in the current recording"),
@@ -209,6 +215,14 @@ impl UICommand {
                 "Step time forward",
                 "Move the time marker to the next point in time with any data",
             ),
+            Self::PlaybackPreviousMarker => (
+                "Previous marker",
+                "Move the time marker back to the previous timeline event or bookmark",
+            ),
+            Self::PlaybackNextMarker => (
+                "Next marker",
+                "Move the time marker to the next timeline event or bookmark",
+            ),
             Self::PlaybackRestart => ("Restart", "Restart from beginning of timeline"),
 
             #[cfg(not(target_arch = "wasm32"))]
@@ -271,6 +285,10 @@ impl UICommand {
             KeyboardShortcut::new(Modifiers::COMMAND, key)
         }
 
+        fn shift(key: Key) -> KeyboardShortcut {
+            KeyboardShortcut::new(Modifiers::SHIFT, key)
+        }
+
         fn cmd_shift(key: Key) -> KeyboardShortcut {
             KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, key)
         }
@@ -287,6 +305,8 @@ impl UICommand {
             Self::SaveRecording => smallvec![cmd(Key::S)],
             Self::SaveRecordingSelection => smallvec![cmd_alt(Key::S)],
             Self::SaveBlueprint => smallvec![],
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::BlueprintTemplates => smallvec![],
             Self::Open => smallvec![cmd(Key::O)],
             Self::Import => smallvec![cmd_shift(Key::O)],
             Self::CloseCurrentRecording => smallvec![],
@@ -348,6 +368,8 @@ impl UICommand {
             Self::PlaybackFollow => smallvec![cmd(Key::ArrowRight)],
             Self::PlaybackStepBack => smallvec![key(Key::ArrowLeft)],
             Self::PlaybackStepForward => smallvec![key(Key::ArrowRight)],
+            Self::PlaybackPreviousMarker => smallvec![shift(Key::ArrowLeft)],
+            Self::PlaybackNextMarker => smallvec![shift(Key::ArrowRight)],
             Self::PlaybackRestart => smallvec![cmd(Key::ArrowLeft)],
 
             #[cfg(not(target_arch = "wasm32"))]
@@ -384,6 +384,8 @@ fn create_view_builder(
 
             // Make sure the map in the background is not completely overwritten
             blend_with_background: true,
+            lens_distortion: None,
+            clip_planes: Vec::new(),
         },
     )
 }
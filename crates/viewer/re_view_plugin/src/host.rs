@@ -0,0 +1,428 @@
+//! The functions the viewer provides to plugins, see the crate docs for the ABI.
+
+use arrow::{
+    array::{Array, AsArray as _},
+    datatypes::{DataType, Float64Type},
+};
+use wasmi::{Caller, Engine, Extern, Linker};
+
+use re_query::LatestAtResults;
+use re_types::ComponentName;
+
+/// A drawing command issued by a plugin.
+///
+/// Commands are collected during the plugin's call and painted afterwards,
+/// since painting text needs access to the ui's fonts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaintCommand {
+    Line {
+        from: egui::Pos2,
+        to: egui::Pos2,
+        stroke: egui::Stroke,
+    },
+    Rect {
+        rect: egui::Rect,
+        color: egui::Color32,
+    },
+    Circle {
+        center: egui::Pos2,
+        radius: f32,
+        color: egui::Color32,
+    },
+    Text {
+        pos: egui::Pos2,
+        size: f32,
+        color: egui::Color32,
+        text: String,
+    },
+}
+
+impl PaintCommand {
+    /// Paints the command, with positions relative to the top left corner of `rect`.
+    pub fn paint(self, painter: &egui::Painter, rect: egui::Rect) {
+        let offset = rect.min.to_vec2();
+        match self {
+            Self::Line { from, to, stroke } => {
+                painter.line_segment([from + offset, to + offset], stroke);
+            }
+            Self::Rect { rect, color } => {
+                painter.rect_filled(rect.translate(offset), 0.0, color);
+            }
+            Self::Circle {
+                center,
+                radius,
+                color,
+            } => {
+                painter.circle_filled(center + offset, radius, color);
+            }
+            Self::Text {
+                pos,
+                size,
+                color,
+                text,
+            } => {
+                painter.text(
+                    pos + offset,
+                    egui::Align2::LEFT_TOP,
+                    text,
+                    egui::FontId::proportional(size),
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// How many paint commands a plugin may issue per frame, further commands are dropped.
+pub const MAX_PAINT_COMMANDS_PER_FRAME: usize = 100_000;
+
+/// How many messages a plugin may log per frame, further messages are dropped.
+pub const MAX_LOG_MESSAGES_PER_FRAME: usize = 10;
+
+/// State of a plugin instance that the host functions work on.
+#[derive(Default)]
+pub struct HostState {
+    /// Latest-at results for all entities the view can query, refreshed every frame.
+    pub entities: Vec<LatestAtResults>,
+
+    /// The time on the active timeline.
+    pub time: i64,
+
+    /// What the plugin drew during the current frame.
+    pub paint_commands: Vec<PaintCommand>,
+
+    /// How many messages the plugin logged during the current frame.
+    pub num_log_messages: usize,
+
+    /// Output of the last host function that produced data, see `read_result`.
+    result: Vec<u8>,
+
+    /// How much memory and how many table elements the plugin may allocate.
+    pub limits: wasmi::StoreLimits,
+}
+
+impl HostState {
+    fn component_batch(&self, entity: i32, component: &str) -> Option<arrow::array::ArrayRef> {
+        let results = self.entities.get(usize::try_from(entity).ok()?)?;
+        let component_name = results
+            .components
+            .keys()
+            .find(|name| is_component(name, component))?;
+        results.component_batch_raw(component_name)
+    }
+
+    /// Records a paint command, unless the plugin already drew [`MAX_PAINT_COMMANDS_PER_FRAME`] this frame.
+    fn paint(&mut self, command: PaintCommand) {
+        if self.paint_commands.len() < MAX_PAINT_COMMANDS_PER_FRAME {
+            self.paint_commands.push(command);
+        } else {
+            re_log::warn_once!(
+                "A view plugin issued more than {MAX_PAINT_COMMANDS_PER_FRAME} paint commands in a frame, dropping the rest"
+            );
+        }
+    }
+
+    /// Sets the result buffer and returns its length, in elements.
+    fn set_result(&mut self, result: Vec<u8>, len: usize) -> i32 {
+        self.result = result;
+        i32::try_from(len).unwrap_or(-1)
+    }
+}
+
+/// Whether `component` is the full or short name of a component, e.g. `rerun.components.Scalar` or `Scalar`.
+pub fn is_component(component_name: &ComponentName, component: &str) -> bool {
+    component_name.full_name() == component || component_name.short_name() == component
+}
+
+fn color(rgba: i32) -> egui::Color32 {
+    let [r, g, b, a] = (rgba as u32).to_be_bytes();
+    egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// Reads a UTF-8 string from the plugin's memory.
+fn read_string(caller: &Caller<'_, HostState>, ptr: i32, len: i32) -> Option<String> {
+    let memory = caller.get_export("memory").and_then(Extern::into_memory)?;
+    string_from_memory(memory.data(caller), ptr, len)
+}
+
+/// Reads a UTF-8 string from a plugin's linear memory.
+///
+/// Returns `None` if the range is outside the memory, without allocating for it first,
+/// since both `ptr` and `len` come from the plugin.
+pub fn string_from_memory(memory: &[u8], ptr: i32, len: i32) -> Option<String> {
+    let ptr = usize::try_from(ptr).ok()?;
+    let len = usize::try_from(len).ok()?;
+    let bytes = memory.get(ptr..ptr.checked_add(len)?)?;
+    std::str::from_utf8(bytes).ok().map(ToOwned::to_owned)
+}
+
+/// The largest stroke width, circle radius and text size a plugin may draw with, in ui points.
+pub const MAX_PAINT_SIZE: f32 = 10_000.0;
+
+/// Clamps a size the plugin passed to `0..=MAX_PAINT_SIZE`, or returns `None` if it isn't finite.
+fn paint_size(size: f32) -> Option<f32> {
+    size.is_finite().then(|| size.clamp(0.0, MAX_PAINT_SIZE))
+}
+
+/// All numeric values of an array, flattened.
+///
+/// Returns `None` for arrays that aren't (lists of) numbers.
+pub fn flatten_f64(array: &dyn Array) -> Option<Vec<f64>> {
+    if let Some(list) = array.as_fixed_size_list_opt() {
+        return flatten_f64(list.values().as_ref());
+    }
+    if let Some(list) = array.as_list_opt::<i32>() {
+        return flatten_f64(list.values().as_ref());
+    }
+    if !array.data_type().is_numeric() {
+        return None;
+    }
+
+    let floats = arrow::compute::cast(array, &DataType::Float64).ok()?;
+    Some(floats.as_primitive::<Float64Type>().values().to_vec())
+}
+
+/// Creates a linker that provides all host functions to plugins.
+pub fn linker(engine: &Engine) -> Result<Linker<HostState>, wasmi::Error> {
+    const MODULE: &str = "rerun";
+
+    let mut linker = Linker::new(engine);
+
+    linker.func_wrap(MODULE, "entity_count", |caller: Caller<'_, HostState>| {
+        i32::try_from(caller.data().entities.len()).unwrap_or(i32::MAX)
+    })?;
+
+    linker.func_wrap(
+        MODULE,
+        "entity_path",
+        |mut caller: Caller<'_, HostState>, entity: i32| {
+            let state = caller.data_mut();
+            let Some(path) = usize::try_from(entity)
+                .ok()
+                .and_then(|entity| state.entities.get(entity))
+                .map(|results| results.entity_path.to_string().into_bytes())
+            else {
+                return -1;
+            };
+            let len = path.len();
+            state.set_result(path, len)
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "query_f64",
+        |mut caller: Caller<'_, HostState>, entity: i32, ptr: i32, len: i32| {
+            let Some(component) = read_string(&caller, ptr, len) else {
+                return -1;
+            };
+            let state = caller.data_mut();
+            let Some(values) = state
+                .component_batch(entity, &component)
+                .and_then(|array| flatten_f64(array.as_ref()))
+            else {
+                return -1;
+            };
+            let num_values = values.len();
+            let bytes = values.into_iter().flat_map(f64::to_le_bytes).collect();
+            state.set_result(bytes, num_values)
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "query_string",
+        |mut caller: Caller<'_, HostState>, entity: i32, ptr: i32, len: i32| {
+            let Some(component) = read_string(&caller, ptr, len) else {
+                return -1;
+            };
+            let state = caller.data_mut();
+            let Some(text) = state.component_batch(entity, &component).and_then(|array| {
+                let strings = array.as_string_opt::<i32>()?;
+                (!strings.is_empty() && strings.is_valid(0)).then(|| strings.value(0).to_owned())
+            }) else {
+                return -1;
+            };
+            let bytes = text.into_bytes();
+            let len = bytes.len();
+            state.set_result(bytes, len)
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "read_result",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            let Some(memory) = caller.get_export("memory").and_then(Extern::into_memory) else {
+                return;
+            };
+            let (Ok(ptr), Ok(len)) = (usize::try_from(ptr), usize::try_from(len)) else {
+                return;
+            };
+            let result = std::mem::take(&mut caller.data_mut().result);
+            let len = len.min(result.len());
+            if let Err(err) = memory.write(&mut caller, ptr, &result[..len]) {
+                re_log::warn_once!(
+                    "View plugin asked for its results at an invalid address: {err}"
+                );
+            }
+            caller.data_mut().result = result;
+        },
+    )?;
+
+    linker.func_wrap(MODULE, "current_time", |caller: Caller<'_, HostState>| {
+        caller.data().time
+    })?;
+
+    linker.func_wrap(
+        MODULE,
+        "log",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            let state = caller.data_mut();
+            if state.num_log_messages >= MAX_LOG_MESSAGES_PER_FRAME {
+                re_log::warn_once!(
+                    "A view plugin logged more than {MAX_LOG_MESSAGES_PER_FRAME} messages in a frame, dropping the rest"
+                );
+                return;
+            }
+            state.num_log_messages += 1;
+
+            if let Some(message) = read_string(&caller, ptr, len) {
+                re_log::info!("View plugin: {message}");
+            }
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "draw_line",
+        |mut caller: Caller<'_, HostState>,
+         x0: f32,
+         y0: f32,
+         x1: f32,
+         y1: f32,
+         width: f32,
+         rgba: i32| {
+            let Some(width) = paint_size(width) else {
+                return;
+            };
+            caller.data_mut().paint(PaintCommand::Line {
+                from: egui::pos2(x0, y0),
+                to: egui::pos2(x1, y1),
+                stroke: egui::Stroke::new(width, color(rgba)),
+            });
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "draw_rect",
+        |mut caller: Caller<'_, HostState>, x: f32, y: f32, width: f32, height: f32, rgba: i32| {
+            caller.data_mut().paint(PaintCommand::Rect {
+                rect: egui::Rect::from_min_size(egui::pos2(x, y), egui::vec2(width, height)),
+                color: color(rgba),
+            });
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "draw_circle",
+        |mut caller: Caller<'_, HostState>, x: f32, y: f32, radius: f32, rgba: i32| {
+            let Some(radius) = paint_size(radius) else {
+                return;
+            };
+            caller.data_mut().paint(PaintCommand::Circle {
+                center: egui::pos2(x, y),
+                radius,
+                color: color(rgba),
+            });
+        },
+    )?;
+
+    linker.func_wrap(
+        MODULE,
+        "draw_text",
+        |mut caller: Caller<'_, HostState>,
+         x: f32,
+         y: f32,
+         size: f32,
+         rgba: i32,
+         ptr: i32,
+         len: i32| {
+            let Some(size) = paint_size(size) else {
+                return;
+            };
+            let Some(text) = read_string(&caller, ptr, len) else {
+                return;
+            };
+            caller.data_mut().paint(PaintCommand::Text {
+                pos: egui::pos2(x, y),
+                size,
+                color: color(rgba),
+                text,
+            });
+        },
+    )?;
+
+    Ok(linker)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::{
+        array::{FixedSizeListArray, Float32Array, StringArray, UInt8Array},
+        datatypes::Field,
+    };
+
+    use super::*;
+
+    #[test]
+    fn flatten_numbers() {
+        let scalars = UInt8Array::from(vec![1, 2, 3]);
+        assert_eq!(flatten_f64(&scalars), Some(vec![1.0, 2.0, 3.0]));
+
+        let points = FixedSizeListArray::new(
+            Arc::new(Field::new("item", DataType::Float32, false)),
+            2,
+            Arc::new(Float32Array::from(vec![0.5, 1.5, 2.5, 3.5])),
+            None,
+        );
+        assert_eq!(flatten_f64(&points), Some(vec![0.5, 1.5, 2.5, 3.5]));
+
+        let strings = StringArray::from(vec!["a", "b"]);
+        assert_eq!(flatten_f64(&strings), None);
+    }
+
+    #[test]
+    fn strings_from_memory() {
+        let memory = b"Hello\xFF";
+        assert_eq!(string_from_memory(memory, 0, 5).as_deref(), Some("Hello"));
+        assert_eq!(string_from_memory(memory, 5, 0).as_deref(), Some(""));
+        assert_eq!(string_from_memory(memory, 4, 2), None, "Not UTF-8");
+        assert_eq!(string_from_memory(memory, 2, 5), None, "Past the end");
+        assert_eq!(string_from_memory(memory, 0, i32::MAX), None);
+        assert_eq!(string_from_memory(memory, i32::MAX, i32::MAX), None);
+        assert_eq!(string_from_memory(memory, -1, 1), None);
+        assert_eq!(string_from_memory(memory, 0, -1), None);
+    }
+
+    #[test]
+    fn paint_sizes() {
+        assert_eq!(paint_size(2.5), Some(2.5));
+        assert_eq!(paint_size(-1.0), Some(0.0));
+        assert_eq!(paint_size(1e30), Some(MAX_PAINT_SIZE));
+        assert_eq!(paint_size(f32::INFINITY), None);
+        assert_eq!(paint_size(f32::NAN), None);
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            color(0xFF00_00FF_u32 as i32),
+            egui::Color32::from_rgba_unmultiplied(255, 0, 0, 255)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Rerun View Plugins
+//!
+//! Views whose ui is provided by WASM modules that are loaded at runtime,
+//! so that domain-specific views can be shipped without maintaining a fork of the viewer.
+//!
+//! ## ABI
+//!
+//! A plugin is a `.wasm` module (e.g. compiled from Rust for `wasm32-unknown-unknown`).
+//! All numbers are little-endian, pointers & lengths are `i32` offsets into the plugin's memory,
+//! and colors are `0xRRGGBBAA` packed into an `i32`.
+//!
+//! The plugin must export:
+//! * `memory`: the plugin's linear memory.
+//! * `rerun_plugin_abi_version() -> i32`: must return [`ABI_VERSION`].
+//! * `rerun_view_class_name() -> i64`: the name of the view class, as a UTF-8 string
+//!   at `ptr = result >> 32` with `len = result & 0xFFFF_FFFF`.
+//! * `rerun_view_components() -> i64`: the components the plugin queries, by full or short name,
+//!   as a comma-separated UTF-8 string encoded like the name.
+//! * `rerun_view_ui(width: f32, height: f32)`: draws one frame of the view.
+//!
+//! Every view of the plugin's class gets its own instance of the module, so plugins can keep
+//! state across frames in their memory.
+//! The time a plugin may spend drawing a frame is limited, a plugin that traps or exceeds the
+//! limit is disabled for that view.
+//! Plugins can't grow their memory beyond 256 MiB, and paint commands beyond the first 100 000
+//! of a frame are dropped, as are log messages beyond the first 10.
+//!
+//! The viewer provides these functions in the `rerun` import module:
+//! * `entity_count() -> i32`: number of entities that the view can query,
+//!   i.e. the entities below the view's space origin that have any of the plugin's components.
+//! * `entity_path(entity: i32) -> i32`: writes the path of an entity to the result buffer,
+//!   returns its length in bytes.
+//! * `query_f64(entity: i32, component_ptr: i32, component_len: i32) -> i32`:
+//!   queries a component of an entity at the current time and writes all its numeric values,
+//!   flattened to `f64`s, to the result buffer. Returns the number of values.
+//!   The component can be given by its full name (`rerun.components.Scalar`) or its short name (`Scalar`),
+//!   and must be one of the plugin's components.
+//! * `query_string(entity: i32, component_ptr: i32, component_len: i32) -> i32`:
+//!   like `query_f64`, but writes the first string of a component. Returns its length in bytes.
+//! * `read_result(ptr: i32, len: i32)`: copies the result buffer into the plugin's memory.
+//! * `current_time() -> i64`: the time on the active timeline.
+//! * `log(ptr: i32, len: i32)`: logs a UTF-8 message to the viewer's log.
+//! * `draw_line(x0: f32, y0: f32, x1: f32, y1: f32, width: f32, color: i32)`
+//! * `draw_rect(x: f32, y: f32, width: f32, height: f32, color: i32)`: a filled rectangle.
+//! * `draw_circle(x: f32, y: f32, radius: f32, color: i32)`: a filled circle.
+//! * `draw_text(x: f32, y: f32, size: f32, color: i32, ptr: i32, len: i32)`:
+//!   UTF-8 text, with its top left corner at the given position.
+//!
+//! Positions are in ui points, relative to the top left corner of the view.
+//! Line widths, circle radii and text sizes are clamped to 10 000 points,
+//! and draw calls with a non-finite size or a string outside the plugin's memory are ignored.
+//! Functions that return a count or a length return `-1` on failure,
+//! e.g. if the entity or component doesn't exist.
+
+mod host;
+mod view_class;
+
+pub use view_class::{PluginError, PluginViewClass};
+
+/// The version of the ABI between the viewer and view plugins, see the crate docs.
+///
+/// Bumped on every breaking change, plugins built for another version are not loaded.
+pub const ABI_VERSION: i32 = 1;
+
+/// Loads all view plugins (`*.wasm` files) in a directory.
+///
+/// Plugins that fail to load are skipped, and the reason is logged.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_view_plugins(dir: &std::path::Path) -> Vec<PluginViewClass> {
+    re_tracing::profile_function!();
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            re_log::error!("Failed to read the view plugin directory {dir:?}: {err}");
+            return Vec::new();
+        }
+    };
+
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "wasm"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| match PluginViewClass::load(&path) {
+            Ok(class) => {
+                re_log::info!("Loaded view plugin {:?} from {path:?}", class.name());
+                Some(class)
+            }
+            Err(err) => {
+                re_log::error!("Failed to load view plugin {path:?}: {err}");
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,646 @@
+use std::path::{Path, PathBuf};
+
+use wasmi::{Engine, Instance, Linker, Module, Store, TypedFunc};
+
+use re_chunk_store::LatestAtQuery;
+use re_query::LatestAtResults;
+use re_types::ViewClassIdentifier;
+use re_ui::UiExt as _;
+use re_viewer_context::{
+    external::re_log_types::EntityPath, Item, ViewClass, ViewClassLayoutPriority,
+    ViewClassRegistryError, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _,
+    ViewSystemExecutionError, ViewerContext,
+};
+
+use crate::host::{self, HostState, PaintCommand};
+
+/// How much work a plugin may do to draw a single frame, in units of wasm fuel.
+///
+/// Roughly one unit per executed instruction.
+const FUEL_PER_FRAME: u64 = 200_000_000;
+
+/// How much linear memory a plugin instance may allocate, in bytes.
+const MAX_MEMORY_BYTES: usize = 256 * 1024 * 1024;
+
+/// How many elements a plugin's tables may hold in total, e.g. for indirect calls.
+const MAX_TABLE_ELEMENTS: u32 = 100_000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("Failed to read the plugin: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Wasm(#[from] wasmi::Error),
+
+    #[error("The plugin was built for version {found} of the plugin ABI, but the viewer uses version {expected}")]
+    AbiVersion { found: i32, expected: i32 },
+
+    #[error("The plugin doesn't export its memory")]
+    MissingMemory,
+
+    #[error("The plugin's view class name is not valid UTF-8, or empty")]
+    InvalidName,
+
+    #[error("The plugin's component names are not valid UTF-8")]
+    InvalidComponents,
+}
+
+/// A view class whose ui is drawn by a WASM plugin.
+///
+/// Every loaded plugin is registered as a separate view class, see
+/// [`re_viewer_context::ViewClassRegistry::add_dynamic_class`].
+pub struct PluginViewClass {
+    path: PathBuf,
+    name: String,
+
+    /// The components the plugin queries, by full or short name.
+    components: Vec<String>,
+
+    engine: Engine,
+    module: Module,
+    linker: Linker<HostState>,
+}
+
+impl PluginViewClass {
+    /// Loads a plugin from a `.wasm` file, checking that it implements the expected ABI.
+    pub fn load(path: &Path) -> Result<Self, PluginError> {
+        let wasm = std::fs::read(path)?;
+        Self::from_wasm(path, &wasm)
+    }
+
+    /// Loads a plugin from the contents of a `.wasm` file.
+    fn from_wasm(path: &Path, wasm: &[u8]) -> Result<Self, PluginError> {
+        let mut config = wasmi::Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm)?;
+        let linker = host::linker(&engine)?;
+
+        let mut instance = PluginInstance::new(&engine, &module, &linker)?;
+        instance.store.set_fuel(FUEL_PER_FRAME)?;
+
+        let abi_version = instance
+            .instance
+            .get_typed_func::<(), i32>(&instance.store, "rerun_plugin_abi_version")?
+            .call(&mut instance.store, ())?;
+        if abi_version != crate::ABI_VERSION {
+            return Err(PluginError::AbiVersion {
+                found: abi_version,
+                expected: crate::ABI_VERSION,
+            });
+        }
+
+        let name = instance
+            .call_string_export("rerun_view_class_name")?
+            .filter(|name| !name.is_empty())
+            .ok_or(PluginError::InvalidName)?;
+
+        let components = instance
+            .call_string_export("rerun_view_components")?
+            .ok_or(PluginError::InvalidComponents)?
+            .split(',')
+            .map(str::trim)
+            .filter(|component| !component.is_empty())
+            .map(ToOwned::to_owned)
+            .collect();
+
+        Ok(Self {
+            path: path.to_owned(),
+            name,
+            components,
+            engine,
+            module,
+            linker,
+        })
+    }
+
+    /// The name of the plugin's view class, as shown to users.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The components the plugin queries, by full or short name.
+    pub fn components(&self) -> &[String] {
+        &self.components
+    }
+
+    /// The identifier the plugin's view class is registered with.
+    ///
+    /// Prefixed with [`ViewClass::identifier`], so that plugins can't replace built-in views.
+    pub fn class_identifier(&self) -> ViewClassIdentifier {
+        format!("{}:{}", Self::identifier(), self.name)
+            .as_str()
+            .into()
+    }
+}
+
+/// An instance of a plugin's module, one per view.
+struct PluginInstance {
+    store: Store<HostState>,
+    instance: Instance,
+}
+
+impl PluginInstance {
+    fn new(
+        engine: &Engine,
+        module: &Module,
+        linker: &Linker<HostState>,
+    ) -> Result<Self, PluginError> {
+        let limits = wasmi::StoreLimitsBuilder::new()
+            .memory_size(MAX_MEMORY_BYTES)
+            .table_elements(MAX_TABLE_ELEMENTS)
+            .instances(1)
+            .build();
+        let mut store = Store::new(
+            engine,
+            HostState {
+                limits,
+                ..Default::default()
+            },
+        );
+        store.limiter(|host_state| &mut host_state.limits);
+        store.set_fuel(FUEL_PER_FRAME)?;
+        let instance = linker.instantiate(&mut store, module)?.start(&mut store)?;
+        if instance.get_memory(&store, "memory").is_none() {
+            return Err(PluginError::MissingMemory);
+        }
+        Ok(Self { store, instance })
+    }
+
+    fn read_string(&self, ptr: i32, len: i32) -> Result<Option<String>, PluginError> {
+        let memory = self
+            .instance
+            .get_memory(&self.store, "memory")
+            .ok_or(PluginError::MissingMemory)?;
+        Ok(host::string_from_memory(memory.data(&self.store), ptr, len))
+    }
+
+    /// Calls an export that returns a string as `ptr << 32 | len`, see the crate docs.
+    fn call_string_export(&mut self, name: &str) -> Result<Option<String>, PluginError> {
+        let result = self
+            .instance
+            .get_typed_func::<(), i64>(&self.store, name)?
+            .call(&mut self.store, ())?;
+        self.read_string((result >> 32) as i32, result as i32)
+    }
+
+    fn ui_func(&self) -> Result<TypedFunc<(f32, f32), ()>, PluginError> {
+        Ok(self
+            .instance
+            .get_typed_func::<(f32, f32), ()>(&self.store, "rerun_view_ui")?)
+    }
+
+    /// Lets the plugin draw a frame of the given size, and returns what it drew.
+    fn draw(
+        &mut self,
+        size: egui::Vec2,
+        entities: Vec<LatestAtResults>,
+        time: i64,
+    ) -> Result<Vec<PaintCommand>, PluginError> {
+        let host_state = self.store.data_mut();
+        host_state.entities = entities;
+        host_state.time = time;
+        host_state.paint_commands.clear();
+        host_state.num_log_messages = 0;
+
+        let result = self.ui_func().and_then(|ui_func| {
+            self.store.set_fuel(FUEL_PER_FRAME)?;
+            Ok(ui_func.call(&mut self.store, (size.x, size.y))?)
+        });
+
+        let host_state = self.store.data_mut();
+        host_state.entities.clear();
+        let paint_commands = std::mem::take(&mut host_state.paint_commands);
+
+        result.map(|()| paint_commands)
+    }
+}
+
+/// Per-view state of a plugin view: the plugin instance, or why it can't be used.
+pub struct PluginViewState {
+    instance: Result<PluginInstance, String>,
+}
+
+impl ViewState for PluginViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ViewClass for PluginViewClass {
+    /// The prefix of the identifiers of all plugin view classes.
+    ///
+    /// Every plugin is registered with its own identifier, see [`PluginViewClass::class_identifier`].
+    fn identifier() -> ViewClassIdentifier {
+        "Plugin".into()
+    }
+
+    fn display_name(&self) -> &str {
+        &self.name
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_GENERIC
+    }
+
+    fn help_markdown(&self, _egui_ctx: &egui::Context) -> String {
+        format!(
+            "# {}\n\nA view provided by the plugin at `{}`.\n\nIt can query these components of all entities below its space origin: {}.",
+            self.name,
+            self.path.display(),
+            self.components.join(", ")
+        )
+    }
+
+    fn on_register(
+        &self,
+        _system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        // Plugins query the data they need themselves.
+        Ok(())
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::new(PluginViewState {
+            instance: PluginInstance::new(&self.engine, &self.module, &self.linker)
+                .map_err(|err| err.to_string()),
+        })
+    }
+
+    fn layout_priority(&self) -> ViewClassLayoutPriority {
+        ViewClassLayoutPriority::Low
+    }
+
+    fn spawn_heuristics(&self, _ctx: &ViewerContext<'_>) -> ViewSpawnHeuristics {
+        // Plugin views are only ever created by the user.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        _view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<PluginViewState>()?;
+
+        ui.selection_grid("plugin_view").show(ui, |ui| {
+            ui.grid_left_hand_label("Plugin");
+            ui.label(self.path.display().to_string());
+            ui.end_row();
+
+            if let Err(err) = &state.instance {
+                ui.grid_left_hand_label("Error");
+                ui.error_label(err.clone());
+                ui.end_row();
+            }
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        _system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!(&self.name);
+
+        let state = state.downcast_mut::<PluginViewState>()?;
+        let instance = match &mut state.instance {
+            Ok(instance) => instance,
+            Err(err) => {
+                ui.error_label(format!(
+                    "The view plugin {:?} is disabled: {err}",
+                    self.name
+                ));
+                return Ok(());
+            }
+        };
+
+        let (response, painter) = ui.allocate_painter(ui.available_size(), egui::Sense::click());
+        let rect = response.rect;
+
+        let entities = query_entities(ctx, query, &self.components);
+        match instance.draw(rect.size(), entities, query.latest_at.as_i64()) {
+            Ok(paint_commands) => {
+                for command in paint_commands {
+                    command.paint(&painter, rect);
+                }
+            }
+            Err(err) => {
+                re_log::error!("View plugin {:?} failed and was disabled: {err}", self.name);
+                state.instance = Err(err.to_string());
+            }
+        }
+
+        if response.hovered() {
+            ctx.selection_state().set_hovered(Item::View(query.view_id));
+        }
+        if response.clicked() {
+            ctx.selection_state()
+                .set_selection(Item::View(query.view_id));
+        }
+
+        Ok(())
+    }
+}
+
+/// Latest-at results of the given components, for all entities below the view's space origin.
+///
+/// Entities without any of the components are skipped.
+fn query_entities(
+    ctx: &ViewerContext<'_>,
+    query: &ViewQuery<'_>,
+    components: &[String],
+) -> Vec<LatestAtResults> {
+    re_tracing::profile_function!();
+
+    let Some(subtree) = ctx.recording().tree().subtree(query.space_origin) else {
+        return Vec::new();
+    };
+
+    let latest_at_query = LatestAtQuery::new(query.timeline, query.latest_at);
+    let engine = ctx.recording_engine();
+
+    let mut entities = Vec::new();
+    subtree.visit_children_recursively(|entity_path| {
+        let Some(entity_components) = engine
+            .store()
+            .all_components_on_timeline(&query.timeline, entity_path)
+        else {
+            return;
+        };
+        let entity_components = entity_components
+            .into_iter()
+            .filter(|component_name| {
+                components
+                    .iter()
+                    .any(|component| host::is_component(component_name, component))
+            })
+            .collect::<Vec<_>>();
+        if entity_components.is_empty() {
+            return;
+        }
+
+        let results = engine
+            .cache()
+            .latest_at(&latest_at_query, entity_path, entity_components);
+        if !results.components.is_empty() {
+            entities.push(results);
+        }
+    });
+    entities
+}
+
+#[cfg(test)]
+mod tests {
+    use re_viewer_context::ViewClassRegistry;
+
+    use super::*;
+
+    /// A plugin named "Test view" that queries scalars and texts, with the given ui function body.
+    fn plugin(abi_version: i32, ui: &str) -> Result<PluginViewClass, PluginError> {
+        let wat = format!(
+            r#"
+            (module
+                (import "rerun" "entity_count" (func $entity_count (result i32)))
+                (import "rerun" "draw_rect" (func $draw_rect (param f32 f32 f32 f32 i32)))
+                (import "rerun" "draw_circle" (func $draw_circle (param f32 f32 f32 i32)))
+                (import "rerun" "draw_text" (func $draw_text (param f32 f32 f32 i32 i32 i32)))
+                (import "rerun" "draw_line" (func $draw_line (param f32 f32 f32 f32 f32 i32)))
+                (import "rerun" "log" (func $log (param i32 i32)))
+
+                (memory (export "memory") 1)
+                (data (i32.const 0) "Test view")
+                (data (i32.const 16) "Scalar, rerun.components.Text")
+
+                (func (export "rerun_plugin_abi_version") (result i32)
+                    (i32.const {abi_version}))
+                (func (export "rerun_view_class_name") (result i64)
+                    (i64.const 9))
+                (func (export "rerun_view_components") (result i64)
+                    (i64.or (i64.shl (i64.const 16) (i64.const 32)) (i64.const 29)))
+                (func (export "rerun_view_ui") (param $width f32) (param $height f32)
+                    {ui})
+            )
+            "#
+        );
+        let wasm = wat::parse_str(wat).unwrap();
+        PluginViewClass::from_wasm(Path::new("plugins/test.wasm"), &wasm)
+    }
+
+    #[test]
+    fn register() {
+        let class = plugin(crate::ABI_VERSION, "").unwrap();
+        assert_eq!(class.name(), "Test view");
+        assert_eq!(class.components(), ["Scalar", "rerun.components.Text"]);
+
+        let identifier = class.class_identifier();
+        assert_eq!(identifier.as_str(), "Plugin:Test view");
+
+        let mut registry = ViewClassRegistry::default();
+        registry
+            .add_dynamic_class(identifier, Box::new(class))
+            .unwrap();
+        assert_eq!(registry.display_name(identifier), "Test view");
+
+        // Plugins with the same name can't replace each other.
+        let class = plugin(crate::ABI_VERSION, "").unwrap();
+        assert!(matches!(
+            registry.add_dynamic_class(class.class_identifier(), Box::new(class)),
+            Err(ViewClassRegistryError::DuplicateClassIdentifier(_))
+        ));
+    }
+
+    #[test]
+    fn abi_version() {
+        assert!(matches!(
+            plugin(crate::ABI_VERSION + 1, ""),
+            Err(PluginError::AbiVersion { found, expected })
+                if found == crate::ABI_VERSION + 1 && expected == crate::ABI_VERSION
+        ));
+    }
+
+    #[test]
+    fn draw() {
+        let class = plugin(
+            crate::ABI_VERSION,
+            r#"
+            (call $draw_rect (f32.const 0) (f32.const 0) (local.get $width) (local.get $height)
+                (i32.const 0xFF0000FF))
+            (call $draw_circle (f32.const 5) (f32.const 6)
+                (f32.convert_i32_s (call $entity_count)) (i32.const 0x00FF0080))
+            (call $draw_text (f32.const 1) (f32.const 2) (f32.const 12) (i32.const -1)
+                (i32.const 0) (i32.const 4))
+            "#,
+        )
+        .unwrap();
+        let mut instance =
+            PluginInstance::new(&class.engine, &class.module, &class.linker).unwrap();
+
+        let expected = vec![
+            PaintCommand::Rect {
+                rect: egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 50.0)),
+                color: egui::Color32::RED,
+            },
+            PaintCommand::Circle {
+                center: egui::pos2(5.0, 6.0),
+                radius: 0.0,
+                color: egui::Color32::from_rgba_unmultiplied(0, 255, 0, 128),
+            },
+            PaintCommand::Text {
+                pos: egui::pos2(1.0, 2.0),
+                size: 12.0,
+                color: egui::Color32::WHITE,
+                text: "Test".to_owned(),
+            },
+        ];
+
+        // Every frame starts from scratch.
+        for _ in 0..2 {
+            let paint_commands = instance
+                .draw(egui::vec2(100.0, 50.0), Vec::new(), 0)
+                .unwrap();
+            assert_eq!(paint_commands, expected);
+        }
+    }
+
+    #[test]
+    fn invalid_draw_arguments() {
+        // Out-of-range strings and non-finite sizes are dropped, too large sizes are clamped.
+        let class = plugin(
+            crate::ABI_VERSION,
+            r#"
+            (call $draw_text (f32.const 1) (f32.const 2) (f32.const 12) (i32.const -1)
+                (i32.const 0) (i32.const 0x7FFFFFFF))
+            (call $draw_text (f32.const 1) (f32.const 2) (f32.const inf) (i32.const -1)
+                (i32.const 0) (i32.const 4))
+            (call $draw_circle (f32.const 5) (f32.const 6) (f32.const nan) (i32.const -1))
+            (call $draw_line (f32.const 0) (f32.const 0) (f32.const 1) (f32.const 1)
+                (f32.const 1e30) (i32.const -1))
+            "#,
+        )
+        .unwrap();
+        let mut instance =
+            PluginInstance::new(&class.engine, &class.module, &class.linker).unwrap();
+
+        let paint_commands = instance
+            .draw(egui::vec2(100.0, 50.0), Vec::new(), 0)
+            .unwrap();
+        assert_eq!(
+            paint_commands,
+            vec![PaintCommand::Line {
+                from: egui::Pos2::ZERO,
+                to: egui::pos2(1.0, 1.0),
+                stroke: egui::Stroke::new(host::MAX_PAINT_SIZE, egui::Color32::WHITE),
+            }]
+        );
+    }
+
+    #[test]
+    fn out_of_fuel() {
+        let class = plugin(crate::ABI_VERSION, "(loop $forever (br $forever))").unwrap();
+        let mut instance =
+            PluginInstance::new(&class.engine, &class.module, &class.linker).unwrap();
+
+        assert!(instance
+            .draw(egui::vec2(100.0, 50.0), Vec::new(), 0)
+            .is_err());
+    }
+
+    #[test]
+    fn memory_limit() {
+        // Growing the memory past the limit fails, which the plugin reports by drawing a circle.
+        let class = plugin(
+            crate::ABI_VERSION,
+            &format!(
+                r#"
+                (if (i32.eq (memory.grow (i32.const {pages})) (i32.const -1))
+                    (then (call $draw_circle (f32.const 0) (f32.const 0) (f32.const 1) (i32.const -1))))
+                "#,
+                pages = MAX_MEMORY_BYTES / 65536 + 1,
+            ),
+        )
+        .unwrap();
+        let mut instance =
+            PluginInstance::new(&class.engine, &class.module, &class.linker).unwrap();
+
+        let paint_commands = instance
+            .draw(egui::vec2(100.0, 50.0), Vec::new(), 0)
+            .unwrap();
+        assert_eq!(paint_commands.len(), 1);
+    }
+
+    #[test]
+    fn paint_command_limit() {
+        let class = plugin(
+            crate::ABI_VERSION,
+            &format!(
+                r#"
+                (local $i i32)
+                (loop $lines
+                    (call $draw_line (f32.const 0) (f32.const 0) (f32.const 1) (f32.const 1)
+                        (f32.const 1) (i32.const -1))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br_if $lines (i32.lt_u (local.get $i) (i32.const {count}))))
+                "#,
+                count = host::MAX_PAINT_COMMANDS_PER_FRAME + 10,
+            ),
+        )
+        .unwrap();
+        let mut instance =
+            PluginInstance::new(&class.engine, &class.module, &class.linker).unwrap();
+
+        let paint_commands = instance
+            .draw(egui::vec2(100.0, 50.0), Vec::new(), 0)
+            .unwrap();
+        assert_eq!(
+            paint_commands.len(),
+            host::MAX_PAINT_COMMANDS_PER_FRAME,
+            "Commands past the limit are dropped"
+        );
+    }
+
+    #[test]
+    fn log_message_limit() {
+        let class = plugin(
+            crate::ABI_VERSION,
+            &format!(
+                r#"
+                (local $i i32)
+                (loop $messages
+                    (call $log (i32.const 0) (i32.const 4))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br_if $messages (i32.lt_u (local.get $i) (i32.const {count}))))
+                "#,
+                count = host::MAX_LOG_MESSAGES_PER_FRAME + 10,
+            ),
+        )
+        .unwrap();
+        let mut instance =
+            PluginInstance::new(&class.engine, &class.module, &class.linker).unwrap();
+
+        // The limit applies to every frame anew.
+        for _ in 0..2 {
+            instance
+                .draw(egui::vec2(100.0, 50.0), Vec::new(), 0)
+                .unwrap();
+            assert_eq!(
+                instance.store.data().num_log_messages,
+                host::MAX_LOG_MESSAGES_PER_FRAME,
+                "Messages past the limit are dropped"
+            );
+        }
+    }
+}
@@ -0,0 +1,761 @@
+//! Interactive annotation tool of the 2D spatial view: bounding boxes, keypoints & polygons.
+//!
+//! Annotations are drawn onto images and logged to the recording at the current time,
+//! as children of the annotated image so that they share its coordinate space (i.e. pixels).
+
+use egui::Color32;
+use re_chunk_store::{Chunk, LatestAtQuery, RowId};
+use re_log_types::{EntityPath, TimePoint};
+use re_types::{
+    archetypes::{Boxes2D, LineStrips2D, Points2D},
+    components::{ClassId, HalfSize2D, KeypointId, LineStrip2D, Position2D},
+    AsComponents, Component,
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{
+    AnnotationMap, Annotations, SystemCommand, SystemCommandSender as _, ViewerContext,
+};
+
+use crate::{
+    contexts::TransformTreeContext,
+    picking::{PickingHitType, PickingResult},
+};
+
+/// The kinds of shapes the annotation tool draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnnotationKind {
+    /// Logged as [`Boxes2D`].
+    BoundingBox,
+
+    /// Logged as [`Points2D`].
+    Keypoint,
+
+    /// Logged as closed [`LineStrips2D`].
+    Polygon,
+}
+
+impl AnnotationKind {
+    /// Name of the entity, below the annotated image, that annotations of this kind are logged to.
+    fn entity_name(self) -> &'static str {
+        match self {
+            Self::BoundingBox => "boxes",
+            Self::Keypoint => "keypoints",
+            Self::Polygon => "polygons",
+        }
+    }
+}
+
+/// A finished annotation, in the coordinates of the annotated image.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnnotationShape {
+    BoundingBox { min: glam::Vec2, max: glam::Vec2 },
+    Keypoint(glam::Vec2),
+    Polygon(Vec<glam::Vec2>),
+}
+
+impl AnnotationShape {
+    fn kind(&self) -> AnnotationKind {
+        match self {
+            Self::BoundingBox { .. } => AnnotationKind::BoundingBox,
+            Self::Keypoint(_) => AnnotationKind::Keypoint,
+            Self::Polygon(_) => AnnotationKind::Polygon,
+        }
+    }
+
+    /// The outline of the shape, closed for boxes & polygons.
+    fn outline(&self) -> Vec<glam::Vec2> {
+        match self {
+            Self::BoundingBox { min, max } => vec![
+                *min,
+                glam::vec2(max.x, min.y),
+                *max,
+                glam::vec2(min.x, max.y),
+                *min,
+            ],
+            Self::Keypoint(point) => vec![*point],
+            Self::Polygon(points) => points.iter().chain(points.first()).copied().collect(),
+        }
+    }
+}
+
+/// The image an annotation is drawn onto.
+#[derive(Clone, Debug, PartialEq)]
+struct AnnotationTarget {
+    entity_path: EntityPath,
+
+    /// Transforms image coordinates into the coordinates of the view's origin.
+    world_from_image: glam::Affine3A,
+}
+
+impl AnnotationTarget {
+    fn image_from_world(&self, point: glam::Vec3) -> glam::Vec2 {
+        self.world_from_image
+            .inverse()
+            .transform_point3(point)
+            .truncate()
+    }
+
+    fn world_from_image(&self, point: glam::Vec2) -> glam::Vec3 {
+        self.world_from_image.transform_point3(point.extend(0.0))
+    }
+}
+
+/// State of the annotation tool of a 2D spatial view.
+#[derive(Clone, Debug, Default)]
+pub struct AnnotationTool {
+    /// The kind of annotation clicks in the view currently draw, if any.
+    pub active: Option<AnnotationKind>,
+
+    /// Class id assigned to new annotations.
+    pub class_id: u16,
+
+    /// Keypoint id assigned to new keypoints.
+    pub keypoint_id: u16,
+
+    /// The image the last annotation was drawn onto.
+    ///
+    /// Kept after an annotation is finished, so the class picker can show the image's classes.
+    target: Option<AnnotationTarget>,
+
+    /// Points placed so far for the annotation in progress, in image coordinates.
+    pending_points: Vec<glam::Vec2>,
+}
+
+impl AnnotationTool {
+    /// Whether clicks in the view draw annotations instead of selecting things.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Whether dragging in the view draws instead of panning.
+    pub fn uses_drag(&self) -> bool {
+        self.active == Some(AnnotationKind::BoundingBox)
+    }
+
+    pub fn set_active(&mut self, active: Option<AnnotationKind>) {
+        if self.active != active {
+            self.active = active;
+            self.pending_points.clear();
+        }
+    }
+
+    /// Starts a new annotation on the given image.
+    fn begin(&mut self, target: AnnotationTarget) {
+        self.target = Some(target);
+        self.pending_points.clear();
+    }
+
+    /// Places a point of the annotation in progress.
+    ///
+    /// Returns the annotation if it is done.
+    fn add_point(&mut self, point: glam::Vec2) -> Option<AnnotationShape> {
+        let kind = self.active?;
+
+        // A double-click also registers as a click: don't place the last point twice.
+        if self.pending_points.last() == Some(&point) {
+            return None;
+        }
+        self.pending_points.push(point);
+
+        match kind {
+            AnnotationKind::Keypoint => self.finish(),
+            AnnotationKind::BoundingBox | AnnotationKind::Polygon => None,
+        }
+    }
+
+    /// Finishes the annotation in progress, if it has enough points.
+    fn finish(&mut self) -> Option<AnnotationShape> {
+        let shape = self.pending_shape(None)?;
+        let is_degenerate = match &shape {
+            AnnotationShape::BoundingBox { min, max } => min.x >= max.x || min.y >= max.y,
+            AnnotationShape::Keypoint(_) => false,
+            AnnotationShape::Polygon(points) => points.len() < 3,
+        };
+        if is_degenerate {
+            return None;
+        }
+
+        self.pending_points.clear();
+        Some(shape)
+    }
+
+    fn cancel(&mut self) {
+        self.pending_points.clear();
+    }
+
+    /// The annotation in progress, with an additional preview point at the pointer.
+    fn pending_shape(&self, pointer: Option<glam::Vec2>) -> Option<AnnotationShape> {
+        let kind = self.active?;
+        let mut points = self.pending_points.iter().copied().chain(pointer);
+        let first = points.next()?;
+
+        Some(match kind {
+            AnnotationKind::BoundingBox => {
+                let corner = points.last().unwrap_or(first);
+                AnnotationShape::BoundingBox {
+                    min: first.min(corner),
+                    max: first.max(corner),
+                }
+            }
+            AnnotationKind::Keypoint => AnnotationShape::Keypoint(first),
+            AnnotationKind::Polygon => {
+                AnnotationShape::Polygon(std::iter::once(first).chain(points).collect())
+            }
+        })
+    }
+
+    /// The annotation context that applies to the image annotations are drawn onto.
+    fn annotations(
+        &self,
+        ctx: &ViewerContext<'_>,
+        fallback: &EntityPath,
+    ) -> std::sync::Arc<Annotations> {
+        let entity_path = self
+            .target
+            .as_ref()
+            .map_or(fallback, |target| &target.entity_path);
+
+        let mut annotation_map = AnnotationMap::default();
+        annotation_map.load(ctx, &ctx.current_query(), std::iter::once(entity_path));
+        annotation_map.find(entity_path)
+    }
+}
+
+/// Finds the image below the pointer, i.e. the front-most textured rect that was picked.
+fn hovered_image(
+    ctx: &ViewerContext<'_>,
+    transforms: &TransformTreeContext,
+    picking_result: Option<&PickingResult>,
+) -> Option<AnnotationTarget> {
+    picking_result?
+        .hits
+        .iter()
+        .filter(|hit| hit.hit_type == PickingHitType::TexturedRect)
+        .find_map(|hit| {
+            let instance_path = hit.instance_path_hash.resolve(ctx.recording())?;
+            let world_from_image = transforms
+                .transform_info_for_entity(instance_path.entity_path.hash())?
+                .single_entity_transform_silent();
+            Some(AnnotationTarget {
+                entity_path: instance_path.entity_path,
+                world_from_image,
+            })
+        })
+}
+
+/// Returns the part of a batch that was logged at exactly the given time, if any.
+///
+/// This is what allows several annotations to be drawn onto the same frame.
+fn batch_at<C: Component>(
+    ctx: &ViewerContext<'_>,
+    query: &LatestAtQuery,
+    entity_path: &EntityPath,
+) -> Vec<C> {
+    let results = ctx.recording().latest_at(query, entity_path, [C::name()]);
+    if results.index().0 == query.at() {
+        results.component_batch::<C>().unwrap_or_default()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Logs an annotation to the recording, next to the annotations already drawn onto the
+/// same image at the current time.
+///
+/// The edit goes through the recording's undo history, see [`SystemCommand::EditRecording`].
+fn log_annotation(
+    ctx: &ViewerContext<'_>,
+    image_path: &EntityPath,
+    shape: &AnnotationShape,
+    class_id: u16,
+    keypoint_id: u16,
+) {
+    let query = ctx.current_query();
+    if query.at().is_static() {
+        return;
+    }
+    let timepoint = TimePoint::default().with(query.timeline(), query.at());
+
+    let entity_path = image_path
+        .join(&EntityPath::from_single_string("annotations"))
+        .join(&EntityPath::from_single_string(shape.kind().entity_name()));
+
+    // Batches that don't line up (e.g. because something else logged to the entity) are
+    // truncated to the length of the main batch, and missing ids are filled in.
+    fn aligned<C: Clone>(mut batch: Vec<C>, len: usize, fill: C) -> Vec<C> {
+        batch.resize(len, fill);
+        batch
+    }
+
+    let archetype: Box<dyn AsComponents> = match shape {
+        AnnotationShape::BoundingBox { min, max } => {
+            let mut centers = batch_at::<Position2D>(ctx, &query, &entity_path);
+            let mut half_sizes = batch_at::<HalfSize2D>(ctx, &query, &entity_path);
+            let len = centers.len().min(half_sizes.len());
+            centers.truncate(len);
+            half_sizes.truncate(len);
+            let mut class_ids = aligned(
+                batch_at::<ClassId>(ctx, &query, &entity_path),
+                len,
+                ClassId::default(),
+            );
+
+            centers.push(((*min + *max) * 0.5).into());
+            half_sizes.push(((*max - *min) * 0.5).into());
+            class_ids.push(class_id.into());
+
+            Box::new(
+                Boxes2D::from_centers_and_half_sizes(centers, half_sizes).with_class_ids(class_ids),
+            )
+        }
+
+        AnnotationShape::Keypoint(position) => {
+            let mut positions = batch_at::<Position2D>(ctx, &query, &entity_path);
+            let len = positions.len();
+            let mut class_ids = aligned(
+                batch_at::<ClassId>(ctx, &query, &entity_path),
+                len,
+                ClassId::default(),
+            );
+            let mut keypoint_ids = aligned(
+                batch_at::<KeypointId>(ctx, &query, &entity_path),
+                len,
+                KeypointId::from(0_u16),
+            );
+
+            positions.push((*position).into());
+            class_ids.push(class_id.into());
+            keypoint_ids.push(keypoint_id.into());
+
+            Box::new(
+                Points2D::new(positions)
+                    .with_class_ids(class_ids)
+                    .with_keypoint_ids(keypoint_ids),
+            )
+        }
+
+        AnnotationShape::Polygon(_) => {
+            let mut strips = batch_at::<LineStrip2D>(ctx, &query, &entity_path);
+            let len = strips.len();
+            let mut class_ids = aligned(
+                batch_at::<ClassId>(ctx, &query, &entity_path),
+                len,
+                ClassId::default(),
+            );
+
+            strips.push(LineStrip2D::from_iter(shape.outline()));
+            class_ids.push(class_id.into());
+
+            Box::new(LineStrips2D::new(strips).with_class_ids(class_ids))
+        }
+    };
+
+    match Chunk::builder(entity_path.clone())
+        .with_archetype(RowId::new(), timepoint, archetype.as_ref())
+        .build()
+    {
+        Ok(chunk) => {
+            ctx.command_sender.send_system(SystemCommand::EditRecording(
+                ctx.recording_id(),
+                vec![chunk],
+            ));
+        }
+        Err(err) => {
+            re_log::error!("Failed to log annotation to {entity_path}: {err}");
+        }
+    }
+}
+
+/// Handles the interactions of the annotation tool and returns the shape of the annotation in
+/// progress.
+///
+/// `scene_from_ui` unprojects ui positions to scene positions,
+/// `ui_from_scene` projects scene positions to ui positions.
+#[allow(clippy::too_many_arguments)]
+pub fn annotation_tool_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    response: &egui::Response,
+    tool: &mut AnnotationTool,
+    transforms: &TransformTreeContext,
+    picking_result: Option<&PickingResult>,
+    scene_from_ui: impl Fn(egui::Pos2) -> glam::Vec3,
+    ui_from_scene: impl Fn(glam::Vec3) -> egui::Pos2,
+) -> Vec<egui::Shape> {
+    let Some(kind) = tool.active else {
+        return Vec::new();
+    };
+    if ctx.rec_cfg.time_ctrl.read().time_int().is_none() {
+        // Static data can't be annotated.
+        return Vec::new();
+    }
+
+    let hovered_image = || hovered_image(ctx, transforms, picking_result);
+    if response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
+    }
+
+    let (escape, enter) = if response.hovered() {
+        ui.input(|i| {
+            (
+                i.key_pressed(egui::Key::Escape),
+                i.key_pressed(egui::Key::Enter),
+            )
+        })
+    } else {
+        (false, false)
+    };
+
+    let hovered_point = response.hover_pos().map(&scene_from_ui);
+    let image_point =
+        |tool: &AnnotationTool| Some(tool.target.as_ref()?.image_from_world(hovered_point?));
+
+    let mut finished = None;
+    if escape {
+        tool.cancel();
+    } else {
+        match kind {
+            AnnotationKind::BoundingBox => {
+                if response.drag_started() {
+                    if let Some(target) = hovered_image() {
+                        // By now the pointer has moved past the drag threshold,
+                        // so the box starts where the button was pressed instead.
+                        let press_point = ui
+                            .input(|i| i.pointer.press_origin())
+                            .map(&scene_from_ui)
+                            .or(hovered_point)
+                            .map(|point| target.image_from_world(point));
+                        tool.begin(target);
+                        if let Some(point) = press_point {
+                            tool.add_point(point);
+                        }
+                    }
+                } else if response.drag_stopped() {
+                    if let Some(point) = image_point(tool) {
+                        tool.add_point(point);
+                    }
+                    finished = tool.finish();
+                    tool.cancel();
+                }
+            }
+
+            AnnotationKind::Keypoint => {
+                if response.clicked() {
+                    if let Some(target) = hovered_image() {
+                        tool.begin(target);
+                        finished = image_point(tool).and_then(|point| tool.add_point(point));
+                    }
+                }
+            }
+
+            AnnotationKind::Polygon => {
+                if response.clicked() {
+                    if tool.pending_points.is_empty() {
+                        if let Some(target) = hovered_image() {
+                            tool.begin(target);
+                        }
+                    }
+                    if tool.target.is_some() {
+                        if let Some(point) = image_point(tool) {
+                            tool.add_point(point);
+                        }
+                    }
+                }
+                if enter || response.double_clicked() {
+                    finished = tool.finish();
+                }
+            }
+        }
+    }
+
+    if let (Some(shape), Some(target)) = (&finished, &tool.target) {
+        log_annotation(
+            ctx,
+            &target.entity_path,
+            shape,
+            tool.class_id,
+            tool.keypoint_id,
+        );
+    }
+
+    let Some(target) = &tool.target else {
+        return Vec::new();
+    };
+    if tool.pending_points.is_empty() {
+        return Vec::new();
+    }
+    let Some(pending) = tool.pending_shape(image_point(tool)) else {
+        return Vec::new();
+    };
+
+    let annotations = tool.annotations(ctx, &target.entity_path);
+    let color = annotations
+        .resolved_class_description(Some(tool.class_id.into()))
+        .annotation_info()
+        .color()
+        .unwrap_or(Color32::WHITE);
+
+    let points: Vec<egui::Pos2> = pending
+        .outline()
+        .into_iter()
+        .map(|point| ui_from_scene(target.world_from_image(point)))
+        .collect();
+    let mut shapes = vec![egui::Shape::line(
+        points.clone(),
+        egui::Stroke::new(2.0, color),
+    )];
+    shapes.extend(
+        points
+            .iter()
+            .map(|point| egui::Shape::circle_filled(*point, 3.5, color)),
+    );
+    shapes
+}
+
+/// Selection panel ui for picking the kind & class of annotations drawn onto images.
+pub fn annotation_tool_selection_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    tool: &mut AnnotationTool,
+    space_origin: &EntityPath,
+) {
+    ui.grid_left_hand_label("Annotate")
+        .on_hover_text("Draw bounding boxes, keypoints & polygons onto images");
+    ui.vertical(|ui| {
+        let mut active = tool.active;
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut active, None, "Off");
+            ui.selectable_value(&mut active, Some(AnnotationKind::BoundingBox), "Box")
+                .on_hover_text("Drag from one corner of the box to the opposite one");
+            ui.selectable_value(&mut active, Some(AnnotationKind::Keypoint), "Keypoint")
+                .on_hover_text("Click to place a keypoint");
+            ui.selectable_value(&mut active, Some(AnnotationKind::Polygon), "Polygon")
+                .on_hover_text(
+                    "Click the corners of a polygon, double-click or press enter on the last one",
+                );
+        });
+        tool.set_active(active);
+
+        if !tool.is_active() {
+            return;
+        }
+
+        if ctx.rec_cfg.time_ctrl.read().time_int().is_none() {
+            ui.warning_label(
+                "Select a time to annotate: annotations are logged at the current time",
+            );
+            return;
+        }
+
+        let annotations = tool.annotations(ctx, space_origin);
+        let classes = annotations.class_descriptions();
+
+        ui.horizontal(|ui| {
+            ui.label("Class");
+            if classes.is_empty() {
+                ui.add(egui::DragValue::new(&mut tool.class_id))
+                    .on_hover_text("No annotation context applies to this image");
+            } else {
+                let class_label = |id: u16| {
+                    let label = classes
+                        .iter()
+                        .find(|class| class.info.id == id)
+                        .and_then(|class| class.info.label.as_ref());
+                    match label {
+                        Some(label) => format!("{id}: {label}"),
+                        None => id.to_string(),
+                    }
+                };
+                egui::ComboBox::from_id_salt("annotation_class_id")
+                    .selected_text(class_label(tool.class_id))
+                    .show_ui(ui, |ui| {
+                        for class in &classes {
+                            ui.selectable_value(
+                                &mut tool.class_id,
+                                class.info.id,
+                                class_label(class.info.id),
+                            );
+                        }
+                    });
+            }
+        });
+
+        if tool.active == Some(AnnotationKind::Keypoint) {
+            let keypoints = classes
+                .iter()
+                .find(|class| class.info.id == tool.class_id)
+                .map(|class| class.keypoint_annotations.as_slice())
+                .unwrap_or_default();
+            ui.horizontal(|ui| {
+                ui.label("Keypoint");
+                if keypoints.is_empty() {
+                    ui.add(egui::DragValue::new(&mut tool.keypoint_id));
+                } else {
+                    let keypoint_label = |id: u16| {
+                        let label = keypoints
+                            .iter()
+                            .find(|keypoint| keypoint.id == id)
+                            .and_then(|keypoint| keypoint.label.as_ref());
+                        match label {
+                            Some(label) => format!("{id}: {label}"),
+                            None => id.to_string(),
+                        }
+                    };
+                    egui::ComboBox::from_id_salt("annotation_keypoint_id")
+                        .selected_text(keypoint_label(tool.keypoint_id))
+                        .show_ui(ui, |ui| {
+                            for keypoint in keypoints {
+                                ui.selectable_value(
+                                    &mut tool.keypoint_id,
+                                    keypoint.id,
+                                    keypoint_label(keypoint.id),
+                                );
+                            }
+                        });
+                }
+            });
+        }
+
+        ui.weak("Annotations are logged below the image at the current time. Esc cancels.");
+    });
+    ui.end_row();
+}
+
+#[cfg(test)]
+mod tests {
+    use re_viewer_context::test_context::TestContext;
+
+    use super::*;
+
+    #[test]
+    fn boxes_are_normalized() {
+        let mut tool = AnnotationTool::default();
+        tool.set_active(Some(AnnotationKind::BoundingBox));
+
+        // Dragged from the bottom right to the top left.
+        assert_eq!(tool.add_point(glam::vec2(10.0, 20.0)), None);
+        assert_eq!(tool.add_point(glam::vec2(2.0, 4.0)), None);
+        assert_eq!(
+            tool.finish(),
+            Some(AnnotationShape::BoundingBox {
+                min: glam::vec2(2.0, 4.0),
+                max: glam::vec2(10.0, 20.0),
+            })
+        );
+
+        // A click without a drag is not a box.
+        tool.add_point(glam::vec2(1.0, 1.0));
+        assert_eq!(tool.finish(), None);
+    }
+
+    #[test]
+    fn keypoints_finish_immediately() {
+        let mut tool = AnnotationTool::default();
+        assert_eq!(tool.add_point(glam::Vec2::ONE), None);
+
+        tool.set_active(Some(AnnotationKind::Keypoint));
+        assert_eq!(
+            tool.add_point(glam::Vec2::ONE),
+            Some(AnnotationShape::Keypoint(glam::Vec2::ONE))
+        );
+        assert!(tool.pending_points.is_empty());
+    }
+
+    #[test]
+    fn polygons_need_three_points() {
+        let mut tool = AnnotationTool::default();
+        tool.set_active(Some(AnnotationKind::Polygon));
+
+        tool.add_point(glam::vec2(0.0, 0.0));
+        tool.add_point(glam::vec2(1.0, 0.0));
+        // The click of a double-click doesn't add the same point twice.
+        tool.add_point(glam::vec2(1.0, 0.0));
+        assert_eq!(tool.finish(), None);
+
+        tool.add_point(glam::vec2(1.0, 1.0));
+        let polygon = tool.finish().unwrap();
+        assert_eq!(
+            polygon.outline(),
+            vec![
+                glam::vec2(0.0, 0.0),
+                glam::vec2(1.0, 0.0),
+                glam::vec2(1.0, 1.0),
+                glam::vec2(0.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn boxes_are_appended_to_the_batch_at_the_current_time() {
+        let mut test_context = TestContext::default();
+        let timeline = test_context.active_timeline();
+        let entity_path = EntityPath::from("image/annotations/boxes");
+
+        // A box without class ids logged by someone else at the same time, and one at another time.
+        test_context.log_entity(entity_path.clone(), |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                [(timeline, 5)],
+                &Boxes2D::from_centers_and_half_sizes([(1.0, 1.0)], [(1.0, 1.0)]),
+            )
+        });
+        test_context.log_entity(entity_path.clone(), |builder| {
+            builder.with_archetype(
+                RowId::new(),
+                [(timeline, 4)],
+                &Boxes2D::from_centers_and_half_sizes([(9.0, 9.0)], [(9.0, 9.0)])
+                    .with_class_ids([7_u16]),
+            )
+        });
+        test_context.recording_config.time_ctrl.write().set_time(5);
+
+        let boxes = [
+            (glam::vec2(0.0, 0.0), glam::vec2(2.0, 4.0), 1),
+            (glam::vec2(10.0, 10.0), glam::vec2(20.0, 30.0), 2),
+        ];
+        for (min, max, class_id) in boxes {
+            test_context.run_once_in_egui_central_panel(|ctx, _ui| {
+                log_annotation(
+                    ctx,
+                    &EntityPath::from("image"),
+                    &AnnotationShape::BoundingBox { min, max },
+                    class_id,
+                    0,
+                );
+            });
+            test_context.handle_system_commands();
+        }
+
+        let query = LatestAtQuery::new(timeline, 5);
+        let results = test_context.recording_store.latest_at(
+            &query,
+            &entity_path,
+            [Position2D::name(), HalfSize2D::name(), ClassId::name()],
+        );
+        assert_eq!(
+            results.component_batch::<Position2D>().unwrap(),
+            vec![
+                Position2D::new(1.0, 1.0),
+                Position2D::new(1.0, 2.0),
+                Position2D::new(15.0, 20.0),
+            ]
+        );
+        assert_eq!(
+            results.component_batch::<HalfSize2D>().unwrap(),
+            vec![
+                HalfSize2D::new(1.0, 1.0),
+                HalfSize2D::new(1.0, 2.0),
+                HalfSize2D::new(5.0, 10.0),
+            ]
+        );
+        assert_eq!(
+            results.component_batch::<ClassId>().unwrap(),
+            vec![
+                ClassId::from(0_u16),
+                ClassId::from(1_u16),
+                ClassId::from(2_u16)
+            ],
+            "The missing class id is filled in"
+        );
+    }
+}
@@ -0,0 +1,390 @@
+//! Clipping planes & section boxes of the 3D view, together with the gizmos to drag them around.
+//!
+//! Both are stored as view properties in the blueprint and applied by `re_renderer`
+//! to meshes, points, lines and depth clouds.
+
+use egui::Color32;
+use glam::Vec3;
+use re_renderer::view_builder::MAX_CLIP_PLANES;
+use re_types::{
+    blueprint::{
+        archetypes::{ClipPlanes3D, SectionBox3D},
+        components::Enabled,
+    },
+    components::{HalfSize3D, Plane3D, Position3D, RotationQuat},
+};
+use re_viewer_context::{ComponentFallbackProvider, ViewId, ViewState, ViewerContext};
+use re_viewport_blueprint::{ViewProperty, ViewPropertyQueryError};
+
+/// Color of the section box outline and the gizmo handles.
+const GIZMO_COLOR: Color32 = Color32::from_rgb(80, 200, 255);
+
+/// Radius of a gizmo handle in ui points.
+const HANDLE_RADIUS: f32 = 5.0;
+
+/// Length of the arrow showing the direction a handle can be dragged in, in ui points.
+const HANDLE_ARROW_LENGTH: f32 = 24.0;
+
+/// The section box can't be made smaller than this along any axis.
+const MIN_SECTION_BOX_HALF_SIZE: f32 = 1e-4;
+
+/// How many of the renderer's [`MAX_CLIP_PLANES`] clipping planes the section box uses.
+const NUM_SECTION_BOX_PLANES: usize = 6;
+
+/// An oriented box outside of which the scene is cut away.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SectionBox {
+    pub center: Vec3,
+    pub half_size: Vec3,
+    pub rotation: glam::Quat,
+}
+
+impl SectionBox {
+    /// The outward facing normals of the six faces, in the order +X, -X, +Y, -Y, +Z, -Z.
+    fn face_normals(&self) -> [Vec3; 6] {
+        let [x, y, z] = [Vec3::X, Vec3::Y, Vec3::Z].map(|axis| self.rotation * axis);
+        [x, -x, y, -y, z, -z]
+    }
+
+    fn face_center(&self, face: usize) -> Vec3 {
+        self.center + self.face_normals()[face] * self.half_size[face / 2]
+    }
+
+    /// The box after moving one of its faces outwards by `distance`, leaving the opposite face in place.
+    fn with_face_moved(&self, face: usize, distance: f32) -> Self {
+        let axis = face / 2;
+        let old_half_size = self.half_size[axis];
+        let new_half_size = (old_half_size + 0.5 * distance).max(MIN_SECTION_BOX_HALF_SIZE);
+
+        let mut moved = *self;
+        moved.half_size[axis] = new_half_size;
+        moved.center += self.face_normals()[face] * (new_half_size - old_half_size);
+        moved
+    }
+
+    /// Planes through the six faces that cut away everything outside of the box.
+    pub fn planes(&self) -> [re_math::Plane3; 6] {
+        let normals = self.face_normals();
+        std::array::from_fn(|face| re_math::Plane3 {
+            normal: normals[face],
+            d: normals[face].dot(self.face_center(face)),
+        })
+    }
+
+    /// The twelve edges of the box.
+    fn edges(&self) -> impl Iterator<Item = [Vec3; 2]> + '_ {
+        let corner = |i: usize| {
+            let sign = |bit: usize| if i & bit == 0 { -1.0 } else { 1.0 };
+            self.center + self.rotation * (self.half_size * glam::vec3(sign(1), sign(2), sign(4)))
+        };
+        (0..8).flat_map(move |i| {
+            [1, 2, 4]
+                .into_iter()
+                .filter(move |bit| i & bit == 0)
+                .map(move |bit| [corner(i), corner(i | bit)])
+        })
+    }
+}
+
+/// The clipping planes & section box of a 3D view, as configured in its blueprint.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ViewClipping {
+    /// Enabled clipping planes, empty if clipping planes are disabled.
+    pub planes: Vec<re_math::Plane3>,
+
+    /// The section box, if enabled.
+    pub section_box: Option<SectionBox>,
+}
+
+impl ViewClipping {
+    /// Reads the clipping configuration from the view's blueprint.
+    pub fn load(
+        ctx: &ViewerContext<'_>,
+        view_id: ViewId,
+        fallback_provider: &dyn ComponentFallbackProvider,
+        view_state: &dyn ViewState,
+    ) -> Result<Self, ViewPropertyQueryError> {
+        let clip_planes = ViewProperty::from_archetype::<ClipPlanes3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+        let planes = if **clip_planes.component_or_fallback::<Enabled>(
+            ctx,
+            fallback_provider,
+            view_state,
+        )? {
+            clip_planes
+                .component_array_or_fallback::<Plane3D>(ctx, fallback_provider, view_state)?
+                .into_iter()
+                .map(re_math::Plane3::from)
+                .filter(|plane| plane.normal.is_finite() && plane.d.is_finite())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let section_box = ViewProperty::from_archetype::<SectionBox3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+        let section_box = if **section_box.component_or_fallback::<Enabled>(
+            ctx,
+            fallback_provider,
+            view_state,
+        )? {
+            let center = section_box.component_or_fallback::<Position3D>(
+                ctx,
+                fallback_provider,
+                view_state,
+            )?;
+            let half_size = section_box.component_or_fallback::<HalfSize3D>(
+                ctx,
+                fallback_provider,
+                view_state,
+            )?;
+            let rotation = section_box.component_or_fallback::<RotationQuat>(
+                ctx,
+                fallback_provider,
+                view_state,
+            )?;
+            Some(SectionBox {
+                center: center.into(),
+                half_size: Vec3::from(half_size).abs(),
+                rotation: glam::Quat::try_from(rotation.0).unwrap_or(glam::Quat::IDENTITY),
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            planes,
+            section_box,
+        })
+    }
+
+    /// How many clipping planes can be applied next to the section box.
+    pub fn max_applied_planes(&self) -> usize {
+        if self.section_box.is_some() {
+            MAX_CLIP_PLANES - NUM_SECTION_BOX_PLANES
+        } else {
+            MAX_CLIP_PLANES
+        }
+    }
+
+    /// The clipping planes that are applied, the rest doesn't fit into the renderer.
+    pub fn applied_planes(&self) -> &[re_math::Plane3] {
+        &self.planes[..self.planes.len().min(self.max_applied_planes())]
+    }
+
+    /// All planes the renderer should clip the scene with.
+    ///
+    /// The section box comes first, so that it never loses any of its faces.
+    pub fn renderer_planes(&self) -> Vec<re_math::Plane3> {
+        let mut planes = Vec::new();
+        if let Some(section_box) = &self.section_box {
+            planes.extend(section_box.planes());
+        }
+        planes.extend_from_slice(self.applied_planes());
+        planes
+    }
+}
+
+/// Handles dragging the clipping planes & section box faces and returns the shapes of their gizmos.
+///
+/// Clipping plane handles are placed on the plane, as close as possible to `reference_point`.
+/// `ui_from_world` projects world positions to ui positions, returning `None` for positions
+/// that can't be shown (e.g. behind the camera).
+pub fn clipping_gizmos_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    view_id: ViewId,
+    clipping: &ViewClipping,
+    reference_point: Vec3,
+    ui_from_world: impl Fn(Vec3) -> Option<egui::Pos2>,
+) -> Vec<egui::Shape> {
+    let mut shapes = Vec::new();
+
+    for (i, plane) in clipping.applied_planes().iter().enumerate() {
+        let handle_pos =
+            reference_point - plane.normal * (plane.normal.dot(reference_point) - plane.d);
+        let id = ui.id().with(("clip_plane_gizmo", view_id, i));
+        if let Some(distance) = gizmo_handle_ui(
+            ui,
+            id,
+            handle_pos,
+            plane.normal,
+            &ui_from_world,
+            &mut shapes,
+        ) {
+            let mut planes = clipping.planes.clone();
+            planes[i].d += distance;
+            let planes: Vec<Plane3D> = planes.into_iter().map(Plane3D::from).collect();
+            ViewProperty::from_archetype::<ClipPlanes3D>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                view_id,
+            )
+            .save_blueprint_component(ctx, &planes);
+        }
+    }
+
+    if let Some(section_box) = &clipping.section_box {
+        let stroke = egui::Stroke::new(1.5, GIZMO_COLOR);
+        for [a, b] in section_box.edges() {
+            if let (Some(a), Some(b)) = (ui_from_world(a), ui_from_world(b)) {
+                shapes.push(egui::Shape::line_segment([a, b], stroke));
+            }
+        }
+
+        for (face, normal) in section_box.face_normals().into_iter().enumerate() {
+            let id = ui.id().with(("section_box_gizmo", view_id, face));
+            if let Some(distance) = gizmo_handle_ui(
+                ui,
+                id,
+                section_box.face_center(face),
+                normal,
+                &ui_from_world,
+                &mut shapes,
+            ) {
+                let moved = section_box.with_face_moved(face, distance);
+                let property = ViewProperty::from_archetype::<SectionBox3D>(
+                    ctx.blueprint_db(),
+                    ctx.blueprint_query,
+                    view_id,
+                );
+                property.save_blueprint_component(ctx, &Position3D::from(moved.center));
+                property.save_blueprint_component(ctx, &HalfSize3D::from(moved.half_size));
+            }
+        }
+    }
+
+    shapes
+}
+
+/// Shows a handle at `position` that can be dragged along `direction`.
+///
+/// Returns how far the handle was dragged along `direction` in world units, if it was dragged at all.
+fn gizmo_handle_ui(
+    ui: &egui::Ui,
+    id: egui::Id,
+    position: Vec3,
+    direction: Vec3,
+    ui_from_world: &impl Fn(Vec3) -> Option<egui::Pos2>,
+    shapes: &mut Vec<egui::Shape>,
+) -> Option<f32> {
+    let handle_pos = ui_from_world(position)?;
+    // How far the handle moves on screen when moving one world unit along `direction`.
+    let ui_per_world_unit = ui_from_world(position + direction)? - handle_pos;
+
+    let response = ui.interact(
+        egui::Rect::from_center_size(handle_pos, egui::Vec2::splat(3.0 * HANDLE_RADIUS)),
+        id,
+        egui::Sense::drag(),
+    );
+    if response.dragged() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+    } else if response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+    }
+
+    let color = if response.hovered() || response.dragged() {
+        Color32::WHITE
+    } else {
+        GIZMO_COLOR
+    };
+    if ui_per_world_unit.length_sq() > f32::EPSILON {
+        let arrow_tip = handle_pos + ui_per_world_unit.normalized() * HANDLE_ARROW_LENGTH;
+        shapes.push(egui::Shape::line_segment(
+            [handle_pos, arrow_tip],
+            egui::Stroke::new(2.0, color),
+        ));
+        shapes.push(egui::Shape::circle_filled(arrow_tip, 2.0, color));
+    }
+    shapes.push(egui::Shape::circle_filled(handle_pos, HANDLE_RADIUS, color));
+
+    let drag_delta = response.drag_delta();
+    // Dragging perpendicular to the screen can't be expressed as a pointer movement.
+    if drag_delta == egui::Vec2::ZERO || ui_per_world_unit.length_sq() < 1e-6 {
+        return None;
+    }
+    Some(drag_delta.dot(ui_per_world_unit) / ui_per_world_unit.length_sq())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_box_planes_contain_inside() {
+        let section_box = SectionBox {
+            center: glam::vec3(1.0, 2.0, 3.0),
+            half_size: glam::vec3(1.0, 2.0, 0.5),
+            rotation: glam::Quat::from_rotation_z(0.3),
+        };
+        let signed_distance = |plane: &re_math::Plane3, p: Vec3| plane.normal.dot(p) - plane.d;
+
+        for plane in section_box.planes() {
+            assert!(signed_distance(&plane, section_box.center) < 0.0);
+        }
+        for face in 0..6 {
+            let outside = section_box.face_center(face) + section_box.face_normals()[face] * 0.01;
+            assert!(section_box
+                .planes()
+                .iter()
+                .any(|plane| signed_distance(plane, outside) > 0.0));
+        }
+        assert_eq!(section_box.edges().count(), 12);
+    }
+
+    #[test]
+    fn renderer_planes_keep_section_box() {
+        let plane = |d: f32| re_math::Plane3 { normal: Vec3::Z, d };
+        let section_box = SectionBox {
+            center: Vec3::ZERO,
+            half_size: Vec3::ONE,
+            rotation: glam::Quat::IDENTITY,
+        };
+        let mut clipping = ViewClipping {
+            planes: (0..10).map(|i| plane(i as f32)).collect(),
+            section_box: None,
+        };
+
+        assert_eq!(
+            clipping.renderer_planes(),
+            clipping.planes[..MAX_CLIP_PLANES]
+        );
+
+        clipping.section_box = Some(section_box);
+        let planes = clipping.renderer_planes();
+        assert_eq!(planes.len(), MAX_CLIP_PLANES);
+        assert_eq!(planes[..NUM_SECTION_BOX_PLANES], section_box.planes());
+        assert_eq!(
+            planes[NUM_SECTION_BOX_PLANES..],
+            clipping.planes[..MAX_CLIP_PLANES - NUM_SECTION_BOX_PLANES]
+        );
+    }
+
+    #[test]
+    fn section_box_face_drag_keeps_opposite_face() {
+        let section_box = SectionBox {
+            center: Vec3::ZERO,
+            half_size: Vec3::ONE,
+            rotation: glam::Quat::IDENTITY,
+        };
+
+        let moved = section_box.with_face_moved(0, 1.0);
+        assert_eq!(moved.center, glam::vec3(0.5, 0.0, 0.0));
+        assert_eq!(moved.half_size, glam::vec3(1.5, 1.0, 1.0));
+        assert!(moved
+            .face_center(1)
+            .abs_diff_eq(section_box.face_center(1), 1e-6));
+
+        let collapsed = section_box.with_face_moved(3, -10.0);
+        assert_eq!(collapsed.half_size.y, MIN_SECTION_BOX_HALF_SIZE);
+        assert!(collapsed
+            .face_center(2)
+            .abs_diff_eq(section_box.face_center(2), 1e-6));
+    }
+}
@@ -39,6 +39,12 @@ pub struct TransformInfo {
     /// TODO(#2663, #1025): Going forward we should have separate transform hierarchies for 2D (i.e. projected) and 3D,
     /// which would remove the need for this.
     pub twod_in_threed_info: Option<TwoDInThreeDTransformInfo>,
+
+    /// True if this entity is 3D content that is projected into the 2D space of a pinhole camera
+    /// the view is looking through, i.e. `reference_from_entity` contains an inverse pinhole transform.
+    ///
+    /// Such content is subject to the lens distortion of that camera.
+    pub threed_in_twod: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -59,6 +65,7 @@ impl Default for TransformInfo {
             reference_from_entity: glam::Affine3A::IDENTITY,
             reference_from_instances: SmallVec1::new(glam::Affine3A::IDENTITY),
             twod_in_threed_info: None,
+            threed_in_twod: false,
         }
     }
 }
@@ -249,6 +256,7 @@ impl TransformTreeContext {
         let entity_tree = ctx.recording().tree();
 
         let mut reference_from_ancestor = glam::Affine3A::IDENTITY;
+        let mut threed_in_twod = false;
         while let Some(parent_path) = current_tree.path.parent() {
             let Some(parent_tree) = entity_tree.subtree(&parent_path) else {
                 // Unlike not having the space path in the hierarchy, this should be impossible.
@@ -269,8 +277,12 @@ impl TransformTreeContext {
                 &mut None, // Don't care about pinhole encounters.
                 transforms,
             );
+            threed_in_twod |= transforms_at_entity
+                .instance_from_pinhole_image_plane
+                .is_some();
             let new_transform = transform_info_for_upward_propagation(
                 reference_from_ancestor,
+                threed_in_twod,
                 &transforms_at_entity,
             );
 
@@ -301,6 +313,7 @@ impl TransformTreeContext {
         transforms_for_timeline: &CachedTransformsForTimeline,
     ) {
         let twod_in_threed_info = transform.twod_in_threed_info.clone();
+        let threed_in_twod = transform.threed_in_twod;
         let reference_from_parent = transform.reference_from_entity;
         match self.transform_per_entity.entry(subtree.path.hash()) {
             std::collections::hash_map::Entry::Occupied(_) => {
@@ -332,6 +345,7 @@ impl TransformTreeContext {
                 child_path,
                 reference_from_parent,
                 twod_in_threed_info.clone(),
+                threed_in_twod,
                 &transforms_at_entity,
             );
 
@@ -381,6 +395,7 @@ fn lookup_image_plane_distance(
 /// Compute transform info for when we walk up the tree from the reference.
 fn transform_info_for_upward_propagation(
     reference_from_ancestor: glam::Affine3A,
+    threed_in_twod: bool,
     transforms_at_entity: &TransformsAtEntity<'_>,
 ) -> TransformInfo {
     let mut reference_from_entity = reference_from_ancestor;
@@ -430,6 +445,7 @@ fn transform_info_for_upward_propagation(
         // Going up the tree, we can only encounter 2D->3D transforms.
         // 3D->2D transforms can't happen because `Pinhole` represents 3D->2D (and we're walking backwards!)
         twod_in_threed_info: None,
+        threed_in_twod,
     }
 }
 
@@ -438,6 +454,7 @@ fn transform_info_for_downward_propagation(
     current_path: &EntityPath,
     reference_from_parent: glam::Affine3A,
     mut twod_in_threed_info: Option<TwoDInThreeDTransformInfo>,
+    threed_in_twod: bool,
     transforms_at_entity: &TransformsAtEntity<'_>,
 ) -> TransformInfo {
     let mut reference_from_entity = reference_from_parent;
@@ -490,6 +507,7 @@ fn transform_info_for_downward_propagation(
         reference_from_entity,
         reference_from_instances,
         twod_in_threed_info,
+        threed_in_twod,
     }
 }
 
@@ -159,6 +159,24 @@ pub enum EyeMode {
     Orbital,
 }
 
+impl From<re_types::blueprint::components::Eye3DKind> for EyeMode {
+    fn from(kind: re_types::blueprint::components::Eye3DKind) -> Self {
+        match kind {
+            re_types::blueprint::components::Eye3DKind::FirstPerson => Self::FirstPerson,
+            re_types::blueprint::components::Eye3DKind::Orbital => Self::Orbital,
+        }
+    }
+}
+
+impl From<EyeMode> for re_types::blueprint::components::Eye3DKind {
+    fn from(mode: EyeMode) -> Self {
+        match mode {
+            EyeMode::FirstPerson => Self::FirstPerson,
+            EyeMode::Orbital => Self::Orbital,
+        }
+    }
+}
+
 /// An eye (camera) in 3D space, controlled by the user.
 ///
 /// This is either a first person camera or an orbital camera,
@@ -281,6 +299,57 @@ impl ViewEye {
         self.eye_up.try_normalize()
     }
 
+    /// The world-space point the eye is looking at.
+    ///
+    /// In orbit mode this is the orbit center.
+    /// In first person mode, it is the point the orbit center would be at if the user switched to orbit mode.
+    pub fn look_target(&self) -> Vec3 {
+        self.position() + self.orbit_radius * self.fwd()
+    }
+
+    /// Place the eye at `position`, looking at `look_target`.
+    ///
+    /// The distance between the two becomes the new orbit radius.
+    /// If `eye_up` is `None`, the current up-axis of the eye is kept.
+    /// Does nothing if `position` and `look_target` coincide.
+    pub fn set_look_at(&mut self, position: Vec3, look_target: Vec3, eye_up: Option<Vec3>) {
+        let Some(fwd) = (look_target - position).try_normalize() else {
+            return;
+        };
+        let eye_up = eye_up
+            .and_then(|up| up.try_normalize())
+            .unwrap_or(self.eye_up);
+
+        // Fall back to any axis if the up-axis is (anti-)parallel to the viewing direction.
+        let right = fwd
+            .cross(eye_up)
+            .try_normalize()
+            .unwrap_or_else(|| fwd.any_orthonormal_vector());
+        let up = right.cross(fwd);
+
+        // View-space is RUB:
+        self.world_from_view_rot = Quat::from_mat3(&glam::Mat3::from_cols(right, up, -fwd));
+        self.eye_up = eye_up;
+        self.orbit_radius = position.distance(look_target);
+        self.center = match self.mode {
+            EyeMode::FirstPerson => position,
+            EyeMode::Orbital => look_target,
+        };
+        self.velocity = Vec3::ZERO;
+    }
+
+    /// Vertical field of view in radians.
+    pub fn fov_y(&self) -> f32 {
+        self.fov_y
+    }
+
+    /// Set the vertical field of view in radians.
+    pub fn set_fov_y(&mut self, fov_y: f32) {
+        if fov_y.is_finite() && 0.0 < fov_y && fov_y < std::f32::consts::PI {
+            self.fov_y = fov_y;
+        }
+    }
+
     pub fn to_eye(self) -> Eye {
         Eye {
             world_from_rub_view: IsoTransform::from_rotation_translation(
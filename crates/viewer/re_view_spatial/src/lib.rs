@@ -5,10 +5,13 @@
 // TODO(#6330): remove unwrap()
 #![allow(clippy::unwrap_used)]
 
+mod annotation_tool;
+mod clipping;
 mod contexts;
 mod eye;
 mod heuristics;
 mod max_image_dimension_subscriber;
+mod measurements;
 mod mesh_cache;
 mod mesh_loader;
 mod pickable_textured_rect;
@@ -16,6 +19,7 @@ mod picking;
 mod picking_ui;
 mod picking_ui_pixel;
 mod pinhole;
+mod point_cloud_lod;
 mod proc_mesh;
 mod scene_bounding_boxes;
 mod space_camera_3d;
@@ -28,6 +32,7 @@ mod view_2d_properties;
 mod view_3d;
 mod view_3d_properties;
 mod visualizers;
+mod voxel_cache;
 
 mod transform_cache;
 
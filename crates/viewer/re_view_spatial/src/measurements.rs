@@ -0,0 +1,603 @@
+//! Interactive measurement tools of the spatial views: rulers, angles & areas.
+//!
+//! Finished measurements are stored in the view's blueprint
+//! and can be exported to the recording as `LineStrips3D`.
+
+use egui::Color32;
+use re_log_types::{EntityPath, TimePoint};
+use re_types::{
+    archetypes::LineStrips3D,
+    blueprint::{archetypes::Measurements, components::MeasurementKind},
+    components::{LineStrip3D, Position2D, Position3D},
+    Component as _, DeserializationError,
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{SystemCommand, SystemCommandSender as _, ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{contexts::TransformTreeContext, picking::PickingResult, view_kind::SpatialViewKind};
+
+/// Color of measurements drawn on top of the scene.
+const MEASUREMENT_COLOR: Color32 = Color32::from_rgb(255, 200, 40);
+
+/// A single measurement, in the coordinate space of the view's origin.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub kind: MeasurementKind,
+
+    /// For 2D views, all points have a z-coordinate of zero.
+    pub points: Vec<glam::Vec3>,
+}
+
+impl Measurement {
+    /// Minimum number of points needed for a measurement of the given kind.
+    fn min_points(kind: MeasurementKind) -> usize {
+        match kind {
+            MeasurementKind::Distance => 2,
+            MeasurementKind::Angle | MeasurementKind::Area => 3,
+        }
+    }
+
+    /// Number of points after which a measurement of the given kind is done.
+    ///
+    /// `None` if the user decides when the measurement is done.
+    fn max_points(kind: MeasurementKind) -> Option<usize> {
+        match kind {
+            MeasurementKind::Distance => Some(2),
+            MeasurementKind::Angle => Some(3),
+            MeasurementKind::Area => None,
+        }
+    }
+
+    /// The measured value, in scene units (or degrees for angles).
+    ///
+    /// `None` if there aren't enough points yet.
+    pub fn value(&self) -> Option<f32> {
+        if self.points.len() < Self::min_points(self.kind) {
+            return None;
+        }
+
+        Some(match self.kind {
+            MeasurementKind::Distance => self
+                .points
+                .windows(2)
+                .map(|segment| segment[0].distance(segment[1]))
+                .sum(),
+
+            MeasurementKind::Angle => {
+                let [a, vertex, b] = [self.points[0], self.points[1], self.points[2]];
+                (a - vertex).angle_between(b - vertex).to_degrees()
+            }
+
+            MeasurementKind::Area => {
+                // Newell's method: the length of the summed cross products is twice the area
+                // of a planar polygon, no matter how it is oriented in space.
+                let normal: glam::Vec3 = self
+                    .points
+                    .iter()
+                    .zip(self.points.iter().cycle().skip(1))
+                    .map(|(a, b)| a.cross(*b))
+                    .sum();
+                0.5 * normal.length()
+            }
+        })
+    }
+
+    /// Human readable text for the measured value.
+    pub fn label(&self) -> Option<String> {
+        let value = self.value()?;
+        Some(match self.kind {
+            MeasurementKind::Distance => format!("{} units", re_format::format_f32(value)),
+            MeasurementKind::Angle => format!("{value:.1}°"),
+            MeasurementKind::Area => format!("{} units²", re_format::format_f32(value)),
+        })
+    }
+
+    /// Where the label of the measurement is placed.
+    fn label_position(&self) -> Option<glam::Vec3> {
+        match self.kind {
+            MeasurementKind::Distance => {
+                let num_segments = self.points.len().checked_sub(1).filter(|&n| n > 0)?;
+                let segment = (num_segments - 1) / 2;
+                Some(self.points[segment].lerp(self.points[segment + 1], 0.5))
+            }
+            MeasurementKind::Angle => self.points.get(1).copied(),
+            MeasurementKind::Area => {
+                if self.points.is_empty() {
+                    return None;
+                }
+                let sum: glam::Vec3 = self.points.iter().sum();
+                Some(sum / self.points.len() as f32)
+            }
+        }
+    }
+}
+
+/// State of the measurement tool of a spatial view.
+#[derive(Clone, Debug, Default)]
+pub struct MeasurementTool {
+    /// The kind of measurement clicks in the view currently take, if any.
+    pub active: Option<MeasurementKind>,
+
+    /// Points placed so far for the measurement in progress.
+    pending_points: Vec<glam::Vec3>,
+}
+
+impl MeasurementTool {
+    /// Whether clicks in the view place measurement points instead of selecting things.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Places a point of the measurement in progress.
+    ///
+    /// Returns the measurement if it is done.
+    fn add_point(&mut self, point: glam::Vec3) -> Option<Measurement> {
+        let kind = self.active?;
+        self.pending_points.push(point);
+
+        if Measurement::max_points(kind).is_some_and(|max| self.pending_points.len() >= max) {
+            self.finish()
+        } else {
+            None
+        }
+    }
+
+    /// Finishes the measurement in progress, if it has enough points.
+    fn finish(&mut self) -> Option<Measurement> {
+        let kind = self.active?;
+        if self.pending_points.len() < Measurement::min_points(kind) {
+            return None;
+        }
+
+        Some(Measurement {
+            kind,
+            points: std::mem::take(&mut self.pending_points),
+        })
+    }
+
+    pub fn set_active(&mut self, active: Option<MeasurementKind>) {
+        if self.active != active {
+            self.active = active;
+            self.pending_points.clear();
+        }
+    }
+
+    /// The measurement in progress, with an additional preview point at the pointer.
+    fn pending_measurement(&self, pointer: Option<glam::Vec3>) -> Option<Measurement> {
+        let kind = self.active?;
+        if self.pending_points.is_empty() {
+            return None;
+        }
+
+        Some(Measurement {
+            kind,
+            points: self.pending_points.iter().copied().chain(pointer).collect(),
+        })
+    }
+}
+
+/// Reads the measurements stored in the blueprint of a view.
+pub fn load_measurements(
+    property: &ViewProperty,
+) -> Result<Vec<Measurement>, DeserializationError> {
+    let kinds = property.component_array_or_empty::<MeasurementKind>()?;
+    let strips = property.component_array_or_empty::<LineStrip3D>()?;
+
+    Ok(kinds
+        .into_iter()
+        .zip(strips)
+        .map(|(kind, strip)| Measurement {
+            kind,
+            points: strip.0.into_iter().map(Into::into).collect(),
+        })
+        .collect())
+}
+
+fn save_measurements(
+    ctx: &ViewerContext<'_>,
+    property: &ViewProperty,
+    measurements: &[Measurement],
+) {
+    if measurements.is_empty() {
+        property.clear_blueprint_component::<MeasurementKind>(ctx);
+        property.clear_blueprint_component::<LineStrip3D>(ctx);
+        return;
+    }
+
+    let kinds: Vec<MeasurementKind> = measurements.iter().map(|m| m.kind).collect();
+    let strips: Vec<LineStrip3D> = measurements
+        .iter()
+        .map(|m| LineStrip3D::from(m.points.iter().copied()))
+        .collect();
+    property.save_blueprint_component(ctx, &kinds);
+    property.save_blueprint_component(ctx, &strips);
+}
+
+/// Logs the measurements to the recording as `LineStrips3D`, at the current time.
+///
+/// They are logged as a child of the view's origin, so they share its coordinate space.
+fn export_measurements(
+    ctx: &ViewerContext<'_>,
+    space_origin: &EntityPath,
+    measurements: &[Measurement],
+) {
+    let entity_path = space_origin.join(&EntityPath::from_single_string("measurements"));
+
+    let query = ctx.current_query();
+    let timepoint = TimePoint::default().with(query.timeline(), query.at());
+
+    let line_strips = LineStrips3D::new(
+        measurements
+            .iter()
+            .map(|m| LineStrip3D::from(m.points.iter().copied())),
+    )
+    .with_labels(measurements.iter().map(|m| m.label().unwrap_or_default()))
+    .with_colors([MEASUREMENT_COLOR]);
+
+    match re_chunk_store::Chunk::builder(entity_path.clone())
+        .with_archetype(re_chunk_store::RowId::new(), timepoint, &line_strips)
+        .build()
+    {
+        Ok(chunk) => {
+            ctx.command_sender.send_system(SystemCommand::AppendToStore(
+                ctx.recording_id(),
+                vec![chunk],
+            ));
+            re_log::info!(
+                "Exported {} measurement(s) to {entity_path}",
+                measurements.len()
+            );
+        }
+        Err(err) => {
+            re_log::error!("Failed to export measurements: {err}");
+        }
+    }
+}
+
+/// Finds the scene position a click at the pointer should place a measurement point at.
+///
+/// Snaps to the hovered instance of entities with positions (e.g. points),
+/// or to the closest vertex of other hovered entities with positions (e.g. meshes).
+/// Falls back to the position the picking ray hit, and finally to `fallback`.
+pub fn picked_point(
+    ctx: &ViewerContext<'_>,
+    transforms: &TransformTreeContext,
+    picking_result: Option<&PickingResult>,
+    spatial_kind: SpatialViewKind,
+    snap: bool,
+    fallback: Option<glam::Vec3>,
+) -> Option<glam::Vec3> {
+    let flatten = |point: glam::Vec3| match spatial_kind {
+        SpatialViewKind::TwoD => point.truncate().extend(0.0),
+        SpatialViewKind::ThreeD => point,
+    };
+
+    let Some(picking_result) = picking_result else {
+        return fallback.map(flatten);
+    };
+
+    if snap {
+        let query = ctx.current_query();
+
+        for hit in &picking_result.hits {
+            let Some(instance_path) = hit.instance_path_hash.resolve(ctx.recording()) else {
+                continue;
+            };
+            let Some(world_from_entity) = transforms
+                .transform_info_for_entity(instance_path.entity_path.hash())
+                .map(|info| info.single_entity_transform_silent())
+            else {
+                continue;
+            };
+
+            let results = ctx.recording().latest_at(
+                &query,
+                &instance_path.entity_path,
+                [Position3D::name(), Position2D::name()],
+            );
+            let positions: Vec<glam::Vec3> = match spatial_kind {
+                SpatialViewKind::ThreeD => results
+                    .component_batch::<Position3D>()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|position| glam::Vec3::from(position.0)),
+                SpatialViewKind::TwoD => results
+                    .component_batch::<Position2D>()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|position| glam::Vec2::from(position.0).extend(0.0)),
+            }
+            .map(|position| world_from_entity.transform_point3(position))
+            .collect();
+
+            let instance = instance_path.instance;
+            let snapped = if instance.is_specific() {
+                positions.get(instance.get() as usize).copied()
+            } else {
+                None
+            }
+            .or_else(|| {
+                positions.iter().copied().min_by(|a, b| {
+                    a.distance_squared(hit.space_position)
+                        .total_cmp(&b.distance_squared(hit.space_position))
+                })
+            });
+
+            if let Some(snapped) = snapped {
+                return Some(flatten(snapped));
+            }
+        }
+    }
+
+    picking_result.space_position().or(fallback).map(flatten)
+}
+
+/// Handles the interactions of the measurement tool and returns the shapes of all measurements.
+///
+/// `ui_from_scene` projects scene positions to ui positions, returning `None` for positions
+/// that can't be shown (e.g. behind the camera).
+#[allow(clippy::too_many_arguments)]
+pub fn measurements_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    response: &egui::Response,
+    tool: &mut MeasurementTool,
+    view_id: ViewId,
+    hovered_point: impl FnOnce() -> Option<glam::Vec3>,
+    ui_from_scene: impl Fn(glam::Vec3) -> Option<egui::Pos2>,
+) -> Result<Vec<egui::Shape>, DeserializationError> {
+    let property = ViewProperty::from_archetype::<Measurements>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    );
+    let mut measurements = load_measurements(&property)?;
+
+    let mut pointer_point = None;
+    if tool.is_active() && response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
+        pointer_point = hovered_point();
+
+        let (escape, enter) = ui.input(|i| {
+            (
+                i.key_pressed(egui::Key::Escape),
+                i.key_pressed(egui::Key::Enter),
+            )
+        });
+
+        let mut finished = None;
+        if escape {
+            tool.pending_points.clear();
+        } else if response.clicked() {
+            if let Some(point) = pointer_point {
+                finished = tool.add_point(point);
+            }
+        }
+        if finished.is_none() && (enter || response.double_clicked()) {
+            finished = tool.finish();
+        }
+
+        if let Some(measurement) = finished {
+            measurements.push(measurement);
+            save_measurements(ctx, &property, &measurements);
+        }
+    }
+
+    let mut shapes = Vec::new();
+    for measurement in &measurements {
+        measurement_shapes(ui, measurement, &ui_from_scene, &mut shapes);
+    }
+    if let Some(pending) = tool.pending_measurement(pointer_point) {
+        measurement_shapes(ui, &pending, &ui_from_scene, &mut shapes);
+    }
+
+    Ok(shapes)
+}
+
+fn measurement_shapes(
+    ui: &egui::Ui,
+    measurement: &Measurement,
+    ui_from_scene: &impl Fn(glam::Vec3) -> Option<egui::Pos2>,
+    shapes: &mut Vec<egui::Shape>,
+) {
+    let stroke = egui::Stroke::new(2.0, MEASUREMENT_COLOR);
+    let points: Vec<Option<egui::Pos2>> = measurement
+        .points
+        .iter()
+        .map(|point| ui_from_scene(*point))
+        .collect();
+
+    let is_closed = measurement.kind == MeasurementKind::Area
+        && measurement.points.len() >= Measurement::min_points(MeasurementKind::Area);
+    let num_segments = if is_closed {
+        points.len()
+    } else {
+        points.len().saturating_sub(1)
+    };
+    for i in 0..num_segments {
+        if let (Some(a), Some(b)) = (points[i], points[(i + 1) % points.len()]) {
+            shapes.push(egui::Shape::line_segment([a, b], stroke));
+        }
+    }
+    for point in points.iter().flatten() {
+        shapes.push(egui::Shape::circle_filled(*point, 3.5, MEASUREMENT_COLOR));
+    }
+
+    let (Some(label), Some(label_pos)) = (
+        measurement.label(),
+        measurement.label_position().and_then(ui_from_scene),
+    ) else {
+        return;
+    };
+    let galley = ui.fonts(|fonts| {
+        fonts.layout_no_wrap(
+            label,
+            egui::TextStyle::Body.resolve(ui.style()),
+            Color32::BLACK,
+        )
+    });
+    let text_rect =
+        egui::Align2::CENTER_BOTTOM.anchor_size(label_pos - egui::vec2(0.0, 6.0), galley.size());
+    shapes.push(egui::Shape::rect_filled(
+        text_rect.expand2(egui::vec2(4.0, 2.0)),
+        3.0,
+        MEASUREMENT_COLOR,
+    ));
+    shapes.push(egui::Shape::galley(text_rect.min, galley, Color32::BLACK));
+}
+
+/// Selection panel ui for picking a measurement tool and managing the measurements of a view.
+pub fn measurement_tool_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    tool: &mut MeasurementTool,
+    space_origin: &EntityPath,
+    view_id: ViewId,
+) {
+    ui.grid_left_hand_label("Measure")
+        .on_hover_text("Measure distances, angles & areas by clicking in the view");
+    ui.vertical(|ui| {
+        let mut active = tool.active;
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut active, None, "Off");
+            ui.selectable_value(&mut active, Some(MeasurementKind::Distance), "Distance")
+                .on_hover_text("Click two points");
+            ui.selectable_value(&mut active, Some(MeasurementKind::Angle), "Angle")
+                .on_hover_text("Click three points, the angle is measured at the second one");
+            ui.selectable_value(&mut active, Some(MeasurementKind::Area), "Area")
+                .on_hover_text(
+                    "Click the corners of a polygon, double-click or press enter on the last one",
+                );
+        });
+        tool.set_active(active);
+
+        if tool.is_active() {
+            ui.weak("Points snap to the hovered point or vertex. Esc cancels.");
+        }
+
+        let property = ViewProperty::from_archetype::<Measurements>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+        let measurements = match load_measurements(&property) {
+            Ok(measurements) => measurements,
+            Err(err) => {
+                ui.error_label(err.to_string());
+                return;
+            }
+        };
+
+        for (i, measurement) in measurements.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} {}: {}",
+                    measurement.kind,
+                    i + 1,
+                    measurement.label().unwrap_or_default()
+                ));
+                if ui.small_icon_button(&re_ui::icons::REMOVE).clicked() {
+                    let mut measurements = measurements.clone();
+                    measurements.remove(i);
+                    save_measurements(ctx, &property, &measurements);
+                }
+            });
+        }
+
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(!measurements.is_empty(), |ui| {
+                if ui.button("Clear").clicked() {
+                    save_measurements(ctx, &property, &[]);
+                }
+                if ui
+                    .button("Export as LineStrips3D")
+                    .on_hover_text(format!(
+                        "Log the measurements to the recording at {space_origin}/measurements"
+                    ))
+                    .clicked()
+                {
+                    export_measurements(ctx, space_origin, &measurements);
+                }
+            });
+        });
+    });
+    ui.end_row();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(kind: MeasurementKind, points: &[[f32; 3]]) -> Measurement {
+        Measurement {
+            kind,
+            points: points.iter().copied().map(glam::Vec3::from).collect(),
+        }
+    }
+
+    #[test]
+    fn measurement_values() {
+        let distance = measurement(
+            MeasurementKind::Distance,
+            &[[1.0, 1.0, 1.0], [1.0, 4.0, 5.0]],
+        );
+        assert_eq!(distance.value(), Some(5.0));
+
+        let angle = measurement(
+            MeasurementKind::Angle,
+            &[[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [1.0, 1.0, 0.0]],
+        );
+        assert!((angle.value().unwrap() - 45.0).abs() < 1e-4);
+        assert_eq!(angle.label().as_deref(), Some("45.0°"));
+
+        // A 2x3 rectangle, tilted out of every axis plane.
+        let rotation = glam::Quat::from_euler(glam::EulerRot::XYZ, 0.3, 0.7, 1.1);
+        let mut area = measurement(
+            MeasurementKind::Area,
+            &[
+                [0.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0],
+                [2.0, 3.0, 0.0],
+                [0.0, 3.0, 0.0],
+            ],
+        );
+        for point in &mut area.points {
+            *point = rotation * *point + glam::vec3(5.0, -2.0, 1.0);
+        }
+        assert!((area.value().unwrap() - 6.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn not_enough_points() {
+        assert_eq!(
+            measurement(MeasurementKind::Distance, &[[0.0, 0.0, 0.0]]).value(),
+            None
+        );
+        assert_eq!(
+            measurement(MeasurementKind::Area, &[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]).value(),
+            None
+        );
+    }
+
+    #[test]
+    fn tool_finishes_measurements() {
+        let mut tool = MeasurementTool::default();
+        assert_eq!(tool.add_point(glam::Vec3::ZERO), None);
+
+        tool.set_active(Some(MeasurementKind::Distance));
+        assert_eq!(tool.add_point(glam::Vec3::ZERO), None);
+        let distance = tool.add_point(glam::Vec3::X).unwrap();
+        assert_eq!(distance.value(), Some(1.0));
+
+        // Areas are only finished on request, once they have enough points.
+        tool.set_active(Some(MeasurementKind::Area));
+        tool.add_point(glam::Vec3::ZERO);
+        tool.add_point(glam::Vec3::X);
+        assert_eq!(tool.finish(), None);
+        assert_eq!(tool.add_point(glam::Vec3::Y), None);
+        let area = tool.finish().unwrap();
+        assert_eq!(area.value(), Some(0.5));
+        assert!(tool.pending_points.is_empty());
+    }
+}
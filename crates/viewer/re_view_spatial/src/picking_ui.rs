@@ -8,8 +8,8 @@ use re_ui::{
 };
 use re_view::AnnotationSceneContext;
 use re_viewer_context::{
-    Item, ItemContext, UiLayout, ViewQuery, ViewSystemExecutionError, ViewerContext,
-    VisualizerCollection,
+    Item, ItemCollection, ItemContext, UiLayout, ViewQuery, ViewSystemExecutionError,
+    ViewerContext, VisualizerCollection,
 };
 
 use crate::{
@@ -229,7 +229,16 @@ pub fn picking(
         });
     };
 
-    ctx.handle_select_hover_drag_interactions(&response, hovered_items.into_iter(), false);
+    if state.measurement_tool.is_active() || state.annotation_tool.is_active() {
+        // Clicks place measurement or annotation points, so only hover.
+        if response.hovered() {
+            ctx.selection_state().set_hovered(
+                ItemCollection::from(hovered_items.into_iter()).into_mono_instance_path_items(ctx),
+            );
+        }
+    } else {
+        ctx.handle_select_hover_drag_interactions(&response, hovered_items.into_iter(), false);
+    }
 
     Ok(response)
 }
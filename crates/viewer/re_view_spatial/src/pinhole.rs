@@ -9,6 +9,9 @@ use crate::resolution_of_image_at;
 pub struct Pinhole {
     pub image_from_camera: glam::Mat3,
     pub resolution: glam::Vec2,
+
+    /// Lens distortion of the camera, `None` for an ideal pinhole camera.
+    pub distortion: Option<re_renderer::LensDistortion>,
 }
 
 impl Pinhole {
@@ -59,23 +62,63 @@ impl Pinhole {
 
     /// Project camera-space coordinates into pixel coordinates,
     /// returning the same z/depth.
+    ///
+    /// Applies the lens distortion, if any.
     #[inline]
     pub fn project(&self, pixel: glam::Vec3) -> glam::Vec3 {
-        ((pixel.truncate() * self.focal_length_in_pixels()) / pixel.z + self.principal_point())
-            .extend(pixel.z)
+        let mut normalized = pixel.truncate() / pixel.z;
+        if let Some(distortion) = &self.distortion {
+            normalized = distortion.distort(normalized);
+        }
+        (normalized * self.focal_length_in_pixels() + self.principal_point()).extend(pixel.z)
     }
 
     /// Given pixel coordinates and a world-space depth,
     /// return a position in the camera space.
     ///
+    /// Removes the lens distortion, if any.
     /// The returned z is the same as the input z (depth).
     #[inline]
     pub fn unproject(&self, pixel: glam::Vec3) -> glam::Vec3 {
-        ((pixel.truncate() - self.principal_point()) * pixel.z / self.focal_length_in_pixels())
-            .extend(pixel.z)
+        let mut normalized =
+            (pixel.truncate() - self.principal_point()) / self.focal_length_in_pixels();
+        if let Some(distortion) = &self.distortion {
+            normalized = distortion.undistort(normalized);
+        }
+        (normalized * pixel.z).extend(pixel.z)
     }
 }
 
+/// Converts the lens distortion components of a [`archetypes::Pinhole`] to a [`re_renderer::LensDistortion`].
+///
+/// Returns `None` if there are no distortion components or they don't distort anything.
+pub fn lens_distortion_from_components(
+    model: Option<components::LensDistortionModel>,
+    radial: Option<components::RadialDistortion>,
+    tangential: Option<components::TangentialDistortion>,
+) -> Option<re_renderer::LensDistortion> {
+    if model.is_none() && radial.is_none() && tangential.is_none() {
+        return None;
+    }
+
+    let distortion = re_renderer::LensDistortion {
+        model: match model.unwrap_or_default() {
+            components::LensDistortionModel::BrownConrady => {
+                re_renderer::LensDistortionModel::BrownConrady
+            }
+            components::LensDistortionModel::KannalaBrandt => {
+                re_renderer::LensDistortionModel::KannalaBrandt
+            }
+        },
+        radial: radial.map_or(glam::Vec4::ZERO, |radial| glam::Vec4::from(radial.0)),
+        tangential: tangential.map_or(glam::Vec2::ZERO, |tangential| {
+            glam::Vec2::from(tangential.0)
+        }),
+    };
+
+    (!distortion.is_identity()).then_some(distortion)
+}
+
 /// Utility for querying the pinhole from the store.
 ///
 /// Fallback provider will be used for everything but the projection itself.
@@ -100,6 +143,9 @@ pub fn query_pinhole_and_view_coordinates_from_store_without_blueprint(
             archetypes::Pinhole::descriptor_image_from_camera().component_name,
             archetypes::Pinhole::descriptor_resolution().component_name,
             archetypes::Pinhole::descriptor_camera_xyz().component_name,
+            archetypes::Pinhole::descriptor_distortion_model().component_name,
+            archetypes::Pinhole::descriptor_radial_distortion().component_name,
+            archetypes::Pinhole::descriptor_tangential_distortion().component_name,
         ],
     );
 
@@ -119,6 +165,11 @@ pub fn query_pinhole_and_view_coordinates_from_store_without_blueprint(
         Pinhole {
             image_from_camera: pinhole_projection.0.into(),
             resolution: resolution.into(),
+            distortion: lens_distortion_from_components(
+                query_results.component_mono_quiet::<components::LensDistortionModel>(),
+                query_results.component_mono_quiet::<components::RadialDistortion>(),
+                query_results.component_mono_quiet::<components::TangentialDistortion>(),
+            ),
         },
         camera_xyz,
     ))
@@ -0,0 +1,610 @@
+//! Level of detail for huge point clouds.
+//!
+//! Point clouds with millions of points are too big to upload and draw every frame.
+//! Instead we build an octree over them once (in the background) and every frame only draw
+//! as many of its nodes as are needed to reach a target point spacing on screen,
+//! up to a fixed point budget.
+//!
+//! The octree is additive: every node owns an evenly spread subsample of the points in its region,
+//! and its children own the rest. Drawing a node together with all its ancestors therefore
+//! never draws a point twice, and each drawn point keeps its original instance index.
+
+use std::{
+    collections::{hash_map::Entry, BinaryHeap},
+    ops::Range,
+    sync::Arc,
+};
+
+use ahash::{HashMap, HashSet};
+use itertools::Either;
+use ordered_float::OrderedFloat;
+use parking_lot::Mutex;
+use smallvec::SmallVec;
+
+use re_chunk_store::{Chunk, ChunkStoreEvent, RowId};
+use re_log_types::Timeline;
+use re_math::BoundingBox;
+use re_types::{components::Position3D, Component as _};
+use re_viewer_context::{Cache, QueryContext};
+
+use crate::ui::SpatialViewState;
+
+/// Point clouds with fewer points than this are always drawn in full.
+pub const POINT_CLOUD_LOD_MIN_POINTS: usize = 1_000_000;
+
+/// Maximum number of points drawn for a single point cloud.
+const POINT_BUDGET: usize = 2_000_000;
+
+/// Nodes are refined until neighboring points are at most this many pixels apart on screen.
+const MAX_POINT_SPACING_IN_PIXELS: f32 = 1.5;
+
+/// Maximum number of points owned by a single octree node.
+const MAX_POINTS_PER_NODE: usize = 16_384;
+
+/// Stop subdividing at this depth, e.g. for many points at the exact same position.
+const MAX_DEPTH: usize = 20;
+
+// ----------------------------------------------------------------------------
+
+struct OctreeNode {
+    bbox: BoundingBox,
+
+    /// The points owned by this node, as a range into [`PointCloudOctree::point_indices`].
+    points: Range<usize>,
+
+    /// Indices into [`PointCloudOctree::nodes`].
+    children: SmallVec<[u32; 8]>,
+}
+
+/// An additive octree over a point cloud, see module docs.
+pub struct PointCloudOctree {
+    /// The root node comes first.
+    nodes: Vec<OctreeNode>,
+
+    /// Original instance indices, grouped by the node that owns them.
+    point_indices: Vec<u32>,
+
+    /// Bounding box of all points.
+    pub bounding_box: BoundingBox,
+}
+
+impl PointCloudOctree {
+    pub fn build(positions: &[glam::Vec3]) -> Self {
+        re_tracing::profile_function!();
+
+        let bounding_box = BoundingBox::from_points(positions.iter().copied());
+
+        // Cubic cells keep the point spacing estimate the same along all axes.
+        let root_bbox = BoundingBox::from_center_size(
+            bounding_box.center(),
+            glam::Vec3::splat(bounding_box.size().max_element()),
+        );
+
+        let mut octree = Self {
+            nodes: Vec::new(),
+            point_indices: Vec::with_capacity(positions.len()),
+            bounding_box,
+        };
+        octree.build_node(
+            positions,
+            root_bbox,
+            (0..positions.len() as u32).collect(),
+            0,
+        );
+        octree
+    }
+
+    fn build_node(
+        &mut self,
+        positions: &[glam::Vec3],
+        bbox: BoundingBox,
+        indices: Vec<u32>,
+        depth: usize,
+    ) -> u32 {
+        let node_index = self.nodes.len() as u32;
+        self.nodes.push(OctreeNode {
+            bbox,
+            points: 0..0,
+            children: SmallVec::new(),
+        });
+
+        let mut octants: [Vec<u32>; 8] = Default::default();
+        let start = self.point_indices.len();
+        if indices.len() <= MAX_POINTS_PER_NODE || depth >= MAX_DEPTH {
+            self.point_indices.extend_from_slice(&indices);
+        } else {
+            // Keep every n-th point, which spreads them evenly enough across the node for
+            // both scanned and randomly ordered clouds. The rest goes down to the children.
+            let stride = indices.len().div_ceil(MAX_POINTS_PER_NODE);
+            let center = bbox.center();
+            for (i, index) in indices.into_iter().enumerate() {
+                if i % stride == 0 {
+                    self.point_indices.push(index);
+                } else {
+                    let p = positions[index as usize];
+                    let octant = usize::from(p.x >= center.x)
+                        | usize::from(p.y >= center.y) << 1
+                        | usize::from(p.z >= center.z) << 2;
+                    octants[octant].push(index);
+                }
+            }
+        }
+        self.nodes[node_index as usize].points = start..self.point_indices.len();
+
+        let half_size = bbox.size() * 0.5;
+        for (octant, indices) in octants.into_iter().enumerate() {
+            if indices.is_empty() {
+                continue;
+            }
+            let min = bbox.min
+                + half_size
+                    * glam::Vec3::new(
+                        (octant & 1) as f32,
+                        ((octant >> 1) & 1) as f32,
+                        ((octant >> 2) & 1) as f32,
+                    );
+            let child_bbox = BoundingBox::from_min_size(min, half_size);
+            let child = self.build_node(positions, child_bbox, indices, depth + 1);
+            self.nodes[node_index as usize].children.push(child);
+        }
+
+        node_index
+    }
+
+    /// Selects the points to draw for the given camera, coarsest first.
+    ///
+    /// Without a camera, nodes are refined breadth-first until the budget is used up.
+    pub fn select(&self, camera: Option<&LodCamera>, point_budget: usize) -> Vec<u32> {
+        re_tracing::profile_function!();
+
+        // Spacing of a full node's points relative to the node size.
+        let relative_spacing = 1.0 / (MAX_POINTS_PER_NODE as f32).sqrt();
+
+        // How urgently a node needs to be drawn, i.e. the on-screen point spacing
+        // of its parent, which this node refines. `None` if it isn't visible.
+        let priority = |node: &OctreeNode| -> Option<f32> {
+            let node_size = node.bbox.size().max_element();
+            let Some(camera) = camera else {
+                return Some(node_size);
+            };
+            camera
+                .sees(&node.bbox)
+                .then(|| 2.0 * node_size * relative_spacing * camera.pixels_per_unit(&node.bbox))
+        };
+
+        let mut selected = Vec::new();
+        let mut queue = BinaryHeap::new();
+        queue.push((OrderedFloat(f32::INFINITY), 0));
+
+        while let Some((OrderedFloat(spacing), node_index)) = queue.pop() {
+            if camera.is_some() && spacing < MAX_POINT_SPACING_IN_PIXELS {
+                // Everything left in the queue is detailed enough already.
+                break;
+            }
+
+            let node = &self.nodes[node_index as usize];
+            if point_budget < selected.len() + node.points.len() {
+                break;
+            }
+            selected.extend_from_slice(&self.point_indices[node.points.clone()]);
+
+            for &child in &node.children {
+                if let Some(spacing) = priority(&self.nodes[child as usize]) {
+                    queue.push((OrderedFloat(spacing), child));
+                }
+            }
+        }
+
+        selected
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The camera a point cloud is looked at with, in the point cloud's object space.
+pub struct LodCamera {
+    position: glam::Vec3,
+
+    /// Normalized viewing direction.
+    forward: glam::Vec3,
+
+    /// Half of the field of view along the viewport diagonal, in radians.
+    half_fov_diagonal: f32,
+
+    /// Size in pixels of something one unit large at unit distance.
+    pixels_at_unit_distance: f32,
+}
+
+impl LodCamera {
+    /// The camera the 3D view looked through last frame.
+    ///
+    /// `None` for orthographic cameras and points that aren't shown in a 3D view.
+    pub fn from_view(ctx: &QueryContext<'_>, world_from_obj: glam::Affine3A) -> Option<Self> {
+        let view_state = ctx.view_state.as_any().downcast_ref::<SpatialViewState>()?;
+        let eye = view_state.state_3d.view_eye.as_ref()?.to_eye();
+        let fov_y = eye.fov_y?;
+
+        let [width, height] = view_state.state_3d.resolution_in_pixel?;
+        let (width, height) = (width as f32, height.max(1) as f32);
+        let aspect_ratio = width / height;
+
+        let tan_half_fov_y = (fov_y * 0.5).tan();
+        let tan_half_fov_diagonal = tan_half_fov_y * (1.0 + aspect_ratio * aspect_ratio).sqrt();
+
+        let obj_from_world = world_from_obj.inverse();
+
+        Some(Self {
+            position: obj_from_world.transform_point3(eye.pos_in_world()),
+            forward: obj_from_world
+                .transform_vector3(eye.forward_in_world())
+                .normalize_or_zero(),
+            half_fov_diagonal: tan_half_fov_diagonal.atan(),
+            pixels_at_unit_distance: height / (2.0 * tan_half_fov_y),
+        })
+    }
+
+    fn distance_to(&self, bbox: &BoundingBox) -> f32 {
+        let closest = self.position.clamp(bbox.min, bbox.max);
+        closest.distance(self.position)
+    }
+
+    fn pixels_per_unit(&self, bbox: &BoundingBox) -> f32 {
+        self.pixels_at_unit_distance / self.distance_to(bbox).max(f32::EPSILON)
+    }
+
+    /// Conservative test whether any part of the box is in the view cone.
+    fn sees(&self, bbox: &BoundingBox) -> bool {
+        let to_center = bbox.center() - self.position;
+        let distance = to_center.length();
+        let radius = bbox.size().length() * 0.5;
+        if distance <= radius {
+            return true;
+        }
+
+        let angle_to_center = (to_center.dot(self.forward) / distance)
+            .clamp(-1.0, 1.0)
+            .acos();
+        let angular_radius = (radius / distance).asin();
+        angle_to_center - angular_radius <= self.half_fov_diagonal
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How many points of a point cloud with the given size are drawn at most.
+pub fn max_drawn_points(num_points: usize) -> usize {
+    if num_points < POINT_CLOUD_LOD_MIN_POINTS {
+        num_points
+    } else {
+        num_points.min(POINT_BUDGET)
+    }
+}
+
+/// The points of a huge point cloud that should be drawn this frame.
+pub struct PointCloudLod {
+    /// Original instance indices of the points to draw.
+    pub indices: Vec<u32>,
+
+    /// Position in [`Self::indices`] of every highlighted point, by original instance index.
+    pub highlighted: HashMap<u32, u32>,
+
+    /// Bounding box of the full point cloud, if known yet.
+    pub bounding_box: Option<BoundingBox>,
+}
+
+impl PointCloudLod {
+    /// Picks the points to draw for a point cloud with at least [`POINT_CLOUD_LOD_MIN_POINTS`] points.
+    ///
+    /// `position_chunks` has to contain the positions at `row_id`.
+    /// Highlighted points are always drawn, they count against the point budget.
+    /// While its octree is still being built, a uniform subsample is drawn instead.
+    pub fn select(
+        ctx: &QueryContext<'_>,
+        row_id: RowId,
+        position_chunks: &[Chunk],
+        num_points: usize,
+        world_from_obj: glam::Affine3A,
+        highlighted: &[u32],
+    ) -> Self {
+        // There are no threads on the web and building an octree over millions of points would
+        // stall the viewer for seconds, so the web viewer always draws a uniform subsample.
+        let octree = if cfg!(target_arch = "wasm32") {
+            None
+        } else {
+            ctx.viewer_ctx.cache.entry(|c: &mut PointCloudLodCache| {
+                c.entry(
+                    row_id,
+                    position_chunks,
+                    ctx.query.timeline(),
+                    ctx.viewer_ctx.egui_ctx,
+                )
+            })
+        };
+
+        let point_budget = POINT_BUDGET.saturating_sub(highlighted.len()).max(1);
+        let (mut indices, bounding_box) = if let Some(octree) = octree {
+            let camera = LodCamera::from_view(ctx, world_from_obj);
+            (
+                octree.select(camera.as_ref(), point_budget),
+                Some(octree.bounding_box),
+            )
+        } else {
+            let stride = num_points.div_ceil(point_budget);
+            ((0..num_points as u32).step_by(stride).collect(), None)
+        };
+        let highlighted = include_highlighted(&mut indices, highlighted);
+
+        Self {
+            indices,
+            highlighted,
+            bounding_box,
+        }
+    }
+}
+
+/// Adds all `highlighted` points that aren't part of `indices` yet.
+///
+/// Returns the position in `indices` of every highlighted point.
+fn include_highlighted(indices: &mut Vec<u32>, highlighted: &[u32]) -> HashMap<u32, u32> {
+    let mut positions = HashMap::default();
+    if highlighted.is_empty() {
+        return positions;
+    }
+
+    let highlighted_set: HashSet<u32> = highlighted.iter().copied().collect();
+    for (position, index) in indices.iter().enumerate() {
+        if highlighted_set.contains(index) {
+            positions.insert(*index, position as u32);
+        }
+    }
+
+    for &index in highlighted {
+        if let Entry::Vacant(entry) = positions.entry(index) {
+            entry.insert(indices.len() as u32);
+            indices.push(index);
+        }
+    }
+
+    positions
+}
+
+// ----------------------------------------------------------------------------
+
+/// An octree that may still be under construction.
+type PendingOctree = Arc<Mutex<Option<Arc<PointCloudOctree>>>>;
+
+/// A request to build the octree over the positions of a single row.
+struct OctreeJob {
+    row_id: RowId,
+
+    /// The chunk holding the positions of the row.
+    ///
+    /// Chunks share their data, so holding on to one doesn't copy any positions.
+    chunk: Chunk,
+    timeline: Timeline,
+
+    octree: PendingOctree,
+}
+
+impl OctreeJob {
+    fn run(self) {
+        let positions = self
+            .chunk
+            .iter_component_indices(&self.timeline, &Position3D::name())
+            .zip(self.chunk.iter_slices::<[f32; 3]>(Position3D::name()))
+            .find_map(|((_time, row_id), positions)| (row_id == self.row_id).then_some(positions));
+
+        let Some(positions) = positions else {
+            re_log::debug!("No positions at row {} to build an octree for", self.row_id);
+            return;
+        };
+
+        re_log::trace!("Building octree for {} points…", positions.len());
+        let octree = PointCloudOctree::build(bytemuck::cast_slice(positions));
+        *self.octree.lock() = Some(Arc::new(octree));
+    }
+}
+
+#[derive(Default)]
+struct OctreeBuilderState {
+    /// The job to run once the current one is done.
+    next_job: Option<OctreeJob>,
+
+    /// True while a thread is running jobs.
+    is_building: bool,
+}
+
+/// Builds octrees one at a time on a background thread.
+///
+/// Only the most recent request waits for its turn, requests that weren't started yet are superseded.
+/// That way scrubbing through a recording with a huge point cloud on every frame doesn't queue up
+/// builds for frames that aren't shown anymore.
+#[derive(Default)]
+struct OctreeBuilder(Arc<Mutex<OctreeBuilderState>>);
+
+impl OctreeBuilder {
+    /// Queues up `job`, returning the row of the job it supersedes.
+    fn submit(&self, job: OctreeJob, egui_ctx: &egui::Context) -> Option<RowId> {
+        let mut state = self.0.lock();
+        let superseded = state.next_job.replace(job).map(|job| job.row_id);
+
+        if !state.is_building {
+            let builder_state = self.0.clone();
+            let egui_ctx = egui_ctx.clone();
+            let spawned = std::thread::Builder::new()
+                .name("point_cloud_lod".to_owned())
+                .spawn(move || run_octree_jobs(&builder_state, &egui_ctx));
+
+            match spawned {
+                Ok(_) => state.is_building = true,
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Failed to spawn thread for building point cloud octrees: {err}"
+                    );
+                    state.next_job = None;
+                }
+            }
+        }
+
+        superseded
+    }
+}
+
+fn run_octree_jobs(state: &Mutex<OctreeBuilderState>, egui_ctx: &egui::Context) {
+    loop {
+        let job = {
+            let mut state = state.lock();
+            let job = state.next_job.take();
+            state.is_building = job.is_some();
+            job
+        };
+        let Some(job) = job else {
+            return;
+        };
+
+        job.run();
+        egui_ctx.request_repaint();
+    }
+}
+
+/// Caches [`PointCloudOctree`]s based on the [`RowId`] of their positions.
+#[derive(Default)]
+pub struct PointCloudLodCache {
+    octrees: HashMap<RowId, PendingOctree>,
+    builder: OctreeBuilder,
+}
+
+impl PointCloudLodCache {
+    /// Returns the octree for the positions at `row_id`, or `None` while it is still being built.
+    pub fn entry(
+        &mut self,
+        row_id: RowId,
+        position_chunks: &[Chunk],
+        timeline: Timeline,
+        egui_ctx: &egui::Context,
+    ) -> Option<Arc<PointCloudOctree>> {
+        if let Some(octree) = self.octrees.get(&row_id) {
+            return octree.lock().clone();
+        }
+
+        let chunk = position_chunks
+            .iter()
+            .find(|chunk| chunk.row_ids().any(|id| id == row_id))?;
+
+        let octree = PendingOctree::default();
+        self.octrees.insert(row_id, octree.clone());
+
+        let job = OctreeJob {
+            row_id,
+            chunk: chunk.clone(),
+            timeline,
+            octree,
+        };
+        if let Some(superseded) = self.builder.submit(job, egui_ctx) {
+            // Requested again once it is needed.
+            self.octrees.remove(&superseded);
+        }
+
+        None
+    }
+}
+
+impl Cache for PointCloudLodCache {
+    fn purge_memory(&mut self) {
+        self.octrees.clear();
+    }
+
+    fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = || event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_positions =
+                    || event.chunk.components().contains_key(&Position3D::name());
+
+                if is_deletion() && contains_positions() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.octrees
+            .retain(|row_id, _| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(size: usize) -> Vec<glam::Vec3> {
+        (0..size * size * size)
+            .map(|i| {
+                glam::Vec3::new(
+                    (i % size) as f32,
+                    ((i / size) % size) as f32,
+                    (i / (size * size)) as f32,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_point_is_owned_once() {
+        let positions = grid(50);
+        let octree = PointCloudOctree::build(&positions);
+        assert!(octree.nodes.len() > 1);
+
+        let mut indices = octree.point_indices.clone();
+        indices.sort_unstable();
+        assert!(indices.into_iter().eq(0..positions.len() as u32));
+
+        // With enough budget and no camera, everything gets drawn.
+        let mut selected = octree.select(None, usize::MAX);
+        selected.sort_unstable();
+        assert!(selected.into_iter().eq(0..positions.len() as u32));
+    }
+
+    #[test]
+    fn selection_respects_budget_and_view() {
+        let positions = grid(50);
+        let octree = PointCloudOctree::build(&positions);
+
+        let selected = octree.select(None, 3 * MAX_POINTS_PER_NODE);
+        assert!(!selected.is_empty());
+        assert!(selected.len() <= 3 * MAX_POINTS_PER_NODE);
+
+        // Looking away from the cloud only leaves the root.
+        let camera = LodCamera {
+            position: glam::Vec3::splat(-10.0),
+            forward: -glam::Vec3::X,
+            half_fov_diagonal: 0.5,
+            pixels_at_unit_distance: 1000.0,
+        };
+        let selected = octree.select(Some(&camera), usize::MAX);
+        assert_eq!(selected.len(), octree.nodes[0].points.len());
+    }
+    #[test]
+    fn highlighted_points_are_included_once() {
+        let mut indices = vec![0, 10, 20, 30];
+        let highlighted = include_highlighted(&mut indices, &[20, 5, 5, 40]);
+
+        assert_eq!(indices, vec![0, 10, 20, 30, 5, 40]);
+        assert_eq!(highlighted.len(), 3);
+        for (index, position) in highlighted {
+            assert_eq!(indices[position as usize], index);
+        }
+
+        let mut indices = vec![0, 10];
+        assert!(include_highlighted(&mut indices, &[]).is_empty());
+        assert_eq!(indices, vec![0, 10]);
+    }
+}
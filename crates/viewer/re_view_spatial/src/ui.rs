@@ -3,13 +3,20 @@ use egui::{epaint::util::OrderedFloat, text::TextWrapping, NumExt as _, WidgetTe
 use re_format::format_f32;
 use re_math::BoundingBox;
 use re_types::{
-    blueprint::components::VisualBounds2D, components::ViewCoordinates, image::ImageKind,
+    blueprint::components::{Eye3DKind, VisualBounds2D},
+    components::ViewCoordinates,
+    image::ImageKind,
 };
 use re_ui::UiExt as _;
-use re_viewer_context::{HoverHighlight, SelectionHighlight, ViewHighlights, ViewState};
+use re_viewer_context::{
+    HoverHighlight, SelectionHighlight, ViewHighlights, ViewState, ViewerContext,
+};
+use re_viewport_blueprint::ViewProperty;
 
 use crate::{
+    annotation_tool::AnnotationTool,
     eye::EyeMode,
+    measurements::MeasurementTool,
     pickable_textured_rect::PickableRectSourceData,
     picking::{PickableUiRect, PickingResult},
     scene_bounding_boxes::SceneBoundingBoxes,
@@ -18,7 +25,10 @@ use crate::{
     Pinhole,
 };
 
-use super::{eye::Eye, ui_3d::View3DState};
+use super::{
+    eye::Eye,
+    ui_3d::{clear_blueprint_eye_pose, View3DState},
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AutoSizeUnit {
@@ -53,7 +63,17 @@ pub struct SpatialViewState {
     /// Pinhole component logged at the origin if any.
     pub pinhole_at_origin: Option<Pinhole>,
 
+    /// If the pinhole at the origin has lens distortion,
+    /// show images undistorted instead of distorting projected 3D content to match them.
+    pub show_undistorted: bool,
+
     pub visual_bounds_2d: Option<VisualBounds2D>,
+
+    /// The measurement tool, if any, that clicks in the view are used for.
+    pub measurement_tool: MeasurementTool,
+
+    /// The annotation tool, if any, that clicks in 2D views are used for.
+    pub annotation_tool: AnnotationTool,
 }
 
 impl ViewState for SpatialViewState {
@@ -110,10 +130,29 @@ impl SpatialViewState {
         ui.end_row();
     }
 
+    /// Lets the user pick how the lens distortion of the pinhole at the origin is handled, if there is any.
+    pub fn lens_distortion_ui(&mut self, ui: &mut egui::Ui) {
+        if self
+            .pinhole_at_origin
+            .map_or(true, |pinhole| pinhole.distortion.is_none())
+        {
+            return;
+        }
+
+        ui.grid_left_hand_label("Lens distortion");
+        ui.re_checkbox(&mut self.show_undistorted, "Show undistorted")
+            .on_hover_text(
+                "Undistort images instead of distorting 3D content to line up with the images",
+            );
+        ui.end_row();
+    }
+
     // Say the name out loud. It is fun!
     pub fn view_eye_ui(
         &mut self,
+        ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
+        eye_property: &ViewProperty,
         scene_view_coordinates: Option<ViewCoordinates>,
     ) {
         if ui
@@ -126,6 +165,7 @@ impl SpatialViewState {
             self.bounding_boxes.smoothed = self.bounding_boxes.current;
             self.state_3d
                 .reset_camera(&self.bounding_boxes, scene_view_coordinates);
+            clear_blueprint_eye_pose(ctx, eye_property);
         }
 
         {
@@ -144,7 +184,10 @@ impl SpatialViewState {
                 let mut mode = eye.mode();
                 ui.selectable_value(&mut mode, EyeMode::FirstPerson, "First Person");
                 ui.selectable_value(&mut mode, EyeMode::Orbital, "Orbital");
-                eye.set_mode(mode);
+                if mode != eye.mode() {
+                    eye.set_mode(mode);
+                    eye_property.save_blueprint_component(ctx, &Eye3DKind::from(mode));
+                }
             });
         }
     }
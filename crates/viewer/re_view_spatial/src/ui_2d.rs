@@ -3,7 +3,10 @@ use re_math::IsoTransform;
 
 use re_entity_db::EntityPath;
 use re_log::ResultExt as _;
-use re_renderer::view_builder::{TargetConfiguration, ViewBuilder};
+use re_renderer::{
+    view_builder::{TargetConfiguration, ViewBuilder},
+    LensDistortionMode, ViewLensDistortion,
+};
 use re_types::blueprint::{
     archetypes::{Background, NearClipPlane, VisualBounds2D},
     components as blueprint_components,
@@ -17,8 +20,8 @@ use re_viewport_blueprint::ViewProperty;
 
 use super::{eye::Eye, ui::create_labels};
 use crate::{
-    ui::SpatialViewState, view_kind::SpatialViewKind, visualizers::collect_ui_labels, Pinhole,
-    SpatialView2D,
+    contexts::TransformTreeContext, ui::SpatialViewState, view_kind::SpatialViewKind,
+    visualizers::collect_ui_labels, Pinhole, SpatialView2D,
 };
 
 // ---
@@ -66,7 +69,12 @@ fn ui_from_scene(
 
     // --------------------------------------------------------------------------
 
-    let mut pan_delta_in_ui = response.drag_delta();
+    // Dragging draws boxes while annotating.
+    let mut pan_delta_in_ui = if view_state.annotation_tool.uses_drag() {
+        Vec2::ZERO
+    } else {
+        response.drag_delta()
+    };
     if response.hovered() {
         pan_delta_in_ui += response.ctx.input(|i| i.smooth_scroll_delta);
     }
@@ -95,7 +103,7 @@ fn ui_from_scene(
 
     // Update blueprint if changed
     let updated_bounds: blueprint_components::VisualBounds2D = bounds_rect.into();
-    if response.double_clicked() {
+    if response.double_clicked() && !view_state.annotation_tool.is_active() {
         bounds_property.reset_blueprint_component::<blueprint_components::VisualBounds2D>(ctx);
     } else if bounds != updated_bounds {
         bounds_property.save_blueprint_component(ctx, &updated_bounds);
@@ -200,6 +208,7 @@ impl SpatialView2D {
             &query.space_origin.to_string(),
             query.highlights.any_outlines(),
             &state.pinhole_at_origin,
+            state.show_undistorted,
         ) else {
             return Ok(());
         };
@@ -216,7 +225,7 @@ impl SpatialView2D {
 
         let mut view_builder = ViewBuilder::new(ctx.render_ctx, target_config);
 
-        if let Some(pointer_pos_ui) = response.hover_pos() {
+        let response = if let Some(pointer_pos_ui) = response.hover_pos() {
             let picking_context = crate::picking::PickingContext::new(
                 pointer_pos_ui,
                 scene_from_ui,
@@ -234,10 +243,50 @@ impl SpatialView2D {
                 &ui_rects,
                 query,
                 SpatialViewKind::TwoD,
-            )?;
+            )?
         } else {
             state.previous_picking_result = None;
-        }
+            response
+        };
+
+        let transforms = system_output
+            .context_systems
+            .get::<TransformTreeContext>()?;
+        let measurement_shapes = crate::measurements::measurements_ui(
+            ctx,
+            ui,
+            &response,
+            &mut state.measurement_tool,
+            query.view_id,
+            || {
+                let pointer_in_scene = response.hover_pos().map(|pos| {
+                    let pos = scene_from_ui.transform_pos(pos);
+                    glam::vec3(pos.x, pos.y, 0.0)
+                });
+                crate::measurements::picked_point(
+                    ctx,
+                    transforms,
+                    state.previous_picking_result.as_ref(),
+                    SpatialViewKind::TwoD,
+                    !ui.input(|i| i.modifiers.shift),
+                    pointer_in_scene,
+                )
+            },
+            |pos| Some(ui_from_scene.transform_pos(pos2(pos.x, pos.y))),
+        )?;
+        let annotation_shapes = crate::annotation_tool::annotation_tool_ui(
+            ctx,
+            ui,
+            &response,
+            &mut state.annotation_tool,
+            transforms,
+            state.previous_picking_result.as_ref(),
+            |pos| {
+                let pos = scene_from_ui.transform_pos(pos);
+                glam::vec3(pos.x, pos.y, 0.0)
+            },
+            |pos| ui_from_scene.transform_pos(pos2(pos.x, pos.y)),
+        );
 
         for draw_data in system_output.draw_data {
             view_builder.queue_draw(draw_data);
@@ -289,6 +338,8 @@ impl SpatialView2D {
 
         // Add egui-rendered labels on top of everything else:
         painter.extend(label_shapes);
+        painter.extend(measurement_shapes);
+        painter.extend(annotation_shapes);
 
         Ok(())
     }
@@ -301,6 +352,7 @@ fn setup_target_config(
     space_name: &str,
     any_outlines: bool,
     scene_pinhole: &Option<Pinhole>,
+    show_undistorted: bool,
 ) -> anyhow::Result<TargetConfiguration> {
     // ⚠️ When changing this code, make sure to run `tests/rust/test_pinhole_projection`.
 
@@ -338,6 +390,7 @@ fn setup_target_config(
                 principal_point.extend(1.0),
             ),
             resolution,
+            distortion: None,
         }
     };
     let pinhole_rect = Rect::from_min_size(
@@ -392,6 +445,16 @@ fn setup_target_config(
             pixels_per_point,
             outline_config: any_outlines.then(|| re_view::outline_config(egui_painter.ctx())),
             blend_with_background: false,
+            lens_distortion: pinhole.distortion.map(|distortion| ViewLensDistortion {
+                image_from_camera: pinhole.image_from_camera,
+                distortion,
+                mode: if show_undistorted {
+                    LensDistortionMode::UndistortImages
+                } else {
+                    LensDistortionMode::DistortGeometry
+                },
+            }),
+            clip_planes: Vec::new(),
         }
     })
 }
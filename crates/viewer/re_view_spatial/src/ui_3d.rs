@@ -10,10 +10,10 @@ use re_renderer::{
 };
 use re_types::{
     blueprint::{
-        archetypes::{Background, LineGrid3D},
-        components::{GridSpacing, Visible},
+        archetypes::{Background, EyeControls3D, LineGrid3D},
+        components::{Eye3DKind, FieldOfView, GridSpacing, LookTarget, Visible},
     },
-    components::ViewCoordinates,
+    components::{Position3D, Translation3D, Vector3D, ViewCoordinates},
     view_coordinates::SignedAxis3,
 };
 use re_ui::{ContextExt, ModifiersMarkdown, MouseButtonMarkdown};
@@ -27,6 +27,7 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewProperty;
 
 use crate::{
+    contexts::TransformTreeContext,
     scene_bounding_boxes::SceneBoundingBoxes,
     space_camera_3d::SpaceCamera3D,
     ui::{create_labels, SpatialViewState},
@@ -61,6 +62,16 @@ pub struct View3DState {
     /// Used to detect changes in view coordinates, in which case we reset the camera eye.
     pub scene_view_coordinates: Option<ViewCoordinates>,
 
+    /// Eye configuration last read from or written to the blueprint.
+    /// Used to detect changes to the blueprint, in which case we move the eye accordingly.
+    last_blueprint_eye: Option<BlueprintEye>,
+
+    /// True if the user moved the eye since it was last written to the blueprint.
+    eye_save_pending: bool,
+
+    /// Size of the view in pixels the last time it was drawn.
+    pub resolution_in_pixel: Option<[u32; 2]>,
+
     // options:
     spin: bool,
     pub show_axes: bool,
@@ -80,6 +91,9 @@ impl Default for View3DState {
             camera_before_tracked_entity: None,
             eye_interpolation: Default::default(),
             scene_view_coordinates: None,
+            last_blueprint_eye: None,
+            eye_save_pending: false,
+            resolution_in_pixel: None,
             spin: false,
             show_axes: false,
             show_bbox: false,
@@ -108,13 +122,14 @@ impl View3DState {
         self.camera_before_tracked_entity = None;
     }
 
+    /// Returns the updated eye and whether the user interacted with it this frame.
     fn update_eye(
         &mut self,
         response: &egui::Response,
         bounding_boxes: &SceneBoundingBoxes,
         space_cameras: &[SpaceCamera3D],
         scene_view_coordinates: Option<ViewCoordinates>,
-    ) -> ViewEye {
+    ) -> (ViewEye, bool) {
         // If the user has not interacted with the eye-camera yet, continue to
         // interpolate to the new default eye. This gives much better robustness
         // with scenes that change over time.
@@ -196,14 +211,128 @@ impl View3DState {
             0.0
         };
 
-        if view_eye.update(response, view_eye_drag_threshold, bounding_boxes) {
+        let interacted = view_eye.update(response, view_eye_drag_threshold, bounding_boxes);
+        if interacted {
             self.last_eye_interaction = Some(Instant::now());
             self.eye_interpolation = None;
             self.tracked_entity = None;
             self.camera_before_tracked_entity = None;
         }
 
-        *view_eye
+        (*view_eye, interacted)
+    }
+
+    /// Moves the eye to the configuration stored in the blueprint, if it changed since we last saw it.
+    fn apply_blueprint_eye(
+        &mut self,
+        blueprint_eye: BlueprintEye,
+        bounding_boxes: &SceneBoundingBoxes,
+        scene_view_coordinates: Option<ViewCoordinates>,
+    ) {
+        let last = self.last_blueprint_eye.replace(blueprint_eye);
+        if last == Some(blueprint_eye) {
+            return;
+        }
+        let pose = |eye: &BlueprintEye| (eye.position, eye.look_target, eye.eye_up);
+        let pose_changed = last.map_or(true, |last| pose(&last) != pose(&blueprint_eye));
+        let kind_changed = last.map_or(true, |last| last.kind != blueprint_eye.kind);
+        let fov_changed = last.map_or(true, |last| last.fov_y != blueprint_eye.fov_y);
+
+        let is_first_frame = self.view_eye.is_none();
+        let view_eye = self
+            .view_eye
+            .get_or_insert_with(|| default_eye(&bounding_boxes.current, scene_view_coordinates));
+
+        // Set mode & fov first, so that the interpolation below doesn't undo them.
+        if let (true, Some(kind)) = (kind_changed, blueprint_eye.kind) {
+            view_eye.set_mode(kind.into());
+        }
+        if let (true, Some(fov_y)) = (fov_changed, blueprint_eye.fov_y) {
+            view_eye.set_fov_y(fov_y);
+        }
+
+        if !pose_changed {
+            return;
+        }
+        let (Some(position), Some(look_target)) =
+            (blueprint_eye.position, blueprint_eye.look_target)
+        else {
+            return;
+        };
+
+        let mut target = *view_eye;
+        target.set_look_at(position, look_target, blueprint_eye.eye_up);
+
+        // A pose from the blueprint counts as interaction since it should win over the automatic default eye.
+        self.last_eye_interaction = Some(Instant::now());
+        self.tracked_entity = None;
+        self.camera_before_tracked_entity = None;
+
+        if is_first_frame {
+            // Don't animate from the default eye when opening a view.
+            self.view_eye = Some(target);
+        } else {
+            self.interpolate_to_view_eye(target);
+        }
+    }
+
+    /// Returns true if the eye should be written to the blueprint this frame.
+    ///
+    /// Saving on every frame of a drag would flood the blueprint (and its undo history) with intermediate poses,
+    /// so the eye is only saved once the user stopped interacting with it.
+    fn take_pending_eye_save(&mut self, interacted: bool, still_interacting: bool) -> bool {
+        self.eye_save_pending |= interacted;
+        if self.eye_save_pending && !interacted && !still_interacting {
+            self.eye_save_pending = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Writes the current eye pose & mode to the blueprint.
+    fn save_eye_to_blueprint(&mut self, ctx: &ViewerContext<'_>, eye_property: &ViewProperty) {
+        let Some(view_eye) = self.view_eye else {
+            return;
+        };
+
+        let blueprint_eye = BlueprintEye {
+            kind: Some(view_eye.mode().into()),
+            position: Some(view_eye.position()),
+            look_target: Some(view_eye.look_target()),
+            eye_up: view_eye.eye_up(),
+            fov_y: self.last_blueprint_eye.and_then(|eye| eye.fov_y),
+        };
+        if self.last_blueprint_eye == Some(blueprint_eye) {
+            return;
+        }
+        blueprint_eye.save(ctx, eye_property);
+        self.last_blueprint_eye = Some(blueprint_eye);
+    }
+
+    /// Keeps the eye attached to an entity that is followed via [`EyeControls3D`].
+    ///
+    /// With a `tracking_offset`, the eye sits at that offset in the entity's local frame and looks at the entity's origin.
+    /// Otherwise, the eye keeps its orientation & distance and only moves along with the entity.
+    fn follow_entity(&mut self, world_from_entity: Affine3A, tracking_offset: Option<Vec3>) {
+        let Some(view_eye) = self.view_eye else {
+            // Happens only the first frame when there's no eye set up yet.
+            return;
+        };
+
+        let entity_origin = Vec3::from(world_from_entity.translation);
+        let position = if let Some(tracking_offset) = tracking_offset {
+            world_from_entity.transform_point3(tracking_offset)
+        } else {
+            entity_origin + (view_eye.position() - view_eye.look_target())
+        };
+
+        let mut target = view_eye;
+        target.set_look_at(position, entity_origin, None);
+
+        // Following an entity should win over the automatic default eye.
+        self.last_eye_interaction = Some(Instant::now());
+        self.interpolate_to_view_eye(target);
     }
 
     fn interpolate_to_eye(&mut self, target: Eye) {
@@ -337,6 +466,63 @@ impl View3DState {
     }
 }
 
+/// The eye configuration stored in the [`EyeControls3D`] view property.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct BlueprintEye {
+    kind: Option<Eye3DKind>,
+    position: Option<Vec3>,
+    look_target: Option<Vec3>,
+    eye_up: Option<Vec3>,
+    fov_y: Option<f32>,
+}
+
+impl BlueprintEye {
+    fn from_property(eye_property: &ViewProperty) -> Result<Self, ViewSystemExecutionError> {
+        Ok(Self {
+            kind: eye_property.component_or_empty::<Eye3DKind>()?,
+            position: eye_property
+                .component_or_empty::<Position3D>()?
+                .map(Vec3::from),
+            look_target: eye_property
+                .component_or_empty::<LookTarget>()?
+                .map(|look_target| Vec3::from(look_target.0)),
+            eye_up: eye_property
+                .component_or_empty::<Vector3D>()?
+                .map(Vec3::from),
+            fov_y: eye_property
+                .component_or_empty::<FieldOfView>()?
+                .map(|fov_y| *fov_y.0),
+        })
+    }
+
+    /// Saves pose & mode. The field of view is only ever changed by the user directly.
+    fn save(&self, ctx: &ViewerContext<'_>, eye_property: &ViewProperty) {
+        if let Some(kind) = self.kind {
+            eye_property.save_blueprint_component(ctx, &kind);
+        }
+        if let Some(position) = self.position {
+            eye_property.save_blueprint_component(ctx, &Position3D::from(position));
+        }
+        if let Some(look_target) = self.look_target {
+            eye_property.save_blueprint_component(ctx, &LookTarget::from(look_target));
+        }
+        if let Some(eye_up) = self.eye_up {
+            eye_property.save_blueprint_component(ctx, &Vector3D::from(eye_up));
+        } else {
+            eye_property.clear_blueprint_component::<Vector3D>(ctx);
+        }
+    }
+}
+
+/// Removes the eye pose from the blueprint, so that the view falls back to the default eye.
+///
+/// Mode, field of view and the followed entity are kept.
+pub fn clear_blueprint_eye_pose(ctx: &ViewerContext<'_>, eye_property: &ViewProperty) {
+    eye_property.clear_blueprint_component::<Position3D>(ctx);
+    eye_property.clear_blueprint_component::<LookTarget>(ctx);
+    eye_property.clear_blueprint_component::<Vector3D>(ctx);
+}
+
 #[derive(Clone, PartialEq)]
 struct EyeInterpolation {
     elapsed_time: f32,
@@ -448,20 +634,66 @@ impl SpatialView3D {
             return Ok(()); // protect against problems with zero-sized views
         }
 
-        let view_eye = state.state_3d.update_eye(
+        let eye_property = ViewProperty::from_archetype::<EyeControls3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            query.view_id,
+        );
+        state.state_3d.apply_blueprint_eye(
+            BlueprintEye::from_property(&eye_property)?,
+            &state.bounding_boxes,
+            scene_view_coordinates,
+        );
+
+        if let Some(tracking_entity) =
+            eye_property.component_or_empty::<re_types::components::EntityPath>()?
+        {
+            let tracking_entity = EntityPath::from(tracking_entity.0);
+            let world_from_entity = system_output
+                .context_systems
+                .get::<TransformTreeContext>()?
+                .transform_info_for_entity(tracking_entity.hash())
+                .map(|info| info.single_entity_transform_silent())
+                .or_else(|| {
+                    state
+                        .bounding_boxes
+                        .per_entity
+                        .get(&tracking_entity.hash())
+                        .map(|bbox| Affine3A::from_translation(bbox.center()))
+                });
+            if let Some(world_from_entity) = world_from_entity {
+                let tracking_offset = eye_property
+                    .component_or_empty::<Translation3D>()?
+                    .map(|offset| Vec3::from(offset.0));
+                state
+                    .state_3d
+                    .follow_entity(world_from_entity, tracking_offset);
+            }
+        }
+
+        let (view_eye, interacted) = state.state_3d.update_eye(
             &response,
             &state.bounding_boxes,
             space_cameras,
             scene_view_coordinates,
         );
+        if state
+            .state_3d
+            .take_pending_eye_save(interacted, response.dragged())
+        {
+            state.state_3d.save_eye_to_blueprint(ctx, &eye_property);
+        }
         let eye = view_eye.to_eye();
 
+        let clipping = crate::clipping::ViewClipping::load(ctx, query.view_id, self, state)?;
+
         // Determine view port resolution and position.
         let resolution_in_pixel =
             gpu_bridge::viewport_resolution_in_pixels(ui_rect, ui.ctx().pixels_per_point());
         if resolution_in_pixel[0] == 0 || resolution_in_pixel[1] == 0 {
             return Ok(());
         }
+        state.state_3d.resolution_in_pixel = Some(resolution_in_pixel);
 
         let target_config = TargetConfiguration {
             name: query.space_origin.to_string().into(),
@@ -483,6 +715,12 @@ impl SpatialView3D {
                 .any_outlines()
                 .then(|| re_view::outline_config(ui.ctx())),
             blend_with_background: false,
+            lens_distortion: None,
+            clip_planes: clipping
+                .renderer_planes()
+                .iter()
+                .map(re_math::Plane3::as_vec4)
+                .collect(),
         };
 
         // Various ui interactions draw additional lines.
@@ -549,6 +787,48 @@ impl SpatialView3D {
             state.previous_picking_result = None;
         }
 
+        let transforms = system_output
+            .context_systems
+            .get::<TransformTreeContext>()?;
+        let ui_from_world = eye.ui_from_world(ui_rect);
+        let ui_from_world_pos = |pos: glam::Vec3| {
+            let pos_in_ui = ui_from_world * pos.extend(1.0);
+            (pos_in_ui.w > 0.0)
+                .then(|| egui::pos2(pos_in_ui.x / pos_in_ui.w, pos_in_ui.y / pos_in_ui.w))
+        };
+        let measurement_shapes = crate::measurements::measurements_ui(
+            ctx,
+            ui,
+            &response,
+            &mut state.measurement_tool,
+            query.view_id,
+            || {
+                crate::measurements::picked_point(
+                    ctx,
+                    transforms,
+                    state.previous_picking_result.as_ref(),
+                    SpatialViewKind::ThreeD,
+                    !ui.input(|i| i.modifiers.shift),
+                    None,
+                )
+            },
+            ui_from_world_pos,
+        )?;
+
+        let scene_center = state.bounding_boxes.current.center();
+        let clipping_shapes = crate::clipping::clipping_gizmos_ui(
+            ctx,
+            ui,
+            query.view_id,
+            &clipping,
+            if scene_center.is_finite() {
+                scene_center
+            } else {
+                glam::Vec3::ZERO
+            },
+            ui_from_world_pos,
+        );
+
         // Track focused entity if any.
         if let Some(focused_item) = ctx.focused_item {
             let focused_entity = match focused_item {
@@ -561,6 +841,7 @@ impl SpatialView3D {
                         state
                             .state_3d
                             .reset_camera(&state.bounding_boxes, scene_view_coordinates);
+                        clear_blueprint_eye_pose(ctx, &eye_property);
                     }
                     None
                 }
@@ -702,6 +983,8 @@ impl SpatialView3D {
         // Add egui-rendered labels on top of everything else:
         let painter = ui.painter().with_clip_rect(ui.max_rect());
         painter.extend(label_shapes);
+        painter.extend(clipping_shapes);
+        painter.extend(measurement_shapes);
 
         Ok(())
     }
@@ -989,3 +1272,58 @@ fn default_eye(
         eye_up,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eye_is_saved_once_interaction_ends() {
+        let mut state = View3DState::default();
+
+        // Nothing to save without interaction.
+        assert!(!state.take_pending_eye_save(false, false));
+
+        // Dragging around, including frames where the pointer holds still.
+        assert!(!state.take_pending_eye_save(true, true));
+        assert!(!state.take_pending_eye_save(true, true));
+        assert!(!state.take_pending_eye_save(false, true));
+        assert!(!state.take_pending_eye_save(true, true));
+
+        // Releasing the drag saves exactly once.
+        assert!(state.take_pending_eye_save(false, false));
+        assert!(!state.take_pending_eye_save(false, false));
+
+        // Scrolling & keyboard navigation don't drag, they end when there's no more change.
+        assert!(!state.take_pending_eye_save(true, false));
+        assert!(!state.take_pending_eye_save(true, false));
+        assert!(state.take_pending_eye_save(false, false));
+        assert!(!state.take_pending_eye_save(false, false));
+    }
+    #[test]
+    fn blueprint_eye_is_only_applied_when_it_changes() {
+        let mut state = View3DState::default();
+        let bounding_boxes = SceneBoundingBoxes::default();
+        let blueprint_eye = BlueprintEye {
+            kind: None,
+            position: Some(Vec3::new(5.0, 0.0, 0.0)),
+            look_target: Some(Vec3::ZERO),
+            eye_up: None,
+            fov_y: None,
+        };
+
+        // The first eye from the blueprint is taken over without animating towards it.
+        state.apply_blueprint_eye(blueprint_eye, &bounding_boxes, None);
+        let position = state.view_eye.unwrap().position();
+        assert!(position.abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-4));
+
+        // Moving the eye locally isn't undone by the unchanged blueprint.
+        let moved = Vec3::new(0.0, 5.0, 0.0);
+        if let Some(view_eye) = &mut state.view_eye {
+            view_eye.set_look_at(moved, Vec3::ZERO, None);
+        }
+        state.apply_blueprint_eye(blueprint_eye, &bounding_boxes, None);
+        let position = state.view_eye.unwrap().position();
+        assert!(position.abs_diff_eq(moved, 1e-4));
+    }
+}
@@ -237,13 +237,38 @@ impl ViewClass for SpatialView2D {
         ctx: &re_viewer_context::ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
-        _space_origin: &EntityPath,
+        space_origin: &EntityPath,
         view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<SpatialViewState>()?;
         // TODO(andreas): list_item'ify the rest
         ui.selection_grid("spatial_settings_ui").show(ui, |ui| {
             state.bounding_box_ui(ui, SpatialViewKind::TwoD);
+            state.lens_distortion_ui(ui);
+
+            let was_annotating = state.annotation_tool.is_active();
+            crate::measurements::measurement_tool_ui(
+                ctx,
+                ui,
+                &mut state.measurement_tool,
+                space_origin,
+                view_id,
+            );
+            crate::annotation_tool::annotation_tool_selection_ui(
+                ctx,
+                ui,
+                &mut state.annotation_tool,
+                space_origin,
+            );
+
+            // Clicks in the view can only go to one tool: the one picked last wins.
+            if state.measurement_tool.is_active() && state.annotation_tool.is_active() {
+                if was_annotating {
+                    state.annotation_tool.set_active(None);
+                } else {
+                    state.measurement_tool.set_active(None);
+                }
+            }
         });
 
         re_ui::list_item::list_item_scope(ui, "spatial_view2d_selection_ui", |ui| {
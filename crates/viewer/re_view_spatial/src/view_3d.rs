@@ -4,7 +4,7 @@ use nohash_hasher::IntSet;
 
 use re_entity_db::EntityDb;
 use re_log_types::EntityPath;
-use re_types::blueprint::archetypes::LineGrid3D;
+use re_types::blueprint::archetypes::{ClipPlanes3D, EyeControls3D, LineGrid3D, SectionBox3D};
 use re_types::{
     blueprint::archetypes::Background, components::ViewCoordinates, Component, View,
     ViewClassIdentifier,
@@ -372,12 +372,18 @@ impl ViewClass for SpatialView3D {
             .latest_at_component::<ViewCoordinates>(space_origin, &ctx.current_query())
             .map(|(_index, c)| c);
 
+        let eye_property = ViewProperty::from_archetype::<EyeControls3D>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+
         // TODO(andreas): list_item'ify the rest
         ui.selection_grid("spatial_settings_ui").show(ui, |ui| {
             ui.grid_left_hand_label("Camera")
                 .on_hover_text("The virtual camera which controls what is shown on screen");
             ui.vertical(|ui| {
-                state.view_eye_ui(ui, scene_view_coordinates);
+                state.view_eye_ui(ctx, ui, &eye_property, scene_view_coordinates);
             });
             ui.end_row();
 
@@ -416,11 +422,35 @@ impl ViewClass for SpatialView3D {
             ui.end_row();
 
             state.bounding_box_ui(ui, SpatialViewKind::ThreeD);
+
+            crate::measurements::measurement_tool_ui(
+                ctx,
+                ui,
+                &mut state.measurement_tool,
+                space_origin,
+                view_id,
+            );
         });
 
+        let clipping = crate::clipping::ViewClipping::load(ctx, view_id, self, state)?;
+
         re_ui::list_item::list_item_scope(ui, "spatial_view3d_selection_ui", |ui| {
             view_property_ui::<Background>(ctx, ui, view_id, self, state);
+            view_property_ui::<EyeControls3D>(ctx, ui, view_id, self, state);
             view_property_ui_grid3d(ctx, ui, view_id, self, state);
+            view_property_ui::<ClipPlanes3D>(ctx, ui, view_id, self, state);
+            if clipping.applied_planes().len() < clipping.planes.len() {
+                ui.warning_label(format!(
+                    "Only the first {} clipping planes are applied{}",
+                    clipping.max_applied_planes(),
+                    if clipping.section_box.is_some() {
+                        " while the section box is enabled"
+                    } else {
+                        ""
+                    }
+                ));
+            }
+            view_property_ui::<SectionBox3D>(ctx, ui, view_id, self, state);
         });
 
         Ok(())
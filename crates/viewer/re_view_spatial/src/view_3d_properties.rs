@@ -1,14 +1,37 @@
 use re_types::{
     blueprint::{
-        archetypes::{Background, LineGrid3D},
-        components::BackgroundKind,
+        archetypes::{Background, ClipPlanes3D, EyeControls3D, LineGrid3D, SectionBox3D},
+        components::{BackgroundKind, Enabled, Eye3DKind, FieldOfView, LookTarget},
     },
-    components::{Color, Plane3D, StrokeWidth},
+    components::{Color, HalfSize3D, Plane3D, Position3D, RotationQuat, StrokeWidth, Vector3D},
     Archetype as _,
 };
 use re_viewer_context::{TypedComponentFallbackProvider, ViewStateExt as _};
 
-use crate::{ui::SpatialViewState, SpatialView3D};
+use crate::{eye::ViewEye, ui::SpatialViewState, SpatialView3D};
+
+/// The eye the user currently looks through, used as fallback for the [`EyeControls3D`] properties.
+fn current_view_eye(ctx: &re_viewer_context::QueryContext<'_>) -> Option<ViewEye> {
+    if ctx.archetype_name != Some(EyeControls3D::name()) {
+        return None;
+    }
+    ctx.view_state
+        .downcast_ref::<SpatialViewState>()
+        .ok()?
+        .state_3d
+        .view_eye
+}
+
+/// The bounding box of the scene, used as fallback for the extent of [`ClipPlanes3D`] & [`SectionBox3D`].
+fn scene_bounding_box(ctx: &re_viewer_context::QueryContext<'_>) -> Option<re_math::BoundingBox> {
+    let bbox = ctx
+        .view_state
+        .downcast_ref::<SpatialViewState>()
+        .ok()?
+        .bounding_boxes
+        .current;
+    (bbox.min.is_finite() && bbox.max.is_finite()).then_some(bbox)
+}
 
 impl TypedComponentFallbackProvider<Color> for SpatialView3D {
     fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> Color {
@@ -43,12 +66,93 @@ impl TypedComponentFallbackProvider<Plane3D> for SpatialView3D {
             return DEFAULT_PLANE;
         };
 
-        view_state
+        let plane = view_state
             .state_3d
             .scene_view_coordinates
             .and_then(|view_coordinates| view_coordinates.up())
-            .map_or(DEFAULT_PLANE, |up| Plane3D::new(up.as_vec3(), 0.0))
+            .map_or(DEFAULT_PLANE, |up| Plane3D::new(up.as_vec3(), 0.0));
+
+        if ctx.archetype_name == Some(ClipPlanes3D::name()) {
+            // Cut away the upper half of the scene.
+            let center = scene_bounding_box(ctx).map_or(glam::Vec3::ZERO, |bbox| bbox.center());
+            let normal = glam::Vec3::from(plane.normal());
+            Plane3D::new(normal, normal.dot(center))
+        } else {
+            plane
+        }
+    }
+}
+
+impl TypedComponentFallbackProvider<Enabled> for SpatialView3D {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Enabled {
+        false.into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Eye3DKind> for SpatialView3D {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> Eye3DKind {
+        current_view_eye(ctx).map_or_else(Eye3DKind::default, |eye| eye.mode().into())
+    }
+}
+
+impl TypedComponentFallbackProvider<Position3D> for SpatialView3D {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> Position3D {
+        if ctx.archetype_name == Some(SectionBox3D::name()) {
+            return scene_bounding_box(ctx)
+                .map_or_else(Position3D::default, |bbox| bbox.center().into());
+        }
+        current_view_eye(ctx).map_or_else(Position3D::default, |eye| eye.position().into())
+    }
+}
+
+impl TypedComponentFallbackProvider<HalfSize3D> for SpatialView3D {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> HalfSize3D {
+        scene_bounding_box(ctx)
+            .map(|bbox| 0.5 * bbox.size())
+            .filter(|half_size| half_size.max_element() > 0.0)
+            // Give flat scenes some thickness, so that they aren't clipped away entirely.
+            .map(|half_size| half_size.max(glam::Vec3::splat(0.01 * half_size.max_element())))
+            .map_or(HalfSize3D::splat(1.0), HalfSize3D::from)
+    }
+}
+
+impl TypedComponentFallbackProvider<RotationQuat> for SpatialView3D {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> RotationQuat {
+        RotationQuat::IDENTITY
+    }
+}
+
+impl TypedComponentFallbackProvider<LookTarget> for SpatialView3D {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> LookTarget {
+        current_view_eye(ctx).map_or_else(LookTarget::default, |eye| eye.look_target().into())
+    }
+}
+
+impl TypedComponentFallbackProvider<Vector3D> for SpatialView3D {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> Vector3D {
+        current_view_eye(ctx)
+            .and_then(|eye| eye.eye_up())
+            .map_or_else(Vector3D::default, Vector3D::from)
+    }
+}
+
+impl TypedComponentFallbackProvider<FieldOfView> for SpatialView3D {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> FieldOfView {
+        current_view_eye(ctx).map_or_else(FieldOfView::default, |eye| eye.fov_y().into())
     }
 }
 
-re_viewer_context::impl_component_fallback_provider!(SpatialView3D => [BackgroundKind, Color, StrokeWidth, Plane3D]);
+re_viewer_context::impl_component_fallback_provider!(SpatialView3D => [
+    BackgroundKind,
+    Color,
+    StrokeWidth,
+    Plane3D,
+    Enabled,
+    Eye3DKind,
+    Position3D,
+    HalfSize3D,
+    RotationQuat,
+    LookTarget,
+    Vector3D,
+    FieldOfView
+]);
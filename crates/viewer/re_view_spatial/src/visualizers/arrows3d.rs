@@ -77,6 +77,7 @@ impl Arrows3DVisualizer {
             let mut line_batch = line_builder
                 .batch(entity_path.to_string())
                 .world_from_obj(world_from_obj)
+                .lens_distortion(ent_context.transform_info.threed_in_twod)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
@@ -127,6 +127,29 @@ impl CamerasVisualizer {
             pinhole_properties.pinhole.unproject(vec3(w, 0.0, z)),
         ];
 
+        // With lens distortion, the edges of the image are no longer straight lines in camera space.
+        let segments_per_edge = if pinhole_properties.pinhole.distortion.is_some() {
+            16
+        } else {
+            1
+        };
+        let pixel_corners = [
+            vec3(0.0, 0.0, z),
+            vec3(0.0, h, z),
+            vec3(w, h, z),
+            vec3(w, 0.0, z),
+            vec3(0.0, 0.0, z),
+        ];
+        let image_border: Vec<_> = pixel_corners
+            .windows(2)
+            .flat_map(|edge| {
+                (0..segments_per_edge)
+                    .map(move |i| edge[0].lerp(edge[1], i as f32 / segments_per_edge as f32))
+            })
+            .chain(std::iter::once(pixel_corners[0]))
+            .map(|pixel| pinhole_properties.pinhole.unproject(pixel))
+            .collect();
+
         let up_triangle = [
             pinhole_properties.pinhole.unproject(vec3(0.4 * w, 0.0, z)),
             pinhole_properties
@@ -136,24 +159,18 @@ impl CamerasVisualizer {
         ];
 
         let strips = vec![
-            // Frustum rectangle, connected with zero point.
+            // Frustum edges, connecting the corners with the zero point.
+            (
+                vec![corners[0], glam::Vec3::ZERO, corners[2]],
+                LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS,
+            ),
             (
-                vec![
-                    corners[0],
-                    corners[1],
-                    glam::Vec3::ZERO,
-                    corners[2],
-                    corners[3],
-                    glam::Vec3::ZERO,
-                    corners[0],
-                    corners[3],
-                    glam::Vec3::ZERO,
-                ],
+                vec![corners[1], glam::Vec3::ZERO, corners[3]],
                 LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS,
             ),
-            // Missing piece of the rectangle at the far plane.
+            // Border of the image at the far plane.
             (
-                vec![corners[1], corners[2]],
+                image_border,
                 LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS,
             ),
             // Triangle indicating up direction.
@@ -269,6 +286,11 @@ impl VisualizerSystem for CamerasVisualizer {
                 pinhole: crate::Pinhole {
                     image_from_camera: pinhole_projection.0.into(),
                     resolution: resolution.into(),
+                    distortion: crate::pinhole::lens_distortion_from_components(
+                        query_results.get_mono::<components::LensDistortionModel>(),
+                        query_results.get_mono::<components::RadialDistortion>(),
+                        query_results.get_mono::<components::TangentialDistortion>(),
+                    ),
                 },
                 camera_xyz,
                 image_plane_distance: image_plane_distance.into(),
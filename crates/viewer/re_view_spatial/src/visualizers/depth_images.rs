@@ -2,7 +2,7 @@ use nohash_hasher::IntMap;
 
 use re_entity_db::EntityPath;
 use re_log_types::EntityPathHash;
-use re_renderer::renderer::{ColormappedTexture, DepthCloud, DepthClouds};
+use re_renderer::renderer::{ColormappedTexture, DepthCloud, DepthCloudAlbedo, DepthClouds};
 use re_types::{
     archetypes::DepthImage,
     components::{
@@ -12,14 +12,14 @@ use re_types::{
     Component as _,
 };
 use re_viewer_context::{
-    ColormapWithRange, IdentifiedViewSystem, ImageInfo, ImageStatsCache, MaybeVisualizableEntities,
-    QueryContext, TypedComponentFallbackProvider, ViewClass, ViewContext, ViewContextCollection,
-    ViewQuery, ViewSystemExecutionError, VisualizableEntities, VisualizableFilterContext,
-    VisualizerQueryInfo, VisualizerSystem,
+    gpu_bridge, ColormapWithRange, IdentifiedViewSystem, ImageDecodeCache, ImageInfo,
+    ImageStatsCache, MaybeVisualizableEntities, QueryContext, TypedComponentFallbackProvider,
+    ViewClass, ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError,
+    VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
 };
 
 use crate::{
-    contexts::{SpatialSceneEntityContext, TwoDInThreeDTransformInfo},
+    contexts::{SpatialSceneEntityContext, TransformTreeContext, TwoDInThreeDTransformInfo},
     view_kind::SpatialViewKind,
     visualizers::filter_visualizable_2d_entities,
     PickableRectSourceData, PickableTexturedRect, SpatialView3D,
@@ -49,6 +49,7 @@ struct DepthImageComponentData {
     fill_ratio: Option<FillRatio>,
     colormap: Option<Colormap>,
     value_range: Option<[f64; 2]>,
+    color_image_reference: Option<EntityPath>,
 }
 
 impl DepthImageVisualizer {
@@ -56,6 +57,7 @@ impl DepthImageVisualizer {
         &mut self,
         ctx: &QueryContext<'_>,
         depth_clouds: &mut Vec<DepthCloud>,
+        transforms: &TransformTreeContext,
         ent_context: &SpatialSceneEntityContext<'_>,
         images: impl Iterator<Item = DepthImageComponentData>,
     ) {
@@ -73,6 +75,7 @@ impl DepthImageVisualizer {
                 fill_ratio,
                 colormap,
                 value_range,
+                color_image_reference,
             } = data;
 
             let depth_meter = depth_meter.unwrap_or_else(|| self.fallback_for(ctx));
@@ -120,12 +123,14 @@ impl DepthImageVisualizer {
                     // What we want are the extrinsics of the depth camera!
                     match Self::process_entity_view_as_depth_cloud(
                         ctx,
+                        transforms,
                         ent_context,
                         entity_path,
                         twod_in_threed_info,
                         depth_meter,
                         fill_ratio,
                         &textured_rect.colormapped_texture,
+                        color_image_reference.as_ref(),
                     ) {
                         Ok(cloud) => {
                             self.data.add_bounding_box(
@@ -160,14 +165,17 @@ impl DepthImageVisualizer {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_entity_view_as_depth_cloud(
         ctx: &QueryContext<'_>,
+        transforms: &TransformTreeContext,
         ent_context: &SpatialSceneEntityContext<'_>,
         ent_path: &EntityPath,
         twod_in_threed_info: &TwoDInThreeDTransformInfo,
         depth_meter: DepthMeter,
         radius_scale: FillRatio,
         depth_texture: &ColormappedTexture,
+        color_image_reference: Option<&EntityPath>,
     ) -> anyhow::Result<DepthCloud> {
         re_tracing::profile_function!();
 
@@ -205,9 +213,20 @@ impl DepthImageVisualizer {
             world_depth_from_texture_depth * depth_texture.range[1],
         ];
 
+        let albedo = color_image_reference.and_then(|color_image_path| {
+            depth_cloud_albedo(ctx, transforms, ent_context, color_image_path, world_from_rdf)
+                .map_err(|err| {
+                    re_log::warn_once!(
+                        "Can't color the depth cloud at {ent_path:?} with the color image at {color_image_path:?}: {err}"
+                    );
+                })
+                .ok()
+        });
+
         Ok(DepthCloud {
             world_from_rdf,
             depth_camera_intrinsics: pinhole.image_from_camera,
+            lens_distortion: pinhole.distortion,
             world_depth_from_texture_depth,
             point_radius_from_world_depth,
             min_max_depth_in_world,
@@ -217,6 +236,7 @@ impl DepthImageVisualizer {
                 re_renderer::renderer::ColorMapper::Function(colormap) => colormap,
                 _ => re_renderer::Colormap::Grayscale,
             },
+            albedo,
             outline_mask_id: ent_context.highlight.overall,
             picking_object_id: re_renderer::PickingLayerObjectId(ent_path.hash64()),
         })
@@ -250,6 +270,7 @@ impl VisualizerSystem for DepthImageVisualizer {
         context_systems: &ViewContextCollection,
     ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
         let mut depth_clouds = Vec::new();
+        let transforms = context_systems.get::<TransformTreeContext>()?;
 
         use super::entity_iterator::{iter_component, iter_slices, process_archetype};
         process_archetype::<Self, DepthImage, _>(
@@ -280,17 +301,29 @@ impl VisualizerSystem for DepthImageVisualizer {
                 let all_value_ranges = results.iter_as(timeline, ValueRange::name());
                 let all_depth_meters = results.iter_as(timeline, DepthMeter::name());
                 let all_fill_ratios = results.iter_as(timeline, FillRatio::name());
+                let all_color_image_references =
+                    results.iter_as(timeline, components::EntityPath::name());
 
-                let mut data = re_query::range_zip_1x5(
+                let mut data = re_query::range_zip_1x6(
                     all_buffers_indexed,
                     all_formats_indexed,
                     all_colormaps.slice::<u8>(),
                     all_value_ranges.slice::<[f64; 2]>(),
                     all_depth_meters.slice::<f32>(),
                     all_fill_ratios.slice::<f32>(),
+                    all_color_image_references.slice::<String>(),
                 )
                 .filter_map(
-                    |(index, buffers, format, colormap, value_range, depth_meter, fill_ratio)| {
+                    |(
+                        index,
+                        buffers,
+                        format,
+                        colormap,
+                        value_range,
+                        depth_meter,
+                        fill_ratio,
+                        color_image_references,
+                    )| {
                         let buffer = buffers.first()?;
 
                         Some(DepthImageComponentData {
@@ -304,11 +337,20 @@ impl VisualizerSystem for DepthImageVisualizer {
                             fill_ratio: first_copied(fill_ratio).map(Into::into),
                             colormap: first_copied(colormap).and_then(Colormap::from_u8),
                             value_range: first_copied(value_range).map(Into::into),
+                            color_image_reference: color_image_references.and_then(|references| {
+                                references.first().map(|r| r.as_str().into())
+                            }),
                         })
                     },
                 );
 
-                self.process_depth_image_data(ctx, &mut depth_clouds, spatial_ctx, &mut data);
+                self.process_depth_image_data(
+                    ctx,
+                    &mut depth_clouds,
+                    transforms,
+                    spatial_ctx,
+                    &mut data,
+                );
 
                 Ok(())
             },
@@ -411,6 +453,96 @@ impl TypedComponentFallbackProvider<DepthMeter> for DepthImageVisualizer {
 
 re_viewer_context::impl_component_fallback_provider!(DepthImageVisualizer => [Colormap, ValueRange, DepthMeter, DrawOrder]);
 
+/// Sets up coloring a depth cloud with the color image at `color_image_path`.
+///
+/// The color image has to be under a pinhole camera that is part of the view,
+/// which may or may not be the same camera as the one of the depth image.
+fn depth_cloud_albedo(
+    ctx: &QueryContext<'_>,
+    transforms: &TransformTreeContext,
+    ent_context: &SpatialSceneEntityContext<'_>,
+    color_image_path: &EntityPath,
+    world_from_depth_rdf: glam::Affine3A,
+) -> anyhow::Result<DepthCloudAlbedo> {
+    let Some(twod_in_threed_info) = transforms
+        .transform_info_for_entity(color_image_path.hash())
+        .and_then(|transform_info| transform_info.twod_in_threed_info.as_ref())
+    else {
+        anyhow::bail!("the color image isn't under a pinhole camera that is part of this view");
+    };
+    let Some((pinhole, camera_xyz)) =
+        crate::pinhole::query_pinhole_and_view_coordinates_from_store_without_blueprint(
+            ctx.viewer_ctx,
+            ctx.query,
+            &twod_in_threed_info.parent_pinhole,
+        )
+    else {
+        anyhow::bail!(
+            "couldn't fetch pinhole intrinsics at {:?}",
+            twod_in_threed_info.parent_pinhole
+        );
+    };
+    let world_from_albedo_rdf = twod_in_threed_info.reference_from_pinhole_entity
+        * glam::Affine3A::from_mat3(camera_xyz.from_rdf());
+
+    let image = latest_color_image_at(ctx, color_image_path)?;
+    let image_stats = ctx
+        .viewer_ctx
+        .cache
+        .entry(|c: &mut ImageStatsCache| c.entry(&image));
+    let texture = gpu_bridge::image_to_gpu(
+        ctx.viewer_ctx.render_ctx,
+        &color_image_path.to_string(),
+        &image,
+        &image_stats,
+        &ent_context.annotations,
+        None,
+    )?;
+
+    Ok(DepthCloudAlbedo {
+        texture,
+        camera_intrinsics: pinhole.image_from_camera,
+        lens_distortion: pinhole.distortion,
+        albedo_rdf_from_depth_rdf: world_from_albedo_rdf.inverse() * world_from_depth_rdf,
+    })
+}
+
+/// Queries the latest color image at `entity_path`,
+/// either from an [`re_types::archetypes::Image`] or an [`re_types::archetypes::EncodedImage`].
+pub(super) fn latest_color_image_at(
+    ctx: &QueryContext<'_>,
+    entity_path: &EntityPath,
+) -> anyhow::Result<ImageInfo> {
+    let db = ctx.recording();
+
+    if let Some(((_time, buffer_row_id), buffer)) =
+        db.latest_at_component::<ImageBuffer>(entity_path, ctx.query)
+    {
+        let Some((_, format)) = db.latest_at_component::<ImageFormat>(entity_path, ctx.query)
+        else {
+            anyhow::bail!("the color image has no image format");
+        };
+        Ok(ImageInfo {
+            buffer_row_id,
+            buffer: buffer.0,
+            format: format.0,
+            kind: ImageKind::Color,
+        })
+    } else if let Some(((_time, blob_row_id), blob)) =
+        db.latest_at_component::<components::Blob>(entity_path, ctx.query)
+    {
+        let media_type = db
+            .latest_at_component::<components::MediaType>(entity_path, ctx.query)
+            .map(|(_, media_type)| media_type);
+        Ok(ctx
+            .viewer_ctx
+            .cache
+            .entry(|c: &mut ImageDecodeCache| c.entry(blob_row_id, &blob, media_type.as_ref()))?)
+    } else {
+        anyhow::bail!("there is no color image");
+    }
+}
+
 fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
     slice.and_then(|element| element.first()).copied()
 }
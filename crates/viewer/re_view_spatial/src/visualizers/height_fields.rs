@@ -0,0 +1,393 @@
+use re_entity_db::EntityPath;
+use re_renderer::renderer::{HeightField, HeightFieldColoring, HeightFieldDrawData};
+use re_types::{
+    archetypes::HeightField3D,
+    components::{
+        self, CellSize, Color, Colormap, ImageBuffer, ImageFormat, Position2D, ValueRange,
+    },
+    image::ImageKind,
+    Component as _,
+};
+use re_viewer_context::{
+    auto_color_for_entity_path, gpu_bridge, IdentifiedViewSystem, ImageInfo, ImageStatsCache,
+    MaybeVisualizableEntities, QueryContext, TypedComponentFallbackProvider, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::{
+    contexts::SpatialSceneEntityContext, view_3d::VisualizableFilterContext3D,
+    view_kind::SpatialViewKind,
+};
+
+use super::{depth_images::latest_color_image_at, SpatialViewVisualizerData};
+
+pub struct HeightField3DVisualizer {
+    pub data: SpatialViewVisualizerData,
+}
+
+impl Default for HeightField3DVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::ThreeD)),
+        }
+    }
+}
+
+struct HeightFieldComponentData {
+    image: ImageInfo,
+    cell_size: Option<glam::Vec2>,
+    origin: Option<glam::Vec2>,
+    color: Option<Color>,
+    colormap: Option<Colormap>,
+    value_range: Option<[f64; 2]>,
+    texture_reference: Option<EntityPath>,
+}
+
+impl HeightField3DVisualizer {
+    fn process_data(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        height_fields: &mut Vec<HeightField>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        data: impl Iterator<Item = HeightFieldComponentData>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+
+        for data in data {
+            let HeightFieldComponentData {
+                image,
+                cell_size,
+                origin,
+                color,
+                colormap,
+                value_range,
+                texture_reference,
+            } = data;
+
+            if image.format.color_model().num_channels() != 1 {
+                re_log::warn_once!(
+                    "Height field at {entity_path} must be single-channel, got {:?}",
+                    image.format.color_model()
+                );
+                continue;
+            }
+
+            let cell_size = cell_size.unwrap_or(glam::Vec2::ONE);
+            let origin = origin.unwrap_or(glam::Vec2::ZERO);
+
+            // The heights are uploaded as-is, the texture is cached by row id.
+            // A new height map arriving every frame is therefore a single texture upload.
+            let image_stats = ctx
+                .viewer_ctx
+                .cache
+                .entry(|c: &mut ImageStatsCache| c.entry(&image));
+            let height_texture = match gpu_bridge::image_to_gpu(
+                ctx.viewer_ctx.render_ctx,
+                &entity_path.to_string(),
+                &image,
+                &image_stats,
+                &ent_context.annotations,
+                None,
+            ) {
+                Ok(colormapped_texture) => colormapped_texture.texture,
+                Err(err) => {
+                    re_log::error_once!(
+                        "Failed to create texture for height field at {entity_path}: {err}"
+                    );
+                    continue;
+                }
+            };
+
+            let texture = texture_reference.and_then(|texture_path| {
+                height_field_texture(ctx, ent_context, &texture_path)
+                    .map_err(|err| {
+                        re_log::warn_once!(
+                            "Failed to drape {texture_path:?} over the height field at {entity_path}: {err}"
+                        );
+                    })
+                    .ok()
+            });
+            let coloring = if let Some(texture) = texture {
+                HeightFieldColoring::Texture(texture)
+            } else if let Some(colormap) = colormap {
+                let value_range =
+                    value_range.unwrap_or([image_stats.finite_range.0, image_stats.finite_range.1]);
+                HeightFieldColoring::Colormap {
+                    colormap: gpu_bridge::colormap_to_re_renderer(colormap),
+                    min_max_height: [value_range[0] as f32, value_range[1] as f32],
+                }
+            } else {
+                let color = color.unwrap_or_else(|| self.fallback_for(ctx));
+                HeightFieldColoring::Solid(re_renderer::Color32::from(color).into())
+            };
+
+            let world_from_entity = ent_context
+                .transform_info
+                .single_entity_transform_required(entity_path, "HeightField3D");
+
+            self.data.add_bounding_box(
+                entity_path.hash(),
+                height_field_bounding_box(
+                    origin,
+                    cell_size,
+                    image.width_height(),
+                    [
+                        image_stats.finite_range.0 as f32,
+                        image_stats.finite_range.1 as f32,
+                    ],
+                ),
+                world_from_entity,
+            );
+
+            height_fields.push(HeightField {
+                world_from_obj: world_from_entity,
+                origin,
+                cell_size,
+                height_texture,
+                coloring,
+                outline_mask_id: ent_context.highlight.overall,
+                picking_object_id: re_renderer::PickingLayerObjectId(entity_path.hash64()),
+            });
+        }
+    }
+}
+
+/// The local space bounds of a height field with `width_height` heights in the given range.
+///
+/// Heights lie on the corners of the grid cells, so there's one cell less than heights per axis.
+fn height_field_bounding_box(
+    origin: glam::Vec2,
+    cell_size: glam::Vec2,
+    [width, height]: [u32; 2],
+    [min_height, max_height]: [f32; 2],
+) -> re_math::BoundingBox {
+    let extent = cell_size
+        * glam::Vec2::new(
+            width.saturating_sub(1) as f32,
+            height.saturating_sub(1) as f32,
+        );
+    re_math::BoundingBox::from_points(
+        [
+            origin.extend(min_height),
+            (origin + extent).extend(max_height),
+        ]
+        .into_iter(),
+    )
+}
+
+/// Loads the image referenced by a height field, to be stretched over its entire surface.
+fn height_field_texture(
+    ctx: &QueryContext<'_>,
+    ent_context: &SpatialSceneEntityContext<'_>,
+    texture_path: &EntityPath,
+) -> anyhow::Result<re_renderer::renderer::ColormappedTexture> {
+    if texture_path == ctx.target_entity_path {
+        anyhow::bail!("the texture has to be logged to a different entity than the height field");
+    }
+
+    let image = latest_color_image_at(ctx, texture_path)?;
+    let image_stats = ctx
+        .viewer_ctx
+        .cache
+        .entry(|c: &mut ImageStatsCache| c.entry(&image));
+    gpu_bridge::image_to_gpu(
+        ctx.viewer_ctx.render_ctx,
+        &texture_path.to_string(),
+        &image,
+        &image_stats,
+        &ent_context.annotations,
+        None,
+    )
+}
+
+impl IdentifiedViewSystem for HeightField3DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "HeightField3D".into()
+    }
+}
+
+impl VisualizerSystem for HeightField3DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<HeightField3D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+
+        // Height fields are surfaces in 3D space, they can't be shown under a pinhole camera.
+        if let Some(context) = context
+            .as_any()
+            .downcast_ref::<VisualizableFilterContext3D>()
+        {
+            VisualizableEntities(
+                context
+                    .entities_in_main_3d_space
+                    .intersection(&entities.0)
+                    .cloned()
+                    .collect(),
+            )
+        } else {
+            VisualizableEntities(entities.0)
+        }
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut height_fields = Vec::new();
+
+        use super::entity_iterator::{iter_component, iter_slices, process_archetype};
+        process_archetype::<Self, HeightField3D, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_buffer_chunks) = results.get_required_chunks(&ImageBuffer::name())
+                else {
+                    return Ok(());
+                };
+                let Some(all_format_chunks) = results.get_required_chunks(&ImageFormat::name())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_buffers_indexed =
+                    iter_slices::<&[u8]>(&all_buffer_chunks, timeline, ImageBuffer::name());
+                let all_formats_indexed = iter_component::<ImageFormat>(
+                    &all_format_chunks,
+                    timeline,
+                    ImageFormat::name(),
+                );
+                let all_cell_sizes = results.iter_as(timeline, CellSize::name());
+                let all_origins = results.iter_as(timeline, Position2D::name());
+                let all_colors = results.iter_as(timeline, Color::name());
+                let all_colormaps = results.iter_as(timeline, Colormap::name());
+                let all_value_ranges = results.iter_as(timeline, ValueRange::name());
+                let all_texture_references =
+                    results.iter_as(timeline, components::EntityPath::name());
+
+                let data = re_query::range_zip_1x7(
+                    all_buffers_indexed,
+                    all_formats_indexed,
+                    all_cell_sizes.slice::<[f32; 2]>(),
+                    all_origins.slice::<[f32; 2]>(),
+                    all_colors.slice::<u32>(),
+                    all_colormaps.slice::<u8>(),
+                    all_value_ranges.slice::<[f64; 2]>(),
+                    all_texture_references.slice::<String>(),
+                )
+                .filter_map(
+                    |(
+                        index,
+                        buffers,
+                        formats,
+                        cell_sizes,
+                        origins,
+                        colors,
+                        colormaps,
+                        value_ranges,
+                        texture_references,
+                    )| {
+                        let buffer = buffers.first()?;
+
+                        Some(HeightFieldComponentData {
+                            image: ImageInfo {
+                                buffer_row_id: index.1,
+                                buffer: buffer.clone().into(),
+                                format: first_copied(formats.as_deref())?.0,
+                                kind: ImageKind::Depth,
+                            },
+                            cell_size: first_copied(cell_sizes).map(glam::Vec2::from_array),
+                            origin: first_copied(origins).map(glam::Vec2::from_array),
+                            color: first_copied(colors).map(Color::from_u32),
+                            colormap: first_copied(colormaps).and_then(Colormap::from_u8),
+                            value_range: first_copied(value_ranges),
+                            texture_reference: texture_references.and_then(|references| {
+                                references.first().map(|r| r.as_str().into())
+                            }),
+                        })
+                    },
+                );
+
+                self.process_data(ctx, &mut height_fields, spatial_ctx, data);
+
+                Ok(())
+            },
+        )?;
+
+        match HeightFieldDrawData::new(ctx.viewer_ctx.render_ctx, &height_fields) {
+            Ok(draw_data) => Ok(vec![draw_data.into()]),
+            Err(err) => {
+                re_log::error_once!("Failed to create height field draw data: {err}");
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Color> for HeightField3DVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HeightField3DVisualizer => [Color]);
+
+fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
+    slice.and_then(|element| element.first()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounding_box_spans_grid() {
+        let bbox = height_field_bounding_box(
+            glam::vec2(1.0, -2.0),
+            glam::vec2(0.5, 2.0),
+            [5, 3],
+            [-1.0, 4.0],
+        );
+        assert_eq!(bbox.min, glam::vec3(1.0, -2.0, -1.0));
+        assert_eq!(bbox.max, glam::vec3(3.0, 2.0, 4.0));
+    }
+
+    #[test]
+    fn bounding_box_with_negative_cell_size() {
+        let bbox =
+            height_field_bounding_box(glam::Vec2::ZERO, glam::vec2(-1.0, 1.0), [3, 3], [0.0, 1.0]);
+        assert_eq!(bbox.min, glam::vec3(-2.0, 0.0, 0.0));
+        assert_eq!(bbox.max, glam::vec3(0.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn single_height_is_a_point() {
+        let bbox = height_field_bounding_box(glam::Vec2::ONE, glam::Vec2::ONE, [1, 1], [2.0, 2.0]);
+        assert!(bbox.is_point());
+        assert_eq!(bbox.min, glam::vec3(1.0, 1.0, 2.0));
+    }
+}
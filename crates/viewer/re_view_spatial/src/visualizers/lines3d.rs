@@ -76,6 +76,7 @@ impl Lines3DVisualizer {
                 .batch(entity_path.to_string())
                 .depth_offset(ent_context.depth_offset)
                 .world_from_obj(world_from_obj)
+                .lens_distortion(ent_context.transform_info.threed_in_twod)
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
@@ -101,6 +101,8 @@ impl Mesh3DVisualizer {
                                 picking_instance_hash,
                             ),
                             additive_tint: re_renderer::Color32::TRANSPARENT,
+                            opacity: 1.0,
+                            lens_distortion: ent_context.transform_info.threed_in_twod,
                         }
                     }));
 
@@ -10,16 +10,19 @@ mod capsules3d;
 mod depth_images;
 mod ellipsoids;
 mod encoded_image;
+mod height_fields;
 mod images;
 mod lines2d;
 mod lines3d;
 mod meshes;
+mod occupancy_grids;
 mod points2d;
 mod points3d;
 mod segmentation_images;
 mod transform3d_arrows;
 mod utilities;
 mod videos;
+mod voxel_grids;
 
 pub use cameras::CamerasVisualizer;
 pub use depth_images::DepthImageVisualizer;
@@ -81,12 +84,14 @@ pub fn register_2d_spatial_visualizers(
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
     system_registry.register_visualizer::<lines3d::Lines3DVisualizer>()?;
     system_registry.register_visualizer::<meshes::Mesh3DVisualizer>()?;
+    system_registry.register_visualizer::<occupancy_grids::OccupancyGridVisualizer>()?;
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     system_registry.register_visualizer::<videos::VideoFrameReferenceVisualizer>()?;
+    system_registry.register_visualizer::<voxel_grids::VoxelGrid3DVisualizer>()?;
     Ok(())
 }
 
@@ -102,10 +107,12 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<cameras::CamerasVisualizer>()?;
     system_registry.register_visualizer::<depth_images::DepthImageVisualizer>()?;
     system_registry.register_visualizer::<encoded_image::EncodedImageVisualizer>()?;
+    system_registry.register_visualizer::<height_fields::HeightField3DVisualizer>()?;
     system_registry.register_visualizer::<images::ImageVisualizer>()?;
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
     system_registry.register_visualizer::<lines3d::Lines3DVisualizer>()?;
     system_registry.register_visualizer::<meshes::Mesh3DVisualizer>()?;
+    system_registry.register_visualizer::<occupancy_grids::OccupancyGridVisualizer>()?;
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
@@ -113,6 +120,7 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     system_registry.register_visualizer::<videos::VideoFrameReferenceVisualizer>()?;
+    system_registry.register_visualizer::<voxel_grids::VoxelGrid3DVisualizer>()?;
     Ok(())
 }
 
@@ -126,6 +134,7 @@ pub fn visualizers_processing_draw_order() -> impl Iterator<Item = ViewSystemIde
         encoded_image::EncodedImageVisualizer::identifier(),
         images::ImageVisualizer::identifier(),
         lines2d::Lines2DVisualizer::identifier(),
+        occupancy_grids::OccupancyGridVisualizer::identifier(),
         points2d::Points2DVisualizer::identifier(),
         segmentation_images::SegmentationImageVisualizer::identifier(),
     ]
@@ -217,6 +226,7 @@ pub fn load_keypoint_connections(
     let mut line_batch = line_builder
         .batch("keypoint connections")
         .world_from_obj(world_from_obj)
+        .lens_distortion(ent_context.transform_info.threed_in_twod)
         .picking_object_id(re_renderer::PickingLayerObjectId(ent_path.hash64()));
 
     // TODO(andreas): Make configurable. Should we pick up the point's radius and make this proportional?
@@ -0,0 +1,332 @@
+use re_renderer::renderer;
+use re_types::{
+    archetypes::OccupancyGrid,
+    components::{
+        CellSize, Colormap, DrawOrder, ImageBuffer, ImageFormat, Opacity, Position2D, ValueRange,
+    },
+    image::ImageKind,
+    Component as _,
+};
+use re_viewer_context::{
+    gpu_bridge, ColormapWithRange, IdentifiedViewSystem, ImageInfo, ImageStatsCache,
+    MaybeVisualizableEntities, QueryContext, TypedComponentFallbackProvider, ViewClass as _,
+    ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::{
+    contexts::SpatialSceneEntityContext, view_2d::VisualizableFilterContext2D,
+    view_3d::VisualizableFilterContext3D, view_kind::SpatialViewKind, PickableRectSourceData,
+    PickableTexturedRect, SpatialView2D,
+};
+
+use super::SpatialViewVisualizerData;
+
+pub struct OccupancyGridVisualizer {
+    pub data: SpatialViewVisualizerData,
+}
+
+impl Default for OccupancyGridVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::TwoD)),
+        }
+    }
+}
+
+struct OccupancyGridComponentData {
+    image: ImageInfo,
+    cell_size: Option<glam::Vec2>,
+    origin: Option<glam::Vec2>,
+    colormap: Option<Colormap>,
+    value_range: Option<[f64; 2]>,
+    opacity: Option<Opacity>,
+}
+
+impl OccupancyGridVisualizer {
+    fn process_data(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        data: impl Iterator<Item = OccupancyGridComponentData>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+
+        for data in data {
+            let OccupancyGridComponentData {
+                image,
+                cell_size,
+                origin,
+                colormap,
+                value_range,
+                opacity,
+            } = data;
+
+            if image.format.color_model().num_channels() != 1 {
+                re_log::warn_once!(
+                    "Occupancy grid at {entity_path} must be single-channel, got {:?}",
+                    image.format.color_model()
+                );
+                continue;
+            }
+
+            let cell_size = cell_size.unwrap_or(glam::Vec2::ONE);
+            let origin = origin.unwrap_or(glam::Vec2::ZERO);
+            let colormap = colormap.unwrap_or_else(|| self.fallback_for(ctx));
+            let value_range: ValueRange = value_range
+                .map(Into::into)
+                .unwrap_or_else(|| self.fallback_for(ctx));
+            let opacity = opacity.unwrap_or_else(|| self.fallback_for(ctx));
+
+            let colormap_with_range = ColormapWithRange {
+                colormap,
+                value_range: [value_range.start() as f32, value_range.end() as f32],
+            };
+
+            let image_stats = ctx
+                .viewer_ctx
+                .cache
+                .entry(|c: &mut ImageStatsCache| c.entry(&image));
+            let colormapped_texture = match gpu_bridge::image_to_gpu(
+                ctx.viewer_ctx.render_ctx,
+                &entity_path.to_string(),
+                &image,
+                &image_stats,
+                &ent_context.annotations,
+                Some(&colormap_with_range),
+            ) {
+                Ok(colormapped_texture) => colormapped_texture,
+                Err(err) => {
+                    re_log::error_once!(
+                        "Failed to create texture for occupancy grid at {entity_path}: {err}"
+                    );
+                    continue;
+                }
+            };
+
+            let world_from_entity = ent_context
+                .transform_info
+                .single_entity_transform_required(entity_path, "OccupancyGrid");
+
+            let extent = cell_size * glam::Vec2::new(image.width() as f32, image.height() as f32);
+            let textured_rect = renderer::TexturedRect {
+                top_left_corner_position: world_from_entity.transform_point3(origin.extend(0.0)),
+                extent_u: world_from_entity.transform_vector3(glam::Vec3::X * extent.x),
+                extent_v: world_from_entity.transform_vector3(glam::Vec3::Y * extent.y),
+
+                colormapped_texture,
+
+                options: renderer::RectangleOptions {
+                    // Cells are discrete, interpolating between them makes no sense.
+                    texture_filter_magnification: renderer::TextureFilterMag::Nearest,
+                    texture_filter_minification: renderer::TextureFilterMin::Nearest,
+                    multiplicative_tint: re_renderer::Rgba::from_white_alpha(
+                        opacity.0.clamp(0.0, 1.0),
+                    ),
+                    depth_offset: ent_context.depth_offset,
+                    outline_mask: ent_context.highlight.overall,
+                    lens_undistortion: !ent_context.transform_info.threed_in_twod,
+                },
+            };
+
+            // Only update the bounding box in 2D views, for the same reasons as for images.
+            // See `textured_rect_from_image`.
+            if ent_context.view_class_identifier == SpatialView2D::identifier() {
+                self.data.add_bounding_box(
+                    entity_path.hash(),
+                    re_math::BoundingBox::from_points(
+                        [origin.extend(0.0), (origin + extent).extend(0.0)].into_iter(),
+                    ),
+                    world_from_entity,
+                );
+            }
+
+            self.data.pickable_rects.push(PickableTexturedRect {
+                ent_path: entity_path.clone(),
+                textured_rect,
+                source_data: PickableRectSourceData::Image {
+                    image,
+                    depth_meter: None,
+                },
+            });
+        }
+    }
+}
+
+impl IdentifiedViewSystem for OccupancyGridVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "OccupancyGrid".into()
+    }
+}
+
+impl VisualizerSystem for OccupancyGridVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<OccupancyGrid>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+
+        // Unlike images, occupancy grids are not tied to a camera:
+        // they are shown in the main space of both 2D and 3D views.
+        if let Some(context) = context
+            .as_any()
+            .downcast_ref::<VisualizableFilterContext2D>()
+        {
+            VisualizableEntities(
+                context
+                    .entities_in_main_2d_space
+                    .intersection(&entities.0)
+                    .cloned()
+                    .collect(),
+            )
+        } else if let Some(context) = context
+            .as_any()
+            .downcast_ref::<VisualizableFilterContext3D>()
+        {
+            VisualizableEntities(
+                context
+                    .entities_in_main_3d_space
+                    .intersection(&entities.0)
+                    .cloned()
+                    .collect(),
+            )
+        } else {
+            VisualizableEntities(entities.0)
+        }
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        use super::entity_iterator::{iter_component, iter_slices, process_archetype};
+        process_archetype::<Self, OccupancyGrid, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_buffer_chunks) = results.get_required_chunks(&ImageBuffer::name())
+                else {
+                    return Ok(());
+                };
+                let Some(all_format_chunks) = results.get_required_chunks(&ImageFormat::name())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_buffers_indexed =
+                    iter_slices::<&[u8]>(&all_buffer_chunks, timeline, ImageBuffer::name());
+                let all_formats_indexed = iter_component::<ImageFormat>(
+                    &all_format_chunks,
+                    timeline,
+                    ImageFormat::name(),
+                );
+                let all_cell_sizes = results.iter_as(timeline, CellSize::name());
+                let all_origins = results.iter_as(timeline, Position2D::name());
+                let all_colormaps = results.iter_as(timeline, Colormap::name());
+                let all_value_ranges = results.iter_as(timeline, ValueRange::name());
+                let all_opacities = results.iter_as(timeline, Opacity::name());
+
+                let data = re_query::range_zip_1x6(
+                    all_buffers_indexed,
+                    all_formats_indexed,
+                    all_cell_sizes.slice::<[f32; 2]>(),
+                    all_origins.slice::<[f32; 2]>(),
+                    all_colormaps.slice::<u8>(),
+                    all_value_ranges.slice::<[f64; 2]>(),
+                    all_opacities.slice::<f32>(),
+                )
+                .filter_map(
+                    |(
+                        index,
+                        buffers,
+                        formats,
+                        cell_sizes,
+                        origins,
+                        colormaps,
+                        value_ranges,
+                        opacities,
+                    )| {
+                        let buffer = buffers.first()?;
+
+                        Some(OccupancyGridComponentData {
+                            image: ImageInfo {
+                                buffer_row_id: index.1,
+                                buffer: buffer.clone().into(),
+                                format: first_copied(formats.as_deref())?.0,
+                                kind: ImageKind::Depth,
+                            },
+                            cell_size: first_copied(cell_sizes).map(glam::Vec2::from_array),
+                            origin: first_copied(origins).map(glam::Vec2::from_array),
+                            colormap: first_copied(colormaps).and_then(Colormap::from_u8),
+                            value_range: first_copied(value_ranges),
+                            opacity: first_copied(opacities).map(Into::into),
+                        })
+                    },
+                );
+
+                self.process_data(ctx, spatial_ctx, data);
+
+                Ok(())
+            },
+        )?;
+
+        Ok(vec![PickableTexturedRect::to_draw_data(
+            ctx.viewer_ctx.render_ctx,
+            &self.data.pickable_rects,
+        )?])
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for OccupancyGridVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        Colormap::Grayscale
+    }
+}
+
+impl TypedComponentFallbackProvider<ValueRange> for OccupancyGridVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> ValueRange {
+        // Free cells (0) are white, occupied cells (100) are black.
+        [100.0, 0.0].into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Opacity> for OccupancyGridVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Opacity {
+        1.0.into()
+    }
+}
+
+impl TypedComponentFallbackProvider<DrawOrder> for OccupancyGridVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> DrawOrder {
+        DrawOrder::DEFAULT_IMAGE
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(OccupancyGridVisualizer => [Colormap, ValueRange, Opacity, DrawOrder]);
+
+fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
+    slice.and_then(|element| element.first()).copied()
+}
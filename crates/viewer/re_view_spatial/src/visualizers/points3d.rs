@@ -1,5 +1,6 @@
 use itertools::Itertools;
 
+use re_chunk_store::{Chunk, RowId};
 use re_renderer::{LineDrawableBuilder, PickingLayerInstanceId, PointCloudBuilder};
 use re_types::{
     archetypes::Points3D,
@@ -16,6 +17,7 @@ use re_viewer_context::{
 
 use crate::{
     contexts::SpatialSceneEntityContext,
+    point_cloud_lod::{max_drawn_points, PointCloudLod, POINT_CLOUD_LOD_MIN_POINTS},
     view_kind::SpatialViewKind,
     visualizers::{load_keypoint_connections, process_radius_slice},
 };
@@ -41,6 +43,7 @@ impl Default for Points3DVisualizer {
 
 struct Points3DComponentData<'a> {
     // Point of views
+    row_id: RowId,
     positions: &'a [Position3D],
 
     // Clamped to edge
@@ -54,6 +57,42 @@ struct Points3DComponentData<'a> {
     show_labels: Option<ShowLabels>,
 }
 
+impl Points3DComponentData<'_> {
+    /// Copies out the given instances, e.g. to only draw part of a huge point cloud.
+    fn gather(&self, indices: &[u32]) -> GatheredPoints3D {
+        GatheredPoints3D {
+            positions: indices
+                .iter()
+                .map(|&i| self.positions[i as usize])
+                .collect(),
+            colors: gather_clamped(self.colors, indices),
+            radii: gather_clamped(self.radii, indices),
+            labels: gather_clamped(&self.labels, indices),
+            class_ids: gather_clamped(self.class_ids, indices),
+        }
+    }
+}
+
+struct GatheredPoints3D {
+    positions: Vec<Position3D>,
+    colors: Vec<Color>,
+    radii: Vec<Radius>,
+    labels: Vec<ArrowString>,
+    class_ids: Vec<ClassId>,
+}
+
+/// Picks the values for the given instances, following the clamp-to-edge semantics of the
+/// component data: a single value applies to all instances and is kept as is.
+fn gather_clamped<T: Clone>(values: &[T], indices: &[u32]) -> Vec<T> {
+    if values.len() <= 1 {
+        return values.to_vec();
+    }
+    indices
+        .iter()
+        .map(|&i| values[(i as usize).min(values.len() - 1)].clone())
+        .collect()
+}
+
 // NOTE: Do not put profile scopes in these methods. They are called for all entities and all
 // timestamps within a time range -- it's _a lot_.
 impl Points3DVisualizer {
@@ -64,19 +103,72 @@ impl Points3DVisualizer {
         line_builder: &mut LineDrawableBuilder<'_>,
         query: &ViewQuery<'_>,
         ent_context: &SpatialSceneEntityContext<'_>,
+        position_chunks: &[Chunk],
         data: impl Iterator<Item = Points3DComponentData<'a>>,
     ) -> Result<(), ViewSystemExecutionError> {
         let entity_path = ctx.target_entity_path;
 
         for data in data {
-            let num_instances = data.positions.len();
-            if num_instances == 0 {
+            if data.positions.is_empty() {
                 continue;
             }
 
-            let picking_ids = (0..num_instances)
-                .map(|i| PickingLayerInstanceId(i as _))
-                .collect_vec();
+            let world_from_obj = ent_context
+                .transform_info
+                .single_entity_transform_required(entity_path, "Points3D");
+
+            // Huge point clouds only draw the part that's visible at the current level of detail.
+            // Keypoints need all their points, so those clouds are always drawn in full.
+            let lod = (data.positions.len() >= POINT_CLOUD_LOD_MIN_POINTS
+                && data.keypoint_ids.is_empty())
+            .then(|| {
+                // Highlighted points should never be left out.
+                let highlighted = ent_context
+                    .highlight
+                    .instances
+                    .keys()
+                    .map(|key| key.get())
+                    .filter(|&index| index < data.positions.len() as u64)
+                    .map(|index| index as u32)
+                    .collect_vec();
+
+                PointCloudLod::select(
+                    ctx,
+                    data.row_id,
+                    position_chunks,
+                    data.positions.len(),
+                    world_from_obj,
+                    &highlighted,
+                )
+            });
+            let gathered = lod.as_ref().map(|lod| data.gather(&lod.indices));
+            let data = match &gathered {
+                Some(gathered) => Points3DComponentData {
+                    row_id: data.row_id,
+                    positions: &gathered.positions,
+                    colors: &gathered.colors,
+                    radii: &gathered.radii,
+                    labels: gathered.labels.clone(),
+                    keypoint_ids: &[],
+                    class_ids: &gathered.class_ids,
+                    show_labels: data.show_labels,
+                },
+                None => data,
+            };
+
+            let num_instances = data.positions.len();
+
+            // Picking always resolves to the index in the original point cloud.
+            let picking_ids = match &lod {
+                Some(lod) => lod
+                    .indices
+                    .iter()
+                    .map(|&i| PickingLayerInstanceId(i as _))
+                    .collect_vec(),
+                None => (0..num_instances)
+                    .map(|i| PickingLayerInstanceId(i as _))
+                    .collect_vec(),
+            };
 
             let (annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
                 query.latest_at,
@@ -96,14 +188,11 @@ impl Points3DVisualizer {
             let colors =
                 process_color_slice(ctx, self, num_instances, &annotation_infos, data.colors);
 
-            let world_from_obj = ent_context
-                .transform_info
-                .single_entity_transform_required(entity_path, "Points3D");
-
             {
                 let point_batch = point_builder
                     .batch(entity_path.to_string())
                     .world_from_obj(world_from_obj)
+                    .lens_distortion(ent_context.transform_info.threed_in_twod)
                     .outline_mask_ids(ent_context.highlight.overall)
                     .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
@@ -113,9 +202,14 @@ impl Points3DVisualizer {
                 // Determine if there's any sub-ranges that need extra highlighting.
                 {
                     for (highlighted_key, instance_mask_ids) in &ent_context.highlight.instances {
-                        let highlighted_point_index = (highlighted_key.get()
-                            < num_instances as u64)
-                            .then_some(highlighted_key.get());
+                        let highlighted_point_index = match &lod {
+                            Some(lod) => u32::try_from(highlighted_key.get())
+                                .ok()
+                                .and_then(|index| lod.highlighted.get(&index))
+                                .map(|&i| i as u64),
+                            None => (highlighted_key.get() < num_instances as u64)
+                                .then_some(highlighted_key.get()),
+                        };
                         if let Some(highlighted_point_index) = highlighted_point_index {
                             point_range_builder = point_range_builder
                                 .push_additional_outline_mask_ids_for_range(
@@ -128,8 +222,10 @@ impl Points3DVisualizer {
                 }
             }
 
-            let obj_space_bounding_box =
-                re_math::BoundingBox::from_points(positions.iter().copied());
+            let obj_space_bounding_box = lod
+                .as_ref()
+                .and_then(|lod| lod.bounding_box)
+                .unwrap_or_else(|| re_math::BoundingBox::from_points(positions.iter().copied()));
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
@@ -208,7 +304,7 @@ impl VisualizerSystem for Points3DVisualizer {
                 let num_positions = all_position_chunks
                     .iter()
                     .flat_map(|chunk| chunk.iter_slices::<[f32; 3]>(Position3D::name()))
-                    .map(|points| points.len())
+                    .map(|points| max_drawn_points(points.len()))
                     .sum();
 
                 if num_positions == 0 {
@@ -238,7 +334,7 @@ impl VisualizerSystem for Points3DVisualizer {
                 )
                 .map(
                     |(
-                        _index,
+                        (_time, row_id),
                         positions,
                         colors,
                         radii,
@@ -248,6 +344,7 @@ impl VisualizerSystem for Points3DVisualizer {
                         show_labels,
                     )| {
                         Points3DComponentData {
+                            row_id,
                             positions: bytemuck::cast_slice(positions),
                             colors: colors.map_or(&[], |colors| bytemuck::cast_slice(colors)),
                             radii: radii.map_or(&[], |radii| bytemuck::cast_slice(radii)),
@@ -269,6 +366,7 @@ impl VisualizerSystem for Points3DVisualizer {
                     &mut line_builder,
                     view_query,
                     spatial_ctx,
+                    &all_position_chunks,
                     data,
                 )
             },
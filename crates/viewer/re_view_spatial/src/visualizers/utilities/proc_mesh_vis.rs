@@ -136,6 +136,7 @@ where
             .line_builder
             .batch(self.line_batch_debug_label.clone())
             .depth_offset(ent_context.depth_offset)
+            .lens_distortion(ent_context.transform_info.threed_in_twod)
             .outline_mask_ids(ent_context.highlight.overall)
             .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
@@ -219,6 +220,7 @@ where
                         ));
                     };
 
+                    let [r, g, b, a] = color.to_srgba_unmultiplied();
                     self.solid_instances.push(GpuMeshInstance {
                         gpu_mesh: solid_mesh.gpu_mesh,
                         world_from_mesh: world_from_instance,
@@ -226,7 +228,9 @@ where
                         picking_layer_id: re_view::picking_layer_id_from_instance_path_hash(
                             InstancePathHash::instance(entity_path, instance),
                         ),
-                        additive_tint: color,
+                        additive_tint: re_renderer::Color32::from_rgb(r, g, b),
+                        opacity: a as f32 / 255.0,
+                        lens_distortion: ent_context.transform_info.threed_in_twod,
                     });
                 }
             }
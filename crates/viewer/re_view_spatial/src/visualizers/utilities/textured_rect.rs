@@ -67,6 +67,8 @@ pub fn textured_rect_from_image(
                     multiplicative_tint,
                     depth_offset: ent_context.depth_offset,
                     outline_mask: ent_context.highlight.overall,
+                    // Images that are projected from 3D don't lie in the pixel space of the view's camera.
+                    lens_undistortion: !ent_context.transform_info.threed_in_twod,
                 },
             };
 
@@ -233,6 +233,7 @@ impl VideoFrameReferenceVisualizer {
                                     texture_filter_magnification: TextureFilterMag::Nearest,
                                     texture_filter_minification: TextureFilterMin::Linear,
                                     outline_mask: spatial_ctx.highlight.overall,
+                                    lens_undistortion: !spatial_ctx.transform_info.threed_in_twod,
                                     ..Default::default()
                                 },
                             };
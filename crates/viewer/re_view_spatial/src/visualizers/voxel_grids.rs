@@ -0,0 +1,343 @@
+use re_chunk_store::RowId;
+use re_entity_db::InstancePathHash;
+use re_log_types::{hash::Hash64, Instance};
+use re_renderer::renderer::GpuMeshInstance;
+use re_types::{
+    archetypes::VoxelGrid3D,
+    components::{
+        Color, Colormap, Position3D, Scalar, TensorData, ValueRange, VoxelIndex, VoxelSize,
+    },
+    Component as _,
+};
+use re_view::clamped_or_nothing;
+use re_viewer_context::{
+    auto_color_for_entity_path, gpu_bridge, IdentifiedViewSystem, MaybeVisualizableEntities,
+    QueryContext, TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo,
+    VisualizerSystem,
+};
+
+use crate::{
+    contexts::SpatialSceneEntityContext,
+    proc_mesh::{self, ProcMeshKey},
+    view_kind::SpatialViewKind,
+    voxel_cache::DenseVoxelCache,
+};
+
+use super::{filter_visualizable_3d_entities, SpatialViewVisualizerData};
+
+// ---
+
+pub struct VoxelGrid3DVisualizer(SpatialViewVisualizerData);
+
+impl Default for VoxelGrid3DVisualizer {
+    fn default() -> Self {
+        Self(SpatialViewVisualizerData::new(Some(
+            SpatialViewKind::ThreeD,
+        )))
+    }
+}
+
+struct VoxelGrid3DComponentData<'a> {
+    row_id: RowId,
+    voxel_size: glam::Vec3,
+    voxel_indices: &'a [[u32; 3]],
+    colors: &'a [Color],
+    dense_values: Option<TensorData>,
+    values: &'a [f64],
+    origin: glam::Vec3,
+    colormap: Option<Colormap>,
+    value_range: Option<[f64; 2]>,
+}
+
+/// A voxel to draw, before it is turned into a mesh instance.
+struct Voxel {
+    index: glam::UVec3,
+    instance: Instance,
+    color: re_renderer::Color32,
+}
+
+// NOTE: Do not put profile scopes in these methods. They are called for all entities and all
+// timestamps within a time range -- it's _a lot_.
+impl VoxelGrid3DVisualizer {
+    fn process_data<'a>(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        instances: &mut Vec<GpuMeshInstance>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        query_result_hash: Hash64,
+        data: impl Iterator<Item = VoxelGrid3DComponentData<'a>>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+
+        let Some(cube) = ctx.viewer_ctx.cache.entry(|c: &mut proc_mesh::SolidCache| {
+            c.entry(ProcMeshKey::Cube, ctx.viewer_ctx.render_ctx)
+        }) else {
+            return;
+        };
+
+        let world_from_obj = ent_context
+            .transform_info
+            .single_entity_transform_required(entity_path, "VoxelGrid3D");
+
+        for data in data {
+            let colormap = gpu_bridge::colormap_to_re_renderer(
+                data.colormap.unwrap_or_else(|| self.fallback_for(ctx)),
+            );
+            let color_for_value = |value: f64, [min, max]: [f64; 2]| {
+                let t = if min < max {
+                    ((value - min) / (max - min)) as f32
+                } else {
+                    0.5
+                };
+                let [r, g, b, _] = re_renderer::colormap_srgb(colormap, t.clamp(0.0, 1.0));
+                re_renderer::Color32::from_rgb(r, g, b)
+            };
+            let fallback_color = || {
+                let color: Color = self.fallback_for(ctx);
+                re_renderer::Color32::from(color)
+            };
+
+            let voxels: Vec<Voxel> = if let Some(dense_values) = &data.dense_values {
+                let Some(dense) = ctx.viewer_ctx.cache.entry(|c: &mut DenseVoxelCache| {
+                    c.entry(data.row_id, query_result_hash, dense_values)
+                }) else {
+                    continue;
+                };
+
+                let value_range = data.value_range.or(dense.value_range);
+                let single_color = match data.colors {
+                    [] => None,
+                    [color] => Some(re_renderer::Color32::from(*color)),
+                    _ => {
+                        re_log::warn_once!(
+                            "Only a single color is supported for dense voxel grids, at {entity_path}"
+                        );
+                        data.colors
+                            .first()
+                            .map(|color| re_renderer::Color32::from(*color))
+                    }
+                };
+
+                dense
+                    .surface
+                    .iter()
+                    .map(|voxel| Voxel {
+                        index: voxel.index,
+                        instance: Instance::from(voxel.flat_index),
+                        color: single_color.unwrap_or_else(|| {
+                            value_range.map_or_else(fallback_color, |range| {
+                                color_for_value(voxel.value, range)
+                            })
+                        }),
+                    })
+                    .collect()
+            } else {
+                let num_voxels = data.voxel_indices.len();
+
+                let colors: Vec<re_renderer::Color32> = if !data.colors.is_empty() {
+                    clamped_or_nothing(data.colors, num_voxels)
+                        .map(|color| re_renderer::Color32::from(*color))
+                        .collect()
+                } else if !data.values.is_empty() {
+                    let value_range = data.value_range.unwrap_or_else(|| {
+                        data.values
+                            .iter()
+                            .filter(|value| value.is_finite())
+                            .fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], &value| {
+                                [min.min(value), max.max(value)]
+                            })
+                    });
+                    clamped_or_nothing(data.values, num_voxels)
+                        .map(|&value| color_for_value(value, value_range))
+                        .collect()
+                } else {
+                    vec![fallback_color(); num_voxels]
+                };
+
+                data.voxel_indices
+                    .iter()
+                    .zip(colors)
+                    .enumerate()
+                    .map(|(i, (index, color))| Voxel {
+                        index: glam::UVec3::from_array(*index),
+                        instance: Instance::from(i as u64),
+                        color,
+                    })
+                    .collect()
+            };
+
+            if voxels.is_empty() {
+                continue;
+            }
+
+            let mut obj_space_bounding_box = re_math::BoundingBox::NOTHING;
+
+            instances.extend(voxels.into_iter().map(|voxel| {
+                let min_corner = data.origin + voxel.index.as_vec3() * data.voxel_size;
+                obj_space_bounding_box.extend(min_corner);
+                obj_space_bounding_box.extend(min_corner + data.voxel_size);
+
+                let obj_from_voxel = glam::Affine3A::from_scale_rotation_translation(
+                    data.voxel_size,
+                    glam::Quat::IDENTITY,
+                    min_corner + 0.5 * data.voxel_size,
+                );
+
+                GpuMeshInstance {
+                    gpu_mesh: cube.gpu_mesh.clone(),
+                    world_from_mesh: world_from_obj * obj_from_voxel,
+                    outline_mask_ids: ent_context.highlight.index_outline_mask(voxel.instance),
+                    picking_layer_id: re_view::picking_layer_id_from_instance_path_hash(
+                        InstancePathHash::instance(entity_path, voxel.instance),
+                    ),
+                    additive_tint: voxel.color,
+                    opacity: 1.0,
+                    lens_distortion: ent_context.transform_info.threed_in_twod,
+                }
+            }));
+
+            self.0
+                .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
+        }
+    }
+}
+
+impl IdentifiedViewSystem for VoxelGrid3DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "VoxelGrid3D".into()
+    }
+}
+
+impl VisualizerSystem for VoxelGrid3DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<VoxelGrid3D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut instances = Vec::new();
+
+        use super::entity_iterator::{iter_slices, process_archetype};
+        process_archetype::<Self, VoxelGrid3D, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_voxel_size_chunks) = results.get_required_chunks(&VoxelSize::name())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_voxel_sizes_indexed =
+                    iter_slices::<[f32; 3]>(&all_voxel_size_chunks, timeline, VoxelSize::name());
+                let all_voxel_indices = results.iter_as(timeline, VoxelIndex::name());
+                let all_colors = results.iter_as(timeline, Color::name());
+                let all_dense_values = results.iter_as(timeline, TensorData::name());
+                let all_values = results.iter_as(timeline, Scalar::name());
+                let all_origins = results.iter_as(timeline, Position3D::name());
+                let all_colormaps = results.iter_as(timeline, Colormap::name());
+                let all_value_ranges = results.iter_as(timeline, ValueRange::name());
+
+                let query_result_hash = results.query_result_hash();
+
+                let data = re_query::range_zip_1x7(
+                    all_voxel_sizes_indexed,
+                    all_voxel_indices.slice::<[u32; 3]>(),
+                    all_colors.slice::<u32>(),
+                    // Legit call to `component_slow`, `TensorData` is real complicated.
+                    all_dense_values.component_slow::<TensorData>(),
+                    all_values.slice::<f64>(),
+                    all_origins.slice::<[f32; 3]>(),
+                    all_colormaps.slice::<u8>(),
+                    all_value_ranges.slice::<[f64; 2]>(),
+                )
+                .filter_map(
+                    |(
+                        index,
+                        voxel_sizes,
+                        voxel_indices,
+                        colors,
+                        dense_values,
+                        values,
+                        origins,
+                        colormaps,
+                        value_ranges,
+                    )| {
+                        Some(VoxelGrid3DComponentData {
+                            row_id: index.1,
+                            voxel_size: glam::Vec3::from_array(*voxel_sizes.first()?),
+                            voxel_indices: voxel_indices.unwrap_or_default(),
+                            colors: colors.map_or(&[], |colors| bytemuck::cast_slice(colors)),
+                            dense_values: dense_values
+                                .and_then(|dense_values| dense_values.into_iter().next()),
+                            values: values.unwrap_or_default(),
+                            origin: origins
+                                .and_then(|origins| origins.first().copied())
+                                .map_or(glam::Vec3::ZERO, glam::Vec3::from_array),
+                            colormap: colormaps
+                                .and_then(|colormaps| colormaps.first().copied())
+                                .and_then(Colormap::from_u8),
+                            value_range: value_ranges
+                                .and_then(|value_ranges| value_ranges.first().copied()),
+                        })
+                    },
+                );
+
+                self.process_data(ctx, &mut instances, spatial_ctx, query_result_hash, data);
+
+                Ok(())
+            },
+        )?;
+
+        match re_renderer::renderer::MeshDrawData::new(ctx.viewer_ctx.render_ctx, &instances) {
+            Ok(draw_data) => Ok(vec![draw_data.into()]),
+            Err(err) => {
+                re_log::error_once!("Failed to create mesh draw data from voxel grids: {err}");
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Color> for VoxelGrid3DVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for VoxelGrid3DVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        Colormap::Viridis
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(VoxelGrid3DVisualizer => [Color, Colormap]);
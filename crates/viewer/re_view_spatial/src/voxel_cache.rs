@@ -0,0 +1,241 @@
+use std::sync::Arc;
+
+use ahash::{HashMap, HashSet};
+
+use itertools::Either;
+use re_chunk_store::{ChunkStoreEvent, RowId};
+use re_log_types::hash::Hash64;
+use re_types::{
+    datatypes::{TensorBuffer, TensorData},
+    tensor_data::TensorElement,
+    Component as _,
+};
+use re_viewer_context::Cache;
+
+// ----------------------------------------------------------------------------
+
+/// A single occupied voxel of a dense voxel grid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DenseVoxel {
+    /// The `[x, y, z]` cell of the voxel.
+    pub index: glam::UVec3,
+
+    /// Row-major index into the dense values, used as the picking instance.
+    pub flat_index: u64,
+
+    pub value: f64,
+}
+
+/// The voxels of a dense voxel grid that are worth drawing.
+#[derive(Debug, Default)]
+pub struct DenseVoxels {
+    /// Occupied voxels with at least one empty (or out of bounds) neighbor.
+    ///
+    /// Voxels that are fully enclosed by other voxels can never be seen,
+    /// so there is no point in drawing them.
+    pub surface: Vec<DenseVoxel>,
+
+    /// Range of the values of all occupied voxels, including hidden ones.
+    pub value_range: Option<[f64; 2]>,
+}
+
+impl DenseVoxels {
+    /// Extracts the visible voxels of a `[x, y, z]` tensor.
+    ///
+    /// Zero and NaN values are empty voxels.
+    /// Returns `None` if the tensor isn't three dimensional.
+    pub fn from_tensor(tensor: &TensorData) -> Option<Self> {
+        re_tracing::profile_function!();
+
+        let &[size_x, size_y, size_z] = tensor.shape() else {
+            return None;
+        };
+        let [size_x, size_y, size_z] = [size_x as usize, size_y as usize, size_z as usize];
+        let num_voxels = size_x * size_y * size_z;
+        let num_elements = tensor.buffer.size_in_bytes() / tensor.dtype().size() as usize;
+        if num_elements < num_voxels {
+            return None;
+        }
+
+        let value_at = |flat_index: usize| -> f64 {
+            match &tensor.buffer {
+                TensorBuffer::U8(buf) => buf[flat_index] as f64,
+                TensorBuffer::U16(buf) => buf[flat_index] as f64,
+                TensorBuffer::U32(buf) => buf[flat_index] as f64,
+                TensorBuffer::U64(buf) => buf[flat_index] as f64,
+                TensorBuffer::I8(buf) => buf[flat_index] as f64,
+                TensorBuffer::I16(buf) => buf[flat_index] as f64,
+                TensorBuffer::I32(buf) => buf[flat_index] as f64,
+                TensorBuffer::I64(buf) => buf[flat_index] as f64,
+                TensorBuffer::F16(buf) => TensorElement::F16(buf[flat_index]).as_f64(),
+                TensorBuffer::F32(buf) => buf[flat_index] as f64,
+                TensorBuffer::F64(buf) => buf[flat_index],
+            }
+        };
+        let is_occupied = |value: f64| value != 0.0 && !value.is_nan();
+        let flat_index_of = |x: usize, y: usize, z: usize| (x * size_y + y) * size_z + z;
+
+        let mut surface = Vec::new();
+        let mut value_range: Option<[f64; 2]> = None;
+
+        for x in 0..size_x {
+            for y in 0..size_y {
+                for z in 0..size_z {
+                    let flat_index = flat_index_of(x, y, z);
+                    let value = value_at(flat_index);
+                    if !is_occupied(value) {
+                        continue;
+                    }
+
+                    value_range = Some(value_range.map_or([value, value], |[min, max]| {
+                        [min.min(value), max.max(value)]
+                    }));
+
+                    let is_enclosed = 0 < x
+                        && x + 1 < size_x
+                        && 0 < y
+                        && y + 1 < size_y
+                        && 0 < z
+                        && z + 1 < size_z
+                        && [
+                            flat_index_of(x - 1, y, z),
+                            flat_index_of(x + 1, y, z),
+                            flat_index_of(x, y - 1, z),
+                            flat_index_of(x, y + 1, z),
+                            flat_index_of(x, y, z - 1),
+                            flat_index_of(x, y, z + 1),
+                        ]
+                        .into_iter()
+                        .all(|neighbor| is_occupied(value_at(neighbor)));
+
+                    if !is_enclosed {
+                        surface.push(DenseVoxel {
+                            index: glam::UVec3::new(x as u32, y as u32, z as u32),
+                            flat_index: flat_index as u64,
+                            value,
+                        });
+                    }
+                }
+            }
+        }
+
+        Some(Self {
+            surface,
+            value_range,
+        })
+    }
+}
+
+/// Caches the visible voxels of dense voxel grids.
+///
+/// Keyed on the row of the voxel grid and the hash of its query results.
+#[derive(Default)]
+pub struct DenseVoxelCache(HashMap<RowId, HashMap<Hash64, Option<Arc<DenseVoxels>>>>);
+
+impl DenseVoxelCache {
+    pub fn entry(
+        &mut self,
+        row_id: RowId,
+        query_result_hash: Hash64,
+        tensor: &TensorData,
+    ) -> Option<Arc<DenseVoxels>> {
+        self.0
+            .entry(row_id)
+            .or_default()
+            .entry(query_result_hash)
+            .or_insert_with(|| {
+                let voxels = DenseVoxels::from_tensor(tensor);
+                if voxels.is_none() {
+                    re_log::warn_once!(
+                        "Dense voxel values must be a 3D tensor, got shape {:?}",
+                        tensor.shape()
+                    );
+                }
+                voxels.map(Arc::new)
+            })
+            .clone()
+    }
+}
+
+impl Cache for DenseVoxelCache {
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = || event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_voxel_grid = || {
+                    let components = event.chunk.components();
+                    components.contains_key(&re_types::components::VoxelSize::name())
+                        || components.contains_key(&re_types::components::TensorData::name())
+                };
+
+                if is_deletion() && contains_voxel_grid() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.0
+            .retain(|row_id, _per_key| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enclosed_voxels_are_skipped() {
+        // A solid 3x3x3 cube with an empty voxel in one corner.
+        let mut values = vec![1u8; 27];
+        values[0] = 0;
+        let tensor = TensorData::new(vec![3, 3, 3], TensorBuffer::U8(values.into()));
+
+        let voxels = DenseVoxels::from_tensor(&tensor).unwrap();
+
+        // Only the center voxel is fully enclosed.
+        assert_eq!(voxels.surface.len(), 25);
+        assert!(!voxels
+            .surface
+            .iter()
+            .any(|voxel| voxel.index == glam::UVec3::ONE));
+        assert_eq!(voxels.value_range, Some([1.0, 1.0]));
+
+        let last = voxels.surface.last().unwrap();
+        assert_eq!(last.index, glam::UVec3::splat(2));
+        assert_eq!(last.flat_index, 26);
+    }
+
+    #[test]
+    fn nan_is_empty() {
+        let tensor = TensorData::new(vec![1, 1, 2], TensorBuffer::F32(vec![f32::NAN, 2.5].into()));
+
+        let voxels = DenseVoxels::from_tensor(&tensor).unwrap();
+
+        assert_eq!(
+            voxels.surface,
+            vec![DenseVoxel {
+                index: glam::UVec3::new(0, 0, 1),
+                flat_index: 1,
+                value: 2.5,
+            }]
+        );
+        assert!(DenseVoxels::from_tensor(&TensorData::new(
+            vec![2, 2],
+            TensorBuffer::U8(vec![1; 4].into())
+        ))
+        .is_none());
+    }
+}
@@ -0,0 +1,103 @@
+//! Matching of log bodies against the search query of a text log view.
+
+/// The compiled search query of a view, cached across frames.
+#[derive(Clone)]
+pub struct BodySearch {
+    query: String,
+    use_regex: bool,
+
+    /// `None` if the query is empty and all entries should be shown.
+    regex: Option<Result<regex::Regex, regex::Error>>,
+}
+
+impl BodySearch {
+    /// Compiles the search query.
+    ///
+    /// A plain query is searched for case-insensitively, a regular expression is used as-is.
+    pub fn new(query: &str, use_regex: bool) -> Self {
+        let regex = (!query.is_empty()).then(|| {
+            if use_regex {
+                regex::Regex::new(query)
+            } else {
+                regex::RegexBuilder::new(&regex::escape(query))
+                    .case_insensitive(true)
+                    .build()
+            }
+        });
+
+        Self {
+            query: query.to_owned(),
+            use_regex,
+            regex,
+        }
+    }
+
+    /// Recompiles the search if the query changed since the last call.
+    pub fn update(&mut self, query: &str, use_regex: bool) {
+        if self.query != query || self.use_regex != use_regex {
+            *self = Self::new(query, use_regex);
+        }
+    }
+
+    /// Whether there is a valid query to search for.
+    pub fn is_active(&self) -> bool {
+        matches!(self.regex, Some(Ok(_)))
+    }
+
+    /// The reason the query couldn't be compiled, if any.
+    pub fn error(&self) -> Option<&regex::Error> {
+        self.regex.as_ref().and_then(|regex| regex.as_ref().err())
+    }
+
+    /// Whether the body of an entry matches the query.
+    ///
+    /// Everything matches an empty or invalid query.
+    pub fn is_match(&self, body: &str) -> bool {
+        match &self.regex {
+            Some(Ok(regex)) => regex.is_match(body),
+            Some(Err(_)) | None => true,
+        }
+    }
+}
+
+impl Default for BodySearch {
+    fn default() -> Self {
+        Self::new("", false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_search_is_case_insensitive_and_literal() {
+        let search = BodySearch::new("Error (42)", false);
+        assert!(search.is_active());
+        assert!(search.is_match("fatal ERROR (42) occurred"));
+        assert!(!search.is_match("error 42"));
+    }
+
+    #[test]
+    fn regex_search() {
+        let search = BodySearch::new(r"^frame \d+$", true);
+        assert!(search.is_match("frame 17"));
+        assert!(!search.is_match("Frame 17"));
+
+        let invalid = BodySearch::new("(unclosed", true);
+        assert!(!invalid.is_active());
+        assert!(invalid.error().is_some());
+        assert!(invalid.is_match("anything"));
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let mut search = BodySearch::default();
+        assert!(!search.is_active());
+        assert!(search.is_match("anything"));
+
+        search.update("thing", false);
+        assert!(search.is_active());
+        assert!(!search.is_match("nope"));
+    }
+}
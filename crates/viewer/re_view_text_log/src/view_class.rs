@@ -1,20 +1,28 @@
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 
 use re_data_ui::item_ui;
-use re_log_types::{EntityPath, Timeline};
+use re_log_types::{EntityPath, TimeInt, Timeline};
+use re_types::blueprint::{archetypes::TextLogSearch, components::UseRegex};
 use re_types::View;
-use re_types::{components::TextLogLevel, ViewClassIdentifier};
-use re_ui::UiExt as _;
+use re_types::{
+    components::{Text, TextLogLevel},
+    ViewClassIdentifier,
+};
+use re_ui::{list_item, UiExt as _};
+use re_view::view_property_ui;
 use re_viewer_context::{
-    level_to_rich_text, IdentifiedViewSystem as _, ViewClass, ViewClassRegistryError, ViewId,
-    ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt, ViewSystemExecutionError,
-    ViewerContext,
+    auto_color_for_entity_path, level_to_rich_text, IdentifiedViewSystem as _, ViewClass,
+    ViewClassRegistryError, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt,
+    ViewSystemExecutionError, ViewerContext,
 };
+use re_viewport_blueprint::ViewProperty;
 
+use super::search::BodySearch;
 use super::visualizer_system::{Entry, TextLogSystem};
 
 // TODO(andreas): This should be a blueprint component.
-#[derive(Clone, PartialEq, Eq, Default)]
+#[derive(Clone, Default)]
 pub struct TextViewState {
     /// Keeps track of the latest time selection made by the user.
     ///
@@ -25,6 +33,38 @@ pub struct TextViewState {
     pub filters: ViewTextFilters,
 
     monospace: bool,
+
+    /// Color entries that don't have a color of their own by their entity path.
+    color_by_entity: bool,
+
+    /// The field column the entries are sorted by, they are sorted by time if `None`.
+    sort: Option<FieldSort>,
+
+    /// The compiled search query of the view, see [`TextLogSearch`].
+    search: BodySearch,
+}
+
+impl TextViewState {
+    /// Cycles the sorting of a field column through ascending, descending and back to time order.
+    fn toggle_sort(&mut self, key: String) {
+        self.sort = match self.sort.take() {
+            Some(sort) if sort.key == key && sort.ascending => Some(FieldSort {
+                key,
+                ascending: false,
+            }),
+            Some(sort) if sort.key == key => None,
+            _ => Some(FieldSort {
+                key,
+                ascending: true,
+            }),
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FieldSort {
+    key: String,
+    ascending: bool,
 }
 
 impl ViewState for TextViewState {
@@ -60,6 +100,13 @@ impl ViewClass for TextView {
 
 Shows `TextLog` entries over time.
 
+Use the search box to only show entries whose body contains the given text,
+or matches a regular expression if `Regex` is checked.
+The arrow buttons next to it move the time cursor to the previous or next match.
+
+Key-value fields of the entries are shown as additional columns.
+Click on the header of a field column to sort by it, click again to reverse or restore the time order.
+
 Note: select the View for filtering options."
             .to_owned()
     }
@@ -101,20 +148,21 @@ Note: select the View for filtering options."
 
     fn selection_ui(
         &self,
-        _ctx: &ViewerContext<'_>,
+        ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
         _space_origin: &EntityPath,
-        _view_id: ViewId,
+        view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
-        let state = state.downcast_mut::<TextViewState>()?;
+        let text_state = state.downcast_mut::<TextViewState>()?;
 
         let ViewTextFilters {
             col_timelines,
             col_entity_path,
             col_log_level,
+            col_fields,
             row_log_levels,
-        } = &mut state.filters;
+        } = &mut text_state.filters;
 
         ui.selection_grid("log_config").show(ui, |ui| {
             ui.grid_left_hand_label("Columns");
@@ -124,6 +172,9 @@ Note: select the View for filtering options."
                 }
                 ui.re_checkbox(col_entity_path, "Entity path");
                 ui.re_checkbox(col_log_level, "Log level");
+                for (key, visible) in col_fields {
+                    ui.re_checkbox(visible, key.as_str());
+                }
             });
             ui.end_row();
 
@@ -137,10 +188,19 @@ Note: select the View for filtering options."
 
             ui.grid_left_hand_label("Text style");
             ui.vertical(|ui| {
-                ui.re_radio_value(&mut state.monospace, false, "Proportional");
-                ui.re_radio_value(&mut state.monospace, true, "Monospace");
+                ui.re_radio_value(&mut text_state.monospace, false, "Proportional");
+                ui.re_radio_value(&mut text_state.monospace, true, "Monospace");
             });
             ui.end_row();
+
+            ui.grid_left_hand_label("Color");
+            ui.re_checkbox(&mut text_state.color_by_entity, "Color by entity")
+                .on_hover_text("Color entries without a color of their own by their entity path");
+            ui.end_row();
+        });
+
+        list_item::list_item_scope(ui, "text_log_selection_ui", |ui| {
+            view_property_ui::<TextLogSearch>(ctx, ui, view_id, self, state);
         });
 
         Ok(())
@@ -152,7 +212,7 @@ Note: select the View for filtering options."
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
 
-        _query: &ViewQuery<'_>,
+        query: &ViewQuery<'_>,
         system_output: re_viewer_context::SystemExecutionOutput,
     ) -> Result<(), ViewSystemExecutionError> {
         re_tracing::profile_function!();
@@ -160,17 +220,36 @@ Note: select the View for filtering options."
         let state = state.downcast_mut::<TextViewState>()?;
         let text = system_output.view_systems.get::<TextLogSystem>()?;
 
+        let search_property = ViewProperty::from_archetype::<TextLogSearch>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            query.view_id,
+        );
+        let search_query = search_property.component_or_fallback::<Text>(ctx, self, state)?;
+        let use_regex = search_property.component_or_fallback::<UseRegex>(ctx, self, state)?;
+        state.search.update(search_query.as_str(), **use_regex);
+
         // TODO(andreas): Should filter text entries in the part-system instead.
         // this likely requires a way to pass state into a context.
-        let entries = text
-            .entries
-            .iter()
-            .filter(|te| {
-                te.level
-                    .as_ref()
-                    .map_or(true, |lvl| state.filters.is_log_level_visible(lvl))
-            })
-            .collect::<Vec<_>>();
+        let mut entries = {
+            re_tracing::profile_scope!("filter");
+            text.entries
+                .iter()
+                .filter(|te| {
+                    te.level
+                        .as_ref()
+                        .map_or(true, |lvl| state.filters.is_log_level_visible(lvl))
+                        && state.search.is_match(te.body.as_str())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if let Some(sort) = &state.sort {
+            re_tracing::profile_scope!("sort by field");
+            entries.sort_by(|a, b| {
+                compare_field_values(a.field(&sort.key), b.field(&sort.key), sort.ascending)
+            });
+        }
 
         egui::Frame {
             inner_margin: re_ui::DesignTokens::view_padding().into(),
@@ -180,6 +259,17 @@ Note: select the View for filtering options."
             // Update filters if necessary.
             state.filters.update(ctx, &entries);
 
+            search_ui(
+                ctx,
+                ui,
+                &search_property,
+                &state.search,
+                search_query.as_str(),
+                **use_regex,
+                query.timeline,
+                &entries,
+            );
+
             let time = ctx
                 .rec_cfg
                 .time_ctrl
@@ -190,29 +280,154 @@ Note: select the View for filtering options."
             // Did the time cursor move since last time?
             // - If it did, autoscroll to the text log to reveal the current time.
             // - Otherwise, let the user scroll around freely!
+            // When sorted by a field, the rows are no longer in time order and there is nothing to scroll to.
             let time_cursor_moved = state.latest_time != time;
-            let scroll_to_row = time_cursor_moved.then(|| {
+            let scroll_to_row = (time_cursor_moved && state.sort.is_none()).then(|| {
                 re_tracing::profile_scope!("search scroll time");
                 entries.partition_point(|te| te.time.as_i64() < time)
             });
 
             state.latest_time = time;
 
-            ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
-                egui::ScrollArea::horizontal().show(ui, |ui| {
-                    re_tracing::profile_scope!("render table");
-                    table_ui(ctx, ui, state, &entries, scroll_to_row);
+            let clicked_sort_key = ui
+                .with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+                    egui::ScrollArea::horizontal()
+                        .show(ui, |ui| {
+                            re_tracing::profile_scope!("render table");
+                            table_ui(ctx, ui, state, &entries, scroll_to_row)
+                        })
+                        .inner
                 })
-            });
+                .inner;
+            if let Some(key) = clicked_sort_key {
+                state.toggle_sort(key);
+            }
         });
 
         Ok(())
     }
 }
 
+re_viewer_context::impl_component_fallback_provider!(TextView => []);
+
+// --- Search ---
+
+/// Shows the search box of the view, together with buttons to jump between the matching entries.
+///
+/// Edits to the search are saved to the blueprint, jumping to a match moves the time cursor.
+#[allow(clippy::too_many_arguments)]
+fn search_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    search_property: &ViewProperty,
+    search: &BodySearch,
+    search_query: &str,
+    use_regex: bool,
+    timeline: Timeline,
+    matches: &[&Entry],
+) {
+    ui.horizontal(|ui| {
+        let mut edited_query = search_query.to_owned();
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut edited_query)
+                .hint_text("Search log bodies")
+                .desired_width(240.0),
+        );
+        if response.changed() {
+            search_property.save_blueprint_component(ctx, &Text::from(edited_query));
+        }
+
+        let mut edited_use_regex = use_regex;
+        if ui
+            .re_checkbox(&mut edited_use_regex, "Regex")
+            .on_hover_text("Search with a regular expression")
+            .changed()
+        {
+            search_property.save_blueprint_component(ctx, &UseRegex::from(edited_use_regex));
+        }
+
+        if let Some(err) = search.error() {
+            ui.label(
+                egui::RichText::new("Invalid regular expression")
+                    .color(ui.visuals().error_fg_color),
+            )
+            .on_hover_text(err.to_string());
+            return;
+        }
+        if !search.is_active() {
+            return;
+        }
+
+        ui.label(match matches.len() {
+            1 => "1 match".to_owned(),
+            num_matches => format!("{num_matches} matches"),
+        });
+
+        let current_time = ctx.rec_cfg.time_ctrl.read().time_int();
+        let previous = ui
+            .small_icon_button(&re_ui::icons::ARROW_LEFT)
+            .on_hover_text("Move the time cursor to the previous match");
+        let next = ui
+            .small_icon_button(&re_ui::icons::ARROW_RIGHT)
+            .on_hover_text("Move the time cursor to the next match");
+
+        let jump_to = if previous.clicked() {
+            previous_match_time(matches, current_time)
+        } else if next.clicked() {
+            next_match_time(matches, current_time)
+        } else {
+            None
+        };
+        if let Some(time) = jump_to {
+            let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+            time_ctrl.set_timeline_and_time(timeline, time);
+            time_ctrl.pause();
+        }
+    });
+}
+
+/// The time of the last match before `current_time`, wrapping around to the last match overall.
+fn previous_match_time(matches: &[&Entry], current_time: Option<TimeInt>) -> Option<TimeInt> {
+    let times = matches.iter().map(|entry| entry.time);
+    current_time
+        .and_then(|current_time| times.clone().filter(|time| *time < current_time).max())
+        .or_else(|| times.max())
+}
+
+/// The time of the first match after `current_time`, wrapping around to the first match overall.
+fn next_match_time(matches: &[&Entry], current_time: Option<TimeInt>) -> Option<TimeInt> {
+    let times = matches.iter().map(|entry| entry.time);
+    current_time
+        .and_then(|current_time| times.clone().filter(|time| *time > current_time).min())
+        .or_else(|| times.min())
+}
+
+/// Orders the values of a field column, numerically if both are numbers.
+///
+/// Numbers come before text, entries without the field always come last.
+fn compare_field_values(a: Option<&str>, b: Option<&str>, ascending: bool) -> Ordering {
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => (a, b),
+        (Some(_), None) => return Ordering::Less,
+        (None, Some(_)) => return Ordering::Greater,
+        (None, None) => return Ordering::Equal,
+    };
+
+    let ordering = match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    };
+    if ascending {
+        ordering
+    } else {
+        ordering.reverse()
+    }
+}
+
 // --- Filters ---
 
-// TODO(cmc): implement "body contains <value>" filter.
 // TODO(cmc): beyond filters, it'd be nice to be able to swap columns at some point.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ViewTextFilters {
@@ -222,6 +437,9 @@ pub struct ViewTextFilters {
     pub col_entity_path: bool,
     pub col_log_level: bool,
 
+    // Each key of the entries' fields has a dedicated column.
+    pub col_fields: BTreeMap<String, bool>,
+
     // Row filters: which rows should be visible?
     pub row_log_levels: BTreeMap<TextLogLevel, bool>,
 }
@@ -232,6 +450,7 @@ impl Default for ViewTextFilters {
             col_entity_path: true,
             col_log_level: true,
             col_timelines: Default::default(),
+            col_fields: Default::default(),
             row_log_levels: Default::default(),
         }
     }
@@ -251,6 +470,7 @@ impl ViewTextFilters {
             col_timelines,
             col_entity_path: _,
             col_log_level: _,
+            col_fields,
             row_log_levels,
         } = self;
 
@@ -261,6 +481,12 @@ impl ViewTextFilters {
         for level in entries.iter().filter_map(|te| te.level.as_ref()) {
             row_log_levels.entry(level.clone()).or_insert(true);
         }
+
+        for field in entries.iter().flat_map(|te| &te.fields) {
+            if !col_fields.contains_key(field.key()) {
+                col_fields.insert(field.key().to_owned(), true);
+            }
+        }
     }
 }
 
@@ -269,13 +495,15 @@ impl ViewTextFilters {
 /// `scroll_to_row` indicates how far down we want to scroll in terms of logical rows,
 /// as opposed to `scroll_to_offset` (computed below) which is how far down we want to
 /// scroll in terms of actual points.
+///
+/// Returns the key of the field column whose header was clicked, if any.
 fn table_ui(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
     state: &TextViewState,
     entries: &[&Entry],
     scroll_to_row: Option<usize>,
-) {
+) -> Option<String> {
     let timelines = state
         .filters
         .col_timelines
@@ -283,6 +511,14 @@ fn table_ui(
         .filter_map(|(timeline, visible)| visible.then_some(timeline))
         .collect::<Vec<_>>();
 
+    let field_keys = state
+        .filters
+        .col_fields
+        .iter()
+        .filter_map(|(key, visible)| visible.then_some(key))
+        .collect::<Vec<_>>();
+    let mut clicked_sort_key = None;
+
     use egui_extras::Column;
 
     let (global_timeline, global_time) = {
@@ -304,6 +540,7 @@ fn table_ui(
 
     let mut body_clip_rect = None;
     let mut current_time_y = None; // where to draw the current time indicator cursor
+    let is_time_ordered = state.sort.is_none();
 
     {
         // timeline(s)
@@ -318,6 +555,9 @@ fn table_ui(
         if state.filters.col_log_level {
             table_builder = table_builder.column(Column::auto().at_least(30.0));
         }
+        // fields
+        table_builder =
+            table_builder.columns(Column::auto().clip(true).at_least(32.0), field_keys.len());
         // body
         table_builder = table_builder.column(Column::remainder().at_least(100.0));
     }
@@ -339,6 +579,31 @@ fn table_ui(
                     ui.strong("Level");
                 });
             }
+            for key in &field_keys {
+                header.col(|ui| {
+                    let sort_indicator = match &state.sort {
+                        Some(sort) if &sort.key == *key => {
+                            if sort.ascending {
+                                " ⏶"
+                            } else {
+                                " ⏷"
+                            }
+                        }
+                        _ => "",
+                    };
+                    let response = ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new(format!("{key}{sort_indicator}")).strong(),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_text("Click to sort by this field");
+                    if response.clicked() {
+                        clicked_sort_key = Some((*key).clone());
+                    }
+                });
+            }
             header.col(|ui| {
                 ui.strong("Body");
             });
@@ -364,7 +629,7 @@ fn table_ui(
                             .unwrap_or(re_log_types::TimeInt::STATIC);
                         item_ui::time_button(ctx, ui, timeline, row_time);
 
-                        if let Some(global_time) = global_time {
+                        if let Some(global_time) = global_time.filter(|_| is_time_ordered) {
                             if *timeline == &global_timeline {
                                 #[allow(clippy::comparison_chain)]
                                 if global_time < row_time {
@@ -408,6 +673,15 @@ fn table_ui(
                     });
                 }
 
+                // fields
+                for key in &field_keys {
+                    row.col(|ui| {
+                        if let Some(value) = entry.field(key) {
+                            ui.label(value);
+                        }
+                    });
+                }
+
                 // body
                 row.col(|ui| {
                     let mut text = egui::RichText::new(entry.body.as_str());
@@ -417,6 +691,8 @@ fn table_ui(
                     }
                     if let Some(color) = entry.color {
                         text = text.color(color);
+                    } else if state.color_by_entity {
+                        text = text.color(auto_color_for_entity_path(&entry.entity_path));
                     }
 
                     ui.label(text);
@@ -433,6 +709,8 @@ fn table_ui(
             (1.0, egui::Color32::WHITE),
         );
     }
+
+    clicked_sort_key
 }
 
 fn calc_row_height(entry: &Entry) -> f32 {
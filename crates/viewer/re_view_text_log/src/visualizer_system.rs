@@ -3,10 +3,10 @@ use re_chunk_store::ResolvedTimeRange;
 use re_entity_db::EntityPath;
 use re_log_types::TimeInt;
 use re_log_types::TimePoint;
-use re_query::{clamped_zip_1x2, range_zip_1x2};
+use re_query::{clamped_zip_1x2, range_zip_1x3};
 use re_types::{
     archetypes::TextLog,
-    components::{Color, Text, TextLogLevel},
+    components::{Color, Text, TextLogField, TextLogLevel},
     Component as _,
 };
 use re_view::{range_with_blueprint_resolved_data, RangeResultsExt};
@@ -23,6 +23,19 @@ pub struct Entry {
     pub color: Option<Color>,
     pub body: Text,
     pub level: Option<TextLogLevel>,
+
+    /// Structured key-value fields, shared by all entries of the same row.
+    pub fields: Vec<TextLogField>,
+}
+
+impl Entry {
+    /// The value of the field with the given key, if the entry has one.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.key() == key)
+            .map(|field| field.value())
+    }
 }
 
 /// A text scene, with everything needed to render it.
@@ -90,7 +103,12 @@ impl TextLogSystem {
             None,
             query,
             data_result,
-            [Text::name(), TextLogLevel::name(), Color::name()],
+            [
+                Text::name(),
+                TextLogLevel::name(),
+                Color::name(),
+                TextLogField::name(),
+            ],
         );
 
         let Some(all_text_chunks) = results.get_required_chunks(&Text::name()) else {
@@ -108,16 +126,31 @@ impl TextLogSystem {
         let all_texts = results.iter_as(timeline, Text::name());
         let all_levels = results.iter_as(timeline, TextLogLevel::name());
         let all_colors = results.iter_as(timeline, Color::name());
+        let all_fields = results.iter_as(timeline, TextLogField::name());
+
+        // Keys and values are stored as the two halves of a `Utf8Pair`.
+        let all_field_keys_and_values = izip!(
+            all_fields.slice_from_struct_field::<String>("first"),
+            all_fields.slice_from_struct_field::<String>("second"),
+        )
+        .map(|((index, keys), (_index, values))| (index, (keys, values)));
 
-        let all_frames = range_zip_1x2(
+        let all_frames = range_zip_1x3(
             all_texts.slice::<String>(),
             all_levels.slice::<String>(),
             all_colors.slice::<u32>(),
+            all_field_keys_and_values,
         );
 
         let all_frames = izip!(all_timepoints, all_frames);
 
-        for (timepoint, ((data_time, _row_id), bodies, levels, colors)) in all_frames {
+        for (timepoint, ((data_time, _row_id), bodies, levels, colors, fields)) in all_frames {
+            let fields = fields.map_or_else(Vec::new, |(keys, values)| {
+                izip!(keys, values)
+                    .map(|(key, value)| TextLogField::new(key, value))
+                    .collect::<Vec<_>>()
+            });
+
             let levels = levels.as_deref().unwrap_or(&[]).iter().cloned().map(Some);
             let colors = colors
                 .unwrap_or(&[])
@@ -140,6 +173,7 @@ impl TextLogSystem {
                     color,
                     body: text.clone().into(),
                     level: level.clone().map(Into::into),
+                    fields: fields.clone(),
                 });
             }
         }
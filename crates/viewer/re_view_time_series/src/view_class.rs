@@ -1,6 +1,6 @@
 use egui::ahash::{HashMap, HashSet};
 
-use egui_plot::{Legend, Line, Plot, PlotPoint, Points};
+use egui_plot::{Legend, Line, LineStyle, Plot, PlotPoint, Points};
 
 use re_chunk_store::TimeType;
 use re_format::next_grid_tick_magnitude_ns;
@@ -152,6 +152,10 @@ Display time series data in a plot.
         true
     }
 
+    fn overlays_compared_recording(&self) -> bool {
+        true
+    }
+
     fn default_query_range(&self, _view_state: &dyn ViewState) -> QueryRange {
         QueryRange::TimeRange(TimeRange::EVERYTHING)
     }
@@ -325,10 +329,29 @@ Display time series data in a plot.
             .chain(point_series.all_series.iter())
             .collect();
 
+        // The series of the recording this view is compared against, if any.
+        let (compared_plot_series, compared_time_offset) =
+            if let Some(compared) = &system_output.compared {
+                let line_series = compared.output.view_systems.get::<SeriesLineSystem>()?;
+                let point_series = compared.output.view_systems.get::<SeriesPointSystem>()?;
+                let compared_plot_series: Vec<_> = std::iter::empty()
+                    .chain(line_series.all_series.iter())
+                    .chain(point_series.all_series.iter())
+                    .collect();
+                (compared_plot_series, compared.time_offset)
+            } else {
+                (Vec::new(), 0)
+            };
+
         // Get the minimum time/X value for the entire plot…
         let min_time = all_plot_series
             .iter()
             .map(|line| line.min_time)
+            .chain(
+                compared_plot_series
+                    .iter()
+                    .map(|line| line.min_time.saturating_add(compared_time_offset)),
+            )
             .min()
             .unwrap_or(0);
 
@@ -451,7 +474,17 @@ Display time series data in a plot.
                     .map(|series| series.entity_path.clone()),
             );
 
-            for series in all_plot_series {
+            // Compared series are shifted onto the times of the active recording and drawn dashed or hollow.
+            let series_to_draw = all_plot_series
+                .iter()
+                .map(|series| (*series, 0, false))
+                .chain(
+                    compared_plot_series
+                        .iter()
+                        .map(|series| (*series, compared_time_offset, true)),
+                );
+
+            for (series, series_time_offset, is_compared) in series_to_draw {
                 let points = series
                     .points
                     .iter()
@@ -463,28 +496,44 @@ Display time series data in a plot.
                             *state.scalar_range.end_mut() = p.1;
                         }
 
-                        [(p.0 - time_offset) as _, p.1]
+                        [(p.0 + series_time_offset - time_offset) as _, p.1]
                     })
                     .collect::<Vec<_>>();
 
                 let color = series.color;
-                let id = egui::Id::new(series.entity_path.hash());
+                let (id, label) = if is_compared {
+                    (
+                        egui::Id::new((series.entity_path.hash(), "compared")),
+                        format!("{} (compared)", series.label),
+                    )
+                } else {
+                    (
+                        egui::Id::new(series.entity_path.hash()),
+                        series.label.clone(),
+                    )
+                };
                 plot_item_id_to_entity_path.insert(id, series.entity_path.clone());
 
                 match series.kind {
                     PlotSeriesKind::Continuous => plot_ui.line(
                         Line::new(points)
-                            .name(&series.label)
+                            .name(&label)
                             .color(color)
                             .width(2.0 * series.radius_ui)
+                            .style(if is_compared {
+                                LineStyle::dashed_loose()
+                            } else {
+                                LineStyle::Solid
+                            })
                             .id(id),
                     ),
                     PlotSeriesKind::Scatter(scatter_attrs) => plot_ui.points(
                         Points::new(points)
-                            .name(&series.label)
+                            .name(&label)
                             .color(color)
                             .radius(series.radius_ui)
                             .shape(scatter_attrs.marker.into())
+                            .filled(!is_compared)
                             .id(id),
                     ),
                     // Break up the chart. At some point we might want something fancier.
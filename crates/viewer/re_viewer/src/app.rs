@@ -6,7 +6,7 @@ use re_build_info::CrateVersion;
 use re_capabilities::MainThreadToken;
 use re_data_source::{DataSource, FileContents};
 use re_entity_db::entity_db::EntityDb;
-use re_log_types::{ApplicationId, FileSource, LogMsg, StoreKind};
+use re_log_types::{ApplicationId, FileSource, LogMsg, StoreKind, TimeInt};
 use re_renderer::WgpuResourcePoolStatistics;
 use re_smart_channel::{ReceiveSet, SmartChannelSource};
 use re_ui::{notifications, DesignTokens, UICommand, UICommandSender};
@@ -31,6 +31,8 @@ enum TimeControlCommand {
     TogglePlayPause,
     StepBack,
     StepForward,
+    PreviousMarker,
+    NextMarker,
     Restart,
     Follow,
 }
@@ -57,6 +59,18 @@ pub struct StartupOptions {
     #[cfg(not(target_arch = "wasm32"))]
     pub screenshot_to_path_then_quit: Option<std::path::PathBuf>,
 
+    /// Render the recording frame by frame to disk, see [`crate::render`].
+    ///
+    /// This hides all panels, so that only the viewport is rendered.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub render_job: Option<crate::RenderJobOptions>,
+
+    /// Load view plugins (`*.wasm` files) from this directory.
+    ///
+    /// See [`re_view_plugin`] for how to write one.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub view_plugin_dir: Option<std::path::PathBuf>,
+
     /// A user has specifically requested the welcome screen be hidden.
     pub hide_welcome_screen: bool,
 
@@ -99,6 +113,11 @@ pub struct StartupOptions {
     /// Default overrides for state of top/side/bottom panels.
     pub panel_state_overrides: PanelStateOverrides,
 
+    /// Labels of custom buttons to show in the top panel.
+    ///
+    /// Pressing one publishes a [`re_log_types::ViewerEventKind::ButtonPress`] to the SDKs we receive data from.
+    pub custom_buttons: Vec<String>,
+
     /// Whether or not to enable usage of the `History` API on web.
     ///
     /// It is disabled by default.
@@ -129,6 +148,12 @@ impl Default for StartupOptions {
             #[cfg(not(target_arch = "wasm32"))]
             screenshot_to_path_then_quit: None,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            render_job: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            view_plugin_dir: None,
+
             hide_welcome_screen: false,
 
             #[cfg(not(target_arch = "wasm32"))]
@@ -145,6 +170,7 @@ impl Default for StartupOptions {
             fullscreen_options: Default::default(),
 
             panel_state_overrides: Default::default(),
+            custom_buttons: Vec::new(),
 
             #[cfg(target_arch = "wasm32")]
             enable_history: false,
@@ -178,6 +204,20 @@ pub struct App {
     pub(crate) egui_ctx: egui::Context,
     screenshotter: crate::screenshotter::Screenshotter,
 
+    #[cfg(not(target_arch = "wasm32"))]
+    render_job: Option<crate::render_job::RenderJob>,
+
+    /// Blueprint templates that can be applied to recordings.
+    #[cfg(not(target_arch = "wasm32"))]
+    blueprint_templates: crate::blueprint_templates::BlueprintTemplates,
+
+    /// Applies matching templates to newly opened recordings.
+    #[cfg(not(target_arch = "wasm32"))]
+    template_matcher: crate::blueprint_templates::TemplateMatcher,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    blueprint_templates_modal: crate::ui::BlueprintTemplatesModal,
+
     #[cfg(target_arch = "wasm32")]
     pub(crate) popstate_listener: Option<crate::history::PopstateListener>,
 
@@ -236,6 +276,13 @@ pub struct App {
     /// This field isn't used directly, but is propagated to all recording configs
     /// when they are created.
     pub timeline_callbacks: Option<re_viewer_context::TimelineCallbacks>,
+
+    /// Tells the SDKs we receive data from what the user is doing.
+    viewer_event_sender: crate::viewer_events::ViewerEventSender,
+
+    /// Lets the SDKs we receive data from remote control the viewer.
+    #[cfg(feature = "grpc")]
+    viewer_command_receiver: crate::viewer_commands::ViewerCommandReceiver,
 }
 
 impl App {
@@ -246,6 +293,27 @@ impl App {
         app_env: &crate::AppEnvironment,
         startup_options: StartupOptions,
         creation_context: &eframe::CreationContext<'_>,
+    ) -> Self {
+        Self::with_render_state(
+            main_thread_token,
+            build_info,
+            app_env,
+            startup_options,
+            &creation_context.egui_ctx,
+            creation_context.storage,
+            creation_context.wgpu_render_state.as_ref(),
+        )
+    }
+
+    /// Create a viewer that isn't tied to a window, see [`Self::update_and_ui`].
+    pub(crate) fn with_render_state(
+        main_thread_token: MainThreadToken,
+        build_info: re_build_info::BuildInfo,
+        app_env: &crate::AppEnvironment,
+        startup_options: StartupOptions,
+        egui_ctx: &egui::Context,
+        storage: Option<&dyn eframe::Storage>,
+        wgpu_render_state: Option<&egui_wgpu::RenderState>,
     ) -> Self {
         re_tracing::profile_function!();
 
@@ -261,7 +329,7 @@ impl App {
         }
 
         let mut state: AppState = if startup_options.persist_state {
-            creation_context.storage
+            storage
                 .and_then(|storage| {
                     // This re-implements: `eframe::get_value` so we can customize the warning message.
                     // TODO(#2849): More thorough error-handling.
@@ -293,14 +361,32 @@ impl App {
             );
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(view_plugin_dir) = &startup_options.view_plugin_dir {
+            for class in re_view_plugin::load_view_plugins(view_plugin_dir) {
+                let name = class.name().to_owned();
+                if let Err(err) =
+                    view_class_registry.add_dynamic_class(class.class_identifier(), Box::new(class))
+                {
+                    re_log::error!("Failed to register the view plugin {name:?}: {err}");
+                }
+            }
+        }
+
         #[allow(unused_mut, clippy::needless_update)] // false positive on web
         let mut screenshotter = crate::screenshotter::Screenshotter::default();
 
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(screenshot_path) = startup_options.screenshot_to_path_then_quit.clone() {
-            screenshotter.screenshot_to_path_then_quit(&creation_context.egui_ctx, screenshot_path);
+            screenshotter.screenshot_to_path_then_quit(egui_ctx, screenshot_path);
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let render_job = startup_options
+            .render_job
+            .clone()
+            .map(crate::render_job::RenderJob::new);
+
         let (command_sender, command_receiver) = command_channel();
 
         let mut component_ui_registry = re_component_ui::create_component_ui_registry();
@@ -311,7 +397,7 @@ impl App {
             .checked_sub(web_time::Duration::from_secs(1_000_000_000))
             .unwrap_or(web_time::Instant::now());
 
-        let (adapter_backend, device_tier) = creation_context.wgpu_render_state.as_ref().map_or(
+        let (adapter_backend, device_tier) = wgpu_render_state.map_or(
             (
                 wgpu::Backend::Empty,
                 re_renderer::config::DeviceTier::Limited,
@@ -332,7 +418,20 @@ impl App {
         );
         analytics.on_viewer_started(build_info, adapter_backend, device_tier);
 
-        let panel_state_overrides = startup_options.panel_state_overrides;
+        #[allow(unused_mut)] // false positive on web
+        let mut panel_state_overrides = startup_options.panel_state_overrides;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if render_job.is_some() {
+            // Only the viewport should end up in the rendered frames.
+            let hidden = Some(re_types::blueprint::components::PanelState::Hidden);
+            panel_state_overrides = PanelStateOverrides {
+                top: hidden,
+                blueprint: hidden,
+                selection: hidden,
+                time: hidden,
+            };
+        }
 
         let reflection = re_types::reflection::generate_reflection().unwrap_or_else(|err| {
             re_log::error!(
@@ -381,15 +480,33 @@ impl App {
         #[cfg(not(target_arch = "wasm32"))]
         let timeline_callbacks = None;
 
+        // Templates are user configuration, don't let them affect e.g. screenshot tests.
+        #[cfg(not(target_arch = "wasm32"))]
+        let blueprint_templates = if startup_options.persist_state {
+            crate::blueprint_templates::BlueprintTemplates::load()
+        } else {
+            Default::default()
+        };
+
         Self {
             main_thread_token,
             build_info,
             startup_options,
             start_time: web_time::Instant::now(),
             ram_limit_warner: re_memory::RamLimitWarner::warn_at_fraction_of_max(0.75),
-            egui_ctx: creation_context.egui_ctx.clone(),
+            egui_ctx: egui_ctx.clone(),
             screenshotter,
 
+            #[cfg(not(target_arch = "wasm32"))]
+            render_job,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            blueprint_templates,
+            #[cfg(not(target_arch = "wasm32"))]
+            template_matcher: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            blueprint_templates_modal: Default::default(),
+
             #[cfg(target_arch = "wasm32")]
             popstate_listener: None,
 
@@ -432,6 +549,11 @@ impl App {
             reflection,
 
             timeline_callbacks,
+
+            viewer_event_sender: Default::default(),
+
+            #[cfg(feature = "grpc")]
+            viewer_command_receiver: Default::default(),
         }
     }
 
@@ -474,6 +596,25 @@ impl App {
         &self.rx
     }
 
+    /// Also send every [`re_log_types::ViewerEvent`] to this sink,
+    /// e.g. to forward them to the SDKs connected to a TCP server.
+    ///
+    /// Events are always published back over the message proxies and WebSocket servers we
+    /// receive data from, there is no need to add a sink for those.
+    pub fn add_viewer_event_sink(&mut self, sink: crate::ViewerEventSink) {
+        self.viewer_event_sender.add_sink(sink);
+    }
+
+    /// See [`StartupOptions::custom_buttons`].
+    pub(crate) fn custom_buttons(&self) -> &[String] {
+        &self.startup_options.custom_buttons
+    }
+
+    /// Publishes that one of the [`StartupOptions::custom_buttons`] was pressed.
+    pub(crate) fn press_custom_button(&mut self, button: String) {
+        self.viewer_event_sender.press_button(button);
+    }
+
     /// Adds a new view class to the viewer.
     pub fn add_view_class<T: ViewClass + Default + 'static>(
         &mut self,
@@ -618,6 +759,53 @@ impl App {
                     }
                 }
             }
+            SystemCommand::AppendToStore(store_id, chunks) => {
+                re_log::trace!(
+                    "Append to store {store_id}: {}",
+                    chunks.iter().map(|c| c.entity_path()).join(", ")
+                );
+
+                let entity_db = store_hub.entity_db_mut(&store_id);
+                for chunk in chunks {
+                    if let Err(err) = entity_db.add_chunk(&Arc::new(chunk)) {
+                        re_log::warn_once!("Failed to append data to {store_id}: {err}");
+                    }
+                }
+            }
+            SystemCommand::EditRecording(recording_id, chunks) => {
+                re_log::trace!(
+                    "Edit recording {recording_id}: {}",
+                    chunks.iter().map(|c| c.entity_path()).join(", ")
+                );
+
+                // Remember where the blueprint was, so undo can interleave both kinds of edits.
+                let blueprint_time = store_hub
+                    .active_blueprint_id()
+                    .and_then(|blueprint_id| {
+                        let blueprint_db = store_hub.store_bundle().get(blueprint_id)?;
+                        Some(
+                            self.state
+                                .blueprint_undo_state
+                                .get(blueprint_id)
+                                .map_or_else(
+                                    || BlueprintUndoState::default().current_time(blueprint_db),
+                                    |undo_state| undo_state.current_time(blueprint_db),
+                                ),
+                        )
+                    })
+                    .unwrap_or(TimeInt::ZERO);
+
+                let recording = store_hub.entity_db_mut(&recording_id);
+                if let Err(err) = self
+                    .state
+                    .recording_undo_state
+                    .entry(recording_id.clone())
+                    .or_default()
+                    .add_edit(recording, blueprint_time, chunks)
+                {
+                    re_log::error!("Failed to edit {recording_id}: {err}");
+                }
+            }
             SystemCommand::UndoBlueprint { blueprint_id } => {
                 let blueprint_db = store_hub.entity_db_mut(&blueprint_id);
                 self.state
@@ -634,6 +822,23 @@ impl App {
                     .redo();
             }
 
+            SystemCommand::UndoRecordingEdit { recording_id } => {
+                let recording = store_hub.entity_db_mut(&recording_id);
+                self.state
+                    .recording_undo_state
+                    .entry(recording_id)
+                    .or_default()
+                    .undo(recording);
+            }
+            SystemCommand::RedoRecordingEdit { recording_id } => {
+                let recording = store_hub.entity_db_mut(&recording_id);
+                self.state
+                    .recording_undo_state
+                    .entry(recording_id)
+                    .or_default()
+                    .redo(recording);
+            }
+
             SystemCommand::DropEntity(blueprint_id, entity_path) => {
                 let blueprint_db = store_hub.entity_db_mut(&blueprint_id);
                 blueprint_db.drop_entity_path_recursive(&entity_path);
@@ -667,6 +872,47 @@ impl App {
         }
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn run_blueprint_template_action(
+        &mut self,
+        action: crate::ui::BlueprintTemplateAction,
+        store_hub: &mut StoreHub,
+    ) {
+        use crate::ui::BlueprintTemplateAction;
+
+        match action {
+            BlueprintTemplateAction::Apply {
+                template,
+                variables,
+            } => {
+                let Some(template) = self.blueprint_templates.get(template) else {
+                    return;
+                };
+                if let Err(err) = self.template_matcher.apply(template, &variables, store_hub) {
+                    re_log::error!(
+                        "Failed to apply blueprint template {:?}: {err}",
+                        template.name
+                    );
+                }
+            }
+
+            BlueprintTemplateAction::Save { name, variables } => {
+                let Some(blueprint) = store_hub
+                    .active_blueprint_id()
+                    .and_then(|id| store_hub.store_bundle().get(id))
+                else {
+                    re_log::error!("There is no active blueprint to save as a template");
+                    return;
+                };
+                let result = crate::blueprint_templates::create_template(blueprint, &variables)
+                    .and_then(|template| self.blueprint_templates.save(&name, template));
+                if let Err(err) = result {
+                    re_log::error!("Failed to save blueprint template {name:?}: {err}");
+                }
+            }
+        }
+    }
+
     fn run_ui_command(
         &mut self,
         egui_ctx: &egui::Context,
@@ -723,6 +969,10 @@ impl App {
                     re_log::error!("Failed to save blueprint: {err}");
                 }
             }
+            #[cfg(not(target_arch = "wasm32"))]
+            UICommand::BlueprintTemplates => {
+                self.blueprint_templates_modal.open();
+            }
 
             #[cfg(not(target_arch = "wasm32"))]
             UICommand::Open => {
@@ -803,15 +1053,50 @@ impl App {
             UICommand::Undo => {
                 if let Some(store_context) = store_context {
                     let blueprint_id = store_context.blueprint.store_id().clone();
-                    self.command_sender
-                        .send_system(SystemCommand::UndoBlueprint { blueprint_id });
+                    let recording_id = store_context.recording.store_id();
+
+                    // Undo whichever edit came last: one of the recording, or one of the blueprint.
+                    let undo_recording_edit = self
+                        .state
+                        .recording_undo_state
+                        .get(&recording_id)
+                        .is_some_and(|undo_state| {
+                            undo_state.undo_is_next(
+                                self.state.blueprint_undo_state.get(&blueprint_id),
+                                store_context.blueprint,
+                            )
+                        });
+
+                    if undo_recording_edit {
+                        self.command_sender
+                            .send_system(SystemCommand::UndoRecordingEdit { recording_id });
+                    } else {
+                        self.command_sender
+                            .send_system(SystemCommand::UndoBlueprint { blueprint_id });
+                    }
                 }
             }
             UICommand::Redo => {
                 if let Some(store_context) = store_context {
                     let blueprint_id = store_context.blueprint.store_id().clone();
-                    self.command_sender
-                        .send_system(SystemCommand::RedoBlueprint { blueprint_id });
+                    let recording_id = store_context.recording.store_id();
+
+                    let redo_recording_edit = self
+                        .state
+                        .recording_undo_state
+                        .get(&recording_id)
+                        .is_some_and(|undo_state| {
+                            undo_state
+                                .redo_is_next(self.state.blueprint_undo_state.get(&blueprint_id))
+                        });
+
+                    if redo_recording_edit {
+                        self.command_sender
+                            .send_system(SystemCommand::RedoRecordingEdit { recording_id });
+                    } else {
+                        self.command_sender
+                            .send_system(SystemCommand::RedoBlueprint { blueprint_id });
+                    }
                 }
             }
 
@@ -920,6 +1205,12 @@ impl App {
             UICommand::PlaybackStepForward => {
                 self.run_time_control_command(store_context, TimeControlCommand::StepForward);
             }
+            UICommand::PlaybackPreviousMarker => {
+                self.run_time_control_command(store_context, TimeControlCommand::PreviousMarker);
+            }
+            UICommand::PlaybackNextMarker => {
+                self.run_time_control_command(store_context, TimeControlCommand::NextMarker);
+            }
             UICommand::PlaybackRestart => {
                 self.run_time_control_command(store_context, TimeControlCommand::Restart);
             }
@@ -987,9 +1278,13 @@ impl App {
         store_context: Option<&StoreContext<'_>>,
         command: TimeControlCommand,
     ) {
-        let Some(entity_db) = store_context.as_ref().map(|ctx| ctx.recording) else {
+        let Some(store_context) = store_context else {
             return;
         };
+        let entity_db = store_context.recording;
+        let blueprint_query = self
+            .state
+            .blueprint_query_for_viewer(store_context.blueprint);
         let rec_id = entity_db.store_id();
         let Some(rec_cfg) = self.state.recording_config_mut(&rec_id) else {
             return;
@@ -1011,12 +1306,157 @@ impl App {
             TimeControlCommand::StepForward => {
                 time_ctrl.step_time_fwd(times_per_timeline);
             }
+            TimeControlCommand::PreviousMarker | TimeControlCommand::NextMarker => {
+                let Some(time) = time_ctrl.time_int() else {
+                    return;
+                };
+                let marker_times = re_time_panel::marker_times(
+                    entity_db,
+                    store_context.caches,
+                    store_context.blueprint,
+                    &blueprint_query,
+                    time_ctrl.timeline(),
+                );
+                let marker = if command == TimeControlCommand::NextMarker {
+                    re_time_panel::next_marker(&marker_times, time)
+                } else {
+                    re_time_panel::previous_marker(&marker_times, time)
+                };
+                if let Some(marker) = marker {
+                    time_ctrl.set_time(marker);
+                    time_ctrl.pause();
+                }
+            }
             TimeControlCommand::Restart => {
                 time_ctrl.restart(times_per_timeline);
             }
         }
     }
 
+    #[cfg(feature = "grpc")]
+    fn run_viewer_command(
+        &mut self,
+        command: re_log_types::ViewerCommand,
+        store_hub: &StoreHub,
+        egui_ctx: &egui::Context,
+    ) {
+        use re_log_types::{ViewerCommand, ViewerPlayState};
+
+        re_log::debug!("Running viewer command: {command:?}");
+
+        match command {
+            ViewerCommand::SetActiveRecording { store_id } => {
+                if store_hub.store_bundle().contains(&store_id) {
+                    self.command_sender
+                        .send_system(SystemCommand::ActivateRecording(store_id));
+                } else {
+                    re_log::warn!("Cannot activate unknown recording {store_id}");
+                }
+            }
+
+            ViewerCommand::CloseRecording { store_id } => {
+                self.command_sender
+                    .send_system(SystemCommand::CloseStore(store_id));
+            }
+
+            ViewerCommand::OpenRecording { uri } => {
+                // Urls are fine, but local files must come from our working directory.
+                #[cfg(not(target_arch = "wasm32"))]
+                let uri = if uri.contains("://") && !uri.starts_with("file://") {
+                    uri
+                } else {
+                    let path = uri.strip_prefix("file://").unwrap_or(&uri);
+                    match crate::viewer_commands::sandboxed_path(path) {
+                        Ok(path) => path.to_string_lossy().into_owned(),
+                        Err(err) => {
+                            re_log::warn!("Refusing to open recording: {err}");
+                            return;
+                        }
+                    }
+                };
+
+                self.command_sender
+                    .send_system(SystemCommand::LoadDataSource(DataSource::from_uri(
+                        FileSource::Uri,
+                        uri,
+                    )));
+            }
+
+            ViewerCommand::Select { items } => {
+                let items = items.into_iter().map(|item| {
+                    let instance_path =
+                        re_entity_db::InstancePath::instance(item.entity_path, item.instance);
+                    (re_viewer_context::Item::InstancePath(instance_path), None)
+                });
+                self.state.selection_state.set_selection(items);
+            }
+
+            ViewerCommand::SetTime { timeline, time } => {
+                let Some(entity_db) = store_hub.active_recording() else {
+                    re_log::warn!("Cannot set time: no active recording");
+                    return;
+                };
+                let Some(timeline) = entity_db
+                    .timelines()
+                    .find(|known| *known.name() == timeline)
+                    .copied()
+                else {
+                    re_log::warn!("Cannot set time: unknown timeline {timeline:?}");
+                    return;
+                };
+                let Some(rec_cfg) = self.state.recording_config_mut(&entity_db.store_id()) else {
+                    return;
+                };
+                let time_ctrl = rec_cfg.time_ctrl.get_mut();
+
+                if let Some(time) = time {
+                    time_ctrl.set_timeline_and_time(timeline, time);
+                } else {
+                    time_ctrl.set_timeline(timeline);
+                }
+            }
+
+            ViewerCommand::SetPlayState { play_state } => {
+                let Some(entity_db) = store_hub.active_recording() else {
+                    re_log::warn!("Cannot set play state: no active recording");
+                    return;
+                };
+                let Some(rec_cfg) = self.state.recording_config_mut(&entity_db.store_id()) else {
+                    return;
+                };
+                let play_state = match play_state {
+                    ViewerPlayState::Paused => PlayState::Paused,
+                    ViewerPlayState::Playing => PlayState::Playing,
+                    ViewerPlayState::Following => PlayState::Following,
+                };
+                rec_cfg
+                    .time_ctrl
+                    .get_mut()
+                    .set_play_state(entity_db.times_per_timeline(), play_state);
+            }
+
+            ViewerCommand::Screenshot { path } => {
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(path) = path {
+                    match crate::viewer_commands::sandboxed_path(&path) {
+                        Ok(path) => self.screenshotter.screenshot_to_path(egui_ctx, path),
+                        Err(err) => re_log::warn!("Refusing to take screenshot: {err}"),
+                    }
+                } else {
+                    self.screenshotter.request_screenshot(egui_ctx);
+                }
+
+                #[cfg(target_arch = "wasm32")]
+                {
+                    _ = (path, egui_ctx);
+                    re_log::warn!("Screenshots are not supported on the web");
+                }
+            }
+        }
+
+        egui_ctx.request_repaint();
+    }
+
     #[cfg(target_arch = "wasm32")]
     fn run_copy_direct_link_command(&mut self, store_context: Option<&StoreContext<'_>>) {
         let location = web_sys::window().unwrap().location();
@@ -1118,7 +1558,7 @@ impl App {
     fn ui(
         &mut self,
         egui_ctx: &egui::Context,
-        frame: &eframe::Frame,
+        render_state: &egui_wgpu::RenderState,
         app_blueprint: &AppBlueprint<'_>,
         gpu_resource_stats: &WgpuResourcePoolStatistics,
         store_context: Option<&StoreContext<'_>>,
@@ -1138,7 +1578,7 @@ impl App {
                 crate::ui::mobile_warning_ui(ui);
 
                 crate::ui::top_panel(
-                    frame,
+                    Some(render_state),
                     self,
                     app_blueprint,
                     store_context,
@@ -1151,10 +1591,7 @@ impl App {
                 self.egui_debug_panel_ui(ui);
 
                 // TODO(andreas): store the re_renderer somewhere else.
-                let egui_renderer = {
-                    let render_state = frame.wgpu_render_state().unwrap();
-                    &mut render_state.renderer.write()
-                };
+                let egui_renderer = &mut render_state.renderer.write();
 
                 if let Some(render_ctx) = egui_renderer
                     .callback_resources
@@ -1718,107 +2155,18 @@ impl App {
             self.screenshotter.save(&self.egui_ctx, image);
         }
     }
-}
-
-#[cfg(target_arch = "wasm32")]
-fn blueprint_loader() -> BlueprintPersistence {
-    // TODO(#2579): implement persistence for web
-    BlueprintPersistence {
-        loader: None,
-        saver: None,
-        validator: Some(Box::new(crate::blueprint::is_valid_blueprint)),
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn blueprint_loader() -> BlueprintPersistence {
-    use re_entity_db::StoreBundle;
-
-    fn load_blueprint_from_disk(app_id: &ApplicationId) -> anyhow::Result<Option<StoreBundle>> {
-        let blueprint_path = crate::saving::default_blueprint_path(app_id)?;
-        if !blueprint_path.exists() {
-            return Ok(None);
-        }
-
-        re_log::debug!("Trying to load blueprint for {app_id} from {blueprint_path:?}");
-
-        if let Some(bundle) = crate::loading::load_blueprint_file(&blueprint_path) {
-            for store in bundle.entity_dbs() {
-                if store.store_kind() == StoreKind::Blueprint
-                    && !crate::blueprint::is_valid_blueprint(store)
-                {
-                    re_log::warn_once!("Blueprint for {app_id} at {blueprint_path:?} appears invalid - will ignore. This is expected if you have just upgraded Rerun versions.");
-                    return Ok(None);
-                }
-            }
-            Ok(Some(bundle))
-        } else {
-            Ok(None)
-        }
-    }
 
     #[cfg(not(target_arch = "wasm32"))]
-    fn save_blueprint_to_disk(app_id: &ApplicationId, blueprint: &EntityDb) -> anyhow::Result<()> {
-        let blueprint_path = crate::saving::default_blueprint_path(app_id)?;
-
-        let messages = blueprint.to_messages(None);
-        let rrd_version = blueprint
-            .store_info()
-            .and_then(|info| info.store_version)
-            .unwrap_or(re_build_info::CrateVersion::LOCAL);
-
-        // TODO(jleibs): Should we push this into a background thread? Blueprints should generally
-        // be small & fast to save, but maybe not once we start adding big pieces of user data?
-        crate::saving::encode_to_file(rrd_version, &blueprint_path, messages)?;
-
-        re_log::debug!("Saved blueprint for {app_id} to {blueprint_path:?}");
-
-        Ok(())
-    }
-
-    BlueprintPersistence {
-        loader: Some(Box::new(load_blueprint_from_disk)),
-        saver: Some(Box::new(save_blueprint_to_disk)),
-        validator: Some(Box::new(crate::blueprint::is_valid_blueprint)),
-    }
-}
-
-impl eframe::App for App {
-    fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
-        [0.0; 4] // transparent so we can get rounded corners when doing [`re_ui::CUSTOM_WINDOW_DECORATIONS`]
-    }
-
-    fn save(&mut self, storage: &mut dyn eframe::Storage) {
-        if !self.startup_options.persist_state {
-            return;
-        }
-
-        re_tracing::profile_function!();
-
-        // Save the app state
-        eframe::set_value(storage, eframe::APP_KEY, &self.state);
-
-        // Save the blueprints
-        // TODO(#2579): implement web-storage for blueprints as well
-        if let Some(hub) = &mut self.store_hub {
-            if self.state.app_options.blueprint_gc {
-                hub.gc_blueprints(&self.state.blueprint_undo_state);
-            }
-
-            if let Err(err) = hub.save_app_blueprints() {
-                re_log::error!("Saving blueprints failed: {err}");
-            }
-        } else {
-            re_log::error!("Could not save blueprints: the store hub is not available");
-        }
+    pub(crate) fn render_job_mut(&mut self) -> Option<&mut crate::render_job::RenderJob> {
+        self.render_job.as_mut()
     }
 
-    fn update(&mut self, egui_ctx: &egui::Context, frame: &mut eframe::Frame) {
-        if let Some(seconds) = frame.info().cpu_usage {
-            self.frame_time_history
-                .add(egui_ctx.input(|i| i.time), seconds);
-        }
-
+    /// Runs a single frame of the viewer: handles incoming data & commands and shows the ui.
+    pub(crate) fn update_and_ui(
+        &mut self,
+        egui_ctx: &egui::Context,
+        render_state: &egui_wgpu::RenderState,
+    ) {
         #[cfg(target_arch = "wasm32")]
         if self.startup_options.enable_history {
             // Handle pressing the back/forward mouse buttons explicitly, since eframe catches those.
@@ -1884,10 +2232,7 @@ impl eframe::App for App {
         let gpu_resource_stats = {
             re_tracing::profile_scope!("gpu_resource_stats");
 
-            let egui_renderer = {
-                let render_state = frame.wgpu_render_state().unwrap();
-                &mut render_state.renderer.read()
-            };
+            let egui_renderer = &mut render_state.renderer.read();
             let render_ctx = egui_renderer
                 .callback_resources
                 .get::<re_renderer::RenderContext>()
@@ -1910,10 +2255,7 @@ impl eframe::App for App {
         self.purge_memory_if_needed(&mut store_hub);
 
         {
-            let egui_renderer = {
-                let render_state = frame.wgpu_render_state().unwrap();
-                &mut render_state.renderer.read()
-            };
+            let egui_renderer = &mut render_state.renderer.read();
             let render_ctx = egui_renderer
                 .callback_resources
                 .get::<re_renderer::RenderContext>()
@@ -1926,6 +2268,11 @@ impl eframe::App for App {
 
         self.receive_messages(&mut store_hub, egui_ctx);
 
+        #[cfg(feature = "grpc")]
+        for command in self.viewer_command_receiver.update(&self.rx, egui_ctx) {
+            self.run_viewer_command(command, &store_hub, egui_ctx);
+        }
+
         if self.app_options().blueprint_gc {
             store_hub.gc_blueprints(&self.state.blueprint_undo_state);
         }
@@ -1951,6 +2298,13 @@ impl eframe::App for App {
             }
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
+        self.template_matcher
+            .update(&self.blueprint_templates, &mut store_hub);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut template_action = None;
+
         {
             let store_context = store_hub.read_context();
 
@@ -1972,18 +2326,38 @@ impl eframe::App for App {
 
             self.ui(
                 egui_ctx,
-                frame,
+                render_state,
                 &app_blueprint,
                 &gpu_resource_stats,
                 store_context.as_ref(),
                 store_stats.as_ref(),
             );
 
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                template_action = self.blueprint_templates_modal.ui(
+                    egui_ctx,
+                    &self.blueprint_templates,
+                    store_context.as_ref(),
+                );
+            }
+
             if re_ui::CUSTOM_WINDOW_DECORATIONS {
                 // Paint the main window frame on top of everything else
                 paint_native_window_frame(egui_ctx);
             }
 
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(render_job) = &mut self.render_job {
+                let is_loading = self.rx.is_connected();
+                render_job.update(
+                    store_context.as_ref(),
+                    &mut self.state,
+                    is_loading,
+                    &self.command_sender,
+                );
+            }
+
             if let Some(cmd) = self.cmd_palette.show(egui_ctx) {
                 self.command_sender.send_ui(cmd);
             }
@@ -1992,9 +2366,28 @@ impl eframe::App for App {
 
             // Run pending commands last (so we don't have to wait for a repaint before they are run):
             self.run_pending_ui_commands(egui_ctx, &app_blueprint, store_context.as_ref());
+
+            if let Some(store_context) = &store_context {
+                let store_id = store_context.recording.store_id();
+                let time_ctrl = self
+                    .state
+                    .recording_config(&store_id)
+                    .map(|config| config.time_ctrl.read());
+                self.viewer_event_sender.update(
+                    &self.rx,
+                    &store_id,
+                    &self.state.selection_state,
+                    time_ctrl.as_deref(),
+                );
+            }
         }
         self.run_pending_system_commands(&mut store_hub, egui_ctx);
 
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(action) = template_action {
+            self.run_blueprint_template_action(action, &mut store_hub);
+        }
+
         // Return the `StoreHub` to the Viewer so we have it on the next frame
         self.store_hub = Some(store_hub);
 
@@ -2022,6 +2415,110 @@ impl eframe::App for App {
             }
         }
     }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn blueprint_loader() -> BlueprintPersistence {
+    // TODO(#2579): implement persistence for web
+    BlueprintPersistence {
+        loader: None,
+        saver: None,
+        validator: Some(Box::new(crate::blueprint::is_valid_blueprint)),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn blueprint_loader() -> BlueprintPersistence {
+    use re_entity_db::StoreBundle;
+
+    fn load_blueprint_from_disk(app_id: &ApplicationId) -> anyhow::Result<Option<StoreBundle>> {
+        let blueprint_path = crate::saving::default_blueprint_path(app_id)?;
+        if !blueprint_path.exists() {
+            return Ok(None);
+        }
+
+        re_log::debug!("Trying to load blueprint for {app_id} from {blueprint_path:?}");
+
+        if let Some(bundle) = crate::loading::load_blueprint_file(&blueprint_path) {
+            for store in bundle.entity_dbs() {
+                if store.store_kind() == StoreKind::Blueprint
+                    && !crate::blueprint::is_valid_blueprint(store)
+                {
+                    re_log::warn_once!("Blueprint for {app_id} at {blueprint_path:?} appears invalid - will ignore. This is expected if you have just upgraded Rerun versions.");
+                    return Ok(None);
+                }
+            }
+            Ok(Some(bundle))
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_blueprint_to_disk(app_id: &ApplicationId, blueprint: &EntityDb) -> anyhow::Result<()> {
+        let blueprint_path = crate::saving::default_blueprint_path(app_id)?;
+
+        let messages = blueprint.to_messages(None);
+        let rrd_version = blueprint
+            .store_info()
+            .and_then(|info| info.store_version)
+            .unwrap_or(re_build_info::CrateVersion::LOCAL);
+
+        // TODO(jleibs): Should we push this into a background thread? Blueprints should generally
+        // be small & fast to save, but maybe not once we start adding big pieces of user data?
+        crate::saving::encode_to_file(rrd_version, &blueprint_path, messages)?;
+
+        re_log::debug!("Saved blueprint for {app_id} to {blueprint_path:?}");
+
+        Ok(())
+    }
+
+    BlueprintPersistence {
+        loader: Some(Box::new(load_blueprint_from_disk)),
+        saver: Some(Box::new(save_blueprint_to_disk)),
+        validator: Some(Box::new(crate::blueprint::is_valid_blueprint)),
+    }
+}
+
+impl eframe::App for App {
+    fn clear_color(&self, _visuals: &egui::Visuals) -> [f32; 4] {
+        [0.0; 4] // transparent so we can get rounded corners when doing [`re_ui::CUSTOM_WINDOW_DECORATIONS`]
+    }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        if !self.startup_options.persist_state {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        // Save the app state
+        eframe::set_value(storage, eframe::APP_KEY, &self.state);
+
+        // Save the blueprints
+        // TODO(#2579): implement web-storage for blueprints as well
+        if let Some(hub) = &mut self.store_hub {
+            if self.state.app_options.blueprint_gc {
+                hub.gc_blueprints(&self.state.blueprint_undo_state);
+            }
+
+            if let Err(err) = hub.save_app_blueprints() {
+                re_log::error!("Saving blueprints failed: {err}");
+            }
+        } else {
+            re_log::error!("Could not save blueprints: the store hub is not available");
+        }
+    }
+
+    fn update(&mut self, egui_ctx: &egui::Context, frame: &mut eframe::Frame) {
+        if let Some(seconds) = frame.info().cpu_usage {
+            self.frame_time_history
+                .add(egui_ctx.input(|i| i.time), seconds);
+        }
+
+        let render_state = frame.wgpu_render_state().unwrap();
+        self.update_and_ui(egui_ctx, render_state);
+    }
 
     #[cfg(target_arch = "wasm32")]
     fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
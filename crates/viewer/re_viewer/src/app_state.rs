@@ -9,7 +9,7 @@ use re_types::blueprint::components::PanelState;
 use re_ui::{ContextExt as _, DesignTokens};
 use re_viewer_context::{
     AppOptions, ApplicationSelectionState, BlueprintUndoState, CommandSender, ComponentUiRegistry,
-    DragAndDropManager, PlayState, RecordingConfig, StoreContext, StoreHub,
+    DragAndDropManager, PlayState, RecordingConfig, RecordingUndoState, StoreContext, StoreHub,
     SystemCommandSender as _, ViewClassExt as _, ViewClassRegistry, ViewStates, ViewerContext,
 };
 use re_viewport::ViewportUi;
@@ -36,6 +36,10 @@ pub struct AppState {
     /// Maps blueprint id to the current undo state for it.
     pub blueprint_undo_state: HashMap<StoreId, BlueprintUndoState>,
 
+    /// Maps recording id to the undo state of the edits made to it from within the viewer.
+    #[serde(skip)]
+    pub recording_undo_state: HashMap<StoreId, RecordingUndoState>,
+
     selection_panel: re_selection_panel::SelectionPanel,
     time_panel: re_time_panel::TimePanel,
     blueprint_time_panel: re_time_panel::TimePanel,
@@ -83,6 +87,7 @@ impl Default for AppState {
             app_options: Default::default(),
             recording_configs: Default::default(),
             blueprint_undo_state: Default::default(),
+            recording_undo_state: Default::default(),
             blueprint_cfg: Default::default(),
             selection_panel: Default::default(),
             time_panel: Default::default(),
@@ -161,6 +166,7 @@ impl AppState {
             app_options,
             recording_configs,
             blueprint_undo_state,
+            recording_undo_state,
             blueprint_cfg,
             selection_panel,
             time_panel,
@@ -182,6 +188,10 @@ impl AppState {
             .entry(store_context.blueprint.store_id().clone())
             .or_default()
             .update(ui.ctx(), store_context.blueprint);
+        if let Some(undo_state) = recording_undo_state.get_mut(&store_context.recording.store_id())
+        {
+            undo_state.update(ui.ctx());
+        }
 
         let viewport_blueprint =
             ViewportBlueprint::try_from_db(store_context.blueprint, &blueprint_query);
@@ -328,7 +338,7 @@ impl AppState {
                         rec_cfg.time_ctrl.read().timeline(),
                         view_class_registry,
                         query_result,
-                        view_states,
+                        view_states.get_mut_or_create(view.id, view.class(view_class_registry)),
                     );
                 }
             }
@@ -547,7 +557,6 @@ impl AppState {
         *focused_item = None;
     }
 
-    #[cfg(target_arch = "wasm32")] // Only used in Wasm
     pub fn recording_config(&self, rec_id: &StoreId) -> Option<&RecordingConfig> {
         self.recording_configs.get(rec_id)
     }
@@ -564,6 +573,9 @@ impl AppState {
 
         self.blueprint_undo_state
             .retain(|store_id, _| store_hub.store_bundle().contains(store_id));
+
+        self.recording_undo_state
+            .retain(|store_id, _| store_hub.store_bundle().contains(store_id));
     }
 
     /// Returns the blueprint query that should be used for generating the current
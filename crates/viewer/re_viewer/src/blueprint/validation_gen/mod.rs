@@ -6,11 +6,15 @@ pub use re_types::blueprint::components::ApplyLatestAt;
 pub use re_types::blueprint::components::AutoLayout;
 pub use re_types::blueprint::components::AutoViews;
 pub use re_types::blueprint::components::BackgroundKind;
+pub use re_types::blueprint::components::BookmarkTime;
 pub use re_types::blueprint::components::ColumnShare;
+pub use re_types::blueprint::components::ComparedRecording;
 pub use re_types::blueprint::components::ComponentColumnSelector;
 pub use re_types::blueprint::components::ContainerKind;
 pub use re_types::blueprint::components::Corner2D;
 pub use re_types::blueprint::components::Enabled;
+pub use re_types::blueprint::components::Eye3DKind;
+pub use re_types::blueprint::components::FieldOfView;
 pub use re_types::blueprint::components::FilterByRange;
 pub use re_types::blueprint::components::FilterIsNotNull;
 pub use re_types::blueprint::components::ForceDistance;
@@ -21,7 +25,9 @@ pub use re_types::blueprint::components::GridSpacing;
 pub use re_types::blueprint::components::IncludedContent;
 pub use re_types::blueprint::components::Interactive;
 pub use re_types::blueprint::components::LockRangeDuringZoom;
+pub use re_types::blueprint::components::LookTarget;
 pub use re_types::blueprint::components::MapProvider;
+pub use re_types::blueprint::components::MeasurementKind;
 pub use re_types::blueprint::components::NearClipPlane;
 pub use re_types::blueprint::components::PanelState;
 pub use re_types::blueprint::components::QueryExpression;
@@ -29,7 +35,9 @@ pub use re_types::blueprint::components::RootContainer;
 pub use re_types::blueprint::components::RowShare;
 pub use re_types::blueprint::components::SelectedColumns;
 pub use re_types::blueprint::components::TensorDimensionIndexSlider;
+pub use re_types::blueprint::components::TimeOffset;
 pub use re_types::blueprint::components::TimelineName;
+pub use re_types::blueprint::components::UseRegex;
 pub use re_types::blueprint::components::ViewClass;
 pub use re_types::blueprint::components::ViewFit;
 pub use re_types::blueprint::components::ViewMaximized;
@@ -50,11 +58,15 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<AutoLayout>(blueprint)
         && validate_component::<AutoViews>(blueprint)
         && validate_component::<BackgroundKind>(blueprint)
+        && validate_component::<BookmarkTime>(blueprint)
         && validate_component::<ColumnShare>(blueprint)
+        && validate_component::<ComparedRecording>(blueprint)
         && validate_component::<ComponentColumnSelector>(blueprint)
         && validate_component::<ContainerKind>(blueprint)
         && validate_component::<Corner2D>(blueprint)
         && validate_component::<Enabled>(blueprint)
+        && validate_component::<Eye3DKind>(blueprint)
+        && validate_component::<FieldOfView>(blueprint)
         && validate_component::<FilterByRange>(blueprint)
         && validate_component::<FilterIsNotNull>(blueprint)
         && validate_component::<ForceDistance>(blueprint)
@@ -65,7 +77,9 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<IncludedContent>(blueprint)
         && validate_component::<Interactive>(blueprint)
         && validate_component::<LockRangeDuringZoom>(blueprint)
+        && validate_component::<LookTarget>(blueprint)
         && validate_component::<MapProvider>(blueprint)
+        && validate_component::<MeasurementKind>(blueprint)
         && validate_component::<NearClipPlane>(blueprint)
         && validate_component::<PanelState>(blueprint)
         && validate_component::<QueryExpression>(blueprint)
@@ -73,7 +87,9 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<RowShare>(blueprint)
         && validate_component::<SelectedColumns>(blueprint)
         && validate_component::<TensorDimensionIndexSlider>(blueprint)
+        && validate_component::<TimeOffset>(blueprint)
         && validate_component::<TimelineName>(blueprint)
+        && validate_component::<UseRegex>(blueprint)
         && validate_component::<ViewClass>(blueprint)
         && validate_component::<ViewFit>(blueprint)
         && validate_component::<ViewMaximized>(blueprint)
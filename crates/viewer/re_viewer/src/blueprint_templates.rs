@@ -0,0 +1,895 @@
+//! Blueprint templates: viewport layouts that can be applied to any recording.
+//!
+//! A template is a `.rbl` file in the template directory, see [`crate::saving::blueprint_templates_dir`].
+//! The view origins and view contents of a template may refer to variables, e.g. `$robot/camera`,
+//! which are replaced by entity paths of the recording when the template is applied,
+//! using the same substitution as `$origin` in view contents (see [`EntityPathSubs`]).
+//!
+//! A variable always stands for the start of an entity path, so its value can be inferred
+//! from the entities of a recording: for `$robot/camera`, any entity ending in `/camera` is a candidate.
+//!
+//! When a recording is opened, the first template that matches it is applied automatically:
+//! either a template saved for the recording's [`ApplicationId`],
+//! or any template whose view origins all exist in the recording.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use ahash::{HashMap, HashSet};
+use anyhow::Context as _;
+
+use re_chunk::{
+    external::arrow::{
+        array::{Array as _, AsArray as _, ListArray, StringArray},
+        datatypes::DataType,
+    },
+    Chunk, RowId,
+};
+use re_chunk_store::LatestAtQuery;
+use re_entity_db::EntityDb;
+use re_log_types::{ApplicationId, EntityPath, EntityPathSubs, SetStoreInfo, StoreId, StoreKind};
+use re_types::blueprint::components::{QueryExpression, ViewOrigin};
+use re_types_core::Component as _;
+use re_viewer_context::{blueprint_timeline, StoreHub};
+use re_viewport_blueprint::ViewportBlueprint;
+
+/// `$origin` always refers to the origin of the view itself, and can't be used as a template variable.
+const ORIGIN_VARIABLE: &str = "origin";
+
+/// How often to retry matching a recording that didn't match any template yet.
+///
+/// Data may still be streaming in, so the entities a template needs can show up later.
+const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// A blueprint that can be applied to different recordings.
+pub struct BlueprintTemplate {
+    /// The name shown to users, i.e. the file name without extension.
+    pub name: String,
+
+    pub path: PathBuf,
+
+    /// The application the template was saved from.
+    pub app_id: Option<ApplicationId>,
+
+    /// All variables the template uses, except for `$origin`.
+    pub variables: BTreeSet<String>,
+
+    /// The origins of all views, before substitution.
+    origins: Vec<String>,
+
+    blueprint: EntityDb,
+}
+
+impl BlueprintTemplate {
+    fn new(path: PathBuf, blueprint: EntityDb) -> Self {
+        let name = path.file_stem().map_or_else(
+            || path.display().to_string(),
+            |stem| stem.to_string_lossy().into_owned(),
+        );
+
+        let query = LatestAtQuery::latest(blueprint_timeline());
+        let viewport = ViewportBlueprint::try_from_db(&blueprint, &query);
+        let origins = viewport
+            .views
+            .keys()
+            .filter_map(|view_id| {
+                blueprint.latest_at_component::<ViewOrigin>(&view_id.as_entity_path(), &query)
+            })
+            .map(|(_, origin)| origin.0 .0.as_str().to_owned())
+            .collect();
+
+        let mut variables = BTreeSet::new();
+        for chunk in blueprint.storage_engine().store().iter_chunks() {
+            for_each_template_string(chunk, |text| {
+                variables.extend(
+                    EntityPathSubs::variable_names(text)
+                        .filter(|name| *name != ORIGIN_VARIABLE)
+                        .map(ToOwned::to_owned),
+                );
+            });
+        }
+
+        Self {
+            name,
+            path,
+            app_id: blueprint.app_id().cloned(),
+            variables,
+            origins,
+            blueprint,
+        }
+    }
+
+    /// Infers the values of the template's variables from the entities in `recording`.
+    ///
+    /// Variables that can't be inferred are missing from the result.
+    /// If there are several candidates for a variable, the first in entity path order is picked.
+    pub fn infer_variables(&self, recording: &EntityDb) -> BTreeMap<String, String> {
+        re_tracing::profile_function!();
+
+        let mut candidates: BTreeMap<&str, BTreeSet<EntityPath>> = BTreeMap::new();
+
+        for origin in &self.origins {
+            let Some((variable, suffix)) = split_variable_prefix(origin) else {
+                continue;
+            };
+            if variable == ORIGIN_VARIABLE {
+                continue;
+            }
+            let suffix = EntityPath::parse_forgiving(suffix);
+            if suffix.is_root() {
+                // `$robot` on its own matches any entity, so it doesn't tell us anything.
+                continue;
+            }
+
+            let mut origin_candidates = BTreeSet::new();
+            recording.tree().visit_children_recursively(|entity_path| {
+                let parts = entity_path.as_slice();
+                if suffix.len() < parts.len() && parts.ends_with(suffix.as_slice()) {
+                    origin_candidates.insert(EntityPath::new(
+                        parts[..parts.len() - suffix.len()].to_vec(),
+                    ));
+                }
+            });
+
+            candidates
+                .entry(variable)
+                .and_modify(|candidates| candidates.retain(|path| origin_candidates.contains(path)))
+                .or_insert(origin_candidates);
+        }
+
+        candidates
+            .into_iter()
+            .filter_map(|(variable, candidates)| {
+                let first = candidates.into_iter().next()?;
+                Some((variable.to_owned(), first.to_string()))
+            })
+            .collect()
+    }
+
+    /// Does `recording` contain the origins of all views, with the given variable values?
+    ///
+    /// Templates whose views all have the root as origin don't match anything,
+    /// since they don't say anything about the shape of the entity tree.
+    fn matches_shape(&self, recording: &EntityDb, variables: &EntityPathSubs) -> bool {
+        let origins = self
+            .origins
+            .iter()
+            .map(|origin| EntityPath::parse_forgiving(&variables.substitute(origin)))
+            .collect::<Vec<_>>();
+
+        origins.iter().any(|origin| !origin.is_root())
+            && origins
+                .iter()
+                .all(|origin| recording.tree().subtree(origin).is_some())
+    }
+
+    /// Creates a blueprint for `app_id` from the template, replacing the variables with their values.
+    pub fn instantiate(
+        &self,
+        app_id: &ApplicationId,
+        variables: &EntityPathSubs,
+    ) -> anyhow::Result<EntityDb> {
+        re_tracing::profile_function!();
+
+        if let Some(variable) = self
+            .variables
+            .iter()
+            .find(|variable| variables.get(variable).is_none())
+        {
+            anyhow::bail!("Missing a value for ${variable}");
+        }
+
+        let store_id = StoreId::random(StoreKind::Blueprint);
+        let mut blueprint = EntityDb::new(store_id.clone());
+
+        let mut store_info = self
+            .blueprint
+            .store_info()
+            .context("Template has no store info")?
+            .clone();
+        store_info.store_id = store_id;
+        store_info.application_id = app_id.clone();
+        store_info.cloned_from = None;
+        blueprint.set_store_info(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: store_info,
+        });
+
+        for chunk in self.blueprint.storage_engine().store().iter_chunks() {
+            let chunk = map_template_strings(chunk, |text| variables.substitute(text))?;
+            blueprint.add_chunk(&Arc::new(chunk))?;
+        }
+
+        Ok(blueprint)
+    }
+}
+
+/// Turns a blueprint into a template, by replacing the given entity paths with variables.
+///
+/// Each variable replaces the entity path it stands for at the start of view origins and
+/// view content rules, e.g. with `robot = /robots/arm_a` the origin `/robots/arm_a/camera`
+/// becomes `$robot/camera`.
+pub fn create_template(
+    blueprint: &EntityDb,
+    variables: &BTreeMap<String, EntityPath>,
+) -> anyhow::Result<EntityDb> {
+    re_tracing::profile_function!();
+
+    for (name, value) in variables {
+        anyhow::ensure!(
+            is_valid_variable_name(name),
+            "Invalid variable name {name:?}: use letters, digits and underscores, and not \"origin\""
+        );
+        anyhow::ensure!(!value.is_root(), "${name} can't stand for the root entity");
+    }
+
+    // Longest paths first, so that nested variables win over their parents.
+    let mut replacements = variables
+        .iter()
+        .map(|(name, value)| (format!("${name}"), value.to_string()))
+        .collect::<Vec<_>>();
+    replacements.sort_by_key(|(_, value)| std::cmp::Reverse(value.len()));
+
+    let store_id = StoreId::random(StoreKind::Blueprint);
+    let mut template = EntityDb::new(store_id.clone());
+
+    if let Some(store_info) = blueprint.store_info() {
+        let mut store_info = store_info.clone();
+        store_info.store_id = store_id;
+        store_info.cloned_from = None;
+        template.set_store_info(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: store_info,
+        });
+    }
+
+    for chunk in blueprint.storage_engine().store().iter_chunks() {
+        let chunk = map_template_strings(chunk, |text| replace_path_prefixes(text, &replacements))?;
+        template.add_chunk(&Arc::new(chunk))?;
+    }
+
+    Ok(template)
+}
+
+/// Variable names consist of ASCII letters, digits and underscores, see [`EntityPathSubs`].
+pub fn is_valid_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != ORIGIN_VARIABLE
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Splits `$name/rest` into `name` and `/rest`.
+fn split_variable_prefix(origin: &str) -> Option<(&str, &str)> {
+    let rest = origin.strip_prefix('$')?;
+    if let Some(braced) = rest.strip_prefix('{') {
+        let end = braced.find('}')?;
+        Some((&braced[..end], &braced[end + 1..]))
+    } else {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        (end > 0).then(|| (&rest[..end], &rest[end..]))
+    }
+}
+
+/// Replaces entity paths at the start of every whitespace-separated word of `text`,
+/// ignoring the `+`/`-` of view content rules.
+fn replace_path_prefixes(text: &str, replacements: &[(String, String)]) -> String {
+    let mut result = String::with_capacity(text.len());
+    for word in text.split_inclusive(char::is_whitespace) {
+        let rule_start = word.len() - word.trim_start_matches(['+', '-']).len();
+        let (effect, path) = word.split_at(rule_start);
+        result.push_str(effect);
+
+        let replaced = replacements.iter().find_map(|(variable, value)| {
+            let rest = path.strip_prefix(value.as_str())?;
+            (rest.is_empty() || rest.starts_with(|c: char| c == '/' || c.is_whitespace()))
+                .then(|| format!("{variable}{rest}"))
+        });
+        result.push_str(replaced.as_deref().unwrap_or(path));
+    }
+    result
+}
+
+/// Calls `f` for all view origins and view content rules in a chunk.
+fn for_each_template_string(chunk: &Chunk, mut f: impl FnMut(&str)) {
+    for (descr, list_array) in chunk.components().iter_flattened() {
+        if !is_template_component(descr) {
+            continue;
+        }
+        if let Some(strings) = list_array.values().as_string_opt::<i32>() {
+            strings.iter().flatten().for_each(&mut f);
+        }
+    }
+}
+
+/// Applies `f` to all view origins and view content rules in a chunk.
+fn map_template_strings(chunk: &Chunk, f: impl Fn(&str) -> String) -> re_chunk::ChunkResult<Chunk> {
+    let mut components = chunk.components().clone();
+
+    for (descr, list_array) in components
+        .values_mut()
+        .flat_map(|per_desc| per_desc.iter_mut())
+    {
+        if !is_template_component(descr) {
+            continue;
+        }
+        let DataType::List(field) = list_array.data_type() else {
+            continue;
+        };
+        let Some(strings) = list_array.values().as_string_opt::<i32>() else {
+            continue;
+        };
+
+        let mapped: StringArray = strings.iter().map(|text| text.map(&f)).collect();
+        *list_array = ListArray::new(
+            field.clone(),
+            list_array.offsets().clone(),
+            Arc::new(mapped),
+            list_array.nulls().cloned(),
+        );
+    }
+
+    Chunk::new(
+        chunk.id(),
+        chunk.entity_path().clone(),
+        None,
+        chunk.row_ids_array().clone(),
+        chunk.timelines().clone(),
+        components,
+    )
+}
+
+fn is_template_component(descr: &re_types_core::ComponentDescriptor) -> bool {
+    descr.component_name == ViewOrigin::name() || descr.component_name == QueryExpression::name()
+}
+
+// ----------------------------------------------------------------------------
+
+/// All known blueprint templates.
+#[derive(Default)]
+pub struct BlueprintTemplates {
+    templates: Vec<BlueprintTemplate>,
+}
+
+impl BlueprintTemplates {
+    /// Loads all templates from the template directory.
+    pub fn load() -> Self {
+        match crate::saving::blueprint_templates_dir() {
+            Ok(dir) => Self::load_from_dir(&dir),
+            Err(err) => {
+                re_log::warn!("Failed to load blueprint templates: {err}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Loads all templates (`*.rbl` files) in a directory.
+    ///
+    /// Templates that fail to load are skipped.
+    fn load_from_dir(dir: &Path) -> Self {
+        re_tracing::profile_function!();
+
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Self::default();
+        };
+
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rbl"))
+            .collect();
+        paths.sort();
+
+        let templates = paths
+            .into_iter()
+            .filter_map(|path| {
+                let mut bundle = crate::loading::load_blueprint_file(&path)?;
+                let blueprint = bundle.drain_entity_dbs().find(|db| {
+                    db.store_kind() == StoreKind::Blueprint
+                        && crate::blueprint::is_valid_blueprint(db)
+                });
+                if blueprint.is_none() {
+                    re_log::warn!("Ignoring invalid blueprint template {path:?}");
+                }
+                Some(BlueprintTemplate::new(path, blueprint?))
+            })
+            .collect();
+
+        Self { templates }
+    }
+
+    /// Saves a template to the template directory, replacing any template with the same name.
+    pub fn save(&mut self, name: &str, template: EntityDb) -> anyhow::Result<()> {
+        let path = crate::saving::blueprint_templates_dir()?.join(format!(
+            "{}.rbl",
+            re_viewer_context::santitize_file_name(name)
+        ));
+
+        let rrd_version = template
+            .store_info()
+            .and_then(|info| info.store_version)
+            .unwrap_or(re_build_info::CrateVersion::LOCAL);
+        crate::saving::encode_to_file(rrd_version, &path, template.to_messages(None))?;
+        re_log::info!("Saved blueprint template to {path:?}");
+
+        let template = BlueprintTemplate::new(path, template);
+        self.templates
+            .retain(|existing| existing.path != template.path);
+        self.templates.push(template);
+        self.templates.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &BlueprintTemplate> {
+        self.templates.iter()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&BlueprintTemplate> {
+        self.templates.get(index)
+    }
+
+    /// Finds the template to apply to a recording, and the values of its variables.
+    ///
+    /// Templates saved for the recording's application are preferred,
+    /// and only need all their variables to be inferred.
+    /// Other templates also need all their view origins to exist in the recording.
+    fn find_match(&self, recording: &EntityDb) -> Option<(&BlueprintTemplate, EntityPathSubs)> {
+        re_tracing::profile_function!();
+
+        let app_id = recording.app_id()?;
+        let infer = |template: &BlueprintTemplate| {
+            let variables = template.infer_variables(recording);
+            (variables.len() == template.variables.len())
+                .then(|| variables.into_iter().collect::<EntityPathSubs>())
+        };
+
+        let same_app = self
+            .templates
+            .iter()
+            .filter(|template| template.app_id.as_ref() == Some(app_id))
+            .find_map(|template| Some((template, infer(template)?)));
+
+        same_app.or_else(|| {
+            self.templates
+                .iter()
+                .filter(|template| template.app_id.as_ref() != Some(app_id))
+                .find_map(|template| {
+                    let variables = infer(template)?;
+                    template
+                        .matches_shape(recording, &variables)
+                        .then_some((template, variables))
+                })
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+enum MatchState {
+    /// Retry at this time, if no template matched yet.
+    Pending(web_time::Instant),
+
+    /// A template was applied, or the recording has a blueprint that we shouldn't replace.
+    Done,
+}
+
+/// Applies matching templates to recordings when they are opened.
+#[derive(Default)]
+pub struct TemplateMatcher {
+    recordings: HashMap<StoreId, MatchState>,
+
+    /// The blueprints we created from templates, which may be replaced by another template.
+    template_blueprints: HashSet<StoreId>,
+}
+
+impl TemplateMatcher {
+    /// Don't apply templates to this recording anymore, e.g. because the user picked one.
+    pub fn mark_done(&mut self, recording_id: StoreId) {
+        self.recordings.insert(recording_id, MatchState::Done);
+    }
+
+    /// Applies a template to the active recording.
+    ///
+    /// The template becomes the default blueprint of the recording's application,
+    /// so that users can go back to it with "Reset to default blueprint".
+    pub fn apply(
+        &mut self,
+        template: &BlueprintTemplate,
+        variables: &EntityPathSubs,
+        store_hub: &mut StoreHub,
+    ) -> anyhow::Result<()> {
+        let recording = store_hub
+            .active_recording()
+            .context("No active recording")?;
+        let app_id = recording
+            .app_id()
+            .context("The recording has no application id")?
+            .clone();
+        self.mark_done(recording.store_id().clone());
+
+        let blueprint = template.instantiate(&app_id, variables)?;
+        let blueprint_id = blueprint.store_id().clone();
+        store_hub.insert_entity_db(blueprint);
+        store_hub.set_default_blueprint_for_app(&app_id, &blueprint_id)?;
+        self.template_blueprints.insert(blueprint_id);
+
+        // The default blueprint will be made active at the start of the next frame.
+        store_hub.clear_active_blueprint();
+
+        Ok(())
+    }
+
+    /// Tries to apply a template to the active recording, if it doesn't have a blueprint yet.
+    pub fn update(&mut self, templates: &BlueprintTemplates, store_hub: &mut StoreHub) {
+        self.recordings
+            .retain(|store_id, _| store_hub.store_bundle().contains(store_id));
+        self.template_blueprints
+            .retain(|store_id| store_hub.store_bundle().contains(store_id));
+
+        if templates.is_empty() {
+            return;
+        }
+        let Some(recording) = store_hub.active_recording() else {
+            return;
+        };
+        let Some(app_id) = recording.app_id().cloned() else {
+            return;
+        };
+        let recording_id = recording.store_id().clone();
+
+        let now = web_time::Instant::now();
+        match self.recordings.get(&recording_id) {
+            Some(MatchState::Done) => return,
+            Some(MatchState::Pending(retry_at)) if now < *retry_at => return,
+            _ => {}
+        }
+
+        if !self.may_replace_blueprint(store_hub, &app_id) {
+            self.mark_done(recording_id);
+            return;
+        }
+
+        let Some(recording) = store_hub.active_recording() else {
+            return;
+        };
+        let Some((template, variables)) = templates.find_match(recording) else {
+            self.recordings
+                .insert(recording_id, MatchState::Pending(now + RETRY_INTERVAL));
+            return;
+        };
+
+        re_log::info!(
+            "Applying blueprint template {:?} to {app_id}",
+            template.name
+        );
+        if let Err(err) = self.apply(template, &variables, store_hub) {
+            re_log::warn!(
+                "Failed to apply blueprint template {:?}: {err}",
+                template.name
+            );
+        }
+        self.mark_done(recording_id);
+    }
+
+    /// Templates only replace blueprints that were created by the heuristics or from another template,
+    /// never ones sent by the SDK or edited by the user.
+    ///
+    /// The active blueprint is a clone of the template's blueprint, which the user edited
+    /// if it has any rows that the template's blueprint doesn't have.
+    fn may_replace_blueprint(&self, store_hub: &StoreHub, app_id: &ApplicationId) -> bool {
+        let is_from_template =
+            |blueprint_id: &StoreId| self.template_blueprints.contains(blueprint_id);
+
+        if let Some(default_id) = store_hub.default_blueprint_id_for_app(app_id) {
+            if !is_from_template(default_id) {
+                return false;
+            }
+        }
+
+        let Some(active) = store_hub.active_blueprint_for_app(app_id) else {
+            return true;
+        };
+        if let Some(template_id) = active.cloned_from().filter(|id| is_from_template(id)) {
+            return store_hub
+                .store_bundle()
+                .get(template_id)
+                .is_some_and(|template| template.latest_row_id() == active.latest_row_id());
+        }
+        let query = LatestAtQuery::latest(blueprint_timeline());
+        ViewportBlueprint::try_from_db(active, &query).auto_views()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::TimePoint;
+    use re_log_types::{StoreInfo, StoreSource, Time};
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    fn new_db(kind: StoreKind, app_id: &str) -> EntityDb {
+        let store_id = StoreId::random(kind);
+        let mut db = EntityDb::new(store_id.clone());
+        db.set_store_info(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                application_id: app_id.into(),
+                store_id,
+                cloned_from: None,
+                is_official_example: false,
+                started: Time::now(),
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        });
+        db
+    }
+
+    fn new_recording(app_id: &str, entity_paths: &[&str]) -> EntityDb {
+        let mut recording = new_db(StoreKind::Recording, app_id);
+        for entity_path in entity_paths {
+            let chunk = Chunk::builder((*entity_path).into())
+                .with_archetype(
+                    RowId::new(),
+                    TimePoint::default(),
+                    &Points3D::new([(0.0, 0.0, 0.0)]),
+                )
+                .build()
+                .unwrap();
+            recording.add_chunk(&Arc::new(chunk)).unwrap();
+        }
+        recording
+    }
+
+    fn new_template(app_id: &str, origins: &[&str]) -> BlueprintTemplate {
+        let blueprint = new_db(StoreKind::Blueprint, app_id);
+        BlueprintTemplate {
+            name: "template".to_owned(),
+            path: PathBuf::from("template.rbl"),
+            app_id: blueprint.app_id().cloned(),
+            variables: origins
+                .iter()
+                .flat_map(|origin| EntityPathSubs::variable_names(origin))
+                .filter(|name| *name != ORIGIN_VARIABLE)
+                .map(ToOwned::to_owned)
+                .collect(),
+            origins: origins.iter().map(|origin| (*origin).to_owned()).collect(),
+            blueprint,
+        }
+    }
+
+    fn template_strings(blueprint: &EntityDb) -> Vec<String> {
+        let mut strings = Vec::new();
+        for chunk in blueprint.storage_engine().store().iter_chunks() {
+            for_each_template_string(chunk, |text| strings.push(text.to_owned()));
+        }
+        strings.sort();
+        strings
+    }
+
+    #[test]
+    fn variable_prefix() {
+        assert_eq!(
+            split_variable_prefix("$robot/camera"),
+            Some(("robot", "/camera"))
+        );
+        assert_eq!(
+            split_variable_prefix("${robot}/camera"),
+            Some(("robot", "/camera"))
+        );
+        assert_eq!(split_variable_prefix("$robot"), Some(("robot", "")));
+        assert_eq!(split_variable_prefix("$/camera"), None);
+        assert_eq!(split_variable_prefix("${robot/camera"), None);
+        assert_eq!(split_variable_prefix("/robots/camera"), None);
+    }
+
+    #[test]
+    fn variable_names() {
+        assert!(is_valid_variable_name("robot"));
+        assert!(is_valid_variable_name("arm_2"));
+        assert!(!is_valid_variable_name(""));
+        assert!(!is_valid_variable_name("origin"));
+        assert!(!is_valid_variable_name("left-arm"));
+        assert!(!is_valid_variable_name("$robot"));
+    }
+
+    #[test]
+    fn path_prefixes() {
+        let replacements = [
+            ("$arm".to_owned(), "/robots/arm_a".to_owned()),
+            ("$robot".to_owned(), "/robots".to_owned()),
+        ];
+
+        assert_eq!(
+            replace_path_prefixes("/robots/arm_a/camera", &replacements),
+            "$arm/camera"
+        );
+        assert_eq!(
+            replace_path_prefixes(
+                "+ $origin/**\n+ /robots/arm_a/**\n- /robots/arm_ab\n+/robots",
+                &replacements
+            ),
+            "+ $origin/**\n+ $arm/**\n- $robot/arm_ab\n+$robot"
+        );
+        assert_eq!(
+            replace_path_prefixes("/world/robots/arm_a", &replacements),
+            "/world/robots/arm_a"
+        );
+    }
+
+    #[test]
+    fn infer_variables_from_origins() {
+        let recording = new_recording(
+            "app",
+            &[
+                "/robots/arm_a/camera",
+                "/robots/arm_a/lidar",
+                "/robots/arm_b/camera",
+                "/world/points",
+            ],
+        );
+
+        // Only `/robots/arm_a` has both a camera and a lidar.
+        let template = new_template("app", &["$robot/camera", "${robot}/lidar", "$origin", "/"]);
+        assert_eq!(
+            template.infer_variables(&recording),
+            BTreeMap::from([("robot".to_owned(), "/robots/arm_a".to_owned())])
+        );
+
+        // With several candidates, the first in entity path order is picked.
+        let template = new_template("app", &["$robot/camera"]);
+        assert_eq!(
+            template.infer_variables(&recording),
+            BTreeMap::from([("robot".to_owned(), "/robots/arm_a".to_owned())])
+        );
+
+        // A variable without a suffix could be anything, and one without candidates can't be bound.
+        let template = new_template("app", &["$robot", "$arm/gripper"]);
+        assert!(template.infer_variables(&recording).is_empty());
+    }
+
+    #[test]
+    fn match_templates() {
+        let templates = BlueprintTemplates {
+            templates: vec![
+                new_template("other_app", &["$robot/camera", "/world/points"]),
+                new_template("app", &["$robot/camera", "/world/missing"]),
+                new_template("app", &["$robot/gripper"]),
+            ],
+        };
+
+        // Templates of the same application don't need their origins to exist.
+        let recording_of_app = new_recording("app", &["/robots/arm_a/camera"]);
+        let (template, variables) = templates.find_match(&recording_of_app).unwrap();
+        assert_eq!(template.origins, ["$robot/camera", "/world/missing"]);
+        assert_eq!(variables.get("robot"), Some("/robots/arm_a"));
+
+        // Templates of other applications need all their origins to exist.
+        let recording_of_other = new_recording("new_app", &["/robots/arm_a/camera"]);
+        assert!(templates.find_match(&recording_of_other).is_none());
+
+        let recording_of_other =
+            new_recording("new_app", &["/robots/arm_a/camera", "/world/points"]);
+        let (template, variables) = templates.find_match(&recording_of_other).unwrap();
+        assert_eq!(template.app_id, Some("other_app".into()));
+        assert_eq!(variables.get("robot"), Some("/robots/arm_a"));
+
+        // Templates with only root origins don't say anything about the recording.
+        let root_only = new_template("other_app", &["/"]);
+        assert!(!root_only.matches_shape(&recording_of_other, &EntityPathSubs::default()));
+    }
+
+    #[test]
+    fn only_replace_unedited_template_blueprints() {
+        let app_id = ApplicationId::from("app");
+        let mut store_hub = StoreHub::test_hub();
+        let recording = new_recording("app", &["/robots/arm_a/camera"]);
+        let recording_id = recording.store_id().clone();
+        store_hub.insert_entity_db(recording);
+        store_hub.set_active_recording_id(recording_id);
+
+        let mut matcher = TemplateMatcher::default();
+        let template = new_template("app", &["$robot/camera"]);
+        let variables: EntityPathSubs = [("robot", "/robots/arm_a")].into_iter().collect();
+        matcher.apply(&template, &variables, &mut store_hub).unwrap();
+
+        // Like the viewer, activate a clone of the template's blueprint.
+        let template_id = store_hub
+            .default_blueprint_id_for_app(&app_id)
+            .unwrap()
+            .clone();
+        store_hub
+            .set_cloned_blueprint_active_for_app(&app_id, &template_id)
+            .unwrap();
+        assert!(matcher.may_replace_blueprint(&store_hub, &app_id));
+
+        // Once the user edits it, it's theirs.
+        let active_id = store_hub
+            .active_blueprint_id_for_app(&app_id)
+            .unwrap()
+            .clone();
+        let chunk = Chunk::builder("/view".into())
+            .with_component_batches(
+                RowId::new(),
+                TimePoint::default(),
+                [&ViewOrigin("/robots".into()) as &dyn re_types_core::ComponentBatch],
+            )
+            .build()
+            .unwrap();
+        store_hub
+            .entity_db_mut(&active_id)
+            .add_chunk(&Arc::new(chunk))
+            .unwrap();
+        assert!(!matcher.may_replace_blueprint(&store_hub, &app_id));
+    }
+
+    #[test]
+    fn create_and_instantiate() {
+        let mut blueprint = new_db(StoreKind::Blueprint, "app");
+        let view_path = EntityPath::from("/view/3f0e6b4b-7c5a-4a68-9d0e-2f4f6d8c1a2b");
+        let origin = ViewOrigin("/robots/arm_a/camera".into());
+        let contents = QueryExpression("+ /robots/arm_a/**\n- /robots/arm_ab/**".into());
+        let chunk = Chunk::builder(view_path)
+            .with_component_batches(
+                RowId::new(),
+                TimePoint::default(),
+                [
+                    &origin as &dyn re_types_core::ComponentBatch,
+                    &contents as &dyn re_types_core::ComponentBatch,
+                ],
+            )
+            .build()
+            .unwrap();
+        blueprint.add_chunk(&Arc::new(chunk)).unwrap();
+
+        assert!(create_template(
+            &blueprint,
+            &BTreeMap::from([("origin".to_owned(), "/robots".into())])
+        )
+        .is_err());
+        assert!(create_template(
+            &blueprint,
+            &BTreeMap::from([("robot".to_owned(), "/".into())])
+        )
+        .is_err());
+
+        let template = create_template(
+            &blueprint,
+            &BTreeMap::from([("robot".to_owned(), "/robots/arm_a".into())]),
+        )
+        .unwrap();
+        assert_eq!(template.app_id(), blueprint.app_id());
+        assert_eq!(
+            template_strings(&template),
+            ["$robot/camera", "+ $robot/**\n- /robots/arm_ab/**"]
+        );
+
+        let template = BlueprintTemplate::new(PathBuf::from("/templates/arm.rbl"), template);
+        assert_eq!(template.name, "arm");
+        assert_eq!(template.variables, BTreeSet::from(["robot".to_owned()]));
+
+        assert!(template
+            .instantiate(&"new_app".into(), &EntityPathSubs::default())
+            .is_err());
+
+        let variables: EntityPathSubs = [("robot".to_owned(), "/robots/arm_b".to_owned())]
+            .into_iter()
+            .collect();
+        let instance = template.instantiate(&"new_app".into(), &variables).unwrap();
+        assert_eq!(instance.app_id(), Some(&"new_app".into()));
+        assert_eq!(
+            template_strings(&instance),
+            [
+                "+ /robots/arm_b/**\n- /robots/arm_ab/**",
+                "/robots/arm_b/camera"
+            ]
+        );
+    }
+}
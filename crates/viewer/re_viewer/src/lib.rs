@@ -16,9 +16,23 @@ mod screenshotter;
 mod ui;
 mod viewer_analytics;
 
+#[cfg(feature = "grpc")]
+mod viewer_commands;
+
+mod viewer_events;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod blueprint_templates;
+
 #[cfg(not(target_arch = "wasm32"))]
 mod loading;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod offscreen;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod render_job;
+
 /// Auto-generated blueprint-related types.
 ///
 /// They all implement the [`re_types_core::Component`] trait.
@@ -29,6 +43,10 @@ pub mod blueprint;
 pub(crate) use {app_state::AppState, ui::memory_panel};
 
 pub use app::{App, StartupOptions};
+pub use viewer_events::ViewerEventSink;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use render_job::{render, RenderJobOptions, RenderTime};
 
 pub use re_capabilities::MainThreadToken;
 
@@ -199,24 +217,29 @@ pub fn customize_eframe_and_setup_renderer(
     re_tracing::profile_function!();
 
     if let Some(render_state) = &cc.wgpu_render_state {
-        use re_renderer::RenderContext;
-
-        // Put the renderer into paint callback resources, so we have access to the renderer
-        // when we need to process egui draw callbacks.
-        let paint_callback_resources = &mut render_state.renderer.write().callback_resources;
-        let render_ctx = RenderContext::new(
-            &render_state.adapter,
-            render_state.device.clone(),
-            render_state.queue.clone(),
-            render_state.target_format,
-        )?;
-        paint_callback_resources.insert(render_ctx);
+        setup_renderer(render_state)?;
     }
 
     re_ui::apply_style_and_install_loaders(&cc.egui_ctx);
     Ok(())
 }
 
+/// Put the renderer into paint callback resources, so we have access to the renderer
+/// when we need to process egui draw callbacks.
+pub(crate) fn setup_renderer(
+    render_state: &egui_wgpu::RenderState,
+) -> Result<(), re_renderer::RenderContextError> {
+    let paint_callback_resources = &mut render_state.renderer.write().callback_resources;
+    let render_ctx = re_renderer::RenderContext::new(
+        &render_state.adapter,
+        render_state.device.clone(),
+        render_state.queue.clone(),
+        render_state.target_format,
+    )?;
+    paint_callback_resources.insert(render_ctx);
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 
 /// This wakes up the ui thread each time we receive a new message.
@@ -0,0 +1,237 @@
+//! Runs the viewer without a window, painting its ui into a texture instead.
+
+/// Sets up a wgpu device that isn't tied to any window surface, with `re_renderer` ready to go.
+pub fn create_render_state(
+    force_wgpu_backend: Option<&str>,
+) -> anyhow::Result<egui_wgpu::RenderState> {
+    re_tracing::profile_function!();
+
+    let instance = wgpu::Instance::new(&re_renderer::config::instance_descriptor(
+        force_wgpu_backend,
+    ));
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+        power_preference: wgpu::PowerPreference::HighPerformance,
+        compatible_surface: None,
+        force_fallback_adapter: false,
+    }))
+    .ok_or_else(|| anyhow::anyhow!("Failed to find a graphics adapter"))?;
+    re_log::debug!(
+        "Rendering with {}",
+        re_renderer::adapter_info_summary(&adapter.get_info())
+    );
+
+    let device_caps = re_renderer::config::DeviceCaps::from_adapter(&adapter)?;
+    let (device, queue) =
+        pollster::block_on(adapter.request_device(&device_caps.device_descriptor(), None))?;
+
+    let config = egui_wgpu::WgpuConfiguration {
+        wgpu_setup: egui_wgpu::WgpuSetupExisting {
+            instance: instance.clone(),
+            adapter,
+            device,
+            queue,
+        }
+        .into(),
+        ..Default::default()
+    };
+
+    let compatible_surface = None;
+    // `re_renderer`'s views do their own MSAA & depth, and there's no display to dither for.
+    let depth_format = None;
+    let msaa_samples = 1;
+    let dithering = false;
+
+    let render_state = pollster::block_on(egui_wgpu::RenderState::create(
+        &config,
+        &instance,
+        compatible_surface,
+        depth_format,
+        msaa_samples,
+        dithering,
+    ))?;
+    crate::setup_renderer(&render_state)?;
+
+    Ok(render_state)
+}
+
+/// Paints the ui into a texture of the exact size of the screen, in pixels.
+pub struct OffscreenPainter {
+    render_state: egui_wgpu::RenderState,
+    target: Option<wgpu::Texture>,
+}
+
+impl OffscreenPainter {
+    pub fn new(render_state: egui_wgpu::RenderState) -> Self {
+        Self {
+            render_state,
+            target: None,
+        }
+    }
+
+    /// Paints the output of the last frame, replacing whatever was painted before.
+    pub fn paint(&mut self, egui_ctx: &egui::Context, output: egui::FullOutput) {
+        re_tracing::profile_function!();
+
+        let pixels_per_point = output.pixels_per_point;
+        let size_in_pixels = egui_ctx.screen_rect().size() * pixels_per_point;
+        let screen_descriptor = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: [
+                size_in_pixels.x.round() as u32,
+                size_in_pixels.y.round() as u32,
+            ],
+            pixels_per_point,
+        };
+        let clipped_primitives = egui_ctx.tessellate(output.shapes, pixels_per_point);
+
+        let target = self.target(screen_descriptor.size_in_pixels);
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let egui_wgpu::RenderState {
+            device,
+            queue,
+            renderer,
+            ..
+        } = &self.render_state;
+        let mut renderer = renderer.write();
+
+        for (id, image_delta) in &output.textures_delta.set {
+            renderer.update_texture(device, queue, *id, image_delta);
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("offscreen ui"),
+        });
+        let user_command_buffers = renderer.update_buffers(
+            device,
+            queue,
+            &mut encoder,
+            &clipped_primitives,
+            &screen_descriptor,
+        );
+        {
+            let mut render_pass = encoder
+                .begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("offscreen ui"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &target_view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                })
+                .forget_lifetime();
+            renderer.render(&mut render_pass, &clipped_primitives, &screen_descriptor);
+        }
+        queue.submit(
+            user_command_buffers
+                .into_iter()
+                .chain(std::iter::once(encoder.finish())),
+        );
+
+        for id in &output.textures_delta.free {
+            renderer.free_texture(id);
+        }
+    }
+
+    /// The texture to paint into, recreated whenever the screen size changes.
+    fn target(&mut self, [width, height]: [u32; 2]) -> wgpu::Texture {
+        if let Some(target) = &self.target {
+            if target.width() == width && target.height() == height {
+                return target.clone();
+            }
+        }
+
+        let target = self
+            .render_state
+            .device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("offscreen ui"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.render_state.target_format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[],
+            });
+        self.target = Some(target.clone());
+        target
+    }
+
+    /// Reads back what was last painted, blocking until the GPU is done with it.
+    pub fn read_back(&self) -> anyhow::Result<image::RgbaImage> {
+        re_tracing::profile_function!();
+
+        let Some(target) = &self.target else {
+            anyhow::bail!("Nothing was painted yet");
+        };
+        let swizzle = match target.format() {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            format => anyhow::bail!("Can't read back textures of format {format:?}"),
+        };
+
+        let egui_wgpu::RenderState { device, queue, .. } = &self.render_state;
+
+        let (width, height) = (target.width(), target.height());
+        let bytes_per_row = (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("offscreen ui readback"),
+            size: bytes_per_row as u64 * height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("offscreen ui readback"),
+        });
+        encoder.copy_texture_to_buffer(
+            target.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            target.size(),
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            tx.send(result).ok();
+        });
+        device.poll(wgpu::Maintain::Wait);
+        rx.recv()??;
+
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(bytes_per_row as usize)
+        {
+            pixels.extend_from_slice(&row[..width as usize * 4]);
+        }
+        buffer.unmap();
+
+        if swizzle {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        image::RgbaImage::from_raw(width, height, pixels)
+            .ok_or_else(|| anyhow::anyhow!("Bad readback size"))
+    }
+}
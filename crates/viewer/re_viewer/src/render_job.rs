@@ -0,0 +1,760 @@
+//! Renders a recording frame by frame to disk, without opening a window.
+//!
+//! This is what powers `rerun render`.
+
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, Command, Stdio};
+
+use re_log_types::{LogMsg, ResolvedTimeRange, TimeReal, TimeType, Timeline};
+use re_types::{blueprint::components::ViewMaximized, ComponentBatch};
+use re_viewer_context::{
+    CommandSender, StoreContext, SystemCommand, SystemCommandSender as _, ViewId, ViewRectPublisher,
+};
+use re_viewport_blueprint::{ViewportBlueprint, VIEWPORT_PATH};
+
+use crate::{app_state::AppState, offscreen::OffscreenPainter, App, StartupOptions};
+
+/// How many frames to wait after moving the time cursor before capturing a frame.
+///
+/// This gives the views time to pick up the new data, e.g. decode video frames,
+/// upload textures and finish any eye interpolation.
+const SETTLE_FRAMES: usize = 5;
+
+/// How long to wait for a settled frame to become capturable before giving up.
+///
+/// When rendering a single view, the screen is resized until that view has the requested resolution.
+const CAPTURE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// The most frames a single render job may produce, e.g. about nine hours of video at 30 fps.
+///
+/// Guards against a tiny step over a long timeline, which would render practically forever.
+const MAX_FRAMES: usize = 1_000_000;
+
+/// A time given to `rerun render`, either absolute or relative to the start of the timeline.
+///
+/// In seconds for temporal timelines, in ticks for sequence timelines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenderTime {
+    /// A time on the timeline, e.g. seconds since the unix epoch for timestamps.
+    Absolute(f64),
+
+    /// An offset from the first time the timeline has data at.
+    FromStart(f64),
+}
+
+impl std::str::FromStr for RenderTime {
+    type Err = String;
+
+    /// Parses `+2.5` as 2.5 after the start of the timeline, and `2.5` as the absolute time 2.5.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, make): (_, fn(f64) -> Self) = match s.trim().strip_prefix('+') {
+            Some(offset) => (offset, Self::FromStart),
+            None => (s.trim(), Self::Absolute),
+        };
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(make)
+            .ok_or_else(|| format!("Invalid time {s:?}, expected e.g. 12.5 or +2"))
+    }
+}
+
+impl RenderTime {
+    /// The time in native units of the timeline, i.e. nanoseconds or ticks.
+    fn in_native_units(self, to_native: f64, timeline_start: f64) -> f64 {
+        match self {
+            Self::Absolute(time) => time * to_native,
+            Self::FromStart(offset) => timeline_start + offset * to_native,
+        }
+    }
+}
+
+/// Settings for rendering a recording to a sequence of images or a video.
+#[derive(Clone, Debug)]
+pub struct RenderJobOptions {
+    /// Where to write the rendered frames.
+    ///
+    /// If this ends with `.mp4` the frames are encoded to a video using `ffmpeg`,
+    /// otherwise this is a directory that a PNG sequence is written to.
+    pub output: PathBuf,
+
+    /// Name of the timeline to step through.
+    ///
+    /// Defaults to the timeline the viewer would pick.
+    pub timeline: Option<String>,
+
+    /// First time to render.
+    ///
+    /// Defaults to the start of the timeline.
+    pub start: Option<RenderTime>,
+
+    /// Last time to render.
+    ///
+    /// Defaults to the end of the timeline.
+    pub end: Option<RenderTime>,
+
+    /// Time between two rendered frames.
+    ///
+    /// In seconds for temporal timelines, in ticks for sequence timelines.
+    ///
+    /// Defaults to `1 / fps` seconds for temporal timelines, and a single tick for sequence timelines.
+    pub step: Option<f64>,
+
+    /// Frame rate of the encoded video.
+    pub fps: f64,
+
+    /// Name or id of the view to render.
+    ///
+    /// If not set, the whole viewport is rendered.
+    pub view: Option<String>,
+
+    /// Size of the rendered frames in pixels.
+    pub resolution: [u32; 2],
+
+    /// The `ffmpeg` executable used for encoding videos.
+    ///
+    /// Defaults to `ffmpeg` on the `PATH`.
+    pub ffmpeg_path: Option<PathBuf>,
+}
+
+impl RenderJobOptions {
+    fn is_video(&self) -> bool {
+        self.output
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("mp4"))
+    }
+
+    fn resolution_in_points(&self) -> egui::Vec2 {
+        // The ui is laid out at one pixel per point.
+        let [width, height] = self.resolution;
+        egui::vec2(width as f32, height as f32)
+    }
+
+    /// The times to render on a timeline of the given type that has data in the given range.
+    fn frame_times(
+        &self,
+        time_type: TimeType,
+        range: ResolvedTimeRange,
+    ) -> anyhow::Result<Vec<TimeReal>> {
+        let (to_native, default_step) = match time_type {
+            TimeType::Time => (1e9, 1e9 / self.fps),
+            TimeType::Sequence => (1.0, 1.0),
+        };
+        let timeline_start = range.min().as_i64() as f64;
+        let start = self.start.map_or(timeline_start, |start| {
+            start.in_native_units(to_native, timeline_start)
+        });
+        let end = self.end.map_or(range.max().as_i64() as f64, |end| {
+            end.in_native_units(to_native, timeline_start)
+        });
+        let step = self.step.map_or(default_step, |step| step * to_native);
+        anyhow::ensure!(step > 0.0, "The step between frames must be positive");
+        anyhow::ensure!(start <= end, "The time range to render is empty");
+
+        let num_steps = ((end - start) / step).floor();
+        anyhow::ensure!(
+            num_steps < MAX_FRAMES as f64,
+            "The render would produce more than {MAX_FRAMES} frames, use a larger --step or a shorter time range"
+        );
+        let num_frames = num_steps as usize + 1;
+        Ok((0..num_frames)
+            .map(|i| TimeReal::from(start + i as f64 * step))
+            .collect())
+    }
+}
+
+/// Renders the recordings received on `receivers` as configured by [`StartupOptions::render_job`].
+///
+/// The viewer runs without a window, its ui is painted into an offscreen texture of exactly the requested resolution.
+/// Returns once all frames have been written, or with the first error.
+pub fn render(
+    main_thread_token: crate::MainThreadToken,
+    build_info: re_build_info::BuildInfo,
+    app_env: &crate::AppEnvironment,
+    startup_options: StartupOptions,
+    receivers: Vec<re_smart_channel::Receiver<LogMsg>>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    anyhow::ensure!(
+        startup_options.render_job.is_some(),
+        "Nothing to render, no render job was given"
+    );
+
+    let render_state =
+        crate::offscreen::create_render_state(startup_options.force_wgpu_backend.as_deref())?;
+
+    let egui_ctx = egui::Context::default();
+    re_ui::apply_style_and_install_loaders(&egui_ctx);
+
+    let mut app = App::with_render_state(
+        main_thread_token,
+        build_info,
+        app_env,
+        startup_options,
+        &egui_ctx,
+        None,
+        Some(&render_state),
+    );
+    for rx in receivers {
+        app.add_receiver(rx);
+    }
+
+    let mut painter = OffscreenPainter::new(render_state.clone());
+    let start_time = std::time::Instant::now();
+
+    loop {
+        let Some(render_job) = app.render_job_mut() else {
+            anyhow::bail!("The render job went missing");
+        };
+
+        let mut raw_input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                render_job.screen_size(&egui_ctx),
+            )),
+            time: Some(start_time.elapsed().as_secs_f64()),
+            ..Default::default()
+        };
+        raw_input
+            .viewports
+            .entry(egui::ViewportId::ROOT)
+            .or_default()
+            .native_pixels_per_point = Some(1.0);
+
+        let output = egui_ctx.run(raw_input, |egui_ctx| {
+            app.update_and_ui(egui_ctx, &render_state);
+        });
+        painter.paint(&egui_ctx, output);
+
+        let Some(render_job) = app.render_job_mut() else {
+            anyhow::bail!("The render job went missing");
+        };
+        if let Some(rect) = render_job.capture_rect(&egui_ctx) {
+            let frame = painter.read_back().map(|image| {
+                image::imageops::crop_imm(
+                    &image,
+                    rect.min.x as u32,
+                    rect.min.y as u32,
+                    rect.width() as u32,
+                    rect.height() as u32,
+                )
+                .to_image()
+            });
+            render_job.save_frame(frame);
+        }
+        if let Some(result) = render_job.take_result() {
+            return result;
+        }
+    }
+}
+
+enum Stage {
+    /// Waiting for all data sources to finish loading.
+    Loading,
+
+    /// The time cursor was moved to the given frame, waiting for the viewer to catch up.
+    Settling {
+        index: usize,
+        countdown: usize,
+    },
+
+    /// Waiting for the given frame to be capturable.
+    Capturing {
+        index: usize,
+        since: std::time::Instant,
+    },
+
+    Done,
+}
+
+enum FrameSink {
+    PngSequence(PathBuf),
+    Ffmpeg { child: Child, stdin: ChildStdin },
+}
+
+/// Steps through a recording and writes every step to disk.
+pub struct RenderJob {
+    options: RenderJobOptions,
+    stage: Stage,
+    timeline: Option<Timeline>,
+    times: Vec<TimeReal>,
+    view_id: Option<ViewId>,
+    sink: Option<FrameSink>,
+
+    /// Set once the job is done.
+    result: Option<anyhow::Result<()>>,
+}
+
+impl RenderJob {
+    pub fn new(options: RenderJobOptions) -> Self {
+        Self {
+            options,
+            stage: Stage::Loading,
+            timeline: None,
+            times: Vec::new(),
+            view_id: None,
+            sink: None,
+            result: None,
+        }
+    }
+
+    /// Call once per frame, after the ui has been drawn.
+    pub fn update(
+        &mut self,
+        store_context: Option<&StoreContext<'_>>,
+        app_state: &mut AppState,
+        is_loading: bool,
+        command_sender: &CommandSender,
+    ) {
+        match self.stage {
+            Stage::Loading => {
+                if is_loading {
+                    return;
+                }
+                let Some(store_context) =
+                    store_context.filter(|store_context| !store_context.recording.is_empty())
+                else {
+                    self.finish(Err(anyhow::anyhow!("The recording is empty")));
+                    return;
+                };
+
+                if let Err(err) = self.start(store_context, app_state, command_sender) {
+                    self.finish(Err(err));
+                } else {
+                    self.stage = Stage::Settling {
+                        index: 0,
+                        // Loading the blueprint & first frame takes a bit longer.
+                        countdown: 2 * SETTLE_FRAMES,
+                    };
+                }
+            }
+
+            Stage::Settling { index, countdown } => {
+                if let Some(store_context) = store_context {
+                    self.move_time_cursor(store_context, app_state, index);
+                }
+
+                self.stage = if countdown == 0 {
+                    Stage::Capturing {
+                        index,
+                        since: std::time::Instant::now(),
+                    }
+                } else {
+                    Stage::Settling {
+                        index,
+                        countdown: countdown - 1,
+                    }
+                };
+            }
+
+            Stage::Capturing { index, since } => {
+                if CAPTURE_TIMEOUT < since.elapsed() {
+                    self.finish(Err(anyhow::anyhow!(
+                        "Timed out waiting for frame {index} to be ready"
+                    )));
+                }
+            }
+
+            Stage::Done => {}
+        }
+    }
+
+    /// Resolve timeline, time range and view, and prepare the output.
+    fn start(
+        &mut self,
+        store_context: &StoreContext<'_>,
+        app_state: &mut AppState,
+        command_sender: &CommandSender,
+    ) -> anyhow::Result<()> {
+        let recording = store_context.recording;
+
+        let timeline = if let Some(name) = &self.options.timeline {
+            let timeline = recording
+                .timelines()
+                .find(|timeline| timeline.name().as_str() == name)
+                .copied();
+            Some(timeline.ok_or_else(|| anyhow::anyhow!("Unknown timeline {name:?}"))?)
+        } else {
+            app_state
+                .recording_config(recording.store_id())
+                .map(|config| *config.time_ctrl.read().timeline())
+        };
+
+        self.times = match timeline.and_then(|timeline| {
+            recording
+                .time_range_for(&timeline)
+                .map(|range| (timeline, range))
+        }) {
+            Some((timeline, range)) => self.options.frame_times(timeline.typ(), range)?,
+            None => {
+                // Static data only: a single frame will do.
+                vec![TimeReal::from(0)]
+            }
+        };
+        self.timeline = timeline;
+
+        if let Some(view) = &self.options.view {
+            let query = app_state.blueprint_query_for_viewer(store_context.blueprint);
+            let viewport = ViewportBlueprint::try_from_db(store_context.blueprint, &query);
+            let view_id = viewport
+                .views
+                .values()
+                .find(|view_blueprint| {
+                    view_blueprint.display_name_or_default().as_ref() == view
+                        || view_blueprint.id.uuid().to_string() == *view
+                })
+                .map(|view_blueprint| view_blueprint.id)
+                .ok_or_else(|| anyhow::anyhow!("No view named {view:?} in the blueprint"))?;
+
+            // Let the view take up the whole viewport.
+            let chunk = re_chunk::Chunk::builder(VIEWPORT_PATH.into())
+                .with_component_batches(
+                    re_chunk::RowId::new(),
+                    store_context.blueprint_timepoint_for_writes(),
+                    [&ViewMaximized(view_id.into()) as &dyn ComponentBatch],
+                )
+                .build()?;
+            command_sender.send_system(SystemCommand::UpdateBlueprint(
+                store_context.blueprint.store_id().clone(),
+                vec![chunk],
+            ));
+
+            self.view_id = Some(view_id);
+        }
+
+        self.sink = Some(if self.options.is_video() {
+            let [width, height] = self.options.resolution;
+            anyhow::ensure!(
+                width % 2 == 0 && height % 2 == 0,
+                "Videos need an even resolution, got {width}x{height}"
+            );
+            let ffmpeg = self
+                .options
+                .ffmpeg_path
+                .clone()
+                .unwrap_or_else(|| "ffmpeg".into());
+            let mut child = Command::new(&ffmpeg)
+                .args(["-y", "-loglevel", "error"])
+                .args(["-f", "rawvideo", "-pix_fmt", "rgba"])
+                .args(["-s", &format!("{width}x{height}")])
+                .args(["-r", &self.options.fps.to_string()])
+                .args(["-i", "-"])
+                .args(["-c:v", "libx264", "-pix_fmt", "yuv420p"])
+                .arg(&self.options.output)
+                .stdin(Stdio::piped())
+                .spawn()
+                .map_err(|err| anyhow::anyhow!("Failed to start {ffmpeg:?}: {err}"))?;
+            let stdin = child
+                .stdin
+                .take()
+                .ok_or_else(|| anyhow::anyhow!("Failed to open stdin of {ffmpeg:?}"))?;
+            FrameSink::Ffmpeg { child, stdin }
+        } else {
+            std::fs::create_dir_all(&self.options.output)?;
+            FrameSink::PngSequence(self.options.output.clone())
+        });
+
+        re_log::info!(
+            "Rendering {} frame(s) to {:?}…",
+            self.times.len(),
+            self.options.output
+        );
+
+        Ok(())
+    }
+
+    fn move_time_cursor(
+        &self,
+        store_context: &StoreContext<'_>,
+        app_state: &mut AppState,
+        index: usize,
+    ) {
+        let (Some(timeline), Some(time)) = (self.timeline, self.times.get(index)) else {
+            return;
+        };
+        if let Some(config) = app_state.recording_config_mut(store_context.recording.store_id()) {
+            let mut time_ctrl = config.time_ctrl.write();
+            time_ctrl.pause();
+            time_ctrl.set_timeline_and_time(timeline, *time);
+        }
+    }
+
+    /// Where the rendered view was shown last frame, in ui points.
+    fn view_rect(egui_ctx: &egui::Context, view_id: ViewId) -> Option<egui::Rect> {
+        egui_ctx.memory_mut(|mem| {
+            mem.caches
+                .cache::<ViewRectPublisher>()
+                .get(&view_id)
+                .map(|info| info.rect)
+        })
+    }
+
+    /// Size of the screen to lay out the next frame on, in ui points.
+    pub fn screen_size(&self, egui_ctx: &egui::Context) -> egui::Vec2 {
+        let resolution = self.options.resolution_in_points();
+        match self
+            .view_id
+            .and_then(|view_id| Self::view_rect(egui_ctx, view_id))
+        {
+            // Grow the screen by whatever the ui around the view takes up,
+            // so that the view itself ends up with the requested resolution.
+            Some(view_rect) => (egui_ctx.screen_rect().size() - view_rect.size() + resolution)
+                .round()
+                .max(resolution),
+            None => resolution,
+        }
+    }
+
+    /// The part of the last painted frame to capture, in pixels, if it is time to capture one.
+    pub fn capture_rect(&self, egui_ctx: &egui::Context) -> Option<egui::Rect> {
+        let Stage::Capturing { .. } = self.stage else {
+            return None;
+        };
+
+        let resolution = self.options.resolution_in_points();
+        let Some(view_id) = self.view_id else {
+            return Some(egui::Rect::from_min_size(egui::Pos2::ZERO, resolution));
+        };
+
+        // Wait until the screen was resized to fit the view.
+        let view_rect = Self::view_rect(egui_ctx, view_id)?;
+        ((view_rect.size() - resolution).abs().max_elem() < 0.5)
+            .then(|| egui::Rect::from_min_size(view_rect.min.round(), resolution))
+    }
+
+    /// Called with the frame captured at [`Self::capture_rect`].
+    pub fn save_frame(&mut self, frame: anyhow::Result<image::RgbaImage>) {
+        let Stage::Capturing { index, .. } = self.stage else {
+            return;
+        };
+
+        if let Err(err) = frame.and_then(|frame| self.write_frame(index, &frame)) {
+            self.finish(Err(err.context(format!("Failed to write frame {index}"))));
+            return;
+        }
+
+        if index + 1 < self.times.len() {
+            self.stage = Stage::Settling {
+                index: index + 1,
+                countdown: SETTLE_FRAMES,
+            };
+        } else {
+            self.finish(Ok(()));
+        }
+    }
+
+    fn write_frame(&mut self, index: usize, frame: &image::RgbaImage) -> anyhow::Result<()> {
+        let [width, height] = self.options.resolution;
+        anyhow::ensure!(
+            frame.dimensions() == (width, height),
+            "Expected a {width}x{height} frame, got {}x{}",
+            frame.width(),
+            frame.height()
+        );
+
+        match &mut self.sink {
+            Some(FrameSink::PngSequence(dir)) => {
+                frame.save(dir.join(format!("frame_{index:05}.png")))?;
+            }
+            Some(FrameSink::Ffmpeg { stdin, .. }) => {
+                stdin.write_all(frame.as_raw())?;
+            }
+            None => anyhow::bail!("No output was set up"),
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, result: anyhow::Result<()>) {
+        let result = result.and(match self.sink.take() {
+            Some(FrameSink::Ffmpeg { mut child, stdin }) => {
+                // Closing stdin tells ffmpeg that there are no more frames.
+                drop(stdin);
+                match child.wait() {
+                    Ok(status) if status.success() => {
+                        re_log::info!("Video saved to {:?}", self.options.output);
+                        Ok(())
+                    }
+                    Ok(status) => Err(anyhow::anyhow!("ffmpeg failed with {status}")),
+                    Err(err) => Err(anyhow::anyhow!("Failed to wait for ffmpeg: {err}")),
+                }
+            }
+            Some(FrameSink::PngSequence(dir)) => {
+                re_log::info!("Frames saved to {dir:?}");
+                Ok(())
+            }
+            None => Ok(()),
+        });
+
+        self.result = Some(result);
+        self.stage = Stage::Done;
+    }
+
+    /// The outcome of the job, once it is done.
+    pub fn take_result(&mut self) -> Option<anyhow::Result<()>> {
+        self.result.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::TimeInt;
+
+    use super::*;
+
+    fn job_options(
+        start: Option<RenderTime>,
+        end: Option<RenderTime>,
+        step: Option<f64>,
+    ) -> RenderJobOptions {
+        RenderJobOptions {
+            output: "frames".into(),
+            timeline: None,
+            start,
+            end,
+            step,
+            fps: 10.0,
+            view: None,
+            resolution: [640, 480],
+            ffmpeg_path: None,
+        }
+    }
+
+    fn range(min: i64, max: i64) -> ResolvedTimeRange {
+        ResolvedTimeRange::new(TimeInt::new_temporal(min), TimeInt::new_temporal(max))
+    }
+
+    fn times(times: &[f64]) -> Vec<TimeReal> {
+        times.iter().copied().map(TimeReal::from).collect()
+    }
+
+    #[test]
+    fn sequence_frame_times() {
+        let options = job_options(None, None, None);
+        assert_eq!(
+            options
+                .frame_times(TimeType::Sequence, range(3, 6))
+                .unwrap(),
+            times(&[3.0, 4.0, 5.0, 6.0])
+        );
+
+        let options = job_options(
+            Some(RenderTime::Absolute(4.0)),
+            Some(RenderTime::Absolute(9.0)),
+            Some(2.0),
+        );
+        assert_eq!(
+            options
+                .frame_times(TimeType::Sequence, range(3, 6))
+                .unwrap(),
+            times(&[4.0, 6.0, 8.0])
+        );
+    }
+
+    #[test]
+    fn temporal_frame_times() {
+        // One frame every 1/fps seconds.
+        let options = job_options(None, None, None);
+        assert_eq!(
+            options
+                .frame_times(TimeType::Time, range(1_000_000_000, 1_250_000_000))
+                .unwrap(),
+            times(&[1e9, 1.1e9, 1.2e9])
+        );
+
+        // Start, end and step are in seconds.
+        let options = job_options(
+            Some(RenderTime::Absolute(2.0)),
+            Some(RenderTime::Absolute(3.0)),
+            Some(0.5),
+        );
+        assert_eq!(
+            options.frame_times(TimeType::Time, range(0, 0)).unwrap(),
+            times(&[2e9, 2.5e9, 3e9])
+        );
+    }
+
+    #[test]
+    fn frame_times_from_start() {
+        // Offsets are relative to the first time with data.
+        let options = job_options(
+            Some(RenderTime::FromStart(1.0)),
+            Some(RenderTime::FromStart(1.5)),
+            Some(0.25),
+        );
+        assert_eq!(
+            options
+                .frame_times(TimeType::Time, range(10_000_000_000, 20_000_000_000))
+                .unwrap(),
+            times(&[11e9, 11.25e9, 11.5e9])
+        );
+
+        let options = job_options(Some(RenderTime::FromStart(2.0)), None, None);
+        assert_eq!(
+            options
+                .frame_times(TimeType::Sequence, range(3, 6))
+                .unwrap(),
+            times(&[5.0, 6.0])
+        );
+    }
+
+    #[test]
+    fn parse_render_time() {
+        assert_eq!("12.5".parse(), Ok(RenderTime::Absolute(12.5)));
+        assert_eq!("-3".parse(), Ok(RenderTime::Absolute(-3.0)));
+        assert_eq!("+2".parse(), Ok(RenderTime::FromStart(2.0)));
+        assert!("+".parse::<RenderTime>().is_err());
+        assert!("soon".parse::<RenderTime>().is_err());
+        assert!("inf".parse::<RenderTime>().is_err());
+    }
+
+    #[test]
+    fn single_frame() {
+        let options = job_options(None, None, None);
+        assert_eq!(
+            options
+                .frame_times(TimeType::Sequence, range(5, 5))
+                .unwrap(),
+            times(&[5.0])
+        );
+    }
+
+    #[test]
+    fn invalid_frame_times() {
+        let options = job_options(
+            Some(RenderTime::Absolute(2.0)),
+            Some(RenderTime::Absolute(1.0)),
+            None,
+        );
+        assert!(options
+            .frame_times(TimeType::Sequence, range(0, 5))
+            .is_err());
+
+        let options = job_options(None, None, Some(0.0));
+        assert!(options
+            .frame_times(TimeType::Sequence, range(0, 5))
+            .is_err());
+
+        // One nanosecond steps over an hour.
+        let options = job_options(None, None, Some(1e-9));
+        assert!(options
+            .frame_times(TimeType::Time, range(0, 3_600_000_000_000))
+            .is_err());
+
+        // The limit itself is fine.
+        let options = job_options(None, None, None);
+        assert_eq!(
+            options
+                .frame_times(TimeType::Sequence, range(0, MAX_FRAMES as i64 - 1))
+                .unwrap()
+                .len(),
+            MAX_FRAMES
+        );
+        assert!(options
+            .frame_times(TimeType::Sequence, range(0, MAX_FRAMES as i64))
+            .is_err());
+    }
+}
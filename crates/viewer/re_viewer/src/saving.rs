@@ -55,6 +55,22 @@ pub fn default_blueprint_path(app_id: &ApplicationId) -> anyhow::Result<std::pat
     Ok(blueprint_dir.join(format!("{sanitized_app_id}.rbl")))
 }
 
+/// The directory that blueprint templates are stored in, see [`crate::blueprint_templates`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn blueprint_templates_dir() -> anyhow::Result<std::path::PathBuf> {
+    use anyhow::Context;
+
+    let Some(storage_dir) = eframe::storage_dir(crate::native::APP_ID) else {
+        anyhow::bail!("Error finding project directory for blueprint templates.")
+    };
+
+    let templates_dir = storage_dir.join("blueprint_templates");
+    std::fs::create_dir_all(&templates_dir)
+        .context("Could not create blueprint template directory.")?;
+
+    Ok(templates_dir)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub fn encode_to_file(
     version: re_build_info::CrateVersion,
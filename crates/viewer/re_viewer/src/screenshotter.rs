@@ -7,6 +7,7 @@
 pub struct Screenshotter {
     countdown: Option<isize>,
     target_path: Option<std::path::PathBuf>,
+    quit_when_saved: bool,
     quit: bool,
     pre_screenshot_zoom_factor: Option<f32>,
 }
@@ -31,9 +32,27 @@ impl Screenshotter {
         assert!(self.countdown.is_none(), "screenshotter misused");
         self.request_screenshot(egui_ctx);
         self.target_path = Some(path);
+        self.quit_when_saved = true;
+    }
+
+    /// Save a screenshot to the given path, and keep running afterwards.
+    ///
+    /// Used when remote controlling the viewer.
+    pub fn screenshot_to_path(&mut self, egui_ctx: &egui::Context, path: std::path::PathBuf) {
+        if self.is_screenshotting() {
+            re_log::warn!("Ignoring screenshot request for {path:?}: already taking a screenshot");
+            return;
+        }
+        self.request_screenshot(egui_ctx);
+        self.target_path = Some(path);
     }
 
     pub fn request_screenshot(&mut self, egui_ctx: &egui::Context) {
+        if self.is_screenshotting() {
+            re_log::warn!("Ignoring screenshot request: already taking a screenshot");
+            return;
+        }
+
         // Give app time to change the style, and then wait for animations to finish:
         self.countdown = Some(10);
 
@@ -93,11 +112,14 @@ impl Screenshotter {
             match image.save(&path) {
                 Ok(()) => {
                     re_log::info!("Screenshot saved to {path:?}");
-                    self.quit = true;
+                    self.quit = self.quit_when_saved;
                 }
-                Err(err) => {
+                Err(err) if self.quit_when_saved => {
                     panic!("Failed saving screenshot to {path:?}: {err}");
                 }
+                Err(err) => {
+                    re_log::error!("Failed saving screenshot to {path:?}: {err}");
+                }
             }
         } else {
             egui_ctx.copy_image(image.clone());
@@ -0,0 +1,236 @@
+//! Modal for applying blueprint templates, and for saving the current blueprint as a template.
+
+use std::collections::BTreeMap;
+
+use re_log_types::{EntityPath, EntityPathSubs};
+use re_ui::UiExt as _;
+use re_viewer_context::{StoreContext, StoreHub};
+
+use crate::blueprint_templates::{is_valid_variable_name, BlueprintTemplates};
+
+/// What the user asked for in the [`BlueprintTemplatesModal`].
+pub enum BlueprintTemplateAction {
+    /// Apply a template to the active recording.
+    Apply {
+        /// Index into [`BlueprintTemplates`].
+        template: usize,
+        variables: EntityPathSubs,
+    },
+
+    /// Save the active blueprint as a template.
+    Save {
+        name: String,
+        variables: BTreeMap<String, EntityPath>,
+    },
+}
+
+#[derive(Default)]
+pub struct BlueprintTemplatesModal {
+    modal_handler: re_ui::modal::ModalHandler,
+
+    /// The template to apply.
+    selected: Option<usize>,
+
+    /// The values of the selected template's variables.
+    variables: BTreeMap<String, String>,
+
+    /// Name of the template to save.
+    new_name: String,
+
+    /// Variables of the template to save, and the entity paths they stand for.
+    new_variables: Vec<(String, String)>,
+}
+
+impl BlueprintTemplatesModal {
+    pub fn open(&mut self) {
+        self.selected = None;
+        self.modal_handler.open();
+    }
+
+    pub fn ui(
+        &mut self,
+        egui_ctx: &egui::Context,
+        templates: &BlueprintTemplates,
+        store_context: Option<&StoreContext<'_>>,
+    ) -> Option<BlueprintTemplateAction> {
+        let Self {
+            modal_handler,
+            selected,
+            variables,
+            new_name,
+            new_variables,
+        } = self;
+
+        modal_handler
+            .ui(
+                egui_ctx,
+                || {
+                    re_ui::modal::ModalWrapper::new("Blueprint templates")
+                        .min_width(450.0)
+                        .scrollable([false, true])
+                },
+                |ui, keep_open| {
+                    let Some(store_context) = store_context.filter(|store_context| {
+                        store_context.app_id != StoreHub::welcome_screen_app_id()
+                    }) else {
+                        ui.label("Open a recording to use blueprint templates.");
+                        return None;
+                    };
+
+                    let action =
+                        apply_template_ui(ui, templates, store_context, selected, variables)
+                            .or_else(|| {
+                                ui.add_space(8.0);
+                                ui.full_span_separator();
+                                ui.add_space(8.0);
+                                save_template_ui(ui, new_name, new_variables)
+                            });
+                    if action.is_some() {
+                        *keep_open = false;
+                    }
+                    action
+                },
+            )
+            .flatten()
+    }
+}
+
+fn apply_template_ui(
+    ui: &mut egui::Ui,
+    templates: &BlueprintTemplates,
+    store_context: &StoreContext<'_>,
+    selected: &mut Option<usize>,
+    variables: &mut BTreeMap<String, String>,
+) -> Option<BlueprintTemplateAction> {
+    ui.strong("Apply a template");
+
+    if templates.is_empty() {
+        ui.weak("No templates yet. Save the current blueprint as a template below.");
+        return None;
+    }
+
+    for (index, template) in templates.iter().enumerate() {
+        let is_selected = *selected == Some(index);
+        let label = if let Some(app_id) = &template.app_id {
+            format!("{} ({app_id})", template.name)
+        } else {
+            template.name.clone()
+        };
+
+        let response = ui
+            .selectable_label(is_selected, label)
+            .on_hover_text(template.path.display().to_string());
+        if response.clicked() && !is_selected {
+            *selected = Some(index);
+            *variables = template.infer_variables(store_context.recording);
+        }
+    }
+
+    let index = (*selected)?;
+    let template = templates.get(index)?;
+
+    if !template.variables.is_empty() {
+        ui.add_space(4.0);
+        ui.weak("The entity paths the template's variables stand for:");
+        ui.selection_grid("template_variables").show(ui, |ui| {
+            for variable in &template.variables {
+                ui.grid_left_hand_label(&format!("${variable}"));
+                ui.text_edit_singleline(variables.entry(variable.clone()).or_default());
+                ui.end_row();
+            }
+        });
+    }
+
+    let is_complete = template.variables.iter().all(|variable| {
+        variables
+            .get(variable)
+            .is_some_and(|value| !value.trim().is_empty())
+    });
+    let response = ui
+        .add_enabled(is_complete, egui::Button::new("Apply"))
+        .on_hover_text("Replaces the current blueprint. Use \"Reset to default blueprint\" to return to the template later.");
+
+    response.clicked().then(|| BlueprintTemplateAction::Apply {
+        template: index,
+        variables: template
+            .variables
+            .iter()
+            .map(|variable| {
+                let value = EntityPath::parse_forgiving(&variables[variable]);
+                (variable.clone(), value.to_string())
+            })
+            .collect(),
+    })
+}
+
+fn save_template_ui(
+    ui: &mut egui::Ui,
+    name: &mut String,
+    variables: &mut Vec<(String, String)>,
+) -> Option<BlueprintTemplateAction> {
+    ui.strong("Save the current blueprint as a template");
+
+    ui.selection_grid("new_template").show(ui, |ui| {
+        ui.grid_left_hand_label("Name");
+        ui.text_edit_singleline(name);
+        ui.end_row();
+    });
+
+    ui.add_space(4.0);
+    ui.weak(
+        "Variables replace entity paths in view origins and view contents, \
+        e.g. `robot` for `/robots/arm_a` turns `/robots/arm_a/camera` into `$robot/camera`.",
+    );
+
+    let mut removed = None;
+    egui::Grid::new("new_template_variables")
+        .num_columns(3)
+        .show(ui, |ui| {
+            for (index, (variable, path)) in variables.iter_mut().enumerate() {
+                ui.add(egui::TextEdit::singleline(variable).hint_text("variable"));
+                ui.add(egui::TextEdit::singleline(path).hint_text("/entity/path"));
+                if ui
+                    .small_icon_button(&re_ui::icons::REMOVE)
+                    .on_hover_text("Remove variable")
+                    .clicked()
+                {
+                    removed = Some(index);
+                }
+                ui.end_row();
+            }
+        });
+    if let Some(index) = removed {
+        variables.remove(index);
+    }
+    if ui.button("Add variable").clicked() {
+        variables.push(Default::default());
+    }
+
+    let error = if name.trim().is_empty() {
+        Some("The template needs a name".to_owned())
+    } else {
+        variables.iter().find_map(|(variable, path)| {
+            if !is_valid_variable_name(variable) {
+                Some(format!("Invalid variable name {variable:?}"))
+            } else if EntityPath::parse_forgiving(path).is_root() {
+                Some(format!("${variable} needs an entity path"))
+            } else {
+                None
+            }
+        })
+    };
+
+    ui.add_space(4.0);
+    let response = ui.add_enabled(error.is_none(), egui::Button::new("Save template"));
+    if let Some(error) = &error {
+        ui.weak(error);
+    }
+
+    response.clicked().then(|| BlueprintTemplateAction::Save {
+        name: name.trim().to_owned(),
+        variables: variables
+            .iter()
+            .map(|(variable, path)| (variable.clone(), EntityPath::parse_forgiving(path)))
+            .collect(),
+    })
+}
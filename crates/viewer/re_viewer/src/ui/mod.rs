@@ -7,7 +7,13 @@ mod welcome_screen;
 pub(crate) mod memory_panel;
 mod settings_screen;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod blueprint_templates_modal;
+
 pub use recordings_panel::recordings_panel_ui;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use blueprint_templates_modal::{BlueprintTemplateAction, BlueprintTemplatesModal};
 // ----
 
 pub(crate) use {
@@ -56,6 +56,9 @@ impl App {
 
         UICommand::SaveBlueprint.menu_button_ui(ui, &self.command_sender);
 
+        #[cfg(not(target_arch = "wasm32"))]
+        UICommand::BlueprintTemplates.menu_button_ui(ui, &self.command_sender);
+
         UICommand::CloseCurrentRecording.menu_button_ui(ui, &self.command_sender);
 
         ui.add_space(SPACING);
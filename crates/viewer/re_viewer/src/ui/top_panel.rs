@@ -10,7 +10,7 @@ use re_viewer_context::StoreContext;
 use crate::{app_blueprint::AppBlueprint, App};
 
 pub fn top_panel(
-    frame: &eframe::Frame,
+    wgpu_render_state: Option<&egui_wgpu::RenderState>,
     app: &mut App,
     app_blueprint: &AppBlueprint<'_>,
     store_context: Option<&StoreContext<'_>>,
@@ -51,7 +51,7 @@ pub fn top_panel(
 
             if show_content {
                 top_bar_ui(
-                    frame,
+                    wgpu_render_state,
                     app,
                     app_blueprint,
                     store_context,
@@ -77,18 +77,20 @@ pub fn top_panel(
 }
 
 fn top_bar_ui(
-    frame: &eframe::Frame,
+    wgpu_render_state: Option<&egui_wgpu::RenderState>,
     app: &mut App,
     app_blueprint: &AppBlueprint<'_>,
     store_context: Option<&StoreContext<'_>>,
     ui: &mut egui::Ui,
     gpu_resource_stats: &WgpuResourcePoolStatistics,
 ) {
-    app.rerun_menu_button_ui(frame.wgpu_render_state(), store_context, ui);
+    app.rerun_menu_button_ui(wgpu_render_state, store_context, ui);
 
     ui.add_space(12.0);
     website_link_ui(ui);
 
+    custom_buttons_ui(ui, app);
+
     if app.app_options().show_metrics && !app.is_screenshotting() {
         ui.separator();
         frame_time_label_ui(ui, app);
@@ -116,7 +118,7 @@ fn top_bar_ui(
             connection_status_ui(ui, app.msg_receive_set());
         }
 
-        if let Some(wgpu) = frame.wgpu_render_state() {
+        if let Some(wgpu) = wgpu_render_state {
             let info = wgpu.adapter.get_info();
             if info.device_type == wgpu::DeviceType::Cpu {
                 // TODO(#4304): replace with a panel showing recent log messages
@@ -319,6 +321,32 @@ fn panel_buttons_r2l(app: &mut App, app_blueprint: &AppBlueprint<'_>, ui: &mut e
 }
 
 /// Shows clickable website link as an image (text doesn't look as nice)
+/// The buttons the viewer was started with, see [`crate::StartupOptions::custom_buttons`].
+fn custom_buttons_ui(ui: &mut egui::Ui, app: &mut App) {
+    if app.custom_buttons().is_empty() || app.is_screenshotting() {
+        return;
+    }
+
+    ui.separator();
+
+    let mut pressed_button = None;
+    for button in app.custom_buttons() {
+        if ui
+            .button(button)
+            .on_hover_text(
+                "Tell the connected SDKs that this button was pressed, and what is selected",
+            )
+            .clicked()
+        {
+            pressed_button = Some(button.clone());
+        }
+    }
+
+    if let Some(button) = pressed_button {
+        app.press_custom_button(button);
+    }
+}
+
 fn website_link_ui(ui: &mut egui::Ui) {
     let desired_height = ui.max_rect().height();
     let desired_height = desired_height.at_most(20.0);
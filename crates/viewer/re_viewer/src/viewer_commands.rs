@@ -0,0 +1,126 @@
+//! Letting SDKs remote control the viewer, via the message proxies we receive data from.
+
+use ahash::HashMap;
+
+use re_log_types::{LogMsg, ViewerCommand};
+use re_smart_channel::ReceiveSet;
+
+use crate::viewer_events::message_proxy_urls;
+
+/// Receives [`ViewerCommand`]s from every message proxy we are currently receiving data from.
+#[derive(Default)]
+pub struct ViewerCommandReceiver {
+    /// One receiver per message proxy url.
+    ///
+    /// Receivers are kept around even once disconnected, so that we don't keep
+    /// reconnecting to a message proxy that doesn't support viewer commands.
+    receivers: HashMap<String, std::sync::mpsc::Receiver<ViewerCommand>>,
+}
+
+impl ViewerCommandReceiver {
+    /// Called once per frame, returns all commands received since the last call.
+    pub fn update(
+        &mut self,
+        rx: &ReceiveSet<LogMsg>,
+        egui_ctx: &egui::Context,
+    ) -> Vec<ViewerCommand> {
+        re_tracing::profile_function!();
+
+        let urls = message_proxy_urls(rx);
+
+        self.receivers.retain(|url, _| urls.contains(url));
+
+        for url in urls {
+            if self.receivers.contains_key(&url) {
+                continue;
+            }
+
+            let egui_ctx = egui_ctx.clone();
+            let waker = Box::new(move || egui_ctx.request_repaint());
+
+            match re_grpc_client::message_proxy::viewer_commands(&url, Some(waker)) {
+                Ok(receiver) => {
+                    self.receivers.insert(url, receiver);
+                }
+                Err(err) => {
+                    re_log::warn_once!("Cannot receive viewer commands: {err}");
+                }
+            }
+        }
+
+        self.receivers
+            .values()
+            .flat_map(|receiver| receiver.try_iter())
+            .collect()
+    }
+}
+
+/// Resolves a file path sent with a [`ViewerCommand`] to a path inside the viewer's working directory.
+///
+/// Anyone who can send commands to the message proxy can send them to us,
+/// so they must not read or write files anywhere else.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn sandboxed_path(path: &str) -> anyhow::Result<std::path::PathBuf> {
+    let sandbox = std::env::current_dir()?.canonicalize()?;
+    sandboxed_path_in(&sandbox, std::path::Path::new(path))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn sandboxed_path_in(
+    sandbox: &std::path::Path,
+    path: &std::path::Path,
+) -> anyhow::Result<std::path::PathBuf> {
+    use std::path::Component;
+
+    let is_relative = path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    anyhow::ensure!(
+        is_relative && path.file_name().is_some(),
+        "Only relative paths inside the viewer's working directory are allowed, got {path:?}"
+    );
+
+    // Symlinks could still lead elsewhere, so check where the deepest existing part of the path points.
+    let resolved = sandbox.join(path);
+    if let Some(existing) = resolved.ancestors().find(|ancestor| ancestor.exists()) {
+        anyhow::ensure!(
+            existing.canonicalize()?.starts_with(sandbox),
+            "{path:?} leads outside of the viewer's working directory"
+        );
+    }
+
+    Ok(resolved)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::path::Path;
+
+    use super::sandboxed_path_in;
+
+    #[test]
+    fn test_sandboxed_path() {
+        let sandbox = std::env::temp_dir().canonicalize().unwrap();
+
+        assert_eq!(
+            sandboxed_path_in(&sandbox, Path::new("screenshot.png")).unwrap(),
+            sandbox.join("screenshot.png")
+        );
+        assert_eq!(
+            sandboxed_path_in(&sandbox, Path::new("./new_dir/screenshot.png")).unwrap(),
+            sandbox.join("./new_dir/screenshot.png")
+        );
+
+        for path in [
+            "",
+            "/etc/passwd",
+            "../screenshot.png",
+            "a/../../screenshot.png",
+        ] {
+            assert!(
+                sandboxed_path_in(&sandbox, Path::new(path)).is_err(),
+                "{path:?} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! Publishing what the user is doing back to the SDKs we receive data from.
+
+use ahash::HashMap;
+
+use re_log_types::{
+    LogMsg, StoreId, TimeInt, Timeline, ViewerEvent, ViewerEventItem, ViewerEventKind,
+};
+use re_smart_channel::{ReceiveSet, SmartChannelSource};
+use re_viewer_context::{ApplicationSelectionState, Item, ItemCollection, PlayState, TimeControl};
+
+/// How often we publish time updates while the time cursor is moving on its own, e.g. during playback.
+///
+/// Every other change is published right away.
+const PLAYBACK_TIME_UPDATE_INTERVAL: web_time::Duration = web_time::Duration::from_millis(100);
+
+/// How long we wait before trying again to connect a publisher that failed to connect.
+///
+/// Doubled with every failed attempt, up to [`MAX_PUBLISHER_RETRY_DELAY`].
+const MIN_PUBLISHER_RETRY_DELAY: web_time::Duration = web_time::Duration::from_secs(1);
+
+/// The longest we wait before trying again to connect a publisher.
+const MAX_PUBLISHER_RETRY_DELAY: web_time::Duration = web_time::Duration::from_secs(60);
+
+/// Something that wants to know about every [`ViewerEvent`], e.g. the TCP server SDKs log to.
+pub type ViewerEventSink = Box<dyn Fn(&ViewerEvent) + Send>;
+
+/// Publishes [`ViewerEvent`]s over a connection we receive data from.
+enum ViewerEventPublisher {
+    #[cfg(feature = "grpc")]
+    MessageProxy(re_grpc_client::message_proxy::ViewerEventPublisher),
+
+    WebSocket(re_ws_comms::ViewerEventPublisher),
+}
+
+impl ViewerEventPublisher {
+    fn new(source: &SmartChannelSource) -> Option<anyhow::Result<Self>> {
+        match source {
+            #[cfg(feature = "grpc")]
+            SmartChannelSource::MessageProxy { url } => Some(
+                re_grpc_client::message_proxy::ViewerEventPublisher::new(url)
+                    .map(Self::MessageProxy)
+                    .map_err(Into::into),
+            ),
+
+            SmartChannelSource::WsClient { ws_server_url } => {
+                Some(re_ws_comms::ViewerEventPublisher::new(ws_server_url).map(Self::WebSocket))
+            }
+
+            _ => None,
+        }
+    }
+
+    fn publish(&mut self, event: &ViewerEvent) {
+        match self {
+            #[cfg(feature = "grpc")]
+            Self::MessageProxy(publisher) => publisher.publish(event.clone()),
+
+            Self::WebSocket(publisher) => publisher.publish(event),
+        }
+    }
+}
+
+/// When to try again to connect a publisher whose last attempt failed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PublisherRetry {
+    retry_at: web_time::Instant,
+    delay: web_time::Duration,
+}
+
+impl PublisherRetry {
+    /// The retry after another failed attempt at `now`, doubling the delay of the previous one.
+    fn after_failure(previous: Option<&Self>, now: web_time::Instant) -> Self {
+        let delay = previous.map_or(MIN_PUBLISHER_RETRY_DELAY, |previous| {
+            (previous.delay * 2).min(MAX_PUBLISHER_RETRY_DELAY)
+        });
+        Self {
+            retry_at: now + delay,
+            delay,
+        }
+    }
+}
+
+/// Sends [`ViewerEvent`]s back over every connection we are currently receiving data from,
+/// and to all registered [`ViewerEventSink`]s.
+#[derive(Default)]
+pub struct ViewerEventSender {
+    /// One publisher per source url.
+    publishers: HashMap<String, ViewerEventPublisher>,
+
+    /// Source urls whose publisher failed to connect, and when to try again.
+    failed_publishers: HashMap<String, PublisherRetry>,
+
+    sinks: Vec<ViewerEventSink>,
+
+    /// Custom buttons that were pressed since the last update.
+    pressed_buttons: Vec<String>,
+
+    last_selection: Vec<ViewerEventItem>,
+    last_hover: Vec<ViewerEventItem>,
+    last_time_update: Option<(Timeline, TimeInt, bool)>,
+    last_time_update_sent_at: Option<web_time::Instant>,
+}
+
+impl ViewerEventSender {
+    /// Called once per frame, after the UI ran.
+    ///
+    /// Compares the selection, hover and time state with what was last published,
+    /// and publishes an event for everything that changed.
+    pub fn update(
+        &mut self,
+        rx: &ReceiveSet<LogMsg>,
+        store_id: &StoreId,
+        selection_state: &ApplicationSelectionState,
+        time_ctrl: Option<&TimeControl>,
+    ) {
+        re_tracing::profile_function!();
+
+        self.update_publishers(rx);
+
+        if self.publishers.is_empty() && self.sinks.is_empty() {
+            self.pressed_buttons.clear();
+            return;
+        }
+
+        let mut events = Vec::new();
+
+        let selection = event_items(selection_state.selected_items());
+        if selection != self.last_selection {
+            self.last_selection.clone_from(&selection);
+            events.push(ViewerEventKind::SelectionChange {
+                items: selection.clone(),
+            });
+        }
+
+        let hover = event_items(selection_state.hovered_items());
+        if hover != self.last_hover {
+            self.last_hover.clone_from(&hover);
+            events.push(ViewerEventKind::HoverChange { items: hover });
+        }
+
+        if let Some(time_update) = time_ctrl.and_then(time_update) {
+            if self.should_publish_time_update(time_update) {
+                let (timeline, time, playing) = time_update;
+                self.last_time_update = Some(time_update);
+                self.last_time_update_sent_at = Some(web_time::Instant::now());
+                events.push(ViewerEventKind::TimeUpdate {
+                    timeline,
+                    time,
+                    playing,
+                });
+            }
+        }
+
+        for button in self.pressed_buttons.drain(..) {
+            events.push(ViewerEventKind::ButtonPress {
+                button,
+                items: selection.clone(),
+            });
+        }
+
+        for kind in events {
+            let event = ViewerEvent {
+                store_id: store_id.clone(),
+                kind,
+            };
+            for publisher in self.publishers.values_mut() {
+                publisher.publish(&event);
+            }
+            for sink in &self.sinks {
+                sink(&event);
+            }
+        }
+    }
+
+    /// Also send all events to this sink, from now on.
+    pub fn add_sink(&mut self, sink: ViewerEventSink) {
+        self.sinks.push(sink);
+    }
+
+    /// Publish that the user pressed one of the custom buttons, with the next [`Self::update`].
+    pub fn press_button(&mut self, button: String) {
+        self.pressed_buttons.push(button);
+    }
+
+    /// Keep one publisher per connection we are receiving data from.
+    fn update_publishers(&mut self, rx: &ReceiveSet<LogMsg>) {
+        let sources = rx.sources();
+        let urls: Vec<String> = sources.iter().map(|source| source.to_string()).collect();
+
+        self.publishers.retain(|url, _| urls.contains(url));
+        self.failed_publishers.retain(|url, _| urls.contains(url));
+
+        let now = web_time::Instant::now();
+        for (source, url) in sources.iter().zip(urls) {
+            if self.publishers.contains_key(&url) {
+                continue;
+            }
+            let previous_retry = self.failed_publishers.get(&url).copied();
+            if previous_retry.is_some_and(|retry| now < retry.retry_at) {
+                continue;
+            }
+
+            match ViewerEventPublisher::new(source) {
+                Some(Ok(publisher)) => {
+                    self.failed_publishers.remove(&url);
+                    self.publishers.insert(url, publisher);
+                }
+                Some(Err(err)) => {
+                    let retry = PublisherRetry::after_failure(previous_retry.as_ref(), now);
+                    if previous_retry.is_none() {
+                        re_log::warn!(
+                            "Cannot publish viewer events to {url}, retrying in {:?}: {err}",
+                            retry.delay
+                        );
+                    } else {
+                        re_log::debug!(
+                            "Cannot publish viewer events to {url}, retrying in {:?}: {err}",
+                            retry.delay
+                        );
+                    }
+                    self.failed_publishers.insert(url, retry);
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn should_publish_time_update(&self, time_update: (Timeline, TimeInt, bool)) -> bool {
+        let Some(last_time_update) = self.last_time_update else {
+            return true;
+        };
+
+        if last_time_update == time_update {
+            return false;
+        }
+
+        let (timeline, _, playing) = time_update;
+        let (last_timeline, _, last_playing) = last_time_update;
+        let only_time_moved = timeline == last_timeline && playing == last_playing;
+
+        // Pausing, switching timelines or scrubbing always goes out right away,
+        // so subscribers never miss where the time cursor ended up.
+        if !only_time_moved || !playing {
+            return true;
+        }
+
+        self.last_time_update_sent_at.map_or(true, |sent_at| {
+            PLAYBACK_TIME_UPDATE_INTERVAL <= sent_at.elapsed()
+        })
+    }
+}
+
+/// The urls of all message proxies we are currently receiving data from.
+#[cfg(feature = "grpc")]
+pub fn message_proxy_urls(rx: &ReceiveSet<LogMsg>) -> Vec<String> {
+    rx.sources()
+        .iter()
+        .filter_map(|source| match &**source {
+            SmartChannelSource::MessageProxy { url } => Some(url.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn time_update(time_ctrl: &TimeControl) -> Option<(Timeline, TimeInt, bool)> {
+    let time = time_ctrl.time_int()?;
+    let playing = time_ctrl.play_state() != PlayState::Paused;
+    Some((*time_ctrl.timeline(), time, playing))
+}
+
+/// The entities and instances in `items`, without duplicates.
+///
+/// Items that don't refer to data (e.g. views or containers) are ignored.
+fn event_items(items: &ItemCollection) -> Vec<ViewerEventItem> {
+    let mut event_items: Vec<ViewerEventItem> = Vec::new();
+
+    for item in items.iter_items() {
+        let event_item = match item {
+            Item::InstancePath(instance_path) | Item::DataResult(_, instance_path) => {
+                ViewerEventItem {
+                    entity_path: instance_path.entity_path.clone(),
+                    instance: instance_path.instance,
+                }
+            }
+
+            Item::ComponentPath(component_path) => component_path.entity_path.clone().into(),
+
+            Item::AppId(_)
+            | Item::DataSource(_)
+            | Item::StoreId(_)
+            | Item::Container(_)
+            | Item::View(_) => continue,
+        };
+
+        if !event_items.contains(&event_item) {
+            event_items.push(event_item);
+        }
+    }
+
+    event_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn publisher_retry_backs_off() {
+        let now = web_time::Instant::now();
+
+        let first = PublisherRetry::after_failure(None, now);
+        assert_eq!(first.delay, MIN_PUBLISHER_RETRY_DELAY);
+        assert_eq!(first.retry_at, now + MIN_PUBLISHER_RETRY_DELAY);
+
+        let second = PublisherRetry::after_failure(Some(&first), first.retry_at);
+        assert_eq!(second.delay, 2 * MIN_PUBLISHER_RETRY_DELAY);
+        assert_eq!(second.retry_at, first.retry_at + second.delay);
+
+        let mut retry = second;
+        for _ in 0..20 {
+            retry = PublisherRetry::after_failure(Some(&retry), now);
+        }
+        assert_eq!(retry.delay, MAX_PUBLISHER_RETRY_DELAY);
+    }
+}
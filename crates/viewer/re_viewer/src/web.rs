@@ -692,6 +692,7 @@ fn create_app(
         timeline_options: timeline.clone(),
         fullscreen_options: fullscreen.clone(),
         panel_state_overrides: panel_state_overrides.unwrap_or_default().into(),
+        custom_buttons: Vec::new(),
 
         enable_history,
     };
@@ -48,6 +48,17 @@ impl Annotations {
         }
     }
 
+    /// All classes described by the annotation context, sorted by class id.
+    pub fn class_descriptions(&self) -> Vec<&ClassDescription> {
+        let mut classes: Vec<_> = self
+            .class_map
+            .values()
+            .map(|cached| &cached.class_description)
+            .collect();
+        classes.sort_by_key(|class| class.info.id);
+        classes
+    }
+
     #[inline]
     pub fn row_id(&self) -> RowId {
         self.row_id
@@ -64,12 +64,30 @@ pub enum SystemCommand {
     /// [`crate::ViewerContext::save_blueprint_archetype`] or similar.
     UpdateBlueprint(StoreId, Vec<Chunk>),
 
+    /// Append data to a store, usually the active recording.
+    ///
+    /// Used for data created from within the viewer, e.g. exported measurements.
+    AppendToStore(StoreId, Vec<Chunk>),
+
+    /// Edit a recording from within the viewer, e.g. to fix up a wrongly logged value.
+    ///
+    /// Unlike [`Self::AppendToStore`], the edit can be undone.
+    ///
+    /// Instead of using this directly, consider using [`crate::ViewerContext::edit_recording_array`].
+    EditRecording(StoreId, Vec<Chunk>),
+
     UndoBlueprint {
         blueprint_id: StoreId,
     },
     RedoBlueprint {
         blueprint_id: StoreId,
     },
+    UndoRecordingEdit {
+        recording_id: StoreId,
+    },
+    RedoRecordingEdit {
+        recording_id: StoreId,
+    },
 
     /// Drop a specific entity from a store.
     ///
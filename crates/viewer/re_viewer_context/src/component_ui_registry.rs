@@ -505,6 +505,29 @@ impl ComponentUiRegistry {
         blueprint_write_path: &EntityPath,
         component_name: ComponentName,
         allow_multiline: bool,
+    ) -> bool {
+        self.try_show_edit_ui_with(
+            ctx,
+            ui,
+            raw_current_value,
+            component_name,
+            allow_multiline,
+            |updated| ctx.save_blueprint_array(blueprint_write_path, component_name, updated),
+        )
+    }
+
+    /// Tries to show a UI for editing a component, and calls `on_edit` with the edited value.
+    ///
+    /// Like [`Self::try_show_edit_ui`], but leaves it up to the caller where the edited value is
+    /// written to, e.g. to the recording rather than to the blueprint.
+    pub fn try_show_edit_ui_with(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        raw_current_value: &dyn arrow::array::Array,
+        component_name: ComponentName,
+        allow_multiline: bool,
+        on_edit: impl FnOnce(arrow::array::ArrayRef),
     ) -> bool {
         re_tracing::profile_function!(component_name.full_name());
 
@@ -521,7 +544,7 @@ impl ComponentUiRegistry {
         };
         if let Some(edit_or_view) = edit_or_view {
             if let Some(updated) = (*edit_or_view)(ctx, ui, raw_current_value, EditOrView::Edit) {
-                ctx.save_blueprint_array(blueprint_write_path, component_name, updated);
+                on_edit(updated);
             }
             return true;
         }
@@ -149,6 +149,8 @@ pub fn render_image(
         pixels_per_point,
         outline_config: None,
         blend_with_background: false,
+        lens_distortion: None,
+        clip_planes: Vec::new(),
     };
 
     let mut view_builder = ViewBuilder::new(render_ctx, target_config);
@@ -75,17 +75,18 @@ pub use self::{
     typed_entity_collections::{
         IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, VisualizableEntities,
     },
-    undo::BlueprintUndoState,
+    undo::{BlueprintUndoState, RecordingEditError, RecordingUndoState},
     utils::{auto_color_egui, auto_color_for_entity_path, level_to_rich_text},
     view::{
-        DataBasedVisualizabilityFilter, DataResult, IdentifiedViewSystem,
-        OptionalViewEntityHighlight, OverridePath, PerSystemDataResults, PerSystemEntities,
-        PropertyOverrides, RecommendedView, SmallVisualizerSet, SystemExecutionOutput, ViewClass,
-        ViewClassExt, ViewClassLayoutPriority, ViewClassRegistry, ViewClassRegistryError,
-        ViewContext, ViewContextCollection, ViewContextSystem, ViewEntityHighlight, ViewHighlights,
-        ViewOutlineMasks, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt, ViewStates,
-        ViewSystemExecutionError, ViewSystemIdentifier, ViewSystemRegistrator,
-        VisualizableFilterContext, VisualizerCollection, VisualizerQueryInfo, VisualizerSystem,
+        ComparedSystemExecutionOutput, DataBasedVisualizabilityFilter, DataResult,
+        IdentifiedViewSystem, OptionalViewEntityHighlight, OverridePath, PerSystemDataResults,
+        PerSystemEntities, PropertyOverrides, RecommendedView, SmallVisualizerSet,
+        SystemExecutionOutput, ViewClass, ViewClassExt, ViewClassLayoutPriority, ViewClassRegistry,
+        ViewClassRegistryError, ViewContext, ViewContextCollection, ViewContextSystem,
+        ViewEntityHighlight, ViewHighlights, ViewOutlineMasks, ViewQuery, ViewSpawnHeuristics,
+        ViewState, ViewStateExt, ViewStates, ViewSystemExecutionError, ViewSystemIdentifier,
+        ViewSystemRegistrator, VisualizableFilterContext, VisualizerCollection,
+        VisualizerQueryInfo, VisualizerSystem,
     },
     viewer_context::{RecordingConfig, ViewerContext},
     visitor_flow_control::VisitorControlFlow,
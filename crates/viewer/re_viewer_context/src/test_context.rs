@@ -7,7 +7,7 @@ use parking_lot::Mutex;
 
 use re_chunk::{Chunk, ChunkBuilder};
 use re_chunk_store::LatestAtQuery;
-use re_entity_db::EntityDb;
+use re_entity_db::{EntityDb, StoreBundle};
 use re_log_types::{EntityPath, StoreId, StoreKind, Timeline};
 use re_types_core::reflection::Reflection;
 
@@ -32,6 +32,10 @@ use crate::{
 pub struct TestContext {
     pub recording_store: EntityDb,
     pub blueprint_store: EntityDb,
+
+    /// Other recordings that are loaded alongside [`Self::recording_store`].
+    pub store_bundle: StoreBundle,
+
     pub view_class_registry: ViewClassRegistry,
     pub selection_state: ApplicationSelectionState,
     pub recording_config: RecordingConfig,
@@ -75,6 +79,7 @@ impl Default for TestContext {
         Self {
             recording_store,
             blueprint_store,
+            store_bundle: Default::default(),
             view_class_registry: Default::default(),
             selection_state: Default::default(),
             recording_config,
@@ -251,7 +256,7 @@ impl TestContext {
             blueprint: &self.blueprint_store,
             default_blueprint: None,
             recording: &self.recording_store,
-            bundle: &Default::default(),
+            bundle: &self.store_bundle,
             caches: &Default::default(),
             hub: &Default::default(),
             should_enable_heuristics: false,
@@ -376,6 +381,17 @@ impl TestContext {
                     }
                 }
 
+                SystemCommand::AppendToStore(store_id, chunks)
+                | SystemCommand::EditRecording(store_id, chunks) => {
+                    assert_eq!(store_id, self.recording_store.store_id());
+
+                    for chunk in chunks {
+                        self.recording_store
+                            .add_chunk(&Arc::new(chunk))
+                            .expect("Appending to the recording chunk store failed");
+                    }
+                }
+
                 SystemCommand::DropEntity(store_id, entity_path) => {
                     assert_eq!(store_id, self.blueprint_store.store_id());
                     self.blueprint_store
@@ -408,6 +424,8 @@ impl TestContext {
                 | SystemCommand::CloseStore(_)
                 | SystemCommand::UndoBlueprint { .. }
                 | SystemCommand::RedoBlueprint { .. }
+                | SystemCommand::UndoRecordingEdit { .. }
+                | SystemCommand::RedoRecordingEdit { .. }
                 | SystemCommand::CloseAllRecordings => handled = false,
 
                 #[cfg(debug_assertions)]
@@ -1,8 +1,9 @@
-use std::collections::BTreeSet;
+use std::{collections::BTreeSet, sync::Arc};
 
-use re_chunk::{LatestAtQuery, TimeInt};
+use re_chunk::{Chunk, LatestAtQuery, RowId, TimeInt};
 use re_entity_db::EntityDb;
-use re_log_types::ResolvedTimeRange;
+use re_log_types::{EntityPath, ResolvedTimeRange};
+use re_types::ComponentName;
 
 use crate::blueprint_timeline;
 
@@ -56,6 +57,12 @@ impl BlueprintUndoState {
         }
     }
 
+    /// The blueprint time that is currently in effect, i.e. taking undo into account.
+    pub fn current_time(&self, blueprint_db: &EntityDb) -> TimeInt {
+        self.current_time
+            .unwrap_or_else(|| max_blueprint_time(blueprint_db))
+    }
+
     /// If set, everything after this time is in "redo-space" (futurum).
     /// If `None`, there is no undo-buffer.
     pub fn redo_time(&self) -> Option<TimeInt> {
@@ -141,6 +148,206 @@ impl BlueprintUndoState {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// Why an edit was rejected by [`RecordingUndoState::add_edit`].
+#[derive(thiserror::Error, Debug)]
+pub enum RecordingEditError {
+    #[error("Edits of {0} have to be logged at a time: static data can't be undone")]
+    StaticEdit(EntityPath),
+
+    #[error("{component_name} of {entity_path} is static, which hides any edit logged at a time")]
+    EditShadowedByStaticData {
+        entity_path: EntityPath,
+        component_name: ComponentName,
+    },
+}
+
+/// One undoable edit of a recording, see [`RecordingUndoState`].
+struct RecordingEdit {
+    /// The blueprint time at which the edit was made.
+    ///
+    /// Used to interleave recording edits with blueprint edits when undoing.
+    blueprint_time: TimeInt,
+
+    /// Everything that was written to the recording as part of this edit.
+    chunks: Vec<Arc<Chunk>>,
+}
+
+impl RecordingEdit {
+    fn row_ids(&self) -> BTreeSet<RowId> {
+        self.chunks
+            .iter()
+            .flat_map(|chunk| chunk.row_ids())
+            .collect()
+    }
+}
+
+/// Undo and redo for data that was written to a recording from within the viewer.
+///
+/// Unlike the blueprint, a recording doesn't keep a timeline of its own edits, so we keep the
+/// written chunks around instead: undoing drops their rows from the recording again, and redoing
+/// adds them back.
+///
+/// Shares the undo/redo commands with [`BlueprintUndoState`]: whichever edit is most recent
+/// gets undone first, see [`Self::undo_is_next`] and [`Self::redo_is_next`].
+#[derive(Default)]
+pub struct RecordingUndoState {
+    undo_stack: Vec<RecordingEdit>,
+    redo_stack: Vec<RecordingEdit>,
+
+    /// Is the most recent edit still ongoing?
+    ///
+    /// When the user drags a slider, we get a new row on each frame.
+    /// All of those should be undone at once, just like for the blueprint.
+    is_editing: bool,
+}
+
+impl RecordingUndoState {
+    /// Writes the given chunks to the recording, and remembers them so they can be undone.
+    ///
+    /// `blueprint_time` is the [`BlueprintUndoState::current_time`] of the active blueprint.
+    ///
+    /// Static data can't be edited: undoing would have to bring back the overwritten static
+    /// value, and temporal edits of a static component would never show up.
+    /// Nothing is written if any of the chunks would touch static data.
+    pub fn add_edit(
+        &mut self,
+        recording: &mut EntityDb,
+        blueprint_time: TimeInt,
+        chunks: Vec<Chunk>,
+    ) -> Result<(), RecordingEditError> {
+        {
+            let engine = recording.storage_engine();
+            let store = engine.store();
+            for chunk in &chunks {
+                let entity_path = chunk.entity_path();
+                if chunk.is_static() {
+                    return Err(RecordingEditError::StaticEdit(entity_path.clone()));
+                }
+                if let Some(component_name) = chunk.component_names().find(|component_name| {
+                    store.entity_has_static_component(entity_path, component_name)
+                }) {
+                    return Err(RecordingEditError::EditShadowedByStaticData {
+                        entity_path: entity_path.clone(),
+                        component_name,
+                    });
+                }
+            }
+        }
+
+        let mut added = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let chunk = Arc::new(chunk);
+            match recording.add_chunk(&chunk) {
+                Ok(_store_events) => added.push(chunk),
+                Err(err) => {
+                    re_log::warn_once!("Failed to edit {}: {err}", recording.store_id());
+                }
+            }
+        }
+        if added.is_empty() {
+            return Ok(());
+        }
+
+        self.redo_stack.clear();
+
+        if self.is_editing {
+            if let Some(edit) = self.undo_stack.last_mut() {
+                edit.chunks.extend(added);
+                return Ok(());
+            }
+        }
+
+        self.is_editing = true;
+        self.undo_stack.push(RecordingEdit {
+            blueprint_time,
+            chunks: added,
+        });
+
+        Ok(())
+    }
+
+    /// Should the next undo revert a recording edit rather than a blueprint edit?
+    pub fn undo_is_next(
+        &self,
+        blueprint_undo_state: Option<&BlueprintUndoState>,
+        blueprint_db: &EntityDb,
+    ) -> bool {
+        let Some(edit) = self.undo_stack.last() else {
+            return false;
+        };
+
+        let blueprint_time = blueprint_undo_state.map_or_else(
+            || max_blueprint_time(blueprint_db),
+            |undo_state| undo_state.current_time(blueprint_db),
+        );
+
+        // No blueprint edits happened since this recording edit, or they have all been undone.
+        blueprint_time <= edit.blueprint_time
+    }
+
+    /// Should the next redo restore a recording edit rather than a blueprint edit?
+    pub fn redo_is_next(&self, blueprint_undo_state: Option<&BlueprintUndoState>) -> bool {
+        let Some(edit) = self.redo_stack.last() else {
+            return false;
+        };
+
+        match blueprint_undo_state.and_then(|undo_state| undo_state.redo_time()) {
+            // The blueprint is already at the time this recording edit was made.
+            Some(blueprint_time) => edit.blueprint_time <= blueprint_time,
+
+            // Nothing to redo in the blueprint.
+            None => true,
+        }
+    }
+
+    pub fn undo(&mut self, recording: &mut EntityDb) {
+        let Some(edit) = self.undo_stack.pop() else {
+            re_log::debug!("Nothing to undo");
+            return;
+        };
+
+        re_log::trace!("Undo recording edit");
+        let events = recording.drop_rows(&edit.row_ids());
+        re_log::trace!(
+            "{} chunks affected when undoing a recording edit",
+            events.len()
+        );
+
+        self.is_editing = false;
+        self.redo_stack.push(edit);
+    }
+
+    pub fn redo(&mut self, recording: &mut EntityDb) {
+        let Some(edit) = self.redo_stack.pop() else {
+            re_log::debug!("Nothing to redo");
+            return;
+        };
+
+        re_log::trace!("Redo recording edit");
+        for chunk in &edit.chunks {
+            if let Err(err) = recording.add_chunk(chunk) {
+                re_log::warn_once!("Failed to redo edit of {}: {err}", recording.store_id());
+            }
+        }
+
+        self.undo_stack.push(edit);
+    }
+
+    // Call each frame
+    pub fn update(&mut self, egui_ctx: &egui::Context) {
+        if !is_interacting(egui_ctx) {
+            self.is_editing = false;
+        }
+
+        // Don't store too many undo-points:
+        if MAX_UNDOS < self.undo_stack.len() {
+            self.undo_stack.drain(..self.undo_stack.len() - MAX_UNDOS);
+        }
+    }
+}
+
 fn max_blueprint_time(blueprint_db: &EntityDb) -> TimeInt {
     blueprint_db
         .time_histogram(&blueprint_timeline())
@@ -159,3 +366,181 @@ fn is_interacting(egui_ctx: &egui::Context) -> bool {
             || is_zooming
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{example_components::MyPoint, StoreId, StoreKind, TimePoint, Timeline};
+    use re_types::Component as _;
+
+    use super::*;
+
+    fn points_at(time_point: TimePoint, point: MyPoint) -> Chunk {
+        Chunk::builder("points".into())
+            .with_component_batch(RowId::new(), time_point, &[point])
+            .build()
+            .unwrap()
+    }
+
+    fn point_at(recording: &EntityDb, timeline: Timeline, time: i64) -> Option<MyPoint> {
+        recording
+            .latest_at_component::<MyPoint>(&"points".into(), &LatestAtQuery::new(timeline, time))
+            .map(|(_index, point)| point)
+    }
+
+    struct TestStores {
+        recording: EntityDb,
+        blueprint: EntityDb,
+        timeline: Timeline,
+    }
+
+    impl TestStores {
+        /// A recording with a single point at time zero.
+        fn new() -> Self {
+            let timeline = Timeline::new_sequence("frame");
+            let mut recording = EntityDb::new(StoreId::random(StoreKind::Recording));
+            recording
+                .add_chunk(&Arc::new(points_at(
+                    TimePoint::default().with(timeline, 0),
+                    MyPoint::new(0.0, 0.0),
+                )))
+                .unwrap();
+
+            Self {
+                recording,
+                blueprint: EntityDb::new(StoreId::random(StoreKind::Blueprint)),
+                timeline,
+            }
+        }
+
+        fn edit(&mut self, undo_state: &mut RecordingUndoState, time: i64, point: MyPoint) {
+            undo_state
+                .add_edit(
+                    &mut self.recording,
+                    TimeInt::ZERO,
+                    vec![points_at(
+                        TimePoint::default().with(self.timeline, time),
+                        point,
+                    )],
+                )
+                .unwrap();
+        }
+
+        fn point_at(&self, time: i64) -> Option<MyPoint> {
+            point_at(&self.recording, self.timeline, time)
+        }
+    }
+
+    #[test]
+    fn edit_undo_redo() {
+        let mut stores = TestStores::new();
+        let mut undo_state = RecordingUndoState::default();
+        assert!(!undo_state.undo_is_next(None, &stores.blueprint));
+
+        stores.edit(&mut undo_state, 1, MyPoint::new(1.0, 1.0));
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(1.0, 1.0)));
+        assert!(undo_state.undo_is_next(None, &stores.blueprint));
+        assert!(!undo_state.redo_is_next(None));
+
+        undo_state.undo(&mut stores.recording);
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(0.0, 0.0)));
+        assert!(!undo_state.undo_is_next(None, &stores.blueprint));
+        assert!(undo_state.redo_is_next(None));
+
+        undo_state.redo(&mut stores.recording);
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(1.0, 1.0)));
+        assert!(undo_state.undo_is_next(None, &stores.blueprint));
+        assert!(!undo_state.redo_is_next(None));
+
+        // A new edit clears the redo stack.
+        undo_state.undo(&mut stores.recording);
+        stores.edit(&mut undo_state, 2, MyPoint::new(2.0, 2.0));
+        assert!(!undo_state.redo_is_next(None));
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(0.0, 0.0)));
+        assert_eq!(stores.point_at(2), Some(MyPoint::new(2.0, 2.0)));
+    }
+
+    #[test]
+    fn undo_after_new_data_arrived() {
+        let mut stores = TestStores::new();
+        let mut undo_state = RecordingUndoState::default();
+
+        stores.edit(&mut undo_state, 1, MyPoint::new(1.0, 1.0));
+
+        // More data streams in after the edit, and likely gets compacted together with it.
+        for time in [2, 3] {
+            stores
+                .recording
+                .add_chunk(&Arc::new(points_at(
+                    TimePoint::default().with(stores.timeline, time),
+                    MyPoint::new(10.0, time as f32),
+                )))
+                .unwrap();
+        }
+
+        // Only the edit is undone, the new data stays.
+        undo_state.undo(&mut stores.recording);
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(0.0, 0.0)));
+        assert_eq!(stores.point_at(2), Some(MyPoint::new(10.0, 2.0)));
+        assert_eq!(stores.point_at(3), Some(MyPoint::new(10.0, 3.0)));
+
+        undo_state.redo(&mut stores.recording);
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(1.0, 1.0)));
+        assert_eq!(stores.point_at(3), Some(MyPoint::new(10.0, 3.0)));
+    }
+
+    #[test]
+    fn ongoing_interaction_is_undone_at_once() {
+        let mut stores = TestStores::new();
+        let mut undo_state = RecordingUndoState::default();
+
+        // E.g. dragging a slider writes a new row each frame.
+        stores.edit(&mut undo_state, 1, MyPoint::new(1.0, 1.0));
+        stores.edit(&mut undo_state, 1, MyPoint::new(2.0, 2.0));
+
+        // No pointer is down anymore: the interaction ended.
+        undo_state.update(&egui::Context::default());
+        stores.edit(&mut undo_state, 1, MyPoint::new(3.0, 3.0));
+
+        undo_state.undo(&mut stores.recording);
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(2.0, 2.0)));
+
+        undo_state.undo(&mut stores.recording);
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(0.0, 0.0)));
+        assert!(!undo_state.undo_is_next(None, &stores.blueprint));
+    }
+
+    #[test]
+    fn static_data_is_not_edited() {
+        let mut stores = TestStores::new();
+        let mut undo_state = RecordingUndoState::default();
+
+        let static_edit = points_at(TimePoint::default(), MyPoint::new(1.0, 1.0));
+        assert!(matches!(
+            undo_state.add_edit(&mut stores.recording, TimeInt::ZERO, vec![static_edit]),
+            Err(RecordingEditError::StaticEdit(_))
+        ));
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(0.0, 0.0)));
+
+        // Static data hides all temporal data of the same component.
+        stores
+            .recording
+            .add_chunk(&Arc::new(points_at(
+                TimePoint::default(),
+                MyPoint::new(5.0, 5.0),
+            )))
+            .unwrap();
+        let temporal_edit = points_at(
+            TimePoint::default().with(stores.timeline, 1),
+            MyPoint::new(1.0, 1.0),
+        );
+        assert!(matches!(
+            undo_state.add_edit(&mut stores.recording, TimeInt::ZERO, vec![temporal_edit]),
+            Err(RecordingEditError::EditShadowedByStaticData { component_name, .. })
+                if component_name == MyPoint::name()
+        ));
+
+        assert!(!undo_state.undo_is_next(None, &stores.blueprint));
+        assert_eq!(stores.point_at(1), Some(MyPoint::new(5.0, 5.0)));
+    }
+}
@@ -15,4 +15,22 @@ pub struct SystemExecutionOutput {
     //                This would allow to run the wgpu command buffer buildup in parallel.
     //                (This implies that we'd pass out the readily built command buffer here instead of drawables.)
     pub draw_data: Vec<re_renderer::QueueableDrawData>,
+
+    /// Output of running the same systems on the recording this view is compared against.
+    ///
+    /// Only set for views that overlay the compared recording,
+    /// see [`crate::ViewClass::overlays_compared_recording`].
+    pub compared: Option<Box<ComparedSystemExecutionOutput>>,
+}
+
+/// Output of view system execution on a recording that is compared against the active recording.
+pub struct ComparedSystemExecutionOutput {
+    /// The compared recording.
+    pub recording_id: re_log_types::StoreId,
+
+    /// Added to all times of the compared recording to line them up with the active recording.
+    pub time_offset: i64,
+
+    /// Executed systems, queried at the current time minus [`Self::time_offset`].
+    pub output: SystemExecutionOutput,
 }
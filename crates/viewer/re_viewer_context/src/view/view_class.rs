@@ -55,7 +55,7 @@ pub trait ViewClass: Send + Sync {
     /// User-facing name of this view class.
     ///
     /// Used for UI display.
-    fn display_name(&self) -> &'static str;
+    fn display_name(&self) -> &str;
 
     /// Icon used to identify this view class.
     fn icon(&self) -> &'static re_ui::Icon {
@@ -98,6 +98,14 @@ pub trait ViewClass: Send + Sync {
         false
     }
 
+    /// Controls whether a compared recording is drawn on top of the active recording.
+    ///
+    /// If false, a view that is compared against another recording shows that recording instead.
+    /// See [`re_types::blueprint::archetypes::RecordingComparison`].
+    fn overlays_compared_recording(&self) -> bool {
+        false
+    }
+
     /// Default query range for this view.
     //TODO(#6918): also provide ViewerContext and ViewId, to enable reading view properties.
     fn default_query_range(&self, _state: &dyn ViewState) -> QueryRange {
@@ -176,15 +176,27 @@ pub struct ViewClassRegistry {
 impl ViewClassRegistry {
     /// Adds a new view class.
     ///
-    /// Fails if a view class with the same name was already registered.
+    /// Fails if a view class with the same identifier was already registered.
     pub fn add_class<T: ViewClass + Default + 'static>(
         &mut self,
     ) -> Result<(), ViewClassRegistryError> {
-        let class = Box::<T>::default();
+        self.add_dynamic_class(T::identifier(), Box::<T>::default())
+    }
 
+    /// Adds a view class that is only known at runtime, e.g. because it was loaded from a plugin.
+    ///
+    /// Unlike with [`Self::add_class`], the identifier is not taken from [`ViewClass::identifier`],
+    /// so that a single type can back several view classes.
+    ///
+    /// Fails if a view class with the same identifier was already registered.
+    pub fn add_dynamic_class(
+        &mut self,
+        identifier: ViewClassIdentifier,
+        class: Box<dyn ViewClass>,
+    ) -> Result<(), ViewClassRegistryError> {
         let mut registrator = ViewSystemRegistrator {
             registry: self,
-            identifier: T::identifier(),
+            identifier,
             context_systems: Default::default(),
             visualizers: Default::default(),
         };
@@ -247,7 +259,7 @@ impl ViewClassRegistry {
     /// Returns the user-facing name for the given view class.
     ///
     /// If the class is unknown, returns a placeholder name.
-    pub fn display_name(&self, name: ViewClassIdentifier) -> &'static str {
+    pub fn display_name(&self, name: ViewClassIdentifier) -> &str {
         self.view_classes
             .get(&name)
             .map_or("<unknown view class>", |boxed| boxed.class.display_name())
@@ -2,16 +2,19 @@ use ahash::HashMap;
 use arrow::array::ArrayRef;
 use parking_lot::RwLock;
 
+use re_chunk::{Chunk, RowId};
 use re_chunk_store::LatestAtQuery;
 use re_entity_db::entity_db::EntityDb;
+use re_log_types::{EntityPath, TimePoint};
 use re_query::StorageEngineReadGuard;
+use re_types::ComponentDescriptor;
 
 use crate::drag_and_drop::DragAndDropPayload;
 use crate::{
     query_context::DataQueryResult, AppOptions, ApplicationSelectionState, Caches, CommandSender,
     ComponentUiRegistry, DragAndDropManager, IndicatedEntities, ItemCollection,
-    MaybeVisualizableEntities, PerVisualizer, StoreContext, SystemCommandSender as _, TimeControl,
-    ViewClassRegistry, ViewId,
+    MaybeVisualizableEntities, PerVisualizer, StoreContext, SystemCommand,
+    SystemCommandSender as _, TimeControl, ViewClassRegistry, ViewId,
 };
 
 /// Common things needed by many parts of the viewer.
@@ -137,6 +140,47 @@ impl ViewerContext<'_> {
         self.rec_cfg.time_ctrl.read().current_query()
     }
 
+    /// Writes a new value for a component of the active recording, at the current time.
+    ///
+    /// The edit can be undone, see [`crate::RecordingUndoState`].
+    /// Does nothing if there is no current time.
+    /// Edits of static components are rejected with an error, see [`crate::RecordingEditError`].
+    pub fn edit_recording_array(
+        &self,
+        entity_path: &EntityPath,
+        component_descr: ComponentDescriptor,
+        array: ArrayRef,
+    ) {
+        let (timeline, time) = {
+            let time_ctrl = self.rec_cfg.time_ctrl.read();
+            let Some(time) = time_ctrl.time_int() else {
+                return;
+            };
+            (*time_ctrl.timeline(), time)
+        };
+
+        let chunk = match Chunk::builder(entity_path.clone())
+            .with_row(
+                RowId::new(),
+                TimePoint::default().with(timeline, time),
+                [(component_descr, array)],
+            )
+            .build()
+        {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                re_log::error_once!("Failed to create Chunk for recording edit: {}", err);
+                return;
+            }
+        };
+
+        self.command_sender
+            .send_system(SystemCommand::EditRecording(
+                self.recording_id(),
+                vec![chunk],
+            ));
+    }
+
     /// Consistently handle the selection, hover, drag start interactions for a given set of items.
     ///
     /// The `draggable` parameter controls whether a drag can be initiated from this item. When a UI
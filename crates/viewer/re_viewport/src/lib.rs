@@ -5,6 +5,7 @@
 // TODO(#6330): remove unwrap()
 #![allow(clippy::unwrap_used)]
 
+mod recording_comparison;
 mod system_execution;
 mod view_highlights;
 mod viewport_ui;
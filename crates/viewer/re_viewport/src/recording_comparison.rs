@@ -0,0 +1,331 @@
+use ahash::HashMap;
+
+use re_log_types::{StoreId, StoreKind, TimeReal};
+use re_types::blueprint::{
+    archetypes::RecordingComparison,
+    components::{ComparedRecording, TimeOffset},
+};
+use re_viewer_context::{
+    DataQueryResult, IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, RecordingConfig,
+    StoreContext, ViewId, ViewState, ViewStates, ViewerContext,
+};
+use re_viewport_blueprint::{ViewBlueprint, ViewProperty};
+
+/// The recording a view is compared against, see [`RecordingComparison`].
+///
+/// Holds everything needed to run a view on the compared recording instead of the active one.
+pub struct ComparedRecordingContext<'a> {
+    store_context: StoreContext<'a>,
+
+    /// Time control of the active recording, shifted back by [`Self::time_offset`].
+    rec_cfg: RecordingConfig,
+
+    maybe_visualizable_entities_per_visualizer: PerVisualizer<MaybeVisualizableEntities>,
+    indicated_entities_per_visualizer: PerVisualizer<IndicatedEntities>,
+
+    /// The query of the view, executed on the compared recording.
+    query_results: HashMap<ViewId, DataQueryResult>,
+
+    /// Added to all times of the compared recording to line them up with the active recording.
+    pub time_offset: i64,
+}
+
+impl<'a> ComparedRecordingContext<'a> {
+    /// Looks up the recording the given view is compared against, and runs the view's query on it.
+    ///
+    /// Returns `None` if the view isn't compared against anything, or if the compared recording isn't loaded.
+    pub fn for_view(
+        ctx: &'a ViewerContext<'_>,
+        view: &ViewBlueprint,
+        view_state: &dyn ViewState,
+    ) -> Option<Self> {
+        re_tracing::profile_function!();
+
+        let property = ViewProperty::from_archetype::<RecordingComparison>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view.id,
+        );
+
+        let recording_id = property
+            .component_or_empty::<ComparedRecording>()
+            .ok()
+            .flatten()?;
+        let store_id = StoreId::from_string(StoreKind::Recording, recording_id.as_str().to_owned());
+        if store_id == ctx.recording_id() {
+            return None;
+        }
+        let recording = ctx.store_context.bundle.get(&store_id)?;
+
+        let time_offset = property
+            .component_or_empty::<TimeOffset>()
+            .ok()
+            .flatten()
+            .map_or(0, |offset| offset.0 .0);
+
+        let mut time_ctrl = ctx.rec_cfg.time_ctrl.read().clone();
+        if let Some(time) = time_ctrl.time() {
+            time_ctrl.set_time(time - TimeReal::from(time_offset));
+        }
+
+        let store_context = StoreContext {
+            app_id: ctx.store_context.app_id.clone(),
+            blueprint: ctx.store_context.blueprint,
+            default_blueprint: ctx.store_context.default_blueprint,
+            recording,
+            bundle: ctx.store_context.bundle,
+            caches: ctx.store_context.caches,
+            hub: ctx.store_context.hub,
+            should_enable_heuristics: ctx.store_context.should_enable_heuristics,
+        };
+
+        let view_class_registry = ctx.view_class_registry;
+        let maybe_visualizable_entities_per_visualizer =
+            view_class_registry.maybe_visualizable_entities_for_visualizer_systems(&store_id);
+        let indicated_entities_per_visualizer =
+            view_class_registry.indicated_entities_per_visualizer(&store_id);
+
+        let visualizable_entities = view
+            .class(view_class_registry)
+            .determine_visualizable_entities(
+                &maybe_visualizable_entities_per_visualizer,
+                recording,
+                &view_class_registry.new_visualizer_collection(view.class_identifier()),
+                &view.space_origin,
+            );
+        let mut query_result = view.contents.execute_query(
+            &store_context,
+            view_class_registry,
+            ctx.blueprint_query,
+            view.id,
+            &visualizable_entities,
+        );
+        view.contents
+            .build_resolver(
+                view_class_registry,
+                view,
+                &maybe_visualizable_entities_per_visualizer,
+                &visualizable_entities,
+                &indicated_entities_per_visualizer,
+            )
+            .update_overrides(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                time_ctrl.timeline(),
+                view_class_registry,
+                &mut query_result,
+                view_state,
+            );
+
+        Some(Self {
+            store_context,
+            rec_cfg: RecordingConfig {
+                time_ctrl: time_ctrl.into(),
+            },
+            maybe_visualizable_entities_per_visualizer,
+            indicated_entities_per_visualizer,
+            query_results: std::iter::once((view.id, query_result)).collect(),
+            time_offset,
+        })
+    }
+
+    /// The compared recording.
+    pub fn recording_id(&self) -> StoreId {
+        self.store_context.recording.store_id()
+    }
+
+    /// The query of the view, executed on the compared recording.
+    pub fn query_result(&self, view_id: ViewId) -> Option<&DataQueryResult> {
+        self.query_results.get(&view_id)
+    }
+
+    /// A copy of `ctx` that has the compared recording as its active recording.
+    ///
+    /// Caches are shared with the active recording, their entries are keyed by row ids which are unique across recordings.
+    pub fn viewer_ctx<'b>(&'b self, ctx: &'b ViewerContext<'_>) -> ViewerContext<'b> {
+        ViewerContext {
+            store_context: &self.store_context,
+            maybe_visualizable_entities_per_visualizer: &self
+                .maybe_visualizable_entities_per_visualizer,
+            indicated_entities_per_visualizer: &self.indicated_entities_per_visualizer,
+            query_results: &self.query_results,
+            rec_cfg: &self.rec_cfg,
+            ..*ctx
+        }
+    }
+}
+
+/// The recordings that the given views are compared against, for the current frame.
+pub fn compared_recordings_for_views<'a>(
+    ctx: &'a ViewerContext<'_>,
+    views: impl IntoIterator<Item = &'a ViewBlueprint>,
+    view_states: &mut ViewStates,
+) -> HashMap<ViewId, ComparedRecordingContext<'a>> {
+    views
+        .into_iter()
+        .filter_map(|view| {
+            let view_state =
+                view_states.get_mut_or_create(view.id, view.class(ctx.view_class_registry));
+            let compared_recording = ComparedRecordingContext::for_view(ctx, view, view_state)?;
+            Some((view.id, compared_recording))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk::{Chunk, RowId};
+    use re_entity_db::EntityDb;
+    use re_log_types::{EntityPath, TimePoint};
+    use re_types::archetypes::TextDocument;
+    use re_view_text_document::TextDocumentView;
+    use re_viewer_context::{test_context::TestContext, RecommendedView, ViewClass as _, ViewId};
+    use re_viewport_blueprint::{test_context_ext::TestContextExt as _, ViewportBlueprint};
+
+    use super::*;
+
+    const COMPARED_RECORDING: &str = "compared";
+
+    fn log_document(db: &mut EntityDb, entity_path: &str, timepoint: TimePoint) {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_archetype(RowId::new(), timepoint, &TextDocument::new(entity_path))
+            .build()
+            .unwrap();
+        db.add_chunk(&Arc::new(chunk)).unwrap();
+    }
+
+    /// A text document view over an active recording and a second, loaded recording.
+    fn setup() -> (TestContext, ViewId) {
+        let mut test_context = TestContext::default();
+
+        // Register the view class first, so that its visualizers see the logged entities.
+        test_context.register_view_class::<TextDocumentView>();
+
+        let timeline = test_context.active_timeline();
+        log_document(
+            &mut test_context.recording_store,
+            "active",
+            [(timeline, 15)].into(),
+        );
+
+        let mut compared = EntityDb::new(StoreId::from_string(
+            StoreKind::Recording,
+            COMPARED_RECORDING.to_owned(),
+        ));
+        log_document(&mut compared, "compared", [(timeline, 10)].into());
+        test_context.store_bundle.insert(compared);
+
+        test_context.recording_config.time_ctrl.write().set_time(15);
+
+        let view_id = test_context.setup_viewport_blueprint(|_ctx, blueprint| {
+            let view = ViewBlueprint::new(TextDocumentView::identifier(), RecommendedView::root());
+            let view_id = view.id;
+            blueprint.add_views(std::iter::once(view), None, None);
+            view_id
+        });
+
+        (test_context, view_id)
+    }
+
+    fn compare(test_context: &mut TestContext, view_id: ViewId, recording: &str, time_offset: i64) {
+        test_context.run_in_egui_central_panel(|ctx, _ui| {
+            let property = ViewProperty::from_archetype::<RecordingComparison>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                view_id,
+            );
+            property.save_blueprint_component(ctx, &ComparedRecording::from(recording));
+            property.save_blueprint_component(ctx, &TimeOffset::from(time_offset));
+        });
+        test_context.handle_system_commands();
+    }
+
+    /// Runs `func` with the compared recording of the view, if any.
+    fn with_compared_recording(
+        test_context: &TestContext,
+        view_id: ViewId,
+        mut func: impl FnMut(&ViewerContext<'_>, Option<ComparedRecordingContext<'_>>),
+    ) {
+        let viewport = ViewportBlueprint::try_from_db(
+            &test_context.blueprint_store,
+            &test_context.blueprint_query,
+        );
+        let view = viewport.view(&view_id).unwrap();
+
+        test_context.run_in_egui_central_panel(|ctx, _ui| {
+            let view_state = view.class(ctx.view_class_registry).new_state();
+            func(
+                ctx,
+                ComparedRecordingContext::for_view(ctx, view, view_state.as_ref()),
+            );
+        });
+    }
+
+    #[test]
+    fn not_compared() {
+        let (test_context, view_id) = setup();
+
+        with_compared_recording(&test_context, view_id, |_ctx, compared_recording| {
+            assert!(compared_recording.is_none());
+        });
+    }
+
+    #[test]
+    fn compared_with_itself() {
+        let (mut test_context, view_id) = setup();
+        let recording_id = test_context.recording_store.store_id();
+        compare(&mut test_context, view_id, recording_id.as_str(), 0);
+
+        with_compared_recording(&test_context, view_id, |_ctx, compared_recording| {
+            assert!(compared_recording.is_none());
+        });
+    }
+
+    #[test]
+    fn compared_with_unknown_recording() {
+        let (mut test_context, view_id) = setup();
+        compare(&mut test_context, view_id, "unknown", 0);
+
+        with_compared_recording(&test_context, view_id, |_ctx, compared_recording| {
+            assert!(compared_recording.is_none());
+        });
+    }
+
+    #[test]
+    fn compared_with_other_recording() {
+        let (mut test_context, view_id) = setup();
+        compare(&mut test_context, view_id, COMPARED_RECORDING, 5);
+
+        with_compared_recording(&test_context, view_id, |ctx, compared_recording| {
+            let compared_recording = compared_recording.unwrap();
+            assert_eq!(
+                compared_recording.recording_id().as_str(),
+                COMPARED_RECORDING
+            );
+            assert_eq!(compared_recording.time_offset, 5);
+
+            // The view's query runs on the compared recording, not on the active one.
+            let query_result = compared_recording.query_result(view_id).unwrap();
+            let compared_entity = query_result
+                .tree
+                .lookup_result_by_path(&EntityPath::from("compared"))
+                .unwrap();
+            assert!(!compared_entity.visualizers.is_empty());
+            assert!(query_result
+                .tree
+                .lookup_result_by_path(&EntityPath::from("active"))
+                .is_none());
+
+            // The compared context sees the compared recording at the shifted time.
+            let compared_ctx = compared_recording.viewer_ctx(ctx);
+            assert_eq!(compared_ctx.recording_id().as_str(), COMPARED_RECORDING);
+            assert_eq!(compared_ctx.current_query().at().as_i64(), 10);
+            assert!(std::ptr::eq(
+                compared_ctx.lookup_query_result(view_id),
+                query_result
+            ));
+        });
+    }
+}
@@ -5,11 +5,12 @@ use rayon::prelude::*;
 
 use re_log_types::TimeInt;
 use re_viewer_context::{
-    PerSystemDataResults, SystemExecutionOutput, ViewContextCollection, ViewId, ViewQuery,
-    ViewState, ViewStates, ViewerContext, VisualizerCollection,
+    ComparedSystemExecutionOutput, DataQueryResult, PerSystemDataResults, SystemExecutionOutput,
+    ViewContextCollection, ViewHighlights, ViewId, ViewQuery, ViewState, ViewStates, ViewerContext,
+    VisualizerCollection,
 };
 
-use crate::view_highlights::highlights_for_view;
+use crate::{recording_comparison::ComparedRecordingContext, view_highlights::highlights_for_view};
 use re_viewport_blueprint::ViewBlueprint;
 
 fn run_view_systems(
@@ -58,37 +59,92 @@ pub fn execute_systems_for_view<'a>(
     view: &'a ViewBlueprint,
     latest_at: TimeInt, // <- TODO(andreas): why not ctx.current_query().at()?
     view_state: &dyn ViewState,
+    compared_recording: Option<&'a ComparedRecordingContext<'_>>,
 ) -> (ViewQuery<'a>, SystemExecutionOutput) {
     re_tracing::profile_function!(view.class_identifier().as_str());
 
     let highlights = highlights_for_view(ctx, view.id);
 
-    let query_result = ctx.lookup_query_result(view.id);
+    let overlays_compared_recording = view
+        .class(ctx.view_class_registry)
+        .overlays_compared_recording();
 
-    let mut per_visualizer_data_results = PerSystemDataResults::default();
-    {
-        re_tracing::profile_scope!("per_system_data_results");
-
-        query_result.tree.visit(&mut |node| {
-            for system in &node.data_result.visualizers {
-                per_visualizer_data_results
-                    .entry(*system)
-                    .or_default()
-                    .push(&node.data_result);
-            }
-            true
-        });
-    }
+    // Views that don't overlay a compared recording show it instead of the active one.
+    let shown_recording = compared_recording.filter(|_| !overlays_compared_recording);
+
+    let query_result = match shown_recording.and_then(|compared| compared.query_result(view.id)) {
+        Some(query_result) => query_result,
+        None => ctx.lookup_query_result(view.id),
+    };
 
-    let query = re_viewer_context::ViewQuery {
+    let mut query = re_viewer_context::ViewQuery {
         view_id: view.id,
         space_origin: &view.space_origin,
-        per_visualizer_data_results,
+        per_visualizer_data_results: per_visualizer_data_results(query_result),
         timeline: *ctx.rec_cfg.time_ctrl.read().timeline(),
         latest_at,
         highlights,
     };
 
+    let output = match shown_recording {
+        Some(compared_recording) => {
+            query.latest_at = shifted_time(latest_at, compared_recording.time_offset);
+            let compared_ctx = compared_recording.viewer_ctx(ctx);
+            run_all_view_systems(&compared_ctx, view, &query, view_state)
+        }
+        None => run_all_view_systems(ctx, view, &query, view_state),
+    };
+
+    let compared = compared_recording
+        .filter(|_| overlays_compared_recording)
+        .and_then(|compared_recording| {
+            re_tracing::profile_scope!("compared recording");
+
+            let compared_query = ViewQuery {
+                view_id: query.view_id,
+                space_origin: query.space_origin,
+                per_visualizer_data_results: per_visualizer_data_results(
+                    compared_recording.query_result(view.id)?,
+                ),
+                timeline: query.timeline,
+                latest_at: shifted_time(latest_at, compared_recording.time_offset),
+                highlights: ViewHighlights::default(),
+            };
+            let compared_ctx = compared_recording.viewer_ctx(ctx);
+
+            Some(Box::new(ComparedSystemExecutionOutput {
+                recording_id: compared_recording.recording_id(),
+                time_offset: compared_recording.time_offset,
+                output: run_all_view_systems(&compared_ctx, view, &compared_query, view_state),
+            }))
+        });
+
+    (query, SystemExecutionOutput { compared, ..output })
+}
+
+/// Groups the data results of a query by the visualizers that should run on them.
+fn per_visualizer_data_results(query_result: &DataQueryResult) -> PerSystemDataResults<'_> {
+    re_tracing::profile_function!();
+
+    let mut per_visualizer_data_results = PerSystemDataResults::default();
+    query_result.tree.visit(&mut |node| {
+        for system in &node.data_result.visualizers {
+            per_visualizer_data_results
+                .entry(*system)
+                .or_default()
+                .push(&node.data_result);
+        }
+        true
+    });
+    per_visualizer_data_results
+}
+
+fn run_all_view_systems(
+    ctx: &ViewerContext<'_>,
+    view: &ViewBlueprint,
+    query: &ViewQuery<'_>,
+    view_state: &dyn ViewState,
+) -> SystemExecutionOutput {
     let mut context_systems = ctx
         .view_class_registry
         .new_context_collection(view.class_identifier());
@@ -99,20 +155,27 @@ pub fn execute_systems_for_view<'a>(
     let draw_data = run_view_systems(
         ctx,
         view,
-        &query,
+        query,
         view_state,
         &mut context_systems,
         &mut view_systems,
     );
 
-    (
-        query,
-        SystemExecutionOutput {
-            view_systems,
-            context_systems,
-            draw_data,
-        },
-    )
+    SystemExecutionOutput {
+        view_systems,
+        context_systems,
+        draw_data,
+        compared: None,
+    }
+}
+
+/// Maps a time of the active recording to the corresponding time of a compared recording.
+fn shifted_time(time: TimeInt, time_offset: i64) -> TimeInt {
+    if time == TimeInt::STATIC {
+        time
+    } else {
+        TimeInt::new_temporal(time.as_i64().saturating_sub(time_offset))
+    }
 }
 
 pub fn execute_systems_for_all_views<'a>(
@@ -120,6 +183,7 @@ pub fn execute_systems_for_all_views<'a>(
     tree: &egui_tiles::Tree<ViewId>,
     views: &'a BTreeMap<ViewId, ViewBlueprint>,
     view_states: &mut ViewStates,
+    compared_recordings: &'a HashMap<ViewId, ComparedRecordingContext<'_>>,
 ) -> HashMap<ViewId, (ViewQuery<'a>, SystemExecutionOutput)> {
     let Some(time_int) = ctx.rec_cfg.time_ctrl.read().time_int() else {
         return Default::default();
@@ -142,7 +206,13 @@ pub fn execute_systems_for_all_views<'a>(
                         return None;
                     };
 
-                    let result = execute_systems_for_view(ctx, view, time_int, view_state);
+                    let result = execute_systems_for_view(
+                        ctx,
+                        view,
+                        time_int,
+                        view_state,
+                        compared_recordings.get(view_id),
+                    );
                     Some((*view_id, result))
                 }),
                 egui_tiles::Tile::Container(_) => None,
@@ -150,3 +220,22 @@ pub fn execute_systems_for_all_views<'a>(
         })
         .collect::<HashMap<_, _>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shifted_time() {
+        assert_eq!(
+            shifted_time(TimeInt::new_temporal(15), 5),
+            TimeInt::new_temporal(10)
+        );
+        assert_eq!(
+            shifted_time(TimeInt::new_temporal(15), -5),
+            TimeInt::new_temporal(20)
+        );
+        assert_eq!(shifted_time(TimeInt::STATIC, 5), TimeInt::STATIC);
+        assert_eq!(shifted_time(TimeInt::MIN, 5), TimeInt::MIN);
+    }
+}
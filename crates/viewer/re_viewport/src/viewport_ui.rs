@@ -16,7 +16,10 @@ use re_viewport_blueprint::{
     create_entity_add_info, ViewBlueprint, ViewportBlueprint, ViewportCommand,
 };
 
-use crate::system_execution::{execute_systems_for_all_views, execute_systems_for_view};
+use crate::{
+    recording_comparison::{compared_recordings_for_views, ComparedRecordingContext},
+    system_execution::{execute_systems_for_all_views, execute_systems_for_view},
+};
 
 // ----------------------------------------------------------------------------
 
@@ -70,8 +73,16 @@ impl ViewportUi {
             blueprint.tree.clone()
         };
 
-        let executed_systems_per_view =
-            execute_systems_for_all_views(ctx, &tree, &blueprint.views, view_states);
+        let compared_recordings =
+            compared_recordings_for_views(ctx, blueprint.views.values(), view_states);
+
+        let executed_systems_per_view = execute_systems_for_all_views(
+            ctx,
+            &tree,
+            &blueprint.views,
+            view_states,
+            &compared_recordings,
+        );
 
         let contents_per_tile_id = blueprint
             .contents_iter()
@@ -89,6 +100,7 @@ impl ViewportUi {
                 viewport_blueprint: blueprint,
                 maximized: &mut maximized,
                 executed_systems_per_view,
+                compared_recordings: &compared_recordings,
                 contents_per_tile_id,
                 edited: false,
                 tile_dropped: false,
@@ -313,6 +325,9 @@ struct TilesDelegate<'a, 'b> {
     /// List of query & system execution results for each view.
     executed_systems_per_view: HashMap<ViewId, (ViewQuery<'a>, SystemExecutionOutput)>,
 
+    /// The recordings that views are compared against.
+    compared_recordings: &'a HashMap<ViewId, ComparedRecordingContext<'a>>,
+
     /// List of contents for each tile id
     contents_per_tile_id: HashMap<egui_tiles::TileId, Contents>,
 
@@ -385,15 +400,29 @@ impl<'a> egui_tiles::Behavior<ViewId> for TilesDelegate<'a, '_> {
             }
 
             let class = view_blueprint.class(self.ctx.view_class_registry);
-            execute_systems_for_view(ctx, view, latest_at, self.view_states.get_mut_or_create(*view_id, class))
+            execute_systems_for_view(
+                ctx,
+                view,
+                latest_at,
+                self.view_states.get_mut_or_create(*view_id, class),
+                self.compared_recordings.get(view_id),
+            )
         });
 
         let class = view_blueprint.class(self.ctx.view_class_registry);
         let view_state = self.view_states.get_mut_or_create(*view_id, class);
 
+        // Views that don't overlay a compared recording show it instead of the active recording.
+        let compared_ctx = self
+            .compared_recordings
+            .get(view_id)
+            .filter(|_| !class.overlays_compared_recording())
+            .map(|compared_recording| compared_recording.viewer_ctx(self.ctx));
+        let ctx = compared_ctx.as_ref().unwrap_or(self.ctx);
+
         ui.scope(|ui| {
             class
-                .ui(self.ctx, ui, view_state, &query, system_output)
+                .ui(ctx, ui, view_state, &query, system_output)
                 .unwrap_or_else(|err| {
                     re_log::error!(
                         "Error in view UI (class: {}, display name: {}): {err}",
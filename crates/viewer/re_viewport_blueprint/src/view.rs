@@ -783,13 +783,15 @@ mod tests {
         let mut view_states = ViewStates::default();
 
         test_ctx.run_in_egui_central_panel(|ctx, _ui| {
+            let view_state =
+                view_states.get_mut_or_create(view.id, view.class(ctx.view_class_registry));
             resolver.update_overrides(
                 ctx.blueprint_db(),
                 ctx.blueprint_query,
                 ctx.rec_cfg.time_ctrl.read().timeline(),
                 ctx.view_class_registry,
                 &mut query_result,
-                &mut view_states,
+                view_state,
             );
         });
 
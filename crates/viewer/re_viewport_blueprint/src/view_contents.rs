@@ -20,7 +20,7 @@ use re_types_core::ComponentName;
 use re_viewer_context::{
     DataQueryResult, DataResult, DataResultHandle, DataResultNode, DataResultTree,
     IndicatedEntities, MaybeVisualizableEntities, OverridePath, PerVisualizer, PropertyOverrides,
-    QueryRange, ViewClassRegistry, ViewId, ViewStates, ViewerContext, VisualizableEntities,
+    QueryRange, ViewClassRegistry, ViewId, ViewState, ViewerContext, VisualizableEntities,
 };
 
 use crate::{ViewBlueprint, ViewProperty};
@@ -599,16 +599,13 @@ impl DataQueryPropertyResolver<'_> {
         active_timeline: &Timeline,
         view_class_registry: &ViewClassRegistry,
         query_result: &mut DataQueryResult,
-        view_states: &mut ViewStates,
+        view_state: &dyn ViewState,
     ) {
         // This is called very frequently, don't put a profile scope here.
 
         if let Some(root) = query_result.tree.root_handle() {
             let recursive_property_overrides = Default::default();
 
-            let class = self.view.class(view_class_registry);
-            let view_state = view_states.get_mut_or_create(self.view.id, class);
-
             let default_query_range = self.view.query_range(
                 blueprint,
                 blueprint_query,
@@ -19,11 +19,13 @@
 #include "archetypes/geo_points.hpp"
 #include "archetypes/graph_edges.hpp"
 #include "archetypes/graph_nodes.hpp"
+#include "archetypes/height_field3d.hpp"
 #include "archetypes/image.hpp"
 #include "archetypes/instance_poses3d.hpp"
 #include "archetypes/line_strips2d.hpp"
 #include "archetypes/line_strips3d.hpp"
 #include "archetypes/mesh3d.hpp"
+#include "archetypes/occupancy_grid.hpp"
 #include "archetypes/pinhole.hpp"
 #include "archetypes/points2d.hpp"
 #include "archetypes/points3d.hpp"
@@ -34,6 +36,9 @@
 #include "archetypes/tensor.hpp"
 #include "archetypes/text_document.hpp"
 #include "archetypes/text_log.hpp"
+#include "archetypes/timeline_event.hpp"
+#include "archetypes/timeline_mapping.hpp"
 #include "archetypes/transform3d.hpp"
 #include "archetypes/video_frame_reference.hpp"
 #include "archetypes/view_coordinates.hpp"
+#include "archetypes/voxel_grid3d.hpp"
@@ -25,12 +25,15 @@ namespace rerun::archetypes {
         archetype.draw_order =
             ComponentBatch::empty<rerun::components::DrawOrder>(Descriptor_draw_order)
                 .value_or_throw();
+        archetype.color_image_reference =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_color_image_reference)
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> DepthImage::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(8);
+        columns.reserve(9);
         if (buffer.has_value()) {
             columns.push_back(
                 ComponentColumn::from_batch_with_lengths(buffer.value(), lengths_).value_or_throw()
@@ -66,6 +69,12 @@ namespace rerun::archetypes {
             columns.push_back(ComponentColumn::from_batch_with_lengths(draw_order.value(), lengths_)
                                   .value_or_throw());
         }
+        if (color_image_reference.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(color_image_reference.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
         columns.push_back(
             ComponentColumn::from_indicators<DepthImage>(static_cast<uint32_t>(lengths_.size()))
                 .value_or_throw()
@@ -95,6 +104,9 @@ namespace rerun::archetypes {
         if (draw_order.has_value()) {
             return columns(std::vector<uint32_t>(draw_order.value().length(), 1));
         }
+        if (color_image_reference.has_value()) {
+            return columns(std::vector<uint32_t>(color_image_reference.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -106,7 +118,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(8);
+        cells.reserve(9);
 
         if (archetype.buffer.has_value()) {
             cells.push_back(archetype.buffer.value());
@@ -129,6 +141,9 @@ namespace rerun {
         if (archetype.draw_order.has_value()) {
             cells.push_back(archetype.draw_order.value());
         }
+        if (archetype.color_image_reference.has_value()) {
+            cells.push_back(archetype.color_image_reference.value());
+        }
         {
             auto result = ComponentBatch::from_indicator<DepthImage>();
             RR_RETURN_NOT_OK(result.error);
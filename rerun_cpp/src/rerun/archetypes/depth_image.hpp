@@ -9,6 +9,7 @@
 #include "../components/colormap.hpp"
 #include "../components/depth_meter.hpp"
 #include "../components/draw_order.hpp"
+#include "../components/entity_path.hpp"
 #include "../components/fill_ratio.hpp"
 #include "../components/image_buffer.hpp"
 #include "../components/image_format.hpp"
@@ -122,6 +123,17 @@ namespace rerun::archetypes {
         /// Objects with higher values are drawn on top of those with lower values.
         std::optional<ComponentBatch> draw_order;
 
+        /// An optional path to a color image that is registered with this depth image.
+        ///
+        /// If set, the points of the point cloud generated from this image are colored by projecting them
+        /// into the referenced `archetypes::Image` or `archetypes::EncodedImage` instead of using the `components::Colormap`.
+        /// If the color image is logged under a different `archetypes::Pinhole` than the depth image,
+        /// the intrinsics of that camera and the transform between the two cameras are taken into account.
+        /// Points that aren't seen by the color camera fall back to the colormap.
+        ///
+        /// TODO(#6744): This applies only to 3D views!
+        std::optional<ComponentBatch> color_image_reference;
+
       public:
         static constexpr const char IndicatorComponentName[] =
             "rerun.components.DepthImageIndicator";
@@ -166,6 +178,11 @@ namespace rerun::archetypes {
             ArchetypeName, "draw_order",
             Loggable<rerun::components::DrawOrder>::Descriptor.component_name
         );
+        /// `ComponentDescriptor` for the `color_image_reference` field.
+        static constexpr auto Descriptor_color_image_reference = ComponentDescriptor(
+            ArchetypeName, "color_image_reference",
+            Loggable<rerun::components::EntityPath>::Descriptor.component_name
+        );
 
       public: // START of extensions from depth_image_ext.cpp:
         /// Constructs image from pointer + resolution, inferring the datatype from the pointer type.
@@ -389,6 +406,41 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// An optional path to a color image that is registered with this depth image.
+        ///
+        /// If set, the points of the point cloud generated from this image are colored by projecting them
+        /// into the referenced `archetypes::Image` or `archetypes::EncodedImage` instead of using the `components::Colormap`.
+        /// If the color image is logged under a different `archetypes::Pinhole` than the depth image,
+        /// the intrinsics of that camera and the transform between the two cameras are taken into account.
+        /// Points that aren't seen by the color camera fall back to the colormap.
+        ///
+        /// TODO(#6744): This applies only to 3D views!
+        DepthImage with_color_image_reference(
+            const rerun::components::EntityPath& _color_image_reference
+        ) && {
+            color_image_reference = ComponentBatch::from_loggable(
+                                        _color_image_reference,
+                                        Descriptor_color_image_reference
+            )
+                                        .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `color_image_reference` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_color_image_reference` should
+        /// be used when logging a single row's worth of data.
+        DepthImage with_many_color_image_reference(
+            const Collection<rerun::components::EntityPath>& _color_image_reference
+        ) && {
+            color_image_reference = ComponentBatch::from_loggable(
+                                        _color_image_reference,
+                                        Descriptor_color_image_reference
+            )
+                                        .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -0,0 +1,319 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/height_field3d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/cell_size.hpp"
+#include "../components/color.hpp"
+#include "../components/colormap.hpp"
+#include "../components/entity_path.hpp"
+#include "../components/image_buffer.hpp"
+#include "../components/image_format.hpp"
+#include "../components/position2d.hpp"
+#include "../components/value_range.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A surface spanned over a regular grid of heights, e.g. a terrain or an elevation map.
+    ///
+    /// The heights are stored like a single-channel image: height `[x, y]` is placed at
+    /// `origin + [x, y] * cell_size` in the XY plane of the entity's local coordinate system,
+    /// and the surface extends from there along +Z.
+    /// Non-finite heights (e.g. NaN) leave holes in the surface.
+    ///
+    /// The surface is shaded and colored either with a single `components::Color`, by height through a
+    /// `components::Colormap`, or with an image draped over it via `components::EntityPath`.
+    /// Logging a new height map every frame is cheap since only the heights are uploaded to the GPU.
+    struct HeightField3D {
+        /// The heights, row by row.
+        std::optional<ComponentBatch> buffer;
+
+        /// The format of the heights.
+        ///
+        /// Must be single-channel.
+        std::optional<ComponentBatch> format;
+
+        /// The distance between neighboring heights along each axis.
+        ///
+        /// If not specified, heights are one unit apart.
+        std::optional<ComponentBatch> cell_size;
+
+        /// The position of height `[0, 0]` in the XY plane.
+        ///
+        /// If not specified, the grid starts at the origin.
+        std::optional<ComponentBatch> origin;
+
+        /// Color of the surface, if neither a colormap nor a texture is used.
+        std::optional<ComponentBatch> color;
+
+        /// If set, the surface is colored by height using this colormap.
+        std::optional<ComponentBatch> colormap;
+
+        /// The range of heights that is mapped to the colormap.
+        ///
+        /// If not specified, the range of the heights is used.
+        std::optional<ComponentBatch> value_range;
+
+        /// Path to an `archetypes::Image` or `archetypes::EncodedImage` that is stretched over the entire surface.
+        ///
+        /// Takes precedence over the color and the colormap.
+        /// The image has to be logged to a different entity than the height field.
+        std::optional<ComponentBatch> texture_reference;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.HeightField3DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.HeightField3D";
+
+        /// `ComponentDescriptor` for the `buffer` field.
+        static constexpr auto Descriptor_buffer = ComponentDescriptor(
+            ArchetypeName, "buffer",
+            Loggable<rerun::components::ImageBuffer>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `format` field.
+        static constexpr auto Descriptor_format = ComponentDescriptor(
+            ArchetypeName, "format",
+            Loggable<rerun::components::ImageFormat>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `cell_size` field.
+        static constexpr auto Descriptor_cell_size = ComponentDescriptor(
+            ArchetypeName, "cell_size",
+            Loggable<rerun::components::CellSize>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `origin` field.
+        static constexpr auto Descriptor_origin = ComponentDescriptor(
+            ArchetypeName, "origin",
+            Loggable<rerun::components::Position2D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `color` field.
+        static constexpr auto Descriptor_color = ComponentDescriptor(
+            ArchetypeName, "color", Loggable<rerun::components::Color>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "colormap",
+            Loggable<rerun::components::Colormap>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `value_range` field.
+        static constexpr auto Descriptor_value_range = ComponentDescriptor(
+            ArchetypeName, "value_range",
+            Loggable<rerun::components::ValueRange>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `texture_reference` field.
+        static constexpr auto Descriptor_texture_reference = ComponentDescriptor(
+            ArchetypeName, "texture_reference",
+            Loggable<rerun::components::EntityPath>::Descriptor.component_name
+        );
+
+      public:
+        HeightField3D() = default;
+        HeightField3D(HeightField3D&& other) = default;
+        HeightField3D(const HeightField3D& other) = default;
+        HeightField3D& operator=(const HeightField3D& other) = default;
+        HeightField3D& operator=(HeightField3D&& other) = default;
+
+        /// Update only some specific fields of a `HeightField3D`.
+        static HeightField3D update_fields() {
+            return HeightField3D();
+        }
+
+        /// Clear all the fields of a `HeightField3D`.
+        static HeightField3D clear_fields();
+
+        /// The heights, row by row.
+        HeightField3D with_buffer(const rerun::components::ImageBuffer& _buffer) && {
+            buffer = ComponentBatch::from_loggable(_buffer, Descriptor_buffer).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `buffer` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_buffer` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_buffer(const Collection<rerun::components::ImageBuffer>& _buffer
+        ) && {
+            buffer = ComponentBatch::from_loggable(_buffer, Descriptor_buffer).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The format of the heights.
+        ///
+        /// Must be single-channel.
+        HeightField3D with_format(const rerun::components::ImageFormat& _format) && {
+            format = ComponentBatch::from_loggable(_format, Descriptor_format).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `format` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_format` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_format(const Collection<rerun::components::ImageFormat>& _format
+        ) && {
+            format = ComponentBatch::from_loggable(_format, Descriptor_format).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The distance between neighboring heights along each axis.
+        ///
+        /// If not specified, heights are one unit apart.
+        HeightField3D with_cell_size(const rerun::components::CellSize& _cell_size) && {
+            cell_size =
+                ComponentBatch::from_loggable(_cell_size, Descriptor_cell_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `cell_size` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_cell_size` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_cell_size(const Collection<rerun::components::CellSize>& _cell_size
+        ) && {
+            cell_size =
+                ComponentBatch::from_loggable(_cell_size, Descriptor_cell_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The position of height `[0, 0]` in the XY plane.
+        ///
+        /// If not specified, the grid starts at the origin.
+        HeightField3D with_origin(const rerun::components::Position2D& _origin) && {
+            origin = ComponentBatch::from_loggable(_origin, Descriptor_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `origin` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_origin` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_origin(const Collection<rerun::components::Position2D>& _origin
+        ) && {
+            origin = ComponentBatch::from_loggable(_origin, Descriptor_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Color of the surface, if neither a colormap nor a texture is used.
+        HeightField3D with_color(const rerun::components::Color& _color) && {
+            color = ComponentBatch::from_loggable(_color, Descriptor_color).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `color` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_color` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_color(const Collection<rerun::components::Color>& _color) && {
+            color = ComponentBatch::from_loggable(_color, Descriptor_color).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// If set, the surface is colored by height using this colormap.
+        HeightField3D with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `colormap` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_colormap` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_colormap(const Collection<rerun::components::Colormap>& _colormap
+        ) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The range of heights that is mapped to the colormap.
+        ///
+        /// If not specified, the range of the heights is used.
+        HeightField3D with_value_range(const rerun::components::ValueRange& _value_range) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `value_range` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_value_range` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_value_range(
+            const Collection<rerun::components::ValueRange>& _value_range
+        ) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Path to an `archetypes::Image` or `archetypes::EncodedImage` that is stretched over the entire surface.
+        ///
+        /// Takes precedence over the color and the colormap.
+        /// The image has to be logged to a different entity than the height field.
+        HeightField3D with_texture_reference(const rerun::components::EntityPath& _texture_reference
+        ) && {
+            texture_reference =
+                ComponentBatch::from_loggable(_texture_reference, Descriptor_texture_reference)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `texture_reference` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_texture_reference` should
+        /// be used when logging a single row's worth of data.
+        HeightField3D with_many_texture_reference(
+            const Collection<rerun::components::EntityPath>& _texture_reference
+        ) && {
+            texture_reference =
+                ComponentBatch::from_loggable(_texture_reference, Descriptor_texture_reference)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::HeightField3D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const archetypes::HeightField3D& archetype
+        );
+    };
+} // namespace rerun
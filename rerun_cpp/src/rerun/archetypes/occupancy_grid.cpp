@@ -0,0 +1,151 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/occupancy_grid.fbs".
+
+#include "occupancy_grid.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    OccupancyGrid OccupancyGrid::clear_fields() {
+        auto archetype = OccupancyGrid();
+        archetype.buffer = ComponentBatch::empty<rerun::components::ImageBuffer>(Descriptor_buffer)
+                               .value_or_throw();
+        archetype.format = ComponentBatch::empty<rerun::components::ImageFormat>(Descriptor_format)
+                               .value_or_throw();
+        archetype.cell_size =
+            ComponentBatch::empty<rerun::components::CellSize>(Descriptor_cell_size)
+                .value_or_throw();
+        archetype.origin = ComponentBatch::empty<rerun::components::Position2D>(Descriptor_origin)
+                               .value_or_throw();
+        archetype.colormap = ComponentBatch::empty<rerun::components::Colormap>(Descriptor_colormap)
+                                 .value_or_throw();
+        archetype.value_range =
+            ComponentBatch::empty<rerun::components::ValueRange>(Descriptor_value_range)
+                .value_or_throw();
+        archetype.opacity =
+            ComponentBatch::empty<rerun::components::Opacity>(Descriptor_opacity).value_or_throw();
+        archetype.draw_order =
+            ComponentBatch::empty<rerun::components::DrawOrder>(Descriptor_draw_order)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> OccupancyGrid::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(9);
+        if (buffer.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(buffer.value(), lengths_).value_or_throw()
+            );
+        }
+        if (format.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(format.value(), lengths_).value_or_throw()
+            );
+        }
+        if (cell_size.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(cell_size.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (origin.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(origin.value(), lengths_).value_or_throw()
+            );
+        }
+        if (colormap.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(colormap.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (value_range.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(value_range.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (opacity.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(opacity.value(), lengths_).value_or_throw()
+            );
+        }
+        if (draw_order.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(draw_order.value(), lengths_)
+                                  .value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<OccupancyGrid>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> OccupancyGrid::columns() {
+        if (buffer.has_value()) {
+            return columns(std::vector<uint32_t>(buffer.value().length(), 1));
+        }
+        if (format.has_value()) {
+            return columns(std::vector<uint32_t>(format.value().length(), 1));
+        }
+        if (cell_size.has_value()) {
+            return columns(std::vector<uint32_t>(cell_size.value().length(), 1));
+        }
+        if (origin.has_value()) {
+            return columns(std::vector<uint32_t>(origin.value().length(), 1));
+        }
+        if (colormap.has_value()) {
+            return columns(std::vector<uint32_t>(colormap.value().length(), 1));
+        }
+        if (value_range.has_value()) {
+            return columns(std::vector<uint32_t>(value_range.value().length(), 1));
+        }
+        if (opacity.has_value()) {
+            return columns(std::vector<uint32_t>(opacity.value().length(), 1));
+        }
+        if (draw_order.has_value()) {
+            return columns(std::vector<uint32_t>(draw_order.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>> AsComponents<archetypes::OccupancyGrid>::serialize(
+        const archetypes::OccupancyGrid& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(9);
+
+        if (archetype.buffer.has_value()) {
+            cells.push_back(archetype.buffer.value());
+        }
+        if (archetype.format.has_value()) {
+            cells.push_back(archetype.format.value());
+        }
+        if (archetype.cell_size.has_value()) {
+            cells.push_back(archetype.cell_size.value());
+        }
+        if (archetype.origin.has_value()) {
+            cells.push_back(archetype.origin.value());
+        }
+        if (archetype.colormap.has_value()) {
+            cells.push_back(archetype.colormap.value());
+        }
+        if (archetype.value_range.has_value()) {
+            cells.push_back(archetype.value_range.value());
+        }
+        if (archetype.opacity.has_value()) {
+            cells.push_back(archetype.opacity.value());
+        }
+        if (archetype.draw_order.has_value()) {
+            cells.push_back(archetype.draw_order.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<OccupancyGrid>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
@@ -0,0 +1,323 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/occupancy_grid.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/cell_size.hpp"
+#include "../components/colormap.hpp"
+#include "../components/draw_order.hpp"
+#include "../components/image_buffer.hpp"
+#include "../components/image_format.hpp"
+#include "../components/opacity.hpp"
+#include "../components/position2d.hpp"
+#include "../components/value_range.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A 2D occupancy grid, e.g. a map built by a robot.
+    ///
+    /// Each cell holds a single value, typically the probability of the cell being occupied.
+    /// Cell `[x, y]` spans from `origin + [x, y] * cell_size` to `origin + [x + 1, y + 1] * cell_size`
+    /// in the local coordinate system of the entity. In 3D views the grid lies in the XY plane.
+    ///
+    /// The cell values are stored like a single-channel image, e.g. an `I8` image following the ROS convention
+    /// of probabilities from 0 to 100, and are mapped to colors through the `components::Colormap`.
+    struct OccupancyGrid {
+        /// The raw cell values, row by row.
+        std::optional<ComponentBatch> buffer;
+
+        /// The format of the cell values.
+        ///
+        /// Must be single-channel.
+        std::optional<ComponentBatch> format;
+
+        /// The size of a single cell along each axis.
+        ///
+        /// If not specified, cells are one unit large.
+        std::optional<ComponentBatch> cell_size;
+
+        /// The position of the corner of cell `[0, 0]`.
+        ///
+        /// If not specified, the grid starts at the origin.
+        std::optional<ComponentBatch> origin;
+
+        /// Colormap to use for mapping cell values to colors.
+        ///
+        /// If not set, the grayscale colormap is used.
+        std::optional<ComponentBatch> colormap;
+
+        /// The range of cell values that is mapped to the colormap.
+        ///
+        /// If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+        /// with the default colormap.
+        std::optional<ComponentBatch> value_range;
+
+        /// Opacity of the grid, useful for layering it over other content.
+        ///
+        /// Defaults to 1.0 (fully opaque).
+        std::optional<ComponentBatch> opacity;
+
+        /// An optional floating point value that specifies the 2D drawing order.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        std::optional<ComponentBatch> draw_order;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.OccupancyGridIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.OccupancyGrid";
+
+        /// `ComponentDescriptor` for the `buffer` field.
+        static constexpr auto Descriptor_buffer = ComponentDescriptor(
+            ArchetypeName, "buffer",
+            Loggable<rerun::components::ImageBuffer>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `format` field.
+        static constexpr auto Descriptor_format = ComponentDescriptor(
+            ArchetypeName, "format",
+            Loggable<rerun::components::ImageFormat>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `cell_size` field.
+        static constexpr auto Descriptor_cell_size = ComponentDescriptor(
+            ArchetypeName, "cell_size",
+            Loggable<rerun::components::CellSize>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `origin` field.
+        static constexpr auto Descriptor_origin = ComponentDescriptor(
+            ArchetypeName, "origin",
+            Loggable<rerun::components::Position2D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "colormap",
+            Loggable<rerun::components::Colormap>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `value_range` field.
+        static constexpr auto Descriptor_value_range = ComponentDescriptor(
+            ArchetypeName, "value_range",
+            Loggable<rerun::components::ValueRange>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `opacity` field.
+        static constexpr auto Descriptor_opacity = ComponentDescriptor(
+            ArchetypeName, "opacity",
+            Loggable<rerun::components::Opacity>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `draw_order` field.
+        static constexpr auto Descriptor_draw_order = ComponentDescriptor(
+            ArchetypeName, "draw_order",
+            Loggable<rerun::components::DrawOrder>::Descriptor.component_name
+        );
+
+      public:
+        OccupancyGrid() = default;
+        OccupancyGrid(OccupancyGrid&& other) = default;
+        OccupancyGrid(const OccupancyGrid& other) = default;
+        OccupancyGrid& operator=(const OccupancyGrid& other) = default;
+        OccupancyGrid& operator=(OccupancyGrid&& other) = default;
+
+        /// Update only some specific fields of a `OccupancyGrid`.
+        static OccupancyGrid update_fields() {
+            return OccupancyGrid();
+        }
+
+        /// Clear all the fields of a `OccupancyGrid`.
+        static OccupancyGrid clear_fields();
+
+        /// The raw cell values, row by row.
+        OccupancyGrid with_buffer(const rerun::components::ImageBuffer& _buffer) && {
+            buffer = ComponentBatch::from_loggable(_buffer, Descriptor_buffer).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `buffer` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_buffer` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_buffer(const Collection<rerun::components::ImageBuffer>& _buffer
+        ) && {
+            buffer = ComponentBatch::from_loggable(_buffer, Descriptor_buffer).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The format of the cell values.
+        ///
+        /// Must be single-channel.
+        OccupancyGrid with_format(const rerun::components::ImageFormat& _format) && {
+            format = ComponentBatch::from_loggable(_format, Descriptor_format).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `format` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_format` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_format(const Collection<rerun::components::ImageFormat>& _format
+        ) && {
+            format = ComponentBatch::from_loggable(_format, Descriptor_format).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The size of a single cell along each axis.
+        ///
+        /// If not specified, cells are one unit large.
+        OccupancyGrid with_cell_size(const rerun::components::CellSize& _cell_size) && {
+            cell_size =
+                ComponentBatch::from_loggable(_cell_size, Descriptor_cell_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `cell_size` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_cell_size` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_cell_size(const Collection<rerun::components::CellSize>& _cell_size
+        ) && {
+            cell_size =
+                ComponentBatch::from_loggable(_cell_size, Descriptor_cell_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The position of the corner of cell `[0, 0]`.
+        ///
+        /// If not specified, the grid starts at the origin.
+        OccupancyGrid with_origin(const rerun::components::Position2D& _origin) && {
+            origin = ComponentBatch::from_loggable(_origin, Descriptor_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `origin` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_origin` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_origin(const Collection<rerun::components::Position2D>& _origin
+        ) && {
+            origin = ComponentBatch::from_loggable(_origin, Descriptor_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Colormap to use for mapping cell values to colors.
+        ///
+        /// If not set, the grayscale colormap is used.
+        OccupancyGrid with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `colormap` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_colormap` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_colormap(const Collection<rerun::components::Colormap>& _colormap
+        ) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The range of cell values that is mapped to the colormap.
+        ///
+        /// If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+        /// with the default colormap.
+        OccupancyGrid with_value_range(const rerun::components::ValueRange& _value_range) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `value_range` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_value_range` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_value_range(
+            const Collection<rerun::components::ValueRange>& _value_range
+        ) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Opacity of the grid, useful for layering it over other content.
+        ///
+        /// Defaults to 1.0 (fully opaque).
+        OccupancyGrid with_opacity(const rerun::components::Opacity& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `opacity` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_opacity` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_opacity(const Collection<rerun::components::Opacity>& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// An optional floating point value that specifies the 2D drawing order.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        OccupancyGrid with_draw_order(const rerun::components::DrawOrder& _draw_order) && {
+            draw_order =
+                ComponentBatch::from_loggable(_draw_order, Descriptor_draw_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `draw_order` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_draw_order` should
+        /// be used when logging a single row's worth of data.
+        OccupancyGrid with_many_draw_order(
+            const Collection<rerun::components::DrawOrder>& _draw_order
+        ) && {
+            draw_order =
+                ComponentBatch::from_loggable(_draw_order, Descriptor_draw_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::OccupancyGrid> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const archetypes::OccupancyGrid& archetype
+        );
+    };
+} // namespace rerun
@@ -23,12 +23,24 @@ namespace rerun::archetypes {
                 Descriptor_image_plane_distance
             )
                 .value_or_throw();
+        archetype.distortion_model = ComponentBatch::empty<rerun::components::LensDistortionModel>(
+                                         Descriptor_distortion_model
+        )
+                                         .value_or_throw();
+        archetype.radial_distortion =
+            ComponentBatch::empty<rerun::components::RadialDistortion>(Descriptor_radial_distortion)
+                .value_or_throw();
+        archetype.tangential_distortion =
+            ComponentBatch::empty<rerun::components::TangentialDistortion>(
+                Descriptor_tangential_distortion
+            )
+                .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> Pinhole::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(5);
+        columns.reserve(8);
         if (image_from_camera.has_value()) {
             columns.push_back(
                 ComponentColumn::from_batch_with_lengths(image_from_camera.value(), lengths_)
@@ -49,6 +61,24 @@ namespace rerun::archetypes {
                     .value_or_throw()
             );
         }
+        if (distortion_model.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(distortion_model.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (radial_distortion.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(radial_distortion.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (tangential_distortion.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(tangential_distortion.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
         columns.push_back(
             ComponentColumn::from_indicators<Pinhole>(static_cast<uint32_t>(lengths_.size()))
                 .value_or_throw()
@@ -69,6 +99,15 @@ namespace rerun::archetypes {
         if (image_plane_distance.has_value()) {
             return columns(std::vector<uint32_t>(image_plane_distance.value().length(), 1));
         }
+        if (distortion_model.has_value()) {
+            return columns(std::vector<uint32_t>(distortion_model.value().length(), 1));
+        }
+        if (radial_distortion.has_value()) {
+            return columns(std::vector<uint32_t>(radial_distortion.value().length(), 1));
+        }
+        if (tangential_distortion.has_value()) {
+            return columns(std::vector<uint32_t>(tangential_distortion.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -80,7 +119,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(5);
+        cells.reserve(8);
 
         if (archetype.image_from_camera.has_value()) {
             cells.push_back(archetype.image_from_camera.value());
@@ -94,6 +133,15 @@ namespace rerun {
         if (archetype.image_plane_distance.has_value()) {
             cells.push_back(archetype.image_plane_distance.value());
         }
+        if (archetype.distortion_model.has_value()) {
+            cells.push_back(archetype.distortion_model.value());
+        }
+        if (archetype.radial_distortion.has_value()) {
+            cells.push_back(archetype.radial_distortion.value());
+        }
+        if (archetype.tangential_distortion.has_value()) {
+            cells.push_back(archetype.tangential_distortion.value());
+        }
         {
             auto result = ComponentBatch::from_indicator<Pinhole>();
             RR_RETURN_NOT_OK(result.error);
@@ -7,8 +7,11 @@
 #include "../component_batch.hpp"
 #include "../component_column.hpp"
 #include "../components/image_plane_distance.hpp"
+#include "../components/lens_distortion_model.hpp"
 #include "../components/pinhole_projection.hpp"
+#include "../components/radial_distortion.hpp"
 #include "../components/resolution.hpp"
+#include "../components/tangential_distortion.hpp"
 #include "../components/view_coordinates.hpp"
 #include "../indicator_component.hpp"
 #include "../result.hpp"
@@ -124,6 +127,23 @@ namespace rerun::archetypes {
         /// This is only used for visualization purposes, and does not affect the projection itself.
         std::optional<ComponentBatch> image_plane_distance;
 
+        /// The lens distortion model, determining how the distortion coefficients are applied.
+        ///
+        /// Defaults to `components::LensDistortionModel::BrownConrady` if any distortion coefficients are set.
+        std::optional<ComponentBatch> distortion_model;
+
+        /// Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+        ///
+        /// For the `components::LensDistortionModel::BrownConrady` model, `k4` is a non-standard `k4·r⁸` term
+        /// which should be left at zero for OpenCV calibrations.
+        /// If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+        std::optional<ComponentBatch> radial_distortion;
+
+        /// Tangential distortion coefficients `[p1, p2]` of the lens.
+        ///
+        /// Only used by the `components::LensDistortionModel::BrownConrady` model.
+        std::optional<ComponentBatch> tangential_distortion;
+
       public:
         static constexpr const char IndicatorComponentName[] = "rerun.components.PinholeIndicator";
 
@@ -152,6 +172,21 @@ namespace rerun::archetypes {
             ArchetypeName, "image_plane_distance",
             Loggable<rerun::components::ImagePlaneDistance>::Descriptor.component_name
         );
+        /// `ComponentDescriptor` for the `distortion_model` field.
+        static constexpr auto Descriptor_distortion_model = ComponentDescriptor(
+            ArchetypeName, "distortion_model",
+            Loggable<rerun::components::LensDistortionModel>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `radial_distortion` field.
+        static constexpr auto Descriptor_radial_distortion = ComponentDescriptor(
+            ArchetypeName, "radial_distortion",
+            Loggable<rerun::components::RadialDistortion>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `tangential_distortion` field.
+        static constexpr auto Descriptor_tangential_distortion = ComponentDescriptor(
+            ArchetypeName, "tangential_distortion",
+            Loggable<rerun::components::TangentialDistortion>::Descriptor.component_name
+        );
 
       public: // START of extensions from pinhole_ext.cpp:
         /// Creates a pinhole from the camera focal length and resolution, both specified in pixels.
@@ -349,6 +384,86 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// The lens distortion model, determining how the distortion coefficients are applied.
+        ///
+        /// Defaults to `components::LensDistortionModel::BrownConrady` if any distortion coefficients are set.
+        Pinhole with_distortion_model(
+            const rerun::components::LensDistortionModel& _distortion_model
+        ) && {
+            distortion_model =
+                ComponentBatch::from_loggable(_distortion_model, Descriptor_distortion_model)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `distortion_model` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_distortion_model` should
+        /// be used when logging a single row's worth of data.
+        Pinhole with_many_distortion_model(
+            const Collection<rerun::components::LensDistortionModel>& _distortion_model
+        ) && {
+            distortion_model =
+                ComponentBatch::from_loggable(_distortion_model, Descriptor_distortion_model)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+        ///
+        /// For the `components::LensDistortionModel::BrownConrady` model, `k4` is a non-standard `k4·r⁸` term
+        /// which should be left at zero for OpenCV calibrations.
+        /// If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+        Pinhole with_radial_distortion(const rerun::components::RadialDistortion& _radial_distortion
+        ) && {
+            radial_distortion =
+                ComponentBatch::from_loggable(_radial_distortion, Descriptor_radial_distortion)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `radial_distortion` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_radial_distortion` should
+        /// be used when logging a single row's worth of data.
+        Pinhole with_many_radial_distortion(
+            const Collection<rerun::components::RadialDistortion>& _radial_distortion
+        ) && {
+            radial_distortion =
+                ComponentBatch::from_loggable(_radial_distortion, Descriptor_radial_distortion)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Tangential distortion coefficients `[p1, p2]` of the lens.
+        ///
+        /// Only used by the `components::LensDistortionModel::BrownConrady` model.
+        Pinhole with_tangential_distortion(
+            const rerun::components::TangentialDistortion& _tangential_distortion
+        ) && {
+            tangential_distortion = ComponentBatch::from_loggable(
+                                        _tangential_distortion,
+                                        Descriptor_tangential_distortion
+            )
+                                        .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `tangential_distortion` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_tangential_distortion` should
+        /// be used when logging a single row's worth of data.
+        Pinhole with_many_tangential_distortion(
+            const Collection<rerun::components::TangentialDistortion>& _tangential_distortion
+        ) && {
+            tangential_distortion = ComponentBatch::from_loggable(
+                                        _tangential_distortion,
+                                        Descriptor_tangential_distortion
+            )
+                                        .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
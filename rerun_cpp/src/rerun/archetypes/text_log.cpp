@@ -14,12 +14,14 @@ namespace rerun::archetypes {
                               .value_or_throw();
         archetype.color =
             ComponentBatch::empty<rerun::components::Color>(Descriptor_color).value_or_throw();
+        archetype.fields = ComponentBatch::empty<rerun::components::TextLogField>(Descriptor_fields)
+                               .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> TextLog::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(4);
+        columns.reserve(5);
         if (text.has_value()) {
             columns.push_back(
                 ComponentColumn::from_batch_with_lengths(text.value(), lengths_).value_or_throw()
@@ -35,6 +37,11 @@ namespace rerun::archetypes {
                 ComponentColumn::from_batch_with_lengths(color.value(), lengths_).value_or_throw()
             );
         }
+        if (fields.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(fields.value(), lengths_).value_or_throw()
+            );
+        }
         columns.push_back(
             ComponentColumn::from_indicators<TextLog>(static_cast<uint32_t>(lengths_.size()))
                 .value_or_throw()
@@ -52,6 +59,9 @@ namespace rerun::archetypes {
         if (color.has_value()) {
             return columns(std::vector<uint32_t>(color.value().length(), 1));
         }
+        if (fields.has_value()) {
+            return columns(std::vector<uint32_t>(fields.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -63,7 +73,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(4);
+        cells.reserve(5);
 
         if (archetype.text.has_value()) {
             cells.push_back(archetype.text.value());
@@ -74,6 +84,9 @@ namespace rerun {
         if (archetype.color.has_value()) {
             cells.push_back(archetype.color.value());
         }
+        if (archetype.fields.has_value()) {
+            cells.push_back(archetype.fields.value());
+        }
         {
             auto result = ComponentBatch::from_indicator<TextLog>();
             RR_RETURN_NOT_OK(result.error);
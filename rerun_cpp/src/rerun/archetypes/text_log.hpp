@@ -8,6 +8,7 @@
 #include "../component_column.hpp"
 #include "../components/color.hpp"
 #include "../components/text.hpp"
+#include "../components/text_log_field.hpp"
 #include "../components/text_log_level.hpp"
 #include "../indicator_component.hpp"
 #include "../result.hpp"
@@ -90,6 +91,12 @@ namespace rerun::archetypes {
         /// Optional color to use for the log line in the Rerun Viewer.
         std::optional<ComponentBatch> color;
 
+        /// Optional key-value fields of a structured log entry.
+        ///
+        /// The Rerun Viewer shows each key as a column of the text log.
+        /// The fields apply to all entries of the same row.
+        std::optional<ComponentBatch> fields;
+
       public:
         static constexpr const char IndicatorComponentName[] = "rerun.components.TextLogIndicator";
 
@@ -111,6 +118,11 @@ namespace rerun::archetypes {
         static constexpr auto Descriptor_color = ComponentDescriptor(
             ArchetypeName, "color", Loggable<rerun::components::Color>::Descriptor.component_name
         );
+        /// `ComponentDescriptor` for the `fields` field.
+        static constexpr auto Descriptor_fields = ComponentDescriptor(
+            ArchetypeName, "fields",
+            Loggable<rerun::components::TextLogField>::Descriptor.component_name
+        );
 
       public:
         TextLog() = default;
@@ -178,6 +190,15 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// Optional key-value fields of a structured log entry.
+        ///
+        /// The Rerun Viewer shows each key as a column of the text log.
+        /// The fields apply to all entries of the same row.
+        TextLog with_fields(const Collection<rerun::components::TextLogField>& _fields) && {
+            fields = ComponentBatch::from_loggable(_fields, Descriptor_fields).value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
@@ -0,0 +1,98 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_event.fbs".
+
+#include "timeline_event.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    TimelineEvent TimelineEvent::clear_fields() {
+        auto archetype = TimelineEvent();
+        archetype.label =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_label).value_or_throw();
+        archetype.level = ComponentBatch::empty<rerun::components::TextLogLevel>(Descriptor_level)
+                              .value_or_throw();
+        archetype.color =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_color).value_or_throw();
+        archetype.duration =
+            ComponentBatch::empty<rerun::components::EventDuration>(Descriptor_duration)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TimelineEvent::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (label.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(label.value(), lengths_).value_or_throw()
+            );
+        }
+        if (level.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(level.value(), lengths_).value_or_throw()
+            );
+        }
+        if (color.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(color.value(), lengths_).value_or_throw()
+            );
+        }
+        if (duration.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(duration.value(), lengths_)
+                                  .value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<TimelineEvent>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> TimelineEvent::columns() {
+        if (label.has_value()) {
+            return columns(std::vector<uint32_t>(label.value().length(), 1));
+        }
+        if (level.has_value()) {
+            return columns(std::vector<uint32_t>(level.value().length(), 1));
+        }
+        if (color.has_value()) {
+            return columns(std::vector<uint32_t>(color.value().length(), 1));
+        }
+        if (duration.has_value()) {
+            return columns(std::vector<uint32_t>(duration.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>> AsComponents<archetypes::TimelineEvent>::serialize(
+        const archetypes::TimelineEvent& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.label.has_value()) {
+            cells.push_back(archetype.label.value());
+        }
+        if (archetype.level.has_value()) {
+            cells.push_back(archetype.level.value());
+        }
+        if (archetype.color.has_value()) {
+            cells.push_back(archetype.color.value());
+        }
+        if (archetype.duration.has_value()) {
+            cells.push_back(archetype.duration.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<TimelineEvent>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
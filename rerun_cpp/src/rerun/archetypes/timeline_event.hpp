@@ -0,0 +1,196 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_event.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/color.hpp"
+#include "../components/event_duration.hpp"
+#include "../components/text.hpp"
+#include "../components/text_log_level.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A discrete event, shown as a marker on the timelines of the Rerun Viewer's time panel.
+    ///
+    /// Use this to highlight important moments of a recording, such as a fault being raised,
+    /// a change of mission phase or an operator intervention.
+    /// Events with a duration are shown as spans starting at the time they are logged at.
+    ///
+    /// The viewer's "next marker" and "previous marker" commands move the time cursor between events.
+    struct TimelineEvent {
+        /// A short description of the event.
+        std::optional<ComponentBatch> label;
+
+        /// The severity of the event.
+        ///
+        /// Events are colored by severity unless a color is specified.
+        std::optional<ComponentBatch> level;
+
+        /// Optional color of the event's marker.
+        std::optional<ComponentBatch> color;
+
+        /// Optional duration of the event, turning its marker into a span.
+        ///
+        /// Measured in the units of the timeline the event is shown on:
+        /// nanoseconds for temporal timelines, and steps for sequence timelines.
+        std::optional<ComponentBatch> duration;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.TimelineEventIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.TimelineEvent";
+
+        /// `ComponentDescriptor` for the `label` field.
+        static constexpr auto Descriptor_label = ComponentDescriptor(
+            ArchetypeName, "label", Loggable<rerun::components::Text>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `level` field.
+        static constexpr auto Descriptor_level = ComponentDescriptor(
+            ArchetypeName, "level",
+            Loggable<rerun::components::TextLogLevel>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `color` field.
+        static constexpr auto Descriptor_color = ComponentDescriptor(
+            ArchetypeName, "color", Loggable<rerun::components::Color>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `duration` field.
+        static constexpr auto Descriptor_duration = ComponentDescriptor(
+            ArchetypeName, "duration",
+            Loggable<rerun::components::EventDuration>::Descriptor.component_name
+        );
+
+      public:
+        TimelineEvent() = default;
+        TimelineEvent(TimelineEvent&& other) = default;
+        TimelineEvent(const TimelineEvent& other) = default;
+        TimelineEvent& operator=(const TimelineEvent& other) = default;
+        TimelineEvent& operator=(TimelineEvent&& other) = default;
+
+        explicit TimelineEvent(rerun::components::Text _label)
+            : label(ComponentBatch::from_loggable(std::move(_label), Descriptor_label)
+                        .value_or_throw()) {}
+
+        /// Update only some specific fields of a `TimelineEvent`.
+        static TimelineEvent update_fields() {
+            return TimelineEvent();
+        }
+
+        /// Clear all the fields of a `TimelineEvent`.
+        static TimelineEvent clear_fields();
+
+        /// A short description of the event.
+        TimelineEvent with_label(const rerun::components::Text& _label) && {
+            label = ComponentBatch::from_loggable(_label, Descriptor_label).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `label` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_label` should
+        /// be used when logging a single row's worth of data.
+        TimelineEvent with_many_label(const Collection<rerun::components::Text>& _label) && {
+            label = ComponentBatch::from_loggable(_label, Descriptor_label).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The severity of the event.
+        ///
+        /// Events are colored by severity unless a color is specified.
+        TimelineEvent with_level(const rerun::components::TextLogLevel& _level) && {
+            level = ComponentBatch::from_loggable(_level, Descriptor_level).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `level` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_level` should
+        /// be used when logging a single row's worth of data.
+        TimelineEvent with_many_level(const Collection<rerun::components::TextLogLevel>& _level
+        ) && {
+            level = ComponentBatch::from_loggable(_level, Descriptor_level).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional color of the event's marker.
+        TimelineEvent with_color(const rerun::components::Color& _color) && {
+            color = ComponentBatch::from_loggable(_color, Descriptor_color).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `color` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_color` should
+        /// be used when logging a single row's worth of data.
+        TimelineEvent with_many_color(const Collection<rerun::components::Color>& _color) && {
+            color = ComponentBatch::from_loggable(_color, Descriptor_color).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional duration of the event, turning its marker into a span.
+        ///
+        /// Measured in the units of the timeline the event is shown on:
+        /// nanoseconds for temporal timelines, and steps for sequence timelines.
+        TimelineEvent with_duration(const rerun::components::EventDuration& _duration) && {
+            duration =
+                ComponentBatch::from_loggable(_duration, Descriptor_duration).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `duration` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_duration` should
+        /// be used when logging a single row's worth of data.
+        TimelineEvent with_many_duration(
+            const Collection<rerun::components::EventDuration>& _duration
+        ) && {
+            duration =
+                ComponentBatch::from_loggable(_duration, Descriptor_duration).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::TimelineEvent> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const archetypes::TimelineEvent& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,100 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_mapping.fbs".
+
+#include "timeline_mapping.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    TimelineMapping TimelineMapping::clear_fields() {
+        auto archetype = TimelineMapping();
+        archetype.source =
+            ComponentBatch::empty<rerun::components::SourceTimeline>(Descriptor_source)
+                .value_or_throw();
+        archetype.target =
+            ComponentBatch::empty<rerun::components::TargetTimeline>(Descriptor_target)
+                .value_or_throw();
+        archetype.offset = ComponentBatch::empty<rerun::components::ClockOffset>(Descriptor_offset)
+                               .value_or_throw();
+        archetype.drift =
+            ComponentBatch::empty<rerun::components::ClockDrift>(Descriptor_drift).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TimelineMapping::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (source.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(source.value(), lengths_).value_or_throw()
+            );
+        }
+        if (target.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(target.value(), lengths_).value_or_throw()
+            );
+        }
+        if (offset.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(offset.value(), lengths_).value_or_throw()
+            );
+        }
+        if (drift.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(drift.value(), lengths_).value_or_throw()
+            );
+        }
+        columns.push_back(ComponentColumn::from_indicators<TimelineMapping>(
+                              static_cast<uint32_t>(lengths_.size())
+        )
+                              .value_or_throw());
+        return columns;
+    }
+
+    Collection<ComponentColumn> TimelineMapping::columns() {
+        if (source.has_value()) {
+            return columns(std::vector<uint32_t>(source.value().length(), 1));
+        }
+        if (target.has_value()) {
+            return columns(std::vector<uint32_t>(target.value().length(), 1));
+        }
+        if (offset.has_value()) {
+            return columns(std::vector<uint32_t>(offset.value().length(), 1));
+        }
+        if (drift.has_value()) {
+            return columns(std::vector<uint32_t>(drift.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>> AsComponents<archetypes::TimelineMapping>::serialize(
+        const archetypes::TimelineMapping& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.source.has_value()) {
+            cells.push_back(archetype.source.value());
+        }
+        if (archetype.target.has_value()) {
+            cells.push_back(archetype.target.value());
+        }
+        if (archetype.offset.has_value()) {
+            cells.push_back(archetype.offset.value());
+        }
+        if (archetype.drift.has_value()) {
+            cells.push_back(archetype.drift.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<TimelineMapping>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
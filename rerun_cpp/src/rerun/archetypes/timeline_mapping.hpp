@@ -0,0 +1,198 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_mapping.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/clock_drift.hpp"
+#include "../components/clock_offset.hpp"
+#include "../components/source_timeline.hpp"
+#include "../components/target_timeline.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Declares how the times of one timeline translate to times on another timeline.
+    ///
+    /// This relates timelines that data was logged on independently, e.g. `frame_nr`, `sensor_time` and `log_time`,
+    /// so that data logged on one of them can be queried at a time expressed on the other.
+    /// The mapping is used in both directions.
+    ///
+    /// If a clock offset or drift is set, the timelines are related by a linear clock model:
+    /// `target = source + offset + drift * source`.
+    ///
+    /// Otherwise the mapping is a correspondence table: every time it is logged with both timelines set,
+    /// it records one pair of corresponding times.
+    /// Times in between two pairs are interpolated linearly, times beyond the first or last pair are extrapolated.
+    struct TimelineMapping {
+        /// The timeline the mapping translates from.
+        std::optional<ComponentBatch> source;
+
+        /// The timeline the mapping translates to.
+        std::optional<ComponentBatch> target;
+
+        /// Constant offset of the linear clock model, in units of the target timeline.
+        std::optional<ComponentBatch> offset;
+
+        /// Drift of the linear clock model, as a fraction of the source time.
+        std::optional<ComponentBatch> drift;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.TimelineMappingIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.TimelineMapping";
+
+        /// `ComponentDescriptor` for the `source` field.
+        static constexpr auto Descriptor_source = ComponentDescriptor(
+            ArchetypeName, "source",
+            Loggable<rerun::components::SourceTimeline>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `target` field.
+        static constexpr auto Descriptor_target = ComponentDescriptor(
+            ArchetypeName, "target",
+            Loggable<rerun::components::TargetTimeline>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `offset` field.
+        static constexpr auto Descriptor_offset = ComponentDescriptor(
+            ArchetypeName, "offset",
+            Loggable<rerun::components::ClockOffset>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `drift` field.
+        static constexpr auto Descriptor_drift = ComponentDescriptor(
+            ArchetypeName, "drift",
+            Loggable<rerun::components::ClockDrift>::Descriptor.component_name
+        );
+
+      public:
+        TimelineMapping() = default;
+        TimelineMapping(TimelineMapping&& other) = default;
+        TimelineMapping(const TimelineMapping& other) = default;
+        TimelineMapping& operator=(const TimelineMapping& other) = default;
+        TimelineMapping& operator=(TimelineMapping&& other) = default;
+
+        explicit TimelineMapping(
+            rerun::components::SourceTimeline _source, rerun::components::TargetTimeline _target
+        )
+            : source(ComponentBatch::from_loggable(std::move(_source), Descriptor_source)
+                         .value_or_throw()),
+              target(ComponentBatch::from_loggable(std::move(_target), Descriptor_target)
+                         .value_or_throw()) {}
+
+        /// Update only some specific fields of a `TimelineMapping`.
+        static TimelineMapping update_fields() {
+            return TimelineMapping();
+        }
+
+        /// Clear all the fields of a `TimelineMapping`.
+        static TimelineMapping clear_fields();
+
+        /// The timeline the mapping translates from.
+        TimelineMapping with_source(const rerun::components::SourceTimeline& _source) && {
+            source = ComponentBatch::from_loggable(_source, Descriptor_source).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `source` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_source` should
+        /// be used when logging a single row's worth of data.
+        TimelineMapping with_many_source(
+            const Collection<rerun::components::SourceTimeline>& _source
+        ) && {
+            source = ComponentBatch::from_loggable(_source, Descriptor_source).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The timeline the mapping translates to.
+        TimelineMapping with_target(const rerun::components::TargetTimeline& _target) && {
+            target = ComponentBatch::from_loggable(_target, Descriptor_target).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `target` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_target` should
+        /// be used when logging a single row's worth of data.
+        TimelineMapping with_many_target(
+            const Collection<rerun::components::TargetTimeline>& _target
+        ) && {
+            target = ComponentBatch::from_loggable(_target, Descriptor_target).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Constant offset of the linear clock model, in units of the target timeline.
+        TimelineMapping with_offset(const rerun::components::ClockOffset& _offset) && {
+            offset = ComponentBatch::from_loggable(_offset, Descriptor_offset).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `offset` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_offset` should
+        /// be used when logging a single row's worth of data.
+        TimelineMapping with_many_offset(const Collection<rerun::components::ClockOffset>& _offset
+        ) && {
+            offset = ComponentBatch::from_loggable(_offset, Descriptor_offset).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Drift of the linear clock model, as a fraction of the source time.
+        TimelineMapping with_drift(const rerun::components::ClockDrift& _drift) && {
+            drift = ComponentBatch::from_loggable(_drift, Descriptor_drift).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `drift` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_drift` should
+        /// be used when logging a single row's worth of data.
+        TimelineMapping with_many_drift(const Collection<rerun::components::ClockDrift>& _drift
+        ) && {
+            drift = ComponentBatch::from_loggable(_drift, Descriptor_drift).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::TimelineMapping> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const archetypes::TimelineMapping& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,155 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/voxel_grid3d.fbs".
+
+#include "voxel_grid3d.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    VoxelGrid3D VoxelGrid3D::clear_fields() {
+        auto archetype = VoxelGrid3D();
+        archetype.voxel_size =
+            ComponentBatch::empty<rerun::components::VoxelSize>(Descriptor_voxel_size)
+                .value_or_throw();
+        archetype.voxel_indices =
+            ComponentBatch::empty<rerun::components::VoxelIndex>(Descriptor_voxel_indices)
+                .value_or_throw();
+        archetype.colors =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_colors).value_or_throw();
+        archetype.dense_values =
+            ComponentBatch::empty<rerun::components::TensorData>(Descriptor_dense_values)
+                .value_or_throw();
+        archetype.values =
+            ComponentBatch::empty<rerun::components::Scalar>(Descriptor_values).value_or_throw();
+        archetype.origin = ComponentBatch::empty<rerun::components::Position3D>(Descriptor_origin)
+                               .value_or_throw();
+        archetype.colormap = ComponentBatch::empty<rerun::components::Colormap>(Descriptor_colormap)
+                                 .value_or_throw();
+        archetype.value_range =
+            ComponentBatch::empty<rerun::components::ValueRange>(Descriptor_value_range)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> VoxelGrid3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(9);
+        if (voxel_size.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(voxel_size.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (voxel_indices.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(voxel_indices.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (colors.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(colors.value(), lengths_).value_or_throw()
+            );
+        }
+        if (dense_values.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(dense_values.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (values.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(values.value(), lengths_).value_or_throw()
+            );
+        }
+        if (origin.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(origin.value(), lengths_).value_or_throw()
+            );
+        }
+        if (colormap.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(colormap.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (value_range.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(value_range.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<VoxelGrid3D>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> VoxelGrid3D::columns() {
+        if (voxel_size.has_value()) {
+            return columns(std::vector<uint32_t>(voxel_size.value().length(), 1));
+        }
+        if (voxel_indices.has_value()) {
+            return columns(std::vector<uint32_t>(voxel_indices.value().length(), 1));
+        }
+        if (colors.has_value()) {
+            return columns(std::vector<uint32_t>(colors.value().length(), 1));
+        }
+        if (dense_values.has_value()) {
+            return columns(std::vector<uint32_t>(dense_values.value().length(), 1));
+        }
+        if (values.has_value()) {
+            return columns(std::vector<uint32_t>(values.value().length(), 1));
+        }
+        if (origin.has_value()) {
+            return columns(std::vector<uint32_t>(origin.value().length(), 1));
+        }
+        if (colormap.has_value()) {
+            return columns(std::vector<uint32_t>(colormap.value().length(), 1));
+        }
+        if (value_range.has_value()) {
+            return columns(std::vector<uint32_t>(value_range.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>> AsComponents<archetypes::VoxelGrid3D>::serialize(
+        const archetypes::VoxelGrid3D& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(9);
+
+        if (archetype.voxel_size.has_value()) {
+            cells.push_back(archetype.voxel_size.value());
+        }
+        if (archetype.voxel_indices.has_value()) {
+            cells.push_back(archetype.voxel_indices.value());
+        }
+        if (archetype.colors.has_value()) {
+            cells.push_back(archetype.colors.value());
+        }
+        if (archetype.dense_values.has_value()) {
+            cells.push_back(archetype.dense_values.value());
+        }
+        if (archetype.values.has_value()) {
+            cells.push_back(archetype.values.value());
+        }
+        if (archetype.origin.has_value()) {
+            cells.push_back(archetype.origin.value());
+        }
+        if (archetype.colormap.has_value()) {
+            cells.push_back(archetype.colormap.value());
+        }
+        if (archetype.value_range.has_value()) {
+            cells.push_back(archetype.value_range.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<VoxelGrid3D>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
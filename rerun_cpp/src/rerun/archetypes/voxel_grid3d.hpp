@@ -0,0 +1,287 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/voxel_grid3d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/color.hpp"
+#include "../components/colormap.hpp"
+#include "../components/position3d.hpp"
+#include "../components/scalar.hpp"
+#include "../components/tensor_data.hpp"
+#include "../components/value_range.hpp"
+#include "../components/voxel_index.hpp"
+#include "../components/voxel_size.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A 3D grid of voxels, e.g. an occupancy map.
+    ///
+    /// The grid is either dense, given by a 3D tensor of voxel values, or sparse, given by a list of voxel indices.
+    /// Voxel `[x, y, z]` spans from `origin + [x, y, z] * voxel_size` to `origin + [x + 1, y + 1, z + 1] * voxel_size`
+    /// in the local coordinate system of the entity.
+    ///
+    /// Voxels are colored by their `components::Color` if set, otherwise their values are mapped through the `components::Colormap`.
+    /// When picking a voxel, the instance index is the index into `voxel_indices` for sparse grids,
+    /// and the row-major index into `dense_values` for dense grids.
+    struct VoxelGrid3D {
+        /// The size of a single voxel along each axis.
+        std::optional<ComponentBatch> voxel_size;
+
+        /// The indices of the occupied voxels of a sparse grid.
+        std::optional<ComponentBatch> voxel_indices;
+
+        /// Optional colors for the voxels.
+        ///
+        /// Takes precedence over the colormap.
+        std::optional<ComponentBatch> colors;
+
+        /// The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+        ///
+        /// Voxels with a value of zero or NaN are empty, all others are occupied.
+        /// Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+        std::optional<ComponentBatch> dense_values;
+
+        /// Optional values of the voxels of a sparse grid, mapped through the colormap.
+        std::optional<ComponentBatch> values;
+
+        /// The position of the corner of voxel `[0, 0, 0]`.
+        ///
+        /// If not specified, the grid starts at the origin.
+        std::optional<ComponentBatch> origin;
+
+        /// Colormap to use for mapping voxel values to colors.
+        ///
+        /// If not set, the Viridis colormap is used.
+        std::optional<ComponentBatch> colormap;
+
+        /// The range of voxel values that is mapped to the colormap.
+        ///
+        /// If not specified, the range is estimated from the data.
+        std::optional<ComponentBatch> value_range;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.VoxelGrid3DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.VoxelGrid3D";
+
+        /// `ComponentDescriptor` for the `voxel_size` field.
+        static constexpr auto Descriptor_voxel_size = ComponentDescriptor(
+            ArchetypeName, "voxel_size",
+            Loggable<rerun::components::VoxelSize>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `voxel_indices` field.
+        static constexpr auto Descriptor_voxel_indices = ComponentDescriptor(
+            ArchetypeName, "voxel_indices",
+            Loggable<rerun::components::VoxelIndex>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `colors` field.
+        static constexpr auto Descriptor_colors = ComponentDescriptor(
+            ArchetypeName, "colors", Loggable<rerun::components::Color>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `dense_values` field.
+        static constexpr auto Descriptor_dense_values = ComponentDescriptor(
+            ArchetypeName, "dense_values",
+            Loggable<rerun::components::TensorData>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `values` field.
+        static constexpr auto Descriptor_values = ComponentDescriptor(
+            ArchetypeName, "values", Loggable<rerun::components::Scalar>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `origin` field.
+        static constexpr auto Descriptor_origin = ComponentDescriptor(
+            ArchetypeName, "origin",
+            Loggable<rerun::components::Position3D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "colormap",
+            Loggable<rerun::components::Colormap>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `value_range` field.
+        static constexpr auto Descriptor_value_range = ComponentDescriptor(
+            ArchetypeName, "value_range",
+            Loggable<rerun::components::ValueRange>::Descriptor.component_name
+        );
+
+      public:
+        VoxelGrid3D() = default;
+        VoxelGrid3D(VoxelGrid3D&& other) = default;
+        VoxelGrid3D(const VoxelGrid3D& other) = default;
+        VoxelGrid3D& operator=(const VoxelGrid3D& other) = default;
+        VoxelGrid3D& operator=(VoxelGrid3D&& other) = default;
+
+        /// Update only some specific fields of a `VoxelGrid3D`.
+        static VoxelGrid3D update_fields() {
+            return VoxelGrid3D();
+        }
+
+        /// Clear all the fields of a `VoxelGrid3D`.
+        static VoxelGrid3D clear_fields();
+
+        /// The size of a single voxel along each axis.
+        VoxelGrid3D with_voxel_size(const rerun::components::VoxelSize& _voxel_size) && {
+            voxel_size =
+                ComponentBatch::from_loggable(_voxel_size, Descriptor_voxel_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `voxel_size` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_voxel_size` should
+        /// be used when logging a single row's worth of data.
+        VoxelGrid3D with_many_voxel_size(const Collection<rerun::components::VoxelSize>& _voxel_size
+        ) && {
+            voxel_size =
+                ComponentBatch::from_loggable(_voxel_size, Descriptor_voxel_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The indices of the occupied voxels of a sparse grid.
+        VoxelGrid3D with_voxel_indices(
+            const Collection<rerun::components::VoxelIndex>& _voxel_indices
+        ) && {
+            voxel_indices = ComponentBatch::from_loggable(_voxel_indices, Descriptor_voxel_indices)
+                                .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional colors for the voxels.
+        ///
+        /// Takes precedence over the colormap.
+        VoxelGrid3D with_colors(const Collection<rerun::components::Color>& _colors) && {
+            colors = ComponentBatch::from_loggable(_colors, Descriptor_colors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+        ///
+        /// Voxels with a value of zero or NaN are empty, all others are occupied.
+        /// Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+        VoxelGrid3D with_dense_values(const rerun::components::TensorData& _dense_values) && {
+            dense_values = ComponentBatch::from_loggable(_dense_values, Descriptor_dense_values)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `dense_values` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_dense_values` should
+        /// be used when logging a single row's worth of data.
+        VoxelGrid3D with_many_dense_values(
+            const Collection<rerun::components::TensorData>& _dense_values
+        ) && {
+            dense_values = ComponentBatch::from_loggable(_dense_values, Descriptor_dense_values)
+                               .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional values of the voxels of a sparse grid, mapped through the colormap.
+        VoxelGrid3D with_values(const Collection<rerun::components::Scalar>& _values) && {
+            values = ComponentBatch::from_loggable(_values, Descriptor_values).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The position of the corner of voxel `[0, 0, 0]`.
+        ///
+        /// If not specified, the grid starts at the origin.
+        VoxelGrid3D with_origin(const rerun::components::Position3D& _origin) && {
+            origin = ComponentBatch::from_loggable(_origin, Descriptor_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `origin` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_origin` should
+        /// be used when logging a single row's worth of data.
+        VoxelGrid3D with_many_origin(const Collection<rerun::components::Position3D>& _origin) && {
+            origin = ComponentBatch::from_loggable(_origin, Descriptor_origin).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Colormap to use for mapping voxel values to colors.
+        ///
+        /// If not set, the Viridis colormap is used.
+        VoxelGrid3D with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `colormap` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_colormap` should
+        /// be used when logging a single row's worth of data.
+        VoxelGrid3D with_many_colormap(const Collection<rerun::components::Colormap>& _colormap
+        ) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The range of voxel values that is mapped to the colormap.
+        ///
+        /// If not specified, the range is estimated from the data.
+        VoxelGrid3D with_value_range(const rerun::components::ValueRange& _value_range) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `value_range` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_value_range` should
+        /// be used when logging a single row's worth of data.
+        VoxelGrid3D with_many_value_range(
+            const Collection<rerun::components::ValueRange>& _value_range
+        ) && {
+            value_range = ComponentBatch::from_loggable(_value_range, Descriptor_value_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::VoxelGrid3D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const archetypes::VoxelGrid3D& archetype
+        );
+    };
+} // namespace rerun
@@ -3,8 +3,10 @@
 #pragma once
 
 #include "blueprint/archetypes/background.hpp"
+#include "blueprint/archetypes/clip_planes3d.hpp"
 #include "blueprint/archetypes/container_blueprint.hpp"
 #include "blueprint/archetypes/dataframe_query.hpp"
+#include "blueprint/archetypes/eye_controls3d.hpp"
 #include "blueprint/archetypes/force_center.hpp"
 #include "blueprint/archetypes/force_collision_radius.hpp"
 #include "blueprint/archetypes/force_link.hpp"
@@ -13,13 +15,18 @@
 #include "blueprint/archetypes/line_grid3d.hpp"
 #include "blueprint/archetypes/map_background.hpp"
 #include "blueprint/archetypes/map_zoom.hpp"
+#include "blueprint/archetypes/measurements.hpp"
 #include "blueprint/archetypes/near_clip_plane.hpp"
 #include "blueprint/archetypes/panel_blueprint.hpp"
 #include "blueprint/archetypes/plot_legend.hpp"
+#include "blueprint/archetypes/recording_comparison.hpp"
 #include "blueprint/archetypes/scalar_axis.hpp"
+#include "blueprint/archetypes/section_box3d.hpp"
 #include "blueprint/archetypes/tensor_scalar_mapping.hpp"
 #include "blueprint/archetypes/tensor_slice_selection.hpp"
 #include "blueprint/archetypes/tensor_view_fit.hpp"
+#include "blueprint/archetypes/text_log_search.hpp"
+#include "blueprint/archetypes/timeline_bookmarks.hpp"
 #include "blueprint/archetypes/view_blueprint.hpp"
 #include "blueprint/archetypes/view_contents.hpp"
 #include "blueprint/archetypes/viewport_blueprint.hpp"
@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/clip_planes3d.fbs".
+
+#include "clip_planes3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    ClipPlanes3D ClipPlanes3D::clear_fields() {
+        auto archetype = ClipPlanes3D();
+        archetype.enabled =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_enabled)
+                .value_or_throw();
+        archetype.planes =
+            ComponentBatch::empty<rerun::components::Plane3D>(Descriptor_planes).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> ClipPlanes3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (enabled.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(enabled.value(), lengths_).value_or_throw()
+            );
+        }
+        if (planes.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(planes.value(), lengths_).value_or_throw()
+            );
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<ClipPlanes3D>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> ClipPlanes3D::columns() {
+        if (enabled.has_value()) {
+            return columns(std::vector<uint32_t>(enabled.value().length(), 1));
+        }
+        if (planes.has_value()) {
+            return columns(std::vector<uint32_t>(planes.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::ClipPlanes3D>::serialize(
+            const blueprint::archetypes::ClipPlanes3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.enabled.has_value()) {
+            cells.push_back(archetype.enabled.value());
+        }
+        if (archetype.planes.has_value()) {
+            cells.push_back(archetype.planes.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<ClipPlanes3D>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
@@ -0,0 +1,120 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/clip_planes3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/plane3d.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Clipping planes that cut away parts of a spatial 3D view.
+    ///
+    /// Everything on the side of a plane its normal points towards is not drawn.
+    struct ClipPlanes3D {
+        /// Whether the clipping planes are applied.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> enabled;
+
+        /// The clipping planes in world-space.
+        ///
+        /// At most 8 planes are supported, additional planes are ignored.
+        /// While the section box is enabled, only the first 2 planes are applied.
+        /// Defaults to a single plane through the center of the scene, facing up.
+        std::optional<ComponentBatch> planes;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.ClipPlanes3DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.ClipPlanes3D";
+
+        /// `ComponentDescriptor` for the `enabled` field.
+        static constexpr auto Descriptor_enabled = ComponentDescriptor(
+            ArchetypeName, "enabled",
+            Loggable<rerun::blueprint::components::Enabled>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `planes` field.
+        static constexpr auto Descriptor_planes = ComponentDescriptor(
+            ArchetypeName, "planes", Loggable<rerun::components::Plane3D>::Descriptor.component_name
+        );
+
+      public:
+        ClipPlanes3D() = default;
+        ClipPlanes3D(ClipPlanes3D&& other) = default;
+        ClipPlanes3D(const ClipPlanes3D& other) = default;
+        ClipPlanes3D& operator=(const ClipPlanes3D& other) = default;
+        ClipPlanes3D& operator=(ClipPlanes3D&& other) = default;
+
+        /// Update only some specific fields of a `ClipPlanes3D`.
+        static ClipPlanes3D update_fields() {
+            return ClipPlanes3D();
+        }
+
+        /// Clear all the fields of a `ClipPlanes3D`.
+        static ClipPlanes3D clear_fields();
+
+        /// Whether the clipping planes are applied.
+        ///
+        /// Defaults to false.
+        ClipPlanes3D with_enabled(const rerun::blueprint::components::Enabled& _enabled) && {
+            enabled = ComponentBatch::from_loggable(_enabled, Descriptor_enabled).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The clipping planes in world-space.
+        ///
+        /// At most 8 planes are supported, additional planes are ignored.
+        /// While the section box is enabled, only the first 2 planes are applied.
+        /// Defaults to a single plane through the center of the scene, facing up.
+        ClipPlanes3D with_planes(const Collection<rerun::components::Plane3D>& _planes) && {
+            planes = ComponentBatch::from_loggable(_planes, Descriptor_planes).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::ClipPlanes3D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::ClipPlanes3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,146 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/eye_controls3d.fbs".
+
+#include "eye_controls3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    EyeControls3D EyeControls3D::clear_fields() {
+        auto archetype = EyeControls3D();
+        archetype.kind =
+            ComponentBatch::empty<rerun::blueprint::components::Eye3DKind>(Descriptor_kind)
+                .value_or_throw();
+        archetype.position =
+            ComponentBatch::empty<rerun::components::Position3D>(Descriptor_position)
+                .value_or_throw();
+        archetype.look_target =
+            ComponentBatch::empty<rerun::blueprint::components::LookTarget>(Descriptor_look_target)
+                .value_or_throw();
+        archetype.eye_up =
+            ComponentBatch::empty<rerun::components::Vector3D>(Descriptor_eye_up).value_or_throw();
+        archetype.fov_y =
+            ComponentBatch::empty<rerun::blueprint::components::FieldOfView>(Descriptor_fov_y)
+                .value_or_throw();
+        archetype.tracking_entity =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_tracking_entity)
+                .value_or_throw();
+        archetype.tracking_offset =
+            ComponentBatch::empty<rerun::components::Translation3D>(Descriptor_tracking_offset)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> EyeControls3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(8);
+        if (kind.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(kind.value(), lengths_).value_or_throw()
+            );
+        }
+        if (position.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(position.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (look_target.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(look_target.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (eye_up.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(eye_up.value(), lengths_).value_or_throw()
+            );
+        }
+        if (fov_y.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(fov_y.value(), lengths_).value_or_throw()
+            );
+        }
+        if (tracking_entity.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(tracking_entity.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        if (tracking_offset.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(tracking_offset.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<EyeControls3D>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> EyeControls3D::columns() {
+        if (kind.has_value()) {
+            return columns(std::vector<uint32_t>(kind.value().length(), 1));
+        }
+        if (position.has_value()) {
+            return columns(std::vector<uint32_t>(position.value().length(), 1));
+        }
+        if (look_target.has_value()) {
+            return columns(std::vector<uint32_t>(look_target.value().length(), 1));
+        }
+        if (eye_up.has_value()) {
+            return columns(std::vector<uint32_t>(eye_up.value().length(), 1));
+        }
+        if (fov_y.has_value()) {
+            return columns(std::vector<uint32_t>(fov_y.value().length(), 1));
+        }
+        if (tracking_entity.has_value()) {
+            return columns(std::vector<uint32_t>(tracking_entity.value().length(), 1));
+        }
+        if (tracking_offset.has_value()) {
+            return columns(std::vector<uint32_t>(tracking_offset.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::EyeControls3D>::serialize(
+            const blueprint::archetypes::EyeControls3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(8);
+
+        if (archetype.kind.has_value()) {
+            cells.push_back(archetype.kind.value());
+        }
+        if (archetype.position.has_value()) {
+            cells.push_back(archetype.position.value());
+        }
+        if (archetype.look_target.has_value()) {
+            cells.push_back(archetype.look_target.value());
+        }
+        if (archetype.eye_up.has_value()) {
+            cells.push_back(archetype.eye_up.value());
+        }
+        if (archetype.fov_y.has_value()) {
+            cells.push_back(archetype.fov_y.value());
+        }
+        if (archetype.tracking_entity.has_value()) {
+            cells.push_back(archetype.tracking_entity.value());
+        }
+        if (archetype.tracking_offset.has_value()) {
+            cells.push_back(archetype.tracking_offset.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<EyeControls3D>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
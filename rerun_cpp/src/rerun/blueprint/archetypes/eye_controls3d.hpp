@@ -0,0 +1,231 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/eye_controls3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/eye3d_kind.hpp"
+#include "../../blueprint/components/field_of_view.hpp"
+#include "../../blueprint/components/look_target.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../components/position3d.hpp"
+#include "../../components/translation3d.hpp"
+#include "../../components/vector3d.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: The controls for the 3D eye in a spatial 3D view.
+    ///
+    /// This configures the camera through which the 3D scene is viewed.
+    /// Fields that are not set are determined automatically from the scene,
+    /// and interacting with the view writes the resulting eye back to the blueprint.
+    struct EyeControls3D {
+        /// The kind of the eye for the spatial 3D view.
+        ///
+        /// This controls how the eye reacts to user input, e.g. mouse dragging.
+        /// Defaults to orbital.
+        std::optional<ComponentBatch> kind;
+
+        /// The world-space position of the eye.
+        ///
+        /// Only used if `look_target` is set as well.
+        std::optional<ComponentBatch> position;
+
+        /// The world-space point the eye is looking at.
+        ///
+        /// For orbital eyes this is the center of rotation.
+        /// Only used if `position` is set as well.
+        std::optional<ComponentBatch> look_target;
+
+        /// The up-axis of the eye in world-space.
+        ///
+        /// Defaults to the up-axis of the scene as defined by `components::ViewCoordinates`, or +Z if not specified.
+        std::optional<ComponentBatch> eye_up;
+
+        /// Vertical field of view of the eye.
+        ///
+        /// Defaults to 55°.
+        std::optional<ComponentBatch> fov_y;
+
+        /// An entity the eye follows.
+        ///
+        /// While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+        std::optional<ComponentBatch> tracking_entity;
+
+        /// Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+        ///
+        /// The eye is placed at this offset and looks at the origin of the tracked entity,
+        /// e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+        /// If not set, the eye keeps its orientation & distance and only follows the entity's position.
+        std::optional<ComponentBatch> tracking_offset;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.EyeControls3DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.EyeControls3D";
+
+        /// `ComponentDescriptor` for the `kind` field.
+        static constexpr auto Descriptor_kind = ComponentDescriptor(
+            ArchetypeName, "kind",
+            Loggable<rerun::blueprint::components::Eye3DKind>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `position` field.
+        static constexpr auto Descriptor_position = ComponentDescriptor(
+            ArchetypeName, "position",
+            Loggable<rerun::components::Position3D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `look_target` field.
+        static constexpr auto Descriptor_look_target = ComponentDescriptor(
+            ArchetypeName, "look_target",
+            Loggable<rerun::blueprint::components::LookTarget>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `eye_up` field.
+        static constexpr auto Descriptor_eye_up = ComponentDescriptor(
+            ArchetypeName, "eye_up",
+            Loggable<rerun::components::Vector3D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `fov_y` field.
+        static constexpr auto Descriptor_fov_y = ComponentDescriptor(
+            ArchetypeName, "fov_y",
+            Loggable<rerun::blueprint::components::FieldOfView>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `tracking_entity` field.
+        static constexpr auto Descriptor_tracking_entity = ComponentDescriptor(
+            ArchetypeName, "tracking_entity",
+            Loggable<rerun::components::EntityPath>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `tracking_offset` field.
+        static constexpr auto Descriptor_tracking_offset = ComponentDescriptor(
+            ArchetypeName, "tracking_offset",
+            Loggable<rerun::components::Translation3D>::Descriptor.component_name
+        );
+
+      public:
+        EyeControls3D() = default;
+        EyeControls3D(EyeControls3D&& other) = default;
+        EyeControls3D(const EyeControls3D& other) = default;
+        EyeControls3D& operator=(const EyeControls3D& other) = default;
+        EyeControls3D& operator=(EyeControls3D&& other) = default;
+
+        /// Update only some specific fields of a `EyeControls3D`.
+        static EyeControls3D update_fields() {
+            return EyeControls3D();
+        }
+
+        /// Clear all the fields of a `EyeControls3D`.
+        static EyeControls3D clear_fields();
+
+        /// The kind of the eye for the spatial 3D view.
+        ///
+        /// This controls how the eye reacts to user input, e.g. mouse dragging.
+        /// Defaults to orbital.
+        EyeControls3D with_kind(const rerun::blueprint::components::Eye3DKind& _kind) && {
+            kind = ComponentBatch::from_loggable(_kind, Descriptor_kind).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The world-space position of the eye.
+        ///
+        /// Only used if `look_target` is set as well.
+        EyeControls3D with_position(const rerun::components::Position3D& _position) && {
+            position =
+                ComponentBatch::from_loggable(_position, Descriptor_position).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The world-space point the eye is looking at.
+        ///
+        /// For orbital eyes this is the center of rotation.
+        /// Only used if `position` is set as well.
+        EyeControls3D with_look_target(const rerun::blueprint::components::LookTarget& _look_target
+        ) && {
+            look_target = ComponentBatch::from_loggable(_look_target, Descriptor_look_target)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The up-axis of the eye in world-space.
+        ///
+        /// Defaults to the up-axis of the scene as defined by `components::ViewCoordinates`, or +Z if not specified.
+        EyeControls3D with_eye_up(const rerun::components::Vector3D& _eye_up) && {
+            eye_up = ComponentBatch::from_loggable(_eye_up, Descriptor_eye_up).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Vertical field of view of the eye.
+        ///
+        /// Defaults to 55°.
+        EyeControls3D with_fov_y(const rerun::blueprint::components::FieldOfView& _fov_y) && {
+            fov_y = ComponentBatch::from_loggable(_fov_y, Descriptor_fov_y).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// An entity the eye follows.
+        ///
+        /// While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+        EyeControls3D with_tracking_entity(const rerun::components::EntityPath& _tracking_entity
+        ) && {
+            tracking_entity =
+                ComponentBatch::from_loggable(_tracking_entity, Descriptor_tracking_entity)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+        ///
+        /// The eye is placed at this offset and looks at the origin of the tracked entity,
+        /// e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+        /// If not set, the eye keeps its orientation & distance and only follows the entity's position.
+        EyeControls3D with_tracking_offset(const rerun::components::Translation3D& _tracking_offset
+        ) && {
+            tracking_offset =
+                ComponentBatch::from_loggable(_tracking_offset, Descriptor_tracking_offset)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::EyeControls3D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::EyeControls3D& archetype
+        );
+    };
+} // namespace rerun
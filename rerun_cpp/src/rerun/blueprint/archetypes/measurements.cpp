@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/measurements.fbs".
+
+#include "measurements.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    Measurements Measurements::clear_fields() {
+        auto archetype = Measurements();
+        archetype.kinds =
+            ComponentBatch::empty<rerun::blueprint::components::MeasurementKind>(Descriptor_kinds)
+                .value_or_throw();
+        archetype.points = ComponentBatch::empty<rerun::components::LineStrip3D>(Descriptor_points)
+                               .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> Measurements::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (kinds.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(kinds.value(), lengths_).value_or_throw()
+            );
+        }
+        if (points.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(points.value(), lengths_).value_or_throw()
+            );
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<Measurements>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> Measurements::columns() {
+        if (kinds.has_value()) {
+            return columns(std::vector<uint32_t>(kinds.value().length(), 1));
+        }
+        if (points.has_value()) {
+            return columns(std::vector<uint32_t>(points.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::Measurements>::serialize(
+            const blueprint::archetypes::Measurements& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.kinds.has_value()) {
+            cells.push_back(archetype.kinds.value());
+        }
+        if (archetype.points.has_value()) {
+            cells.push_back(archetype.points.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<Measurements>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
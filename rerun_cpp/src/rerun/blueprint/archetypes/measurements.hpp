@@ -0,0 +1,112 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/measurements.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/measurement_kind.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/line_strip3d.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Measurements taken with the measurement tools of a spatial view.
+    ///
+    /// Each measurement is a kind together with the points it was taken between.
+    /// Measurements in 2D views are stored with a z-coordinate of zero.
+    struct Measurements {
+        /// The kind of each measurement.
+        std::optional<ComponentBatch> kinds;
+
+        /// The points of each measurement, in the coordinate space of the view's origin.
+        std::optional<ComponentBatch> points;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.MeasurementsIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.Measurements";
+
+        /// `ComponentDescriptor` for the `kinds` field.
+        static constexpr auto Descriptor_kinds = ComponentDescriptor(
+            ArchetypeName, "kinds",
+            Loggable<rerun::blueprint::components::MeasurementKind>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `points` field.
+        static constexpr auto Descriptor_points = ComponentDescriptor(
+            ArchetypeName, "points",
+            Loggable<rerun::components::LineStrip3D>::Descriptor.component_name
+        );
+
+      public:
+        Measurements() = default;
+        Measurements(Measurements&& other) = default;
+        Measurements(const Measurements& other) = default;
+        Measurements& operator=(const Measurements& other) = default;
+        Measurements& operator=(Measurements&& other) = default;
+
+        /// Update only some specific fields of a `Measurements`.
+        static Measurements update_fields() {
+            return Measurements();
+        }
+
+        /// Clear all the fields of a `Measurements`.
+        static Measurements clear_fields();
+
+        /// The kind of each measurement.
+        Measurements with_kinds(
+            const Collection<rerun::blueprint::components::MeasurementKind>& _kinds
+        ) && {
+            kinds = ComponentBatch::from_loggable(_kinds, Descriptor_kinds).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The points of each measurement, in the coordinate space of the view's origin.
+        Measurements with_points(const Collection<rerun::components::LineStrip3D>& _points) && {
+            points = ComponentBatch::from_loggable(_points, Descriptor_points).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::Measurements> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::Measurements& archetype
+        );
+    };
+} // namespace rerun
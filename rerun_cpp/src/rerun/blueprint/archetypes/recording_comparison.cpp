@@ -0,0 +1,77 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_comparison.fbs".
+
+#include "recording_comparison.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    RecordingComparison RecordingComparison::clear_fields() {
+        auto archetype = RecordingComparison();
+        archetype.recording =
+            ComponentBatch::empty<rerun::blueprint::components::ComparedRecording>(
+                Descriptor_recording
+            )
+                .value_or_throw();
+        archetype.time_offset =
+            ComponentBatch::empty<rerun::blueprint::components::TimeOffset>(Descriptor_time_offset)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> RecordingComparison::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (recording.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(recording.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (time_offset.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(time_offset.value(), lengths_)
+                    .value_or_throw()
+            );
+        }
+        columns.push_back(ComponentColumn::from_indicators<RecordingComparison>(
+                              static_cast<uint32_t>(lengths_.size())
+        )
+                              .value_or_throw());
+        return columns;
+    }
+
+    Collection<ComponentColumn> RecordingComparison::columns() {
+        if (recording.has_value()) {
+            return columns(std::vector<uint32_t>(recording.value().length(), 1));
+        }
+        if (time_offset.has_value()) {
+            return columns(std::vector<uint32_t>(time_offset.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::RecordingComparison>::serialize(
+            const blueprint::archetypes::RecordingComparison& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.recording.has_value()) {
+            cells.push_back(archetype.recording.value());
+        }
+        if (archetype.time_offset.has_value()) {
+            cells.push_back(archetype.time_offset.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<RecordingComparison>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
@@ -0,0 +1,126 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_comparison.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/compared_recording.hpp"
+#include "../../blueprint/components/time_offset.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Compares the active recording against another recording in a view.
+    ///
+    /// Time series views overlay the data of both recordings, all other views show the compared recording instead of the active one.
+    struct RecordingComparison {
+        /// The id of the recording to compare against.
+        ///
+        /// If not set, or if no recording with this id is loaded, the view only shows the active recording.
+        std::optional<ComponentBatch> recording;
+
+        /// How much later things happen in the active recording than in the compared recording.
+        ///
+        /// Added to all times of the compared recording so that both recordings share one time cursor.
+        /// In the unit of the currently selected timeline, defaults to zero.
+        std::optional<ComponentBatch> time_offset;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.RecordingComparisonIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.RecordingComparison";
+
+        /// `ComponentDescriptor` for the `recording` field.
+        static constexpr auto Descriptor_recording = ComponentDescriptor(
+            ArchetypeName, "recording",
+            Loggable<rerun::blueprint::components::ComparedRecording>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `time_offset` field.
+        static constexpr auto Descriptor_time_offset = ComponentDescriptor(
+            ArchetypeName, "time_offset",
+            Loggable<rerun::blueprint::components::TimeOffset>::Descriptor.component_name
+        );
+
+      public:
+        RecordingComparison() = default;
+        RecordingComparison(RecordingComparison&& other) = default;
+        RecordingComparison(const RecordingComparison& other) = default;
+        RecordingComparison& operator=(const RecordingComparison& other) = default;
+        RecordingComparison& operator=(RecordingComparison&& other) = default;
+
+        /// Update only some specific fields of a `RecordingComparison`.
+        static RecordingComparison update_fields() {
+            return RecordingComparison();
+        }
+
+        /// Clear all the fields of a `RecordingComparison`.
+        static RecordingComparison clear_fields();
+
+        /// The id of the recording to compare against.
+        ///
+        /// If not set, or if no recording with this id is loaded, the view only shows the active recording.
+        RecordingComparison with_recording(
+            const rerun::blueprint::components::ComparedRecording& _recording
+        ) && {
+            recording =
+                ComponentBatch::from_loggable(_recording, Descriptor_recording).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How much later things happen in the active recording than in the compared recording.
+        ///
+        /// Added to all times of the compared recording so that both recordings share one time cursor.
+        /// In the unit of the currently selected timeline, defaults to zero.
+        RecordingComparison with_time_offset(
+            const rerun::blueprint::components::TimeOffset& _time_offset
+        ) && {
+            time_offset = ComponentBatch::from_loggable(_time_offset, Descriptor_time_offset)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::RecordingComparison> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::RecordingComparison& archetype
+        );
+    };
+} // namespace rerun
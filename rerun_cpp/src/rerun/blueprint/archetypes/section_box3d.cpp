@@ -0,0 +1,100 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_box3d.fbs".
+
+#include "section_box3d.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    SectionBox3D SectionBox3D::clear_fields() {
+        auto archetype = SectionBox3D();
+        archetype.enabled =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_enabled)
+                .value_or_throw();
+        archetype.center = ComponentBatch::empty<rerun::components::Position3D>(Descriptor_center)
+                               .value_or_throw();
+        archetype.half_size =
+            ComponentBatch::empty<rerun::components::HalfSize3D>(Descriptor_half_size)
+                .value_or_throw();
+        archetype.rotation =
+            ComponentBatch::empty<rerun::components::RotationQuat>(Descriptor_rotation)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> SectionBox3D::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (enabled.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(enabled.value(), lengths_).value_or_throw()
+            );
+        }
+        if (center.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(center.value(), lengths_).value_or_throw()
+            );
+        }
+        if (half_size.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(half_size.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (rotation.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(rotation.value(), lengths_)
+                                  .value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<SectionBox3D>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> SectionBox3D::columns() {
+        if (enabled.has_value()) {
+            return columns(std::vector<uint32_t>(enabled.value().length(), 1));
+        }
+        if (center.has_value()) {
+            return columns(std::vector<uint32_t>(center.value().length(), 1));
+        }
+        if (half_size.has_value()) {
+            return columns(std::vector<uint32_t>(half_size.value().length(), 1));
+        }
+        if (rotation.has_value()) {
+            return columns(std::vector<uint32_t>(rotation.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::SectionBox3D>::serialize(
+            const blueprint::archetypes::SectionBox3D& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.enabled.has_value()) {
+            cells.push_back(archetype.enabled.value());
+        }
+        if (archetype.center.has_value()) {
+            cells.push_back(archetype.center.value());
+        }
+        if (archetype.half_size.has_value()) {
+            cells.push_back(archetype.half_size.value());
+        }
+        if (archetype.rotation.has_value()) {
+            cells.push_back(archetype.rotation.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<SectionBox3D>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
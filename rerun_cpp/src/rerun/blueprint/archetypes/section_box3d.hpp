@@ -0,0 +1,155 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_box3d.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/half_size3d.hpp"
+#include "../../components/position3d.hpp"
+#include "../../components/rotation_quat.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: A box in a spatial 3D view outside of which nothing is drawn.
+    struct SectionBox3D {
+        /// Whether the section box is applied.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> enabled;
+
+        /// The center of the box in world-space.
+        ///
+        /// Defaults to the center of the scene's bounding box.
+        std::optional<ComponentBatch> center;
+
+        /// Half of the box's size along each of its local axes.
+        ///
+        /// Defaults to half the size of the scene's bounding box.
+        std::optional<ComponentBatch> half_size;
+
+        /// Rotation of the box in world-space.
+        ///
+        /// Defaults to no rotation, i.e. an axis-aligned box.
+        std::optional<ComponentBatch> rotation;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.SectionBox3DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.SectionBox3D";
+
+        /// `ComponentDescriptor` for the `enabled` field.
+        static constexpr auto Descriptor_enabled = ComponentDescriptor(
+            ArchetypeName, "enabled",
+            Loggable<rerun::blueprint::components::Enabled>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `center` field.
+        static constexpr auto Descriptor_center = ComponentDescriptor(
+            ArchetypeName, "center",
+            Loggable<rerun::components::Position3D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `half_size` field.
+        static constexpr auto Descriptor_half_size = ComponentDescriptor(
+            ArchetypeName, "half_size",
+            Loggable<rerun::components::HalfSize3D>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `rotation` field.
+        static constexpr auto Descriptor_rotation = ComponentDescriptor(
+            ArchetypeName, "rotation",
+            Loggable<rerun::components::RotationQuat>::Descriptor.component_name
+        );
+
+      public:
+        SectionBox3D() = default;
+        SectionBox3D(SectionBox3D&& other) = default;
+        SectionBox3D(const SectionBox3D& other) = default;
+        SectionBox3D& operator=(const SectionBox3D& other) = default;
+        SectionBox3D& operator=(SectionBox3D&& other) = default;
+
+        /// Update only some specific fields of a `SectionBox3D`.
+        static SectionBox3D update_fields() {
+            return SectionBox3D();
+        }
+
+        /// Clear all the fields of a `SectionBox3D`.
+        static SectionBox3D clear_fields();
+
+        /// Whether the section box is applied.
+        ///
+        /// Defaults to false.
+        SectionBox3D with_enabled(const rerun::blueprint::components::Enabled& _enabled) && {
+            enabled = ComponentBatch::from_loggable(_enabled, Descriptor_enabled).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The center of the box in world-space.
+        ///
+        /// Defaults to the center of the scene's bounding box.
+        SectionBox3D with_center(const rerun::components::Position3D& _center) && {
+            center = ComponentBatch::from_loggable(_center, Descriptor_center).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Half of the box's size along each of its local axes.
+        ///
+        /// Defaults to half the size of the scene's bounding box.
+        SectionBox3D with_half_size(const rerun::components::HalfSize3D& _half_size) && {
+            half_size =
+                ComponentBatch::from_loggable(_half_size, Descriptor_half_size).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Rotation of the box in world-space.
+        ///
+        /// Defaults to no rotation, i.e. an axis-aligned box.
+        SectionBox3D with_rotation(const rerun::components::RotationQuat& _rotation) && {
+            rotation =
+                ComponentBatch::from_loggable(_rotation, Descriptor_rotation).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::SectionBox3D> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::SectionBox3D& archetype
+        );
+    };
+} // namespace rerun
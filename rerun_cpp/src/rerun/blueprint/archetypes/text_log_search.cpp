@@ -0,0 +1,73 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_search.fbs".
+
+#include "text_log_search.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TextLogSearch TextLogSearch::clear_fields() {
+        auto archetype = TextLogSearch();
+        archetype.query =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_query).value_or_throw();
+        archetype.use_regex =
+            ComponentBatch::empty<rerun::blueprint::components::UseRegex>(Descriptor_use_regex)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TextLogSearch::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (query.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(query.value(), lengths_).value_or_throw()
+            );
+        }
+        if (use_regex.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(use_regex.value(), lengths_)
+                                  .value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<TextLogSearch>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> TextLogSearch::columns() {
+        if (query.has_value()) {
+            return columns(std::vector<uint32_t>(query.value().length(), 1));
+        }
+        if (use_regex.has_value()) {
+            return columns(std::vector<uint32_t>(use_regex.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TextLogSearch>::serialize(
+            const blueprint::archetypes::TextLogSearch& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.query.has_value()) {
+            cells.push_back(archetype.query.value());
+        }
+        if (archetype.use_regex.has_value()) {
+            cells.push_back(archetype.use_regex.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<TextLogSearch>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
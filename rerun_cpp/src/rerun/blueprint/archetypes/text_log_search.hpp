@@ -0,0 +1,121 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_search.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/use_regex.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/text.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Searches the bodies of the entries of a text log view.
+    ///
+    /// Only entries whose body matches the query are shown.
+    struct TextLogSearch {
+        /// The text to search for.
+        ///
+        /// Matched case-insensitively as a substring, unless `use_regex` is set.
+        /// An empty query shows all entries.
+        std::optional<ComponentBatch> query;
+
+        /// Whether `query` is a regular expression.
+        ///
+        /// Regular expressions are case-sensitive unless they start with `(?i)`.
+        /// Defaults to false.
+        std::optional<ComponentBatch> use_regex;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.TextLogSearchIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TextLogSearch";
+
+        /// `ComponentDescriptor` for the `query` field.
+        static constexpr auto Descriptor_query = ComponentDescriptor(
+            ArchetypeName, "query", Loggable<rerun::components::Text>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `use_regex` field.
+        static constexpr auto Descriptor_use_regex = ComponentDescriptor(
+            ArchetypeName, "use_regex",
+            Loggable<rerun::blueprint::components::UseRegex>::Descriptor.component_name
+        );
+
+      public:
+        TextLogSearch() = default;
+        TextLogSearch(TextLogSearch&& other) = default;
+        TextLogSearch(const TextLogSearch& other) = default;
+        TextLogSearch& operator=(const TextLogSearch& other) = default;
+        TextLogSearch& operator=(TextLogSearch&& other) = default;
+
+        /// Update only some specific fields of a `TextLogSearch`.
+        static TextLogSearch update_fields() {
+            return TextLogSearch();
+        }
+
+        /// Clear all the fields of a `TextLogSearch`.
+        static TextLogSearch clear_fields();
+
+        /// The text to search for.
+        ///
+        /// Matched case-insensitively as a substring, unless `use_regex` is set.
+        /// An empty query shows all entries.
+        TextLogSearch with_query(const rerun::components::Text& _query) && {
+            query = ComponentBatch::from_loggable(_query, Descriptor_query).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether `query` is a regular expression.
+        ///
+        /// Regular expressions are case-sensitive unless they start with `(?i)`.
+        /// Defaults to false.
+        TextLogSearch with_use_regex(const rerun::blueprint::components::UseRegex& _use_regex) && {
+            use_regex =
+                ComponentBatch::from_loggable(_use_regex, Descriptor_use_regex).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TextLogSearch> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::TextLogSearch& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,87 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/timeline_bookmarks.fbs".
+
+#include "timeline_bookmarks.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TimelineBookmarks TimelineBookmarks::clear_fields() {
+        auto archetype = TimelineBookmarks();
+        archetype.timelines =
+            ComponentBatch::empty<rerun::blueprint::components::TimelineName>(Descriptor_timelines)
+                .value_or_throw();
+        archetype.times =
+            ComponentBatch::empty<rerun::blueprint::components::BookmarkTime>(Descriptor_times)
+                .value_or_throw();
+        archetype.labels =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_labels).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TimelineBookmarks::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (timelines.has_value()) {
+            columns.push_back(ComponentColumn::from_batch_with_lengths(timelines.value(), lengths_)
+                                  .value_or_throw());
+        }
+        if (times.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(times.value(), lengths_).value_or_throw()
+            );
+        }
+        if (labels.has_value()) {
+            columns.push_back(
+                ComponentColumn::from_batch_with_lengths(labels.value(), lengths_).value_or_throw()
+            );
+        }
+        columns.push_back(ComponentColumn::from_indicators<TimelineBookmarks>(
+                              static_cast<uint32_t>(lengths_.size())
+        )
+                              .value_or_throw());
+        return columns;
+    }
+
+    Collection<ComponentColumn> TimelineBookmarks::columns() {
+        if (timelines.has_value()) {
+            return columns(std::vector<uint32_t>(timelines.value().length(), 1));
+        }
+        if (times.has_value()) {
+            return columns(std::vector<uint32_t>(times.value().length(), 1));
+        }
+        if (labels.has_value()) {
+            return columns(std::vector<uint32_t>(labels.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<std::vector<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TimelineBookmarks>::serialize(
+            const blueprint::archetypes::TimelineBookmarks& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.timelines.has_value()) {
+            cells.push_back(archetype.timelines.value());
+        }
+        if (archetype.times.has_value()) {
+            cells.push_back(archetype.times.value());
+        }
+        if (archetype.labels.has_value()) {
+            cells.push_back(archetype.labels.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<TimelineBookmarks>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
@@ -0,0 +1,130 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/timeline_bookmarks.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/bookmark_time.hpp"
+#include "../../blueprint/components/timeline_name.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/name.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Bookmarked moments on the timelines of the time panel.
+    ///
+    /// The i-th bookmark is made up of the i-th timeline, time and label.
+    /// Bookmarks are shown as markers above the streams of the time panel.
+    struct TimelineBookmarks {
+        /// The timeline of each bookmark.
+        std::optional<ComponentBatch> timelines;
+
+        /// The time of each bookmark on its timeline.
+        std::optional<ComponentBatch> times;
+
+        /// The label of each bookmark.
+        std::optional<ComponentBatch> labels;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.TimelineBookmarksIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.TimelineBookmarks";
+
+        /// `ComponentDescriptor` for the `timelines` field.
+        static constexpr auto Descriptor_timelines = ComponentDescriptor(
+            ArchetypeName, "timelines",
+            Loggable<rerun::blueprint::components::TimelineName>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `times` field.
+        static constexpr auto Descriptor_times = ComponentDescriptor(
+            ArchetypeName, "times",
+            Loggable<rerun::blueprint::components::BookmarkTime>::Descriptor.component_name
+        );
+        /// `ComponentDescriptor` for the `labels` field.
+        static constexpr auto Descriptor_labels = ComponentDescriptor(
+            ArchetypeName, "labels", Loggable<rerun::components::Name>::Descriptor.component_name
+        );
+
+      public:
+        TimelineBookmarks() = default;
+        TimelineBookmarks(TimelineBookmarks&& other) = default;
+        TimelineBookmarks(const TimelineBookmarks& other) = default;
+        TimelineBookmarks& operator=(const TimelineBookmarks& other) = default;
+        TimelineBookmarks& operator=(TimelineBookmarks&& other) = default;
+
+        /// Update only some specific fields of a `TimelineBookmarks`.
+        static TimelineBookmarks update_fields() {
+            return TimelineBookmarks();
+        }
+
+        /// Clear all the fields of a `TimelineBookmarks`.
+        static TimelineBookmarks clear_fields();
+
+        /// The timeline of each bookmark.
+        TimelineBookmarks with_timelines(
+            const Collection<rerun::blueprint::components::TimelineName>& _timelines
+        ) && {
+            timelines =
+                ComponentBatch::from_loggable(_timelines, Descriptor_timelines).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The time of each bookmark on its timeline.
+        TimelineBookmarks with_times(
+            const Collection<rerun::blueprint::components::BookmarkTime>& _times
+        ) && {
+            times = ComponentBatch::from_loggable(_times, Descriptor_times).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The label of each bookmark.
+        TimelineBookmarks with_labels(const Collection<rerun::components::Name>& _labels) && {
+            labels = ComponentBatch::from_loggable(_labels, Descriptor_labels).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentColumn::from_batch_with_lengths`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TimelineBookmarks> {
+        /// Serialize all set component batches.
+        static Result<std::vector<ComponentBatch>> serialize(
+            const blueprint::archetypes::TimelineBookmarks& archetype
+        );
+    };
+} // namespace rerun
@@ -7,11 +7,15 @@
 #include "blueprint/components/auto_layout.hpp"
 #include "blueprint/components/auto_views.hpp"
 #include "blueprint/components/background_kind.hpp"
+#include "blueprint/components/bookmark_time.hpp"
 #include "blueprint/components/column_share.hpp"
+#include "blueprint/components/compared_recording.hpp"
 #include "blueprint/components/component_column_selector.hpp"
 #include "blueprint/components/container_kind.hpp"
 #include "blueprint/components/corner2d.hpp"
 #include "blueprint/components/enabled.hpp"
+#include "blueprint/components/eye3d_kind.hpp"
+#include "blueprint/components/field_of_view.hpp"
 #include "blueprint/components/filter_by_range.hpp"
 #include "blueprint/components/filter_is_not_null.hpp"
 #include "blueprint/components/force_distance.hpp"
@@ -22,7 +26,9 @@
 #include "blueprint/components/included_content.hpp"
 #include "blueprint/components/interactive.hpp"
 #include "blueprint/components/lock_range_during_zoom.hpp"
+#include "blueprint/components/look_target.hpp"
 #include "blueprint/components/map_provider.hpp"
+#include "blueprint/components/measurement_kind.hpp"
 #include "blueprint/components/near_clip_plane.hpp"
 #include "blueprint/components/panel_state.hpp"
 #include "blueprint/components/query_expression.hpp"
@@ -30,7 +36,9 @@
 #include "blueprint/components/row_share.hpp"
 #include "blueprint/components/selected_columns.hpp"
 #include "blueprint/components/tensor_dimension_index_slider.hpp"
+#include "blueprint/components/time_offset.hpp"
 #include "blueprint/components/timeline_name.hpp"
+#include "blueprint/components/use_regex.hpp"
 #include "blueprint/components/view_class.hpp"
 #include "blueprint/components/view_fit.hpp"
 #include "blueprint/components/view_maximized.hpp"
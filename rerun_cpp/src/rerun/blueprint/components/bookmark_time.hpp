@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/bookmark_time.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/time_int.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The time of a bookmark on a timeline.
+    struct BookmarkTime {
+        rerun::datatypes::TimeInt time;
+
+      public:
+        BookmarkTime() = default;
+
+        BookmarkTime(rerun::datatypes::TimeInt time_) : time(time_) {}
+
+        BookmarkTime& operator=(rerun::datatypes::TimeInt time_) {
+            time = time_;
+            return *this;
+        }
+
+        BookmarkTime(int64_t value_) : time(value_) {}
+
+        BookmarkTime& operator=(int64_t value_) {
+            time = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying TimeInt datatype
+        operator rerun::datatypes::TimeInt() const {
+            return time;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::TimeInt) == sizeof(blueprint::components::BookmarkTime));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::BookmarkTime> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.components.BookmarkTime";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::TimeInt>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::BookmarkTime` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::BookmarkTime* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(
+                    &instances->time,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
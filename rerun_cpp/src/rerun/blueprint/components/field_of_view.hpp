@@ -0,0 +1,75 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/field_of_view.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/float32.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Vertical field of view of a 3D eye.
+    struct FieldOfView {
+        /// Vertical field of view in radians.
+        rerun::datatypes::Float32 angle;
+
+      public:
+        FieldOfView() = default;
+
+        FieldOfView(rerun::datatypes::Float32 angle_) : angle(angle_) {}
+
+        FieldOfView& operator=(rerun::datatypes::Float32 angle_) {
+            angle = angle_;
+            return *this;
+        }
+
+        FieldOfView(float value_) : angle(value_) {}
+
+        FieldOfView& operator=(float value_) {
+            angle = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Float32 datatype
+        operator rerun::datatypes::Float32() const {
+            return angle;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Float32) == sizeof(blueprint::components::FieldOfView));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::FieldOfView> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.components.FieldOfView";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Float32>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::FieldOfView` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::FieldOfView* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Float32>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Float32>::to_arrow(
+                    &instances->angle,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/look_target.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/vec3d.hpp"
+#include "../../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The point in 3D space an eye is looking at.
+    struct LookTarget {
+        /// The point in 3D scene units.
+        rerun::datatypes::Vec3D position;
+
+      public:
+        LookTarget() = default;
+
+        LookTarget(rerun::datatypes::Vec3D position_) : position(position_) {}
+
+        LookTarget& operator=(rerun::datatypes::Vec3D position_) {
+            position = position_;
+            return *this;
+        }
+
+        LookTarget(std::array<float, 3> xyz_) : position(xyz_) {}
+
+        LookTarget& operator=(std::array<float, 3> xyz_) {
+            position = xyz_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec3D datatype
+        operator rerun::datatypes::Vec3D() const {
+            return position;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Vec3D) == sizeof(blueprint::components::LookTarget));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::LookTarget> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.components.LookTarget";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Vec3D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::LookTarget` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::LookTarget* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Vec3D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Vec3D>::to_arrow(
+                    &instances->position,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/time_offset.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/time_int.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: A shift applied to all times of a recording.
+    struct TimeOffset {
+        rerun::datatypes::TimeInt offset;
+
+      public:
+        TimeOffset() = default;
+
+        TimeOffset(rerun::datatypes::TimeInt offset_) : offset(offset_) {}
+
+        TimeOffset& operator=(rerun::datatypes::TimeInt offset_) {
+            offset = offset_;
+            return *this;
+        }
+
+        TimeOffset(int64_t value_) : offset(value_) {}
+
+        TimeOffset& operator=(int64_t value_) {
+            offset = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying TimeInt datatype
+        operator rerun::datatypes::TimeInt() const {
+            return offset;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::TimeInt) == sizeof(blueprint::components::TimeOffset));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TimeOffset> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.components.TimeOffset";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::TimeInt>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TimeOffset` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TimeOffset* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(
+                    &instances->offset,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
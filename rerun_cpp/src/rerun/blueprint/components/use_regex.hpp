@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/use_regex.fbs".
+
+#pragma once
+
+#include "../../component_descriptor.hpp"
+#include "../../datatypes/bool.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: Whether a search query is a regular expression rather than a plain substring.
+    struct UseRegex {
+        rerun::datatypes::Bool use_regex;
+
+      public:
+        UseRegex() = default;
+
+        UseRegex(rerun::datatypes::Bool use_regex_) : use_regex(use_regex_) {}
+
+        UseRegex& operator=(rerun::datatypes::Bool use_regex_) {
+            use_regex = use_regex_;
+            return *this;
+        }
+
+        UseRegex(bool value_) : use_regex(value_) {}
+
+        UseRegex& operator=(bool value_) {
+            use_regex = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Bool datatype
+        operator rerun::datatypes::Bool() const {
+            return use_regex;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Bool) == sizeof(blueprint::components::UseRegex));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::UseRegex> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.blueprint.components.UseRegex";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Bool>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::UseRegex` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::UseRegex* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Bool>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Bool>::to_arrow(
+                    &instances->use_regex,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
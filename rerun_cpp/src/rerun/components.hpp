@@ -7,13 +7,17 @@
 #include "components/annotation_context.hpp"
 #include "components/axis_length.hpp"
 #include "components/blob.hpp"
+#include "components/cell_size.hpp"
 #include "components/class_id.hpp"
 #include "components/clear_is_recursive.hpp"
+#include "components/clock_drift.hpp"
+#include "components/clock_offset.hpp"
 #include "components/color.hpp"
 #include "components/colormap.hpp"
 #include "components/depth_meter.hpp"
 #include "components/draw_order.hpp"
 #include "components/entity_path.hpp"
+#include "components/event_duration.hpp"
 #include "components/fill_mode.hpp"
 #include "components/fill_ratio.hpp"
 #include "components/gamma_correction.hpp"
@@ -29,6 +33,7 @@
 #include "components/keypoint_id.hpp"
 #include "components/lat_lon.hpp"
 #include "components/length.hpp"
+#include "components/lens_distortion_model.hpp"
 #include "components/line_strip2d.hpp"
 #include "components/line_strip3d.hpp"
 #include "components/magnification_filter.hpp"
@@ -46,6 +51,7 @@
 #include "components/pose_translation3d.hpp"
 #include "components/position2d.hpp"
 #include "components/position3d.hpp"
+#include "components/radial_distortion.hpp"
 #include "components/radius.hpp"
 #include "components/range1d.hpp"
 #include "components/recording_uri.hpp"
@@ -55,13 +61,17 @@
 #include "components/scalar.hpp"
 #include "components/scale3d.hpp"
 #include "components/show_labels.hpp"
+#include "components/source_timeline.hpp"
 #include "components/stroke_width.hpp"
+#include "components/tangential_distortion.hpp"
+#include "components/target_timeline.hpp"
 #include "components/tensor_data.hpp"
 #include "components/tensor_dimension_index_selection.hpp"
 #include "components/tensor_height_dimension.hpp"
 #include "components/tensor_width_dimension.hpp"
 #include "components/texcoord2d.hpp"
 #include "components/text.hpp"
+#include "components/text_log_field.hpp"
 #include "components/text_log_level.hpp"
 #include "components/transform_mat3x3.hpp"
 #include "components/transform_relation.hpp"
@@ -72,3 +82,5 @@
 #include "components/vector3d.hpp"
 #include "components/video_timestamp.hpp"
 #include "components/view_coordinates.hpp"
+#include "components/voxel_index.hpp"
+#include "components/voxel_size.hpp"
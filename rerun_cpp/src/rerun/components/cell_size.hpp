@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/cell_size.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/vec2d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: The size of a single cell of a 2D grid along each axis.
+    ///
+    /// Measured in the local coordinate system of the grid.
+    struct CellSize {
+        rerun::datatypes::Vec2D xy;
+
+      public:
+        CellSize() = default;
+
+        CellSize(rerun::datatypes::Vec2D xy_) : xy(xy_) {}
+
+        CellSize& operator=(rerun::datatypes::Vec2D xy_) {
+            xy = xy_;
+            return *this;
+        }
+
+        CellSize(std::array<float, 2> xy_) : xy(xy_) {}
+
+        CellSize& operator=(std::array<float, 2> xy_) {
+            xy = xy_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec2D datatype
+        operator rerun::datatypes::Vec2D() const {
+            return xy;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Vec2D) == sizeof(components::CellSize));
+
+    /// \private
+    template <>
+    struct Loggable<components::CellSize> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.CellSize";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Vec2D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::CellSize` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::CellSize* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Vec2D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Vec2D>::to_arrow(&instances->xy, num_instances);
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/clock_drift.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/float64.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: How much faster one clock runs than another, as a fraction.
+    ///
+    /// A drift of `0.001` means that the target clock advances by 1.001 units for every unit of the source clock.
+    struct ClockDrift {
+        rerun::datatypes::Float64 value;
+
+      public:
+        ClockDrift() = default;
+
+        ClockDrift(rerun::datatypes::Float64 value_) : value(value_) {}
+
+        ClockDrift& operator=(rerun::datatypes::Float64 value_) {
+            value = value_;
+            return *this;
+        }
+
+        ClockDrift(double value_) : value(value_) {}
+
+        ClockDrift& operator=(double value_) {
+            value = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Float64 datatype
+        operator rerun::datatypes::Float64() const {
+            return value;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Float64) == sizeof(components::ClockDrift));
+
+    /// \private
+    template <>
+    struct Loggable<components::ClockDrift> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.ClockDrift";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Float64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::ClockDrift` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::ClockDrift* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Float64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Float64>::to_arrow(
+                    &instances->value,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,77 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/clock_offset.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/time_int.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: A constant offset added to the times of a timeline to express them on another timeline.
+    ///
+    /// Measured in the units of the target timeline:
+    /// nanoseconds for temporal timelines, and steps for sequence timelines.
+    struct ClockOffset {
+        rerun::datatypes::TimeInt offset;
+
+      public:
+        ClockOffset() = default;
+
+        ClockOffset(rerun::datatypes::TimeInt offset_) : offset(offset_) {}
+
+        ClockOffset& operator=(rerun::datatypes::TimeInt offset_) {
+            offset = offset_;
+            return *this;
+        }
+
+        ClockOffset(int64_t value_) : offset(value_) {}
+
+        ClockOffset& operator=(int64_t value_) {
+            offset = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying TimeInt datatype
+        operator rerun::datatypes::TimeInt() const {
+            return offset;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::TimeInt) == sizeof(components::ClockOffset));
+
+    /// \private
+    template <>
+    struct Loggable<components::ClockOffset> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.ClockOffset";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::TimeInt>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::ClockOffset` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::ClockOffset* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(
+                    &instances->offset,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
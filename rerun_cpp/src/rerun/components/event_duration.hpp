@@ -0,0 +1,77 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/event_duration.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/time_int.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: The duration of a `archetypes::TimelineEvent`.
+    ///
+    /// Measured in the units of the timeline the event is shown on:
+    /// nanoseconds for temporal timelines, and steps for sequence timelines.
+    struct EventDuration {
+        rerun::datatypes::TimeInt duration;
+
+      public:
+        EventDuration() = default;
+
+        EventDuration(rerun::datatypes::TimeInt duration_) : duration(duration_) {}
+
+        EventDuration& operator=(rerun::datatypes::TimeInt duration_) {
+            duration = duration_;
+            return *this;
+        }
+
+        EventDuration(int64_t value_) : duration(value_) {}
+
+        EventDuration& operator=(int64_t value_) {
+            duration = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying TimeInt datatype
+        operator rerun::datatypes::TimeInt() const {
+            return duration;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::TimeInt) == sizeof(components::EventDuration));
+
+    /// \private
+    template <>
+    struct Loggable<components::EventDuration> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.EventDuration";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::TimeInt>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::EventDuration` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::EventDuration* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(
+                    &instances->duration,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
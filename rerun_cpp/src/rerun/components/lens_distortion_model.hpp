@@ -0,0 +1,83 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/lens_distortion_model.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The lens distortion model of a camera, applied on top of its pinhole projection.
+    ///
+    /// The model determines how `components::RadialDistortion` and `components::TangentialDistortion`
+    /// are interpreted.
+    /// All models operate on normalized image coordinates, i.e. `(x, y) = (X / Z, Y / Z)`
+    /// for a point `(X, Y, Z)` in camera space, before the focal length and principal point are applied.
+    enum class LensDistortionModel : uint8_t {
+
+        /// The Brown–Conrady model with radial and tangential distortion, e.g. OpenCV's default camera model.
+        ///
+        /// With `r² = x² + y²`, normalized image coordinates are distorted as:
+        /// ```text
+        /// radial = 1 + k1·r² + k2·r⁴ + k3·r⁶ + k4·r⁸
+        /// x' = x·radial + 2·p1·x·y + p2·(r² + 2·x²)
+        /// y' = y·radial + p1·(r² + 2·y²) + 2·p2·x·y
+        /// ```
+        /// The `k4·r⁸` term is an extension of OpenCV's model, which stops at `k3·r⁶`.
+        /// Leave `k4` at zero for calibrations from OpenCV or most other tools.
+        /// In particular, the `k4` of OpenCV's rational model (`CALIB_RATIONAL_MODEL`) is a
+        /// denominator coefficient and can't be represented here.
+        ///
+        /// Note that OpenCV orders its distortion coefficients as `[k1, k2, p1, p2, k3]`.
+        BrownConrady = 1,
+
+        /// The Kannala–Brandt equidistant fisheye model, e.g. OpenCV's fisheye camera model.
+        ///
+        /// With `θ = atan(r)` the angle of incidence, normalized image coordinates are distorted as:
+        /// ```text
+        /// θ' = θ·(1 + k1·θ² + k2·θ⁴ + k3·θ⁶ + k4·θ⁸)
+        /// (x', y') = (θ' / r)·(x, y)
+        /// ```
+        /// Tangential distortion is ignored by this model.
+        KannalaBrandt = 2,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::LensDistortionModel> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.LensDistortionModel";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::LensDistortionModel` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::LensDistortionModel* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const components::LensDistortionModel* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
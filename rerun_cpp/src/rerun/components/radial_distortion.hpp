@@ -0,0 +1,79 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/radial_distortion.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/vec4d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: Radial lens distortion coefficients `[k1, k2, k3, k4]`.
+    ///
+    /// How they are applied depends on the `components::LensDistortionModel`.
+    /// For `components::LensDistortionModel::BrownConrady`, `k4` is a non-standard `k4·r⁸` term
+    /// which should be left at zero for OpenCV calibrations.
+    struct RadialDistortion {
+        rerun::datatypes::Vec4D coefficients;
+
+      public:
+        RadialDistortion() = default;
+
+        RadialDistortion(rerun::datatypes::Vec4D coefficients_) : coefficients(coefficients_) {}
+
+        RadialDistortion& operator=(rerun::datatypes::Vec4D coefficients_) {
+            coefficients = coefficients_;
+            return *this;
+        }
+
+        RadialDistortion(std::array<float, 4> xyzw_) : coefficients(xyzw_) {}
+
+        RadialDistortion& operator=(std::array<float, 4> xyzw_) {
+            coefficients = xyzw_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec4D datatype
+        operator rerun::datatypes::Vec4D() const {
+            return coefficients;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Vec4D) == sizeof(components::RadialDistortion));
+
+    /// \private
+    template <>
+    struct Loggable<components::RadialDistortion> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.RadialDistortion";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Vec4D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::RadialDistortion` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::RadialDistortion* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Vec4D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Vec4D>::to_arrow(
+                    &instances->coefficients,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,77 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/tangential_distortion.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/vec2d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: Tangential lens distortion coefficients `[p1, p2]`.
+    ///
+    /// Only used by the `components::LensDistortionModel::BrownConrady` model.
+    struct TangentialDistortion {
+        rerun::datatypes::Vec2D coefficients;
+
+      public:
+        TangentialDistortion() = default;
+
+        TangentialDistortion(rerun::datatypes::Vec2D coefficients_) : coefficients(coefficients_) {}
+
+        TangentialDistortion& operator=(rerun::datatypes::Vec2D coefficients_) {
+            coefficients = coefficients_;
+            return *this;
+        }
+
+        TangentialDistortion(std::array<float, 2> xy_) : coefficients(xy_) {}
+
+        TangentialDistortion& operator=(std::array<float, 2> xy_) {
+            coefficients = xy_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec2D datatype
+        operator rerun::datatypes::Vec2D() const {
+            return coefficients;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Vec2D) == sizeof(components::TangentialDistortion));
+
+    /// \private
+    template <>
+    struct Loggable<components::TangentialDistortion> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.TangentialDistortion";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Vec2D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::TangentialDistortion` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::TangentialDistortion* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Vec2D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Vec2D>::to_arrow(
+                    &instances->coefficients,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
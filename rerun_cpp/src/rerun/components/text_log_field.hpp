@@ -0,0 +1,68 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/text_log_field.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/utf8pair.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <utility>
+
+namespace rerun::components {
+    /// **Component**: A key-value field of a structured log entry, e.g. `("request_id", "42")`.
+    struct TextLogField {
+        rerun::datatypes::Utf8Pair field;
+
+      public:
+        TextLogField() = default;
+
+        TextLogField(rerun::datatypes::Utf8Pair field_) : field(std::move(field_)) {}
+
+        TextLogField& operator=(rerun::datatypes::Utf8Pair field_) {
+            field = std::move(field_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8Pair datatype
+        operator rerun::datatypes::Utf8Pair() const {
+            return field;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8Pair) == sizeof(components::TextLogField));
+
+    /// \private
+    template <>
+    struct Loggable<components::TextLogField> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.TextLogField";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8Pair>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::TextLogField` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::TextLogField* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8Pair>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8Pair>::to_arrow(
+                    &instances->field,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/voxel_index.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/uvec3d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: The integer coordinates of a voxel in a voxel grid.
+    ///
+    /// Voxel `[0, 0, 0]` is the one at the origin of the grid.
+    struct VoxelIndex {
+        rerun::datatypes::UVec3D xyz;
+
+      public:
+        VoxelIndex() = default;
+
+        VoxelIndex(rerun::datatypes::UVec3D xyz_) : xyz(xyz_) {}
+
+        VoxelIndex& operator=(rerun::datatypes::UVec3D xyz_) {
+            xyz = xyz_;
+            return *this;
+        }
+
+        VoxelIndex(std::array<uint32_t, 3> xyz_) : xyz(xyz_) {}
+
+        VoxelIndex& operator=(std::array<uint32_t, 3> xyz_) {
+            xyz = xyz_;
+            return *this;
+        }
+
+        /// Cast to the underlying UVec3D datatype
+        operator rerun::datatypes::UVec3D() const {
+            return xyz;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::UVec3D) == sizeof(components::VoxelIndex));
+
+    /// \private
+    template <>
+    struct Loggable<components::VoxelIndex> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.VoxelIndex";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UVec3D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::VoxelIndex` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::VoxelIndex* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UVec3D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UVec3D>::to_arrow(&instances->xyz, num_instances);
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,74 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/voxel_size.fbs".
+
+#pragma once
+
+#include "../component_descriptor.hpp"
+#include "../datatypes/vec3d.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: The size of a single voxel of a voxel grid along each axis.
+    ///
+    /// Measured in the local coordinate system of the grid.
+    struct VoxelSize {
+        rerun::datatypes::Vec3D xyz;
+
+      public:
+        VoxelSize() = default;
+
+        VoxelSize(rerun::datatypes::Vec3D xyz_) : xyz(xyz_) {}
+
+        VoxelSize& operator=(rerun::datatypes::Vec3D xyz_) {
+            xyz = xyz_;
+            return *this;
+        }
+
+        VoxelSize(std::array<float, 3> xyz_) : xyz(xyz_) {}
+
+        VoxelSize& operator=(std::array<float, 3> xyz_) {
+            xyz = xyz_;
+            return *this;
+        }
+
+        /// Cast to the underlying Vec3D datatype
+        operator rerun::datatypes::Vec3D() const {
+            return xyz;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Vec3D) == sizeof(components::VoxelSize));
+
+    /// \private
+    template <>
+    struct Loggable<components::VoxelSize> {
+        static constexpr ComponentDescriptor Descriptor = "rerun.components.VoxelSize";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Vec3D>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::VoxelSize` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::VoxelSize* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Vec3D>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Vec3D>::to_arrow(&instances->xyz, num_instances);
+            }
+        }
+    };
+} // namespace rerun
@@ -19,11 +19,13 @@ from .geo_line_strings import GeoLineStrings
 from .geo_points import GeoPoints
 from .graph_edges import GraphEdges
 from .graph_nodes import GraphNodes
+from .height_field3d import HeightField3D
 from .image import Image
 from .instance_poses3d import InstancePoses3D
 from .line_strips2d import LineStrips2D
 from .line_strips3d import LineStrips3D
 from .mesh3d import Mesh3D
+from .occupancy_grid import OccupancyGrid
 from .pinhole import Pinhole
 from .points2d import Points2D
 from .points3d import Points3D
@@ -34,9 +36,12 @@ from .series_point import SeriesPoint
 from .tensor import Tensor
 from .text_document import TextDocument
 from .text_log import TextLog
+from .timeline_event import TimelineEvent
+from .timeline_mapping import TimelineMapping
 from .transform3d import Transform3D
 from .video_frame_reference import VideoFrameReference
 from .view_coordinates import ViewCoordinates
+from .voxel_grid3d import VoxelGrid3D
 
 __all__ = [
     "AnnotationContext",
@@ -56,11 +61,13 @@ __all__ = [
     "GeoPoints",
     "GraphEdges",
     "GraphNodes",
+    "HeightField3D",
     "Image",
     "InstancePoses3D",
     "LineStrips2D",
     "LineStrips3D",
     "Mesh3D",
+    "OccupancyGrid",
     "Pinhole",
     "Points2D",
     "Points3D",
@@ -71,7 +78,10 @@ __all__ = [
     "Tensor",
     "TextDocument",
     "TextLog",
+    "TimelineEvent",
+    "TimelineMapping",
     "Transform3D",
     "VideoFrameReference",
     "ViewCoordinates",
+    "VoxelGrid3D",
 ]
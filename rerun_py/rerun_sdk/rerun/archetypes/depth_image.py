@@ -76,6 +76,7 @@ class DepthImage(DepthImageExt, Archetype):
             depth_range=None,
             point_fill_ratio=None,
             draw_order=None,
+            color_image_reference=None,
         )
 
     @classmethod
@@ -97,6 +98,7 @@ class DepthImage(DepthImageExt, Archetype):
         depth_range: datatypes.Range1DLike | None = None,
         point_fill_ratio: datatypes.Float32Like | None = None,
         draw_order: datatypes.Float32Like | None = None,
+        color_image_reference: datatypes.EntityPathLike | None = None,
     ) -> DepthImage:
         """
         Update only some specific fields of a `DepthImage`.
@@ -145,6 +147,16 @@ class DepthImage(DepthImageExt, Archetype):
             An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
 
             Objects with higher values are drawn on top of those with lower values.
+        color_image_reference:
+            An optional path to a color image that is registered with this depth image.
+
+            If set, the points of the point cloud generated from this image are colored by projecting them
+            into the referenced [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] instead of using the [`components.Colormap`][rerun.components.Colormap].
+            If the color image is logged under a different [`archetypes.Pinhole`][rerun.archetypes.Pinhole] than the depth image,
+            the intrinsics of that camera and the transform between the two cameras are taken into account.
+            Points that aren't seen by the color camera fall back to the colormap.
+
+            TODO(#6744): This applies only to 3D views!
 
         """
 
@@ -158,6 +170,7 @@ class DepthImage(DepthImageExt, Archetype):
                 "depth_range": depth_range,
                 "point_fill_ratio": point_fill_ratio,
                 "draw_order": draw_order,
+                "color_image_reference": color_image_reference,
             }
 
             if clear_unset:
@@ -185,6 +198,7 @@ class DepthImage(DepthImageExt, Archetype):
         depth_range: datatypes.Range1DArrayLike | None = None,
         point_fill_ratio: datatypes.Float32ArrayLike | None = None,
         draw_order: datatypes.Float32ArrayLike | None = None,
+        color_image_reference: datatypes.EntityPathArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -236,6 +250,16 @@ class DepthImage(DepthImageExt, Archetype):
             An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
 
             Objects with higher values are drawn on top of those with lower values.
+        color_image_reference:
+            An optional path to a color image that is registered with this depth image.
+
+            If set, the points of the point cloud generated from this image are colored by projecting them
+            into the referenced [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] instead of using the [`components.Colormap`][rerun.components.Colormap].
+            If the color image is logged under a different [`archetypes.Pinhole`][rerun.archetypes.Pinhole] than the depth image,
+            the intrinsics of that camera and the transform between the two cameras are taken into account.
+            Points that aren't seen by the color camera fall back to the colormap.
+
+            TODO(#6744): This applies only to 3D views!
 
         """
 
@@ -249,6 +273,7 @@ class DepthImage(DepthImageExt, Archetype):
                 depth_range=depth_range,
                 point_fill_ratio=point_fill_ratio,
                 draw_order=draw_order,
+                color_image_reference=color_image_reference,
             )
 
         batches = inst.as_component_batches(include_indicators=False)
@@ -351,5 +376,22 @@ class DepthImage(DepthImageExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    color_image_reference: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # An optional path to a color image that is registered with this depth image.
+    #
+    # If set, the points of the point cloud generated from this image are colored by projecting them
+    # into the referenced [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] instead of using the [`components.Colormap`][rerun.components.Colormap].
+    # If the color image is logged under a different [`archetypes.Pinhole`][rerun.archetypes.Pinhole] than the depth image,
+    # the intrinsics of that camera and the transform between the two cameras are taken into account.
+    # Points that aren't seen by the color camera fall back to the colormap.
+    #
+    # TODO(#6744): This applies only to 3D views!
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,359 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/height_field3d.fbs".
+
+# You can extend this class by creating a "HeightField3DExt" class in "height_field3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["HeightField3D"]
+
+
+@define(str=False, repr=False, init=False)
+class HeightField3D(Archetype):
+    """
+    **Archetype**: A surface spanned over a regular grid of heights, e.g. a terrain or an elevation map.
+
+    The heights are stored like a single-channel image: height `[x, y]` is placed at
+    `origin + [x, y] * cell_size` in the XY plane of the entity's local coordinate system,
+    and the surface extends from there along +Z.
+    Non-finite heights (e.g. NaN) leave holes in the surface.
+
+    The surface is shaded and colored either with a single [`components.Color`][rerun.components.Color], by height through a
+    [`components.Colormap`][rerun.components.Colormap], or with an image draped over it via [`components.EntityPath`][rerun.components.EntityPath].
+    Logging a new height map every frame is cheap since only the heights are uploaded to the GPU.
+    """
+
+    def __init__(
+        self: Any,
+        buffer: datatypes.BlobLike,
+        format: datatypes.ImageFormatLike,
+        *,
+        cell_size: datatypes.Vec2DLike | None = None,
+        origin: datatypes.Vec2DLike | None = None,
+        color: datatypes.Rgba32Like | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+        texture_reference: datatypes.EntityPathLike | None = None,
+    ):
+        """
+        Create a new instance of the HeightField3D archetype.
+
+        Parameters
+        ----------
+        buffer:
+            The heights, row by row.
+        format:
+            The format of the heights.
+
+            Must be single-channel.
+        cell_size:
+            The distance between neighboring heights along each axis.
+
+            If not specified, heights are one unit apart.
+        origin:
+            The position of height `[0, 0]` in the XY plane.
+
+            If not specified, the grid starts at the origin.
+        color:
+            Color of the surface, if neither a colormap nor a texture is used.
+        colormap:
+            If set, the surface is colored by height using this colormap.
+        value_range:
+            The range of heights that is mapped to the colormap.
+
+            If not specified, the range of the heights is used.
+        texture_reference:
+            Path to an [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] that is stretched over the entire surface.
+
+            Takes precedence over the color and the colormap.
+            The image has to be logged to a different entity than the height field.
+
+        """
+
+        # You can define your own __init__ function as a member of HeightField3DExt in height_field3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                buffer=buffer,
+                format=format,
+                cell_size=cell_size,
+                origin=origin,
+                color=color,
+                colormap=colormap,
+                value_range=value_range,
+                texture_reference=texture_reference,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            buffer=None,
+            format=None,
+            cell_size=None,
+            origin=None,
+            color=None,
+            colormap=None,
+            value_range=None,
+            texture_reference=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> HeightField3D:
+        """Produce an empty HeightField3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        buffer: datatypes.BlobLike | None = None,
+        format: datatypes.ImageFormatLike | None = None,
+        cell_size: datatypes.Vec2DLike | None = None,
+        origin: datatypes.Vec2DLike | None = None,
+        color: datatypes.Rgba32Like | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+        texture_reference: datatypes.EntityPathLike | None = None,
+    ) -> HeightField3D:
+        """
+        Update only some specific fields of a `HeightField3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        buffer:
+            The heights, row by row.
+        format:
+            The format of the heights.
+
+            Must be single-channel.
+        cell_size:
+            The distance between neighboring heights along each axis.
+
+            If not specified, heights are one unit apart.
+        origin:
+            The position of height `[0, 0]` in the XY plane.
+
+            If not specified, the grid starts at the origin.
+        color:
+            Color of the surface, if neither a colormap nor a texture is used.
+        colormap:
+            If set, the surface is colored by height using this colormap.
+        value_range:
+            The range of heights that is mapped to the colormap.
+
+            If not specified, the range of the heights is used.
+        texture_reference:
+            Path to an [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] that is stretched over the entire surface.
+
+            Takes precedence over the color and the colormap.
+            The image has to be logged to a different entity than the height field.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "buffer": buffer,
+                "format": format,
+                "cell_size": cell_size,
+                "origin": origin,
+                "color": color,
+                "colormap": colormap,
+                "value_range": value_range,
+                "texture_reference": texture_reference,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> HeightField3D:
+        """Clear all the fields of a `HeightField3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        buffer: datatypes.BlobArrayLike | None = None,
+        format: datatypes.ImageFormatArrayLike | None = None,
+        cell_size: datatypes.Vec2DArrayLike | None = None,
+        origin: datatypes.Vec2DArrayLike | None = None,
+        color: datatypes.Rgba32ArrayLike | None = None,
+        colormap: components.ColormapArrayLike | None = None,
+        value_range: datatypes.Range1DArrayLike | None = None,
+        texture_reference: datatypes.EntityPathArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        buffer:
+            The heights, row by row.
+        format:
+            The format of the heights.
+
+            Must be single-channel.
+        cell_size:
+            The distance between neighboring heights along each axis.
+
+            If not specified, heights are one unit apart.
+        origin:
+            The position of height `[0, 0]` in the XY plane.
+
+            If not specified, the grid starts at the origin.
+        color:
+            Color of the surface, if neither a colormap nor a texture is used.
+        colormap:
+            If set, the surface is colored by height using this colormap.
+        value_range:
+            The range of heights that is mapped to the colormap.
+
+            If not specified, the range of the heights is used.
+        texture_reference:
+            Path to an [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] that is stretched over the entire surface.
+
+            Takes precedence over the color and the colormap.
+            The image has to be logged to a different entity than the height field.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                buffer=buffer,
+                format=format,
+                cell_size=cell_size,
+                origin=origin,
+                color=color,
+                colormap=colormap,
+                value_range=value_range,
+                texture_reference=texture_reference,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        lengths = np.ones(len(batches[0]._batch.as_arrow_array()))
+        columns = [batch.partition(lengths) for batch in batches]
+
+        indicator_column = cls.indicator().partition(np.zeros(len(lengths)))
+
+        return ComponentColumnList([indicator_column] + columns)
+
+    buffer: components.ImageBufferBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImageBufferBatch._converter,  # type: ignore[misc]
+    )
+    # The heights, row by row.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    format: components.ImageFormatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImageFormatBatch._converter,  # type: ignore[misc]
+    )
+    # The format of the heights.
+    #
+    # Must be single-channel.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    cell_size: components.CellSizeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.CellSizeBatch._converter,  # type: ignore[misc]
+    )
+    # The distance between neighboring heights along each axis.
+    #
+    # If not specified, heights are one unit apart.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    origin: components.Position2DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position2DBatch._converter,  # type: ignore[misc]
+    )
+    # The position of height `[0, 0]` in the XY plane.
+    #
+    # If not specified, the grid starts at the origin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    color: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Color of the surface, if neither a colormap nor a texture is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # If set, the surface is colored by height using this colormap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    value_range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The range of heights that is mapped to the colormap.
+    #
+    # If not specified, the range of the heights is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    texture_reference: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # Path to an [`archetypes.Image`][rerun.archetypes.Image] or [`archetypes.EncodedImage`][rerun.archetypes.EncodedImage] that is stretched over the entire surface.
+    #
+    # Takes precedence over the color and the colormap.
+    # The image has to be logged to a different entity than the height field.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,373 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/occupancy_grid.fbs".
+
+# You can extend this class by creating a "OccupancyGridExt" class in "occupancy_grid_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["OccupancyGrid"]
+
+
+@define(str=False, repr=False, init=False)
+class OccupancyGrid(Archetype):
+    """
+    **Archetype**: A 2D occupancy grid, e.g. a map built by a robot.
+
+    Each cell holds a single value, typically the probability of the cell being occupied.
+    Cell `[x, y]` spans from `origin + [x, y] * cell_size` to `origin + [x + 1, y + 1] * cell_size`
+    in the local coordinate system of the entity. In 3D views the grid lies in the XY plane.
+
+    The cell values are stored like a single-channel image, e.g. an `I8` image following the ROS convention
+    of probabilities from 0 to 100, and are mapped to colors through the [`components.Colormap`][rerun.components.Colormap].
+    """
+
+    def __init__(
+        self: Any,
+        buffer: datatypes.BlobLike,
+        format: datatypes.ImageFormatLike,
+        *,
+        cell_size: datatypes.Vec2DLike | None = None,
+        origin: datatypes.Vec2DLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        draw_order: datatypes.Float32Like | None = None,
+    ):
+        """
+        Create a new instance of the OccupancyGrid archetype.
+
+        Parameters
+        ----------
+        buffer:
+            The raw cell values, row by row.
+        format:
+            The format of the cell values.
+
+            Must be single-channel.
+        cell_size:
+            The size of a single cell along each axis.
+
+            If not specified, cells are one unit large.
+        origin:
+            The position of the corner of cell `[0, 0]`.
+
+            If not specified, the grid starts at the origin.
+        colormap:
+            Colormap to use for mapping cell values to colors.
+
+            If not set, the grayscale colormap is used.
+        value_range:
+            The range of cell values that is mapped to the colormap.
+
+            If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+            with the default colormap.
+        opacity:
+            Opacity of the grid, useful for layering it over other content.
+
+            Defaults to 1.0 (fully opaque).
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order.
+
+            Objects with higher values are drawn on top of those with lower values.
+
+        """
+
+        # You can define your own __init__ function as a member of OccupancyGridExt in occupancy_grid_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                buffer=buffer,
+                format=format,
+                cell_size=cell_size,
+                origin=origin,
+                colormap=colormap,
+                value_range=value_range,
+                opacity=opacity,
+                draw_order=draw_order,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            buffer=None,
+            format=None,
+            cell_size=None,
+            origin=None,
+            colormap=None,
+            value_range=None,
+            opacity=None,
+            draw_order=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> OccupancyGrid:
+        """Produce an empty OccupancyGrid, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        buffer: datatypes.BlobLike | None = None,
+        format: datatypes.ImageFormatLike | None = None,
+        cell_size: datatypes.Vec2DLike | None = None,
+        origin: datatypes.Vec2DLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        draw_order: datatypes.Float32Like | None = None,
+    ) -> OccupancyGrid:
+        """
+        Update only some specific fields of a `OccupancyGrid`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        buffer:
+            The raw cell values, row by row.
+        format:
+            The format of the cell values.
+
+            Must be single-channel.
+        cell_size:
+            The size of a single cell along each axis.
+
+            If not specified, cells are one unit large.
+        origin:
+            The position of the corner of cell `[0, 0]`.
+
+            If not specified, the grid starts at the origin.
+        colormap:
+            Colormap to use for mapping cell values to colors.
+
+            If not set, the grayscale colormap is used.
+        value_range:
+            The range of cell values that is mapped to the colormap.
+
+            If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+            with the default colormap.
+        opacity:
+            Opacity of the grid, useful for layering it over other content.
+
+            Defaults to 1.0 (fully opaque).
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order.
+
+            Objects with higher values are drawn on top of those with lower values.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "buffer": buffer,
+                "format": format,
+                "cell_size": cell_size,
+                "origin": origin,
+                "colormap": colormap,
+                "value_range": value_range,
+                "opacity": opacity,
+                "draw_order": draw_order,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> OccupancyGrid:
+        """Clear all the fields of a `OccupancyGrid`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        buffer: datatypes.BlobArrayLike | None = None,
+        format: datatypes.ImageFormatArrayLike | None = None,
+        cell_size: datatypes.Vec2DArrayLike | None = None,
+        origin: datatypes.Vec2DArrayLike | None = None,
+        colormap: components.ColormapArrayLike | None = None,
+        value_range: datatypes.Range1DArrayLike | None = None,
+        opacity: datatypes.Float32ArrayLike | None = None,
+        draw_order: datatypes.Float32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        buffer:
+            The raw cell values, row by row.
+        format:
+            The format of the cell values.
+
+            Must be single-channel.
+        cell_size:
+            The size of a single cell along each axis.
+
+            If not specified, cells are one unit large.
+        origin:
+            The position of the corner of cell `[0, 0]`.
+
+            If not specified, the grid starts at the origin.
+        colormap:
+            Colormap to use for mapping cell values to colors.
+
+            If not set, the grayscale colormap is used.
+        value_range:
+            The range of cell values that is mapped to the colormap.
+
+            If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+            with the default colormap.
+        opacity:
+            Opacity of the grid, useful for layering it over other content.
+
+            Defaults to 1.0 (fully opaque).
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order.
+
+            Objects with higher values are drawn on top of those with lower values.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                buffer=buffer,
+                format=format,
+                cell_size=cell_size,
+                origin=origin,
+                colormap=colormap,
+                value_range=value_range,
+                opacity=opacity,
+                draw_order=draw_order,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        lengths = np.ones(len(batches[0]._batch.as_arrow_array()))
+        columns = [batch.partition(lengths) for batch in batches]
+
+        indicator_column = cls.indicator().partition(np.zeros(len(lengths)))
+
+        return ComponentColumnList([indicator_column] + columns)
+
+    buffer: components.ImageBufferBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImageBufferBatch._converter,  # type: ignore[misc]
+    )
+    # The raw cell values, row by row.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    format: components.ImageFormatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ImageFormatBatch._converter,  # type: ignore[misc]
+    )
+    # The format of the cell values.
+    #
+    # Must be single-channel.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    cell_size: components.CellSizeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.CellSizeBatch._converter,  # type: ignore[misc]
+    )
+    # The size of a single cell along each axis.
+    #
+    # If not specified, cells are one unit large.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    origin: components.Position2DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position2DBatch._converter,  # type: ignore[misc]
+    )
+    # The position of the corner of cell `[0, 0]`.
+    #
+    # If not specified, the grid starts at the origin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # Colormap to use for mapping cell values to colors.
+    #
+    # If not set, the grayscale colormap is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    value_range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The range of cell values that is mapped to the colormap.
+    #
+    # If not specified, values from 100 to 0 are mapped, which shows free cells in white and occupied cells in black
+    # with the default colormap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacity: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # Opacity of the grid, useful for layering it over other content.
+    #
+    # Defaults to 1.0 (fully opaque).
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    draw_order: components.DrawOrderBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.DrawOrderBatch._converter,  # type: ignore[misc]
+    )
+    # An optional floating point value that specifies the 2D drawing order.
+    #
+    # Objects with higher values are drawn on top of those with lower values.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -82,6 +82,9 @@ class Pinhole(PinholeExt, Archetype):
             resolution=None,
             camera_xyz=None,
             image_plane_distance=None,
+            distortion_model=None,
+            radial_distortion=None,
+            tangential_distortion=None,
         )
 
     @classmethod
@@ -100,6 +103,9 @@ class Pinhole(PinholeExt, Archetype):
         resolution: datatypes.Vec2DLike | None = None,
         camera_xyz: datatypes.ViewCoordinatesLike | None = None,
         image_plane_distance: datatypes.Float32Like | None = None,
+        distortion_model: components.LensDistortionModelLike | None = None,
+        radial_distortion: datatypes.Vec4DLike | None = None,
+        tangential_distortion: datatypes.Vec2DLike | None = None,
     ) -> Pinhole:
         """
         Update only some specific fields of a `Pinhole`.
@@ -151,6 +157,20 @@ class Pinhole(PinholeExt, Archetype):
             The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.
 
             This is only used for visualization purposes, and does not affect the projection itself.
+        distortion_model:
+            The lens distortion model, determining how the distortion coefficients are applied.
+
+            Defaults to [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] if any distortion coefficients are set.
+        radial_distortion:
+            Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+
+            For the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model, `k4` is a non-standard `k4·r⁸` term
+            which should be left at zero for OpenCV calibrations.
+            If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+        tangential_distortion:
+            Tangential distortion coefficients `[p1, p2]` of the lens.
+
+            Only used by the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model.
 
         """
 
@@ -161,6 +181,9 @@ class Pinhole(PinholeExt, Archetype):
                 "resolution": resolution,
                 "camera_xyz": camera_xyz,
                 "image_plane_distance": image_plane_distance,
+                "distortion_model": distortion_model,
+                "radial_distortion": radial_distortion,
+                "tangential_distortion": tangential_distortion,
             }
 
             if clear_unset:
@@ -185,6 +208,9 @@ class Pinhole(PinholeExt, Archetype):
         resolution: datatypes.Vec2DArrayLike | None = None,
         camera_xyz: datatypes.ViewCoordinatesArrayLike | None = None,
         image_plane_distance: datatypes.Float32ArrayLike | None = None,
+        distortion_model: components.LensDistortionModelArrayLike | None = None,
+        radial_distortion: datatypes.Vec4DArrayLike | None = None,
+        tangential_distortion: datatypes.Vec2DArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -239,6 +265,20 @@ class Pinhole(PinholeExt, Archetype):
             The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.
 
             This is only used for visualization purposes, and does not affect the projection itself.
+        distortion_model:
+            The lens distortion model, determining how the distortion coefficients are applied.
+
+            Defaults to [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] if any distortion coefficients are set.
+        radial_distortion:
+            Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+
+            For the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model, `k4` is a non-standard `k4·r⁸` term
+            which should be left at zero for OpenCV calibrations.
+            If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+        tangential_distortion:
+            Tangential distortion coefficients `[p1, p2]` of the lens.
+
+            Only used by the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model.
 
         """
 
@@ -249,6 +289,9 @@ class Pinhole(PinholeExt, Archetype):
                 resolution=resolution,
                 camera_xyz=camera_xyz,
                 image_plane_distance=image_plane_distance,
+                distortion_model=distortion_model,
+                radial_distortion=radial_distortion,
+                tangential_distortion=tangential_distortion,
             )
 
         batches = inst.as_component_batches(include_indicators=False)
@@ -333,5 +376,40 @@ class Pinhole(PinholeExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    distortion_model: components.LensDistortionModelBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.LensDistortionModelBatch._converter,  # type: ignore[misc]
+    )
+    # The lens distortion model, determining how the distortion coefficients are applied.
+    #
+    # Defaults to [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] if any distortion coefficients are set.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    radial_distortion: components.RadialDistortionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RadialDistortionBatch._converter,  # type: ignore[misc]
+    )
+    # Radial distortion coefficients `[k1, k2, k3, k4]` of the lens.
+    #
+    # For the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model, `k4` is a non-standard `k4·r⁸` term
+    # which should be left at zero for OpenCV calibrations.
+    # If neither radial nor tangential distortion is set, the camera is treated as an ideal pinhole.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    tangential_distortion: components.TangentialDistortionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TangentialDistortionBatch._converter,  # type: ignore[misc]
+    )
+    # Tangential distortion coefficients `[p1, p2]` of the lens.
+    #
+    # Only used by the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
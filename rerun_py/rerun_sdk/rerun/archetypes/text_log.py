@@ -61,6 +61,7 @@ class TextLog(Archetype):
         *,
         level: datatypes.Utf8Like | None = None,
         color: datatypes.Rgba32Like | None = None,
+        fields: datatypes.Utf8PairArrayLike | None = None,
     ):
         """
         Create a new instance of the TextLog archetype.
@@ -75,12 +76,17 @@ class TextLog(Archetype):
             This can be used to filter the log messages in the Rerun Viewer.
         color:
             Optional color to use for the log line in the Rerun Viewer.
+        fields:
+            Optional key-value fields of a structured log entry.
+
+            The Rerun Viewer shows each key as a column of the text log.
+            The fields apply to all entries of the same row.
 
         """
 
         # You can define your own __init__ function as a member of TextLogExt in text_log_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(text=text, level=level, color=color)
+            self.__attrs_init__(text=text, level=level, color=color, fields=fields)
             return
         self.__attrs_clear__()
 
@@ -90,6 +96,7 @@ class TextLog(Archetype):
             text=None,
             level=None,
             color=None,
+            fields=None,
         )
 
     @classmethod
@@ -107,6 +114,7 @@ class TextLog(Archetype):
         text: datatypes.Utf8Like | None = None,
         level: datatypes.Utf8Like | None = None,
         color: datatypes.Rgba32Like | None = None,
+        fields: datatypes.Utf8PairArrayLike | None = None,
     ) -> TextLog:
         """
         Update only some specific fields of a `TextLog`.
@@ -123,6 +131,11 @@ class TextLog(Archetype):
             This can be used to filter the log messages in the Rerun Viewer.
         color:
             Optional color to use for the log line in the Rerun Viewer.
+        fields:
+            Optional key-value fields of a structured log entry.
+
+            The Rerun Viewer shows each key as a column of the text log.
+            The fields apply to all entries of the same row.
 
         """
 
@@ -132,6 +145,7 @@ class TextLog(Archetype):
                 "text": text,
                 "level": level,
                 "color": color,
+                "fields": fields,
             }
 
             if clear_unset:
@@ -155,6 +169,7 @@ class TextLog(Archetype):
         text: datatypes.Utf8ArrayLike | None = None,
         level: datatypes.Utf8ArrayLike | None = None,
         color: datatypes.Rgba32ArrayLike | None = None,
+        fields: datatypes.Utf8PairArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -174,6 +189,11 @@ class TextLog(Archetype):
             This can be used to filter the log messages in the Rerun Viewer.
         color:
             Optional color to use for the log line in the Rerun Viewer.
+        fields:
+            Optional key-value fields of a structured log entry.
+
+            The Rerun Viewer shows each key as a column of the text log.
+            The fields apply to all entries of the same row.
 
         """
 
@@ -183,6 +203,7 @@ class TextLog(Archetype):
                 text=text,
                 level=level,
                 color=color,
+                fields=fields,
             )
 
         batches = inst.as_component_batches(include_indicators=False)
@@ -225,5 +246,17 @@ class TextLog(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    fields: components.TextLogFieldBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextLogFieldBatch._converter,  # type: ignore[misc]
+    )
+    # Optional key-value fields of a structured log entry.
+    #
+    # The Rerun Viewer shows each key as a column of the text log.
+    # The fields apply to all entries of the same row.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,239 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_event.fbs".
+
+# You can extend this class by creating a "TimelineEventExt" class in "timeline_event_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["TimelineEvent"]
+
+
+@define(str=False, repr=False, init=False)
+class TimelineEvent(Archetype):
+    """
+    **Archetype**: A discrete event, shown as a marker on the timelines of the Rerun Viewer's time panel.
+
+    Use this to highlight important moments of a recording, such as a fault being raised,
+    a change of mission phase or an operator intervention.
+    Events with a duration are shown as spans starting at the time they are logged at.
+
+    The viewer's "next marker" and "previous marker" commands move the time cursor between events.
+    """
+
+    def __init__(
+        self: Any,
+        label: datatypes.Utf8Like,
+        *,
+        level: datatypes.Utf8Like | None = None,
+        color: datatypes.Rgba32Like | None = None,
+        duration: datatypes.TimeIntLike | None = None,
+    ):
+        """
+        Create a new instance of the TimelineEvent archetype.
+
+        Parameters
+        ----------
+        label:
+            A short description of the event.
+        level:
+            The severity of the event.
+
+            Events are colored by severity unless a color is specified.
+        color:
+            Optional color of the event's marker.
+        duration:
+            Optional duration of the event, turning its marker into a span.
+
+            Measured in the units of the timeline the event is shown on:
+            nanoseconds for temporal timelines, and steps for sequence timelines.
+
+        """
+
+        # You can define your own __init__ function as a member of TimelineEventExt in timeline_event_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(label=label, level=level, color=color, duration=duration)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            label=None,
+            level=None,
+            color=None,
+            duration=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TimelineEvent:
+        """Produce an empty TimelineEvent, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        label: datatypes.Utf8Like | None = None,
+        level: datatypes.Utf8Like | None = None,
+        color: datatypes.Rgba32Like | None = None,
+        duration: datatypes.TimeIntLike | None = None,
+    ) -> TimelineEvent:
+        """
+        Update only some specific fields of a `TimelineEvent`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        label:
+            A short description of the event.
+        level:
+            The severity of the event.
+
+            Events are colored by severity unless a color is specified.
+        color:
+            Optional color of the event's marker.
+        duration:
+            Optional duration of the event, turning its marker into a span.
+
+            Measured in the units of the timeline the event is shown on:
+            nanoseconds for temporal timelines, and steps for sequence timelines.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "label": label,
+                "level": level,
+                "color": color,
+                "duration": duration,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TimelineEvent:
+        """Clear all the fields of a `TimelineEvent`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        label: datatypes.Utf8ArrayLike | None = None,
+        level: datatypes.Utf8ArrayLike | None = None,
+        color: datatypes.Rgba32ArrayLike | None = None,
+        duration: datatypes.TimeIntArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        label:
+            A short description of the event.
+        level:
+            The severity of the event.
+
+            Events are colored by severity unless a color is specified.
+        color:
+            Optional color of the event's marker.
+        duration:
+            Optional duration of the event, turning its marker into a span.
+
+            Measured in the units of the timeline the event is shown on:
+            nanoseconds for temporal timelines, and steps for sequence timelines.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                label=label,
+                level=level,
+                color=color,
+                duration=duration,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        lengths = np.ones(len(batches[0]._batch.as_arrow_array()))
+        columns = [batch.partition(lengths) for batch in batches]
+
+        indicator_column = cls.indicator().partition(np.zeros(len(lengths)))
+
+        return ComponentColumnList([indicator_column] + columns)
+
+    label: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # A short description of the event.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    level: components.TextLogLevelBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextLogLevelBatch._converter,  # type: ignore[misc]
+    )
+    # The severity of the event.
+    #
+    # Events are colored by severity unless a color is specified.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    color: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Optional color of the event's marker.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    duration: components.EventDurationBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EventDurationBatch._converter,  # type: ignore[misc]
+    )
+    # Optional duration of the event, turning its marker into a span.
+    #
+    # Measured in the units of the timeline the event is shown on:
+    # nanoseconds for temporal timelines, and steps for sequence timelines.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,224 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/timeline_mapping.fbs".
+
+# You can extend this class by creating a "TimelineMappingExt" class in "timeline_mapping_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["TimelineMapping"]
+
+
+@define(str=False, repr=False, init=False)
+class TimelineMapping(Archetype):
+    """
+    **Archetype**: Declares how the times of one timeline translate to times on another timeline.
+
+    This relates timelines that data was logged on independently, e.g. `frame_nr`, `sensor_time` and `log_time`,
+    so that data logged on one of them can be queried at a time expressed on the other.
+    The mapping is used in both directions.
+
+    If a clock offset or drift is set, the timelines are related by a linear clock model:
+    `target = source + offset + drift * source`.
+
+    Otherwise the mapping is a correspondence table: every time it is logged with both timelines set,
+    it records one pair of corresponding times.
+    Times in between two pairs are interpolated linearly, times beyond the first or last pair are extrapolated.
+    """
+
+    def __init__(
+        self: Any,
+        source: datatypes.Utf8Like,
+        target: datatypes.Utf8Like,
+        *,
+        offset: datatypes.TimeIntLike | None = None,
+        drift: datatypes.Float64Like | None = None,
+    ):
+        """
+        Create a new instance of the TimelineMapping archetype.
+
+        Parameters
+        ----------
+        source:
+            The timeline the mapping translates from.
+        target:
+            The timeline the mapping translates to.
+        offset:
+            Constant offset of the linear clock model, in units of the target timeline.
+        drift:
+            Drift of the linear clock model, as a fraction of the source time.
+
+        """
+
+        # You can define your own __init__ function as a member of TimelineMappingExt in timeline_mapping_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(source=source, target=target, offset=offset, drift=drift)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            source=None,
+            target=None,
+            offset=None,
+            drift=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TimelineMapping:
+        """Produce an empty TimelineMapping, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        source: datatypes.Utf8Like | None = None,
+        target: datatypes.Utf8Like | None = None,
+        offset: datatypes.TimeIntLike | None = None,
+        drift: datatypes.Float64Like | None = None,
+    ) -> TimelineMapping:
+        """
+        Update only some specific fields of a `TimelineMapping`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        source:
+            The timeline the mapping translates from.
+        target:
+            The timeline the mapping translates to.
+        offset:
+            Constant offset of the linear clock model, in units of the target timeline.
+        drift:
+            Drift of the linear clock model, as a fraction of the source time.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "source": source,
+                "target": target,
+                "offset": offset,
+                "drift": drift,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TimelineMapping:
+        """Clear all the fields of a `TimelineMapping`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        source: datatypes.Utf8ArrayLike | None = None,
+        target: datatypes.Utf8ArrayLike | None = None,
+        offset: datatypes.TimeIntArrayLike | None = None,
+        drift: datatypes.Float64ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        source:
+            The timeline the mapping translates from.
+        target:
+            The timeline the mapping translates to.
+        offset:
+            Constant offset of the linear clock model, in units of the target timeline.
+        drift:
+            Drift of the linear clock model, as a fraction of the source time.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                source=source,
+                target=target,
+                offset=offset,
+                drift=drift,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        lengths = np.ones(len(batches[0]._batch.as_arrow_array()))
+        columns = [batch.partition(lengths) for batch in batches]
+
+        indicator_column = cls.indicator().partition(np.zeros(len(lengths)))
+
+        return ComponentColumnList([indicator_column] + columns)
+
+    source: components.SourceTimelineBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.SourceTimelineBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline the mapping translates from.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    target: components.TargetTimelineBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TargetTimelineBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline the mapping translates to.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    offset: components.ClockOffsetBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ClockOffsetBatch._converter,  # type: ignore[misc]
+    )
+    # Constant offset of the linear clock model, in units of the target timeline.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    drift: components.ClockDriftBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ClockDriftBatch._converter,  # type: ignore[misc]
+    )
+    # Drift of the linear clock model, as a fraction of the source time.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,358 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/voxel_grid3d.fbs".
+
+# You can extend this class by creating a "VoxelGrid3DExt" class in "voxel_grid3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["VoxelGrid3D"]
+
+
+@define(str=False, repr=False, init=False)
+class VoxelGrid3D(Archetype):
+    """
+    **Archetype**: A 3D grid of voxels, e.g. an occupancy map.
+
+    The grid is either dense, given by a 3D tensor of voxel values, or sparse, given by a list of voxel indices.
+    Voxel `[x, y, z]` spans from `origin + [x, y, z] * voxel_size` to `origin + [x + 1, y + 1, z + 1] * voxel_size`
+    in the local coordinate system of the entity.
+
+    Voxels are colored by their [`components.Color`][rerun.components.Color] if set, otherwise their values are mapped through the [`components.Colormap`][rerun.components.Colormap].
+    When picking a voxel, the instance index is the index into `voxel_indices` for sparse grids,
+    and the row-major index into `dense_values` for dense grids.
+    """
+
+    def __init__(
+        self: Any,
+        voxel_size: datatypes.Vec3DLike,
+        *,
+        voxel_indices: datatypes.UVec3DArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        dense_values: datatypes.TensorDataLike | None = None,
+        values: datatypes.Float64ArrayLike | None = None,
+        origin: datatypes.Vec3DLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+    ):
+        """
+        Create a new instance of the VoxelGrid3D archetype.
+
+        Parameters
+        ----------
+        voxel_size:
+            The size of a single voxel along each axis.
+        voxel_indices:
+            The indices of the occupied voxels of a sparse grid.
+        colors:
+            Optional colors for the voxels.
+
+            Takes precedence over the colormap.
+        dense_values:
+            The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+
+            Voxels with a value of zero or NaN are empty, all others are occupied.
+            Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+        values:
+            Optional values of the voxels of a sparse grid, mapped through the colormap.
+        origin:
+            The position of the corner of voxel `[0, 0, 0]`.
+
+            If not specified, the grid starts at the origin.
+        colormap:
+            Colormap to use for mapping voxel values to colors.
+
+            If not set, the Viridis colormap is used.
+        value_range:
+            The range of voxel values that is mapped to the colormap.
+
+            If not specified, the range is estimated from the data.
+
+        """
+
+        # You can define your own __init__ function as a member of VoxelGrid3DExt in voxel_grid3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                voxel_size=voxel_size,
+                voxel_indices=voxel_indices,
+                colors=colors,
+                dense_values=dense_values,
+                values=values,
+                origin=origin,
+                colormap=colormap,
+                value_range=value_range,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            voxel_size=None,
+            voxel_indices=None,
+            colors=None,
+            dense_values=None,
+            values=None,
+            origin=None,
+            colormap=None,
+            value_range=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> VoxelGrid3D:
+        """Produce an empty VoxelGrid3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        voxel_size: datatypes.Vec3DLike | None = None,
+        voxel_indices: datatypes.UVec3DArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        dense_values: datatypes.TensorDataLike | None = None,
+        values: datatypes.Float64ArrayLike | None = None,
+        origin: datatypes.Vec3DLike | None = None,
+        colormap: components.ColormapLike | None = None,
+        value_range: datatypes.Range1DLike | None = None,
+    ) -> VoxelGrid3D:
+        """
+        Update only some specific fields of a `VoxelGrid3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        voxel_size:
+            The size of a single voxel along each axis.
+        voxel_indices:
+            The indices of the occupied voxels of a sparse grid.
+        colors:
+            Optional colors for the voxels.
+
+            Takes precedence over the colormap.
+        dense_values:
+            The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+
+            Voxels with a value of zero or NaN are empty, all others are occupied.
+            Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+        values:
+            Optional values of the voxels of a sparse grid, mapped through the colormap.
+        origin:
+            The position of the corner of voxel `[0, 0, 0]`.
+
+            If not specified, the grid starts at the origin.
+        colormap:
+            Colormap to use for mapping voxel values to colors.
+
+            If not set, the Viridis colormap is used.
+        value_range:
+            The range of voxel values that is mapped to the colormap.
+
+            If not specified, the range is estimated from the data.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "voxel_size": voxel_size,
+                "voxel_indices": voxel_indices,
+                "colors": colors,
+                "dense_values": dense_values,
+                "values": values,
+                "origin": origin,
+                "colormap": colormap,
+                "value_range": value_range,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> VoxelGrid3D:
+        """Clear all the fields of a `VoxelGrid3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        voxel_size: datatypes.Vec3DArrayLike | None = None,
+        voxel_indices: datatypes.UVec3DArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        dense_values: datatypes.TensorDataArrayLike | None = None,
+        values: datatypes.Float64ArrayLike | None = None,
+        origin: datatypes.Vec3DArrayLike | None = None,
+        colormap: components.ColormapArrayLike | None = None,
+        value_range: datatypes.Range1DArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        voxel_size:
+            The size of a single voxel along each axis.
+        voxel_indices:
+            The indices of the occupied voxels of a sparse grid.
+        colors:
+            Optional colors for the voxels.
+
+            Takes precedence over the colormap.
+        dense_values:
+            The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+
+            Voxels with a value of zero or NaN are empty, all others are occupied.
+            Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+        values:
+            Optional values of the voxels of a sparse grid, mapped through the colormap.
+        origin:
+            The position of the corner of voxel `[0, 0, 0]`.
+
+            If not specified, the grid starts at the origin.
+        colormap:
+            Colormap to use for mapping voxel values to colors.
+
+            If not set, the Viridis colormap is used.
+        value_range:
+            The range of voxel values that is mapped to the colormap.
+
+            If not specified, the range is estimated from the data.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                voxel_size=voxel_size,
+                voxel_indices=voxel_indices,
+                colors=colors,
+                dense_values=dense_values,
+                values=values,
+                origin=origin,
+                colormap=colormap,
+                value_range=value_range,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        lengths = np.ones(len(batches[0]._batch.as_arrow_array()))
+        columns = [batch.partition(lengths) for batch in batches]
+
+        indicator_column = cls.indicator().partition(np.zeros(len(lengths)))
+
+        return ComponentColumnList([indicator_column] + columns)
+
+    voxel_size: components.VoxelSizeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.VoxelSizeBatch._converter,  # type: ignore[misc]
+    )
+    # The size of a single voxel along each axis.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    voxel_indices: components.VoxelIndexBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.VoxelIndexBatch._converter,  # type: ignore[misc]
+    )
+    # The indices of the occupied voxels of a sparse grid.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Optional colors for the voxels.
+    #
+    # Takes precedence over the colormap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    dense_values: components.TensorDataBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TensorDataBatch._converter,  # type: ignore[misc]
+    )
+    # The values of a dense grid, as a 3D tensor with shape `[x, y, z]`.
+    #
+    # Voxels with a value of zero or NaN are empty, all others are occupied.
+    # Signed distance volumes such as a TSDF need to be thresholded into occupancy first.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    values: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # Optional values of the voxels of a sparse grid, mapped through the colormap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    origin: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # The position of the corner of voxel `[0, 0, 0]`.
+    #
+    # If not specified, the grid starts at the origin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # Colormap to use for mapping voxel values to colors.
+    #
+    # If not set, the Viridis colormap is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    value_range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The range of voxel values that is mapped to the colormap.
+    #
+    # If not specified, the range is estimated from the data.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -29,18 +29,25 @@ from .api import (
 )
 from .archetypes import (
     Background as Background,
+    ClipPlanes3D as ClipPlanes3D,
+    EyeControls3D as EyeControls3D,
+    Measurements as Measurements,
     PlotLegend as PlotLegend,
     ScalarAxis as ScalarAxis,
+    SectionBox3D as SectionBox3D,
     TensorScalarMapping as TensorScalarMapping,
     TensorSliceSelection as TensorSliceSelection,
+    TextLogSearch as TextLogSearch,
     # VisibleTimeRanges, # Don't expose this mono-archetype directly - one can always use the component instead!
     VisualBounds2D as VisualBounds2D,
 )
 from .components import (
     BackgroundKind as BackgroundKind,
     Corner2D as Corner2D,
+    Eye3DKind as Eye3DKind,
     LockRangeDuringZoom as LockRangeDuringZoom,
     MapProvider as MapProvider,
+    MeasurementKind as MeasurementKind,
     VisibleTimeRange as VisibleTimeRange,
     VisualizerOverrides as VisualizerOverrides,
 )
@@ -3,8 +3,10 @@
 from __future__ import annotations
 
 from .background import Background
+from .clip_planes3d import ClipPlanes3D
 from .container_blueprint import ContainerBlueprint
 from .dataframe_query import DataframeQuery
+from .eye_controls3d import EyeControls3D
 from .force_center import ForceCenter
 from .force_collision_radius import ForceCollisionRadius
 from .force_link import ForceLink
@@ -13,13 +15,18 @@ from .force_position import ForcePosition
 from .line_grid3d import LineGrid3D
 from .map_background import MapBackground
 from .map_zoom import MapZoom
+from .measurements import Measurements
 from .near_clip_plane import NearClipPlane
 from .panel_blueprint import PanelBlueprint
 from .plot_legend import PlotLegend
+from .recording_comparison import RecordingComparison
 from .scalar_axis import ScalarAxis
+from .section_box3d import SectionBox3D
 from .tensor_scalar_mapping import TensorScalarMapping
 from .tensor_slice_selection import TensorSliceSelection
 from .tensor_view_fit import TensorViewFit
+from .text_log_search import TextLogSearch
+from .timeline_bookmarks import TimelineBookmarks
 from .view_blueprint import ViewBlueprint
 from .view_contents import ViewContents
 from .viewport_blueprint import ViewportBlueprint
@@ -28,8 +35,10 @@ from .visual_bounds2d import VisualBounds2D
 
 __all__ = [
     "Background",
+    "ClipPlanes3D",
     "ContainerBlueprint",
     "DataframeQuery",
+    "EyeControls3D",
     "ForceCenter",
     "ForceCollisionRadius",
     "ForceLink",
@@ -38,13 +47,18 @@ __all__ = [
     "LineGrid3D",
     "MapBackground",
     "MapZoom",
+    "Measurements",
     "NearClipPlane",
     "PanelBlueprint",
     "PlotLegend",
+    "RecordingComparison",
     "ScalarAxis",
+    "SectionBox3D",
     "TensorScalarMapping",
     "TensorSliceSelection",
     "TensorViewFit",
+    "TextLogSearch",
+    "TimelineBookmarks",
     "ViewBlueprint",
     "ViewContents",
     "ViewportBlueprint",
@@ -0,0 +1,145 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/clip_planes3d.fbs".
+
+# You can extend this class by creating a "ClipPlanes3DExt" class in "clip_planes3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["ClipPlanes3D"]
+
+
+@define(str=False, repr=False, init=False)
+class ClipPlanes3D(Archetype):
+    """
+    **Archetype**: Clipping planes that cut away parts of a spatial 3D view.
+
+    Everything on the side of a plane its normal points towards is not drawn.
+    """
+
+    def __init__(
+        self: Any, *, enabled: datatypes.BoolLike | None = None, planes: datatypes.Plane3DArrayLike | None = None
+    ):
+        """
+        Create a new instance of the ClipPlanes3D archetype.
+
+        Parameters
+        ----------
+        enabled:
+            Whether the clipping planes are applied.
+
+            Defaults to false.
+        planes:
+            The clipping planes in world-space.
+
+            At most 8 planes are supported, additional planes are ignored.
+            While the section box is enabled, only the first 2 planes are applied.
+            Defaults to a single plane through the center of the scene, facing up.
+
+        """
+
+        # You can define your own __init__ function as a member of ClipPlanes3DExt in clip_planes3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(enabled=enabled, planes=planes)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            enabled=None,
+            planes=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> ClipPlanes3D:
+        """Produce an empty ClipPlanes3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        enabled: datatypes.BoolLike | None = None,
+        planes: datatypes.Plane3DArrayLike | None = None,
+    ) -> ClipPlanes3D:
+        """
+        Update only some specific fields of a `ClipPlanes3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        enabled:
+            Whether the clipping planes are applied.
+
+            Defaults to false.
+        planes:
+            The clipping planes in world-space.
+
+            At most 8 planes are supported, additional planes are ignored.
+            While the section box is enabled, only the first 2 planes are applied.
+            Defaults to a single plane through the center of the scene, facing up.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "enabled": enabled,
+                "planes": planes,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> ClipPlanes3D:
+        """Clear all the fields of a `ClipPlanes3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    enabled: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the clipping planes are applied.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    planes: components.Plane3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Plane3DBatch._converter,  # type: ignore[misc]
+    )
+    # The clipping planes in world-space.
+    #
+    # At most 8 planes are supported, additional planes are ignored.
+    # While the section box is enabled, only the first 2 planes are applied.
+    # Defaults to a single plane through the center of the scene, facing up.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,279 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/eye_controls3d.fbs".
+
+# You can extend this class by creating a "EyeControls3DExt" class in "eye_controls3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["EyeControls3D"]
+
+
+@define(str=False, repr=False, init=False)
+class EyeControls3D(Archetype):
+    """
+    **Archetype**: The controls for the 3D eye in a spatial 3D view.
+
+    This configures the camera through which the 3D scene is viewed.
+    Fields that are not set are determined automatically from the scene,
+    and interacting with the view writes the resulting eye back to the blueprint.
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        kind: blueprint_components.Eye3DKindLike | None = None,
+        position: datatypes.Vec3DLike | None = None,
+        look_target: datatypes.Vec3DLike | None = None,
+        eye_up: datatypes.Vec3DLike | None = None,
+        fov_y: datatypes.Float32Like | None = None,
+        tracking_entity: datatypes.EntityPathLike | None = None,
+        tracking_offset: datatypes.Vec3DLike | None = None,
+    ):
+        """
+        Create a new instance of the EyeControls3D archetype.
+
+        Parameters
+        ----------
+        kind:
+            The kind of the eye for the spatial 3D view.
+
+            This controls how the eye reacts to user input, e.g. mouse dragging.
+            Defaults to orbital.
+        position:
+            The world-space position of the eye.
+
+            Only used if `look_target` is set as well.
+        look_target:
+            The world-space point the eye is looking at.
+
+            For orbital eyes this is the center of rotation.
+            Only used if `position` is set as well.
+        eye_up:
+            The up-axis of the eye in world-space.
+
+            Defaults to the up-axis of the scene as defined by [`components.ViewCoordinates`][rerun.components.ViewCoordinates], or +Z if not specified.
+        fov_y:
+            Vertical field of view of the eye.
+
+            Defaults to 55°.
+        tracking_entity:
+            An entity the eye follows.
+
+            While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+        tracking_offset:
+            Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+
+            The eye is placed at this offset and looks at the origin of the tracked entity,
+            e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+            If not set, the eye keeps its orientation & distance and only follows the entity's position.
+
+        """
+
+        # You can define your own __init__ function as a member of EyeControls3DExt in eye_controls3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                kind=kind,
+                position=position,
+                look_target=look_target,
+                eye_up=eye_up,
+                fov_y=fov_y,
+                tracking_entity=tracking_entity,
+                tracking_offset=tracking_offset,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            kind=None,
+            position=None,
+            look_target=None,
+            eye_up=None,
+            fov_y=None,
+            tracking_entity=None,
+            tracking_offset=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> EyeControls3D:
+        """Produce an empty EyeControls3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        kind: blueprint_components.Eye3DKindLike | None = None,
+        position: datatypes.Vec3DLike | None = None,
+        look_target: datatypes.Vec3DLike | None = None,
+        eye_up: datatypes.Vec3DLike | None = None,
+        fov_y: datatypes.Float32Like | None = None,
+        tracking_entity: datatypes.EntityPathLike | None = None,
+        tracking_offset: datatypes.Vec3DLike | None = None,
+    ) -> EyeControls3D:
+        """
+        Update only some specific fields of a `EyeControls3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        kind:
+            The kind of the eye for the spatial 3D view.
+
+            This controls how the eye reacts to user input, e.g. mouse dragging.
+            Defaults to orbital.
+        position:
+            The world-space position of the eye.
+
+            Only used if `look_target` is set as well.
+        look_target:
+            The world-space point the eye is looking at.
+
+            For orbital eyes this is the center of rotation.
+            Only used if `position` is set as well.
+        eye_up:
+            The up-axis of the eye in world-space.
+
+            Defaults to the up-axis of the scene as defined by [`components.ViewCoordinates`][rerun.components.ViewCoordinates], or +Z if not specified.
+        fov_y:
+            Vertical field of view of the eye.
+
+            Defaults to 55°.
+        tracking_entity:
+            An entity the eye follows.
+
+            While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+        tracking_offset:
+            Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+
+            The eye is placed at this offset and looks at the origin of the tracked entity,
+            e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+            If not set, the eye keeps its orientation & distance and only follows the entity's position.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "kind": kind,
+                "position": position,
+                "look_target": look_target,
+                "eye_up": eye_up,
+                "fov_y": fov_y,
+                "tracking_entity": tracking_entity,
+                "tracking_offset": tracking_offset,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> EyeControls3D:
+        """Clear all the fields of a `EyeControls3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    kind: blueprint_components.Eye3DKindBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.Eye3DKindBatch._converter,  # type: ignore[misc]
+    )
+    # The kind of the eye for the spatial 3D view.
+    #
+    # This controls how the eye reacts to user input, e.g. mouse dragging.
+    # Defaults to orbital.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    position: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # The world-space position of the eye.
+    #
+    # Only used if `look_target` is set as well.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    look_target: blueprint_components.LookTargetBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.LookTargetBatch._converter,  # type: ignore[misc]
+    )
+    # The world-space point the eye is looking at.
+    #
+    # For orbital eyes this is the center of rotation.
+    # Only used if `position` is set as well.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    eye_up: components.Vector3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Vector3DBatch._converter,  # type: ignore[misc]
+    )
+    # The up-axis of the eye in world-space.
+    #
+    # Defaults to the up-axis of the scene as defined by [`components.ViewCoordinates`][rerun.components.ViewCoordinates], or +Z if not specified.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    fov_y: blueprint_components.FieldOfViewBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.FieldOfViewBatch._converter,  # type: ignore[misc]
+    )
+    # Vertical field of view of the eye.
+    #
+    # Defaults to 55°.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    tracking_entity: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # An entity the eye follows.
+    #
+    # While set, the eye stays attached to the entity as it moves and ignores `position` & `look_target`.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    tracking_offset: components.Translation3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Translation3DBatch._converter,  # type: ignore[misc]
+    )
+    # Offset of the eye from the tracked entity, in the entity's local coordinate frame.
+    #
+    # The eye is placed at this offset and looks at the origin of the tracked entity,
+    # e.g. `[-5, 0, 2]` for a chase camera behind a robot whose forward direction is +X.
+    # If not set, the eye keeps its orientation & distance and only follows the entity's position.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
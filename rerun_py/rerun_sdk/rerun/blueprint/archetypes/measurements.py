@@ -0,0 +1,131 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/measurements.fbs".
+
+# You can extend this class by creating a "MeasurementsExt" class in "measurements_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["Measurements"]
+
+
+@define(str=False, repr=False, init=False)
+class Measurements(Archetype):
+    """
+    **Archetype**: Measurements taken with the measurement tools of a spatial view.
+
+    Each measurement is a kind together with the points it was taken between.
+    Measurements in 2D views are stored with a z-coordinate of zero.
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        kinds: blueprint_components.MeasurementKindArrayLike | None = None,
+        points: components.LineStrip3DArrayLike | None = None,
+    ):
+        """
+        Create a new instance of the Measurements archetype.
+
+        Parameters
+        ----------
+        kinds:
+            The kind of each measurement.
+        points:
+            The points of each measurement, in the coordinate space of the view's origin.
+
+        """
+
+        # You can define your own __init__ function as a member of MeasurementsExt in measurements_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(kinds=kinds, points=points)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            kinds=None,
+            points=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> Measurements:
+        """Produce an empty Measurements, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        kinds: blueprint_components.MeasurementKindArrayLike | None = None,
+        points: components.LineStrip3DArrayLike | None = None,
+    ) -> Measurements:
+        """
+        Update only some specific fields of a `Measurements`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        kinds:
+            The kind of each measurement.
+        points:
+            The points of each measurement, in the coordinate space of the view's origin.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "kinds": kinds,
+                "points": points,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> Measurements:
+        """Clear all the fields of a `Measurements`."""
+        return cls.from_fields(clear_unset=True)
+
+    kinds: blueprint_components.MeasurementKindBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.MeasurementKindBatch._converter,  # type: ignore[misc]
+    )
+    # The kind of each measurement.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    points: components.LineStrip3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.LineStrip3DBatch._converter,  # type: ignore[misc]
+    )
+    # The points of each measurement, in the coordinate space of the view's origin.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
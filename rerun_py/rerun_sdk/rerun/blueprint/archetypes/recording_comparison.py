@@ -0,0 +1,142 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/recording_comparison.fbs".
+
+# You can extend this class by creating a "RecordingComparisonExt" class in "recording_comparison_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["RecordingComparison"]
+
+
+@define(str=False, repr=False, init=False)
+class RecordingComparison(Archetype):
+    """
+    **Archetype**: Compares the active recording against another recording in a view.
+
+    Time series views overlay the data of both recordings, all other views show the compared recording instead of the active one.
+    """
+
+    def __init__(
+        self: Any, *, recording: datatypes.Utf8Like | None = None, time_offset: datatypes.TimeIntLike | None = None
+    ):
+        """
+        Create a new instance of the RecordingComparison archetype.
+
+        Parameters
+        ----------
+        recording:
+            The id of the recording to compare against.
+
+            If not set, or if no recording with this id is loaded, the view only shows the active recording.
+        time_offset:
+            How much later things happen in the active recording than in the compared recording.
+
+            Added to all times of the compared recording so that both recordings share one time cursor.
+            In the unit of the currently selected timeline, defaults to zero.
+
+        """
+
+        # You can define your own __init__ function as a member of RecordingComparisonExt in recording_comparison_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(recording=recording, time_offset=time_offset)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            recording=None,
+            time_offset=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> RecordingComparison:
+        """Produce an empty RecordingComparison, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        recording: datatypes.Utf8Like | None = None,
+        time_offset: datatypes.TimeIntLike | None = None,
+    ) -> RecordingComparison:
+        """
+        Update only some specific fields of a `RecordingComparison`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        recording:
+            The id of the recording to compare against.
+
+            If not set, or if no recording with this id is loaded, the view only shows the active recording.
+        time_offset:
+            How much later things happen in the active recording than in the compared recording.
+
+            Added to all times of the compared recording so that both recordings share one time cursor.
+            In the unit of the currently selected timeline, defaults to zero.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "recording": recording,
+                "time_offset": time_offset,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> RecordingComparison:
+        """Clear all the fields of a `RecordingComparison`."""
+        return cls.from_fields(clear_unset=True)
+
+    recording: blueprint_components.ComparedRecordingBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.ComparedRecordingBatch._converter,  # type: ignore[misc]
+    )
+    # The id of the recording to compare against.
+    #
+    # If not set, or if no recording with this id is loaded, the view only shows the active recording.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    time_offset: blueprint_components.TimeOffsetBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimeOffsetBatch._converter,  # type: ignore[misc]
+    )
+    # How much later things happen in the active recording than in the compared recording.
+    #
+    # Added to all times of the compared recording so that both recordings share one time cursor.
+    # In the unit of the currently selected timeline, defaults to zero.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,184 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/section_box3d.fbs".
+
+# You can extend this class by creating a "SectionBox3DExt" class in "section_box3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["SectionBox3D"]
+
+
+@define(str=False, repr=False, init=False)
+class SectionBox3D(Archetype):
+    """**Archetype**: A box in a spatial 3D view outside of which nothing is drawn."""
+
+    def __init__(
+        self: Any,
+        *,
+        enabled: datatypes.BoolLike | None = None,
+        center: datatypes.Vec3DLike | None = None,
+        half_size: datatypes.Vec3DLike | None = None,
+        rotation: datatypes.QuaternionLike | None = None,
+    ):
+        """
+        Create a new instance of the SectionBox3D archetype.
+
+        Parameters
+        ----------
+        enabled:
+            Whether the section box is applied.
+
+            Defaults to false.
+        center:
+            The center of the box in world-space.
+
+            Defaults to the center of the scene's bounding box.
+        half_size:
+            Half of the box's size along each of its local axes.
+
+            Defaults to half the size of the scene's bounding box.
+        rotation:
+            Rotation of the box in world-space.
+
+            Defaults to no rotation, i.e. an axis-aligned box.
+
+        """
+
+        # You can define your own __init__ function as a member of SectionBox3DExt in section_box3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(enabled=enabled, center=center, half_size=half_size, rotation=rotation)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            enabled=None,
+            center=None,
+            half_size=None,
+            rotation=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> SectionBox3D:
+        """Produce an empty SectionBox3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        enabled: datatypes.BoolLike | None = None,
+        center: datatypes.Vec3DLike | None = None,
+        half_size: datatypes.Vec3DLike | None = None,
+        rotation: datatypes.QuaternionLike | None = None,
+    ) -> SectionBox3D:
+        """
+        Update only some specific fields of a `SectionBox3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        enabled:
+            Whether the section box is applied.
+
+            Defaults to false.
+        center:
+            The center of the box in world-space.
+
+            Defaults to the center of the scene's bounding box.
+        half_size:
+            Half of the box's size along each of its local axes.
+
+            Defaults to half the size of the scene's bounding box.
+        rotation:
+            Rotation of the box in world-space.
+
+            Defaults to no rotation, i.e. an axis-aligned box.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "enabled": enabled,
+                "center": center,
+                "half_size": half_size,
+                "rotation": rotation,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> SectionBox3D:
+        """Clear all the fields of a `SectionBox3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    enabled: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the section box is applied.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    center: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # The center of the box in world-space.
+    #
+    # Defaults to the center of the scene's bounding box.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    half_size: components.HalfSize3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.HalfSize3DBatch._converter,  # type: ignore[misc]
+    )
+    # Half of the box's size along each of its local axes.
+    #
+    # Defaults to half the size of the scene's bounding box.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    rotation: components.RotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # Rotation of the box in world-space.
+    #
+    # Defaults to no rotation, i.e. an axis-aligned box.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
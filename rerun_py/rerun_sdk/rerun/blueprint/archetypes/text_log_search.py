@@ -0,0 +1,143 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_search.fbs".
+
+# You can extend this class by creating a "TextLogSearchExt" class in "text_log_search_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TextLogSearch"]
+
+
+@define(str=False, repr=False, init=False)
+class TextLogSearch(Archetype):
+    """
+    **Archetype**: Searches the bodies of the entries of a text log view.
+
+    Only entries whose body matches the query are shown.
+    """
+
+    def __init__(self: Any, *, query: datatypes.Utf8Like | None = None, use_regex: datatypes.BoolLike | None = None):
+        """
+        Create a new instance of the TextLogSearch archetype.
+
+        Parameters
+        ----------
+        query:
+            The text to search for.
+
+            Matched case-insensitively as a substring, unless `use_regex` is set.
+            An empty query shows all entries.
+        use_regex:
+            Whether `query` is a regular expression.
+
+            Regular expressions are case-sensitive unless they start with `(?i)`.
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogSearchExt in text_log_search_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(query=query, use_regex=use_regex)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            query=None,
+            use_regex=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TextLogSearch:
+        """Produce an empty TextLogSearch, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        query: datatypes.Utf8Like | None = None,
+        use_regex: datatypes.BoolLike | None = None,
+    ) -> TextLogSearch:
+        """
+        Update only some specific fields of a `TextLogSearch`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        query:
+            The text to search for.
+
+            Matched case-insensitively as a substring, unless `use_regex` is set.
+            An empty query shows all entries.
+        use_regex:
+            Whether `query` is a regular expression.
+
+            Regular expressions are case-sensitive unless they start with `(?i)`.
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "query": query,
+                "use_regex": use_regex,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TextLogSearch:
+        """Clear all the fields of a `TextLogSearch`."""
+        return cls.from_fields(clear_unset=True)
+
+    query: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # The text to search for.
+    #
+    # Matched case-insensitively as a substring, unless `use_regex` is set.
+    # An empty query shows all entries.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    use_regex: blueprint_components.UseRegexBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.UseRegexBatch._converter,  # type: ignore[misc]
+    )
+    # Whether `query` is a regular expression.
+    #
+    # Regular expressions are case-sensitive unless they start with `(?i)`.
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,148 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/timeline_bookmarks.fbs".
+
+# You can extend this class by creating a "TimelineBookmarksExt" class in "timeline_bookmarks_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TimelineBookmarks"]
+
+
+@define(str=False, repr=False, init=False)
+class TimelineBookmarks(Archetype):
+    """
+    **Archetype**: Bookmarked moments on the timelines of the time panel.
+
+    The i-th bookmark is made up of the i-th timeline, time and label.
+    Bookmarks are shown as markers above the streams of the time panel.
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        timelines: datatypes.Utf8ArrayLike | None = None,
+        times: datatypes.TimeIntArrayLike | None = None,
+        labels: datatypes.Utf8ArrayLike | None = None,
+    ):
+        """
+        Create a new instance of the TimelineBookmarks archetype.
+
+        Parameters
+        ----------
+        timelines:
+            The timeline of each bookmark.
+        times:
+            The time of each bookmark on its timeline.
+        labels:
+            The label of each bookmark.
+
+        """
+
+        # You can define your own __init__ function as a member of TimelineBookmarksExt in timeline_bookmarks_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(timelines=timelines, times=times, labels=labels)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            timelines=None,
+            times=None,
+            labels=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TimelineBookmarks:
+        """Produce an empty TimelineBookmarks, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        timelines: datatypes.Utf8ArrayLike | None = None,
+        times: datatypes.TimeIntArrayLike | None = None,
+        labels: datatypes.Utf8ArrayLike | None = None,
+    ) -> TimelineBookmarks:
+        """
+        Update only some specific fields of a `TimelineBookmarks`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        timelines:
+            The timeline of each bookmark.
+        times:
+            The time of each bookmark on its timeline.
+        labels:
+            The label of each bookmark.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "timelines": timelines,
+                "times": times,
+                "labels": labels,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TimelineBookmarks:
+        """Clear all the fields of a `TimelineBookmarks`."""
+        return cls.from_fields(clear_unset=True)
+
+    timelines: blueprint_components.TimelineNameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimelineNameBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline of each bookmark.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    times: blueprint_components.BookmarkTimeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.BookmarkTimeBatch._converter,  # type: ignore[misc]
+    )
+    # The time of each bookmark on its timeline.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    labels: components.NameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.NameBatch._converter,  # type: ignore[misc]
+    )
+    # The label of each bookmark.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
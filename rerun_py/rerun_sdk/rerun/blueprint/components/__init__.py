@@ -7,11 +7,15 @@ from .apply_latest_at import ApplyLatestAt, ApplyLatestAtBatch
 from .auto_layout import AutoLayout, AutoLayoutBatch
 from .auto_views import AutoViews, AutoViewsBatch
 from .background_kind import BackgroundKind, BackgroundKindArrayLike, BackgroundKindBatch, BackgroundKindLike
+from .bookmark_time import BookmarkTime, BookmarkTimeBatch
 from .column_share import ColumnShare, ColumnShareBatch
+from .compared_recording import ComparedRecording, ComparedRecordingBatch
 from .component_column_selector import ComponentColumnSelector, ComponentColumnSelectorBatch
 from .container_kind import ContainerKind, ContainerKindArrayLike, ContainerKindBatch, ContainerKindLike
 from .corner2d import Corner2D, Corner2DArrayLike, Corner2DBatch, Corner2DLike
 from .enabled import Enabled, EnabledBatch
+from .eye3d_kind import Eye3DKind, Eye3DKindArrayLike, Eye3DKindBatch, Eye3DKindLike
+from .field_of_view import FieldOfView, FieldOfViewBatch
 from .filter_by_range import FilterByRange, FilterByRangeBatch
 from .filter_is_not_null import FilterIsNotNull, FilterIsNotNullBatch
 from .force_distance import ForceDistance, ForceDistanceBatch
@@ -22,7 +26,9 @@ from .grid_spacing import GridSpacing, GridSpacingBatch
 from .included_content import IncludedContent, IncludedContentBatch
 from .interactive import Interactive, InteractiveBatch
 from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatch
+from .look_target import LookTarget, LookTargetBatch
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
+from .measurement_kind import MeasurementKind, MeasurementKindArrayLike, MeasurementKindBatch, MeasurementKindLike
 from .near_clip_plane import NearClipPlane, NearClipPlaneBatch
 from .panel_state import PanelState, PanelStateArrayLike, PanelStateBatch, PanelStateLike
 from .query_expression import QueryExpression, QueryExpressionBatch
@@ -30,7 +36,9 @@ from .root_container import RootContainer, RootContainerBatch
 from .row_share import RowShare, RowShareBatch
 from .selected_columns import SelectedColumns, SelectedColumnsBatch
 from .tensor_dimension_index_slider import TensorDimensionIndexSlider, TensorDimensionIndexSliderBatch
+from .time_offset import TimeOffset, TimeOffsetBatch
 from .timeline_name import TimelineName, TimelineNameBatch
+from .use_regex import UseRegex, UseRegexBatch
 from .view_class import ViewClass, ViewClassBatch
 from .view_fit import ViewFit, ViewFitArrayLike, ViewFitBatch, ViewFitLike
 from .view_maximized import ViewMaximized, ViewMaximizedBatch
@@ -55,8 +63,12 @@ __all__ = [
     "BackgroundKindArrayLike",
     "BackgroundKindBatch",
     "BackgroundKindLike",
+    "BookmarkTime",
+    "BookmarkTimeBatch",
     "ColumnShare",
     "ColumnShareBatch",
+    "ComparedRecording",
+    "ComparedRecordingBatch",
     "ComponentColumnSelector",
     "ComponentColumnSelectorBatch",
     "ContainerKind",
@@ -69,6 +81,12 @@ __all__ = [
     "Corner2DLike",
     "Enabled",
     "EnabledBatch",
+    "Eye3DKind",
+    "Eye3DKindArrayLike",
+    "Eye3DKindBatch",
+    "Eye3DKindLike",
+    "FieldOfView",
+    "FieldOfViewBatch",
     "FilterByRange",
     "FilterByRangeBatch",
     "FilterIsNotNull",
@@ -89,10 +107,16 @@ __all__ = [
     "InteractiveBatch",
     "LockRangeDuringZoom",
     "LockRangeDuringZoomBatch",
+    "LookTarget",
+    "LookTargetBatch",
     "MapProvider",
     "MapProviderArrayLike",
     "MapProviderBatch",
     "MapProviderLike",
+    "MeasurementKind",
+    "MeasurementKindArrayLike",
+    "MeasurementKindBatch",
+    "MeasurementKindLike",
     "NearClipPlane",
     "NearClipPlaneBatch",
     "PanelState",
@@ -109,8 +133,12 @@ __all__ = [
     "SelectedColumnsBatch",
     "TensorDimensionIndexSlider",
     "TensorDimensionIndexSliderBatch",
+    "TimeOffset",
+    "TimeOffsetBatch",
     "TimelineName",
     "TimelineNameBatch",
+    "UseRegex",
+    "UseRegexBatch",
     "ViewClass",
     "ViewClassBatch",
     "ViewFit",
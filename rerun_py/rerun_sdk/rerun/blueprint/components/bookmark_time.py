@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/bookmark_time.fbs".
+
+# You can extend this class by creating a "BookmarkTimeExt" class in "bookmark_time_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["BookmarkTime", "BookmarkTimeBatch"]
+
+
+class BookmarkTime(datatypes.TimeInt, ComponentMixin):
+    """**Component**: The time of a bookmark on a timeline."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of BookmarkTimeExt in bookmark_time_ext.py
+
+    # Note: there are no fields here because BookmarkTime delegates to datatypes.TimeInt
+    pass
+
+
+class BookmarkTimeBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.BookmarkTime")
+
+
+# This is patched in late to avoid circular dependencies.
+BookmarkTime._BATCH_TYPE = BookmarkTimeBatch  # type: ignore[assignment]
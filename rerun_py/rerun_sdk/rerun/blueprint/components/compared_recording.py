@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/compared_recording.fbs".
+
+# You can extend this class by creating a "ComparedRecordingExt" class in "compared_recording_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["ComparedRecording", "ComparedRecordingBatch"]
+
+
+class ComparedRecording(datatypes.Utf8, ComponentMixin):
+    """**Component**: The id of a recording that a view compares against the active recording."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of ComparedRecordingExt in compared_recording_ext.py
+
+    # Note: there are no fields here because ComparedRecording delegates to datatypes.Utf8
+    pass
+
+
+class ComparedRecordingBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.ComparedRecording")
+
+
+# This is patched in late to avoid circular dependencies.
+ComparedRecording._BATCH_TYPE = ComparedRecordingBatch  # type: ignore[assignment]
@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/field_of_view.fbs".
+
+# You can extend this class by creating a "FieldOfViewExt" class in "field_of_view_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["FieldOfView", "FieldOfViewBatch"]
+
+
+class FieldOfView(datatypes.Float32, ComponentMixin):
+    """**Component**: Vertical field of view of a 3D eye."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of FieldOfViewExt in field_of_view_ext.py
+
+    # Note: there are no fields here because FieldOfView delegates to datatypes.Float32
+    pass
+
+
+class FieldOfViewBatch(datatypes.Float32Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.FieldOfView")
+
+
+# This is patched in late to avoid circular dependencies.
+FieldOfView._BATCH_TYPE = FieldOfViewBatch  # type: ignore[assignment]
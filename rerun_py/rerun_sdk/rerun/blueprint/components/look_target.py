@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/look_target.fbs".
+
+# You can extend this class by creating a "LookTargetExt" class in "look_target_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["LookTarget", "LookTargetBatch"]
+
+
+class LookTarget(datatypes.Vec3D, ComponentMixin):
+    """**Component**: The point in 3D space an eye is looking at."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of LookTargetExt in look_target_ext.py
+
+    # Note: there are no fields here because LookTarget delegates to datatypes.Vec3D
+    pass
+
+
+class LookTargetBatch(datatypes.Vec3DBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.LookTarget")
+
+
+# This is patched in late to avoid circular dependencies.
+LookTarget._BATCH_TYPE = LookTargetBatch  # type: ignore[assignment]
@@ -0,0 +1,72 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/measurement_kind.fbs".
+
+# You can extend this class by creating a "MeasurementKindExt" class in "measurement_kind_ext.py".
+
+from __future__ import annotations
+
+from typing import Literal, Sequence, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentDescriptor,
+)
+
+__all__ = ["MeasurementKind", "MeasurementKindArrayLike", "MeasurementKindBatch", "MeasurementKindLike"]
+
+
+from enum import Enum
+
+
+class MeasurementKind(Enum):
+    """**Component**: The kind of a measurement taken in a spatial view."""
+
+    Distance = 1
+    """The distance between two points."""
+
+    Angle = 2
+    """The angle at the middle point of three points."""
+
+    Area = 3
+    """The area of the planar polygon spanned by three or more points."""
+
+    @classmethod
+    def auto(cls, val: str | int | MeasurementKind) -> MeasurementKind:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, MeasurementKind):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+MeasurementKindLike = Union[MeasurementKind, Literal["Angle", "Area", "Distance", "angle", "area", "distance"], int]
+MeasurementKindArrayLike = Union[MeasurementKindLike, Sequence[MeasurementKindLike]]
+
+
+class MeasurementKindBatch(BaseBatch[MeasurementKindArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.MeasurementKind")
+
+    @staticmethod
+    def _native_to_pa_array(data: MeasurementKindArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (MeasurementKind, int, str)):
+            data = [data]
+
+        pa_data = [MeasurementKind.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/time_offset.fbs".
+
+# You can extend this class by creating a "TimeOffsetExt" class in "time_offset_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TimeOffset", "TimeOffsetBatch"]
+
+
+class TimeOffset(datatypes.TimeInt, ComponentMixin):
+    """**Component**: A shift applied to all times of a recording."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TimeOffsetExt in time_offset_ext.py
+
+    # Note: there are no fields here because TimeOffset delegates to datatypes.TimeInt
+    pass
+
+
+class TimeOffsetBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.TimeOffset")
+
+
+# This is patched in late to avoid circular dependencies.
+TimeOffset._BATCH_TYPE = TimeOffsetBatch  # type: ignore[assignment]
@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/use_regex.fbs".
+
+# You can extend this class by creating a "UseRegexExt" class in "use_regex_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["UseRegex", "UseRegexBatch"]
+
+
+class UseRegex(datatypes.Bool, ComponentMixin):
+    """**Component**: Whether a search query is a regular expression rather than a plain substring."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of UseRegexExt in use_regex_ext.py
+
+    # Note: there are no fields here because UseRegex delegates to datatypes.Bool
+    pass
+
+
+class UseRegexBatch(datatypes.BoolBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.blueprint.components.UseRegex")
+
+
+# This is patched in late to avoid circular dependencies.
+UseRegex._BATCH_TYPE = UseRegexBatch  # type: ignore[assignment]
@@ -81,6 +81,7 @@ class Spatial2DView(View):
         | blueprint_components.BackgroundKindLike
         | None = None,
         visual_bounds: blueprint_archetypes.VisualBounds2D | None = None,
+        measurements: blueprint_archetypes.Measurements | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -122,6 +123,8 @@ class Spatial2DView(View):
 
             Everything within these bounds are guaranteed to be visible.
             Somethings outside of these bounds may also be visible due to letterboxing.
+        measurements:
+            Measurements taken with the measurement tools of this view.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -141,6 +144,11 @@ class Spatial2DView(View):
                 visual_bounds = blueprint_archetypes.VisualBounds2D(visual_bounds)
             properties["VisualBounds2D"] = visual_bounds
 
+        if measurements is not None:
+            if not isinstance(measurements, blueprint_archetypes.Measurements):
+                measurements = blueprint_archetypes.Measurements(measurements)
+            properties["Measurements"] = measurements
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
@@ -86,6 +86,10 @@ class Spatial3DView(View):
         | blueprint_components.BackgroundKindLike
         | None = None,
         line_grid: blueprint_archetypes.LineGrid3D | None = None,
+        eye_controls: blueprint_archetypes.EyeControls3D | None = None,
+        measurements: blueprint_archetypes.Measurements | None = None,
+        clip_planes: blueprint_archetypes.ClipPlanes3D | None = None,
+        section_box: blueprint_archetypes.SectionBox3D | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -124,6 +128,14 @@ class Spatial3DView(View):
             Configuration for the background of the view.
         line_grid:
             Configuration for the 3D line grid.
+        eye_controls:
+            Configuration of the 3D eye, i.e. the camera through which the scene is viewed.
+        measurements:
+            Measurements taken with the measurement tools of this view.
+        clip_planes:
+            Clipping planes that cut away parts of the scene.
+        section_box:
+            A box outside of which the scene is cut away.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -143,6 +155,26 @@ class Spatial3DView(View):
                 line_grid = blueprint_archetypes.LineGrid3D(line_grid)
             properties["LineGrid3D"] = line_grid
 
+        if eye_controls is not None:
+            if not isinstance(eye_controls, blueprint_archetypes.EyeControls3D):
+                eye_controls = blueprint_archetypes.EyeControls3D(eye_controls)
+            properties["EyeControls3D"] = eye_controls
+
+        if measurements is not None:
+            if not isinstance(measurements, blueprint_archetypes.Measurements):
+                measurements = blueprint_archetypes.Measurements(measurements)
+            properties["Measurements"] = measurements
+
+        if clip_planes is not None:
+            if not isinstance(clip_planes, blueprint_archetypes.ClipPlanes3D):
+                clip_planes = blueprint_archetypes.ClipPlanes3D(clip_planes)
+            properties["ClipPlanes3D"] = clip_planes
+
+        if section_box is not None:
+            if not isinstance(section_box, blueprint_archetypes.SectionBox3D):
+                section_box = blueprint_archetypes.SectionBox3D(section_box)
+            properties["SectionBox3D"] = section_box
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
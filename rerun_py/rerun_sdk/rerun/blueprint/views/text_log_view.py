@@ -11,6 +11,7 @@ __all__ = ["TextLogView"]
 from ... import datatypes
 from ..._baseclasses import AsComponents, ComponentBatchLike
 from ...datatypes import EntityPathLike, Utf8Like
+from .. import archetypes as blueprint_archetypes
 from ..api import View, ViewContentsLike
 
 
@@ -61,6 +62,7 @@ class TextLogView(View):
         visible: datatypes.BoolLike | None = None,
         defaults: list[Union[AsComponents, ComponentBatchLike]] = [],
         overrides: dict[EntityPathLike, list[ComponentBatchLike]] = {},
+        search: blueprint_archetypes.TextLogSearch | None = None,
     ) -> None:
         """
         Construct a blueprint for a new TextLogView view.
@@ -91,10 +93,17 @@ class TextLogView(View):
             Important note: the path must be a fully qualified entity path starting at the root. The override paths
             do not yet support `$origin` relative paths or glob expressions.
             This will be addressed in <https://github.com/rerun-io/rerun/issues/6673>.
+        search:
+            Searches the bodies of the log entries.
 
         """
 
         properties: dict[str, AsComponents] = {}
+        if search is not None:
+            if not isinstance(search, blueprint_archetypes.TextLogSearch):
+                search = blueprint_archetypes.TextLogSearch(search)
+            properties["TextLogSearch"] = search
+
         super().__init__(
             class_identifier="TextLog",
             origin=origin,
@@ -17,13 +17,17 @@ from .annotation_context import (
 )
 from .axis_length import AxisLength, AxisLengthBatch
 from .blob import Blob, BlobBatch
+from .cell_size import CellSize, CellSizeBatch
 from .class_id import ClassId, ClassIdBatch
 from .clear_is_recursive import ClearIsRecursive, ClearIsRecursiveBatch
+from .clock_drift import ClockDrift, ClockDriftBatch
+from .clock_offset import ClockOffset, ClockOffsetBatch
 from .color import Color, ColorBatch
 from .colormap import Colormap, ColormapArrayLike, ColormapBatch, ColormapLike
 from .depth_meter import DepthMeter, DepthMeterBatch
 from .draw_order import DrawOrder, DrawOrderBatch
 from .entity_path import EntityPath, EntityPathBatch
+from .event_duration import EventDuration, EventDurationBatch
 from .fill_mode import FillMode, FillModeArrayLike, FillModeBatch, FillModeLike
 from .fill_ratio import FillRatio, FillRatioBatch
 from .gamma_correction import GammaCorrection, GammaCorrectionBatch
@@ -39,6 +43,12 @@ from .image_plane_distance import ImagePlaneDistance, ImagePlaneDistanceBatch
 from .keypoint_id import KeypointId, KeypointIdBatch
 from .lat_lon import LatLon, LatLonBatch
 from .length import Length, LengthBatch
+from .lens_distortion_model import (
+    LensDistortionModel,
+    LensDistortionModelArrayLike,
+    LensDistortionModelBatch,
+    LensDistortionModelLike,
+)
 from .line_strip2d import LineStrip2D, LineStrip2DArrayLike, LineStrip2DBatch, LineStrip2DLike
 from .line_strip3d import LineStrip3D, LineStrip3DArrayLike, LineStrip3DBatch, LineStrip3DLike
 from .magnification_filter import (
@@ -61,6 +71,7 @@ from .pose_transform_mat3x3 import PoseTransformMat3x3, PoseTransformMat3x3Batch
 from .pose_translation3d import PoseTranslation3D, PoseTranslation3DBatch
 from .position2d import Position2D, Position2DBatch
 from .position3d import Position3D, Position3DBatch
+from .radial_distortion import RadialDistortion, RadialDistortionBatch
 from .radius import Radius, RadiusBatch
 from .range1d import Range1D, Range1DBatch
 from .recording_uri import RecordingUri, RecordingUriBatch
@@ -70,13 +81,17 @@ from .rotation_quat import RotationQuat, RotationQuatBatch
 from .scalar import Scalar, ScalarBatch
 from .scale3d import Scale3D, Scale3DBatch
 from .show_labels import ShowLabels, ShowLabelsBatch
+from .source_timeline import SourceTimeline, SourceTimelineBatch
 from .stroke_width import StrokeWidth, StrokeWidthBatch
+from .tangential_distortion import TangentialDistortion, TangentialDistortionBatch
+from .target_timeline import TargetTimeline, TargetTimelineBatch
 from .tensor_data import TensorData, TensorDataBatch
 from .tensor_dimension_index_selection import TensorDimensionIndexSelection, TensorDimensionIndexSelectionBatch
 from .tensor_height_dimension import TensorHeightDimension, TensorHeightDimensionBatch
 from .tensor_width_dimension import TensorWidthDimension, TensorWidthDimensionBatch
 from .texcoord2d import Texcoord2D, Texcoord2DBatch
 from .text import Text, TextBatch
+from .text_log_field import TextLogField, TextLogFieldBatch
 from .text_log_level import TextLogLevel, TextLogLevelBatch
 from .transform_mat3x3 import TransformMat3x3, TransformMat3x3Batch
 from .transform_relation import (
@@ -92,6 +107,8 @@ from .vector2d import Vector2D, Vector2DBatch
 from .vector3d import Vector3D, Vector3DBatch
 from .video_timestamp import VideoTimestamp, VideoTimestampBatch
 from .view_coordinates import ViewCoordinates, ViewCoordinatesBatch
+from .voxel_index import VoxelIndex, VoxelIndexBatch
+from .voxel_size import VoxelSize, VoxelSizeBatch
 
 __all__ = [
     "AggregationPolicy",
@@ -108,10 +125,16 @@ __all__ = [
     "AxisLengthBatch",
     "Blob",
     "BlobBatch",
+    "CellSize",
+    "CellSizeBatch",
     "ClassId",
     "ClassIdBatch",
     "ClearIsRecursive",
     "ClearIsRecursiveBatch",
+    "ClockDrift",
+    "ClockDriftBatch",
+    "ClockOffset",
+    "ClockOffsetBatch",
     "Color",
     "ColorBatch",
     "Colormap",
@@ -124,6 +147,8 @@ __all__ = [
     "DrawOrderBatch",
     "EntityPath",
     "EntityPathBatch",
+    "EventDuration",
+    "EventDurationBatch",
     "FillMode",
     "FillModeArrayLike",
     "FillModeBatch",
@@ -160,6 +185,10 @@ __all__ = [
     "LatLonBatch",
     "Length",
     "LengthBatch",
+    "LensDistortionModel",
+    "LensDistortionModelArrayLike",
+    "LensDistortionModelBatch",
+    "LensDistortionModelLike",
     "LineStrip2D",
     "LineStrip2DArrayLike",
     "LineStrip2DBatch",
@@ -202,6 +231,8 @@ __all__ = [
     "Position2DBatch",
     "Position3D",
     "Position3DBatch",
+    "RadialDistortion",
+    "RadialDistortionBatch",
     "Radius",
     "RadiusBatch",
     "Range1D",
@@ -220,8 +251,14 @@ __all__ = [
     "Scale3DBatch",
     "ShowLabels",
     "ShowLabelsBatch",
+    "SourceTimeline",
+    "SourceTimelineBatch",
     "StrokeWidth",
     "StrokeWidthBatch",
+    "TangentialDistortion",
+    "TangentialDistortionBatch",
+    "TargetTimeline",
+    "TargetTimelineBatch",
     "TensorData",
     "TensorDataBatch",
     "TensorDimensionIndexSelection",
@@ -234,6 +271,8 @@ __all__ = [
     "Texcoord2DBatch",
     "Text",
     "TextBatch",
+    "TextLogField",
+    "TextLogFieldBatch",
     "TextLogLevel",
     "TextLogLevelBatch",
     "TransformMat3x3",
@@ -256,4 +295,8 @@ __all__ = [
     "VideoTimestampBatch",
     "ViewCoordinates",
     "ViewCoordinatesBatch",
+    "VoxelIndex",
+    "VoxelIndexBatch",
+    "VoxelSize",
+    "VoxelSizeBatch",
 ]
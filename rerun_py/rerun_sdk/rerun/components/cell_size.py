@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/cell_size.fbs".
+
+# You can extend this class by creating a "CellSizeExt" class in "cell_size_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["CellSize", "CellSizeBatch"]
+
+
+class CellSize(datatypes.Vec2D, ComponentMixin):
+    """
+    **Component**: The size of a single cell of a 2D grid along each axis.
+
+    Measured in the local coordinate system of the grid.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of CellSizeExt in cell_size_ext.py
+
+    # Note: there are no fields here because CellSize delegates to datatypes.Vec2D
+    pass
+
+
+class CellSizeBatch(datatypes.Vec2DBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.CellSize")
+
+
+# This is patched in late to avoid circular dependencies.
+CellSize._BATCH_TYPE = CellSizeBatch  # type: ignore[assignment]
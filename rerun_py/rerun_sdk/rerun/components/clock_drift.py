@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/clock_drift.fbs".
+
+# You can extend this class by creating a "ClockDriftExt" class in "clock_drift_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["ClockDrift", "ClockDriftBatch"]
+
+
+class ClockDrift(datatypes.Float64, ComponentMixin):
+    """
+    **Component**: How much faster one clock runs than another, as a fraction.
+
+    A drift of `0.001` means that the target clock advances by 1.001 units for every unit of the source clock.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of ClockDriftExt in clock_drift_ext.py
+
+    # Note: there are no fields here because ClockDrift delegates to datatypes.Float64
+    pass
+
+
+class ClockDriftBatch(datatypes.Float64Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.ClockDrift")
+
+
+# This is patched in late to avoid circular dependencies.
+ClockDrift._BATCH_TYPE = ClockDriftBatch  # type: ignore[assignment]
@@ -0,0 +1,38 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/clock_offset.fbs".
+
+# You can extend this class by creating a "ClockOffsetExt" class in "clock_offset_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["ClockOffset", "ClockOffsetBatch"]
+
+
+class ClockOffset(datatypes.TimeInt, ComponentMixin):
+    """
+    **Component**: A constant offset added to the times of a timeline to express them on another timeline.
+
+    Measured in the units of the target timeline:
+    nanoseconds for temporal timelines, and steps for sequence timelines.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of ClockOffsetExt in clock_offset_ext.py
+
+    # Note: there are no fields here because ClockOffset delegates to datatypes.TimeInt
+    pass
+
+
+class ClockOffsetBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.ClockOffset")
+
+
+# This is patched in late to avoid circular dependencies.
+ClockOffset._BATCH_TYPE = ClockOffsetBatch  # type: ignore[assignment]
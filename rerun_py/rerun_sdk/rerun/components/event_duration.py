@@ -0,0 +1,38 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/event_duration.fbs".
+
+# You can extend this class by creating a "EventDurationExt" class in "event_duration_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["EventDuration", "EventDurationBatch"]
+
+
+class EventDuration(datatypes.TimeInt, ComponentMixin):
+    """
+    **Component**: The duration of a [`archetypes.TimelineEvent`][rerun.archetypes.TimelineEvent].
+
+    Measured in the units of the timeline the event is shown on:
+    nanoseconds for temporal timelines, and steps for sequence timelines.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of EventDurationExt in event_duration_ext.py
+
+    # Note: there are no fields here because EventDuration delegates to datatypes.TimeInt
+    pass
+
+
+class EventDurationBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.EventDuration")
+
+
+# This is patched in late to avoid circular dependencies.
+EventDuration._BATCH_TYPE = EventDurationBatch  # type: ignore[assignment]
@@ -0,0 +1,102 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/lens_distortion_model.fbs".
+
+# You can extend this class by creating a "LensDistortionModelExt" class in "lens_distortion_model_ext.py".
+
+from __future__ import annotations
+
+from typing import Literal, Sequence, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentDescriptor,
+)
+
+__all__ = ["LensDistortionModel", "LensDistortionModelArrayLike", "LensDistortionModelBatch", "LensDistortionModelLike"]
+
+
+from enum import Enum
+
+
+class LensDistortionModel(Enum):
+    """
+    **Component**: The lens distortion model of a camera, applied on top of its pinhole projection.
+
+    The model determines how [`components.RadialDistortion`][rerun.components.RadialDistortion] and [`components.TangentialDistortion`][rerun.components.TangentialDistortion]
+    are interpreted.
+    All models operate on normalized image coordinates, i.e. `(x, y) = (X / Z, Y / Z)`
+    for a point `(X, Y, Z)` in camera space, before the focal length and principal point are applied.
+    """
+
+    BrownConrady = 1
+    """
+    The Brown–Conrady model with radial and tangential distortion, e.g. OpenCV's default camera model.
+
+    With `r² = x² + y²`, normalized image coordinates are distorted as:
+    ```text
+    radial = 1 + k1·r² + k2·r⁴ + k3·r⁶ + k4·r⁸
+    x' = x·radial + 2·p1·x·y + p2·(r² + 2·x²)
+    y' = y·radial + p1·(r² + 2·y²) + 2·p2·x·y
+    ```
+    The `k4·r⁸` term is an extension of OpenCV's model, which stops at `k3·r⁶`.
+    Leave `k4` at zero for calibrations from OpenCV or most other tools.
+    In particular, the `k4` of OpenCV's rational model (`CALIB_RATIONAL_MODEL`) is a
+    denominator coefficient and can't be represented here.
+
+    Note that OpenCV orders its distortion coefficients as `[k1, k2, p1, p2, k3]`.
+    """
+
+    KannalaBrandt = 2
+    """
+    The Kannala–Brandt equidistant fisheye model, e.g. OpenCV's fisheye camera model.
+
+    With `θ = atan(r)` the angle of incidence, normalized image coordinates are distorted as:
+    ```text
+    θ' = θ·(1 + k1·θ² + k2·θ⁴ + k3·θ⁶ + k4·θ⁸)
+    (x', y') = (θ' / r)·(x, y)
+    ```
+    Tangential distortion is ignored by this model.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | LensDistortionModel) -> LensDistortionModel:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, LensDistortionModel):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+LensDistortionModelLike = Union[
+    LensDistortionModel, Literal["BrownConrady", "KannalaBrandt", "brownconrady", "kannalabrandt"], int
+]
+LensDistortionModelArrayLike = Union[LensDistortionModelLike, Sequence[LensDistortionModelLike]]
+
+
+class LensDistortionModelBatch(BaseBatch[LensDistortionModelArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.LensDistortionModel")
+
+    @staticmethod
+    def _native_to_pa_array(data: LensDistortionModelArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (LensDistortionModel, int, str)):
+            data = [data]
+
+        pa_data = [LensDistortionModel.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,39 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/radial_distortion.fbs".
+
+# You can extend this class by creating a "RadialDistortionExt" class in "radial_distortion_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["RadialDistortion", "RadialDistortionBatch"]
+
+
+class RadialDistortion(datatypes.Vec4D, ComponentMixin):
+    """
+    **Component**: Radial lens distortion coefficients `[k1, k2, k3, k4]`.
+
+    How they are applied depends on the [`components.LensDistortionModel`][rerun.components.LensDistortionModel].
+    For [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady], `k4` is a non-standard `k4·r⁸` term
+    which should be left at zero for OpenCV calibrations.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of RadialDistortionExt in radial_distortion_ext.py
+
+    # Note: there are no fields here because RadialDistortion delegates to datatypes.Vec4D
+    pass
+
+
+class RadialDistortionBatch(datatypes.Vec4DBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.RadialDistortion")
+
+
+# This is patched in late to avoid circular dependencies.
+RadialDistortion._BATCH_TYPE = RadialDistortionBatch  # type: ignore[assignment]
@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/source_timeline.fbs".
+
+# You can extend this class by creating a "SourceTimelineExt" class in "source_timeline_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["SourceTimeline", "SourceTimelineBatch"]
+
+
+class SourceTimeline(datatypes.Utf8, ComponentMixin):
+    """**Component**: The name of the timeline a [`archetypes.TimelineMapping`][rerun.archetypes.TimelineMapping] maps from."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of SourceTimelineExt in source_timeline_ext.py
+
+    # Note: there are no fields here because SourceTimeline delegates to datatypes.Utf8
+    pass
+
+
+class SourceTimelineBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.SourceTimeline")
+
+
+# This is patched in late to avoid circular dependencies.
+SourceTimeline._BATCH_TYPE = SourceTimelineBatch  # type: ignore[assignment]
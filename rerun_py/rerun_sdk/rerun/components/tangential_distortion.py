@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/tangential_distortion.fbs".
+
+# You can extend this class by creating a "TangentialDistortionExt" class in "tangential_distortion_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TangentialDistortion", "TangentialDistortionBatch"]
+
+
+class TangentialDistortion(datatypes.Vec2D, ComponentMixin):
+    """
+    **Component**: Tangential lens distortion coefficients `[p1, p2]`.
+
+    Only used by the [`components.LensDistortionModel.BrownConrady`][rerun.components.LensDistortionModel.BrownConrady] model.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TangentialDistortionExt in tangential_distortion_ext.py
+
+    # Note: there are no fields here because TangentialDistortion delegates to datatypes.Vec2D
+    pass
+
+
+class TangentialDistortionBatch(datatypes.Vec2DBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.TangentialDistortion")
+
+
+# This is patched in late to avoid circular dependencies.
+TangentialDistortion._BATCH_TYPE = TangentialDistortionBatch  # type: ignore[assignment]
@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/target_timeline.fbs".
+
+# You can extend this class by creating a "TargetTimelineExt" class in "target_timeline_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TargetTimeline", "TargetTimelineBatch"]
+
+
+class TargetTimeline(datatypes.Utf8, ComponentMixin):
+    """**Component**: The name of the timeline a [`archetypes.TimelineMapping`][rerun.archetypes.TimelineMapping] maps to."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TargetTimelineExt in target_timeline_ext.py
+
+    # Note: there are no fields here because TargetTimeline delegates to datatypes.Utf8
+    pass
+
+
+class TargetTimelineBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.TargetTimeline")
+
+
+# This is patched in late to avoid circular dependencies.
+TargetTimeline._BATCH_TYPE = TargetTimelineBatch  # type: ignore[assignment]
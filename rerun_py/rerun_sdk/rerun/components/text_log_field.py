@@ -0,0 +1,33 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/text_log_field.fbs".
+
+# You can extend this class by creating a "TextLogFieldExt" class in "text_log_field_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["TextLogField", "TextLogFieldBatch"]
+
+
+class TextLogField(datatypes.Utf8Pair, ComponentMixin):
+    """**Component**: A key-value field of a structured log entry, e.g. `("request_id", "42")`."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TextLogFieldExt in text_log_field_ext.py
+
+    # Note: there are no fields here because TextLogField delegates to datatypes.Utf8Pair
+    pass
+
+
+class TextLogFieldBatch(datatypes.Utf8PairBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.TextLogField")
+
+
+# This is patched in late to avoid circular dependencies.
+TextLogField._BATCH_TYPE = TextLogFieldBatch  # type: ignore[assignment]
@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/voxel_index.fbs".
+
+# You can extend this class by creating a "VoxelIndexExt" class in "voxel_index_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["VoxelIndex", "VoxelIndexBatch"]
+
+
+class VoxelIndex(datatypes.UVec3D, ComponentMixin):
+    """
+    **Component**: The integer coordinates of a voxel in a voxel grid.
+
+    Voxel `[0, 0, 0]` is the one at the origin of the grid.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of VoxelIndexExt in voxel_index_ext.py
+
+    # Note: there are no fields here because VoxelIndex delegates to datatypes.UVec3D
+    pass
+
+
+class VoxelIndexBatch(datatypes.UVec3DBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.VoxelIndex")
+
+
+# This is patched in late to avoid circular dependencies.
+VoxelIndex._BATCH_TYPE = VoxelIndexBatch  # type: ignore[assignment]
@@ -0,0 +1,37 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/voxel_size.fbs".
+
+# You can extend this class by creating a "VoxelSizeExt" class in "voxel_size_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentDescriptor,
+    ComponentMixin,
+)
+
+__all__ = ["VoxelSize", "VoxelSizeBatch"]
+
+
+class VoxelSize(datatypes.Vec3D, ComponentMixin):
+    """
+    **Component**: The size of a single voxel of a voxel grid along each axis.
+
+    Measured in the local coordinate system of the grid.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of VoxelSizeExt in voxel_size_ext.py
+
+    # Note: there are no fields here because VoxelSize delegates to datatypes.Vec3D
+    pass
+
+
+class VoxelSizeBatch(datatypes.Vec3DBatch, ComponentBatchMixin):
+    _COMPONENT_DESCRIPTOR: ComponentDescriptor = ComponentDescriptor("rerun.components.VoxelSize")
+
+
+# This is patched in late to avoid circular dependencies.
+VoxelSize._BATCH_TYPE = VoxelSizeBatch  # type: ignore[assignment]